@@ -16,6 +16,14 @@ export const config = {
   jwtRefreshExpiryDays: parseInt(process.env.JWT_REFRESH_EXPIRY_DAYS || '30', 10),
   encryptionKey: process.env.ENCRYPTION_KEY || '',
 
+  // Auth rate limits (sliding window)
+  loginRateLimitWindowSecs: parseInt(process.env.LOGIN_RATE_LIMIT_WINDOW_SECS || '900', 10),
+  loginRateLimitPerIp: parseInt(process.env.LOGIN_RATE_LIMIT_PER_IP || '5', 10),
+  loginRateLimitPerAccount: parseInt(process.env.LOGIN_RATE_LIMIT_PER_ACCOUNT || '10', 10),
+  refreshRateLimitWindowSecs: parseInt(process.env.REFRESH_RATE_LIMIT_WINDOW_SECS || '3600', 10),
+  refreshRateLimitPerIp: parseInt(process.env.REFRESH_RATE_LIMIT_PER_IP || '30', 10),
+  refreshRateLimitPerAccount: parseInt(process.env.REFRESH_RATE_LIMIT_PER_ACCOUNT || '30', 10),
+
   // AI
   openaiApiKey: process.env.OPENAI_API_KEY || '',
   anthropicApiKey: process.env.ANTHROPIC_API_KEY || '',
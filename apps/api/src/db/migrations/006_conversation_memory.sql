@@ -0,0 +1,13 @@
+-- Outreach Database Schema
+-- Migration 006: Conversation Memory
+
+-- =============================================================================
+-- RECORDING TRANSCRIPTS AS RAG DOCUMENTS
+-- =============================================================================
+
+-- Link indexed call transcripts back to their recording
+ALTER TABLE documents
+    ADD COLUMN recording_id UUID REFERENCES recordings(id) ON DELETE CASCADE;
+
+-- One indexed document per recording (re-indexing replaces it)
+CREATE UNIQUE INDEX idx_documents_recording ON documents(recording_id) WHERE recording_id IS NOT NULL;
@@ -0,0 +1,24 @@
+-- Outreach Database Schema
+-- Migration 007: Ranked Lead Search
+
+CREATE EXTENSION IF NOT EXISTS "pg_trgm";
+
+-- =============================================================================
+-- FULL-TEXT SEARCH
+-- =============================================================================
+
+-- Must match LEAD_SEARCH_VECTOR in routes/leads.ts for the index to be used
+CREATE INDEX idx_leads_fts ON leads USING gin(
+    to_tsvector('english',
+        COALESCE(company_name, '') || ' ' || COALESCE(company_domain, '') || ' ' ||
+        COALESCE(contact_name, '') || ' ' || COALESCE(contact_email, '') || ' ' ||
+        COALESCE(industry, ''))
+);
+
+-- =============================================================================
+-- FUZZY MATCHING (also speeds up ILIKE '%term%')
+-- =============================================================================
+
+CREATE INDEX idx_leads_company_trgm ON leads USING gin(company_name gin_trgm_ops);
+CREATE INDEX idx_leads_contact_name_trgm ON leads USING gin(contact_name gin_trgm_ops);
+CREATE INDEX idx_leads_contact_email_trgm ON leads USING gin(contact_email gin_trgm_ops);
@@ -0,0 +1,21 @@
+-- Outreach Database Schema
+-- Migration 008: Refresh Token Reuse Detection
+
+-- =============================================================================
+-- TOKEN FAMILIES
+-- =============================================================================
+
+-- Every login starts a family; each rotation adds a token to it and marks
+-- the previous one consumed. Presenting a consumed token again means it was
+-- stolen, so the whole family is revoked.
+ALTER TABLE refresh_tokens
+    ADD COLUMN family_id UUID NOT NULL DEFAULT gen_random_uuid(),
+    ADD COLUMN consumed_at TIMESTAMPTZ;
+
+-- Consumed tokens are kept, so only the active token is unique per device
+ALTER TABLE refresh_tokens DROP CONSTRAINT refresh_tokens_user_id_device_id_key;
+CREATE UNIQUE INDEX idx_refresh_tokens_active_device ON refresh_tokens(user_id, device_id)
+    WHERE consumed_at IS NULL;
+
+CREATE INDEX idx_refresh_tokens_hash ON refresh_tokens(token_hash);
+CREATE INDEX idx_refresh_tokens_family ON refresh_tokens(family_id);
@@ -0,0 +1,18 @@
+-- Outreach Database Schema
+-- Migration 009: Desktop Recording Sessions
+
+-- =============================================================================
+-- FULL SESSIONS FROM THE DESKTOP APP
+-- =============================================================================
+
+ALTER TABLE recordings
+    ADD COLUMN client_session_id VARCHAR(64),  -- RecordingSession.id, makes uploads idempotent
+    ADD COLUMN session JSONB,                  -- Serialized RecordingSession (turns, suggestions, metadata)
+    ADD COLUMN call_summary JSONB,             -- Serialized CallSummary, if one was generated
+    ADD COLUMN score INTEGER;                  -- Overall performance score (0-100)
+
+CREATE UNIQUE INDEX idx_recordings_client_session ON recordings(user_id, client_session_id)
+    WHERE client_session_id IS NOT NULL;
+CREATE INDEX idx_recordings_user_mode ON recordings(user_id, mode);
+CREATE INDEX idx_recordings_user_created ON recordings(user_id, created_at DESC);
+CREATE INDEX idx_recordings_user_score ON recordings(user_id, score DESC) WHERE score IS NOT NULL;
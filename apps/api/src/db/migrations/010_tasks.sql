@@ -0,0 +1,23 @@
+-- Outreach Database Schema
+-- Migration 010: Tasks
+
+-- =============================================================================
+-- ACTION ITEMS FROM MEETINGS
+-- =============================================================================
+
+CREATE TABLE tasks (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    lead_id UUID REFERENCES leads(id) ON DELETE SET NULL,
+
+    client_session_id VARCHAR(64),  -- RecordingSession.id the task came from
+    title TEXT NOT NULL,
+    owner VARCHAR(200),             -- Who took it on, as said in the meeting
+    due_date DATE,
+    completed_at TIMESTAMPTZ,
+
+    created_at TIMESTAMPTZ DEFAULT NOW()
+);
+
+CREATE INDEX idx_tasks_user_open ON tasks(user_id, due_date) WHERE completed_at IS NULL;
+CREATE INDEX idx_tasks_user_session ON tasks(user_id, client_session_id) WHERE client_session_id IS NOT NULL;
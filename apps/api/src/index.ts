@@ -23,6 +23,9 @@ import trackingRouter from './routes/tracking.js';
 import cvRouter from './routes/cv.js';
 import icpRouter from './routes/icp.js';
 import discoveryRouter from './routes/discovery.js';
+import memoryRouter from './routes/memory.js';
+import syncRouter from './routes/sync.js';
+import tasksRouter from './routes/tasks.js';
 
 async function main() {
   // Validate config
@@ -96,7 +99,7 @@ async function main() {
     },
     credentials: true,
     methods: ['GET', 'POST', 'PUT', 'PATCH', 'DELETE', 'OPTIONS'],
-    allowedHeaders: ['Content-Type', 'Authorization', 'X-Requested-With'],
+    allowedHeaders: ['Content-Type', 'Authorization', 'X-Requested-With', 'X-Device-Id'],
     exposedHeaders: ['X-RateLimit-Limit', 'X-RateLimit-Remaining', 'X-RateLimit-Reset', 'Retry-After'],
     maxAge: 86400, // 24 hours
   };
@@ -131,6 +134,9 @@ async function main() {
   app.use('/cv', cvRouter);
   app.use('/icp', icpRouter);
   app.use('/discovery', discoveryRouter);
+  app.use('/memory', memoryRouter);
+  app.use('/sync', syncRouter);
+  app.use('/tasks', tasksRouter);
 
   // Tracking (public, no auth - but has HMAC verification)
   app.use('/track', trackingRouter);
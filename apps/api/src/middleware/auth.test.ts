@@ -0,0 +1,74 @@
+/**
+ * Auth middleware device binding tests - run with `npm test`
+ */
+
+import { test } from 'node:test';
+import assert from 'node:assert/strict';
+import { Response } from 'express';
+import jwt from 'jsonwebtoken';
+import { config } from '../config.js';
+import { authMiddleware, AuthRequest } from './auth.js';
+
+const DEVICE = '0b6f3a62-4f0e-4c7d-9a1b-2f5e8c9d1a34';
+const OTHER_DEVICE = '9d2e7c41-8b3a-4f6d-b5e0-1c4a7f2e9b83';
+
+config.jwtSecret = 'test-secret';
+
+function accessToken(deviceId?: string): string {
+  return jwt.sign(
+    { sub: 'user-1', email: 'sam@example.com', subscriptionTier: 'free', tokenVersion: 0, deviceId },
+    config.jwtSecret,
+    { expiresIn: 900, issuer: 'outreach-api', audience: 'outreach-app', algorithm: 'HS256' },
+  );
+}
+
+function request(token: string, deviceId?: string): AuthRequest {
+  const headers: Record<string, string> = { authorization: `Bearer ${token}` };
+  if (deviceId) {
+    headers['x-device-id'] = deviceId;
+  }
+  return { headers } as unknown as AuthRequest;
+}
+
+function response() {
+  const res = {
+    statusCode: 200,
+    body: undefined as unknown,
+    status(code: number) {
+      res.statusCode = code;
+      return res;
+    },
+    json(body: unknown) {
+      res.body = body;
+      return res;
+    },
+  };
+  return res;
+}
+
+function authenticate(req: AuthRequest) {
+  const res = response();
+  let passed = false;
+  authMiddleware(req, res as unknown as Response, () => { passed = true; });
+  return { passed, res };
+}
+
+test('token bound to a device is accepted with its X-Device-Id', () => {
+  const req = request(accessToken(DEVICE), DEVICE);
+  const { passed } = authenticate(req);
+  assert.equal(passed, true);
+  assert.equal(req.user?.deviceId, DEVICE);
+});
+
+test('token bound to a device is rejected from another device', () => {
+  for (const header of [OTHER_DEVICE, undefined]) {
+    const { passed, res } = authenticate(request(accessToken(DEVICE), header));
+    assert.equal(passed, false);
+    assert.equal(res.statusCode, 401);
+    assert.equal((res.body as { error: { code: string } }).error.code, 'DEVICE_MISMATCH');
+  }
+});
+
+test('token without a device claim needs no X-Device-Id', () => {
+  assert.equal(authenticate(request(accessToken())).passed, true);
+});
@@ -16,6 +16,7 @@ export interface AuthUser {
   email: string;
   subscriptionTier: string;
   tokenVersion: number;
+  deviceId?: string;
 }
 
 export interface AuthRequest extends Request {
@@ -27,12 +28,18 @@ interface JWTPayload {
   email: string;
   subscriptionTier: string;
   tokenVersion: number;
+  deviceId?: string;     // Device the token was issued to
   iat: number;
   exp: number;
   iss: string;
   aud: string;
 }
 
+// Device binding: a token issued to a device only works with its X-Device-Id
+function deviceMatches(req: Request, payload: JWTPayload): boolean {
+  return !payload.deviceId || req.headers['x-device-id'] === payload.deviceId;
+}
+
 export function authMiddleware(req: AuthRequest, res: Response, next: NextFunction) {
   const authHeader = req.headers.authorization;
 
@@ -64,12 +71,22 @@ export function authMiddleware(req: AuthRequest, res: Response, next: NextFuncti
       algorithms: ['HS256'],
     }) as JWTPayload;
 
+    if (!deviceMatches(req, payload)) {
+      return res.status(401).json({
+        error: {
+          code: 'DEVICE_MISMATCH',
+          message: 'Token was issued to a different device',
+        },
+      });
+    }
+
     // Map payload to AuthUser
     req.user = {
       id: payload.sub,
       email: payload.email,
       subscriptionTier: payload.subscriptionTier,
       tokenVersion: payload.tokenVersion,
+      deviceId: payload.deviceId,
     };
 
     next();
@@ -119,12 +136,15 @@ export function optionalAuth(req: AuthRequest, res: Response, next: NextFunction
       algorithms: ['HS256'],
     }) as JWTPayload;
 
-    req.user = {
-      id: payload.sub,
-      email: payload.email,
-      subscriptionTier: payload.subscriptionTier,
-      tokenVersion: payload.tokenVersion,
-    };
+    if (deviceMatches(req, payload)) {
+      req.user = {
+        id: payload.sub,
+        email: payload.email,
+        subscriptionTier: payload.subscriptionTier,
+        tokenVersion: payload.tokenVersion,
+        deviceId: payload.deviceId,
+      };
+    }
   } catch {
     // Ignore errors for optional auth - just don't set user
   }
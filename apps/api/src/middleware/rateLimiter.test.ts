@@ -0,0 +1,81 @@
+/**
+ * Rate limiter tests - run with `npm test`
+ */
+
+import { test } from 'node:test';
+import assert from 'node:assert/strict';
+import { Request, Response } from 'express';
+import { createRateLimiter, bodyFieldKey, MemoryRateLimitStore } from './rateLimiter.js';
+
+function request(ip: string, body: Record<string, unknown> = {}): Request {
+  return { headers: {}, ip, socket: {}, body } as unknown as Request;
+}
+
+function response() {
+  const res = {
+    statusCode: 200,
+    headers: {} as Record<string, unknown>,
+    setHeader(name: string, value: unknown) {
+      res.headers[name] = value;
+      return res;
+    },
+    status(code: number) {
+      res.statusCode = code;
+      return res;
+    },
+    json() {
+      return res;
+    },
+  };
+  return res;
+}
+
+async function attempt(limiter: ReturnType<typeof createRateLimiter>, req: Request) {
+  const res = response();
+  let passed = false;
+  await limiter(req, res as unknown as Response, () => { passed = true; });
+  return { passed, res };
+}
+
+test('rejects the attempt after the limit within the window', async () => {
+  const limiter = createRateLimiter('test-ip', { windowMs: 60_000, maxRequests: 3 });
+
+  for (let i = 0; i < 3; i++) {
+    assert.equal((await attempt(limiter, request('10.0.0.1'))).passed, true);
+  }
+
+  const { passed, res } = await attempt(limiter, request('10.0.0.1'));
+  assert.equal(passed, false);
+  assert.equal(res.statusCode, 429);
+  assert.ok(Number(res.headers['Retry-After']) > 0);
+
+  // Other IPs are limited separately
+  assert.equal((await attempt(limiter, request('10.0.0.2'))).passed, true);
+});
+
+test('per-account limit applies across IPs', async () => {
+  const limiter = createRateLimiter('test-account', {
+    windowMs: 60_000,
+    maxRequests: 2,
+    keyGenerator: bodyFieldKey('email'),
+  });
+
+  assert.equal((await attempt(limiter, request('10.0.0.1', { email: 'a@example.com' }))).passed, true);
+  assert.equal((await attempt(limiter, request('10.0.0.2', { email: 'A@example.com' }))).passed, true);
+  assert.equal((await attempt(limiter, request('10.0.0.3', { email: 'a@example.com' }))).passed, false);
+
+  // Requests without the field aren't limited by this limiter
+  assert.equal((await attempt(limiter, request('10.0.0.3'))).passed, true);
+});
+
+test('sliding window frees slots as old hits expire', async () => {
+  const store = new MemoryRateLimitStore();
+  await store.add('ip', 1_000);
+  await store.add('ip', 30_000);
+
+  assert.deepEqual(await store.recent('ip', 59_000, 60_000), [1_000, 30_000]);
+  assert.deepEqual(await store.recent('ip', 61_000, 60_000), [30_000]);
+
+  store.prune(91_000, 60_000);
+  assert.deepEqual(await store.recent('ip', 91_000, 60_000), []);
+});
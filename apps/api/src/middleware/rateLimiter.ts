@@ -1,45 +1,69 @@
 /**
  * Rate Limiter Middleware
- * In-memory rate limiting for auth endpoints
+ * Sliding-window rate limiting, per IP by default or keyed per account
  *
- * For production at scale, consider Redis-based rate limiting
+ * Hits are kept in an in-memory store; for multi-instance deployments,
+ * pass a Redis-backed RateLimitStore instead
  */
 
 import { Request, Response, NextFunction } from 'express';
-
-interface RateLimitEntry {
-  count: number;
-  resetTime: number;
+import { config as appConfig } from '../config.js';
+
+// Sliding window log: timestamps of recent allowed hits per key
+export interface RateLimitStore {
+  // Hits for a key still inside the window, oldest first
+  recent(key: string, now: number, windowMs: number): Promise<number[]>;
+  // Record an allowed hit
+  add(key: string, now: number): Promise<void>;
+  // Drop hits older than the window
+  prune(now: number, windowMs: number): void;
 }
 
-interface RateLimitStore {
-  [key: string]: RateLimitEntry;
+export class MemoryRateLimitStore implements RateLimitStore {
+  private hits: Map<string, number[]> = new Map();
+
+  async recent(key: string, now: number, windowMs: number): Promise<number[]> {
+    const hits = (this.hits.get(key) || []).filter(t => t > now - windowMs);
+    this.hits.set(key, hits);
+    return hits;
+  }
+
+  async add(key: string, now: number): Promise<void> {
+    const hits = this.hits.get(key) || [];
+    hits.push(now);
+    this.hits.set(key, hits);
+  }
+
+  prune(now: number, windowMs: number): void {
+    for (const [key, hits] of this.hits) {
+      const recent = hits.filter(t => t > now - windowMs);
+      if (recent.length === 0) {
+        this.hits.delete(key);
+      } else {
+        this.hits.set(key, recent);
+      }
+    }
+  }
 }
 
 interface RateLimitConfig {
   windowMs: number;      // Time window in milliseconds
   maxRequests: number;   // Max requests per window
   message?: string;      // Error message
-  keyGenerator?: (req: Request) => string;
-  skipSuccessfulRequests?: boolean;
-  skipFailedRequests?: boolean;
+  keyGenerator?: (req: Request) => string; // Empty key = not limited
+  store?: RateLimitStore;
 }
 
-// In-memory store (per-instance)
-// For multi-instance deployments, use Redis
-const stores: Map<string, RateLimitStore> = new Map();
+// Stores per limiter, with their window for cleanup
+const stores: Map<string, { store: RateLimitStore; windowMs: number }> = new Map();
 
 // Cleanup old entries every 5 minutes
 setInterval(() => {
   const now = Date.now();
-  for (const [, store] of stores) {
-    for (const key of Object.keys(store)) {
-      if (store[key].resetTime < now) {
-        delete store[key];
-      }
-    }
+  for (const [, { store, windowMs }] of stores) {
+    store.prune(now, windowMs);
   }
-}, 5 * 60 * 1000);
+}, 5 * 60 * 1000).unref();
 
 function getClientIdentifier(req: Request): string {
   // Use X-Forwarded-For for proxied requests (Railway, etc.)
@@ -51,58 +75,63 @@ function getClientIdentifier(req: Request): string {
   return req.ip || req.socket.remoteAddress || 'unknown';
 }
 
-export function createRateLimiter(name: string, config: RateLimitConfig) {
-  // Initialize store for this limiter
-  if (!stores.has(name)) {
-    stores.set(name, {});
-  }
+// Login/refresh body field to limit per account (e.g. email, deviceId)
+export function bodyFieldKey(field: string) {
+  return (req: Request): string => {
+    const value = req.body?.[field];
+    return typeof value === 'string' ? value.toLowerCase().trim() : '';
+  };
+}
 
+export function createRateLimiter(name: string, config: RateLimitConfig) {
   const {
     windowMs,
     maxRequests,
     message = 'Too many requests, please try again later',
     keyGenerator = getClientIdentifier,
+    store = new MemoryRateLimitStore(),
   } = config;
 
-  return (req: Request, res: Response, next: NextFunction) => {
-    const store = stores.get(name)!;
+  stores.set(name, { store, windowMs });
+
+  return async (req: Request, res: Response, next: NextFunction) => {
     const key = keyGenerator(req);
-    const now = Date.now();
-
-    // Get or create entry
-    let entry = store[key];
-    if (!entry || entry.resetTime < now) {
-      entry = {
-        count: 0,
-        resetTime: now + windowMs,
-      };
-      store[key] = entry;
+    if (!key) {
+      return next();
     }
 
-    // Increment count
-    entry.count++;
-
-    // Set rate limit headers
-    const remaining = Math.max(0, maxRequests - entry.count);
-    const resetSeconds = Math.ceil((entry.resetTime - now) / 1000);
-
-    res.setHeader('X-RateLimit-Limit', maxRequests);
-    res.setHeader('X-RateLimit-Remaining', remaining);
-    res.setHeader('X-RateLimit-Reset', Math.ceil(entry.resetTime / 1000));
-    res.setHeader('Retry-After', resetSeconds);
-
-    // Check if over limit
-    if (entry.count > maxRequests) {
-      return res.status(429).json({
-        error: {
-          code: 'RATE_LIMIT_EXCEEDED',
-          message,
-          retryAfter: resetSeconds,
-        },
-      });
-    }
+    try {
+      const now = Date.now();
+      const hits = await store.recent(key, now, windowMs);
+
+      // Check if over limit; a slot frees up when the oldest counted hit expires
+      if (hits.length >= maxRequests) {
+        const retryAfter = Math.max(1, Math.ceil((hits[hits.length - maxRequests] + windowMs - now) / 1000));
+        res.setHeader('X-RateLimit-Limit', maxRequests);
+        res.setHeader('X-RateLimit-Remaining', 0);
+        res.setHeader('X-RateLimit-Reset', Math.ceil((now + retryAfter * 1000) / 1000));
+        res.setHeader('Retry-After', retryAfter);
+        return res.status(429).json({
+          error: {
+            code: 'RATE_LIMIT_EXCEEDED',
+            message,
+            retryAfter,
+          },
+        });
+      }
+
+      await store.add(key, now);
+
+      // Set rate limit headers
+      const oldest = hits.length > 0 ? hits[0] : now;
+      res.setHeader('X-RateLimit-Limit', maxRequests);
+      res.setHeader('X-RateLimit-Remaining', maxRequests - hits.length - 1);
+      res.setHeader('X-RateLimit-Reset', Math.ceil((oldest + windowMs) / 1000));
 
-    next();
+      next();
+    } catch (err) {
+      next(err);
+    }
   };
 }
 
@@ -110,11 +139,19 @@ export function createRateLimiter(name: string, config: RateLimitConfig) {
 // PRE-CONFIGURED LIMITERS
 // ============================================================================
 
-// Strict limiter for login attempts: 5 attempts per 15 minutes per IP
+// Strict limiter for login attempts: 5 attempts per 15 minutes per IP (configurable)
 export const loginLimiter = createRateLimiter('login', {
-  windowMs: 15 * 60 * 1000, // 15 minutes
-  maxRequests: 5,
-  message: 'Too many login attempts. Please try again in 15 minutes.',
+  windowMs: appConfig.loginRateLimitWindowSecs * 1000,
+  maxRequests: appConfig.loginRateLimitPerIp,
+  message: 'Too many login attempts. Please try again later.',
+});
+
+// Login attempts per account, so a botnet can't spread guesses across IPs
+export const loginAccountLimiter = createRateLimiter('loginAccount', {
+  windowMs: appConfig.loginRateLimitWindowSecs * 1000,
+  maxRequests: appConfig.loginRateLimitPerAccount,
+  message: 'Too many login attempts for this account. Please try again later.',
+  keyGenerator: bodyFieldKey('email'),
 });
 
 // Registration limiter: 3 registrations per hour per IP
@@ -131,11 +168,19 @@ export const passwordResetLimiter = createRateLimiter('passwordReset', {
   message: 'Too many password reset requests. Please try again later.',
 });
 
-// Token refresh limiter: 30 refreshes per hour per IP
+// Token refresh limiter: 30 refreshes per hour per IP (configurable)
 export const refreshLimiter = createRateLimiter('refresh', {
-  windowMs: 60 * 60 * 1000, // 1 hour
-  maxRequests: 30,
+  windowMs: appConfig.refreshRateLimitWindowSecs * 1000,
+  maxRequests: appConfig.refreshRateLimitPerIp,
+  message: 'Too many token refresh requests. Please try again later.',
+});
+
+// Token refreshes per device session
+export const refreshAccountLimiter = createRateLimiter('refreshAccount', {
+  windowMs: appConfig.refreshRateLimitWindowSecs * 1000,
+  maxRequests: appConfig.refreshRateLimitPerAccount,
   message: 'Too many token refresh requests. Please try again later.',
+  keyGenerator: bodyFieldKey('deviceId'),
 });
 
 // Email sending limiter: 50 emails per hour per user
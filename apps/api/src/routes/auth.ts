@@ -9,13 +9,17 @@ import jwt from 'jsonwebtoken';
 import crypto from 'crypto';
 import { z } from 'zod';
 import { v4 as uuidv4 } from 'uuid';
+import { PoolClient } from 'pg';
 import { pool } from '../db.js';
 import { config } from '../config.js';
 import { authMiddleware, AuthRequest } from '../middleware/auth.js';
+import { checkRefreshToken } from '../utils/refreshTokens.js';
 import {
   loginLimiter,
+  loginAccountLimiter,
   registerLimiter,
   refreshLimiter,
+  refreshAccountLimiter,
   bruteForceProtection,
   recordFailedAttempt,
   resetFailedAttempts,
@@ -63,13 +67,14 @@ interface TokenUser {
   token_version: number;
 }
 
-function createAccessToken(user: TokenUser): string {
+function createAccessToken(user: TokenUser, deviceId: string): string {
   return jwt.sign(
     {
       sub: user.id, // Standard claim for subject
       email: user.email,
       subscriptionTier: user.subscription_tier,
       tokenVersion: user.token_version,
+      deviceId, // Bound device, checked against X-Device-Id
     },
     config.jwtSecret,
     {
@@ -81,27 +86,42 @@ function createAccessToken(user: TokenUser): string {
   );
 }
 
-async function createRefreshToken(
+async function issueRefreshToken(
+  db: Pick<PoolClient, 'query'>,
   userId: string,
   deviceId: string,
   deviceName: string | null,
-  tokenVersion: number
+  tokenVersion: number,
+  familyId: string
 ): Promise<string> {
   const token = crypto.randomBytes(64).toString('hex');
   const tokenHash = crypto.createHash('sha256').update(token).digest('hex');
   const expiresAt = new Date(Date.now() + config.jwtRefreshExpiryDays * 24 * 60 * 60 * 1000);
 
-  await pool.query(
-    `INSERT INTO refresh_tokens (user_id, token_hash, device_id, device_name, token_version, expires_at)
-     VALUES ($1, $2, $3, $4, $5, $6)
-     ON CONFLICT (user_id, device_id)
-     DO UPDATE SET token_hash = $2, token_version = $5, expires_at = $6, last_used_at = NOW()`,
-    [userId, tokenHash, deviceId, deviceName, tokenVersion, expiresAt]
+  await db.query(
+    `INSERT INTO refresh_tokens (user_id, token_hash, device_id, device_name, token_version, expires_at, family_id)
+     VALUES ($1, $2, $3, $4, $5, $6, $7)`,
+    [userId, tokenHash, deviceId, deviceName, tokenVersion, expiresAt, familyId]
   );
 
   return token;
 }
 
+// Start a new token family for a device, replacing any earlier session on it
+async function createRefreshToken(
+  userId: string,
+  deviceId: string,
+  deviceName: string | null,
+  tokenVersion: number
+): Promise<string> {
+  await pool.query(
+    'DELETE FROM refresh_tokens WHERE user_id = $1 AND device_id = $2',
+    [userId, deviceId]
+  );
+
+  return issueRefreshToken(pool, userId, deviceId, deviceName, tokenVersion, uuidv4());
+}
+
 function sanitizeUser(user: any) {
   return {
     id: user.id,
@@ -159,7 +179,7 @@ router.post('/register', registerLimiter, async (req, res) => {
     const deviceId = uuidv4();
 
     // Create tokens
-    const accessToken = createAccessToken(user);
+    const accessToken = createAccessToken(user, deviceId);
     const refreshToken = await createRefreshToken(
       user.id,
       deviceId,
@@ -177,6 +197,7 @@ router.post('/register', registerLimiter, async (req, res) => {
       user: sanitizeUser(user),
       accessToken,
       refreshToken,
+      deviceId,
       expiresIn: config.jwtAccessExpirySecs,
     });
   } catch (err) {
@@ -200,9 +221,9 @@ router.post('/register', registerLimiter, async (req, res) => {
 });
 
 // POST /auth/login
-// Rate limited: 5 attempts per 15 minutes per IP
+// Rate limited: 5 attempts per 15 minutes per IP, 10 per account
 // Brute force protection: progressive blocking after repeated failures
-router.post('/login', bruteForceProtection, loginLimiter, async (req, res) => {
+router.post('/login', bruteForceProtection, loginLimiter, loginAccountLimiter, async (req, res) => {
   try {
     const body = loginSchema.parse(req.body);
 
@@ -239,7 +260,7 @@ router.post('/login', bruteForceProtection, loginLimiter, async (req, res) => {
     resetFailedAttempts(req);
 
     // Create tokens
-    const accessToken = createAccessToken(user);
+    const accessToken = createAccessToken(user, body.deviceId);
     const refreshToken = await createRefreshToken(
       user.id,
       body.deviceId,
@@ -257,6 +278,7 @@ router.post('/login', bruteForceProtection, loginLimiter, async (req, res) => {
       user: sanitizeUser(user),
       accessToken,
       refreshToken,
+      deviceId: body.deviceId,
       expiresIn: config.jwtAccessExpirySecs,
     });
   } catch (err) {
@@ -280,22 +302,28 @@ router.post('/login', bruteForceProtection, loginLimiter, async (req, res) => {
 });
 
 // POST /auth/refresh
-// Rate limited: 30 refreshes per hour per IP
-router.post('/refresh', refreshLimiter, async (req, res) => {
+// Rate limited: 30 refreshes per hour per IP and per device
+// Reuse detection: replaying an already-rotated token revokes every session
+router.post('/refresh', refreshLimiter, refreshAccountLimiter, async (req, res) => {
+  const client = await pool.connect();
   try {
     const body = refreshSchema.parse(req.body);
     const tokenHash = crypto.createHash('sha256').update(body.refreshToken).digest('hex');
 
-    // Find and validate refresh token
-    const tokenResult = await pool.query(
+    await client.query('BEGIN');
+
+    // Find the token, locking it so concurrent refreshes can't both rotate it
+    const tokenResult = await client.query(
       `SELECT rt.*, u.token_version as user_token_version
        FROM refresh_tokens rt
        JOIN users u ON u.id = rt.user_id
-       WHERE rt.token_hash = $1 AND rt.device_id = $2 AND rt.expires_at > NOW()`,
+       WHERE rt.token_hash = $1 AND rt.device_id = $2
+       FOR UPDATE OF rt`,
       [tokenHash, body.deviceId]
     );
 
     if (tokenResult.rows.length === 0) {
+      await client.query('ROLLBACK');
       return res.status(401).json({
         error: {
           code: 'INVALID_REFRESH_TOKEN',
@@ -306,30 +334,66 @@ router.post('/refresh', refreshLimiter, async (req, res) => {
 
     const storedToken = tokenResult.rows[0];
 
-    // Check token version matches (for global logout)
-    if (storedToken.token_version !== storedToken.user_token_version) {
-      return res.status(401).json({
-        error: {
-          code: 'TOKEN_REVOKED',
-          message: 'This token has been revoked',
-        },
-      });
+    switch (checkRefreshToken(storedToken)) {
+      case 'reused':
+        // Token was stolen: bump the token version to invalidate everything
+        await client.query(
+          'UPDATE users SET token_version = token_version + 1 WHERE id = $1',
+          [storedToken.user_id]
+        );
+        await client.query('DELETE FROM refresh_tokens WHERE family_id = $1', [storedToken.family_id]);
+        await client.query(
+          `INSERT INTO activity_log (user_id, activity_type, metadata) VALUES ($1, 'refresh_token_reuse', $2)`,
+          [storedToken.user_id, JSON.stringify({ device_id: body.deviceId, family_id: storedToken.family_id, ip: req.ip })]
+        );
+        await client.query('COMMIT');
+        return res.status(401).json({
+          error: {
+            code: 'TOKEN_REUSED',
+            message: 'This token was already used. All sessions have been signed out',
+          },
+        });
+      case 'revoked':
+        // Token version doesn't match (global logout)
+        await client.query('ROLLBACK');
+        return res.status(401).json({
+          error: {
+            code: 'TOKEN_REVOKED',
+            message: 'This token has been revoked',
+          },
+        });
+      case 'expired':
+        await client.query('ROLLBACK');
+        return res.status(401).json({
+          error: {
+            code: 'INVALID_REFRESH_TOKEN',
+            message: 'Invalid or expired refresh token',
+          },
+        });
     }
 
     // Get user
-    const userResult = await pool.query('SELECT * FROM users WHERE id = $1', [storedToken.user_id]);
+    const userResult = await client.query('SELECT * FROM users WHERE id = $1', [storedToken.user_id]);
     const user = userResult.rows[0];
 
-    // Rotate refresh token (security best practice)
-    const newRefreshToken = await createRefreshToken(
+    // Rotate refresh token: keep the old one as consumed to catch reuse
+    await client.query(
+      'UPDATE refresh_tokens SET consumed_at = NOW(), last_used_at = NOW() WHERE id = $1',
+      [storedToken.id]
+    );
+    const newRefreshToken = await issueRefreshToken(
+      client,
       user.id,
       body.deviceId,
       storedToken.device_name,
-      user.token_version
+      user.token_version,
+      storedToken.family_id
     );
 
+    await client.query('COMMIT');
+
     // Create new access token
-    const accessToken = createAccessToken(user);
+    const accessToken = createAccessToken(user, body.deviceId);
 
     res.json({
       user: sanitizeUser(user),
@@ -338,6 +402,7 @@ router.post('/refresh', refreshLimiter, async (req, res) => {
       expiresIn: config.jwtAccessExpirySecs,
     });
   } catch (err) {
+    await client.query('ROLLBACK').catch(() => {});
     if (err instanceof z.ZodError) {
       return res.status(400).json({
         error: {
@@ -354,6 +419,8 @@ router.post('/refresh', refreshLimiter, async (req, res) => {
         message: 'An error occurred during token refresh',
       },
     });
+  } finally {
+    client.release();
   }
 });
 
@@ -435,13 +502,13 @@ router.get('/me', authMiddleware, async (req: AuthRequest, res: Response) => {
   }
 });
 
-// GET /auth/sessions - List active sessions
-router.get('/sessions', authMiddleware, async (req: AuthRequest, res: Response) => {
+// GET /auth/sessions (or /auth/devices) - List active sessions, one per device
+router.get(['/sessions', '/devices'], authMiddleware, async (req: AuthRequest, res: Response) => {
   try {
     const result = await pool.query(
-      `SELECT device_id, device_name, created_at, last_used_at
+      `SELECT device_id, device_name, family_id, created_at, last_used_at
        FROM refresh_tokens
-       WHERE user_id = $1 AND expires_at > NOW()
+       WHERE user_id = $1 AND expires_at > NOW() AND consumed_at IS NULL
        ORDER BY last_used_at DESC`,
       [req.user!.id]
     );
@@ -450,6 +517,8 @@ router.get('/sessions', authMiddleware, async (req: AuthRequest, res: Response)
       sessions: result.rows.map(row => ({
         deviceId: row.device_id,
         deviceName: row.device_name,
+        familyId: row.family_id,
+        current: row.device_id === req.user!.deviceId,
         createdAt: row.created_at,
         lastUsedAt: row.last_used_at,
       })),
@@ -465,8 +534,9 @@ router.get('/sessions', authMiddleware, async (req: AuthRequest, res: Response)
   }
 });
 
-// DELETE /auth/sessions/:deviceId - Revoke specific session
-router.delete('/sessions/:deviceId', authMiddleware, async (req: AuthRequest, res: Response) => {
+// DELETE /auth/sessions/:deviceId (or /auth/devices/:deviceId) - Revoke a device
+// Its refresh tokens are removed; access tokens bound to it lapse on expiry
+router.delete(['/sessions/:deviceId', '/devices/:deviceId'], authMiddleware, async (req: AuthRequest, res: Response) => {
   try {
     const { deviceId } = req.params;
 
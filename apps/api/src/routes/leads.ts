@@ -5,6 +5,18 @@ import { authMiddleware, AuthRequest } from '../middleware/auth.js';
 
 const router = Router();
 
+// Full-text document for lead search (matches idx_leads_fts)
+const LEAD_SEARCH_VECTOR = `to_tsvector('english',
+  COALESCE(company_name, '') || ' ' || COALESCE(company_domain, '') || ' ' ||
+  COALESCE(contact_name, '') || ' ' || COALESCE(contact_email, '') || ' ' ||
+  COALESCE(industry, ''))`;
+
+// Relevance: full-text rank plus the best fuzzy match on the main fields
+const LEAD_SEARCH_RANK = `ts_rank(${LEAD_SEARCH_VECTOR}, plainto_tsquery('english', $4)) + GREATEST(
+  similarity(company_name, $4),
+  similarity(COALESCE(contact_name, ''), $4),
+  similarity(COALESCE(contact_email, ''), $4))`;
+
 // Validation schemas
 const createLeadSchema = z.object({
   companyName: z.string().min(1),
@@ -27,7 +39,13 @@ const createLeadSchema = z.object({
   nextFollowupAt: z.string().datetime().optional(),
 });
 
-const updateLeadSchema = createLeadSchema.partial();
+const updateLeadSchema = createLeadSchema.partial().extend({
+  // Latest sync version the client has seen; rejects the update if stale
+  expectedVersion: z.number().int().min(0).optional(),
+  // Added after the existing notes (e.g. a call summary from the desktop)
+  appendNotes: z.string().max(20000).optional(),
+  lastContactedAt: z.string().datetime().optional(),
+});
 
 const listQuerySchema = z.object({
   page: z.coerce.number().min(1).default(1),
@@ -45,17 +63,22 @@ router.get('/', authMiddleware, async (req: AuthRequest, res: Response) => {
     const query = listQuerySchema.parse(req.query);
     const offset = (query.page - 1) * query.perPage;
 
-    // Get leads with filtering
+    // Get leads with filtering, most relevant first when searching
     const leadsResult = await pool.query(
-      `SELECT * FROM leads
+      `SELECT *,
+         CASE WHEN $4::text IS NULL THEN NULL ELSE ${LEAD_SEARCH_RANK} END as search_rank
+       FROM leads
        WHERE user_id = $1
          AND ($2::text IS NULL OR status = $2)
          AND ($3::int IS NULL OR priority >= $3)
          AND ($4::text IS NULL OR
+              ${LEAD_SEARCH_VECTOR} @@ plainto_tsquery('english', $4) OR
+              company_name % $4 OR contact_name % $4 OR contact_email % $4 OR
               company_name ILIKE '%' || $4 || '%' OR
               contact_name ILIKE '%' || $4 || '%' OR
               contact_email ILIKE '%' || $4 || '%')
        ORDER BY
+         search_rank DESC NULLS LAST,
          CASE WHEN $5 = 'created_at' AND $6 = 'desc' THEN created_at END DESC,
          CASE WHEN $5 = 'created_at' AND $6 = 'asc' THEN created_at END ASC,
          CASE WHEN $5 = 'priority' AND $6 = 'desc' THEN priority END DESC,
@@ -75,6 +98,8 @@ router.get('/', authMiddleware, async (req: AuthRequest, res: Response) => {
          AND ($2::text IS NULL OR status = $2)
          AND ($3::int IS NULL OR priority >= $3)
          AND ($4::text IS NULL OR
+              ${LEAD_SEARCH_VECTOR} @@ plainto_tsquery('english', $4) OR
+              company_name % $4 OR contact_name % $4 OR contact_email % $4 OR
               company_name ILIKE '%' || $4 || '%' OR
               contact_name ILIKE '%' || $4 || '%' OR
               contact_email ILIKE '%' || $4 || '%')`,
@@ -85,7 +110,10 @@ router.get('/', authMiddleware, async (req: AuthRequest, res: Response) => {
     const totalPages = Math.ceil(total / query.perPage);
 
     res.json({
-      leads: leadsResult.rows.map(formatLead),
+      leads: leadsResult.rows.map(row => ({
+        ...formatLead(row),
+        rank: row.search_rank === null ? null : Number(row.search_rank),
+      })),
       total,
       page: query.page,
       perPage: query.perPage,
@@ -170,20 +198,41 @@ router.get('/:id', authMiddleware, async (req: AuthRequest, res: Response) => {
 
 // PUT /leads/:id
 router.put('/:id', authMiddleware, async (req: AuthRequest, res: Response) => {
+  const client = await pool.connect();
   try {
     const body = updateLeadSchema.parse(req.body);
 
-    // Check ownership
-    const existing = await pool.query(
-      'SELECT id FROM leads WHERE id = $1 AND user_id = $2',
+    await client.query('BEGIN');
+
+    // Check ownership, locking the row so concurrent updates are serialized
+    const existing = await client.query(
+      'SELECT * FROM leads WHERE id = $1 AND user_id = $2 FOR UPDATE',
       [req.params.id, req.user!.id]
     );
 
     if (existing.rows.length === 0) {
+      await client.query('ROLLBACK');
       return res.status(404).json({ error: 'Lead not found' });
     }
 
-    const result = await pool.query(
+    const currentResult = await client.query(
+      `SELECT COALESCE(MAX(version), 0) as version FROM sync_events WHERE entity_type = 'lead' AND entity_id = $1`,
+      [req.params.id]
+    );
+    const serverVersion = Number(currentResult.rows[0].version);
+
+    // Someone else updated the lead since the client last synced
+    if (body.expectedVersion !== undefined && body.expectedVersion !== serverVersion) {
+      await client.query('ROLLBACK');
+      return res.status(409).json({
+        error: 'Version conflict',
+        expectedVersion: body.expectedVersion,
+        serverVersion,
+        lead: formatLead(existing.rows[0]),
+      });
+    }
+
+    const result = await client.query(
       `UPDATE leads SET
         company_name = COALESCE($3, company_name),
         company_domain = COALESCE($4, company_domain),
@@ -200,8 +249,10 @@ router.put('/:id', authMiddleware, async (req: AuthRequest, res: Response) => {
         priority = COALESCE($15, priority),
         estimated_value = COALESCE($16, estimated_value),
         tags = COALESCE($17, tags),
-        notes = COALESCE($18, notes),
+        notes = CASE WHEN $20::text IS NULL THEN COALESCE($18, notes)
+          ELSE concat_ws(E'\n\n', NULLIF(COALESCE($18, notes), ''), $20) END,
         next_followup_at = COALESCE($19, next_followup_at),
+        last_contacted_at = COALESCE($21, last_contacted_at),
         updated_at = NOW()
        WHERE id = $1 AND user_id = $2
        RETURNING *`,
@@ -211,32 +262,35 @@ router.put('/:id', authMiddleware, async (req: AuthRequest, res: Response) => {
         body.companySize, body.industry, body.location,
         body.contactName, body.contactTitle, body.contactEmail, body.contactPhone, body.contactLinkedin,
         body.status, body.priority, body.estimatedValue, body.tags, body.notes,
-        body.nextFollowupAt ? new Date(body.nextFollowupAt) : null
+        body.nextFollowupAt ? new Date(body.nextFollowupAt) : null,
+        body.appendNotes ?? null,
+        body.lastContactedAt ? new Date(body.lastContactedAt) : null
       ]
     );
 
     const lead = result.rows[0];
 
     // Create sync event
-    const versionResult = await pool.query(
-      `SELECT COALESCE(MAX(version), 0) + 1 as version FROM sync_events WHERE entity_type = 'lead' AND entity_id = $1`,
-      [lead.id]
-    );
-    const version = versionResult.rows[0].version;
+    const version = serverVersion + 1;
 
-    await pool.query(
+    await client.query(
       `INSERT INTO sync_events (user_id, entity_type, entity_id, event_type, payload, version)
        VALUES ($1, 'lead', $2, 'updated', $3, $4)`,
       [req.user!.id, lead.id, JSON.stringify(lead), version]
     );
 
-    res.json(formatLead(lead));
+    await client.query('COMMIT');
+
+    res.json({ ...formatLead(lead), version });
   } catch (err) {
+    await client.query('ROLLBACK').catch(() => {});
     if (err instanceof z.ZodError) {
       return res.status(400).json({ error: 'Validation error', details: err.errors });
     }
     console.error('Update lead error:', err);
     res.status(500).json({ error: 'Internal server error' });
+  } finally {
+    client.release();
   }
 });
 
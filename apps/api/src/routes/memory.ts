@@ -0,0 +1,40 @@
+import { Router, Response } from 'express';
+import { z } from 'zod';
+import { authMiddleware, AuthRequest } from '../middleware/auth.js';
+import { hybridSearch } from '../services/rag.js';
+
+const router = Router();
+
+// Validation schemas
+const searchQuerySchema = z.object({
+  q: z.string().min(1).max(1000),
+  limit: z.coerce.number().min(1).max(20).default(5),
+  vectorWeight: z.coerce.number().min(0).max(10).optional(),
+  textWeight: z.coerce.number().min(0).max(10).optional(),
+  k: z.coerce.number().min(1).max(1000).optional(),
+});
+
+// GET /memory/search
+router.get('/search', authMiddleware, async (req: AuthRequest, res: Response) => {
+  try {
+    const query = searchQuerySchema.parse(req.query);
+    const results = await hybridSearch(req.user!.id, query.q, query.limit, {
+      vectorWeight: query.vectorWeight,
+      textWeight: query.textWeight,
+      k: query.k,
+    });
+
+    res.json({
+      query: query.q,
+      results,
+    });
+  } catch (err) {
+    if (err instanceof z.ZodError) {
+      return res.status(400).json({ error: 'Validation error', details: err.errors });
+    }
+    console.error('Memory search error:', err);
+    res.status(500).json({ error: 'Internal server error' });
+  }
+});
+
+export default router;
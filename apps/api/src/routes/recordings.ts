@@ -2,6 +2,8 @@ import { Router, Response } from 'express';
 import { z } from 'zod';
 import { pool } from '../db.js';
 import { authMiddleware, AuthRequest } from '../middleware/auth.js';
+import { indexRecording } from '../services/rag.js';
+import { generateCallSummary } from '../services/callSummary.js';
 
 const router = Router();
 
@@ -23,12 +25,46 @@ const uploadRecordingSchema = z.object({
   userWpm: z.number().optional(),
 });
 
+// Serialized desktop RecordingSession (extra fields are kept as-is)
+const recordingSessionSchema = z.object({
+  id: z.string().min(1).max(64),
+  start_time: z.string().datetime(),
+  end_time: z.string().datetime().nullable().optional(),
+  mode: z.string().min(1).max(50),
+  turns: z.array(z.object({
+    timestamp: z.string().datetime(),
+    speaker: z.string(),
+    text: z.string(),
+    duration_ms: z.number().nonnegative(),
+  }).passthrough()).max(10000),
+  suggestions: z.array(z.any()).max(10000).default([]),
+  metadata: z.object({
+    user_word_count: z.number().default(0),
+    other_word_count: z.number().default(0),
+    user_talk_time_ms: z.number().default(0),
+    other_talk_time_ms: z.number().default(0),
+  }).passthrough().default({}),
+}).passthrough();
+
+const uploadSessionSchema = z.object({
+  leadId: z.string().uuid().optional(),
+  session: recordingSessionSchema,
+  // Serialized CallSummary
+  summary: z.object({
+    executive_summary: z.string().optional(),
+    score: z.object({ overall: z.number().min(0).max(100) }).passthrough().optional(),
+    next_steps: z.array(z.string()).optional(),
+    did_well: z.array(z.string()).optional(),
+  }).passthrough().optional(),
+});
+
 const listQuerySchema = z.object({
   page: z.coerce.number().min(1).default(1),
   perPage: z.coerce.number().min(1).max(100).default(20),
   leadId: z.string().uuid().optional(),
   mode: z.string().optional(),
   status: z.string().optional(),
+  minScore: z.coerce.number().min(0).max(100).optional(),
   fromDate: z.string().datetime().optional(),
   toDate: z.string().datetime().optional(),
 });
@@ -42,8 +78,8 @@ router.get('/', authMiddleware, async (req: AuthRequest, res: Response) => {
     const result = await pool.query(
       `SELECT
         r.id, r.lead_id, r.mode, r.status, r.start_time,
-        r.duration_seconds, r.summary, r.outcome, r.sentiment_score,
-        l.company_name as lead_name
+        r.duration_seconds, r.summary, r.outcome, r.sentiment_score, r.score,
+        r.created_at, l.company_name as lead_name
        FROM recordings r
        LEFT JOIN leads l ON l.id = r.lead_id
        WHERE r.user_id = $1
@@ -52,6 +88,7 @@ router.get('/', authMiddleware, async (req: AuthRequest, res: Response) => {
          AND ($4::text IS NULL OR r.status = $4)
          AND ($5::timestamptz IS NULL OR r.start_time >= $5)
          AND ($6::timestamptz IS NULL OR r.start_time <= $6)
+         AND ($9::int IS NULL OR r.score >= $9)
        ORDER BY r.start_time DESC
        LIMIT $7 OFFSET $8`,
       [
@@ -62,7 +99,8 @@ router.get('/', authMiddleware, async (req: AuthRequest, res: Response) => {
         query.fromDate ? new Date(query.fromDate) : null,
         query.toDate ? new Date(query.toDate) : null,
         query.perPage,
-        offset
+        offset,
+        query.minScore ?? null
       ]
     );
 
@@ -72,8 +110,9 @@ router.get('/', authMiddleware, async (req: AuthRequest, res: Response) => {
        WHERE user_id = $1
          AND ($2::uuid IS NULL OR lead_id = $2)
          AND ($3::text IS NULL OR mode = $3)
-         AND ($4::text IS NULL OR status = $4)`,
-      [req.user!.id, query.leadId || null, query.mode || null, query.status || null]
+         AND ($4::text IS NULL OR status = $4)
+         AND ($5::int IS NULL OR score >= $5)`,
+      [req.user!.id, query.leadId || null, query.mode || null, query.status || null, query.minScore ?? null]
     );
 
     const total = parseInt(countResult.rows[0].count, 10);
@@ -90,6 +129,8 @@ router.get('/', authMiddleware, async (req: AuthRequest, res: Response) => {
         summary: row.summary,
         outcome: row.outcome,
         sentimentScore: row.sentiment_score,
+        score: row.score,
+        createdAt: row.created_at,
       })),
       total,
       page: query.page,
@@ -105,7 +146,12 @@ router.get('/', authMiddleware, async (req: AuthRequest, res: Response) => {
 });
 
 // POST /recordings
+// Starts a live recording, or with `session` stores a finished desktop session
 router.post('/', authMiddleware, async (req: AuthRequest, res: Response) => {
+  if (req.body && req.body.session) {
+    return uploadSession(req, res);
+  }
+
   try {
     const body = createRecordingSchema.parse(req.body);
 
@@ -135,6 +181,82 @@ router.post('/', authMiddleware, async (req: AuthRequest, res: Response) => {
   }
 });
 
+// Store a finished desktop RecordingSession; re-uploading the same session updates it
+async function uploadSession(req: AuthRequest, res: Response) {
+  try {
+    const { leadId, session, summary } = uploadSessionSchema.parse(req.body);
+    const startTime = new Date(session.start_time);
+    const endTime = session.end_time ? new Date(session.end_time) : null;
+    const durationSeconds = endTime ? Math.round((endTime.getTime() - startTime.getTime()) / 1000) : null;
+
+    const meta = session.metadata;
+    const totalTalkMs = meta.user_talk_time_ms + meta.other_talk_time_ms;
+    const talkRatio = totalTalkMs > 0 ? meta.user_talk_time_ms / totalTalkMs : null;
+    const userWpm = meta.user_talk_time_ms > 0 ? meta.user_word_count / (meta.user_talk_time_ms / 60000) : null;
+
+    const transcriptTurns = session.turns.map(turn => ({
+      speaker: turn.speaker,
+      text: turn.text,
+      timestamp_ms: new Date(turn.timestamp).getTime() - startTime.getTime(),
+      duration_ms: turn.duration_ms,
+    }));
+    const questionCount = session.turns.filter(t => t.speaker === 'Other' && t.text.trim().endsWith('?')).length;
+
+    const result = await pool.query(
+      `INSERT INTO recordings (
+        user_id, lead_id, client_session_id, mode, status, start_time, end_time, duration_seconds,
+        transcript_turns, session, call_summary, summary, key_points, action_items, score,
+        talk_ratio, user_word_count, other_word_count, user_wpm, question_count
+      ) VALUES ($1, $2, $3, $4, 'completed', $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19)
+      ON CONFLICT (user_id, client_session_id) WHERE client_session_id IS NOT NULL
+      DO UPDATE SET
+        lead_id = COALESCE(EXCLUDED.lead_id, recordings.lead_id),
+        end_time = EXCLUDED.end_time,
+        duration_seconds = EXCLUDED.duration_seconds,
+        transcript_turns = EXCLUDED.transcript_turns,
+        session = EXCLUDED.session,
+        call_summary = COALESCE(EXCLUDED.call_summary, recordings.call_summary),
+        summary = COALESCE(EXCLUDED.summary, recordings.summary),
+        key_points = EXCLUDED.key_points,
+        action_items = EXCLUDED.action_items,
+        score = COALESCE(EXCLUDED.score, recordings.score),
+        talk_ratio = EXCLUDED.talk_ratio,
+        user_word_count = EXCLUDED.user_word_count,
+        other_word_count = EXCLUDED.other_word_count,
+        user_wpm = EXCLUDED.user_wpm,
+        question_count = EXCLUDED.question_count
+      RETURNING *, (xmax = 0) as inserted`,
+      [
+        req.user!.id, leadId || null, session.id, session.mode, startTime, endTime, durationSeconds,
+        JSON.stringify(transcriptTurns), JSON.stringify(session), summary ? JSON.stringify(summary) : null,
+        summary?.executive_summary || null,
+        JSON.stringify(summary?.did_well || []),
+        JSON.stringify(summary?.next_steps || []),
+        summary?.score ? Math.round(summary.score.overall) : null,
+        talkRatio, meta.user_word_count, meta.other_word_count, userWpm, questionCount,
+      ]
+    );
+
+    const recording = result.rows[0];
+
+    if (recording.inserted) {
+      await pool.query(
+        `INSERT INTO activity_log (user_id, activity_type, entity_type, entity_id, metadata)
+         VALUES ($1, 'call_synced', 'recording', $2, $3)`,
+        [req.user!.id, recording.id, JSON.stringify({ mode: session.mode, duration_seconds: durationSeconds })]
+      );
+    }
+
+    res.status(recording.inserted ? 201 : 200).json(formatRecording(recording));
+  } catch (err) {
+    if (err instanceof z.ZodError) {
+      return res.status(400).json({ error: 'Validation error', details: err.errors });
+    }
+    console.error('Upload session error:', err);
+    res.status(500).json({ error: 'Internal server error' });
+  }
+}
+
 // GET /recordings/:id
 router.get('/:id', authMiddleware, async (req: AuthRequest, res: Response) => {
   try {
@@ -213,6 +335,81 @@ router.post('/:id/upload', authMiddleware, async (req: AuthRequest, res: Respons
   }
 });
 
+// POST /recordings/:id/index
+router.post('/:id/index', authMiddleware, async (req: AuthRequest, res: Response) => {
+  try {
+    const result = await pool.query(
+      'SELECT id, lead_id, mode, start_time, transcript_turns FROM recordings WHERE id = $1 AND user_id = $2',
+      [req.params.id, req.user!.id]
+    );
+
+    if (result.rows.length === 0) {
+      return res.status(404).json({ error: 'Recording not found' });
+    }
+
+    const recording = result.rows[0];
+    if (!Array.isArray(recording.transcript_turns) || recording.transcript_turns.length === 0) {
+      return res.status(400).json({ error: 'Recording has no transcript' });
+    }
+
+    const indexed = await indexRecording(req.user!.id, recording);
+
+    res.json({
+      recordingId: recording.id,
+      documentId: indexed.documentId,
+      chunkCount: indexed.chunkCount,
+    });
+  } catch (err) {
+    console.error('Index recording error:', err);
+    res.status(500).json({ error: 'Internal server error' });
+  }
+});
+
+// POST /recordings/:id/summary
+// Generates the CallSummary for an uploaded desktop session with the server's key
+router.post('/:id/summary', authMiddleware, async (req: AuthRequest, res: Response) => {
+  try {
+    const result = await pool.query(
+      'SELECT id, session FROM recordings WHERE id = $1 AND user_id = $2',
+      [req.params.id, req.user!.id]
+    );
+
+    if (result.rows.length === 0) {
+      return res.status(404).json({ error: 'Recording not found' });
+    }
+
+    const session = result.rows[0].session;
+    if (!session || !Array.isArray(session.turns) || session.turns.length === 0) {
+      return res.status(400).json({ error: 'Recording has no session transcript' });
+    }
+
+    const summary = await generateCallSummary(session);
+
+    await pool.query(
+      `UPDATE recordings SET
+        call_summary = $3,
+        summary = $4,
+        key_points = $5,
+        action_items = $6,
+        score = $7
+       WHERE id = $1 AND user_id = $2`,
+      [
+        req.params.id, req.user!.id,
+        JSON.stringify(summary),
+        summary.executive_summary,
+        JSON.stringify(summary.did_well),
+        JSON.stringify(summary.next_steps),
+        summary.score.overall,
+      ]
+    );
+
+    res.json(summary);
+  } catch (err) {
+    console.error('Generate summary error:', err);
+    res.status(500).json({ error: 'Internal server error' });
+  }
+});
+
 // GET /recordings/:id/presigned-url
 router.get('/:id/presigned-url', authMiddleware, async (req: AuthRequest, res: Response) => {
   try {
@@ -267,6 +464,10 @@ function formatRecording(row: any) {
     sentimentScore: row.sentiment_score,
     performanceScore: row.performance_score,
     outcome: row.outcome,
+    score: row.score,
+    clientSessionId: row.client_session_id,
+    session: row.session,
+    callSummary: row.call_summary,
     audioR2Key: row.audio_r2_key,
     transcriptR2Key: row.transcript_r2_key,
     createdAt: row.created_at,
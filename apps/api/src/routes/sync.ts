@@ -0,0 +1,72 @@
+import { Router, Response } from 'express';
+import { z } from 'zod';
+import { pool } from '../db.js';
+import { authMiddleware, AuthRequest } from '../middleware/auth.js';
+
+const router = Router();
+
+// Validation schemas
+const syncQuerySchema = z.object({
+  since: z.coerce.number().int().min(0).default(0),
+  entityType: z.enum(['lead', 'recording', 'document']).optional(),
+  entity_type: z.enum(['lead', 'recording', 'document']).optional(),
+  limit: z.coerce.number().int().min(1).max(500).default(100),
+});
+
+// GET /sync?since=<cursor>&entity_type=lead
+// Returns sync events after the cursor, oldest first. Each event carries the
+// entity's latest server version so clients can spot concurrent edits.
+router.get('/', authMiddleware, async (req: AuthRequest, res: Response) => {
+  try {
+    const query = syncQuerySchema.parse(req.query);
+    const entityType = query.entityType || query.entity_type || null;
+
+    // Fetch one extra row to know whether there's another page
+    const result = await pool.query(
+      `SELECT
+        e.id, e.entity_type, e.entity_id, e.event_type, e.payload, e.version, e.created_at,
+        (SELECT MAX(s.version) FROM sync_events s
+         WHERE s.entity_type = e.entity_type AND s.entity_id = e.entity_id) as server_version
+       FROM sync_events e
+       WHERE e.user_id = $1
+         AND e.id > $2
+         AND ($3::text IS NULL OR e.entity_type = $3)
+       ORDER BY e.id ASC
+       LIMIT $4`,
+      [req.user!.id, query.since, entityType, query.limit + 1]
+    );
+
+    const hasMore = result.rows.length > query.limit;
+    const rows = result.rows.slice(0, query.limit);
+    const cursor = rows.length > 0 ? Number(rows[rows.length - 1].id) : query.since;
+
+    res.json({
+      events: rows.map(row => {
+        const version = Number(row.version);
+        const serverVersion = Number(row.server_version);
+        return {
+          id: Number(row.id),
+          entityType: row.entity_type,
+          entityId: row.entity_id,
+          eventType: row.event_type,
+          payload: row.payload,
+          version,
+          serverVersion,
+          // A newer event for this entity exists (possibly on a later page)
+          superseded: version < serverVersion,
+          createdAt: row.created_at,
+        };
+      }),
+      cursor,
+      hasMore,
+    });
+  } catch (err) {
+    if (err instanceof z.ZodError) {
+      return res.status(400).json({ error: 'Validation error', details: err.errors });
+    }
+    console.error('Sync error:', err);
+    res.status(500).json({ error: 'Internal server error' });
+  }
+});
+
+export default router;
@@ -0,0 +1,116 @@
+import { Router, Response } from 'express';
+import { z } from 'zod';
+import { pool } from '../db.js';
+import { authMiddleware, AuthRequest } from '../middleware/auth.js';
+
+const router = Router();
+
+// Validation schemas
+const createTasksSchema = z.object({
+  leadId: z.string().uuid().optional(),
+  // RecordingSession.id from the desktop app
+  sessionId: z.string().min(1).max(64).optional(),
+  tasks: z.array(z.object({
+    title: z.string().min(1).max(2000),
+    owner: z.string().max(200).optional(),
+    dueDate: z.string().regex(/^\d{4}-\d{2}-\d{2}$/).optional(),
+  })).min(1).max(100),
+});
+
+const listQuerySchema = z.object({
+  page: z.coerce.number().min(1).default(1),
+  perPage: z.coerce.number().min(1).max(100).default(20),
+  // true for tasks not yet done, false for done ones
+  open: z.enum(['true', 'false']).transform(value => value === 'true').optional(),
+  leadId: z.string().uuid().optional(),
+});
+
+// GET /tasks
+router.get('/', authMiddleware, async (req: AuthRequest, res: Response) => {
+  try {
+    const query = listQuerySchema.parse(req.query);
+    const offset = (query.page - 1) * query.perPage;
+
+    const result = await pool.query(
+      `SELECT *, COUNT(*) OVER() as total_count FROM tasks
+       WHERE user_id = $1
+         AND ($2::boolean IS NULL OR (completed_at IS NULL) = $2)
+         AND ($3::uuid IS NULL OR lead_id = $3)
+       ORDER BY due_date ASC NULLS LAST, created_at DESC
+       LIMIT $4 OFFSET $5`,
+      [req.user!.id, query.open ?? null, query.leadId || null, query.perPage, offset]
+    );
+
+    const total = result.rows.length > 0 ? parseInt(result.rows[0].total_count, 10) : 0;
+
+    res.json({
+      tasks: result.rows.map(formatTask),
+      total,
+      page: query.page,
+      perPage: query.perPage,
+      totalPages: Math.ceil(total / query.perPage),
+    });
+  } catch (err) {
+    if (err instanceof z.ZodError) {
+      return res.status(400).json({ error: 'Validation error', details: err.errors });
+    }
+    console.error('List tasks error:', err);
+    res.status(500).json({ error: 'Internal server error' });
+  }
+});
+
+// POST /tasks
+router.post('/', authMiddleware, async (req: AuthRequest, res: Response) => {
+  try {
+    const body = createTasksSchema.parse(req.body);
+
+    const result = await pool.query(
+      `INSERT INTO tasks (user_id, lead_id, client_session_id, title, owner, due_date)
+       SELECT $1, $2, $3, t.title, t.owner, t.due_date
+       FROM jsonb_to_recordset($4::jsonb) AS t(title text, owner text, due_date date)
+       RETURNING *`,
+      [
+        req.user!.id, body.leadId || null, body.sessionId || null,
+        JSON.stringify(body.tasks.map(task => ({
+          title: task.title,
+          owner: task.owner || null,
+          due_date: task.dueDate || null,
+        }))),
+      ]
+    );
+
+    // Log activity
+    await pool.query(
+      `INSERT INTO activity_log (user_id, activity_type, entity_type, entity_id, metadata)
+       VALUES ($1, 'tasks_created', 'task', $2, $3)`,
+      [req.user!.id, result.rows[0].id, JSON.stringify({
+        count: result.rows.length,
+        lead_id: body.leadId,
+        session_id: body.sessionId,
+      })]
+    );
+
+    res.status(201).json({ tasks: result.rows.map(formatTask) });
+  } catch (err) {
+    if (err instanceof z.ZodError) {
+      return res.status(400).json({ error: 'Validation error', details: err.errors });
+    }
+    console.error('Create tasks error:', err);
+    res.status(500).json({ error: 'Internal server error' });
+  }
+});
+
+function formatTask(row: any) {
+  return {
+    id: row.id,
+    leadId: row.lead_id,
+    sessionId: row.client_session_id,
+    title: row.title,
+    owner: row.owner,
+    dueDate: row.due_date,
+    completedAt: row.completed_at,
+    createdAt: row.created_at,
+  };
+}
+
+export default router;
@@ -0,0 +1,82 @@
+/**
+ * Call Summary tests - run with `npm test`
+ * Expected values mirror the desktop PerformanceScore and parse_summary
+ */
+
+import { test } from 'node:test';
+import assert from 'node:assert/strict';
+import { buildSummaryPrompt, calculatePerformanceScore, parseSummary, SessionInput } from './callSummary.js';
+
+const SESSION: SessionInput = {
+  id: 'session-1',
+  start_time: '2026-10-01T10:00:00Z',
+  end_time: '2026-10-01T10:12:30Z',
+  mode: 'sales',
+  turns: [
+    { timestamp: '2026-10-01T10:01:00Z', speaker: 'Other', text: 'Our budget resets in Q3, so timing is tight.', duration_ms: 3000 },
+    { timestamp: '2026-10-01T10:01:05Z', speaker: 'User', text: 'Understood. Can we start a pilot now?', duration_ms: 2000 },
+  ],
+  metadata: { user_talk_time_ms: 2000, other_talk_time_ms: 3000, total_suggestions: 4, suggestions_used: 1 },
+};
+
+test('performance score averages and grades like the desktop', () => {
+  assert.deepEqual(calculatePerformanceScore(90, 85, 80, 75, 70), {
+    overall: 80,
+    listening: 90,
+    response_quality: 85,
+    delivery: 80,
+    suggestion_usage: 75,
+    outcome: 70,
+    grade: 'B',
+    assessment: 'Great job! Minor improvements possible.',
+  });
+  // Integer division rounds down: 449 / 5 -> 89
+  assert.equal(calculatePerformanceScore(90, 90, 90, 90, 89).grade, 'B');
+  assert.equal(calculatePerformanceScore(100, 100, 100, 100, 100).grade, 'A');
+  assert.equal(calculatePerformanceScore(60, 60, 60, 60, 60).grade, 'D');
+  assert.equal(calculatePerformanceScore(0, 0, 0, 0, 0).grade, 'F');
+});
+
+test('prompt includes transcript and session info', () => {
+  const prompt = buildSummaryPrompt(SESSION);
+  assert.ok(prompt.includes('Them: Our budget resets in Q3, so timing is tight.\n\nYou: Understood.'));
+  assert.ok(prompt.includes('- Duration: 12 minutes'));
+  assert.ok(prompt.includes('- User talk time: 40%'));
+  assert.ok(prompt.includes('- Suggestions used: 1'));
+});
+
+test('missing fields fall back to desktop defaults', () => {
+  const summary = parseSummary(SESSION, {});
+  assert.equal(summary.session_id, 'session-1');
+  assert.equal(summary.score.overall, 70);
+  assert.equal(summary.delivery_analysis.pace, 'Perfect');
+  assert.equal(summary.delivery_analysis.clarity, 'Clear');
+  assert.equal(summary.outcome.goal_achieved, 'TooEarlyToTell');
+  assert.equal(summary.outcome.success_proximity, 50);
+  assert.equal(summary.outcome.difference_maker, null);
+  assert.deepEqual(summary.key_moments, []);
+  assert.equal(summary.executive_summary, 'Summary not available');
+});
+
+test('analysis maps to CallSummary enums and matched timestamps', () => {
+  const summary = parseSummary(SESSION, {
+    delivery: { pace: 'slightly_fast', naturalness: 65 },
+    outcome: { status: 'partial', proximity: 60, difference_maker: 'Offer a Q3 start date' },
+    key_moments: [
+      { quote: 'budget resets in Q3', significance: 'Timing objection', sentiment: 'critical' },
+      { quote: 'never said', significance: 'Invented', sentiment: 'positive', ideal_response: 'n/a' },
+    ],
+    scores: { listening: 80, response_quality: 80, delivery: 80, suggestion_usage: 80, outcome: 80 },
+    next_steps: ['Send pilot proposal', 42],
+  });
+
+  assert.equal(summary.score.grade, 'B');
+  assert.equal(summary.delivery_analysis.pace, 'SlightlyFast');
+  assert.equal(summary.delivery_analysis.naturalness, 65);
+  assert.equal(summary.outcome.goal_achieved, 'PartiallyAchieved');
+  assert.equal(summary.key_moments[0].sentiment, 'Critical');
+  assert.equal(summary.key_moments[0].timestamp, '2026-10-01T10:01:00Z');
+  assert.equal(summary.key_moments[0].ideal_response, null);
+  assert.equal(summary.key_moments[1].timestamp, null);
+  assert.deepEqual(summary.next_steps, ['Send pilot proposal']);
+});
@@ -0,0 +1,375 @@
+/**
+ * Call Summary Service
+ * Runs the desktop's post-call analysis on the server, so the desktop does
+ * not need its own provider key. Output matches the desktop CallSummary.
+ */
+
+import { config } from '../config.js';
+
+// ============================================================================
+// TYPES
+// ============================================================================
+
+export const SUMMARY_MODEL = 'gpt-4o-mini';
+
+// Shared with the desktop summary providers
+export const SUMMARY_SYSTEM_PROMPT = 'You are an expert conversation analyst and coach. Analyze conversations and provide actionable, honest feedback.';
+
+// Serialized desktop RecordingSession (only the fields the analysis uses)
+export interface SessionInput {
+  id: string;
+  start_time: string;
+  end_time?: string | null;
+  mode: string;
+  turns: Array<{ timestamp: string; speaker: string; text: string; duration_ms: number }>;
+  metadata: {
+    user_talk_time_ms?: number;
+    other_talk_time_ms?: number;
+    total_suggestions?: number;
+    suggestions_used?: number;
+  };
+}
+
+export interface PerformanceScore {
+  overall: number;
+  listening: number;
+  response_quality: number;
+  delivery: number;
+  suggestion_usage: number;
+  outcome: number;
+  grade: string;
+  assessment: string;
+}
+
+export interface KeyMoment {
+  timestamp: string | null;
+  quote: string;
+  significance: string;
+  sentiment: 'Positive' | 'Neutral' | 'Negative' | 'Critical';
+  ideal_response: string | null;
+}
+
+export interface CallSummary {
+  session_id: string;
+  generated_at: string;
+  score: PerformanceScore;
+  caller_needs: string[];
+  what_you_did: string[];
+  did_well: string[];
+  could_improve: string[];
+  alternative_approaches: string[];
+  delivery_analysis: {
+    pace: 'TooFast' | 'SlightlyFast' | 'Perfect' | 'SlightlySlow' | 'TooSlow';
+    clarity: 'VeryUnclear' | 'Unclear' | 'Acceptable' | 'Clear' | 'VeryClear';
+    naturalness: number;
+    confidence: number;
+    personalization: string;
+    feedback: string[];
+  };
+  outcome: {
+    goal_achieved: 'Achieved' | 'PartiallyAchieved' | 'NotAchieved' | 'TooEarlyToTell';
+    likely_outcome: string;
+    success_proximity: number;
+    difference_maker: string | null;
+  };
+  key_moments: KeyMoment[];
+  next_steps: string[];
+  executive_summary: string;
+}
+
+// Share of quote words a turn must contain to count as a match
+const QUOTE_MATCH_THRESHOLD = 0.6;
+
+const SPEAKER_LABELS: Record<string, string> = {
+  User: 'You',
+  Other: 'Them',
+  System: 'System',
+};
+
+// ============================================================================
+// SCORING
+// ============================================================================
+
+// Same bands as the desktop PerformanceScore::calculate
+export function calculatePerformanceScore(
+  listening: number,
+  responseQuality: number,
+  delivery: number,
+  suggestionUsage: number,
+  outcome: number
+): PerformanceScore {
+  const overall = Math.floor((listening + responseQuality + delivery + suggestionUsage + outcome) / 5);
+
+  let grade: string;
+  let assessment: string;
+  if (overall >= 90) {
+    grade = 'A';
+    assessment = 'Excellent performance! You nailed it.';
+  } else if (overall >= 80) {
+    grade = 'B';
+    assessment = 'Great job! Minor improvements possible.';
+  } else if (overall >= 70) {
+    grade = 'C';
+    assessment = 'Good effort. Some areas need work.';
+  } else if (overall >= 60) {
+    grade = 'D';
+    assessment = 'Acceptable but significant room for improvement.';
+  } else {
+    grade = 'F';
+    assessment = 'Needs improvement. Review the suggestions.';
+  }
+
+  return {
+    overall,
+    listening,
+    response_quality: responseQuality,
+    delivery,
+    suggestion_usage: suggestionUsage,
+    outcome,
+    grade,
+    assessment,
+  };
+}
+
+// ============================================================================
+// PROMPT
+// ============================================================================
+
+// Share of talk time that was the user's (0.5 when nothing was timed)
+function talkRatio(session: SessionInput): number {
+  const user = session.metadata.user_talk_time_ms || 0;
+  const total = user + (session.metadata.other_talk_time_ms || 0);
+  return total === 0 ? 0.5 : user / total;
+}
+
+// Same prompt as the desktop build_summary_prompt
+export function buildSummaryPrompt(session: SessionInput): string {
+  const transcript = session.turns
+    .map(turn => `${SPEAKER_LABELS[turn.speaker] || turn.speaker}: ${turn.text}`)
+    .join('\n\n');
+  const end = session.end_time ? new Date(session.end_time) : new Date();
+  const durationMins = Math.floor((end.getTime() - new Date(session.start_time).getTime()) / 60000);
+  const ratio = talkRatio(session);
+  const mode = session.mode;
+
+  return `Analyze this ${mode} conversation and provide a comprehensive assessment.
+
+TRANSCRIPT:
+${transcript}
+
+SESSION INFO:
+- Duration: ${durationMins} minutes
+- Mode: ${mode}
+- User talk time: ${Math.floor(ratio * 100)}%
+- Other person talk time: ${Math.floor((1 - ratio) * 100)}%
+- AI suggestions provided: ${session.metadata.total_suggestions || 0}
+- Suggestions used: ${session.metadata.suggestions_used || 0}
+
+Provide analysis in the following JSON format:
+{
+    "caller_needs": ["what they needed/wanted"],
+    "what_you_did": ["actions you took"],
+    "did_well": ["things done well"],
+    "could_improve": ["areas for improvement"],
+    "alternative_approaches": ["what might have worked better"],
+    "delivery": {
+        "pace": "too_fast|slightly_fast|perfect|slightly_slow|too_slow",
+        "naturalness": 0-100,
+        "confidence": 0-100,
+        "feedback": ["specific delivery feedback"]
+    },
+    "outcome": {
+        "status": "achieved|partial|not_achieved|unknown",
+        "proximity": 0-100,
+        "difference_maker": "what would have made the difference"
+    },
+    "key_moments": [
+        {
+            "quote": "what was said",
+            "significance": "why it mattered",
+            "sentiment": "positive|neutral|negative|critical",
+            "ideal_response": "what you should have said (if different)"
+        }
+    ],
+    "scores": {
+        "listening": 0-100,
+        "response_quality": 0-100,
+        "delivery": 0-100,
+        "suggestion_usage": 0-100,
+        "outcome": 0-100
+    },
+    "next_steps": ["actionable follow-ups"],
+    "executive_summary": "one paragraph summary"
+}
+
+Be honest and constructive. Focus on actionable insights.`;
+}
+
+// ============================================================================
+// PARSING
+// ============================================================================
+
+// Non-negative integer, or the fallback when missing
+function score(value: unknown, fallback: number): number {
+  return typeof value === 'number' && value >= 0 ? Math.floor(value) : fallback;
+}
+
+function stringArray(value: unknown): string[] {
+  return Array.isArray(value) ? value.filter((v): v is string => typeof v === 'string') : [];
+}
+
+function stringOrNull(value: unknown): string | null {
+  return typeof value === 'string' ? value : null;
+}
+
+// Lowercase and strip punctuation so quotes compare loosely
+function normalizeQuote(text: string): string {
+  return text
+    .toLowerCase()
+    .replace(/[^\p{L}\p{N}']/gu, ' ')
+    .split(/\s+/)
+    .filter(Boolean)
+    .join(' ');
+}
+
+// Index of the turn a key-moment quote came from: substring first, then word overlap
+export function findQuoteTurn(session: SessionInput, quote: string): number | null {
+  const normalized = normalizeQuote(quote);
+  if (!normalized) {
+    return null;
+  }
+
+  const exact = session.turns.findIndex(turn => normalizeQuote(turn.text).includes(normalized));
+  if (exact >= 0) {
+    return exact;
+  }
+
+  const quoteWords = normalized.split(' ');
+  let best: number | null = null;
+  let bestScore = QUOTE_MATCH_THRESHOLD;
+  session.turns.forEach((turn, index) => {
+    const words = new Set(normalizeQuote(turn.text).split(' '));
+    const hits = quoteWords.filter(w => words.has(w)).length / quoteWords.length;
+    // Earliest turn wins ties
+    if (hits > bestScore || (best === null && hits >= bestScore)) {
+      best = index;
+      bestScore = hits;
+    }
+  });
+  return best;
+}
+
+// Turn the model's JSON analysis into a CallSummary, same defaults as the desktop
+export function parseSummary(session: SessionInput, analysis: any): CallSummary {
+  const scores = analysis?.scores || {};
+  const delivery = analysis?.delivery || {};
+  const outcome = analysis?.outcome || {};
+
+  const pace = ({
+    too_fast: 'TooFast',
+    slightly_fast: 'SlightlyFast',
+    slightly_slow: 'SlightlySlow',
+    too_slow: 'TooSlow',
+  } as const)[delivery.pace as string] || 'Perfect';
+
+  const goal = ({
+    achieved: 'Achieved',
+    partial: 'PartiallyAchieved',
+    not_achieved: 'NotAchieved',
+  } as const)[outcome.status as string] || 'TooEarlyToTell';
+
+  const keyMoments: KeyMoment[] = Array.isArray(analysis?.key_moments)
+    ? analysis.key_moments.map((m: any) => {
+        const quote = typeof m?.quote === 'string' ? m.quote : '';
+        const index = findQuoteTurn(session, quote);
+        return {
+          timestamp: index === null ? null : session.turns[index].timestamp,
+          quote,
+          significance: typeof m?.significance === 'string' ? m.significance : '',
+          sentiment: ({
+            positive: 'Positive',
+            negative: 'Negative',
+            critical: 'Critical',
+          } as const)[m?.sentiment as string] || 'Neutral',
+          ideal_response: stringOrNull(m?.ideal_response),
+        };
+      })
+    : [];
+
+  return {
+    session_id: session.id,
+    generated_at: new Date().toISOString(),
+    score: calculatePerformanceScore(
+      score(scores.listening, 70),
+      score(scores.response_quality, 70),
+      score(scores.delivery, 70),
+      score(scores.suggestion_usage, 70),
+      score(scores.outcome, 70)
+    ),
+    caller_needs: stringArray(analysis?.caller_needs),
+    what_you_did: stringArray(analysis?.what_you_did),
+    did_well: stringArray(analysis?.did_well),
+    could_improve: stringArray(analysis?.could_improve),
+    alternative_approaches: stringArray(analysis?.alternative_approaches),
+    delivery_analysis: {
+      pace,
+      clarity: 'Clear',
+      naturalness: score(delivery.naturalness, 70),
+      confidence: score(delivery.confidence, 70),
+      personalization: 'Adapted suggestions to context',
+      feedback: stringArray(delivery.feedback),
+    },
+    outcome: {
+      goal_achieved: goal,
+      likely_outcome: 'Based on conversation trajectory',
+      success_proximity: score(outcome.proximity, 50),
+      difference_maker: stringOrNull(outcome.difference_maker),
+    },
+    key_moments: keyMoments,
+    next_steps: stringArray(analysis?.next_steps),
+    executive_summary: typeof analysis?.executive_summary === 'string'
+      ? analysis.executive_summary
+      : 'Summary not available',
+  };
+}
+
+// ============================================================================
+// GENERATION
+// ============================================================================
+
+// Analyze a session with the server's OpenAI key
+export async function generateCallSummary(session: SessionInput): Promise<CallSummary> {
+  if (!config.openaiApiKey) {
+    throw new Error('OpenAI API key not configured');
+  }
+
+  const response = await fetch('https://api.openai.com/v1/chat/completions', {
+    method: 'POST',
+    headers: {
+      'Content-Type': 'application/json',
+      'Authorization': `Bearer ${config.openaiApiKey}`,
+    },
+    body: JSON.stringify({
+      model: SUMMARY_MODEL,
+      messages: [
+        { role: 'system', content: SUMMARY_SYSTEM_PROMPT },
+        { role: 'user', content: buildSummaryPrompt(session) },
+      ],
+      temperature: 0.7,
+      response_format: { type: 'json_object' },
+    }),
+  });
+
+  if (!response.ok) {
+    const error = await response.text();
+    throw new Error(`OpenAI API error: ${error}`);
+  }
+
+  const data = await response.json() as { choices: Array<{ message: { content: string } }> };
+  return parseSummary(session, JSON.parse(data.choices[0].message.content));
+}
+
+export default {
+  calculatePerformanceScore,
+  generateCallSummary,
+};
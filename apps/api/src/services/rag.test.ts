@@ -0,0 +1,40 @@
+/**
+ * RAG Service tests - run with `npm test`
+ */
+
+import { test } from 'node:test';
+import assert from 'node:assert/strict';
+import { chunkDocument, reciprocalRankFusion, DEFAULT_RRF_K } from './rag.js';
+
+// Fixture: "a" is the best semantic match, "d" the best lexical one
+const VECTOR_RANKING = ['a', 'b', 'c', 'd'];
+const TEXT_RANKING = ['d', 'c', 'e'];
+
+function order(weights?: number[], k?: number): string[] {
+  return reciprocalRankFusion([VECTOR_RANKING, TEXT_RANKING], weights, k).map(r => r.id);
+}
+
+test('equal weights keep the default ranking', () => {
+  assert.deepEqual(order(), ['d', 'c', 'a', 'b', 'e']);
+  assert.deepEqual(order([1, 1], DEFAULT_RRF_K), order());
+});
+
+test('vector weight favors semantic matches', () => {
+  assert.deepEqual(order([2, 1]), ['c', 'd', 'a', 'b', 'e']);
+});
+
+test('text weight favors lexical matches', () => {
+  assert.deepEqual(order([1, 3]), ['d', 'c', 'e', 'a', 'b']);
+});
+
+test('zero weight ignores a ranking', () => {
+  assert.deepEqual(order([1, 0]).slice(0, 4), VECTOR_RANKING);
+});
+
+test('chunks overlap on line boundaries', () => {
+  const text = ['Them: one', 'Me: two', 'Them: three'].join('\n');
+  assert.deepEqual(chunkDocument(text, { maxChars: 20, overlapChars: 10 }), [
+    'Them: one\nMe: two',
+    'Me: two\nThem: three',
+  ]);
+});
@@ -0,0 +1,326 @@
+/**
+ * RAG Service - Conversation Memory
+ * Chunks call transcripts, embeds them with OpenAI and finds relevant
+ * snippets with pgvector + full-text search
+ */
+
+import { config } from '../config.js';
+import { pool } from '../db.js';
+
+// ============================================================================
+// TYPES
+// ============================================================================
+
+export const EMBEDDING_MODEL = 'text-embedding-3-small';
+
+export interface TranscriptTurn {
+  speaker?: string;
+  text: string;
+  timestamp_ms?: number;
+  duration_ms?: number;
+}
+
+export interface ChunkOptions {
+  maxChars?: number;
+  overlapChars?: number;
+}
+
+export interface MemorySnippet {
+  chunkId: string;
+  documentId: string;
+  recordingId: string | null;
+  title: string;
+  content: string;
+  score: number;
+  createdAt: string;
+}
+
+// How vector and full-text rankings are weighted when merged
+export interface FusionOptions {
+  vectorWeight?: number;
+  textWeight?: number;
+  k?: number;
+}
+
+export interface IndexResult {
+  documentId: string;
+  chunkCount: number;
+}
+
+const DEFAULT_MAX_CHARS = 1200;
+const DEFAULT_OVERLAP_CHARS = 200;
+const EMBEDDING_BATCH_SIZE = 100;
+
+// Reciprocal rank fusion constant (higher = flatter ranking)
+export const DEFAULT_RRF_K = 60;
+
+// ============================================================================
+// CHUNKING
+// ============================================================================
+
+// Render transcript turns as "Speaker: text" lines
+export function transcriptToText(turns: TranscriptTurn[]): string {
+  return turns
+    .filter(turn => turn.text && turn.text.trim())
+    .map(turn => `${turn.speaker || 'Speaker'}: ${turn.text.trim()}`)
+    .join('\n');
+}
+
+// Split text into overlapping chunks on line boundaries
+export function chunkDocument(text: string, options: ChunkOptions = {}): string[] {
+  const maxChars = options.maxChars ?? DEFAULT_MAX_CHARS;
+  const overlapChars = options.overlapChars ?? DEFAULT_OVERLAP_CHARS;
+
+  // Lines longer than a chunk are split on word boundaries
+  const lines: string[] = [];
+  for (const line of text.split('\n').map(l => l.trim()).filter(Boolean)) {
+    if (line.length <= maxChars) {
+      lines.push(line);
+      continue;
+    }
+    let current = '';
+    for (const word of line.split(/\s+/)) {
+      if (current && current.length + word.length + 1 > maxChars) {
+        lines.push(current);
+        current = '';
+      }
+      current = current ? `${current} ${word}` : word;
+    }
+    if (current) lines.push(current);
+  }
+
+  const chunks: string[] = [];
+  let current: string[] = [];
+  let length = 0;
+
+  for (const line of lines) {
+    if (current.length > 0 && length + line.length + 1 > maxChars) {
+      chunks.push(current.join('\n'));
+
+      // Carry the last few lines over so context isn't cut mid-exchange
+      const overlap: string[] = [];
+      let overlapLength = 0;
+      for (let i = current.length - 1; i >= 0; i--) {
+        if (overlapLength + current[i].length + 1 > overlapChars) break;
+        overlap.unshift(current[i]);
+        overlapLength += current[i].length + 1;
+      }
+      current = overlap;
+      length = overlapLength;
+    }
+    current.push(line);
+    length += line.length + 1;
+  }
+
+  if (current.length > 0) {
+    chunks.push(current.join('\n'));
+  }
+
+  return chunks;
+}
+
+// Rough token estimate (~4 characters per token)
+function estimateTokens(text: string): number {
+  return Math.ceil(text.length / 4);
+}
+
+// ============================================================================
+// EMBEDDINGS
+// ============================================================================
+
+// Embed texts with OpenAI, in batches
+export async function embedTexts(texts: string[]): Promise<number[][]> {
+  if (!config.openaiApiKey) {
+    throw new Error('OpenAI API key not configured');
+  }
+
+  const embeddings: number[][] = [];
+
+  for (let i = 0; i < texts.length; i += EMBEDDING_BATCH_SIZE) {
+    const batch = texts.slice(i, i + EMBEDDING_BATCH_SIZE);
+    const response = await fetch('https://api.openai.com/v1/embeddings', {
+      method: 'POST',
+      headers: {
+        'Content-Type': 'application/json',
+        'Authorization': `Bearer ${config.openaiApiKey}`,
+      },
+      body: JSON.stringify({
+        model: EMBEDDING_MODEL,
+        input: batch,
+      }),
+    });
+
+    if (!response.ok) {
+      const error = await response.text();
+      throw new Error(`OpenAI embeddings error: ${error}`);
+    }
+
+    const data = await response.json() as { data: Array<{ index: number; embedding: number[] }> };
+    const sorted = [...data.data].sort((a, b) => a.index - b.index);
+    embeddings.push(...sorted.map(d => d.embedding));
+  }
+
+  return embeddings;
+}
+
+// pgvector literal, e.g. "[0.1,0.2]"
+function toVector(embedding: number[]): string {
+  return `[${embedding.join(',')}]`;
+}
+
+// ============================================================================
+// INDEXING
+// ============================================================================
+
+// Chunk, embed and store a recording's transcript, replacing any earlier index
+export async function indexRecording(userId: string, recording: {
+  id: string;
+  lead_id: string | null;
+  mode: string;
+  start_time: Date;
+  transcript_turns: TranscriptTurn[] | null;
+}): Promise<IndexResult> {
+  const text = transcriptToText(recording.transcript_turns || []);
+  const chunks = chunkDocument(text);
+  if (chunks.length === 0) {
+    throw new Error('Recording has no transcript');
+  }
+
+  const embeddings = await embedTexts(chunks);
+  const title = `Call transcript (${recording.mode}, ${new Date(recording.start_time).toISOString().slice(0, 10)})`;
+
+  const client = await pool.connect();
+  try {
+    await client.query('BEGIN');
+
+    // Chunks are removed with the old document
+    await client.query(
+      'DELETE FROM documents WHERE recording_id = $1 AND user_id = $2',
+      [recording.id, userId]
+    );
+
+    const docResult = await client.query(
+      `INSERT INTO documents (
+        user_id, lead_id, recording_id, title, document_type, content_text,
+        is_indexed, chunk_count, embedding_model, indexed_at, mode
+      ) VALUES ($1, $2, $3, $4, 'call_transcript', $5, true, $6, $7, NOW(), $8)
+      RETURNING id`,
+      [userId, recording.lead_id, recording.id, title, text, chunks.length, EMBEDDING_MODEL, recording.mode]
+    );
+    const documentId = docResult.rows[0].id;
+
+    for (let i = 0; i < chunks.length; i++) {
+      await client.query(
+        `INSERT INTO document_chunks (
+          document_id, user_id, lead_id, chunk_index, content, token_count,
+          embedding, embedding_model, doc_type, mode
+        ) VALUES ($1, $2, $3, $4, $5, $6, $7::vector, $8, 'call_transcript', $9)`,
+        [
+          documentId, userId, recording.lead_id, i, chunks[i], estimateTokens(chunks[i]),
+          toVector(embeddings[i]), EMBEDDING_MODEL, recording.mode,
+        ]
+      );
+    }
+
+    await client.query('COMMIT');
+    return { documentId, chunkCount: chunks.length };
+  } catch (err) {
+    await client.query('ROLLBACK');
+    throw err;
+  } finally {
+    client.release();
+  }
+}
+
+// ============================================================================
+// SEARCH
+// ============================================================================
+
+// Merge ranked id lists with weighted reciprocal rank fusion, best first
+export function reciprocalRankFusion(
+  rankings: string[][],
+  weights: number[] = [],
+  k = DEFAULT_RRF_K
+): Array<{ id: string; score: number }> {
+  const scores = new Map<string, number>();
+
+  rankings.forEach((ranking, r) => {
+    const weight = weights[r] ?? 1;
+    ranking.forEach((id, index) => {
+      scores.set(id, (scores.get(id) || 0) + weight / (k + index + 1));
+    });
+  });
+
+  return [...scores.entries()]
+    .map(([id, score]) => ({ id, score }))
+    .sort((a, b) => b.score - a.score);
+}
+
+// Find the chunks most relevant to a query across the user's indexed calls.
+// Raise vectorWeight for paraphrased questions, textWeight for exact names/terms.
+export async function hybridSearch(
+  userId: string,
+  query: string,
+  limit = 5,
+  fusion: FusionOptions = {}
+): Promise<MemorySnippet[]> {
+  const [embedding] = await embedTexts([query]);
+  const candidates = limit * 4;
+
+  const vectorResult = await pool.query(
+    `SELECT id FROM document_chunks
+     WHERE user_id = $1 AND embedding IS NOT NULL
+     ORDER BY embedding <=> $2::vector
+     LIMIT $3`,
+    [userId, toVector(embedding), candidates]
+  );
+
+  const textResult = await pool.query(
+    `SELECT id FROM document_chunks
+     WHERE user_id = $1 AND search_vector @@ plainto_tsquery('english', $2)
+     ORDER BY ts_rank(search_vector, plainto_tsquery('english', $2)) DESC
+     LIMIT $3`,
+    [userId, query, candidates]
+  );
+
+  const ranked = reciprocalRankFusion(
+    [vectorResult.rows.map(row => row.id), textResult.rows.map(row => row.id)],
+    [fusion.vectorWeight ?? 1, fusion.textWeight ?? 1],
+    fusion.k ?? DEFAULT_RRF_K
+  ).slice(0, limit);
+
+  if (ranked.length === 0) {
+    return [];
+  }
+
+  const result = await pool.query(
+    `SELECT c.id, c.document_id, c.content, d.title, d.recording_id, d.created_at
+     FROM document_chunks c
+     JOIN documents d ON d.id = c.document_id
+     WHERE c.id = ANY($1) AND c.user_id = $2`,
+    [ranked.map(r => r.id), userId]
+  );
+  const rows = new Map(result.rows.map(row => [row.id, row]));
+
+  return ranked
+    .filter(r => rows.has(r.id))
+    .map(r => {
+      const row = rows.get(r.id);
+      return {
+        chunkId: row.id,
+        documentId: row.document_id,
+        recordingId: row.recording_id,
+        title: row.title,
+        content: row.content,
+        score: r.score,
+        createdAt: row.created_at,
+      };
+    });
+}
+
+export default {
+  chunkDocument,
+  embedTexts,
+  indexRecording,
+  hybridSearch,
+};
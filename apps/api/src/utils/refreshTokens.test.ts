@@ -0,0 +1,49 @@
+/**
+ * Refresh token reuse detection tests - run with `npm test`
+ */
+
+import { test } from 'node:test';
+import assert from 'node:assert/strict';
+import { checkRefreshToken, StoredRefreshToken } from './refreshTokens.js';
+
+const FAMILY = '6f1c2a9e-0d4b-4c1e-9a51-3b7e2f0c8d11';
+const NOW = new Date('2026-10-01T12:00:00Z');
+const LATER = new Date('2026-10-31T12:00:00Z');
+
+function token(overrides: Partial<StoredRefreshToken> = {}): StoredRefreshToken {
+  return {
+    family_id: FAMILY,
+    token_version: 0,
+    user_token_version: 0,
+    expires_at: LATER,
+    consumed_at: null,
+    ...overrides,
+  };
+}
+
+test('fresh token can be rotated', () => {
+  assert.equal(checkRefreshToken(token(), NOW), 'valid');
+});
+
+test('stolen token reused after rotation revokes the family', () => {
+  // The legitimate client rotates A into B; A is kept as consumed
+  const stolen = token({ consumed_at: NOW });
+  let current = token();
+  assert.equal(checkRefreshToken(current, NOW), 'valid');
+
+  // The attacker replays A
+  assert.equal(checkRefreshToken(stolen, NOW), 'reused');
+
+  // Reuse bumps the user's token version, so B stops working too
+  current = { ...current, user_token_version: current.user_token_version + 1 };
+  assert.equal(checkRefreshToken(current, NOW), 'revoked');
+});
+
+test('reuse is reported even for expired or revoked tokens', () => {
+  const stale = token({ consumed_at: NOW, expires_at: NOW, user_token_version: 3 });
+  assert.equal(checkRefreshToken(stale, LATER), 'reused');
+});
+
+test('expired token is rejected', () => {
+  assert.equal(checkRefreshToken(token({ expires_at: NOW }), NOW), 'expired');
+});
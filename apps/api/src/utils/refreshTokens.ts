@@ -0,0 +1,32 @@
+/**
+ * Refresh Token Checks
+ * Rotated tokens are kept as consumed so a replayed (stolen) token can be
+ * told apart from one that's simply expired or revoked
+ */
+
+export interface StoredRefreshToken {
+  family_id: string;
+  token_version: number;
+  user_token_version: number;
+  expires_at: Date;
+  consumed_at: Date | null;
+}
+
+export type RefreshTokenStatus = 'valid' | 'expired' | 'revoked' | 'reused';
+
+/**
+ * Decide whether a presented refresh token may be rotated
+ */
+export function checkRefreshToken(token: StoredRefreshToken, now: Date = new Date()): RefreshTokenStatus {
+  // Checked first: a consumed token is a theft signal even once expired
+  if (token.consumed_at) {
+    return 'reused';
+  }
+  if (token.token_version !== token.user_token_version) {
+    return 'revoked';
+  }
+  if (new Date(token.expires_at) <= now) {
+    return 'expired';
+  }
+  return 'valid';
+}
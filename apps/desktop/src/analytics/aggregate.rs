@@ -0,0 +1,127 @@
+//! Cross-Session Aggregates
+//!
+//! Rolls up metrics from many sessions to show trends over time.
+
+use super::{SessionAnalytics, Sentiment};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// Aggregate metrics across sessions
+#[derive(Debug, Clone, Serialize)]
+pub struct AggregateReport {
+    /// Mode filter applied (None = all modes)
+    pub mode: Option<String>,
+    /// Earliest session start included
+    pub since: Option<DateTime<Utc>>,
+    /// Number of sessions included
+    pub session_count: usize,
+    /// Total conversation time
+    pub total_minutes: f32,
+    /// Average share of talk time that was you (0-100)
+    pub average_talk_ratio_percent: u32,
+    /// Average speaking pace
+    pub average_wpm_user: f32,
+    pub average_wpm_other: f32,
+    /// Questions asked across all sessions
+    pub total_user_questions: usize,
+    pub total_other_questions: usize,
+    /// Most mentioned topics, highest first
+    pub top_topics: Vec<(String, usize)>,
+    /// How many turns fell into each sentiment bucket
+    pub sentiment_distribution: Vec<(Sentiment, usize)>,
+}
+
+/// Build an aggregate report from sessions
+pub fn aggregate_sessions<'a>(
+    sessions: impl IntoIterator<Item = &'a SessionAnalytics>,
+    mode: Option<&str>,
+    since: Option<DateTime<Utc>>,
+) -> AggregateReport {
+    let sessions: Vec<&SessionAnalytics> = sessions
+        .into_iter()
+        .filter(|s| mode.map_or(true, |m| s.mode.eq_ignore_ascii_case(m)))
+        .filter(|s| since.map_or(true, |t| s.start_time >= t))
+        .collect();
+
+    let mut topics: HashMap<String, usize> = HashMap::new();
+    let mut sentiments = [
+        (Sentiment::VeryPositive, 0),
+        (Sentiment::Positive, 0),
+        (Sentiment::Neutral, 0),
+        (Sentiment::Negative, 0),
+        (Sentiment::VeryNegative, 0),
+    ];
+
+    for session in &sessions {
+        for (topic, count) in session.topics.all_topics() {
+            *topics.entry(topic.clone()).or_insert(0) += count;
+        }
+        for (_, sentiment, _) in &session.sentiment_history {
+            if let Some(bucket) = sentiments.iter_mut().find(|(s, _)| s == sentiment) {
+                bucket.1 += 1;
+            }
+        }
+    }
+
+    let mut top_topics: Vec<(String, usize)> = topics.into_iter().collect();
+    top_topics.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    top_topics.truncate(10);
+
+    // Sessions with no recorded talk time would skew the averages
+    let talked: Vec<&&SessionAnalytics> = sessions
+        .iter()
+        .filter(|s| s.metrics.total_talk_time_ms() > 0)
+        .collect();
+
+    let average = |values: Vec<f32>| {
+        if values.is_empty() {
+            0.0
+        } else {
+            values.iter().sum::<f32>() / values.len() as f32
+        }
+    };
+
+    AggregateReport {
+        mode: mode.map(|m| m.to_string()),
+        since,
+        session_count: sessions.len(),
+        total_minutes: sessions.iter().map(|s| s.duration().num_seconds() as f32 / 60.0).sum(),
+        average_talk_ratio_percent: (average(talked.iter().map(|s| s.talk_ratio()).collect()) * 100.0).round() as u32,
+        average_wpm_user: average(talked.iter().map(|s| s.metrics.user.words_per_minute()).collect()),
+        average_wpm_other: average(talked.iter().map(|s| s.metrics.other.words_per_minute()).collect()),
+        total_user_questions: sessions.iter().map(|s| s.metrics.user.question_count).sum(),
+        total_other_questions: sessions.iter().map(|s| s.metrics.other.question_count).sum(),
+        top_topics,
+        sentiment_distribution: sentiments.to_vec(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analytics::Speaker;
+
+    #[test]
+    fn test_aggregate_filters_by_mode() {
+        let mut sales = SessionAnalytics::new("sales");
+        sales.add_turn(Speaker::User, "What is your budget?", 2000);
+        sales.add_turn(Speaker::Other, "The pricing looks great.", 2000);
+
+        let mut interview = SessionAnalytics::new("interview");
+        interview.add_turn(Speaker::Other, "Tell me about your experience?", 3000);
+
+        let all = aggregate_sessions([&sales, &interview], None, None);
+        assert_eq!(all.session_count, 2);
+        assert_eq!(all.total_user_questions, 1);
+        assert_eq!(all.total_other_questions, 1);
+
+        let sales_only = aggregate_sessions([&sales, &interview], Some("sales"), None);
+        assert_eq!(sales_only.session_count, 1);
+        assert_eq!(sales_only.average_talk_ratio_percent, 50);
+        assert!(sales_only.top_topics.iter().any(|(t, _)| t == "Pricing"));
+
+        let turns: usize = sales_only.sentiment_distribution.iter().map(|(_, c)| c).sum();
+        assert_eq!(turns, 2);
+    }
+}
@@ -2,7 +2,7 @@
 //!
 //! Export conversation analytics to various formats.
 
-use super::{SessionAnalytics, Speaker};
+use super::{AggregateReport, ConversationTurn, SessionAnalytics, Speaker};
 use chrono::{DateTime, Utc};
 use serde::Serialize;
 
@@ -195,6 +195,163 @@ pub fn export_to_markdown(session: &SessionAnalytics) -> String {
     md
 }
 
+/// One caption: start and end (ms from the session start), speaker and text
+pub type Cue<'a> = (u64, u64, &'a str, &'a str);
+
+/// Export to SRT subtitle format
+pub fn export_to_srt(session: &SessionAnalytics) -> String {
+    cues_to_srt(session_cues(session))
+}
+
+/// Export to WebVTT subtitle format
+pub fn export_to_vtt(session: &SessionAnalytics) -> String {
+    cues_to_vtt(session_cues(session))
+}
+
+/// Captions as SRT
+pub fn cues_to_srt<'a>(cues: impl IntoIterator<Item = Cue<'a>>) -> String {
+    let mut srt = String::new();
+
+    for (index, (start_ms, end_ms, speaker, text)) in cues.into_iter().enumerate() {
+        srt.push_str(&format!(
+            "{}\n{} --> {}\n{}: {}\n\n",
+            index + 1,
+            format_cue_time(start_ms, ','),
+            format_cue_time(end_ms, ','),
+            speaker,
+            text
+        ));
+    }
+
+    srt
+}
+
+/// Captions as WebVTT
+pub fn cues_to_vtt<'a>(cues: impl IntoIterator<Item = Cue<'a>>) -> String {
+    let mut vtt = String::from("WEBVTT\n\n");
+
+    for (start_ms, end_ms, speaker, text) in cues {
+        vtt.push_str(&format!(
+            "{} --> {}\n<v {}>{}\n\n",
+            format_cue_time(start_ms, '.'),
+            format_cue_time(end_ms, '.'),
+            speaker,
+            text
+        ));
+    }
+
+    vtt
+}
+
+fn session_cues(session: &SessionAnalytics) -> impl Iterator<Item = Cue<'_>> {
+    session.turns.iter().map(move |turn| {
+        let (start_ms, end_ms) = cue_bounds(session, turn);
+        (start_ms, end_ms, speaker_label(&turn.speaker), turn.text.as_str())
+    })
+}
+
+/// Cue start/end relative to the session start (ms)
+fn cue_bounds(session: &SessionAnalytics, turn: &ConversationTurn) -> (u64, u64) {
+    let start_ms = (turn.timestamp - session.start_time).num_milliseconds().max(0) as u64;
+    (start_ms, start_ms + turn.duration_ms)
+}
+
+/// Format milliseconds as HH:MM:SS,mmm (SRT) or HH:MM:SS.mmm (WebVTT)
+fn format_cue_time(ms: u64, separator: char) -> String {
+    format!(
+        "{:02}:{:02}:{:02}{}{:03}",
+        ms / 3_600_000,
+        (ms / 60_000) % 60,
+        (ms / 1000) % 60,
+        separator,
+        ms % 1000
+    )
+}
+
+fn speaker_label(speaker: &Speaker) -> &'static str {
+    match speaker {
+        Speaker::User => "You",
+        Speaker::Other => "Them",
+    }
+}
+
+/// Export an aggregate report to JSON format
+pub fn export_aggregate_to_json(report: &AggregateReport) -> String {
+    serde_json::to_string_pretty(report).unwrap_or_else(|_| "{}".to_string())
+}
+
+/// Export an aggregate report to CSV format (one metric per row)
+pub fn export_aggregate_to_csv(report: &AggregateReport) -> String {
+    let mut csv = String::new();
+
+    csv.push_str("Metric,Value\n");
+    csv.push_str(&format!("Sessions,{}\n", report.session_count));
+    csv.push_str(&format!("Total Minutes,{:.1}\n", report.total_minutes));
+    csv.push_str(&format!("Average Talk Ratio (%),{}\n", report.average_talk_ratio_percent));
+    csv.push_str(&format!("Average WPM (You),{:.0}\n", report.average_wpm_user));
+    csv.push_str(&format!("Average WPM (Them),{:.0}\n", report.average_wpm_other));
+    csv.push_str(&format!("Questions (You),{}\n", report.total_user_questions));
+    csv.push_str(&format!("Questions (Them),{}\n", report.total_other_questions));
+
+    for (topic, count) in &report.top_topics {
+        csv.push_str(&format!("\"Topic: {}\",{}\n", topic.replace("\"", "\"\""), count));
+    }
+    for (sentiment, count) in &report.sentiment_distribution {
+        csv.push_str(&format!("\"Sentiment: {}\",{}\n", sentiment.label(), count));
+    }
+
+    csv
+}
+
+/// Export an aggregate report to Markdown format
+pub fn export_aggregate_to_markdown(report: &AggregateReport) -> String {
+    let mut md = String::new();
+
+    md.push_str("# Analytics Report\n\n");
+    if let Some(mode) = &report.mode {
+        md.push_str(&format!("**Mode:** {}\n\n", mode));
+    }
+    if let Some(since) = report.since {
+        md.push_str(&format!("**Since:** {}\n\n", since.format("%Y-%m-%d")));
+    }
+    md.push_str(&format!(
+        "**Sessions:** {} ({:.0} minutes total)\n\n",
+        report.session_count, report.total_minutes
+    ));
+
+    md.push_str("## Averages\n\n");
+    md.push_str("| Metric | You | Them |\n");
+    md.push_str("|--------|-----|------|\n");
+    md.push_str(&format!(
+        "| Talk Ratio | {}% | {}% |\n",
+        report.average_talk_ratio_percent,
+        100 - report.average_talk_ratio_percent.min(100)
+    ));
+    md.push_str(&format!(
+        "| WPM | {:.0} | {:.0} |\n",
+        report.average_wpm_user, report.average_wpm_other
+    ));
+    md.push_str(&format!(
+        "| Questions | {} | {} |\n\n",
+        report.total_user_questions, report.total_other_questions
+    ));
+
+    if !report.top_topics.is_empty() {
+        md.push_str("## Top Topics\n\n");
+        for (topic, count) in &report.top_topics {
+            md.push_str(&format!("- **{}** (mentioned {} times)\n", topic, count));
+        }
+        md.push_str("\n");
+    }
+
+    md.push_str("## Sentiment\n\n");
+    for (sentiment, count) in &report.sentiment_distribution {
+        md.push_str(&format!("- {} {}: {}\n", sentiment.emoji(), sentiment.label(), count));
+    }
+
+    md
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -230,4 +387,38 @@ mod tests {
         assert!(md.contains("# Conversation Analytics"));
         assert!(md.contains("**Mode:** sales"));
     }
+
+    #[test]
+    fn test_aggregate_export() {
+        let mut session = SessionAnalytics::new("sales");
+        session.add_turn(Speaker::Other, "What about pricing?", 2000);
+        let report = crate::analytics::aggregate_sessions([&session], None, None);
+
+        assert!(export_aggregate_to_json(&report).contains("\"session_count\": 1"));
+        assert!(export_aggregate_to_csv(&report).contains("Questions (Them),1"));
+        assert!(export_aggregate_to_markdown(&report).contains("- **Pricing** (mentioned 1 times)"));
+    }
+
+    #[test]
+    fn test_cue_time_format() {
+        assert_eq!(format_cue_time(0, ','), "00:00:00,000");
+        assert_eq!(format_cue_time(3_723_456, ','), "01:02:03,456");
+        assert_eq!(format_cue_time(61_005, '.'), "00:01:01.005");
+    }
+
+    #[test]
+    fn test_subtitle_export() {
+        let mut session = SessionAnalytics::new("sales");
+        session.add_turn(Speaker::User, "Thanks for joining.", 1500);
+        session.add_turn(Speaker::Other, "Happy to be here.", 2000);
+
+        let srt = export_to_srt(&session);
+        assert!(srt.starts_with("1\n00:00:00,"));
+        assert!(srt.contains("2\n"));
+        assert!(srt.contains("Them: Happy to be here."));
+
+        let vtt = export_to_vtt(&session);
+        assert!(vtt.starts_with("WEBVTT\n\n"));
+        assert!(vtt.contains("<v You>Thanks for joining."));
+    }
 }
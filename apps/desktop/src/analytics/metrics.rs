@@ -2,10 +2,11 @@
 //!
 //! Tracks quantitative metrics about the conversation.
 
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 /// Overall conversation metrics
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ConversationMetrics {
     /// User's metrics
     pub user: SpeakerMetrics,
@@ -31,7 +32,7 @@ impl ConversationMetrics {
 }
 
 /// Metrics for a single speaker
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct SpeakerMetrics {
     /// Total time spent talking (milliseconds)
     pub total_talk_time_ms: u64,
@@ -65,18 +66,43 @@ impl SpeakerMetrics {
     }
 }
 
+/// Common words excluded from keyword frequencies
+const DEFAULT_STOPWORDS: &[&str] = &[
+    "a", "about", "all", "also", "am", "an", "and", "any", "are", "as", "at",
+    "be", "because", "been", "but", "by", "can", "could", "did", "do", "does",
+    "for", "from", "get", "got", "had", "has", "have", "he", "her", "here",
+    "him", "his", "how", "i", "if", "in", "into", "is", "it", "its", "just",
+    "know", "like", "me", "my", "no", "not", "now", "of", "oh", "ok", "okay",
+    "on", "or", "our", "out", "really", "so", "some", "that", "the", "their",
+    "them", "then", "there", "they", "think", "this", "to", "um", "uh", "up",
+    "us", "very", "was", "we", "well", "were", "what", "when", "where", "which",
+    "who", "why", "will", "with", "would", "yeah", "yes", "you", "your",
+];
+
 /// Topic tracker - extracts and counts key topics
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct TopicTracker {
     /// Topic counts
     topics: HashMap<String, usize>,
     /// Keywords that indicate topics
     keywords: Vec<(String, String)>, // (keyword, topic)
+    /// Word counts keyed by stem
+    #[serde(default)]
+    word_counts: HashMap<String, usize>,
+    /// Shortest spelling seen for each stem (used for display)
+    #[serde(default)]
+    surface_forms: HashMap<String, String>,
+    /// Words excluded from frequencies
+    #[serde(default)]
+    stopwords: Vec<String>,
 }
 
 impl TopicTracker {
     pub fn new() -> Self {
-        let mut tracker = Self::default();
+        let mut tracker = Self {
+            stopwords: DEFAULT_STOPWORDS.iter().map(|w| w.to_string()).collect(),
+            ..Self::default()
+        };
 
         // Add default topic keywords
         tracker.add_keyword_mappings(vec![
@@ -138,6 +164,12 @@ impl TopicTracker {
         tracker
     }
 
+    /// Replace the stopword list used for keyword frequencies
+    pub fn with_stopwords(mut self, stopwords: Vec<String>) -> Self {
+        self.stopwords = stopwords.into_iter().map(|w| w.to_lowercase()).collect();
+        self
+    }
+
     fn add_keyword_mappings(&mut self, mappings: Vec<(&str, &str)>) {
         for (keyword, topic) in mappings {
             self.keywords.push((keyword.to_lowercase(), topic.to_string()));
@@ -154,6 +186,41 @@ impl TopicTracker {
                 *self.topics.entry(topic.clone()).or_insert(0) += 1;
             }
         }
+
+        for word in &words {
+            let word: String = word
+                .trim_matches(|c: char| !c.is_alphanumeric())
+                .to_string();
+            if word.is_empty() {
+                continue;
+            }
+
+            let stem = stem(&word);
+            *self.word_counts.entry(stem.clone()).or_insert(0) += 1;
+
+            let surface = self.surface_forms.entry(stem).or_insert_with(|| word.clone());
+            if word.len() < surface.len() {
+                *surface = word;
+            }
+        }
+    }
+
+    /// Keyword frequencies with stopwords removed
+    ///
+    /// Words sharing a stem ("price", "pricing") are counted together under
+    /// the shortest spelling seen.
+    pub fn frequencies(&self) -> HashMap<String, usize> {
+        self.word_counts
+            .iter()
+            .filter_map(|(stem, count)| {
+                let word = self.surface_forms.get(stem)?;
+                if self.stopwords.iter().any(|s| s == word) || word.chars().all(|c| c.is_numeric()) {
+                    None
+                } else {
+                    Some((word.clone(), *count))
+                }
+            })
+            .collect()
     }
 
     /// Get top N topics
@@ -171,7 +238,30 @@ impl TopicTracker {
     /// Clear topics
     pub fn clear(&mut self) {
         self.topics.clear();
+        self.word_counts.clear();
+        self.surface_forms.clear();
+    }
+}
+
+/// Reduce a word to a crude stem by stripping common English suffixes
+fn stem(word: &str) -> String {
+    let mut stem = word.to_string();
+
+    for suffix in ["ing", "ed", "es", "ly", "s"] {
+        if suffix == "s" && stem.ends_with("ss") {
+            continue;
+        }
+        if stem.len() > suffix.len() + 2 && stem.ends_with(suffix) {
+            stem.truncate(stem.len() - suffix.len());
+            break;
+        }
+    }
+
+    if stem.len() > 3 && stem.ends_with('e') {
+        stem.pop();
     }
+
+    stem
 }
 
 #[cfg(test)]
@@ -203,4 +293,31 @@ mod tests {
 
         assert!((metrics.words_per_minute() - 150.0).abs() < 0.1);
     }
+
+    #[test]
+    fn test_frequencies() {
+        let mut tracker = TopicTracker::new();
+        tracker.extract_topics("The price is high.");
+        tracker.extract_topics("Our pricing depends on seats, and prices drop for annual plans.");
+
+        let freq = tracker.frequencies();
+        assert_eq!(freq.get("price"), Some(&3));
+        assert_eq!(freq.get("seats"), Some(&1));
+        assert!(!freq.contains_key("the"));
+        assert!(!freq.contains_key("and"));
+
+        let mut custom = TopicTracker::new().with_stopwords(vec!["Price".to_string()]);
+        custom.extract_topics("price and pricing");
+        let freq = custom.frequencies();
+        assert!(!freq.contains_key("price"));
+        assert_eq!(freq.get("and"), Some(&1));
+    }
+
+    #[test]
+    fn test_stem() {
+        assert_eq!(stem("pricing"), stem("price"));
+        assert_eq!(stem("prices"), stem("price"));
+        assert_eq!(stem("tests"), "test");
+        assert_eq!(stem("business"), "business");
+    }
 }
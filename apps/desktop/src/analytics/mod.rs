@@ -10,18 +10,26 @@
 mod metrics;
 mod sentiment;
 mod export;
+mod aggregate;
 
 pub use metrics::{ConversationMetrics, SpeakerMetrics, TopicTracker};
-pub use sentiment::{SentimentAnalyzer, Sentiment};
-pub use export::{export_to_json, export_to_csv, export_to_markdown, AnalyticsExport};
+pub use sentiment::{SentimentAnalyzer, Sentiment, SentimentScore};
+pub use export::{export_to_json, export_to_csv, export_to_markdown, export_to_srt, export_to_vtt, cues_to_srt, cues_to_vtt, AnalyticsExport, Cue};
+pub use export::{export_aggregate_to_json, export_aggregate_to_csv, export_aggregate_to_markdown};
+pub use aggregate::{AggregateReport, aggregate_sessions};
 
+use anyhow::Result;
 use chrono::{DateTime, Utc, Duration};
 use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
 use std::sync::Arc;
 use std::collections::HashMap;
 
+use crate::redaction::{redact, RedactionRules};
+
 /// Session analytics tracker
-#[derive(Debug)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SessionAnalytics {
     /// Session start time
     pub start_time: DateTime<Utc>,
@@ -35,8 +43,8 @@ pub struct SessionAnalytics {
     pub metrics: ConversationMetrics,
     /// Topic tracker
     pub topics: TopicTracker,
-    /// Sentiment over time
-    pub sentiment_history: Vec<(DateTime<Utc>, Sentiment)>,
+    /// Sentiment over time (bucket plus continuous score for plotting)
+    pub sentiment_history: Vec<(DateTime<Utc>, Sentiment, SentimentScore)>,
 }
 
 impl SessionAnalytics {
@@ -88,8 +96,8 @@ impl SessionAnalytics {
         self.topics.extract_topics(text);
 
         // Track sentiment
-        let sentiment = SentimentAnalyzer::analyze(text);
-        self.sentiment_history.push((Utc::now(), sentiment));
+        let score = SentimentAnalyzer::analyze_scored(text);
+        self.sentiment_history.push((Utc::now(), score.sentiment(), score));
 
         self.turns.push(turn);
     }
@@ -99,6 +107,15 @@ impl SessionAnalytics {
         self.end_time = Some(Utc::now());
     }
 
+    /// Copy of the session with PII masked in every turn
+    pub fn redacted(&self, rules: &RedactionRules) -> Self {
+        let mut session = self.clone();
+        for turn in &mut session.turns {
+            turn.text = redact(&turn.text, rules);
+        }
+        session
+    }
+
     /// Get session duration
     pub fn duration(&self) -> Duration {
         let end = self.end_time.unwrap_or_else(Utc::now);
@@ -124,7 +141,7 @@ impl SessionAnalytics {
         let mut negative = 0;
         let mut neutral = 0;
 
-        for (_, sentiment) in &self.sentiment_history {
+        for (_, sentiment, _) in &self.sentiment_history {
             match sentiment {
                 Sentiment::Positive => positive += 1,
                 Sentiment::Negative => negative += 1,
@@ -142,6 +159,16 @@ impl SessionAnalytics {
         }
     }
 
+    /// Get average polarity (-1.0 to 1.0) across all turns
+    pub fn average_polarity(&self) -> f32 {
+        if self.sentiment_history.is_empty() {
+            return 0.0;
+        }
+
+        let total: f32 = self.sentiment_history.iter().map(|(_, _, score)| score.polarity).sum();
+        total / self.sentiment_history.len() as f32
+    }
+
     /// Get top N topics
     pub fn top_topics(&self, n: usize) -> Vec<(&String, usize)> {
         self.topics.top_topics(n)
@@ -150,6 +177,8 @@ impl SessionAnalytics {
     /// Generate summary statistics
     pub fn summary(&self) -> SessionSummary {
         SessionSummary {
+            start_time: self.start_time,
+            mode: self.mode.clone(),
             duration_minutes: self.duration().num_minutes() as f32,
             talk_ratio_percent: (self.talk_ratio() * 100.0).round() as u32,
             total_turns: self.turns.len(),
@@ -159,6 +188,7 @@ impl SessionAnalytics {
             average_sentiment: self.average_sentiment(),
             words_per_minute_user: self.metrics.user.words_per_minute(),
             words_per_minute_other: self.metrics.other.words_per_minute(),
+            keyword_frequencies: self.topics.frequencies(),
         }
     }
 }
@@ -170,14 +200,14 @@ impl Default for SessionAnalytics {
 }
 
 /// Speaker identifier
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum Speaker {
     User,
     Other,
 }
 
 /// A single conversation turn
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ConversationTurn {
     pub timestamp: DateTime<Utc>,
     pub speaker: Speaker,
@@ -188,8 +218,10 @@ pub struct ConversationTurn {
 }
 
 /// Summary of a session
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct SessionSummary {
+    pub start_time: DateTime<Utc>,
+    pub mode: String,
     pub duration_minutes: f32,
     pub talk_ratio_percent: u32,
     pub total_turns: usize,
@@ -199,12 +231,16 @@ pub struct SessionSummary {
     pub average_sentiment: Sentiment,
     pub words_per_minute_user: f32,
     pub words_per_minute_other: f32,
+    /// Word-cloud data: keyword -> count, stopwords removed
+    pub keyword_frequencies: HashMap<String, usize>,
 }
 
 /// Thread-safe analytics manager
 pub struct AnalyticsManager {
     current_session: Arc<RwLock<Option<SessionAnalytics>>>,
     past_sessions: Arc<RwLock<Vec<SessionAnalytics>>>,
+    /// Applied to transcripts on export
+    redaction: Arc<RwLock<Option<RedactionRules>>>,
 }
 
 impl AnalyticsManager {
@@ -212,9 +248,15 @@ impl AnalyticsManager {
         Self {
             current_session: Arc::new(RwLock::new(None)),
             past_sessions: Arc::new(RwLock::new(Vec::new())),
+            redaction: Arc::new(RwLock::new(None)),
         }
     }
 
+    /// Mask PII in exported transcripts (None disables redaction)
+    pub fn set_redaction(&self, rules: Option<RedactionRules>) {
+        *self.redaction.write() = rules;
+    }
+
     /// Start a new session
     pub fn start_session(&self, mode: &str) {
         let mut current = self.current_session.write();
@@ -224,12 +266,100 @@ impl AnalyticsManager {
         *current = Some(SessionAnalytics::new(mode));
     }
 
-    /// End current session
+    /// End current session and persist history
     pub fn end_session(&self) {
-        let mut current = self.current_session.write();
-        if let Some(ref mut session) = *current {
-            session.end_session();
+        {
+            let mut current = self.current_session.write();
+            if let Some(ref mut session) = *current {
+                session.end_session();
+            }
+        }
+
+        if let Err(e) = self.save_to_disk() {
+            tracing::warn!("Failed to save analytics history: {}", e);
+        }
+    }
+
+    /// Get the analytics history file path
+    pub fn path() -> PathBuf {
+        dirs::config_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join("voice-copilot")
+            .join("analytics.json")
+    }
+
+    /// Save past sessions (and the current one, if ended) to disk
+    pub fn save_to_disk(&self) -> Result<()> {
+        let past = self.past_sessions.read();
+        let current = self.current_session.read();
+
+        let sessions: Vec<&SessionAnalytics> = past
+            .iter()
+            .chain(current.as_ref().filter(|s| s.end_time.is_some()))
+            .collect();
+
+        let path = Self::path();
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let content = serde_json::to_string_pretty(&sessions)?;
+        std::fs::write(&path, content)?;
+        Ok(())
+    }
+
+    /// Load past sessions from disk, replacing the in-memory history
+    pub fn load_from_disk(&self) -> Result<()> {
+        let path = Self::path();
+        if !path.exists() {
+            return Ok(());
         }
+
+        let content = std::fs::read_to_string(&path)?;
+        let sessions: Vec<SessionAnalytics> = serde_json::from_str(&content)?;
+        *self.past_sessions.write() = sessions;
+        Ok(())
+    }
+
+    /// Aggregate metrics across all persisted sessions
+    pub fn aggregate(&self, mode: Option<&str>) -> AggregateReport {
+        self.aggregate_since(mode, None)
+    }
+
+    /// Aggregate metrics across sessions started after `since` (e.g. last 30 days)
+    pub fn aggregate_since(&self, mode: Option<&str>, since: Option<DateTime<Utc>>) -> AggregateReport {
+        let past = self.past_sessions.read();
+        let current = self.current_session.read();
+
+        let sessions = past
+            .iter()
+            .chain(current.as_ref().filter(|s| s.end_time.is_some()));
+
+        aggregate_sessions(sessions, mode, since)
+    }
+
+    /// Export the all-sessions aggregate report
+    ///
+    /// Subtitle formats need a transcript, so they return None here.
+    pub fn export_aggregate(&self, format: ExportFormat) -> Option<String> {
+        let report = self.aggregate(None);
+        match format {
+            ExportFormat::Json => Some(export_aggregate_to_json(&report)),
+            ExportFormat::Csv => Some(export_aggregate_to_csv(&report)),
+            ExportFormat::Markdown => Some(export_aggregate_to_markdown(&report)),
+            ExportFormat::Srt | ExportFormat::Vtt => None,
+        }
+    }
+
+    /// Summaries of past sessions, newest first
+    pub fn list_sessions(&self) -> Vec<SessionSummary> {
+        let mut summaries: Vec<SessionSummary> = self
+            .past_sessions
+            .read()
+            .iter()
+            .map(|s| s.summary())
+            .collect();
+        summaries.sort_by(|a, b| b.start_time.cmp(&a.start_time));
+        summaries
     }
 
     /// Add a turn to current session
@@ -251,11 +381,23 @@ impl AnalyticsManager {
 
     /// Export current session
     pub fn export_current(&self, format: ExportFormat) -> Option<String> {
+        let rules = self.redaction.read().clone();
         self.current_session.read().as_ref().map(|session| {
+            let redacted;
+            let session = match &rules {
+                Some(rules) => {
+                    redacted = session.redacted(rules);
+                    &redacted
+                }
+                None => session,
+            };
+
             match format {
                 ExportFormat::Json => export_to_json(session),
                 ExportFormat::Csv => export_to_csv(session),
                 ExportFormat::Markdown => export_to_markdown(session),
+                ExportFormat::Srt => export_to_srt(session),
+                ExportFormat::Vtt => export_to_vtt(session),
             }
         })
     }
@@ -273,4 +415,59 @@ pub enum ExportFormat {
     Json,
     Csv,
     Markdown,
+    /// SubRip subtitles
+    Srt,
+    /// WebVTT subtitles
+    Vtt,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_session_roundtrip() {
+        let mut session = SessionAnalytics::new("sales");
+        session.add_turn(Speaker::User, "What's your budget for this?", 2000);
+        session.add_turn(Speaker::Other, "Around fifty thousand, great value.", 3000);
+        session.end_session();
+
+        let json = serde_json::to_string(&session).unwrap();
+        let restored: SessionAnalytics = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored.mode, "sales");
+        assert_eq!(restored.turns.len(), 2);
+        assert_eq!(restored.metrics.user.question_count, 1);
+        assert_eq!(restored.sentiment_history.len(), 2);
+        assert!(restored.end_time.is_some());
+    }
+
+    #[test]
+    fn test_export_applies_redaction() {
+        let manager = AnalyticsManager::new();
+        let mut session = SessionAnalytics::new("sales");
+        session.add_turn(Speaker::Other, "Send it to sam@example.com", 2000);
+        *manager.current_session.write() = Some(session);
+
+        let plain = manager.export_current(ExportFormat::Csv).unwrap();
+        assert!(plain.contains("sam@example.com"));
+
+        manager.set_redaction(Some(RedactionRules::default()));
+        let redacted = manager.export_current(ExportFormat::Csv).unwrap();
+        assert!(!redacted.contains("sam@example.com"));
+        assert!(redacted.contains("[EMAIL]"));
+    }
+
+    #[test]
+    fn test_list_sessions_newest_first() {
+        let manager = AnalyticsManager::new();
+        let mut older = SessionAnalytics::new("interview");
+        older.start_time = Utc::now() - Duration::days(1);
+        manager.past_sessions.write().push(older);
+        manager.past_sessions.write().push(SessionAnalytics::new("sales"));
+
+        let sessions = manager.list_sessions();
+        assert_eq!(sessions.len(), 2);
+        assert_eq!(sessions[0].mode, "sales");
+    }
 }
@@ -3,10 +3,11 @@
 //! Basic sentiment analysis for conversation tracking.
 //! Uses keyword-based analysis for speed.
 
+use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 
 /// Sentiment classification
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum Sentiment {
     VeryPositive,
     Positive,
@@ -67,52 +68,75 @@ impl Default for Sentiment {
     }
 }
 
+/// Continuous sentiment score
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct SentimentScore {
+    /// Direction, from -1.0 (negative) to 1.0 (positive)
+    pub polarity: f32,
+    /// Strength of feeling regardless of direction, from 0.0 to 1.0
+    pub magnitude: f32,
+}
+
+impl SentimentScore {
+    /// Map to the coarse sentiment bucket
+    pub fn sentiment(&self) -> Sentiment {
+        Sentiment::from_score(self.polarity * 2.0)
+    }
+}
+
+/// Smoothing constant for squashing raw lexicon sums into -1..1
+const NORMALIZATION_ALPHA: f32 = 4.0;
+
+/// How many words back a negation still applies ("not very good")
+const NEGATION_WINDOW: usize = 2;
+
 /// Simple keyword-based sentiment analyzer
 pub struct SentimentAnalyzer;
 
 impl SentimentAnalyzer {
     /// Analyze sentiment of text
     pub fn analyze(text: &str) -> Sentiment {
+        Self::analyze_scored(text).sentiment()
+    }
+
+    /// Analyze sentiment with polarity and intensity
+    ///
+    /// Each lexicon word contributes its weight, boosted by a preceding
+    /// intensifier and flipped by a negation within the last two words.
+    pub fn analyze_scored(text: &str) -> SentimentScore {
         let text_lower = text.to_lowercase();
         let words: Vec<&str> = text_lower.split_whitespace().collect();
 
-        let mut score: f32 = 0.0;
-        let mut word_count = 0;
+        let mut sum: f32 = 0.0;
+        let mut intensity: f32 = 0.0;
 
-        for word in &words {
-            if let Some(s) = Self::word_sentiment(word) {
-                score += s;
-                word_count += 1;
+        for (i, word) in words.iter().enumerate() {
+            // Negations act on the words after them rather than scoring themselves
+            if Self::is_negation(word) {
+                continue;
             }
-        }
 
-        // Check for intensifiers and negations
-        for i in 0..words.len() {
-            if Self::is_negation(words[i]) {
-                // Flip sentiment of next word
-                if i + 1 < words.len() {
-                    if let Some(s) = Self::word_sentiment(words[i + 1]) {
-                        score -= s * 2.0; // Undo positive and make negative (or vice versa)
-                    }
-                }
+            let Some(mut weight) = Self::word_sentiment(word) else {
+                continue;
+            };
+
+            if i > 0 && Self::is_intensifier(words[i - 1]) {
+                weight *= 1.5;
             }
 
-            if Self::is_intensifier(words[i]) {
-                // Amplify sentiment of next word
-                if i + 1 < words.len() {
-                    if let Some(s) = Self::word_sentiment(words[i + 1]) {
-                        score += s * 0.5;
-                    }
-                }
+            let window_start = i.saturating_sub(NEGATION_WINDOW);
+            if words[window_start..i].iter().any(|w| Self::is_negation(w)) {
+                weight = -weight * 0.75; // "not good" is milder than "bad"
             }
-        }
 
-        // Normalize by word count
-        if word_count > 0 {
-            score /= word_count as f32;
+            sum += weight;
+            intensity += weight.abs();
         }
 
-        Sentiment::from_score(score)
+        SentimentScore {
+            polarity: squash(sum),
+            magnitude: squash(intensity),
+        }
     }
 
     fn word_sentiment(word: &str) -> Option<f32> {
@@ -192,6 +216,11 @@ impl SentimentAnalyzer {
     }
 }
 
+/// Squash an unbounded sum into -1..1
+fn squash(value: f32) -> f32 {
+    value / (value * value + NORMALIZATION_ALPHA).sqrt()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -219,4 +248,28 @@ mod tests {
         let sentiment = SentimentAnalyzer::analyze("This is not good.");
         assert!(matches!(sentiment, Sentiment::Negative | Sentiment::Neutral));
     }
+
+    #[test]
+    fn test_scored_negation_flips_polarity() {
+        let good = SentimentAnalyzer::analyze_scored("This is good.");
+        let not_good = SentimentAnalyzer::analyze_scored("This is not good.");
+        assert!(good.polarity > 0.0);
+        assert!(not_good.polarity < 0.0);
+
+        let neutral = SentimentAnalyzer::analyze_scored("The meeting is at 3pm.");
+        assert_eq!(neutral.polarity, 0.0);
+        assert_eq!(neutral.magnitude, 0.0);
+    }
+
+    #[test]
+    fn test_scored_intensity() {
+        let mild = SentimentAnalyzer::analyze_scored("That's good.");
+        let strong = SentimentAnalyzer::analyze_scored("That's really amazing, I love it!");
+        assert!(strong.polarity > mild.polarity);
+        assert!(strong.magnitude > mild.magnitude);
+        assert!(strong.polarity <= 1.0);
+
+        let mixed = SentimentAnalyzer::analyze_scored("Great product but terrible support.");
+        assert!(mixed.magnitude > mixed.polarity.abs());
+    }
 }
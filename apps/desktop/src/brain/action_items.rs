@@ -0,0 +1,153 @@
+//! Live Action Items
+//!
+//! Picks commitments out of each final turn as it arrives ("I'll send the
+//! deck", "can you loop in legal by Friday?") so Meeting and Coaching
+//! calls build their to-do list while they happen. Matching is local
+//! regex only, so it adds no latency to the turn; the full minutes can
+//! still be rewritten by a model afterwards.
+
+use chrono::NaiveDate;
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use super::followup::due_date;
+use crate::recording::ActionItem;
+
+static SENTENCE_END: Lazy<Regex> = Lazy::new(|| Regex::new(r"[.!?](?:\s|$)|\n").unwrap());
+
+/// The speaker takes it on: "I'll send...", "let me check..."
+static COMMITMENT: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)\b(?:i'll|i will|i'm going to|i am going to|let me|we'll|we will)\s+(.+)").unwrap()
+});
+
+/// The speaker asks the other side: "can you send...", "please share..."
+static REQUEST: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)\b(?:can you|could you|would you|will you|please)\s+(.+)").unwrap()
+});
+
+/// Clause openings that sound like a commitment but aren't one
+static NOT_A_TASK: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)^(?:be (?:honest|frank|quick)|think about it|tell you (?:what|the truth)|let you go|repeat that|hear me|see\b|explain\b)").unwrap()
+});
+
+/// Tasks sharing this much of their wording are the same item
+const DUPLICATE_OVERLAP: f32 = 0.6;
+
+/// Finds action items in final turns, skipping ones already found
+#[derive(Debug, Default)]
+pub struct ActionItemDetector {
+    found: Vec<ActionItem>,
+}
+
+impl ActionItemDetector {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// New action items in one turn
+    ///
+    /// `from_user` is whether we said it: "I'll..." is then ours ("You")
+    /// and "can you..." theirs, and the other way round for their turns.
+    pub fn scan(&mut self, text: &str, from_user: bool, today: NaiveDate) -> Vec<ActionItem> {
+        let (speaker, listener) = if from_user { ("You", "Them") } else { ("Them", "You") };
+        let mut new_items = Vec::new();
+
+        for sentence in SENTENCE_END.split(text) {
+            let (owner, clause) = if let Some(caps) = COMMITMENT.captures(sentence) {
+                (speaker, caps[1].to_string())
+            } else if let Some(caps) = REQUEST.captures(sentence) {
+                (listener, caps[1].to_string())
+            } else {
+                continue;
+            };
+
+            let Some(task) = task_text(&clause) else {
+                continue;
+            };
+            if self.found.iter().chain(new_items.iter()).any(|item: &ActionItem| is_duplicate(&item.task, &task)) {
+                continue;
+            }
+
+            new_items.push(ActionItem {
+                owner: Some(owner.to_string()),
+                task,
+                due: due_date(sentence, today),
+            });
+        }
+
+        self.found.extend(new_items.iter().cloned());
+        new_items
+    }
+}
+
+/// The clause as a task, e.g. "send over the deck" -> "Send over the deck"
+fn task_text(clause: &str) -> Option<String> {
+    let clause = clause.trim().trim_end_matches([',', ';', ':', '?', '!', '.']).trim();
+    // Two words at least: "I'll see" or "I will, yes" isn't something to do
+    if clause.split_whitespace().count() < 2 || NOT_A_TASK.is_match(clause) {
+        return None;
+    }
+
+    let mut chars = clause.chars();
+    let first = chars.next()?;
+    Some(first.to_uppercase().chain(chars).collect())
+}
+
+/// Whether two tasks are worded nearly the same
+fn is_duplicate(a: &str, b: &str) -> bool {
+    let a = words(a);
+    let b = words(b);
+    let shared = a.iter().filter(|w| b.contains(w)).count();
+    let smaller = a.len().min(b.len());
+    smaller > 0 && shared as f32 / smaller as f32 >= DUPLICATE_OVERLAP
+}
+
+fn words(text: &str) -> Vec<String> {
+    let mut words: Vec<String> = text
+        .to_lowercase()
+        .split(|c: char| !c.is_alphanumeric() && c != '\'')
+        .filter(|w| w.len() > 2)
+        .map(String::from)
+        .collect();
+    words.sort();
+    words.dedup();
+    words
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scan_owners_and_due_dates() {
+        // Wednesday
+        let today = NaiveDate::from_ymd_opt(2026, 10, 14).unwrap();
+        let mut detector = ActionItemDetector::new();
+
+        let items = detector.scan("Sounds good. I'll send the pricing sheet by Friday.", true, today);
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].owner.as_deref(), Some("You"));
+        assert_eq!(items[0].task, "Send the pricing sheet by Friday");
+        assert_eq!(items[0].due, NaiveDate::from_ymd_opt(2026, 10, 16));
+
+        let items = detector.scan("Could you loop in our legal team?", true, today);
+        assert_eq!(items[0].owner.as_deref(), Some("Them"));
+        assert_eq!(items[0].due, None);
+
+        // Their "can you" is ours to do
+        let items = detector.scan("Can you share the security docs?", false, today);
+        assert_eq!(items[0].owner.as_deref(), Some("You"));
+
+        assert!(detector.scan("To be honest, I'll be honest with you.", false, today).is_empty());
+    }
+
+    #[test]
+    fn test_scan_skips_near_duplicates() {
+        let today = NaiveDate::from_ymd_opt(2026, 10, 14).unwrap();
+        let mut detector = ActionItemDetector::new();
+
+        assert_eq!(detector.scan("I'll send the pricing sheet.", true, today).len(), 1);
+        assert!(detector.scan("Yes, I will send over the pricing sheet", true, today).is_empty());
+        assert_eq!(detector.scan("We'll book the follow-up demo", true, today).len(), 1);
+    }
+}
@@ -0,0 +1,93 @@
+//! Urgency Alerts
+//!
+//! Gets the user's attention when Flash marks a suggestion as urgent: the
+//! UI flashes its border and, if enabled, a short chime plays. Capture is
+//! muted while the chime plays so it isn't transcribed as their speech.
+
+use parking_lot::Mutex;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use crate::config::{AlertLevel, AlertSettings};
+use crate::flash::Urgency;
+use crate::voice::{earcon, AudioPlayer, EARCON_DURATION};
+
+/// Extra quiet time after the chime while the output device drains
+const MUTE_TAIL: Duration = Duration::from_millis(200);
+
+/// Captured audio is dropped until a deadline
+///
+/// Clones share the deadline, so the alert and the capture loop can each
+/// hold one.
+#[derive(Debug, Clone, Default)]
+pub struct CaptureMute {
+    until: Arc<Mutex<Option<Instant>>>,
+}
+
+impl CaptureMute {
+    /// Mute for at least `duration` from now (never shortens a mute)
+    pub fn mute_for(&self, duration: Duration) {
+        let at = Instant::now() + duration;
+        let mut until = self.until.lock();
+        if until.map_or(true, |current| current < at) {
+            *until = Some(at);
+        }
+    }
+
+    pub fn is_muted(&self) -> bool {
+        self.until.lock().is_some_and(|at| Instant::now() < at)
+    }
+}
+
+/// Plays alerts for urgent suggestions
+#[derive(Clone)]
+pub struct Alerter {
+    settings: AlertSettings,
+    mute: CaptureMute,
+    player: AudioPlayer,
+}
+
+impl Alerter {
+    pub fn new(settings: AlertSettings, mute: CaptureMute) -> Self {
+        Self {
+            settings,
+            mute,
+            player: AudioPlayer::new(0.6, 1.0),
+        }
+    }
+
+    /// Alert for a Flash urgency, returning the level used
+    ///
+    /// The chime plays in the background; the caller shows the visual part.
+    pub fn alert(&self, urgency: &Urgency) -> AlertLevel {
+        let level = self.settings.level_for(urgency);
+        if level == AlertLevel::Audio {
+            // Opening the output device takes a moment, so mute again once it
+            // has actually finished
+            self.mute.mute_for(EARCON_DURATION + MUTE_TAIL);
+            let alerter = self.clone();
+            tokio::spawn(async move {
+                if let Err(e) = alerter.player.play(earcon()).await {
+                    tracing::debug!("Alert chime failed: {}", e);
+                }
+                alerter.mute.mute_for(MUTE_TAIL);
+            });
+        }
+        level
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_capture_mute() {
+        let mute = CaptureMute::default();
+        assert!(!mute.is_muted());
+
+        mute.mute_for(Duration::from_secs(60));
+        mute.mute_for(Duration::ZERO);
+        assert!(mute.clone().is_muted());
+    }
+}
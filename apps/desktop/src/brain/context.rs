@@ -1,10 +1,27 @@
 //! Conversation Context
 //!
 //! Tracks the ongoing conversation for better AI responses.
+//! Older turns are folded into a short rolling summary so prompts stay
+//! bounded on long calls. The flash model writes that summary a few turns
+//! at a time; until it has (or when it can't), each dropped turn is kept
+//! as an extractive note: its first sentence, cut short.
 
 use chrono::{DateTime, Utc};
 use std::collections::VecDeque;
 
+use crate::config::{Battlecard, Glossary, ObjectionEntry};
+use super::intent::DetectedIntent;
+use super::memory::{memory_block, MemorySnippet};
+
+/// Maximum length of the rolling summary (characters)
+const SUMMARY_MAX_CHARS: usize = 600;
+
+/// Words kept from each summarized turn
+const SUMMARY_WORDS_PER_TURN: usize = 12;
+
+/// Dropped turns that make a summary request worth sending
+pub const SUMMARY_BATCH_TURNS: usize = 4;
+
 /// A single turn in the conversation
 #[derive(Debug, Clone)]
 pub struct ConversationTurn {
@@ -46,6 +63,26 @@ pub struct ConversationContext {
     key_facts: Vec<String>,
     /// Objections that have been raised
     objections_raised: Vec<String>,
+    /// Model-written summaries of turns that left the history, oldest first
+    summary: VecDeque<String>,
+    /// Turns that left the history but aren't in `summary` yet
+    dropped: VecDeque<ConversationTurn>,
+    /// How many turns have left `dropped` since the call started
+    dropped_offset: u64,
+    /// A summary request is out for the turns in `dropped`
+    summary_pending: bool,
+    /// Domain terms block from the user's glossary
+    glossary: Option<String>,
+    /// Snippets from past calls relevant to the current turn
+    memory: Option<String>,
+    /// Concerns detected in their latest turn
+    intents: Option<String>,
+    /// Battlecard for the competitor they last named
+    battlecard: Option<String>,
+    /// Library response to the objection in their latest turn
+    objection: Option<String>,
+    /// Background on who the call is with; kept across `clear`
+    brief: Option<String>,
 }
 
 impl Default for ConversationContext {
@@ -63,6 +100,16 @@ impl ConversationContext {
             mode_context: String::new(),
             key_facts: Vec::new(),
             objections_raised: Vec::new(),
+            summary: VecDeque::new(),
+            dropped: VecDeque::new(),
+            dropped_offset: 0,
+            summary_pending: false,
+            glossary: None,
+            memory: None,
+            intents: None,
+            battlecard: None,
+            objection: None,
+            brief: None,
         }
     }
 
@@ -71,6 +118,44 @@ impl ConversationContext {
         self.mode_context = context.into();
     }
 
+    /// Set the domain glossary included in every prompt
+    pub fn set_glossary(&mut self, glossary: &Glossary) {
+        self.glossary = glossary.prompt_block();
+    }
+
+    /// Set the past-call snippets for the current turn, replacing earlier ones
+    pub fn set_memory(&mut self, snippets: &[MemorySnippet]) {
+        self.memory = memory_block(snippets);
+    }
+
+    /// Set the concerns detected in their latest turn, replacing earlier ones
+    pub fn set_intents(&mut self, intents: &[DetectedIntent]) {
+        self.intents = intents_block(intents);
+    }
+
+    /// Set the battlecard for a competitor they named, replacing any earlier one
+    pub fn set_battlecard(&mut self, card: Option<&Battlecard>) {
+        self.battlecard = card.map(Battlecard::prompt_block);
+    }
+
+    /// Set the library response for their latest turn, replacing any earlier one
+    pub fn set_objection(&mut self, entry: Option<&ObjectionEntry>) {
+        self.objection = entry.map(ObjectionEntry::prompt_block);
+    }
+
+    /// Set the background on the prospect or company included in every prompt
+    ///
+    /// An empty brief removes it.
+    pub fn set_brief(&mut self, brief: &str) {
+        let brief = brief.trim();
+        self.brief = (!brief.is_empty()).then(|| brief.to_string());
+    }
+
+    /// The brief, if one is set
+    pub fn brief(&self) -> Option<&str> {
+        self.brief.as_deref()
+    }
+
     /// Add a turn from the other person
     pub fn add_their_turn(&mut self, text: impl Into<String>, intent: Option<String>) {
         self.add_turn(ConversationTurn {
@@ -94,9 +179,107 @@ impl ConversationContext {
     /// Add a turn
     fn add_turn(&mut self, turn: ConversationTurn) {
         self.turns.push_back(turn);
-        while self.turns.len() > self.max_turns {
-            self.turns.pop_front();
+        self.summarize_old_turns(self.max_turns);
+    }
+
+    /// Compress all but the last `keep` turns into the rolling summary
+    pub fn summarize_old_turns(&mut self, keep: usize) {
+        while self.turns.len() > keep {
+            if let Some(turn) = self.turns.pop_front() {
+                self.dropped.push_back(turn);
+            }
         }
+        self.trim_summary();
+    }
+
+    /// Oldest notes go first once the summary gets long
+    fn trim_summary(&mut self) {
+        while self.summary.len() + self.dropped.len() > 1 && summary_len(self.notes().iter()) > SUMMARY_MAX_CHARS {
+            if self.summary.pop_front().is_none() {
+                self.dropped.pop_front();
+                self.dropped_offset += 1;
+            }
+        }
+    }
+
+    /// Model summaries, then extractive notes for turns they don't cover yet
+    fn notes(&self) -> Vec<String> {
+        self.summary
+            .iter()
+            .cloned()
+            .chain(self.dropped.iter().map(summarize_turn))
+            .collect()
+    }
+
+    /// Dropped turns for the flash model to summarize, once there are at
+    /// least `min_turns` of them and no request is already out
+    ///
+    /// Returns the transcript to summarize and a marker to hand back to
+    /// [`Self::apply_summary`].
+    pub fn summary_request(&mut self, min_turns: usize) -> Option<(String, u64)> {
+        if self.summary_pending || self.dropped.is_empty() || self.dropped.len() < min_turns {
+            return None;
+        }
+        self.summary_pending = true;
+        let transcript = self
+            .dropped
+            .iter()
+            .map(|turn| format!("{}: {}", turn.speaker.label(), turn.text))
+            .collect::<Vec<_>>()
+            .join("\n");
+        Some((transcript, self.dropped_offset + self.dropped.len() as u64))
+    }
+
+    /// Replace the extractive notes for a request's turns with the model's summary
+    ///
+    /// Turns trimmed or cleared while it was out are skipped.
+    pub fn apply_summary(&mut self, upto: u64, summary: &str) {
+        self.summary_pending = false;
+        let covered = (upto.saturating_sub(self.dropped_offset) as usize).min(self.dropped.len());
+        let summary = summary.trim();
+        if covered == 0 || summary.is_empty() {
+            return;
+        }
+        self.dropped.drain(..covered);
+        self.dropped_offset += covered as u64;
+        self.summary.push_back(summary.to_string());
+        self.trim_summary();
+    }
+
+    /// The summary request failed; its turns keep their extractive notes
+    pub fn summary_failed(&mut self) {
+        self.summary_pending = false;
+    }
+
+    /// Rolling summary of turns no longer kept in full
+    pub fn get_summary(&self) -> Option<String> {
+        let notes = self.notes();
+        if notes.is_empty() {
+            None
+        } else {
+            Some(notes.join(" "))
+        }
+    }
+
+    /// History for prompts: a summary of older turns plus the last `max_turns` verbatim
+    pub fn get_windowed_context(&self, max_turns: usize) -> String {
+        let older = self.turns.len().saturating_sub(max_turns);
+
+        let mut notes = self.notes();
+        notes.extend(self.turns.iter().take(older).map(summarize_turn));
+
+        let recent = self.get_recent_history(max_turns);
+        if notes.is_empty() {
+            return recent;
+        }
+
+        // Keep the newest notes within the summary budget
+        let mut start = 0;
+        while start + 1 < notes.len() && summary_len(notes[start..].iter()) > SUMMARY_MAX_CHARS {
+            start += 1;
+        }
+
+        format!("Earlier in the conversation: {}\n\n{}", notes[start..].join(" "), recent)
     }
 
     /// Record an objection
@@ -134,7 +317,15 @@ impl ConversationContext {
 
     /// Get the full context string for prompts
     pub fn get_full_context(&self) -> String {
-        let mut context = self.mode_context.clone();
+        let mut context = match &self.glossary {
+            Some(glossary) => format!("{}\n\n{}", glossary, self.mode_context),
+            None => self.mode_context.clone(),
+        };
+
+        if let Some(brief) = &self.brief {
+            context.push_str("\n\nAbout this call:\n");
+            context.push_str(brief);
+        }
 
         if !self.key_facts.is_empty() {
             context.push_str("\n\nKey facts established:");
@@ -150,6 +341,26 @@ impl ConversationContext {
             }
         }
 
+        if let Some(intents) = &self.intents {
+            context.push_str("\n\n");
+            context.push_str(intents);
+        }
+
+        if let Some(battlecard) = &self.battlecard {
+            context.push_str("\n\n");
+            context.push_str(battlecard);
+        }
+
+        if let Some(objection) = &self.objection {
+            context.push_str("\n\n");
+            context.push_str(objection);
+        }
+
+        if let Some(memory) = &self.memory {
+            context.push_str("\n\n");
+            context.push_str(memory);
+        }
+
         context
     }
 
@@ -158,11 +369,30 @@ impl ConversationContext {
         self.turns.iter().rev().find(|t| t.speaker == Speaker::Them)
     }
 
+    /// Fix misheard text in the last thing they said, returning the corrected turn
+    pub fn correct_last_their_turn(&mut self, original: &str, corrected: &str) -> Option<String> {
+        let turn = self.turns.iter_mut().rev().find(|t| t.speaker == Speaker::Them)?;
+        turn.text = if turn.text.contains(original) {
+            turn.text.replacen(original, corrected, 1)
+        } else {
+            corrected.to_string()
+        };
+        Some(turn.text.clone())
+    }
+
     /// Clear the conversation
     pub fn clear(&mut self) {
         self.turns.clear();
         self.key_facts.clear();
         self.objections_raised.clear();
+        self.summary.clear();
+        // Requests still out then cover nothing
+        self.dropped_offset += self.dropped.len() as u64;
+        self.dropped.clear();
+        self.summary_pending = false;
+        self.intents = None;
+        self.battlecard = None;
+        self.objection = None;
     }
 
     /// Get turn count
@@ -171,9 +401,52 @@ impl ConversationContext {
     }
 }
 
+/// Compress a turn into a one-line extractive note, the fallback for
+/// turns the flash model hasn't summarized
+fn summarize_turn(turn: &ConversationTurn) -> String {
+    let text = turn.text.trim();
+    let sentence = text
+        .find(|c| c == '.' || c == '?' || c == '!')
+        .map(|end| &text[..=end])
+        .unwrap_or(text);
+
+    let words: Vec<&str> = sentence.split_whitespace().collect();
+    let mut note = words
+        .iter()
+        .take(SUMMARY_WORDS_PER_TURN)
+        .copied()
+        .collect::<Vec<_>>()
+        .join(" ");
+    if words.len() > SUMMARY_WORDS_PER_TURN {
+        note.push_str("...");
+    }
+
+    let verb = if sentence.ends_with('?') { "asked" } else { "said" };
+    format!("{} {}: \"{}\"", turn.speaker.label(), verb, note)
+}
+
+/// Prompt lines asking the model to cover every detected concern
+fn intents_block(intents: &[DetectedIntent]) -> Option<String> {
+    let labels: Vec<&str> = intents.iter().map(|i| i.category.label()).collect();
+    match labels.as_slice() {
+        [] => None,
+        [only] => Some(format!("Their latest message is about: {}", only)),
+        _ => Some(format!(
+            "Their latest message raises several concerns: {}. Address each of them.",
+            labels.join(", ")
+        )),
+    }
+}
+
+fn summary_len<'a>(notes: impl Iterator<Item = &'a String>) -> usize {
+    notes.map(|n| n.len() + 1).sum()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::brain::IntentAnalyzer;
+    use crate::config::Battlecards;
 
     #[test]
     fn test_conversation_context() {
@@ -190,4 +463,143 @@ mod tests {
         assert!(history.contains("How much does it cost?"));
         assert!(history.contains("About 50 people"));
     }
+
+    #[test]
+    fn test_glossary_in_context() {
+        let mut ctx = ConversationContext::default();
+        ctx.set_mode_context("Sales call");
+        ctx.set_glossary(&Glossary::from_lines("Acme Vault: our secrets manager"));
+        assert_eq!(ctx.get_full_context(), "Glossary:\n- Acme Vault: our secrets manager\n\nSales call");
+    }
+
+    #[test]
+    fn test_memory_in_context() {
+        let mut ctx = ConversationContext::default();
+        ctx.set_mode_context("Sales call");
+        ctx.set_memory(&[MemorySnippet {
+            title: "Last call".to_string(),
+            content: "Them: Budget resets in Q3.".to_string(),
+            score: 0.03,
+        }]);
+        assert_eq!(
+            ctx.get_full_context(),
+            "Sales call\n\nRelevant past conversations:\n[Last call]\nThem: Budget resets in Q3."
+        );
+
+        ctx.set_memory(&[]);
+        assert_eq!(ctx.get_full_context(), "Sales call");
+    }
+
+    #[test]
+    fn test_intents_in_context() {
+        let mut ctx = ConversationContext::default();
+        ctx.set_mode_context("Sales call");
+        ctx.set_intents(&IntentAnalyzer::new().analyze_multi("How much is it and are you GDPR compliant?"));
+        assert_eq!(
+            ctx.get_full_context(),
+            "Sales call\n\nTheir latest message raises several concerns: Security/Compliance, Pricing Question. Address each of them."
+        );
+
+        ctx.set_intents(&[]);
+        assert_eq!(ctx.get_full_context(), "Sales call");
+    }
+
+    #[test]
+    fn test_battlecard_in_context() {
+        let mut ctx = ConversationContext::default();
+        ctx.set_mode_context("Sales call");
+        let cards = Battlecards::from_text("[Gong]\n+ Live suggestions during the call");
+        ctx.set_battlecard(cards.find("gong"));
+        assert_eq!(
+            ctx.get_full_context(),
+            "Sales call\n\nThey mentioned Gong. Battlecard:\nOur differentiators:\n- Live suggestions during the call"
+        );
+
+        ctx.clear();
+        assert_eq!(ctx.get_full_context(), "Sales call");
+    }
+
+    #[test]
+    fn test_brief_in_context() {
+        let mut ctx = ConversationContext::default();
+        ctx.set_mode_context("Sales call");
+        ctx.set_brief("  Acme Corp, logistics. Evaluating us against Gong.\n");
+        ctx.add_key_fact("Team of 50");
+        assert_eq!(
+            ctx.get_full_context(),
+            "Sales call\n\nAbout this call:\nAcme Corp, logistics. Evaluating us against Gong.\n\nKey facts established:\n- Team of 50"
+        );
+
+        // Survives the conversation being cleared, not being emptied
+        ctx.clear();
+        assert_eq!(ctx.brief(), Some("Acme Corp, logistics. Evaluating us against Gong."));
+        ctx.set_brief(" ");
+        assert_eq!(ctx.get_full_context(), "Sales call");
+    }
+
+    #[test]
+    fn test_windowed_context() {
+        let mut ctx = ConversationContext::new(20);
+        ctx.add_their_turn("What does onboarding look like? We have a small team.", None);
+        ctx.add_my_turn("Usually two weeks with a dedicated engineer.");
+        ctx.add_their_turn("And pricing?", None);
+
+        let window = ctx.get_windowed_context(1);
+        assert!(window.starts_with("Earlier in the conversation:"));
+        assert!(window.contains("Them asked: \"What does onboarding look like?\""));
+        assert!(window.contains("Me said:"));
+        assert!(window.ends_with("Them: And pricing?"));
+
+        // Everything fits, so no summary is added
+        assert_eq!(ctx.get_windowed_context(5), ctx.get_history_string());
+    }
+
+    #[test]
+    fn test_summarize_old_turns_is_bounded() {
+        let mut ctx = ConversationContext::new(3);
+        for i in 0..200 {
+            ctx.add_their_turn(format!("Point number {} about the rollout plan.", i), None);
+        }
+
+        assert_eq!(ctx.turn_count(), 3);
+        let summary = ctx.get_summary().unwrap();
+        assert!(summary.len() <= SUMMARY_MAX_CHARS);
+        assert!(summary.contains("Point number 196"));
+        assert!(!summary.contains("Point number 0 "));
+
+        ctx.summarize_old_turns(1);
+        assert_eq!(ctx.turn_count(), 1);
+        assert!(ctx.get_windowed_context(1).contains("Point number 198"));
+    }
+
+    #[test]
+    fn test_model_summary_replaces_notes() {
+        let mut ctx = ConversationContext::new(1);
+        ctx.add_their_turn("We run everything on AWS today.", None);
+        ctx.add_my_turn("Which region?");
+        assert_eq!(ctx.summary_request(2), None);
+        ctx.add_their_turn("Frankfurt, for GDPR reasons.", None);
+
+        let (transcript, upto) = ctx.summary_request(2).unwrap();
+        assert_eq!(transcript, "Them: We run everything on AWS today.\nMe: Which region?");
+        // One request at a time
+        assert_eq!(ctx.summary_request(1), None);
+
+        // A turn dropped while it was out keeps its note
+        ctx.add_my_turn("We have a Frankfurt region too.");
+        ctx.apply_summary(upto, "They host on AWS and asked about regions.");
+        assert_eq!(
+            ctx.get_summary().unwrap(),
+            "They host on AWS and asked about regions. Them said: \"Frankfurt, for GDPR reasons.\""
+        );
+
+        // Offline, the notes stay and the turns are asked for again
+        let (_, upto) = ctx.summary_request(1).unwrap();
+        ctx.summary_failed();
+        assert!(ctx.summary_request(1).is_some());
+
+        ctx.clear();
+        ctx.apply_summary(upto, "Stale");
+        assert_eq!(ctx.get_summary(), None);
+    }
 }
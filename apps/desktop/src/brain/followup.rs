@@ -0,0 +1,252 @@
+//! Follow-up Dates
+//!
+//! Finds when to follow up ("let's reconnect next Tuesday", "send the
+//! proposal by end of week") in deep responses and call summary next
+//! steps, so the lead's next follow-up can be prefilled.
+
+use chrono::{DateTime, Datelike, Days, Local, Months, NaiveDate, TimeZone, Utc, Weekday};
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+static SENTENCE_END: Lazy<Regex> = Lazy::new(|| Regex::new(r"[.!?](?:\s|$)|\n").unwrap());
+static ISO_DATE: Lazy<Regex> = Lazy::new(|| Regex::new(r"\b(\d{4})-(\d{2})-(\d{2})\b").unwrap());
+static MONTH_DAY: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(&format!(r"\b{}\.?\s+(\d{{1,2}})(?:st|nd|rd|th)?\b(?:,?\s+(\d{{4}}))?", MONTH_PATTERN)).unwrap()
+});
+static DAY_MONTH: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(&format!(r"\b(\d{{1,2}})(?:st|nd|rd|th)?\s+(?:of\s+)?{}\b(?:,?\s+(\d{{4}}))?", MONTH_PATTERN)).unwrap()
+});
+static IN_PERIOD: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\bin\s+(\d{1,2}|a|an|one|two|three|four|five|six|seven|eight|nine|ten|a couple of|a couple)\s+(day|week|month)s?\b").unwrap()
+});
+static FOLLOWUP_CUE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\b(follow[- ]?up|check (in|back)|circle back|reconnect|touch base|reach out|get back|call|meet|schedule|send|demo)").unwrap()
+});
+static WEEKDAY: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\b(monday|tuesday|wednesday|thursday|friday|saturday|sunday)\b").unwrap());
+
+/// Month names, longest first so "june" isn't read as "jun"
+const MONTH_PATTERN: &str = r"(january|february|march|april|may|june|july|august|september|october|november|december|sept|jan|feb|mar|apr|jun|jul|aug|sep|oct|nov|dec)";
+
+/// Follow-up suggested in free text (e.g. a deep response), if there's exactly one
+///
+/// Only sentences about the next contact ("follow up", "call", "send"...)
+/// are considered, so unrelated dates in the text are ignored.
+pub fn extract_followup(text: &str, now: DateTime<Local>, hour: u32) -> Option<DateTime<Utc>> {
+    let sentences = SENTENCE_END
+        .split(text)
+        .filter(|s| FOLLOWUP_CUE.is_match(&s.to_lowercase()));
+    at_hour(followup_date(sentences, now.date_naive())?, hour)
+}
+
+/// Follow-up from a call summary's next steps, if they agree on one date
+pub fn followup_from_steps(steps: &[String], now: DateTime<Local>, hour: u32) -> Option<DateTime<Utc>> {
+    at_hour(followup_date(steps.iter().map(String::as_str), now.date_naive())?, hour)
+}
+
+/// Date a task is due ("send it by Friday"), if the text names exactly one
+pub fn due_date(text: &str, today: NaiveDate) -> Option<NaiveDate> {
+    followup_date(std::iter::once(text), today)
+}
+
+/// Local time on a date, in UTC
+fn at_hour(date: NaiveDate, hour: u32) -> Option<DateTime<Utc>> {
+    let local = date.and_hms_opt(hour.min(23), 0, 0)?;
+    Local
+        .from_local_datetime(&local)
+        .earliest()
+        .map(|at| at.with_timezone(&Utc))
+}
+
+/// The single upcoming date the sentences mention; None if none or several
+fn followup_date<'a>(sentences: impl IntoIterator<Item = &'a str>, today: NaiveDate) -> Option<NaiveDate> {
+    let mut dates: Vec<NaiveDate> = sentences
+        .into_iter()
+        .flat_map(|s| dates_in(s, today))
+        .filter(|d| *d >= today)
+        .collect();
+    dates.sort();
+    dates.dedup();
+
+    match dates.as_slice() {
+        [date] => Some(*date),
+        _ => None,
+    }
+}
+
+/// Dates in one sentence; explicit dates win over relative phrases
+fn dates_in(sentence: &str, today: NaiveDate) -> Vec<NaiveDate> {
+    let text = sentence.to_lowercase();
+    let explicit = explicit_dates(&text, today);
+    if !explicit.is_empty() {
+        return explicit;
+    }
+    relative_dates(&text, today)
+}
+
+fn explicit_dates(text: &str, today: NaiveDate) -> Vec<NaiveDate> {
+    let mut dates = Vec::new();
+
+    for caps in ISO_DATE.captures_iter(text) {
+        if let (Ok(y), Ok(m), Ok(d)) = (caps[1].parse(), caps[2].parse(), caps[3].parse()) {
+            dates.extend(NaiveDate::from_ymd_opt(y, m, d));
+        }
+    }
+
+    for caps in MONTH_DAY.captures_iter(text) {
+        dates.extend(month_date(&caps[1], &caps[2], caps.get(3).map(|y| y.as_str()), today));
+    }
+    for caps in DAY_MONTH.captures_iter(text) {
+        dates.extend(month_date(&caps[2], &caps[1], caps.get(3).map(|y| y.as_str()), today));
+    }
+
+    dates
+}
+
+/// "October 20" is this year's, or next year's once it has passed
+fn month_date(month: &str, day: &str, year: Option<&str>, today: NaiveDate) -> Option<NaiveDate> {
+    let month = month_number(month)?;
+    let day: u32 = day.parse().ok()?;
+    if let Some(year) = year {
+        return NaiveDate::from_ymd_opt(year.parse().ok()?, month, day);
+    }
+
+    let date = NaiveDate::from_ymd_opt(today.year(), month, day)?;
+    if date >= today {
+        Some(date)
+    } else {
+        NaiveDate::from_ymd_opt(today.year() + 1, month, day)
+    }
+}
+
+fn month_number(name: &str) -> Option<u32> {
+    let index = [
+        "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+    ]
+    .iter()
+    .position(|prefix| name.starts_with(prefix))?;
+    Some(index as u32 + 1)
+}
+
+fn relative_dates(text: &str, today: NaiveDate) -> Vec<NaiveDate> {
+    let mut dates = Vec::new();
+
+    if text.contains("day after tomorrow") {
+        dates.extend(today.checked_add_days(Days::new(2)));
+    } else if text.contains("tomorrow") {
+        dates.extend(today.succ_opt());
+    } else if text.contains("today") || text.contains("end of day") {
+        dates.push(today);
+    }
+
+    if text.contains("end of the week") || text.contains("end of week") {
+        dates.push(next_weekday(today, Weekday::Fri, true));
+    }
+    if text.contains("end of the month") || text.contains("end of month") {
+        dates.extend(last_of_month(today));
+    }
+    if text.contains("next week") {
+        dates.push(next_weekday(today, Weekday::Mon, false));
+    }
+    if text.contains("next month") {
+        dates.extend(today.with_day(1).and_then(|d| d.checked_add_months(Months::new(1))));
+    }
+
+    for caps in IN_PERIOD.captures_iter(text) {
+        let count: u32 = match &caps[1] {
+            "a" | "an" | "one" => 1,
+            "a couple" | "a couple of" | "two" => 2,
+            "three" => 3,
+            "four" => 4,
+            "five" => 5,
+            "six" => 6,
+            "seven" => 7,
+            "eight" => 8,
+            "nine" => 9,
+            "ten" => 10,
+            n => n.parse().unwrap_or(0),
+        };
+        let date = match &caps[2] {
+            "day" => today.checked_add_days(Days::new(count as u64)),
+            "week" => today.checked_add_days(Days::new(7 * count as u64)),
+            _ => today.checked_add_months(Months::new(count)),
+        };
+        dates.extend(date);
+    }
+
+    for caps in WEEKDAY.captures_iter(text) {
+        if let Ok(weekday) = caps[1].parse::<Weekday>() {
+            dates.push(next_weekday(today, weekday, false));
+        }
+    }
+
+    dates
+}
+
+/// Next date falling on a weekday (today counts only if `inclusive`)
+fn next_weekday(today: NaiveDate, weekday: Weekday, inclusive: bool) -> NaiveDate {
+    let ahead = (weekday.num_days_from_monday() + 7 - today.weekday().num_days_from_monday()) % 7;
+    let ahead = if ahead == 0 && !inclusive { 7 } else { ahead };
+    today + chrono::Duration::days(ahead as i64)
+}
+
+fn last_of_month(today: NaiveDate) -> Option<NaiveDate> {
+    today
+        .with_day(1)?
+        .checked_add_months(Months::new(1))?
+        .pred_opt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Timelike;
+
+    // Wednesday
+    fn today() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 10, 14).unwrap()
+    }
+
+    fn date(m: u32, d: u32) -> Option<NaiveDate> {
+        NaiveDate::from_ymd_opt(2026, m, d)
+    }
+
+    #[test]
+    fn test_relative_dates() {
+        let cases = [
+            ("Follow up next Tuesday.", date(10, 20)),
+            ("Send the proposal by end of week", date(10, 16)),
+            ("Check in again in 2 weeks", date(10, 28)),
+            ("Reconnect in a couple of days", date(10, 16)),
+            ("Call them tomorrow", date(10, 15)),
+            ("Schedule a demo next week", date(10, 19)),
+            ("Follow up at the end of the month", date(10, 31)),
+        ];
+        for (text, expected) in cases {
+            assert_eq!(followup_date([text], today()), expected, "{}", text);
+        }
+    }
+
+    #[test]
+    fn test_explicit_and_ambiguous_dates() {
+        assert_eq!(followup_date(["Follow up on October 20th"], today()), date(10, 20));
+        assert_eq!(followup_date(["Send contract by 2026-11-02"], today()), date(11, 2));
+        // An explicit date wins over the weekday in the same sentence
+        assert_eq!(followup_date(["Meet Tuesday, Oct 20"], today()), date(10, 20));
+        // Early-year dates roll over to next year
+        assert_eq!(followup_date(["Call on 3 March"], today()), NaiveDate::from_ymd_opt(2027, 3, 3));
+
+        assert_eq!(followup_date(["Call Monday or Friday"], today()), None);
+        assert_eq!(followup_date(["Send a recap", "Book the demo in 2 weeks", "Call next Friday"], today()), None);
+        assert_eq!(followup_date(["Send pricing soon"], today()), None);
+    }
+
+    #[test]
+    fn test_extract_followup_uses_cue_sentences() {
+        let now = Local.from_local_datetime(&today().and_hms_opt(15, 0, 0).unwrap()).unwrap();
+        let text = "Their budget resets on Monday. Suggest you follow up on Friday with the pilot plan.";
+        let at = extract_followup(text, now, 9).unwrap().with_timezone(&Local);
+        assert_eq!(at.date_naive(), date(10, 16).unwrap());
+        assert_eq!(at.hour(), 9);
+    }
+}
@@ -2,7 +2,7 @@
 //!
 //! Intelligently routes requests between local and cloud AI:
 //! - Local LLM (Ollama): Fast responses, simple queries
-//! - Cloud AI (OpenAI/Anthropic/Gemini): Complex reasoning, nuanced responses
+//! - Cloud AI (OpenAI/Anthropic/Gemini/Groq): Complex reasoning, nuanced responses
 //!
 //! Provides the best of both worlds:
 //! - Speed when you need it (local)
@@ -10,10 +10,14 @@
 //! - Cost savings by using local when appropriate
 
 use anyhow::Result;
+use parking_lot::RwLock;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
 use std::time::Duration;
 
-use crate::flash::{FlashAnalysis, GeminiFlash, GPT4oMini, OllamaFlash};
+use crate::cost::CostTracker;
+use crate::flash::{ClaudeFlash, FlashAnalysis, FlashProvider, GeminiFlash, GPT4oMini, GroqFlash, OllamaFlash, DEFAULT_GROQ_MODEL};
 
 /// Query complexity level
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
@@ -28,44 +32,66 @@ pub enum Complexity {
     Critical,
 }
 
+/// Keyword dictionary for complexity detection
+///
+/// Users can add domain jargon (medical, legal, ...) that should route to
+/// the cloud model.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ComplexityConfig {
+    /// Each match adds 2 to the complexity score
+    pub complex_keywords: Vec<String>,
+    /// Each match subtracts 1
+    pub simple_keywords: Vec<String>,
+    /// Queries longer than this many words score +1 (twice as long: +2)
+    pub long_word_threshold: usize,
+}
+
+impl Default for ComplexityConfig {
+    fn default() -> Self {
+        let to_strings = |words: &[&str]| words.iter().map(|w| w.to_string()).collect();
+
+        Self {
+            complex_keywords: to_strings(&[
+                "why", "explain", "compare", "analyze", "evaluate", "justify",
+                "critique", "strategy", "negotiate", "convince", "objection",
+                "budget", "decision", "stakeholder", "executive", "contract",
+                "legal", "compliance", "security", "architecture", "scale",
+            ]),
+            simple_keywords: to_strings(&[
+                "what is", "how do", "when", "where", "who", "list",
+                "define", "describe", "tell me about", "features",
+            ]),
+            long_word_threshold: 15,
+        }
+    }
+}
+
 impl Complexity {
-    pub fn from_text(text: &str) -> Self {
+    pub fn from_text(text: &str, config: &ComplexityConfig) -> Self {
         let text_lower = text.to_lowercase();
         let word_count = text.split_whitespace().count();
 
-        // Indicators of complexity
-        let complex_keywords = [
-            "why", "explain", "compare", "analyze", "evaluate", "justify",
-            "critique", "strategy", "negotiate", "convince", "objection",
-            "budget", "decision", "stakeholder", "executive", "contract",
-            "legal", "compliance", "security", "architecture", "scale",
-        ];
-
-        let simple_keywords = [
-            "what is", "how do", "when", "where", "who", "list",
-            "define", "describe", "tell me about", "features",
-        ];
-
         let mut complexity_score = 0;
 
         // Check for complex patterns
-        for keyword in &complex_keywords {
-            if text_lower.contains(keyword) {
+        for keyword in &config.complex_keywords {
+            if text_lower.contains(&keyword.to_lowercase()) {
                 complexity_score += 2;
             }
         }
 
         // Check for simple patterns
-        for keyword in &simple_keywords {
-            if text_lower.contains(keyword) {
+        for keyword in &config.simple_keywords {
+            if text_lower.contains(&keyword.to_lowercase()) {
                 complexity_score -= 1;
             }
         }
 
         // Long queries tend to be more complex
-        if word_count > 30 {
+        if word_count > config.long_word_threshold * 2 {
             complexity_score += 2;
-        } else if word_count > 15 {
+        } else if word_count > config.long_word_threshold {
             complexity_score += 1;
         }
 
@@ -85,33 +111,13 @@ impl Complexity {
     }
 }
 
-/// AI provider selection
-#[derive(Debug, Clone, PartialEq)]
-pub enum AIProvider {
-    /// Local Ollama (Llama, Mistral, etc.)
-    Local(String), // model name
-    /// OpenAI (GPT-4o, GPT-4o-mini)
-    OpenAI(String),
-    /// Anthropic (Claude)
-    Anthropic(String),
-    /// Google (Gemini)
-    Google(String),
-}
-
-impl AIProvider {
-    pub fn name(&self) -> &str {
-        match self {
-            AIProvider::Local(m) => m,
-            AIProvider::OpenAI(m) => m,
-            AIProvider::Anthropic(m) => m,
-            AIProvider::Google(m) => m,
-        }
-    }
-
-    pub fn is_local(&self) -> bool {
-        matches!(self, AIProvider::Local(_))
-    }
-}
+/// Registry key of the local Ollama provider
+pub const LOCAL_PROVIDER: &str = "local";
+/// Registry keys of the built-in cloud providers
+pub const OPENAI_PROVIDER: &str = "openai";
+pub const ANTHROPIC_PROVIDER: &str = "anthropic";
+pub const GOOGLE_PROVIDER: &str = "google";
+pub const GROQ_PROVIDER: &str = "groq";
 
 /// Routing strategy
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -129,6 +135,8 @@ pub enum RoutingStrategy {
     SpeedFirst,
     /// Quality priority (always use best model)
     QualityFirst,
+    /// Cloud until today's spend reaches the budget, then local
+    CostAware { daily_budget_usd: f64 },
 }
 
 /// Hybrid router configuration
@@ -144,11 +152,13 @@ pub struct HybridRouterConfig {
     pub openai_model: String,
     pub anthropic_model: String,
     pub google_model: String,
+    pub groq_model: String,
 
     /// API keys
     pub openai_key: Option<String>,
     pub anthropic_key: Option<String>,
     pub google_key: Option<String>,
+    pub groq_key: Option<String>,
 
     /// Timeout for local (short for fast fallback)
     pub local_timeout: Duration,
@@ -158,6 +168,9 @@ pub struct HybridRouterConfig {
 
     /// Prefer local for these modes
     pub prefer_local_modes: Vec<String>,
+
+    /// Keywords used to judge query complexity
+    pub complexity: ComplexityConfig,
 }
 
 impl Default for HybridRouterConfig {
@@ -168,12 +181,15 @@ impl Default for HybridRouterConfig {
             openai_model: "gpt-4o-mini".to_string(),
             anthropic_model: "claude-3-5-sonnet-20241022".to_string(),
             google_model: "gemini-2.0-flash-exp".to_string(),
+            groq_model: DEFAULT_GROQ_MODEL.to_string(),
             openai_key: None,
             anthropic_key: None,
             google_key: None,
+            groq_key: None,
             local_timeout: Duration::from_secs(5),
             cloud_threshold: Complexity::Moderate,
             prefer_local_modes: vec!["technical".to_string()],
+            complexity: ComplexityConfig::default(),
         }
     }
 }
@@ -182,14 +198,100 @@ impl Default for HybridRouterConfig {
 pub struct HybridRouter {
     config: HybridRouterConfig,
     local_available: bool,
+    /// Spend so far, consulted by `RoutingStrategy::CostAware`
+    cost: Option<Arc<RwLock<CostTracker>>>,
+    /// Flash backends by registry key
+    providers: HashMap<String, Box<dyn FlashProvider>>,
+    /// Cloud keys tried by `best_cloud_provider`, most preferred first
+    cloud_order: Vec<String>,
 }
 
 impl HybridRouter {
     pub fn new(config: HybridRouterConfig) -> Self {
+        let mut providers: HashMap<String, Box<dyn FlashProvider>> = HashMap::new();
+        providers.insert(
+            LOCAL_PROVIDER.to_string(),
+            Box::new(OllamaFlash::new().with_model(&config.local_model)),
+        );
+        if let Some(key) = &config.google_key {
+            providers.insert(
+                GOOGLE_PROVIDER.to_string(),
+                Box::new(GeminiFlash::new(key.clone()).with_model(config.google_model.clone())),
+            );
+        }
+        if let Some(key) = &config.openai_key {
+            providers.insert(OPENAI_PROVIDER.to_string(), Box::new(GPT4oMini::new(key.clone())));
+        }
+        if let Some(key) = &config.anthropic_key {
+            providers.insert(
+                ANTHROPIC_PROVIDER.to_string(),
+                Box::new(ClaudeFlash::new(key.clone()).with_model(config.anthropic_model.clone())),
+            );
+        }
+        if let Some(key) = &config.groq_key {
+            providers.insert(
+                GROQ_PROVIDER.to_string(),
+                Box::new(GroqFlash::new(key.clone()).with_model(config.groq_model.clone())),
+            );
+        }
+
+        // Prefer Gemini Flash for speed, Claude for quality
+        let cloud_order = [GOOGLE_PROVIDER, OPENAI_PROVIDER, ANTHROPIC_PROVIDER]
+            .iter()
+            .map(|k| k.to_string())
+            .collect();
+
         Self {
             config,
             local_available: false,
+            cost: None,
+            providers,
+            cloud_order,
+        }
+    }
+
+    /// Track spend for cost-aware routing
+    pub fn with_cost_tracker(mut self, cost: Arc<RwLock<CostTracker>>) -> Self {
+        self.cost = Some(cost);
+        self
+    }
+
+    /// Add or replace a provider (e.g. OpenRouter or Azure)
+    ///
+    /// New cloud providers are tried after the built-in ones when picking
+    /// the best cloud provider.
+    pub fn register(&mut self, key: impl Into<String>, provider: Box<dyn FlashProvider>) {
+        let key = key.into();
+        if !provider.is_local() && !self.cloud_order.contains(&key) {
+            self.cloud_order.push(key.clone());
         }
+        self.providers.insert(key, provider);
+    }
+
+    fn has(&self, key: &str) -> bool {
+        self.providers.contains_key(key)
+    }
+
+    /// Whether a registry key refers to a local model
+    pub fn is_local(&self, key: &str) -> bool {
+        self.providers
+            .get(key)
+            .map_or(key == LOCAL_PROVIDER, |p| p.is_local())
+    }
+
+    /// Model name behind a registry key
+    pub fn provider_name(&self, key: &str) -> String {
+        self.providers
+            .get(key)
+            .map_or_else(|| key.to_string(), |p| p.name().to_string())
+    }
+
+    /// Whether today's spend has reached the budget
+    fn budget_reached(&self, daily_budget_usd: f64) -> bool {
+        self.cost
+            .as_ref()
+            .map(|cost| cost.read().today_cost_usd() >= daily_budget_usd)
+            .unwrap_or(false)
     }
 
     /// Check if local Ollama is available
@@ -199,13 +301,13 @@ impl HybridRouter {
         self.local_available
     }
 
-    /// Determine which provider to use
-    pub fn select_provider(&self, text: &str, mode: &str) -> AIProvider {
-        let complexity = Complexity::from_text(text);
+    /// Determine which provider to use, as a registry key
+    pub fn select_provider(&self, text: &str, mode: &str) -> String {
+        let complexity = Complexity::from_text(text, &self.config.complexity);
 
         match self.config.strategy {
             RoutingStrategy::AlwaysLocal => {
-                AIProvider::Local(self.config.local_model.clone())
+                LOCAL_PROVIDER.to_string()
             }
 
             RoutingStrategy::AlwaysCloud => {
@@ -216,7 +318,7 @@ impl HybridRouter {
                 // Use local for simple/moderate, cloud for complex/critical
                 if complexity < self.config.cloud_threshold {
                     if self.local_available {
-                        AIProvider::Local(self.config.local_model.clone())
+                        LOCAL_PROVIDER.to_string()
                     } else {
                         self.best_cloud_provider()
                     }
@@ -227,136 +329,113 @@ impl HybridRouter {
 
             RoutingStrategy::LocalWithFallback => {
                 if self.local_available {
-                    AIProvider::Local(self.config.local_model.clone())
+                    LOCAL_PROVIDER.to_string()
                 } else {
                     self.best_cloud_provider()
                 }
             }
 
             RoutingStrategy::SpeedFirst => {
-                // Local is usually faster
-                if self.local_available {
-                    AIProvider::Local(self.config.local_model.clone())
-                } else if self.config.google_key.is_some() {
+                // Groq beats even local inference on most machines
+                if self.has(GROQ_PROVIDER) {
+                    GROQ_PROVIDER.to_string()
+                } else if self.local_available {
+                    LOCAL_PROVIDER.to_string()
+                } else if self.has(GOOGLE_PROVIDER) {
                     // Gemini Flash is fast
-                    AIProvider::Google(self.config.google_model.clone())
-                } else if self.config.openai_key.is_some() {
-                    AIProvider::OpenAI("gpt-4o-mini".to_string())
+                    GOOGLE_PROVIDER.to_string()
+                } else if self.has(OPENAI_PROVIDER) {
+                    OPENAI_PROVIDER.to_string()
                 } else {
-                    AIProvider::Anthropic(self.config.anthropic_model.clone())
+                    ANTHROPIC_PROVIDER.to_string()
                 }
             }
 
             RoutingStrategy::QualityFirst => {
                 // Claude > GPT-4o > Gemini > Local
-                if self.config.anthropic_key.is_some() {
-                    AIProvider::Anthropic(self.config.anthropic_model.clone())
-                } else if self.config.openai_key.is_some() {
-                    AIProvider::OpenAI("gpt-4o".to_string())
-                } else if self.config.google_key.is_some() {
-                    AIProvider::Google(self.config.google_model.clone())
+                if self.has(ANTHROPIC_PROVIDER) {
+                    ANTHROPIC_PROVIDER.to_string()
+                } else if self.has(OPENAI_PROVIDER) {
+                    OPENAI_PROVIDER.to_string()
+                } else if self.has(GOOGLE_PROVIDER) {
+                    GOOGLE_PROVIDER.to_string()
                 } else {
-                    AIProvider::Local(self.config.local_model.clone())
+                    LOCAL_PROVIDER.to_string()
+                }
+            }
+
+            RoutingStrategy::CostAware { daily_budget_usd } => {
+                if self.budget_reached(daily_budget_usd) {
+                    LOCAL_PROVIDER.to_string()
+                } else {
+                    self.best_cloud_provider()
                 }
             }
         }
     }
 
     /// Get best available cloud provider
-    fn best_cloud_provider(&self) -> AIProvider {
-        // Prefer Gemini Flash for speed, Claude for quality
-        if self.config.google_key.is_some() {
-            AIProvider::Google(self.config.google_model.clone())
-        } else if self.config.openai_key.is_some() {
-            AIProvider::OpenAI(self.config.openai_model.clone())
-        } else if self.config.anthropic_key.is_some() {
-            AIProvider::Anthropic(self.config.anthropic_model.clone())
-        } else {
+    fn best_cloud_provider(&self) -> String {
+        self.cloud_order
+            .iter()
+            .find(|key| self.has(key))
+            .cloned()
             // Fallback to local if no cloud keys
-            AIProvider::Local(self.config.local_model.clone())
-        }
+            .unwrap_or_else(|| LOCAL_PROVIDER.to_string())
     }
 
     /// Run flash analysis with hybrid routing
-    pub async fn analyze_flash(&self, transcript: &str, context: &str) -> Result<(FlashAnalysis, AIProvider)> {
-        let provider = self.select_provider(transcript, context);
+    ///
+    /// Returns the analysis and the registry key of the provider used.
+    pub async fn analyze_flash(&self, transcript: &str, context: &str) -> Result<(FlashAnalysis, String)> {
+        let key = self.select_provider(transcript, context);
 
         tracing::info!("Routing to {:?} (complexity: {:?})",
-            provider.name(),
-            Complexity::from_text(transcript)
+            self.provider_name(&key),
+            Complexity::from_text(transcript, &self.config.complexity)
         );
 
-        let result = match &provider {
-            AIProvider::Local(model) => {
-                let client = OllamaFlash::new().with_model(model.clone());
-                client.analyze(transcript, context).await
-            }
-            AIProvider::Google(model) => {
-                let key = self.config.google_key.as_ref().ok_or_else(|| anyhow::anyhow!("No Google key"))?;
-                let client = GeminiFlash::new(key.clone()).with_model(model.clone());
-                client.analyze(transcript, context).await
-            }
-            AIProvider::OpenAI(_model) => {
-                let key = self.config.openai_key.as_ref().ok_or_else(|| anyhow::anyhow!("No OpenAI key"))?;
-                let client = GPT4oMini::new(key.clone());
-                client.analyze(transcript, context).await
-            }
-            AIProvider::Anthropic(_model) => {
-                // Use OpenAI as fallback since we don't have Anthropic flash implementation
-                let key = self.config.openai_key.as_ref()
-                    .or(self.config.google_key.as_ref())
-                    .ok_or_else(|| anyhow::anyhow!("No fallback key"))?;
-
-                if self.config.google_key.is_some() {
-                    let client = GeminiFlash::new(key.clone());
-                    client.analyze(transcript, context).await
-                } else {
-                    let client = GPT4oMini::new(key.clone());
-                    client.analyze(transcript, context).await
-                }
-            }
-        };
+        let result = self.analyze_with_provider(transcript, context, &key).await;
 
         // On error with local, try cloud fallback
-        if result.is_err() && provider.is_local() && matches!(self.config.strategy, RoutingStrategy::LocalWithFallback | RoutingStrategy::Smart) {
+        if result.is_err() && self.is_local(&key) && matches!(self.config.strategy, RoutingStrategy::LocalWithFallback | RoutingStrategy::Smart) {
             tracing::warn!("Local failed, falling back to cloud");
-            let cloud_provider = self.best_cloud_provider();
-            if !cloud_provider.is_local() {
-                return self.analyze_with_provider(transcript, context, &cloud_provider).await;
+            let cloud_key = self.best_cloud_provider();
+            if !self.is_local(&cloud_key) {
+                return self.analyze_with_provider(transcript, context, &cloud_key).await;
             }
         }
 
-        Ok((result?, provider))
+        result
     }
 
-    /// Analyze with specific provider
-    async fn analyze_with_provider(&self, transcript: &str, context: &str, provider: &AIProvider) -> Result<(FlashAnalysis, AIProvider)> {
-        let result = match provider {
-            AIProvider::Google(model) => {
-                let key = self.config.google_key.as_ref().ok_or_else(|| anyhow::anyhow!("No Google key"))?;
-                let client = GeminiFlash::new(key.clone()).with_model(model.clone());
-                client.analyze(transcript, context).await?
-            }
-            AIProvider::OpenAI(_) => {
-                let key = self.config.openai_key.as_ref().ok_or_else(|| anyhow::anyhow!("No OpenAI key"))?;
-                let client = GPT4oMini::new(key.clone());
-                client.analyze(transcript, context).await?
-            }
-            _ => return Err(anyhow::anyhow!("Provider not supported for fallback")),
+    /// Analyze with a specific provider
+    ///
+    /// A selected provider that isn't configured falls back to the best
+    /// configured cloud provider.
+    async fn analyze_with_provider(&self, transcript: &str, context: &str, key: &str) -> Result<(FlashAnalysis, String)> {
+        let (key, provider) = match self.providers.get(key) {
+            Some(provider) => (key.to_string(), provider),
+            None => self
+                .cloud_order
+                .iter()
+                .find_map(|k| self.providers.get(k).map(|p| (k.clone(), p)))
+                .ok_or_else(|| anyhow::anyhow!("No {} key and no fallback provider", key))?,
         };
 
-        Ok((result, provider.clone()))
+        let analysis = provider.analyze(transcript, context).await?;
+        Ok((analysis, key))
     }
 
     /// Get routing explanation for UI
     pub fn explain_routing(&self, text: &str) -> RoutingExplanation {
-        let complexity = Complexity::from_text(text);
-        let provider = self.select_provider(text, "");
+        let complexity = Complexity::from_text(text, &self.config.complexity);
+        let key = self.select_provider(text, "");
 
         RoutingExplanation {
             complexity,
-            provider_name: provider.name().to_string(),
-            is_local: provider.is_local(),
+            provider_name: self.provider_name(&key),
+            is_local: self.is_local(&key),
             reason: match (&self.config.strategy, &complexity) {
                 (RoutingStrategy::AlwaysLocal, _) => "Using local (always local mode)".to_string(),
                 (RoutingStrategy::AlwaysCloud, _) => "Using cloud (always cloud mode)".to_string(),
@@ -366,6 +445,14 @@ impl HybridRouter {
                 (RoutingStrategy::Smart, Complexity::Critical) => "Using cloud (critical - high accuracy)".to_string(),
                 (RoutingStrategy::SpeedFirst, _) => "Using fastest available".to_string(),
                 (RoutingStrategy::QualityFirst, _) => "Using highest quality".to_string(),
+                (RoutingStrategy::CostAware { daily_budget_usd }, _) => {
+                    if self.budget_reached(*daily_budget_usd) {
+                        "Switched to local: daily budget reached".to_string()
+                    } else {
+                        let spent = self.cost.as_ref().map(|c| c.read().today_cost_usd()).unwrap_or(0.0);
+                        format!("Using cloud (${:.2} of ${:.2} daily budget)", spent, daily_budget_usd)
+                    }
+                }
                 _ => "Auto-selected".to_string(),
             },
         }
@@ -384,20 +471,31 @@ pub struct RoutingExplanation {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::flash::{StatementType, Urgency};
 
     #[test]
     fn test_complexity_simple() {
         let text = "What is the price?";
-        assert_eq!(Complexity::from_text(text), Complexity::Simple);
+        assert_eq!(Complexity::from_text(text, &ComplexityConfig::default()), Complexity::Simple);
     }
 
     #[test]
     fn test_complexity_complex() {
         let text = "Can you explain why your enterprise solution would be better for our compliance and security requirements compared to the competition, and how would you justify the budget to our stakeholders?";
-        let complexity = Complexity::from_text(text);
+        let complexity = Complexity::from_text(text, &ComplexityConfig::default());
         assert!(complexity >= Complexity::Complex);
     }
 
+    #[test]
+    fn test_custom_complexity_keywords() {
+        let text = "Is the tachycardia related to the dosage?";
+        let mut config = ComplexityConfig::default();
+        assert_eq!(Complexity::from_text(text, &config), Complexity::Simple);
+
+        config.complex_keywords.extend(["Tachycardia".to_string(), "dosage".to_string()]);
+        assert!(Complexity::from_text(text, &config) >= Complexity::Complex);
+    }
+
     #[test]
     fn test_smart_routing() {
         let config = HybridRouterConfig {
@@ -409,13 +507,90 @@ mod tests {
 
         // Simple should go local
         let provider = router.select_provider("What is the price?", "sales");
-        assert!(provider.is_local());
+        assert!(router.is_local(&provider));
 
         // Complex should go cloud
         let provider = router.select_provider(
             "Explain why this architecture would scale better and justify the budget",
             "sales"
         );
-        assert!(!provider.is_local());
+        assert!(!router.is_local(&provider));
+    }
+
+    #[test]
+    fn test_speed_first_prefers_groq() {
+        let config = HybridRouterConfig {
+            strategy: RoutingStrategy::SpeedFirst,
+            google_key: Some("test".to_string()),
+            ..Default::default()
+        };
+        let mut router = HybridRouter::new(config.clone());
+        router.local_available = true;
+        assert_eq!(router.select_provider("What is the price?", "sales"), LOCAL_PROVIDER);
+
+        let mut router = HybridRouter::new(HybridRouterConfig {
+            groq_key: Some("test".to_string()),
+            ..config
+        });
+        router.local_available = true;
+        assert_eq!(
+            router.select_provider("What is the price?", "sales"),
+            GROQ_PROVIDER
+        );
+        assert_eq!(router.provider_name(GROQ_PROVIDER), DEFAULT_GROQ_MODEL);
+    }
+
+    #[test]
+    fn test_cost_aware_routing() {
+        use crate::cost::TokenUsage;
+
+        let cost = Arc::new(RwLock::new(CostTracker::new()));
+        let config = HybridRouterConfig {
+            strategy: RoutingStrategy::CostAware { daily_budget_usd: 0.01 },
+            openai_key: Some("test".to_string()),
+            ..Default::default()
+        };
+        let router = HybridRouter::new(config).with_cost_tracker(cost.clone());
+
+        assert_eq!(router.select_provider("What is the price?", "sales"), OPENAI_PROVIDER);
+
+        cost.write().record("gpt-4o", TokenUsage::new(2000, 1000));
+        let explanation = router.explain_routing("What is the price?");
+        assert!(explanation.is_local);
+        assert_eq!(explanation.reason, "Switched to local: daily budget reached");
+    }
+
+    struct MockProvider;
+
+    #[async_trait::async_trait]
+    impl FlashProvider for MockProvider {
+        async fn analyze(&self, transcript: &str, _context: &str) -> Result<FlashAnalysis, crate::error::FlashError> {
+            Ok(FlashAnalysis {
+                summary: format!("Mock: {}", transcript),
+                bullets: Vec::new(),
+                statement_type: StatementType::Question,
+                urgency: Urgency::AnswerNow,
+                usage: None,
+            })
+        }
+
+        fn name(&self) -> &str {
+            "mock-model"
+        }
+    }
+
+    #[tokio::test]
+    async fn test_registered_provider() {
+        let mut router = HybridRouter::new(HybridRouterConfig {
+            strategy: RoutingStrategy::AlwaysCloud,
+            ..Default::default()
+        });
+        assert_eq!(router.select_provider("What is the price?", "sales"), LOCAL_PROVIDER);
+
+        router.register("mock", Box::new(MockProvider));
+        let (analysis, key) = router.analyze_flash("What is the price?", "sales").await.unwrap();
+        assert_eq!(key, "mock");
+        assert_eq!(analysis.summary, "Mock: What is the price?");
+        assert_eq!(router.explain_routing("What is the price?").provider_name, "mock-model");
     }
 }
@@ -2,19 +2,58 @@
 //!
 //! Analyzes transcripts to detect buyer intent, objections, and signals.
 
+use std::cmp::Ordering;
+
 use crate::flash::StatementType;
 
+/// Keywords this short must match a whole word ("api" not in "rapid")
+const SHORT_KEYWORD_LEN: usize = 3;
+
+/// Keyword score at which confidence reaches ~63%
+const CONFIDENCE_SCALE: f32 = 3.0;
+
+/// Highest confidence a keyword match can claim
+const MAX_CONFIDENCE: f32 = 0.95;
+
+/// Score for naming a configured competitor, like a keyword unique to one category
+const COMPETITOR_WEIGHT: f32 = 2.3;
+
+/// Secondary categories below this are keyword noise, not a real concern
+const MULTI_MIN_CONFIDENCE: f32 = 0.1;
+
+/// Openings that make a turn a question even without a question mark
+const QUESTION_STARTS: &[&str] = &[
+    "what", "how", "why", "when", "where", "who", "which", "can", "could", "do",
+    "does", "is", "are", "will", "would", "should",
+];
+
+/// Pleasantries with no concern in them
+const SMALL_TALK: &[&str] = &[
+    "how are you", "how's it going", "good morning", "good afternoon", "nice to meet",
+    "weekend", "weather", "thanks for having", "can you hear me",
+];
+
+/// Generic words that say little about intent on their own
+const COMMON_WORDS: &[&str] = &[
+    "a", "about", "back", "by", "can", "do", "does", "else", "from", "get", "good",
+    "how", "i'm", "in", "it", "let's", "long", "me", "much", "not", "of", "ready",
+    "so", "soon", "sounds", "sure", "the", "to", "use", "we", "what", "when", "who",
+    "why", "with", "you",
+];
+
 /// Detected intent from analysis
 #[derive(Debug, Clone)]
 pub struct DetectedIntent {
     /// Primary intent category
     pub category: IntentCategory,
-    /// Confidence score (0.0 to 1.0)
+    /// Confidence score (0.0 to 1.0), relative to the other matched categories
     pub confidence: f32,
     /// Whether this needs immediate response
     pub needs_response: bool,
     /// Specific keywords that triggered detection
     pub triggers: Vec<String>,
+    /// Configured competitor they named (Competition only)
+    pub competitor: Option<String>,
 }
 
 /// Categories of intent
@@ -91,8 +130,10 @@ impl IntentCategory {
 
 /// Analyzes text to detect intent
 pub struct IntentAnalyzer {
-    /// Keyword patterns for each intent category
-    patterns: Vec<(IntentCategory, Vec<&'static str>)>,
+    /// Weighted keyword patterns for each intent category
+    patterns: Vec<(IntentCategory, Vec<(&'static str, f32)>)>,
+    /// Competitor names to listen for, as the user spells them
+    competitors: Vec<String>,
 }
 
 impl Default for IntentAnalyzer {
@@ -103,101 +144,234 @@ impl Default for IntentAnalyzer {
 
 impl IntentAnalyzer {
     pub fn new() -> Self {
+        Self::with_patterns(vec![
+            (IntentCategory::Pricing, vec![
+                "how much", "cost", "price", "pricing", "budget", "expensive",
+                "afford", "discount", "payment", "subscription", "per user",
+                "per seat", "annual", "monthly", "fee", "charge",
+            ]),
+            (IntentCategory::Security, vec![
+                "security", "secure", "soc2", "soc 2", "gdpr", "hipaa", "compliance",
+                "compliant", "data protection", "encryption", "privacy", "audit",
+                "penetration test", "vulnerability", "certification",
+            ]),
+            (IntentCategory::Timeline, vec![
+                "how long", "timeline", "when can", "how soon", "implementation",
+                "onboarding", "setup time", "go live", "deploy", "migrate",
+                "transition", "deadline", "by when", "urgent",
+            ]),
+            (IntentCategory::Competition, vec![
+                "compared to", "vs", "versus", "competitor", "alternative",
+                "different from", "better than", "why not use", "already using",
+                "switch from", "salesforce", "hubspot", "zendesk", // Add common competitors
+            ]),
+            (IntentCategory::Technical, vec![
+                "integrate", "integration", "api", "sdk", "webhook", "technical",
+                "architecture", "scalability", "performance", "uptime", "sla",
+                "latency", "database", "infrastructure", "stack",
+            ]),
+            (IntentCategory::BuyingSignal, vec![
+                "next steps", "how do we start", "get started", "sign up",
+                "contract", "agreement", "pilot", "trial", "proof of concept",
+                "let's do it", "sounds good", "i'm interested", "move forward",
+                "ready to", "when can we",
+            ]),
+            (IntentCategory::Objection, vec![
+                "too expensive", "not sure", "concern", "worried", "hesitant",
+                "don't think", "not convinced", "problem with", "issue with",
+                "can't", "won't work", "doesn't fit", "not ready",
+            ]),
+            (IntentCategory::Stalling, vec![
+                "think about it", "get back to you", "send me info", "email me",
+                "send a proposal", "need to discuss", "talk to my team",
+                "check internally", "not the right time", "maybe later",
+                "circle back", "follow up",
+            ]),
+            (IntentCategory::Procurement, vec![
+                "who else", "decision maker", "sign off", "approval", "procurement",
+                "purchasing", "legal review", "it review", "security review",
+                "vendor", "rfp", "rfi", "evaluation", "committee",
+            ]),
+        ])
+    }
+
+    /// Weigh each keyword by how specific it is
+    ///
+    /// Keywords shared with other categories (or contained in their longer
+    /// phrases) get an IDF-style discount, so "expensive" counts for less
+    /// than "gdpr".
+    fn with_patterns(patterns: Vec<(IntentCategory, Vec<&'static str>)>) -> Self {
+        let categories = patterns.len() as f32;
+        let weighted = patterns
+            .iter()
+            .map(|(category, keywords)| {
+                let weights = keywords
+                    .iter()
+                    .map(|keyword| {
+                        let df = patterns
+                            .iter()
+                            .filter(|(_, other)| other.iter().any(|o| o.contains(keyword)))
+                            .count()
+                            .max(1);
+                        let idf = (1.0 + categories / df as f32).ln();
+                        (*keyword, idf * specificity(keyword))
+                    })
+                    .collect();
+                (category.clone(), weights)
+            })
+            .collect();
+
         Self {
-            patterns: vec![
-                (IntentCategory::Pricing, vec![
-                    "how much", "cost", "price", "pricing", "budget", "expensive",
-                    "afford", "discount", "payment", "subscription", "per user",
-                    "per seat", "annual", "monthly", "fee", "charge",
-                ]),
-                (IntentCategory::Security, vec![
-                    "security", "secure", "soc2", "soc 2", "gdpr", "hipaa", "compliance",
-                    "compliant", "data protection", "encryption", "privacy", "audit",
-                    "penetration test", "vulnerability", "certification",
-                ]),
-                (IntentCategory::Timeline, vec![
-                    "how long", "timeline", "when can", "how soon", "implementation",
-                    "onboarding", "setup time", "go live", "deploy", "migrate",
-                    "transition", "deadline", "by when", "urgent",
-                ]),
-                (IntentCategory::Competition, vec![
-                    "compared to", "vs", "versus", "competitor", "alternative",
-                    "different from", "better than", "why not use", "already using",
-                    "switch from", "salesforce", "hubspot", "zendesk", // Add common competitors
-                ]),
-                (IntentCategory::Technical, vec![
-                    "integrate", "integration", "api", "sdk", "webhook", "technical",
-                    "architecture", "scalability", "performance", "uptime", "sla",
-                    "latency", "database", "infrastructure", "stack",
-                ]),
-                (IntentCategory::BuyingSignal, vec![
-                    "next steps", "how do we start", "get started", "sign up",
-                    "contract", "agreement", "pilot", "trial", "proof of concept",
-                    "let's do it", "sounds good", "i'm interested", "move forward",
-                    "ready to", "when can we",
-                ]),
-                (IntentCategory::Objection, vec![
-                    "too expensive", "not sure", "concern", "worried", "hesitant",
-                    "don't think", "not convinced", "problem with", "issue with",
-                    "can't", "won't work", "doesn't fit", "not ready",
-                ]),
-                (IntentCategory::Stalling, vec![
-                    "think about it", "get back to you", "send me info", "email me",
-                    "send a proposal", "need to discuss", "talk to my team",
-                    "check internally", "not the right time", "maybe later",
-                    "circle back", "follow up",
-                ]),
-                (IntentCategory::Procurement, vec![
-                    "who else", "decision maker", "sign off", "approval", "procurement",
-                    "purchasing", "legal review", "it review", "security review",
-                    "vendor", "rfp", "rfi", "evaluation", "committee",
-                ]),
-            ],
+            patterns: weighted,
+            competitors: Vec::new(),
         }
     }
 
+    /// Also detect these competitor names (e.g. from the user's battlecards)
+    pub fn with_competitors(mut self, competitors: Vec<String>) -> Self {
+        self.competitors = competitors
+            .into_iter()
+            .filter(|name| !name.trim().is_empty())
+            .collect();
+        self
+    }
+
+    /// The configured competitor named first in the text
+    pub fn detect_competitor(&self, text: &str) -> Option<String> {
+        let text_lower = text.to_lowercase();
+        self.competitors
+            .iter()
+            .filter_map(|name| keyword_position(&text_lower, &name.to_lowercase()).map(|at| (at, name)))
+            .min_by_key(|(at, _)| *at)
+            .map(|(_, name)| name.clone())
+    }
+
     /// Analyze text and detect intent
     pub fn analyze(&self, text: &str) -> DetectedIntent {
-        let text_lower = text.to_lowercase();
+        self.rank(text).into_iter().next().unwrap_or(DetectedIntent {
+            category: IntentCategory::Other,
+            confidence: 0.0,
+            needs_response: true,
+            triggers: vec![],
+            competitor: None,
+        })
+    }
 
-        let mut best_match: Option<(IntentCategory, f32, Vec<String>)> = None;
+    /// Every concern in the text ("how much, and are you GDPR compliant?"), best first
+    pub fn analyze_multi(&self, text: &str) -> Vec<DetectedIntent> {
+        self.rank(text)
+            .into_iter()
+            .filter(|intent| intent.confidence >= MULTI_MIN_CONFIDENCE)
+            .collect()
+    }
 
-        for (category, keywords) in &self.patterns {
-            let mut matched_keywords = Vec::new();
+    /// Every matched category with calibrated confidence, best first
+    fn rank(&self, text: &str) -> Vec<DetectedIntent> {
+        let text_lower = text.to_lowercase();
+        let competitor = self.detect_competitor(text);
 
-            for keyword in keywords {
-                if text_lower.contains(keyword) {
-                    matched_keywords.push(keyword.to_string());
+        let matches: Vec<(IntentCategory, f32, Vec<String>)> = self
+            .patterns
+            .iter()
+            .filter_map(|(category, keywords)| {
+                let mut score = 0.0;
+                let mut triggers = Vec::new();
+                for (keyword, weight) in keywords {
+                    if contains_keyword(&text_lower, keyword) {
+                        score += weight;
+                        triggers.push(keyword.to_string());
+                    }
                 }
-            }
-
-            if !matched_keywords.is_empty() {
-                // Score based on number of matches and keyword specificity
-                let score = matched_keywords.len() as f32 / keywords.len() as f32;
-
-                if best_match.is_none() || score > best_match.as_ref().unwrap().1 {
-                    best_match = Some((category.clone(), score, matched_keywords));
+                if let (IntentCategory::Competition, Some(name)) = (category, &competitor) {
+                    let name = name.to_lowercase();
+                    if !triggers.contains(&name) {
+                        score += COMPETITOR_WEIGHT;
+                        triggers.push(name);
+                    }
                 }
-            }
-        }
+                (!triggers.is_empty()).then(|| (category.clone(), score, triggers))
+            })
+            .collect();
 
-        match best_match {
-            Some((category, confidence, triggers)) => DetectedIntent {
+        let total: f32 = matches.iter().map(|(_, score, _)| score).sum();
+        let mut intents: Vec<DetectedIntent> = matches
+            .into_iter()
+            .map(|(category, score, triggers)| DetectedIntent {
                 needs_response: !matches!(category, IntentCategory::SmallTalk),
+                competitor: competitor.clone().filter(|_| category == IntentCategory::Competition),
                 category,
-                confidence: confidence.min(0.95), // Cap confidence
+                confidence: calibrate(score, total),
                 triggers,
-            },
-            None => DetectedIntent {
-                category: IntentCategory::Other,
-                confidence: 0.0,
-                needs_response: true,
-                triggers: vec![],
-            },
-        }
+            })
+            .collect();
+
+        // Stable, so ties keep the pattern order
+        intents.sort_by(|a, b| b.confidence.partial_cmp(&a.confidence).unwrap_or(Ordering::Equal));
+        intents
+    }
+}
+
+/// Statement type of a turn from its detected intents, without a model call
+///
+/// Lets the pipeline decide whether a turn is worth a Flash/Deep round
+/// before anything is sent to the cloud.
+pub fn statement_type(text: &str, intents: &[DetectedIntent]) -> StatementType {
+    match intents.first().map(|i| &i.category) {
+        Some(IntentCategory::Objection | IntentCategory::Stalling) => return StatementType::Objection,
+        Some(IntentCategory::BuyingSignal) => return StatementType::BuyingSignal,
+        Some(IntentCategory::Technical | IntentCategory::Security) => return StatementType::Technical,
+        Some(
+            IntentCategory::Pricing
+            | IntentCategory::Timeline
+            | IntentCategory::Competition
+            | IntentCategory::Procurement,
+        ) => return StatementType::Question,
+        _ => {}
+    }
+
+    let text = text.trim().to_lowercase();
+    if SMALL_TALK.iter().any(|phrase| text.contains(phrase)) {
+        return StatementType::SmallTalk;
+    }
+    let first_word = text.split(|c: char| !c.is_alphanumeric()).next().unwrap_or_default();
+    if text.contains('?') || QUESTION_STARTS.contains(&first_word) {
+        StatementType::Question
+    } else {
+        StatementType::Statement
     }
 }
 
+/// Phrases beat single words; phrases of only generic words count half
+fn specificity(keyword: &str) -> f32 {
+    let words: Vec<&str> = keyword.split_whitespace().collect();
+    let base = 1.0 + 0.5 * words.len().saturating_sub(1) as f32;
+    if words.iter().all(|w| COMMON_WORDS.contains(w)) {
+        base * 0.5
+    } else {
+        base
+    }
+}
+
+/// Whether a keyword starts a word in the text ("cost" matches "costs")
+fn contains_keyword(text: &str, keyword: &str) -> bool {
+    keyword_position(text, keyword).is_some()
+}
+
+/// Byte offset of the first match that starts a word
+fn keyword_position(text: &str, keyword: &str) -> Option<usize> {
+    text.match_indices(keyword).map(|(start, _)| start).find(|&start| {
+        let starts_word = text[..start].chars().next_back().map_or(true, |c| !c.is_alphanumeric());
+        let ends_word = text[start + keyword.len()..].chars().next().map_or(true, |c| !c.is_alphanumeric());
+        starts_word && (ends_word || keyword.len() > SHORT_KEYWORD_LEN)
+    })
+}
+
+/// Confidence from a category's keyword score and its share of all matches
+fn calibrate(score: f32, total: f32) -> f32 {
+    let strength = 1.0 - (-score / CONFIDENCE_SCALE).exp();
+    (strength * score / total).min(MAX_CONFIDENCE)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -218,4 +392,67 @@ mod tests {
         let stalling = analyzer.analyze("Let me think about it and get back to you");
         assert_eq!(stalling.category, IntentCategory::Stalling);
     }
+
+    #[test]
+    fn test_specific_keywords_win() {
+        let analyzer = IntentAnalyzer::new();
+
+        let ranked = analyzer.rank("Are you SOC2 compliant?");
+        assert_eq!(ranked[0].category, IntentCategory::Security);
+        assert!(ranked[0].confidence > 0.7);
+        assert!(ranked.iter().all(|i| i.category != IntentCategory::Timeline));
+
+        // "when can" is shared with "when can we", "go live" is Timeline only
+        let ranked = analyzer.rank("When can we go live?");
+        assert_eq!(ranked[0].category, IntentCategory::Timeline);
+        assert!(ranked[0].confidence > 2.0 * ranked[1].confidence);
+
+        // Both concerns show up, the more specific one first
+        let ranked = analyzer.rank("How much is it and are you GDPR compliant?");
+        let categories: Vec<_> = ranked.iter().map(|i| i.category.clone()).collect();
+        assert_eq!(categories, vec![IntentCategory::Security, IntentCategory::Pricing]);
+
+        // Short keywords only match whole words
+        assert_eq!(analyzer.analyze("We need a rapid translation").category, IntentCategory::Other);
+    }
+
+    #[test]
+    fn test_analyze_multi() {
+        let analyzer = IntentAnalyzer::new();
+
+        let intents = analyzer.analyze_multi("We're already using Salesforce, does it integrate via API?");
+        let categories: Vec<_> = intents.iter().map(|i| i.category.clone()).collect();
+        assert_eq!(categories, vec![IntentCategory::Competition, IntentCategory::Technical]);
+
+        let intents = analyzer.analyze_multi("Sounds good, what are the next steps?");
+        assert_eq!(intents.len(), 1);
+        assert!(analyzer.analyze_multi("Nice weather today").is_empty());
+    }
+
+    #[test]
+    fn test_statement_type() {
+        let analyzer = IntentAnalyzer::new();
+        let classify = |text: &str| statement_type(text, &analyzer.analyze_multi(text));
+
+        assert_eq!(classify("That's too expensive for us"), StatementType::Objection);
+        assert_eq!(classify("Does it integrate with our API gateway?"), StatementType::Technical);
+        assert_eq!(classify("How much per seat?"), StatementType::Question);
+        assert_eq!(classify("Who would own the rollout on your side"), StatementType::Question);
+        assert_eq!(classify("How are you doing this morning?"), StatementType::SmallTalk);
+        assert_eq!(classify("We had our offsite last week"), StatementType::Statement);
+    }
+
+    #[test]
+    fn test_detect_competitor() {
+        let analyzer = IntentAnalyzer::new().with_competitors(vec!["Gong".to_string(), "HubSpot".to_string()]);
+
+        // Named competitors count as Competition even without comparison words
+        let intent = analyzer.analyze("Honestly hubspot does most of this for us, and gong too");
+        assert_eq!(intent.category, IntentCategory::Competition);
+        assert_eq!(intent.competitor.as_deref(), Some("HubSpot"));
+
+        assert_eq!(analyzer.detect_competitor("We looked at Gong"), Some("Gong".to_string()));
+        assert_eq!(analyzer.detect_competitor("Let's jump on a call"), None);
+        assert_eq!(analyzer.analyze("How much is it?").competitor, None);
+    }
 }
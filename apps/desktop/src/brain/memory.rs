@@ -0,0 +1,104 @@
+//! Conversation Memory
+//!
+//! Looks up snippets from past calls indexed by the Outreach API so the
+//! deep model can refer back to what was said before ("last time you
+//! mentioned the Q3 budget...").
+
+use anyhow::{anyhow, Result};
+use reqwest::{Client, Method};
+use serde::Deserialize;
+use std::time::Duration;
+
+use crate::config::OutreachAccount;
+
+/// Longest wait for memory before answering without it
+const SEARCH_TIMEOUT: Duration = Duration::from_millis(1500);
+
+/// Snippets requested per lookup
+const SEARCH_LIMIT: usize = 3;
+
+/// Longest memory block added to prompts (characters)
+const MEMORY_BLOCK_MAX_CHARS: usize = 1500;
+
+/// One relevant snippet from a past call
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MemorySnippet {
+    pub title: String,
+    pub content: String,
+    pub score: f64,
+}
+
+#[derive(Deserialize)]
+struct SearchResponse {
+    results: Vec<MemorySnippet>,
+}
+
+/// Client for the memory search endpoint
+pub struct MemoryClient {
+    client: Client,
+    account: OutreachAccount,
+}
+
+impl MemoryClient {
+    pub fn new(account: OutreachAccount) -> Self {
+        Self {
+            client: Client::new(),
+            account,
+        }
+    }
+
+    /// Snippets from past calls most relevant to what was just said
+    pub async fn search(&self, query: &str) -> Result<Vec<MemorySnippet>> {
+        let response = self
+            .account
+            .request(&self.client, Method::GET, "/memory/search")
+            .query(&[("q", query), ("limit", &SEARCH_LIMIT.to_string())])
+            .timeout(SEARCH_TIMEOUT)
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(anyhow!("Memory search failed: {}", response.status()));
+        }
+
+        Ok(response.json::<SearchResponse>().await?.results)
+    }
+}
+
+/// Compact block for the AI context, if there are any snippets
+pub fn memory_block(snippets: &[MemorySnippet]) -> Option<String> {
+    if snippets.is_empty() {
+        return None;
+    }
+
+    let mut block = String::from("Relevant past conversations:");
+    for snippet in snippets {
+        let entry = format!("\n[{}]\n{}", snippet.title, snippet.content.trim());
+        if block.len() + entry.len() > MEMORY_BLOCK_MAX_CHARS {
+            break;
+        }
+        block.push_str(&entry);
+    }
+    Some(block)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_memory_block() {
+        assert_eq!(memory_block(&[]), None);
+
+        let snippets = vec![MemorySnippet {
+            title: "Call transcript (sales, 2026-09-02)".to_string(),
+            content: "Them: Our budget resets in Q3.\n".to_string(),
+            score: 0.03,
+        }];
+        assert_eq!(
+            memory_block(&snippets).unwrap(),
+            "Relevant past conversations:\n[Call transcript (sales, 2026-09-02)]\nThem: Our budget resets in Q3."
+        );
+    }
+}
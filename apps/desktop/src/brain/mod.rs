@@ -11,10 +11,19 @@
 pub mod pipeline;
 mod context;
 mod intent;
+mod action_items;
+pub mod followup;
+pub mod memory;
+pub mod webhook;
+pub mod alert;
 pub mod modes;
 pub mod hybrid_router;
 
-pub use pipeline::{CopilotPipeline, PipelineConfig, CopilotState, PipelineEvent, FlashModelChoice};
+pub use pipeline::{CopilotPipeline, PipelineConfig, CopilotState, PipelineEvent, FlashModelChoice, InjectedProviders, TriggerMode, TurnTiming};
 pub use context::{ConversationContext, ConversationTurn};
-pub use intent::{IntentAnalyzer, DetectedIntent};
-pub use hybrid_router::{HybridRouter, HybridRouterConfig, RoutingStrategy, Complexity, AIProvider};
+pub use intent::{IntentAnalyzer, IntentCategory, DetectedIntent};
+pub use followup::{due_date, extract_followup, followup_from_steps};
+pub use memory::{MemoryClient, MemorySnippet};
+pub use webhook::WebhookNotifier;
+pub use alert::{Alerter, CaptureMute};
+pub use hybrid_router::{HybridRouter, HybridRouterConfig, RoutingStrategy, Complexity, ComplexityConfig, LOCAL_PROVIDER, OPENAI_PROVIDER, ANTHROPIC_PROVIDER, GOOGLE_PROVIDER, GROQ_PROVIDER};
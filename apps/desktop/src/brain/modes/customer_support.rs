@@ -0,0 +1,87 @@
+//! Customer Support Mode
+//!
+//! Optimized for support calls - focuses on:
+//! - Empathy and de-escalation
+//! - Clear troubleshooting steps
+//! - Setting expectations
+//! - Confirming resolution
+
+use super::CopilotMode;
+
+pub struct CustomerSupportMode {
+    /// Product being supported
+    pub product: String,
+    /// Known issues and their workarounds
+    pub known_issues: Vec<(String, String)>,
+    /// When to hand off to a higher tier
+    pub escalation_policy: Option<String>,
+}
+
+impl Default for CustomerSupportMode {
+    fn default() -> Self {
+        Self {
+            product: "the product".to_string(),
+            known_issues: vec![],
+            escalation_policy: None,
+        }
+    }
+}
+
+impl CopilotMode for CustomerSupportMode {
+    fn name(&self) -> &'static str {
+        "Customer Support"
+    }
+
+    fn context_description(&self) -> String {
+        format!(
+            r#"This is a customer support call for {}.
+
+The goal is to:
+1. Acknowledge the customer's frustration
+2. Understand the exact problem and its impact
+3. Walk through a fix one step at a time
+4. Confirm the issue is resolved before closing
+
+Be patient and warm. Never blame the customer."#,
+            self.product
+        )
+    }
+
+    fn prompt_additions(&self) -> String {
+        let mut additions = String::new();
+
+        if !self.known_issues.is_empty() {
+            additions.push_str("\nKnown issues and workarounds:");
+            for (issue, workaround) in &self.known_issues {
+                additions.push_str(&format!("\n- {}: {}", issue, workaround));
+            }
+        }
+        if let Some(policy) = &self.escalation_policy {
+            additions.push_str(&format!("\nEscalation policy: {}\n", policy));
+        }
+
+        additions.push_str("\n\nSupport-specific guidance:");
+        additions.push_str("\n- Empathize before troubleshooting");
+        additions.push_str("\n- Give one instruction at a time and check it worked");
+        additions.push_str("\n- Avoid internal jargon");
+        additions.push_str("\n- Set clear expectations on timelines and follow-up");
+
+        additions
+    }
+
+    fn customize_bullets(&self, bullets: &mut Vec<String>) {
+        // Frustrated customers need acknowledgement first
+        let is_upset = bullets.iter().any(|b| {
+            let lower = b.to_lowercase();
+            lower.contains("frustrat") || lower.contains("angry") || lower.contains("upset")
+        });
+        let has_empathy = bullets.iter().any(|b| {
+            let lower = b.to_lowercase();
+            lower.contains("sorry") || lower.contains("understand")
+        });
+
+        if is_upset && !has_empathy {
+            bullets.insert(0, "Acknowledge their frustration first".to_string());
+        }
+    }
+}
@@ -0,0 +1,86 @@
+//! Meeting Mode
+//!
+//! Optimized for meetings - focuses on:
+//! - Staying on agenda
+//! - Capturing decisions and action items
+//! - Keeping time
+//! - Clear next steps
+
+use super::CopilotMode;
+
+pub struct MeetingMode {
+    /// Purpose of the meeting
+    pub purpose: String,
+    /// Agenda items in order
+    pub agenda: Vec<String>,
+    /// Scheduled length in minutes
+    pub duration_minutes: Option<u32>,
+}
+
+impl Default for MeetingMode {
+    fn default() -> Self {
+        Self {
+            purpose: "the meeting".to_string(),
+            agenda: vec![],
+            duration_minutes: None,
+        }
+    }
+}
+
+impl CopilotMode for MeetingMode {
+    fn name(&self) -> &'static str {
+        "Meeting"
+    }
+
+    fn context_description(&self) -> String {
+        format!(
+            r#"This is a meeting about {}.
+
+{}
+
+The goal is to:
+1. Keep the discussion on the agenda
+2. Drive each topic to a decision
+3. Capture action items with an owner and a date
+4. End on time with clear next steps
+
+Be concise. Park tangents instead of following them."#,
+            self.purpose,
+            if !self.agenda.is_empty() {
+                format!(
+                    "Agenda:\n{}",
+                    self.agenda.iter().map(|a| format!("- {}", a)).collect::<Vec<_>>().join("\n")
+                )
+            } else {
+                String::new()
+            }
+        )
+    }
+
+    fn prompt_additions(&self) -> String {
+        let mut additions = String::new();
+
+        if let Some(minutes) = self.duration_minutes {
+            additions.push_str(&format!("\nScheduled length: {} minutes\n", minutes));
+        }
+
+        additions.push_str("\nMeeting-specific guidance:");
+        additions.push_str("\n- Restate decisions so everyone agrees on them");
+        additions.push_str("\n- Turn vague commitments into owner + deadline");
+        additions.push_str("\n- Suggest parking off-topic items for later");
+
+        additions
+    }
+
+    fn customize_bullets(&self, bullets: &mut Vec<String>) {
+        // Make sure commitments get pinned down
+        let has_action = bullets.iter().any(|b| {
+            let lower = b.to_lowercase();
+            lower.contains("action") || lower.contains("owner") || lower.contains("next step")
+        });
+
+        if !has_action && !bullets.is_empty() {
+            bullets.push("Confirm the owner and deadline".to_string());
+        }
+    }
+}
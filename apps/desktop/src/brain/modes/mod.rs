@@ -6,13 +6,21 @@
 mod sales;
 mod interview;
 mod technical;
+mod negotiation;
+mod customer_support;
+mod meeting;
 
 pub use sales::SalesMode;
 pub use interview::InterviewMode;
 pub use technical::TechnicalMode;
+pub use negotiation::NegotiationMode;
+pub use customer_support::CustomerSupportMode;
+pub use meeting::MeetingMode;
 
 use serde::{Deserialize, Serialize};
 
+use crate::flash::{Bullet, FlashAnalysis};
+
 /// Common trait for all modes
 pub trait CopilotMode {
     /// Get the mode name
@@ -171,6 +179,43 @@ impl ConversationMode {
     }
 }
 
+impl ConversationMode {
+    /// Mode with dedicated behavior, if there is one
+    pub fn behavior(&self) -> Option<Box<dyn CopilotMode>> {
+        match self {
+            Self::Sales => Some(Box::new(SalesMode::default())),
+            Self::Interview => Some(Box::new(InterviewMode::default())),
+            Self::Technical => Some(Box::new(TechnicalMode::default())),
+            Self::Negotiation => Some(Box::new(NegotiationMode::default())),
+            Self::CustomerSupport => Some(Box::new(CustomerSupportMode::default())),
+            Self::Meeting => Some(Box::new(MeetingMode::default())),
+            _ => None,
+        }
+    }
+
+    /// Apply mode-specific bullet customization to a flash result
+    ///
+    /// Bullets are re-prioritized in the order the mode leaves them.
+    pub fn customize_flash(&self, analysis: &mut FlashAnalysis) {
+        let Some(mode) = self.behavior() else {
+            return;
+        };
+
+        analysis.bullets.sort_by_key(|b| b.priority);
+        let mut points: Vec<String> = analysis.bullets.drain(..).map(|b| b.point).collect();
+        mode.customize_bullets(&mut points);
+
+        analysis.bullets = points
+            .into_iter()
+            .enumerate()
+            .map(|(i, point)| Bullet {
+                point,
+                priority: (i + 1).min(u8::MAX as usize) as u8,
+            })
+            .collect();
+    }
+}
+
 impl Default for ConversationMode {
     fn default() -> Self {
         Self::Sales
@@ -182,3 +227,72 @@ impl std::fmt::Display for ConversationMode {
         write!(f, "{}", self.name())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::flash::{StatementType, Urgency};
+
+    fn analysis(points: &[&str]) -> FlashAnalysis {
+        FlashAnalysis {
+            summary: "Prospect pushed back".to_string(),
+            bullets: points
+                .iter()
+                .enumerate()
+                .map(|(i, p)| Bullet { point: p.to_string(), priority: i as u8 + 1 })
+                .collect(),
+            statement_type: StatementType::Objection,
+            urgency: Urgency::AnswerNow,
+            usage: None,
+        }
+    }
+
+    #[test]
+    fn test_sales_leads_with_price_objection() {
+        let mut flash = analysis(&[
+            "Mention the onboarding support",
+            "They think the price is too high",
+            "Ask who else is involved?",
+        ]);
+        ConversationMode::Sales.customize_flash(&mut flash);
+
+        assert_eq!(flash.bullets[0].point, "They think the price is too high");
+        assert_eq!(flash.bullets[0].priority, 1);
+        assert_eq!(flash.bullets[1].point, "Mention the onboarding support");
+        assert_eq!(flash.bullets[1].priority, 2);
+    }
+
+    #[test]
+    fn test_modes_without_behavior_are_noop() {
+        let mut flash = analysis(&["Ask what they work on", "Share a recent project"]);
+        ConversationMode::Networking.customize_flash(&mut flash);
+
+        assert_eq!(flash.bullets.len(), 2);
+        assert_eq!(flash.bullets[0].point, "Ask what they work on");
+        assert_eq!(flash.bullets[1].priority, 2);
+    }
+
+    #[test]
+    fn test_support_acknowledges_frustration_first() {
+        let mut flash = analysis(&["Customer is frustrated the export fails", "Check their app version"]);
+        ConversationMode::CustomerSupport.customize_flash(&mut flash);
+
+        assert_eq!(flash.bullets[0].point, "Acknowledge their frustration first");
+        assert_eq!(flash.bullets.len(), 3);
+    }
+
+    #[test]
+    fn test_core_and_added_modes_have_behavior() {
+        for mode in [
+            ConversationMode::Sales,
+            ConversationMode::Interview,
+            ConversationMode::Technical,
+            ConversationMode::Negotiation,
+            ConversationMode::CustomerSupport,
+            ConversationMode::Meeting,
+        ] {
+            assert!(mode.behavior().is_some(), "{}", mode);
+        }
+        assert!(ConversationMode::Legal.behavior().is_none());
+    }
+}
@@ -0,0 +1,92 @@
+//! Negotiation Mode
+//!
+//! Optimized for negotiations - focuses on:
+//! - Anchoring and concessions
+//! - Understanding the other side's interests
+//! - Protecting your walk-away point
+//! - Finding win-win trades
+
+use super::CopilotMode;
+
+pub struct NegotiationMode {
+    /// What is being negotiated
+    pub subject: String,
+    /// Outcome you are aiming for
+    pub target: Option<String>,
+    /// Point below which you walk away
+    pub walk_away: Option<String>,
+    /// Things you can trade without much cost
+    pub tradeables: Vec<String>,
+}
+
+impl Default for NegotiationMode {
+    fn default() -> Self {
+        Self {
+            subject: "the agreement".to_string(),
+            target: None,
+            walk_away: None,
+            tradeables: vec![
+                "Payment terms".to_string(),
+                "Contract length".to_string(),
+                "Timeline".to_string(),
+            ],
+        }
+    }
+}
+
+impl CopilotMode for NegotiationMode {
+    fn name(&self) -> &'static str {
+        "Negotiation"
+    }
+
+    fn context_description(&self) -> String {
+        format!(
+            r#"This is a negotiation about {}.
+
+Things we can trade:
+{}
+
+The goal is to:
+1. Understand their underlying interests, not just their position
+2. Anchor early and concede slowly
+3. Trade concessions - never give one away for free
+4. Reach an agreement both sides can commit to
+
+Stay calm and curious. Silence is a tool - don't rush to fill it."#,
+            self.subject,
+            self.tradeables.iter().map(|t| format!("- {}", t)).collect::<Vec<_>>().join("\n")
+        )
+    }
+
+    fn prompt_additions(&self) -> String {
+        let mut additions = String::new();
+
+        if let Some(target) = &self.target {
+            additions.push_str(&format!("\nTarget outcome: {}\n", target));
+        }
+        if let Some(walk_away) = &self.walk_away {
+            additions.push_str(&format!("\nWalk-away point (never reveal): {}\n", walk_away));
+        }
+
+        additions.push_str("\nNegotiation-specific guidance:");
+        additions.push_str("\n- Ask why before answering what");
+        additions.push_str("\n- Tie every concession to something in return (\"if we..., would you...?\")");
+        additions.push_str("\n- Label their emotions and concerns out loud");
+        additions.push_str("\n- Summarize agreed points before moving on");
+
+        additions
+    }
+
+    fn customize_bullets(&self, bullets: &mut Vec<String>) {
+        // Any concession should come with an ask attached
+        let mentions_concession = bullets.iter().any(|b| {
+            let lower = b.to_lowercase();
+            lower.contains("discount") || lower.contains("concession") || lower.contains("lower")
+        });
+        let has_trade = bullets.iter().any(|b| b.to_lowercase().contains("in return"));
+
+        if mentions_concession && !has_trade {
+            bullets.push("Ask for something in return before conceding".to_string());
+        }
+    }
+}
@@ -79,6 +79,9 @@ Be confident but not pushy. Focus on their problems, not your features."#,
     }
 
     fn customize_bullets(&self, bullets: &mut Vec<String>) {
+        // Handle price objections before anything else
+        bullets.sort_by_key(|b| !is_price_objection(b));
+
         // Ensure there's always a qualifying question
         let has_question = bullets.iter().any(|b| b.contains('?'));
         if !has_question && !bullets.is_empty() {
@@ -86,3 +89,11 @@ Be confident but not pushy. Focus on their problems, not your features."#,
         }
     }
 }
+
+/// Check whether a bullet is about price or budget
+fn is_price_objection(bullet: &str) -> bool {
+    let lower = bullet.to_lowercase();
+    ["price", "pricing", "cost", "expensive", "budget"]
+        .iter()
+        .any(|word| lower.contains(word))
+}
@@ -5,14 +5,29 @@
 
 use anyhow::Result;
 use parking_lot::RwLock;
+use std::collections::{HashMap, VecDeque};
 use std::sync::Arc;
+use std::time::Instant;
 use tokio::sync::{mpsc, broadcast};
+use tokio_util::sync::CancellationToken;
 
-use crate::capture::{AudioCapture, AudioConfig, DeepgramClient, DeepgramConfig, TranscriptBuffer};
-use crate::flash::{GeminiFlash, GPT4oMini, OllamaFlash, FlashAnalysis};
-use crate::deep::{ModelRouter, ModelChoice};
-use super::context::ConversationContext;
-use super::intent::IntentAnalyzer;
+use crate::capture::{AudioCapture, AudioCaptureState, AudioConfig, AudioLevel, CorrectionDictionary, DeepgramClient, DeepgramConfig, DeepgramStatus, SpeechGate, StreamMap, TranscriptBuffer, TranscriptSegment};
+use crate::flash::{AzureOpenAIConfig, Bullet, GeminiFlash, GPT4oMini, GroqFlash, OllamaFlash, OpenRouterClient, FlashAnalysis, FlashProvider, limit_bullets};
+use crate::deep::{DeepError, DeepProvider, ModelRouter, ModelChoice, StreamChunk};
+use crate::error::{CopilotError, FlashError};
+use crate::cost::{CostTracker, ModelPrice, TokenUsage, LOCAL_PREFIX};
+use crate::recording::{ActionItem, AudioWrite, RecordingManager, RecordingSession, Speaker, AUDIO_SAMPLE_RATE};
+use crate::analytics::{AnalyticsManager, Speaker as AnalyticsSpeaker};
+use crate::i18n::Language;
+use crate::config::{AlertLevel, AlertSettings, Battlecards, FollowupSettings, Glossary, ObjectionEntry, ObjectionLibrary, OutreachAccount, ResponseMode, ResponseSettings, WebhookConfig};
+use super::action_items::ActionItemDetector;
+use super::alert::{Alerter, CaptureMute};
+use super::context::{ConversationContext, SUMMARY_BATCH_TURNS};
+use super::followup::extract_followup;
+use super::intent::{statement_type, DetectedIntent, IntentAnalyzer};
+use super::memory::MemoryClient;
+use super::webhook::WebhookNotifier;
+use super::modes::ConversationMode;
 
 /// Pipeline configuration
 #[derive(Debug, Clone)]
@@ -25,10 +40,93 @@ pub struct PipelineConfig {
     pub anthropic_key: Option<String>,
     /// Google AI API key
     pub google_key: Option<String>,
+    /// Groq API key
+    pub groq_key: Option<String>,
+    /// OpenRouter API key (for `OpenRouter` model choices)
+    pub openrouter_key: Option<String>,
     /// Which flash model to use
     pub flash_model: FlashModelChoice,
     /// Which deep model to use
     pub deep_model: ModelChoice,
+    /// Ask Deepgram to label speakers
+    pub diarize: bool,
+    /// Diarized speaker ID that belongs to the user
+    ///
+    /// Deepgram numbers speakers in order of appearance, so "0" is whoever
+    /// spoke first. Segments from this speaker are recorded as our own turns
+    /// and do not trigger suggestions.
+    pub user_speaker: Option<String>,
+    /// Wait this long after the last final segment before analyzing (ms)
+    ///
+    /// Short bursts of speech are merged into one utterance instead of
+    /// firing a Flash + Deep round for each fragment.
+    pub coalesce_ms: u64,
+    /// Flash model overrides keyed by lowercase mode (e.g. "technical")
+    pub per_mode_flash: HashMap<String, FlashModelChoice>,
+    /// Deep model overrides keyed by lowercase mode
+    pub per_mode_deep: HashMap<String, ModelChoice>,
+    /// Price per 1K tokens, keyed by model prefix
+    pub prices: HashMap<String, ModelPrice>,
+    /// When Flash/Deep analysis runs
+    pub trigger_mode: TriggerMode,
+    /// How much recent transcript a manual trigger analyzes (seconds)
+    pub manual_window_secs: u64,
+    /// Final segments below this STT confidence (0.0 to 1.0) are not analyzed
+    pub min_confidence: f32,
+    /// Conversation mode used to customize flash bullets
+    pub mode: ConversationMode,
+    /// Send OpenAI requests to this Azure deployment instead
+    pub azure: Option<AzureOpenAIConfig>,
+    /// Domain terms boosted in STT and added to prompts
+    pub glossary: Glossary,
+    /// Competitor counters added to prompts when a competitor is named
+    pub battlecards: Battlecards,
+    /// Proven responses, matched against each of their turns
+    pub objections: ObjectionLibrary,
+    /// Look up relevant past calls before each deep request
+    pub memory: Option<OutreachAccount>,
+    /// Notify a team channel when configured intents are detected
+    pub webhooks: WebhookConfig,
+    /// Lead the call is with, included in notifications
+    pub lead: Option<String>,
+    /// Suggest follow-up dates mentioned in deep responses
+    pub followup: FollowupSettings,
+    /// Border flash and chime per Flash urgency
+    pub alerts: AlertSettings,
+    /// Bullet count and length caps
+    pub responses: ResponseSettings,
+    /// Language suggestions are written in
+    pub language: Language,
+    /// Use these instead of the configured models
+    pub providers: InjectedProviders,
+}
+
+/// Flash and deep backends that replace `flash_model` / `deep_model`
+///
+/// Tests use these to run the pipeline against canned responses.
+#[derive(Clone, Default)]
+pub struct InjectedProviders {
+    pub flash: Option<Arc<dyn FlashProvider>>,
+    pub deep: Option<Arc<dyn DeepProvider>>,
+}
+
+impl std::fmt::Debug for InjectedProviders {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("InjectedProviders")
+            .field("flash", &self.flash.as_ref().map(|p| p.name()))
+            .field("deep", &self.deep.as_ref().map(|p| p.name()))
+            .finish()
+    }
+}
+
+/// When the pipeline runs analysis
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum TriggerMode {
+    /// Analyze every utterance once they pause
+    #[default]
+    Continuous,
+    /// Only analyze when `request_analysis` is called (push to talk)
+    Manual,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -38,6 +136,34 @@ pub enum FlashModelChoice {
     GPT4oMini,
     /// Local Ollama (Llama 3.1 8B, Mistral, etc.)
     LocalOllama(String), // model name
+    /// Groq-hosted model, e.g. "llama-3.1-8b-instant"
+    Groq(String),
+    /// Any OpenRouter model, e.g. "google/gemini-2.0-flash-001"
+    OpenRouter(String),
+}
+
+impl FlashModelChoice {
+    /// Model name used for cost accounting
+    pub fn cost_model(&self) -> String {
+        match self {
+            Self::GeminiFlash => "gemini-2.0-flash".to_string(),
+            Self::GPT4oMini => "gpt-4o-mini".to_string(),
+            Self::LocalOllama(model) => format!("{}{}", LOCAL_PREFIX, model),
+            Self::Groq(model) => format!("groq/{}", model),
+            Self::OpenRouter(model) => model.clone(),
+        }
+    }
+
+    /// Who to blame in error messages, e.g. "Check your OpenAI key"
+    pub fn provider(&self) -> &'static str {
+        match self {
+            Self::GeminiFlash => "Google AI",
+            Self::GPT4oMini => "OpenAI",
+            Self::LocalOllama(_) => "Ollama",
+            Self::Groq(_) => "Groq",
+            Self::OpenRouter(_) => "OpenRouter",
+        }
+    }
 }
 
 impl Default for PipelineConfig {
@@ -47,17 +173,63 @@ impl Default for PipelineConfig {
             openai_key: None,
             anthropic_key: None,
             google_key: None,
+            groq_key: None,
+            openrouter_key: None,
             flash_model: FlashModelChoice::GeminiFlash,
             deep_model: ModelChoice::ClaudeSonnet,
+            diarize: false,
+            user_speaker: None,
+            coalesce_ms: 800,
+            per_mode_flash: HashMap::new(),
+            per_mode_deep: HashMap::new(),
+            prices: crate::cost::default_prices(),
+            trigger_mode: TriggerMode::Continuous,
+            manual_window_secs: 30,
+            min_confidence: 0.6,
+            mode: ConversationMode::default(),
+            azure: None,
+            glossary: Glossary::default(),
+            battlecards: Battlecards::default(),
+            objections: ObjectionLibrary { entries: Vec::new() },
+            memory: None,
+            webhooks: WebhookConfig::default(),
+            lead: None,
+            followup: FollowupSettings::default(),
+            alerts: AlertSettings::default(),
+            responses: ResponseSettings::default(),
+            language: Language::default(),
+            providers: InjectedProviders::default(),
         }
     }
 }
 
+impl PipelineConfig {
+    /// Flash and deep models for a mode, falling back to the global defaults
+    pub fn models_for_mode(&self, mode: &str) -> (FlashModelChoice, ModelChoice) {
+        let mode = mode.to_lowercase();
+        let flash = self
+            .per_mode_flash
+            .get(&mode)
+            .cloned()
+            .unwrap_or_else(|| self.flash_model.clone());
+        let deep = self
+            .per_mode_deep
+            .get(&mode)
+            .cloned()
+            .unwrap_or_else(|| self.deep_model.clone());
+        (flash, deep)
+    }
+}
+
 /// Current state of the copilot
 #[derive(Debug, Clone, Default)]
 pub struct CopilotState {
     /// Is the pipeline running
     pub is_running: bool,
+    /// Running, but audio isn't sent to STT and nothing is analyzed
+    pub is_paused: bool,
+    /// Background on the prospect included in every prompt
+    pub brief: String,
     /// Current transcript
     pub transcript: String,
     /// Flash analysis (quick bullets)
@@ -70,6 +242,112 @@ pub struct CopilotState {
     pub question_to_ask: Option<String>,
     /// Current error (if any)
     pub error: Option<String>,
+    /// Timing of the most recent turn
+    pub last_timing: Option<TurnTiming>,
+    /// Rolling average over recent turns
+    pub average_timing: Option<TurnTiming>,
+    /// Recent turn timings used for the rolling average
+    pub recent_timings: VecDeque<TurnTiming>,
+    /// Estimated API spend this session (USD)
+    pub estimated_cost_usd: f64,
+    /// Tokens used this session across all providers
+    pub total_tokens: u64,
+    /// Follow-up date the deep model last suggested
+    pub suggested_followup: Option<chrono::DateTime<chrono::Utc>>,
+    /// Concerns detected in their latest turn, best first
+    pub intents: Vec<DetectedIntent>,
+    /// Commitments made so far in a Meeting or Coaching call
+    pub action_items: Vec<ActionItem>,
+    /// No model to analyze with, so only the transcript is shown
+    pub transcription_only: bool,
+}
+
+/// Turns sent verbatim to the deep model; older ones are summarized
+const DEEP_HISTORY_TURNS: usize = 8;
+
+/// Number of turns in the rolling latency average
+const TIMING_WINDOW: usize = 10;
+
+/// Context for the flash call that summarizes earlier turns
+const SUMMARY_INSTRUCTION: &str = "This is an earlier part of the call, not the latest message. \
+    Put a summary of it in the summary field: at most two sentences, keeping names, numbers, \
+    objections and anything agreed.";
+
+impl CopilotState {
+    /// Record a turn's timing and refresh the rolling average
+    pub fn record_timing(&mut self, timing: TurnTiming) {
+        self.recent_timings.push_back(timing);
+        while self.recent_timings.len() > TIMING_WINDOW {
+            self.recent_timings.pop_front();
+        }
+
+        self.last_timing = Some(timing);
+        self.average_timing = Some(TurnTiming::average(self.recent_timings.iter()));
+    }
+}
+
+/// Per-turn latency breakdown (milliseconds)
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct TurnTiming {
+    /// First interim result to final result for the last segment
+    pub stt_ms: u64,
+    /// Flash request to parsed bullets
+    pub flash_ms: u64,
+    /// Deep request to first streamed token
+    pub deep_first_token_ms: u64,
+    /// Deep request to end of stream
+    pub deep_total_ms: u64,
+}
+
+impl TurnTiming {
+    /// Average a set of timings field by field
+    ///
+    /// Zero means the step didn't run that turn (bullets only, or Deep
+    /// failed or was cancelled), so each field is averaged only over the
+    /// turns where it ran.
+    pub fn average<'a>(timings: impl Iterator<Item = &'a TurnTiming>) -> Self {
+        let timings: Vec<&TurnTiming> = timings.collect();
+        let mean = |step: fn(&TurnTiming) -> u64| {
+            let (total, count) = timings
+                .iter()
+                .map(|t| step(t))
+                .filter(|&ms| ms > 0)
+                .fold((0, 0), |(total, count), ms| (total + ms, count + 1));
+            if count == 0 { 0 } else { total / count }
+        };
+
+        TurnTiming {
+            stt_ms: mean(|t| t.stt_ms),
+            flash_ms: mean(|t| t.flash_ms),
+            deep_first_token_ms: mean(|t| t.deep_first_token_ms),
+            deep_total_ms: mean(|t| t.deep_total_ms),
+        }
+    }
+
+    /// Short label for the status bar, e.g. "Flash 680ms / Deep 1.2s"
+    ///
+    /// A step that didn't run (bullets only, say) is left out.
+    pub fn label(&self) -> String {
+        if self.deep_first_token_ms == 0 {
+            return format!("Flash {}", format_ms(self.flash_ms));
+        }
+        if self.flash_ms == 0 {
+            return format!("Deep {}", format_ms(self.deep_first_token_ms));
+        }
+        format!(
+            "Flash {} / Deep {}",
+            format_ms(self.flash_ms),
+            format_ms(self.deep_first_token_ms)
+        )
+    }
+}
+
+fn format_ms(ms: u64) -> String {
+    if ms < 1000 {
+        format!("{}ms", ms)
+    } else {
+        format!("{:.1}s", ms as f64 / 1000.0)
+    }
 }
 
 /// Events emitted by the pipeline
@@ -77,49 +355,166 @@ pub struct CopilotState {
 pub enum PipelineEvent {
     /// New transcript segment
     Transcript(String),
+    /// Final segment too unreliable to analyze; shown greyed out
+    LowConfidence(TranscriptSegment),
+    /// Concerns detected in their latest turn, best first
+    IntentsDetected(Vec<DetectedIntent>),
+    /// A library response fits their latest turn (sent after `IntentsDetected`)
+    ObjectionMatched(ObjectionEntry),
+    /// New commitment in a final turn (Meeting and Coaching modes)
+    ActionItem(ActionItem),
+    /// No LLM key or local Ollama; the session only transcribes (sent once, after `Started`)
+    TranscriptionOnly,
+    /// Bullet `index` of the turn, as a local model generates it (before `FlashReady`)
+    FlashBullet(usize, Bullet),
     /// Flash analysis ready
     FlashReady(FlashAnalysis),
+    /// The suggestion is urgent enough to flash the window (and maybe chime)
+    UrgentAlert(AlertLevel),
     /// Deep content chunk
     DeepChunk(String),
     /// Deep response complete
     DeepComplete,
+    /// The deep response suggested a follow-up date
+    FollowupSuggested(chrono::DateTime<chrono::Utc>),
+    /// Deep response superseded by a newer utterance and cleared
+    DeepCancelled,
+    /// The other side started speaking (used to interrupt voice output)
+    RemoteSpeech,
+    /// Capture input level, sent a few times a second
+    AudioLevel(AudioLevel),
     /// Question extracted
     QuestionReady(String),
     /// Error occurred
     Error(String),
+    /// Classified provider error (bad key, rate limit, ...)
+    TypedError(CopilotError),
+    /// Latency breakdown for a completed turn
+    Timing(TurnTiming),
+    /// STT connection dropped; reconnect attempt number
+    SttReconnecting(u32),
+    /// STT connection restored
+    SttReconnected,
+    /// Non-fatal problem worth surfacing
+    Warning(String),
     /// Pipeline started
     Started,
+    /// Audio forwarding and analysis halted; STT stays connected
+    Paused,
+    /// Listening again after a pause
+    Resumed,
     /// Pipeline stopped
     Stopped,
 }
 
+/// How often the capture level is sent to the UI
+const LEVEL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(100);
+
+/// Speaker ID given to the user's turns during a replay
+const REPLAY_USER_SPEAKER: &str = "0";
+
+/// Speaker ID given to the other side's turns during a replay
+const REPLAY_OTHER_SPEAKER: &str = "1";
+
+/// Receivers for the controls a running session listens to
+struct SessionControls {
+    shutdown_rx: mpsc::Receiver<()>,
+    analysis_rx: mpsc::Receiver<()>,
+    correction_rx: mpsc::Receiver<String>,
+}
+
 /// The main copilot pipeline
 pub struct CopilotPipeline {
     config: PipelineConfig,
+    /// Flash and deep models for the current mode
+    models: Arc<RwLock<(FlashModelChoice, ModelChoice)>>,
+    /// Conversation mode for bullet customization
+    mode: Arc<RwLock<ConversationMode>>,
+    /// Which of Flash and Deep run, switchable mid-call
+    response_mode: Arc<RwLock<ResponseMode>>,
+    /// Who the call is with, for webhook messages; switchable mid-call
+    lead: Arc<RwLock<Option<String>>>,
     state: Arc<RwLock<CopilotState>>,
+    cost: Arc<RwLock<CostTracker>>,
     context: Arc<RwLock<ConversationContext>>,
     transcript_buffer: Arc<TranscriptBuffer>,
     intent_analyzer: IntentAnalyzer,
     event_tx: broadcast::Sender<PipelineEvent>,
     shutdown_tx: Option<mpsc::Sender<()>>,
+    analysis_tx: Option<mpsc::Sender<()>>,
+    /// Corrected text for the latest line, to re-run analysis on
+    correction_tx: Option<mpsc::Sender<String>>,
+    /// Misheard phrases fixed on every new segment
+    corrections: Arc<RwLock<CorrectionDictionary>>,
+    /// Receives captured audio while a recording is running
+    recorder: Option<Arc<RecordingManager>>,
+    /// Collects talk-time and sentiment metrics for the call
+    analytics: Option<Arc<AnalyticsManager>>,
+    /// Token for the analysis running now, so pausing can cancel it
+    analysis_cancel: Arc<parking_lot::Mutex<Option<CancellationToken>>>,
+    /// Places STT word times on the recording's timeline
+    stream_map: Arc<parking_lot::Mutex<StreamMap>>,
+    /// Silences capture while an alert chime plays
+    capture_mute: CaptureMute,
+    /// Live audio capture; dropping it stops the stream
+    audio_capture: Option<AudioCapture>,
+    /// Segments `start` reads instead of capturing and transcribing audio
+    transcript_source: Option<mpsc::Receiver<TranscriptSegment>>,
 }
 
 impl CopilotPipeline {
     /// Create a new pipeline
     pub fn new(config: PipelineConfig) -> Self {
         let (event_tx, _) = broadcast::channel(100);
+        let mut context = ConversationContext::default();
+        context.set_glossary(&config.glossary);
 
         Self {
+            models: Arc::new(RwLock::new((config.flash_model.clone(), config.deep_model.clone()))),
+            mode: Arc::new(RwLock::new(config.mode.clone())),
+            response_mode: Arc::new(RwLock::new(config.responses.mode)),
+            lead: Arc::new(RwLock::new(config.lead.clone())),
+            cost: Arc::new(RwLock::new(CostTracker::with_prices(config.prices.clone()))),
             config,
             state: Arc::new(RwLock::new(CopilotState::default())),
-            context: Arc::new(RwLock::new(ConversationContext::default())),
+            context: Arc::new(RwLock::new(context)),
             transcript_buffer: Arc::new(TranscriptBuffer::default()),
             intent_analyzer: IntentAnalyzer::new(),
             event_tx,
             shutdown_tx: None,
+            analysis_tx: None,
+            correction_tx: None,
+            corrections: Arc::new(RwLock::new(CorrectionDictionary::load().unwrap_or_default())),
+            recorder: None,
+            analytics: None,
+            analysis_cancel: Arc::new(parking_lot::Mutex::new(None)),
+            stream_map: Arc::new(parking_lot::Mutex::new(StreamMap::new(AUDIO_SAMPLE_RATE))),
+            capture_mute: CaptureMute::default(),
+            audio_capture: None,
+            transcript_source: None,
         }
     }
 
+    /// Tee captured audio into a recording manager
+    pub fn with_recorder(mut self, recorder: Arc<RecordingManager>) -> Self {
+        self.recorder = Some(recorder);
+        self
+    }
+
+    /// Have `start` take its transcript from `segments`, with no audio
+    /// capture or STT, so a whole live session can run in tests
+    #[cfg(test)]
+    pub fn with_transcript_source(mut self, segments: mpsc::Receiver<TranscriptSegment>) -> Self {
+        self.transcript_source = Some(segments);
+        self
+    }
+
+    /// Feed final turns into an analytics manager
+    pub fn with_analytics(mut self, analytics: Arc<AnalyticsManager>) -> Self {
+        self.analytics = Some(analytics);
+        self
+    }
+
     /// Subscribe to pipeline events
     pub fn subscribe(&self) -> broadcast::Receiver<PipelineEvent> {
         self.event_tx.subscribe()
@@ -130,66 +525,477 @@ impl CopilotPipeline {
         self.state.read().clone()
     }
 
+    /// Transcript of the running session (cleared on stop)
+    pub fn transcript_buffer(&self) -> Arc<TranscriptBuffer> {
+        self.transcript_buffer.clone()
+    }
+
     /// Set conversation context/mode
     pub fn set_context(&self, context: impl Into<String>) {
         self.context.write().set_mode_context(context);
     }
 
+    /// Set the call brief, used from the next analysis on
+    pub fn set_brief(&self, brief: &str) {
+        let mut context = self.context.write();
+        context.set_brief(brief);
+        self.state.write().brief = context.brief().unwrap_or_default().to_string();
+    }
+
+    /// Switch to the models and bullet customization for a mode
+    ///
+    /// Takes effect from the next utterance; modes without an override use
+    /// the global defaults.
+    pub fn set_mode(&self, mode: &str) {
+        *self.models.write() = self.config.models_for_mode(mode);
+        *self.mode.write() = ConversationMode::from_str(mode);
+    }
+
+    /// Name the lead in webhook notifications from the next turn
+    pub fn set_lead(&self, lead: Option<String>) {
+        *self.lead.write() = lead;
+    }
+
+    /// Run Flash only, Deep only or both from the next utterance
+    pub fn set_response_mode(&self, mode: ResponseMode) {
+        *self.response_mode.write() = mode;
+    }
+
+    /// Analyze the last few seconds of transcript now
+    ///
+    /// This is the only way analysis runs in `TriggerMode::Manual`.
+    pub fn request_analysis(&self) {
+        if let Some(tx) = &self.analysis_tx {
+            let _ = tx.try_send(());
+        }
+    }
+
+    /// Replace the latest transcript line and re-run analysis on it
+    ///
+    /// The changed phrase is remembered and fixed in future segments.
+    pub fn correct_transcript(&self, corrected: String) {
+        if let Some(tx) = &self.correction_tx {
+            let _ = tx.try_send(corrected);
+        }
+    }
+
+    /// Stop sending audio to STT and triggering analysis
+    ///
+    /// The STT socket stays open (its keepalive covers the silence), so
+    /// resuming is instant. Analysis still running is cancelled. An
+    /// auto-started recording pauses too, keeping its audio and transcript
+    /// in step with what was analyzed.
+    pub fn pause(&self) {
+        {
+            let mut state = self.state.write();
+            if !state.is_running || state.is_paused {
+                return;
+            }
+            state.is_paused = true;
+        }
+        if let Some(cancel) = self.analysis_cancel.lock().take() {
+            cancel_analysis(&cancel, &self.state, &self.event_tx);
+        }
+        if let Some(recorder) = self.recorder.as_ref().filter(|r| r.is_auto_record()) {
+            recorder.pause();
+        }
+        let _ = self.event_tx.send(PipelineEvent::Paused);
+    }
+
+    /// Pick up again after `pause`
+    pub fn resume(&self) {
+        {
+            let mut state = self.state.write();
+            if !state.is_running || !state.is_paused {
+                return;
+            }
+            state.is_paused = false;
+        }
+        if let Some(recorder) = self.recorder.as_ref().filter(|r| r.is_auto_record()) {
+            recorder.resume();
+        }
+        let _ = self.event_tx.send(PipelineEvent::Resumed);
+    }
+
     /// Start the pipeline
     pub async fn start(&mut self) -> Result<()> {
         if self.state.read().is_running {
             return Ok(());
         }
 
+        let injected = self.transcript_source.take();
+
         // Validate configuration
-        if self.config.deepgram_key.is_none() && self.config.openai_key.is_none() {
+        if injected.is_none() && self.config.deepgram_key.is_none() && self.config.openai_key.is_none() {
             return Err(anyhow::anyhow!("No STT API key configured"));
         }
+        // Without a model every turn would fail; transcribe only instead
+        let transcription_only = !self.llm_available().await;
 
-        let (shutdown_tx, mut shutdown_rx) = mpsc::channel::<()>(1);
-        self.shutdown_tx = Some(shutdown_tx);
+        if let Some(transcript_rx) = injected {
+            let controls = self.begin_session(transcription_only);
+            let (_, stt_status_rx) = mpsc::channel::<DeepgramStatus>(1);
+            self.spawn_transcript_task(self.config.clone(), transcript_rx, stt_status_rx, controls);
+            return Ok(());
+        }
 
         // Start audio capture
-        let mut audio_capture = AudioCapture::new(AudioConfig::default());
+        let audio_config = AudioConfig::default();
+        let mut audio_capture = AudioCapture::new(audio_config.clone());
         let audio_rx = audio_capture.start()?;
 
         // Start STT
+        let (stt_status_tx, stt_status_rx) = mpsc::channel::<DeepgramStatus>(8);
         let deepgram = DeepgramClient::new(
             self.config.deepgram_key.clone().unwrap_or_default()
-        );
-        let (audio_tx, mut transcript_rx) = deepgram
-            .start_streaming(DeepgramConfig::default())
+        ).with_status_channel(stt_status_tx);
+        let deepgram_config = DeepgramConfig {
+            diarize: self.config.diarize,
+            keywords: self.config.glossary.keywords(),
+            ..DeepgramConfig::default()
+        };
+        let (audio_tx, transcript_rx) = deepgram
+            .start_streaming(deepgram_config)
             .await?;
 
-        // Update state
-        self.state.write().is_running = true;
-        let _ = self.event_tx.send(PipelineEvent::Started);
+        let controls = self.begin_session(transcription_only);
 
-        // Spawn audio forwarding task
+        // Spawn audio forwarding task (only speech reaches STT)
         let audio_tx_clone = audio_tx.clone();
+        let recorder = self.recorder.clone();
+        let stream_map = self.stream_map.clone();
+        let capture_mute = self.capture_mute.clone();
+        let forward_event_tx = self.event_tx.clone();
+        let forward_state = self.state.clone();
+        let capture_state = audio_capture.state_handle();
+        // With diarization the transcript says who is talking; otherwise
+        // any speech onset counts as the other side
+        let vad_remote_speech = !diarization_active(&self.config);
         tokio::spawn(async move {
             let mut audio_rx = audio_rx;
-            while let Some(samples) = audio_rx.recv().await {
-                // Convert to PCM bytes
-                let bytes = crate::capture::audio::f32_to_pcm_bytes(&samples);
-                if audio_tx_clone.send(bytes).await.is_err() {
-                    break;
+            let mut gate = SpeechGate::new(&audio_config);
+            let mut level_sent = Instant::now();
+            'forward: while let Some(samples) = audio_rx.recv().await {
+                if level_sent.elapsed() >= LEVEL_INTERVAL {
+                    level_sent = Instant::now();
+                    let state = capture_state.lock().clone();
+                    if let AudioCaptureState::Running(level) = state {
+                        let _ = forward_event_tx.send(PipelineEvent::AudioLevel(level));
+                    }
+                }
+
+                // Recordings keep the full audio, not just gated speech
+                if let Some(recorder) = &recorder {
+                    if recorder.add_audio(&samples) == AudioWrite::LimitReached {
+                        let _ = forward_event_tx.send(PipelineEvent::Warning(
+                            "Audio recording size limit reached; transcript recording continues".to_string(),
+                        ));
+                    }
+                }
+
+                // Our own alert chime is playing, or the user paused
+                if capture_mute.is_muted() || forward_state.read().is_paused {
+                    continue;
+                }
+
+                let was_active = gate.is_active();
+                let frames = gate.process(samples);
+                if vad_remote_speech && !was_active && gate.is_active() {
+                    let _ = forward_event_tx.send(PipelineEvent::RemoteSpeech);
+                }
+
+                // What goes out ends at the latest captured sample
+                if let Some(recorder) = recorder.as_ref().filter(|_| !frames.is_empty()) {
+                    let len = frames.iter().map(|frame| frame.len() as u64).sum();
+                    stream_map.lock().sent(len, recorder.stream_samples());
+                }
+
+                for frame in frames {
+                    // Convert to PCM bytes
+                    let bytes = crate::capture::f32_to_pcm_bytes(&frame);
+                    if audio_tx_clone.send(bytes).await.is_err() {
+                        break 'forward;
+                    }
+                }
+            }
+        });
+
+        let config = self.config.clone();
+        self.spawn_transcript_task(config, transcript_rx, stt_status_rx, controls);
+        self.audio_capture = Some(audio_capture);
+
+        Ok(())
+    }
+
+    /// Replay a recorded session instead of listening live
+    ///
+    /// Each turn arrives as a final transcript segment at its recorded
+    /// time, divided by `speed` (2.0 plays twice as fast), and goes through
+    /// the same processing as live speech, so Flash and Deep really run.
+    /// No audio is captured and no STT key is needed. At high speeds turns
+    /// closer together than `coalesce_ms` merge into one utterance, as
+    /// they would live. Call `stop` when done.
+    pub fn replay(&mut self, session: &RecordingSession, speed: f32) -> Result<()> {
+        if self.state.read().is_running {
+            return Ok(());
+        }
+
+        if !(speed > 0.0 && speed.is_finite()) {
+            return Err(anyhow::anyhow!("Replay speed must be positive, got {}", speed));
+        }
+
+        // Recorded turns say who spoke, so tell them apart like diarization would
+        let mut config = self.config.clone();
+        config.diarize = true;
+        config.user_speaker = Some(REPLAY_USER_SPEAKER.to_string());
+
+        let schedule = replay_schedule(session, speed);
+        let controls = self.begin_session(false);
+        let (segment_tx, transcript_rx) = mpsc::channel::<TranscriptSegment>(100);
+        // Nothing reports STT status during a replay
+        let (_, stt_status_rx) = mpsc::channel::<DeepgramStatus>(1);
+        self.spawn_transcript_task(config, transcript_rx, stt_status_rx, controls);
+
+        let session_id = session.id.clone();
+        tokio::spawn(async move {
+            let started = tokio::time::Instant::now();
+            for (offset, mut segment) in schedule {
+                tokio::time::sleep_until(started + offset).await;
+                segment.timestamp = chrono::Utc::now();
+                if segment_tx.send(segment).await.is_err() {
+                    return;
                 }
             }
+            tracing::info!("Finished replaying session {}", session_id);
         });
 
-        // Spawn transcript processing task
+        Ok(())
+    }
+
+    /// Open the control channels and mark the pipeline running
+    ///
+    /// With `transcription_only`, tells the UI once that nothing will be
+    /// analyzed.
+    fn begin_session(&mut self, transcription_only: bool) -> SessionControls {
+        let (shutdown_tx, shutdown_rx) = mpsc::channel::<()>(1);
+        self.shutdown_tx = Some(shutdown_tx);
+
+        let (analysis_tx, analysis_rx) = mpsc::channel::<()>(1);
+        self.analysis_tx = Some(analysis_tx);
+
+        let (correction_tx, correction_rx) = mpsc::channel::<String>(4);
+        self.correction_tx = Some(correction_tx);
+
+        self.stream_map.lock().restart();
+
+        // Costs accumulate per session
+        self.cost.write().reset();
+        {
+            let mut state = self.state.write();
+            state.estimated_cost_usd = 0.0;
+            state.total_tokens = 0;
+            state.action_items.clear();
+            state.transcription_only = false;
+        }
+
+        // Update state
+        self.state.write().is_running = true;
+        let _ = self.event_tx.send(PipelineEvent::Started);
+
+        if transcription_only {
+            tracing::warn!("No LLM key configured and Ollama isn't running; transcribing only");
+            self.state.write().transcription_only = true;
+            let _ = self.event_tx.send(PipelineEvent::TranscriptionOnly);
+        }
+
+        SessionControls {
+            shutdown_rx,
+            analysis_rx,
+            correction_rx,
+        }
+    }
+
+    /// Whether the first model each turn runs can be reached: the selected
+    /// flash provider's key (or a running Ollama), or for Deep only, any
+    /// deep key
+    async fn llm_available(&self) -> bool {
+        let config = &self.config;
+        let has_key = |key: &Option<String>| key.as_deref().is_some_and(|k| !k.trim().is_empty());
+
+        if !config.responses.mode.runs_flash() {
+            // The deep router falls back to whichever model has a key
+            return config.providers.deep.is_some()
+                || [&config.anthropic_key, &config.openai_key, &config.openrouter_key]
+                    .into_iter()
+                    .any(has_key);
+        }
+
+        // Flash has no fallback, and a turn stops when it fails
+        if config.providers.flash.is_some() {
+            return true;
+        }
+        let flash_model = self.models.read().0.clone();
+        match flash_model {
+            FlashModelChoice::GeminiFlash => has_key(&config.google_key),
+            FlashModelChoice::GPT4oMini => has_key(&config.openai_key),
+            FlashModelChoice::Groq(_) => has_key(&config.groq_key),
+            FlashModelChoice::OpenRouter(_) => has_key(&config.openrouter_key),
+            FlashModelChoice::LocalOllama(_) => OllamaFlash::new().is_available().await,
+        }
+    }
+
+    /// Spawn the task that turns transcript segments into analysis
+    ///
+    /// Live capture and replay both feed this, so they share every step
+    /// from debouncing to Deep.
+    fn spawn_transcript_task(
+        &self,
+        config: PipelineConfig,
+        mut transcript_rx: mpsc::Receiver<TranscriptSegment>,
+        mut stt_status_rx: mpsc::Receiver<DeepgramStatus>,
+        controls: SessionControls,
+    ) {
         let state = self.state.clone();
+        let cost = self.cost.clone();
         let context = self.context.clone();
         let transcript_buffer = self.transcript_buffer.clone();
         let event_tx = self.event_tx.clone();
-        let config = self.config.clone();
-        let intent_analyzer = IntentAnalyzer::new();
+        let models = self.models.clone();
+        let mode = self.mode.clone();
+        let response_mode = self.response_mode.clone();
+        let lead = self.lead.clone();
+        let recorder = self.recorder.clone();
+        let analytics = self.analytics.clone();
+        let analysis_cancel = self.analysis_cancel.clone();
+        let stream_map = self.stream_map.clone();
+        let corrections = self.corrections.clone();
+        let intent_analyzer = IntentAnalyzer::new().with_competitors(config.battlecards.competitors());
+        let notifier = WebhookNotifier::new(config.webhooks.clone());
+        let alerter = Alerter::new(config.alerts.clone(), self.capture_mute.clone());
+        let action_items = parking_lot::Mutex::new(ActionItemDetector::new());
+
+        let SessionControls { mut shutdown_rx, mut analysis_rx, mut correction_rx } = controls;
 
         tokio::spawn(async move {
+            let coalesce = std::time::Duration::from_millis(config.coalesce_ms);
+
+            // Final text waiting for the debounce window to close
+            let mut pending_text = String::new();
+            let mut pending_stt_ms = 0u64;
+
+            // When the first interim result for the current segment arrived
+            let mut segment_started: Option<Instant> = None;
+            // RemoteSpeech already sent for the current segment
+            let mut remote_speech_sent = false;
+            let mut deadline: Option<tokio::time::Instant> = None;
+
+            // Analysis for the previous utterance (cancelled when superseded)
+            let mut in_flight: Option<(tokio::task::JoinHandle<()>, CancellationToken)> = None;
+
+            let spawn_analysis = |utterance: String, stt_ms: u64| {
+                let mut turn_config = config.clone();
+                (turn_config.flash_model, turn_config.deep_model) = models.read().clone();
+                turn_config.mode = mode.read().clone();
+                turn_config.responses.mode = *response_mode.read();
+
+                let cancel = CancellationToken::new();
+                *analysis_cancel.lock() = Some(cancel.clone());
+                let handle = tokio::spawn(run_analysis(
+                    turn_config,
+                    utterance,
+                    stt_ms,
+                    context.clone(),
+                    event_tx.clone(),
+                    state.clone(),
+                    cost.clone(),
+                    alerter.clone(),
+                    cancel.clone(),
+                ));
+                (handle, cancel)
+            };
+
+            // Fold turns that left the prompt window into a model-written summary
+            let summarize_dropped = || {
+                if state.read().transcription_only {
+                    return;
+                }
+                let Some((transcript, upto)) = context.write().summary_request(SUMMARY_BATCH_TURNS) else {
+                    return;
+                };
+                let mut summary_config = config.clone();
+                summary_config.flash_model = models.read().0.clone();
+                tokio::spawn(summarize_history(
+                    summary_config,
+                    transcript,
+                    upto,
+                    context.clone(),
+                    state.clone(),
+                    cost.clone(),
+                ));
+            };
+
+            // Commitments in a final turn, tracked only where a call has them
+            let detect_action_items = |text: &str, from_user: bool| {
+                if !matches!(*mode.read(), ConversationMode::Meeting | ConversationMode::Coaching) {
+                    return;
+                }
+                let today = chrono::Local::now().date_naive();
+                for item in action_items.lock().scan(text, from_user, today) {
+                    state.write().action_items.push(item.clone());
+                    let _ = event_tx.send(PipelineEvent::ActionItem(item));
+                }
+            };
+
+            // Record their turn with every concern it raises; returns its type
+            let add_their_turn = |text: &str| {
+                detect_action_items(text, false);
+                let intents = intent_analyzer.analyze_multi(text);
+                let statement = statement_type(text, &intents);
+                let objection = config.objections.best_match(text);
+                for intent in &intents {
+                    notifier.notify(intent, text, mode.read().name(), lead.read().as_deref());
+                }
+
+                {
+                    let mut context = context.write();
+                    context.add_their_turn(text, intents.first().map(|i| format!("{:?}", i.category)));
+                    context.set_intents(&intents);
+                    // Keep the card until they name a different competitor
+                    let named = intents.iter().find_map(|i| i.competitor.as_deref());
+                    if let Some(card) = named.and_then(|name| config.battlecards.find(name)) {
+                        context.set_battlecard(Some(card));
+                    }
+                    // Matched on every turn: stalls like "let me think about it"
+                    // are objections too, though they aren't detected as one
+                    context.set_objection(objection);
+                }
+                summarize_dropped();
+                state.write().intents = intents.clone();
+                let _ = event_tx.send(PipelineEvent::IntentsDetected(intents));
+                if let Some(entry) = objection {
+                    let _ = event_tx.send(PipelineEvent::ObjectionMatched(entry.clone()));
+                }
+                statement
+            };
+
             loop {
+                let debounce = async move {
+                    match deadline {
+                        Some(at) => tokio::time::sleep_until(at).await,
+                        None => std::future::pending().await,
+                    }
+                };
+
                 tokio::select! {
-                    Some(segment) = transcript_rx.recv() => {
+                    Some(mut segment) = transcript_rx.recv() => {
+                        // Fix words the user has corrected before
+                        {
+                            let corrections = corrections.read();
+                            if !corrections.is_empty() {
+                                segment.text = corrections.apply(&segment.text);
+                            }
+                        }
+
                         // Add to buffer
                         transcript_buffer.add(segment.clone());
 
@@ -200,49 +1006,208 @@ impl CopilotPipeline {
                         // Emit event
                         let _ = event_tx.send(PipelineEvent::Transcript(segment.text.clone()));
 
-                        // If final segment, trigger AI analysis
-                        if segment.is_final && !segment.text.is_empty() {
-                            // Add to conversation context
-                            let intent = intent_analyzer.analyze(&segment.text);
-                            context.write().add_their_turn(&segment.text, Some(format!("{:?}", intent.category)));
-
-                            // Trigger Flash analysis
-                            let flash_result = run_flash_analysis(
-                                &config,
-                                &segment.text,
-                                &context.read().get_full_context(),
-                            ).await;
-
-                            if let Ok(flash) = flash_result {
-                                state.write().flash = Some(flash.clone());
-                                let _ = event_tx.send(PipelineEvent::FlashReady(flash.clone()));
-
-                                // Trigger Deep analysis
-                                let bullets: Vec<String> = flash.bullets.iter().map(|b| b.point.clone()).collect();
-                                let deep_result = run_deep_analysis(
-                                    &config,
-                                    &segment.text,
-                                    &context.read().get_full_context(),
-                                    &bullets,
-                                    &context.read().get_history_string(),
-                                    event_tx.clone(),
-                                    state.clone(),
-                                ).await;
-
-                                if let Err(e) = deep_result {
-                                    let _ = event_tx.send(PipelineEvent::Error(e.to_string()));
+                        // Words still in flight when paused are shown, not analyzed
+                        if state.read().is_paused {
+                            continue;
+                        }
+
+                        // Interim results are the earliest sign of who is talking
+                        if diarization_active(&config)
+                            && !remote_speech_sent
+                            && !segment.text.is_empty()
+                            && !is_user_speaker(&config, segment.speaker.as_deref())
+                        {
+                            remote_speech_sent = true;
+                            let _ = event_tx.send(PipelineEvent::RemoteSpeech);
+                        }
+
+                        let started = *segment_started.get_or_insert_with(Instant::now);
+                        if !segment.is_final {
+                            continue;
+                        }
+
+                        segment_started = None;
+                        remote_speech_sent = false;
+                        if segment.text.is_empty() {
+                            continue;
+                        }
+
+                        // Recorded as soon as it's final, so the debounce
+                        // doesn't shift where the turn sits in the call
+                        if let Some(recorder) = &recorder {
+                            let speaker = if is_user_speaker(&config, segment.speaker.as_deref()) {
+                                Speaker::User
+                            } else {
+                                Speaker::Other
+                            };
+                            // STT word times place it exactly; the clock is the fallback
+                            let span = segment.audio_span.and_then(|(start, end)| {
+                                let map = stream_map.lock();
+                                map.capture_ms(start).zip(map.capture_ms(end))
+                            });
+                            match span {
+                                Some((start_ms, end_ms)) => recorder.add_turn_at(speaker, &segment.text, start_ms, end_ms),
+                                None => recorder.add_turn(speaker, &segment.text, started.elapsed().as_millis() as u64),
+                            }
+                        }
+                        if let Some(analytics) = &analytics {
+                            let speaker = if is_user_speaker(&config, segment.speaker.as_deref()) {
+                                AnalyticsSpeaker::User
+                            } else {
+                                AnalyticsSpeaker::Other
+                            };
+                            // Talk time from the words themselves when STT gives them
+                            let duration_ms = match segment.audio_span {
+                                Some((start, end)) => ((end - start).max(0.0) * 1000.0) as u64,
+                                None => started.elapsed().as_millis() as u64,
+                            };
+                            analytics.add_turn(speaker, &segment.text, duration_ms);
+                        }
+
+                        // Our own speech is recorded but never analyzed
+                        if is_user_speaker(&config, segment.speaker.as_deref()) {
+                            context.write().add_my_turn(&segment.text);
+                            summarize_dropped();
+                            detect_action_items(&segment.text, true);
+                            continue;
+                        }
+
+                        // Likely misheard - not worth spending tokens on
+                        if is_low_confidence(&config, &segment) {
+                            let _ = event_tx.send(PipelineEvent::LowConfidence(segment));
+                            continue;
+                        }
+
+                        // Push to talk: keep the context, wait for a request
+                        if config.trigger_mode == TriggerMode::Manual {
+                            add_their_turn(&segment.text);
+                            continue;
+                        }
+
+                        // Buffer their speech until they pause
+                        if !pending_text.is_empty() {
+                            pending_text.push(' ');
+                        }
+                        pending_text.push_str(&segment.text);
+                        pending_stt_ms = started.elapsed().as_millis() as u64;
+                        deadline = Some(tokio::time::Instant::now() + coalesce);
+                    }
+                    _ = debounce => {
+                        deadline = None;
+                        let utterance = std::mem::take(&mut pending_text);
+                        if state.read().is_paused {
+                            continue;
+                        }
+
+                        // Add to conversation context; small talk and the
+                        // like stop here, before any cloud call
+                        let statement = add_their_turn(&utterance);
+                        if !config.responses.triggers_on(&statement) {
+                            tracing::debug!("No suggestion for {}: {}", statement.label(), utterance);
+                            continue;
+                        }
+
+                        // A newer utterance supersedes whatever is still streaming
+                        if let Some((_, cancel)) = in_flight.take() {
+                            cancel_analysis(&cancel, &state, &event_tx);
+                        }
+
+                        in_flight = Some(spawn_analysis(utterance, pending_stt_ms));
+                    }
+                    Some(corrected) = correction_rx.recv() => {
+                        let corrected = corrected.trim().to_string();
+                        if corrected.is_empty() {
+                            continue;
+                        }
+                        let Some(original) = transcript_buffer.replace_latest(corrected.clone()) else {
+                            continue;
+                        };
+                        if original == corrected {
+                            continue;
+                        }
+
+                        {
+                            let mut corrections = corrections.write();
+                            if let Some(learned) = corrections.learn(&original, &corrected) {
+                                tracing::info!("Learned correction: {} -> {}", learned.heard, learned.correct);
+                                if let Err(e) = corrections.save() {
+                                    tracing::warn!("Failed to save corrections: {}", e);
                                 }
                             }
                         }
+
+                        state.write().transcript = transcript_buffer.get_current_text();
+                        let _ = event_tx.send(PipelineEvent::Transcript(corrected.clone()));
+
+                        // Still waiting for them to pause: fix the pending text instead
+                        if pending_text.contains(&original) {
+                            pending_text = pending_text.replacen(&original, &corrected, 1);
+                            continue;
+                        }
+
+                        let utterance = context
+                            .write()
+                            .correct_last_their_turn(&original, &corrected)
+                            .unwrap_or_else(|| corrected.clone());
+                        if state.read().is_paused {
+                            continue;
+                        }
+
+                        if let Some((_, cancel)) = in_flight.take() {
+                            cancel_analysis(&cancel, &state, &event_tx);
+                        }
+
+                        in_flight = Some(spawn_analysis(utterance, 0));
+                    }
+                    Some(status) = stt_status_rx.recv() => {
+                        let event = match status {
+                            DeepgramStatus::Reconnecting { attempt } => PipelineEvent::SttReconnecting(attempt),
+                            DeepgramStatus::Reconnected => {
+                                stream_map.lock().restart();
+                                PipelineEvent::SttReconnected
+                            }
+                            DeepgramStatus::Disconnected => {
+                                let message = "Lost connection to Deepgram".to_string();
+                                state.write().error = Some(message.clone());
+                                PipelineEvent::Error(message)
+                            }
+                        };
+                        let _ = event_tx.send(event);
+                    }
+                    Some(()) = analysis_rx.recv() => {
+                        if state.read().is_paused {
+                            continue;
+                        }
+                        let since = chrono::Utc::now()
+                            - chrono::Duration::seconds(config.manual_window_secs as i64);
+                        let utterance = transcript_buffer
+                            .get_segments_since(since)
+                            .into_iter()
+                            .filter(|s| !is_user_speaker(&config, s.speaker.as_deref()))
+                            .map(|s| s.text)
+                            .collect::<Vec<_>>()
+                            .join(" ");
+
+                        if utterance.is_empty() {
+                            continue;
+                        }
+
+                        if let Some((_, cancel)) = in_flight.take() {
+                            cancel_analysis(&cancel, &state, &event_tx);
+                        }
+
+                        in_flight = Some(spawn_analysis(utterance, 0));
                     }
                     _ = shutdown_rx.recv() => {
+                        if let Some((handle, cancel)) = in_flight.take() {
+                            cancel.cancel();
+                            handle.abort();
+                        }
                         break;
                     }
                 }
             }
         });
-
-        Ok(())
     }
 
     /// Stop the pipeline
@@ -250,30 +1215,339 @@ impl CopilotPipeline {
         if let Some(tx) = self.shutdown_tx.take() {
             let _ = tx.try_send(());
         }
-        self.state.write().is_running = false;
+        self.analysis_tx = None;
+        self.correction_tx = None;
+        self.audio_capture = None;
+        {
+            let mut state = self.state.write();
+            state.is_running = false;
+            state.is_paused = false;
+        }
         self.transcript_buffer.clear();
         let _ = self.event_tx.send(PipelineEvent::Stopped);
     }
 }
 
+/// When each recorded turn should arrive, relative to the first
+///
+/// System turns aren't speech and are skipped.
+fn replay_schedule(session: &RecordingSession, speed: f32) -> Vec<(std::time::Duration, TranscriptSegment)> {
+    let Some(first) = session.turns.first().map(|t| t.timestamp) else {
+        return Vec::new();
+    };
+
+    session
+        .turns
+        .iter()
+        .filter_map(|turn| {
+            let speaker = match turn.speaker {
+                Speaker::User => REPLAY_USER_SPEAKER,
+                Speaker::Other => REPLAY_OTHER_SPEAKER,
+                Speaker::System => return None,
+            };
+            let at = (turn.timestamp - first).to_std().unwrap_or_default();
+            let segment = TranscriptSegment {
+                text: turn.text.clone(),
+                confidence: 1.0,
+                is_final: true,
+                speaker: Some(speaker.to_string()),
+                timestamp: turn.timestamp,
+                audio_span: None,
+            };
+            Some((at.div_f32(speed), segment))
+        })
+        .collect()
+}
+
+/// Check whether a diarized speaker ID is the user
+fn is_user_speaker(config: &PipelineConfig, speaker: Option<&str>) -> bool {
+    match (config.diarize, config.user_speaker.as_deref(), speaker) {
+        (true, Some(user), Some(speaker)) => user == speaker,
+        _ => false,
+    }
+}
+
+/// Whether a segment is too unreliable to analyze
+fn is_low_confidence(config: &PipelineConfig, segment: &TranscriptSegment) -> bool {
+    segment.confidence < config.min_confidence
+}
+
+/// Whether diarization can tell the user apart from the other side
+fn diarization_active(config: &PipelineConfig) -> bool {
+    config.diarize && config.user_speaker.is_some()
+}
+
+/// Cancel an analysis round and clear whatever it had streamed
+///
+/// Holds the state lock while cancelling so a stale chunk is either
+/// cleared here or rejected by `push_deep_chunk`, never left behind.
+fn cancel_analysis(
+    cancel: &CancellationToken,
+    state: &RwLock<CopilotState>,
+    event_tx: &broadcast::Sender<PipelineEvent>,
+) {
+    let mut state = state.write();
+    cancel.cancel();
+
+    if state.deep_streaming {
+        state.deep_streaming = false;
+        state.deep_content.clear();
+        let _ = event_tx.send(PipelineEvent::DeepCancelled);
+    }
+}
+
+/// Append deep output unless the round was cancelled
+fn push_deep_chunk(
+    text: String,
+    cancel: &CancellationToken,
+    state: &RwLock<CopilotState>,
+    event_tx: &broadcast::Sender<PipelineEvent>,
+) -> bool {
+    let mut state = state.write();
+    if cancel.is_cancelled() {
+        return false;
+    }
+
+    state.deep_content.push_str(&text);
+    let _ = event_tx.send(PipelineEvent::DeepChunk(text));
+    true
+}
+
+/// Run flash then deep analysis for one utterance, as the response mode allows
+async fn run_analysis(
+    config: PipelineConfig,
+    utterance: String,
+    stt_ms: u64,
+    context: Arc<RwLock<ConversationContext>>,
+    event_tx: broadcast::Sender<PipelineEvent>,
+    state: Arc<RwLock<CopilotState>>,
+    cost: Arc<RwLock<CostTracker>>,
+    alerter: Alerter,
+    cancel: CancellationToken,
+) {
+    // Reported once at start; not an error on every turn
+    if state.read().transcription_only {
+        return;
+    }
+
+    let mut timing = TurnTiming {
+        stt_ms,
+        ..TurnTiming::default()
+    };
+
+    let full_context = with_language(&config, context.read().get_full_context());
+
+    // Trigger Flash analysis, unless only the detailed answer is wanted
+    let bullets: Vec<String> = if config.responses.mode.runs_flash() {
+        let flash_context = format!("{}\n\n{}", full_context, config.responses.flash_instruction());
+        let flash_started = Instant::now();
+        let mut flash = tokio::select! {
+            _ = cancel.cancelled() => return,
+            result = run_flash_analysis(&config, &utterance, &flash_context, &event_tx) => match result {
+                Ok(flash) => flash,
+                Err(error) => {
+                    report_error(&state, &event_tx, CopilotError::Flash {
+                        provider: config.flash_model.provider().to_string(),
+                        error,
+                    });
+                    return;
+                }
+            },
+        };
+        timing.flash_ms = flash_started.elapsed().as_millis() as u64;
+        config.mode.customize_flash(&mut flash);
+        limit_bullets(&mut flash, config.responses.max_bullets, config.responses.max_bullet_words);
+
+        if let Some(usage) = flash.usage {
+            record_usage(&cost, &state, &config.flash_model.cost_model(), usage);
+        }
+
+        {
+            let mut state = state.write();
+            if cancel.is_cancelled() {
+                return;
+            }
+            state.flash = Some(flash.clone());
+            let _ = event_tx.send(PipelineEvent::FlashReady(flash.clone()));
+        }
+
+        let level = alerter.alert(&flash.urgency);
+        if level != AlertLevel::Silent {
+            let _ = event_tx.send(PipelineEvent::UrgentAlert(level));
+        }
+
+        flash.bullets.iter().map(|b| b.point.clone()).collect()
+    } else {
+        Vec::new()
+    };
+
+    // Bullets only: Deep is skipped entirely
+    if !config.responses.mode.runs_deep() {
+        state.write().record_timing(timing);
+        let _ = event_tx.send(PipelineEvent::Timing(timing));
+        return;
+    }
+
+    // Pull in relevant history from past calls
+    let full_context = match &config.memory {
+        Some(memory) => {
+            let client = MemoryClient::new(memory.clone());
+            let snippets = tokio::select! {
+                _ = cancel.cancelled() => return,
+                result = client.search(&utterance) => result.unwrap_or_else(|e| {
+                    tracing::debug!("Memory search failed: {}", e);
+                    Vec::new()
+                }),
+            };
+            let mut context = context.write();
+            context.set_memory(&snippets);
+            with_language(&config, context.get_full_context())
+        }
+        None => full_context,
+    };
+
+    // Trigger Deep analysis
+    let history = context.read().get_windowed_context(DEEP_HISTORY_TURNS);
+    let deep_result = run_deep_analysis(
+        &config,
+        &utterance,
+        &full_context,
+        &bullets,
+        &history,
+        event_tx.clone(),
+        state.clone(),
+        &cost,
+        &cancel,
+        &mut timing,
+    ).await;
+
+    if cancel.is_cancelled() {
+        return;
+    }
+
+    if let Err(e) = deep_result {
+        report_error(&state, &event_tx, CopilotError::Deep {
+            provider: config.deep_model.provider().to_string(),
+            error: DeepError::classify(&e),
+        });
+        return;
+    }
+
+    state.write().record_timing(timing);
+    let _ = event_tx.send(PipelineEvent::Timing(timing));
+}
+
+/// Ask for answers in the configured language
+fn with_language(config: &PipelineConfig, context: String) -> String {
+    match config.language.instruction() {
+        Some(instruction) => format!("{}\n\n{}", context, instruction),
+        None => context,
+    }
+}
+
+/// Record a classified error and tell the UI
+fn report_error(
+    state: &RwLock<CopilotState>,
+    event_tx: &broadcast::Sender<PipelineEvent>,
+    error: CopilotError,
+) {
+    tracing::warn!("{} ({})", error, error.error());
+    state.write().error = Some(error.message());
+    let _ = event_tx.send(PipelineEvent::TypedError(error));
+}
+
+/// Add a call's tokens to the session total and refresh the state
+fn record_usage(
+    cost: &RwLock<CostTracker>,
+    state: &RwLock<CopilotState>,
+    model: &str,
+    usage: TokenUsage,
+) {
+    let mut cost = cost.write();
+    cost.record(model, usage);
+
+    let mut state = state.write();
+    state.estimated_cost_usd = cost.total_cost_usd();
+    state.total_tokens = cost.total_tokens();
+}
+
 /// Run flash analysis using configured model
+///
+/// Local models stream, sending `FlashBullet` for each bullet as it's
+/// generated; their bullets take seconds where hosted ones take a fraction.
 async fn run_flash_analysis(
     config: &PipelineConfig,
     transcript: &str,
     context: &str,
-) -> Result<FlashAnalysis> {
+    event_tx: &broadcast::Sender<PipelineEvent>,
+) -> Result<FlashAnalysis, FlashError> {
     match &config.flash_model {
-        FlashModelChoice::GeminiFlash => {
-            let client = GeminiFlash::new(config.google_key.clone().unwrap_or_default());
-            client.analyze(transcript, context).await
+        FlashModelChoice::LocalOllama(model) if config.providers.flash.is_none() => {
+            let client = OllamaFlash::new().with_model(model.clone());
+            let (bullet_tx, mut bullet_rx) = mpsc::channel::<Bullet>(8);
+            let forward = async {
+                let mut index = 0;
+                while let Some(bullet) = bullet_rx.recv().await {
+                    if index < config.responses.max_bullets {
+                        let _ = event_tx.send(PipelineEvent::FlashBullet(index, bullet));
+                    }
+                    index += 1;
+                }
+            };
+            let (analysis, ()) = tokio::join!(client.analyze_streaming(transcript, context, bullet_tx), forward);
+            analysis
         }
+        _ => flash_client(config).analyze(transcript, context).await,
+    }
+}
+
+/// The configured flash model as a provider
+fn flash_client(config: &PipelineConfig) -> Arc<dyn FlashProvider> {
+    if let Some(provider) = &config.providers.flash {
+        return provider.clone();
+    }
+
+    match &config.flash_model {
+        FlashModelChoice::GeminiFlash => Arc::new(GeminiFlash::new(config.google_key.clone().unwrap_or_default())),
         FlashModelChoice::GPT4oMini => {
-            let client = GPT4oMini::new(config.openai_key.clone().unwrap_or_default());
-            client.analyze(transcript, context).await
+            let key = config.openai_key.clone().unwrap_or_default();
+            match &config.azure {
+                Some(azure) => Arc::new(GPT4oMini::azure(key, azure)),
+                None => Arc::new(GPT4oMini::new(key)),
+            }
         }
-        FlashModelChoice::LocalOllama(model) => {
-            let client = OllamaFlash::new().with_model(model.clone());
-            client.analyze(transcript, context).await
+        FlashModelChoice::LocalOllama(model) => Arc::new(OllamaFlash::new().with_model(model.clone())),
+        FlashModelChoice::Groq(model) => {
+            Arc::new(GroqFlash::new(config.groq_key.clone().unwrap_or_default()).with_model(model.clone()))
+        }
+        FlashModelChoice::OpenRouter(model) => {
+            Arc::new(OpenRouterClient::new(config.openrouter_key.clone().unwrap_or_default(), model.clone()))
+        }
+    }
+}
+
+/// Have the flash model summarize turns that left the prompt window
+///
+/// Its one-line summary replaces their extractive notes; if the call
+/// fails they keep them.
+async fn summarize_history(
+    config: PipelineConfig,
+    transcript: String,
+    upto: u64,
+    context: Arc<RwLock<ConversationContext>>,
+    state: Arc<RwLock<CopilotState>>,
+    cost: Arc<RwLock<CostTracker>>,
+) {
+    match flash_client(&config).analyze(&transcript, SUMMARY_INSTRUCTION).await {
+        Ok(analysis) => {
+            if let Some(usage) = analysis.usage {
+                record_usage(&cost, &state, &config.flash_model.cost_model(), usage);
+            }
+            context.write().apply_summary(upto, &analysis.summary);
+        }
+        Err(e) => {
+            tracing::debug!("Summarizing earlier turns failed, keeping notes: {}", e);
+            context.write().summary_failed();
         }
     }
 }
@@ -287,6 +1561,9 @@ async fn run_deep_analysis(
     history: &str,
     event_tx: broadcast::Sender<PipelineEvent>,
     state: Arc<RwLock<CopilotState>>,
+    cost: &RwLock<CostTracker>,
+    cancel: &CancellationToken,
+    timing: &mut TurnTiming,
 ) -> Result<()> {
     let mut router = ModelRouter::new();
 
@@ -294,37 +1571,102 @@ async fn run_deep_analysis(
         router = router.with_claude(key.clone());
     }
     if let Some(key) = &config.openai_key {
-        router = router.with_gpt4o(key.clone()).with_o1(key.clone());
+        router = match &config.azure {
+            Some(azure) => router.with_azure(key.clone(), azure),
+            None => router.with_gpt4o(key.clone()).with_o1(key.clone()),
+        };
+    }
+    if let Some(key) = &config.openrouter_key {
+        router = router.with_openrouter(key.clone());
+    }
+
+    router = router
+        .with_default(config.deep_model.clone())
+        .with_max_words(config.responses.deep_max_words);
+
+    {
+        let mut state = state.write();
+        if cancel.is_cancelled() {
+            return Ok(());
+        }
+        state.deep_streaming = true;
+        state.deep_content.clear();
     }
 
-    router = router.with_default(config.deep_model.clone());
+    let deep_started = Instant::now();
 
-    state.write().deep_streaming = true;
-    state.write().deep_content.clear();
+    let mut stream = tokio::select! {
+        _ = cancel.cancelled() => return Ok(()),
+        stream = async {
+            match &config.providers.deep {
+                Some(provider) => provider.analyze_streaming(transcript, context, bullets, history).await,
+                None => router.analyze_streaming(transcript, context, bullets, history, config.deep_model.clone()).await,
+            }
+        } => stream?,
+    };
 
-    let mut stream = router
-        .analyze_streaming(transcript, context, bullets, history, config.deep_model.clone())
-        .await?;
+    loop {
+        // Dropping the receiver on cancel also stops the provider task
+        let chunk = tokio::select! {
+            _ = cancel.cancelled() => return Ok(()),
+            chunk = stream.receiver.recv() => match chunk {
+                Some(chunk) => chunk,
+                None => break,
+            },
+        };
 
-    while let Some(chunk) = stream.receiver.recv().await {
         match chunk {
-            crate::deep::streaming::StreamChunk::Content(text) => {
-                state.write().deep_content.push_str(&text);
-                let _ = event_tx.send(PipelineEvent::DeepChunk(text));
+            StreamChunk::Content(text) => {
+                if timing.deep_first_token_ms == 0 {
+                    timing.deep_first_token_ms = deep_started.elapsed().as_millis() as u64;
+                }
+                if !push_deep_chunk(text, cancel, &state, &event_tx) {
+                    return Ok(());
+                }
             }
-            crate::deep::streaming::StreamChunk::Question(q) => {
-                state.write().question_to_ask = Some(q.clone());
+            StreamChunk::Question(q) => {
+                let mut state = state.write();
+                if cancel.is_cancelled() {
+                    return Ok(());
+                }
+                state.question_to_ask = Some(q.clone());
                 let _ = event_tx.send(PipelineEvent::QuestionReady(q));
             }
-            crate::deep::streaming::StreamChunk::Done => {
-                state.write().deep_streaming = false;
+            StreamChunk::Info(note) => {
+                tracing::info!("Deep: {}", note);
+            }
+            StreamChunk::Usage { model, usage } => {
+                record_usage(cost, &state, &model, usage);
+            }
+            StreamChunk::Done => {
+                timing.deep_total_ms = deep_started.elapsed().as_millis() as u64;
+                let mut state = state.write();
+                if cancel.is_cancelled() {
+                    return Ok(());
+                }
+                state.deep_streaming = false;
                 let _ = event_tx.send(PipelineEvent::DeepComplete);
+
+                if config.followup.auto_extract {
+                    if let Some(at) = extract_followup(&state.deep_content, chrono::Local::now(), config.followup.hour) {
+                        state.suggested_followup = Some(at);
+                        let _ = event_tx.send(PipelineEvent::FollowupSuggested(at));
+                    }
+                }
                 break;
             }
-            crate::deep::streaming::StreamChunk::Error(e) => {
-                state.write().deep_streaming = false;
-                state.write().error = Some(e.clone());
-                let _ = event_tx.send(PipelineEvent::Error(e));
+            StreamChunk::Error(error) => {
+                let error = CopilotError::Deep {
+                    provider: config.deep_model.provider().to_string(),
+                    error,
+                };
+                let mut state = state.write();
+                if cancel.is_cancelled() {
+                    return Ok(());
+                }
+                state.deep_streaming = false;
+                state.error = Some(error.message());
+                let _ = event_tx.send(PipelineEvent::TypedError(error));
                 break;
             }
             _ => {}
@@ -333,3 +1675,432 @@ async fn run_deep_analysis(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Variant name only, e.g. "FlashReady"
+    fn event_name(event: &PipelineEvent) -> String {
+        let debug = format!("{:?}", event);
+        debug.split(['(', ' ']).next().unwrap_or_default().to_string()
+    }
+
+    fn segment(text: &str, is_final: bool) -> TranscriptSegment {
+        TranscriptSegment {
+            text: text.to_string(),
+            confidence: 0.95,
+            is_final,
+            speaker: None,
+            timestamp: chrono::Utc::now(),
+            audio_span: None,
+        }
+    }
+
+    fn mock_config(flash_delay: std::time::Duration) -> PipelineConfig {
+        PipelineConfig {
+            coalesce_ms: 20,
+            providers: InjectedProviders {
+                flash: Some(Arc::new(crate::flash::MockFlashProvider {
+                    delay: flash_delay,
+                    ..crate::flash::MockFlashProvider::default()
+                })),
+                deep: Some(Arc::new(crate::deep::MockDeepProvider::default())),
+            },
+            ..PipelineConfig::default()
+        }
+    }
+
+    /// Feeds `text` in as STT would (an interim, then the final result),
+    /// waits for the turn's timing and stops. Returns the event names in
+    /// the order they arrived.
+    async fn run_mock_turn(config: PipelineConfig, text: &str) -> (Vec<String>, CopilotPipeline) {
+        let (segment_tx, segment_rx) = mpsc::channel(4);
+        let mut pipeline = CopilotPipeline::new(config).with_transcript_source(segment_rx);
+        let mut events = pipeline.subscribe();
+        pipeline.start().await.unwrap();
+        assert!(pipeline.state().is_running);
+
+        let interim = text.rsplit_once(' ').map_or(text, |(head, _)| head);
+        segment_tx.send(segment(interim, false)).await.unwrap();
+        segment_tx.send(segment(text, true)).await.unwrap();
+
+        let mut seen = Vec::new();
+        let finished = tokio::time::timeout(std::time::Duration::from_secs(5), async {
+            while let Ok(event) = events.recv().await {
+                seen.push(event_name(&event));
+                if matches!(event, PipelineEvent::Timing(_)) {
+                    break;
+                }
+            }
+        })
+        .await;
+        assert!(finished.is_ok(), "timed out after {:?}", seen);
+
+        pipeline.stop();
+        while let Ok(event) = events.try_recv() {
+            seen.push(event_name(&event));
+        }
+        (seen, pipeline)
+    }
+
+    #[test]
+    fn test_replay_schedule() {
+        let mut session = RecordingSession::new("sales");
+        let start = chrono::Utc::now();
+        for (secs, speaker, text) in [
+            (0, Speaker::Other, "What does it cost?"),
+            (4, Speaker::System, "Paused"),
+            (10, Speaker::User, "It starts at $50 a seat"),
+        ] {
+            session.add_turn(crate::recording::RecordedTurn {
+                timestamp: start + chrono::Duration::seconds(secs),
+                speaker,
+                text: text.to_string(),
+                duration_ms: 0,
+                start_ms: None,
+                end_ms: None,
+            });
+        }
+
+        let schedule = replay_schedule(&session, 2.0);
+        assert_eq!(schedule.len(), 2);
+        assert_eq!(schedule[0].0, std::time::Duration::ZERO);
+        assert_eq!(schedule[1].0, std::time::Duration::from_secs(5));
+
+        let config = PipelineConfig {
+            diarize: true,
+            user_speaker: Some(REPLAY_USER_SPEAKER.to_string()),
+            ..PipelineConfig::default()
+        };
+        assert!(!is_user_speaker(&config, schedule[0].1.speaker.as_deref()));
+        assert!(is_user_speaker(&config, schedule[1].1.speaker.as_deref()));
+
+        // Until the user picks their speaker, nobody is treated as them
+        assert!(!is_user_speaker(&PipelineConfig::default(), Some("0")));
+    }
+
+    #[tokio::test]
+    async fn test_pipeline_with_mock_providers() {
+        let config = mock_config(std::time::Duration::ZERO);
+        let (seen, pipeline) = run_mock_turn(config, "How much does it cost per seat?").await;
+
+        assert_eq!(
+            seen,
+            [
+                "Started",
+                "Transcript",
+                "Transcript",
+                "IntentsDetected",
+                "FlashReady",
+                "DeepChunk",
+                "DeepChunk",
+                "QuestionReady",
+                "DeepComplete",
+                "Timing",
+                "Stopped",
+            ]
+        );
+        let state = pipeline.state();
+        assert_eq!(state.deep_content, "Pricing starts at $50 per seat.");
+        assert_eq!(state.question_to_ask.as_deref(), Some("How many seats are you planning for?"));
+        assert!(!state.is_running);
+    }
+
+    #[tokio::test]
+    async fn test_flash_only_skips_deep() {
+        let mut config = mock_config(std::time::Duration::from_millis(20));
+        config.responses.mode = ResponseMode::FlashOnly;
+        let (seen, pipeline) = run_mock_turn(config, "How much does it cost per seat?").await;
+
+        assert_eq!(
+            seen,
+            ["Started", "Transcript", "Transcript", "IntentsDetected", "FlashReady", "Timing", "Stopped"]
+        );
+        let state = pipeline.state();
+        assert!(state.deep_content.is_empty());
+        // Deep didn't run, so it has no average rather than a zero one
+        let average = state.average_timing.unwrap();
+        assert!(average.flash_ms >= 20);
+        assert_eq!(average.deep_first_token_ms, 0);
+        assert_eq!(state.last_timing.unwrap().label(), format!("Flash {}", format_ms(average.flash_ms)));
+    }
+
+    #[tokio::test]
+    async fn test_transcription_only_without_flash_key() {
+        // A key, but not for the selected flash model (Gemini)
+        let config = PipelineConfig {
+            coalesce_ms: 20,
+            groq_key: Some("gsk-test".to_string()),
+            ..PipelineConfig::default()
+        };
+        let (segment_tx, segment_rx) = mpsc::channel(4);
+        let mut pipeline = CopilotPipeline::new(config).with_transcript_source(segment_rx);
+        let mut events = pipeline.subscribe();
+        pipeline.start().await.unwrap();
+        assert!(pipeline.state().transcription_only);
+
+        for text in ["How much does it cost per seat?", "Do you offer annual plans?"] {
+            segment_tx.send(segment(text, true)).await.unwrap();
+            tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+        pipeline.stop();
+
+        // Said once up front, then turns are shown without an error each
+        let mut seen = Vec::new();
+        while let Ok(event) = events.try_recv() {
+            seen.push(event_name(&event));
+        }
+        assert_eq!(
+            seen,
+            [
+                "Started",
+                "TranscriptionOnly",
+                "Transcript",
+                "IntentsDetected",
+                "Transcript",
+                "IntentsDetected",
+                "Stopped",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_webhook_names_the_lead() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let config = PipelineConfig {
+            coalesce_ms: 20,
+            webhooks: WebhookConfig {
+                url: format!("http://{}/hook", listener.local_addr().unwrap()),
+                min_confidence: 0.0,
+                ..WebhookConfig::default()
+            },
+            lead: Some("Initech".to_string()),
+            ..mock_config(std::time::Duration::ZERO)
+        };
+        let mut pipeline = CopilotPipeline::new(config);
+        // The brief was filled from another lead before the call
+        pipeline.set_lead(Some("Acme".to_string()));
+
+        let mut session = RecordingSession::new("sales");
+        session.add_turn(crate::recording::RecordedTurn {
+            timestamp: chrono::Utc::now(),
+            speaker: Speaker::Other,
+            text: "Sounds good, what are the next steps?".to_string(),
+            duration_ms: 0,
+            start_ms: None,
+            end_ms: None,
+        });
+        pipeline.replay(&session, 1.0).unwrap();
+
+        let request = tokio::time::timeout(std::time::Duration::from_secs(5), async {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut request = Vec::new();
+            let mut buf = [0u8; 4096];
+            // Headers never end in a brace; the JSON body does
+            while !String::from_utf8_lossy(&request).trim_end().ends_with('}') {
+                let n = socket.read(&mut buf).await.unwrap();
+                if n == 0 {
+                    break;
+                }
+                request.extend_from_slice(&buf[..n]);
+            }
+            socket.write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 0\r\n\r\n").await.unwrap();
+            String::from_utf8_lossy(&request).into_owned()
+        })
+        .await
+        .expect("no webhook delivered");
+        pipeline.stop();
+
+        let body = &request[request.find("\r\n\r\n").unwrap() + 4..];
+        let body: serde_json::Value = serde_json::from_str(body).unwrap();
+        assert_eq!(body["category"], "BuyingSignal");
+        assert_eq!(body["lead"], "Acme");
+    }
+
+    #[tokio::test]
+    async fn test_pause_cancels_and_skips_analysis() {
+        let mut pipeline = CopilotPipeline::new(mock_config(std::time::Duration::from_millis(200)));
+        let mut events = pipeline.subscribe();
+
+        let start = chrono::Utc::now();
+        let mut session = RecordingSession::new("sales");
+        for (offset_ms, text) in [(0, "How much does it cost per seat?"), (300, "Is there an annual discount?")] {
+            session.add_turn(crate::recording::RecordedTurn {
+                timestamp: start + chrono::Duration::milliseconds(offset_ms),
+                speaker: Speaker::Other,
+                text: text.to_string(),
+                duration_ms: 0,
+                start_ms: None,
+                end_ms: None,
+            });
+        }
+        pipeline.replay(&session, 1.0).unwrap();
+
+        // Pause while the first turn's Flash is still running
+        let analyzing = tokio::time::timeout(std::time::Duration::from_secs(5), async {
+            while !matches!(events.recv().await, Ok(PipelineEvent::IntentsDetected(_))) {}
+        })
+        .await;
+        assert!(analyzing.is_ok());
+        pipeline.pause();
+        assert!(pipeline.state().is_paused);
+
+        // The second turn is shown but not analyzed, and the first's
+        // Flash (done by then) never reports
+        let mut seen = Vec::new();
+        let shown = tokio::time::timeout(std::time::Duration::from_secs(5), async {
+            while let Ok(event) = events.recv().await {
+                let done = matches!(&event, PipelineEvent::Transcript(text) if text.contains("annual"));
+                seen.push(event_name(&event));
+                if done {
+                    break;
+                }
+            }
+        })
+        .await;
+        assert!(shown.is_ok(), "timed out after {:?}", seen);
+        tokio::time::sleep(std::time::Duration::from_millis(300)).await;
+        while let Ok(event) = events.try_recv() {
+            seen.push(event_name(&event));
+        }
+        assert_eq!(seen, ["Paused", "Transcript"]);
+        assert!(pipeline.state().flash.is_none());
+
+        pipeline.resume();
+        assert!(matches!(events.recv().await, Ok(PipelineEvent::Resumed)));
+
+        // Stopping while paused leaves nothing paused for the next call
+        pipeline.pause();
+        pipeline.stop();
+        let state = pipeline.state();
+        assert!(!state.is_paused);
+        assert!(!state.is_running);
+    }
+
+    #[test]
+    fn test_timing_label() {
+        let timing = TurnTiming {
+            stt_ms: 300,
+            flash_ms: 680,
+            deep_first_token_ms: 1200,
+            deep_total_ms: 4500,
+        };
+        assert_eq!(timing.label(), "Flash 680ms / Deep 1.2s");
+
+        let flash_only = TurnTiming { deep_first_token_ms: 0, deep_total_ms: 0, ..timing };
+        assert_eq!(flash_only.label(), "Flash 680ms");
+    }
+
+    #[test]
+    fn test_rolling_average() {
+        let mut state = CopilotState::default();
+        for flash_ms in [400, 600, 800] {
+            state.record_timing(TurnTiming {
+                flash_ms,
+                ..TurnTiming::default()
+            });
+        }
+
+        assert_eq!(state.last_timing.unwrap().flash_ms, 800);
+        assert_eq!(state.average_timing.unwrap().flash_ms, 600);
+
+        // Turns where Deep failed or was cancelled don't pull its average down
+        state.record_timing(TurnTiming {
+            flash_ms: 600,
+            deep_first_token_ms: 1200,
+            deep_total_ms: 3000,
+            ..TurnTiming::default()
+        });
+        state.record_timing(TurnTiming {
+            flash_ms: 600,
+            ..TurnTiming::default()
+        });
+        let average = state.average_timing.unwrap();
+        assert_eq!(average.flash_ms, 600);
+        assert_eq!(average.deep_first_token_ms, 1200);
+        assert_eq!(average.deep_total_ms, 3000);
+
+        for _ in 0..TIMING_WINDOW {
+            state.record_timing(TurnTiming::default());
+        }
+        assert_eq!(state.recent_timings.len(), TIMING_WINDOW);
+        assert_eq!(state.average_timing.unwrap().flash_ms, 0);
+    }
+
+    #[test]
+    fn test_cancel_clears_stale_deep_output() {
+        let (event_tx, mut event_rx) = broadcast::channel(16);
+        let state = RwLock::new(CopilotState::default());
+        let cancel = CancellationToken::new();
+
+        state.write().deep_streaming = true;
+        assert!(push_deep_chunk("Old answer ".to_string(), &cancel, &state, &event_tx));
+
+        cancel_analysis(&cancel, &state, &event_tx);
+        assert!(cancel.is_cancelled());
+        assert!(!state.read().deep_streaming);
+        assert!(state.read().deep_content.is_empty());
+
+        // A chunk that arrives after cancelling must not leak into the next round
+        assert!(!push_deep_chunk("stale".to_string(), &cancel, &state, &event_tx));
+        assert!(state.read().deep_content.is_empty());
+
+        assert!(matches!(event_rx.try_recv(), Ok(PipelineEvent::DeepChunk(_))));
+        assert!(matches!(event_rx.try_recv(), Ok(PipelineEvent::DeepCancelled)));
+        assert!(event_rx.try_recv().is_err());
+    }
+
+    #[test]
+    fn test_flash_cost_model() {
+        let tracker = CostTracker::new();
+        assert!(tracker.price_for(&FlashModelChoice::GeminiFlash.cost_model()).is_some());
+
+        let local = FlashModelChoice::LocalOllama("llama3.1:8b".to_string()).cost_model();
+        assert_eq!(local, "ollama/llama3.1:8b");
+        assert_eq!(tracker.price_for(&local).unwrap().cost(&TokenUsage::new(500, 500)), 0.0);
+
+        let routed = FlashModelChoice::OpenRouter(crate::flash::DEFAULT_OPENROUTER_FLASH_MODEL.to_string());
+        assert!(tracker.price_for(&routed.cost_model()).is_some());
+
+        let groq = FlashModelChoice::Groq(crate::flash::DEFAULT_GROQ_MODEL.to_string()).cost_model();
+        assert_eq!(groq, "groq/llama-3.1-8b-instant");
+        assert!(tracker.price_for(&groq).is_some());
+    }
+
+    #[test]
+    fn test_per_mode_models() {
+        let mut config = PipelineConfig::default();
+        config.per_mode_deep.insert("technical".to_string(), ModelChoice::O1Preview);
+
+        let pipeline = CopilotPipeline::new(config);
+        assert_eq!(pipeline.models.read().1, ModelChoice::ClaudeSonnet);
+
+        pipeline.set_mode("Technical");
+        assert_eq!(pipeline.models.read().1, ModelChoice::O1Preview);
+        assert!(matches!(pipeline.models.read().0, FlashModelChoice::GeminiFlash));
+
+        pipeline.set_mode("sales");
+        assert_eq!(pipeline.models.read().1, ModelChoice::ClaudeSonnet);
+    }
+
+    #[test]
+    fn test_low_confidence_threshold() {
+        let config = PipelineConfig::default();
+        let mut segment = TranscriptSegment {
+            text: "coober netties".to_string(),
+            confidence: 0.4,
+            is_final: true,
+            speaker: Some("1".to_string()),
+            timestamp: chrono::Utc::now(),
+            audio_span: None,
+        };
+        assert!(is_low_confidence(&config, &segment));
+
+        segment.confidence = 0.9;
+        assert!(!is_low_confidence(&config, &segment));
+    }
+}
@@ -0,0 +1,152 @@
+//! Webhook Notifications
+//!
+//! Pings a team channel when a live call shows a configured intent (by
+//! default a strong buying signal). Payloads are signed with HMAC-SHA256
+//! over "{timestamp}.{body}" so receivers can verify they came from us.
+
+use anyhow::Result;
+use chrono::Utc;
+use hmac::{Hmac, Mac};
+use parking_lot::Mutex;
+use reqwest::Client;
+use serde_json::{json, Value};
+use sha2::Sha256;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use super::intent::DetectedIntent;
+use crate::config::WebhookConfig;
+use crate::flash::retry::{with_backoff, HttpStatusError};
+
+/// Retries after the first delivery attempt
+const MAX_RETRIES: u32 = 3;
+
+/// Longest wait for the receiver per attempt
+const DELIVERY_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Hex HMAC-SHA256 signature of a timestamped body
+pub fn sign(secret: &str, timestamp: i64, body: &str) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts any key length");
+    mac.update(format!("{}.{}", timestamp, body).as_bytes());
+    mac.finalize()
+        .into_bytes()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+/// Notification body; `text` makes it render as-is in Slack and Teams
+pub fn payload(intent: &DetectedIntent, quote: &str, mode: &str, lead: Option<&str>) -> Value {
+    let text = match lead {
+        Some(lead) => format!("{} {} on a {} call with {}: \"{}\"", intent.category.emoji(), intent.category.label(), mode, lead, quote),
+        None => format!("{} {} on a {} call: \"{}\"", intent.category.emoji(), intent.category.label(), mode, quote),
+    };
+
+    json!({
+        "event": "intent_detected",
+        "text": text,
+        "category": format!("{:?}", intent.category),
+        "confidence": intent.confidence,
+        "triggers": intent.triggers,
+        "quote": quote,
+        "mode": mode,
+        "lead": lead,
+        "detected_at": Utc::now().to_rfc3339(),
+    })
+}
+
+/// Sends intent notifications, at most one per category per cooldown
+#[derive(Clone)]
+pub struct WebhookNotifier {
+    client: Client,
+    config: Arc<WebhookConfig>,
+    last_sent: Arc<Mutex<HashMap<String, Instant>>>,
+}
+
+impl WebhookNotifier {
+    pub fn new(config: WebhookConfig) -> Self {
+        Self {
+            client: Client::new(),
+            config: Arc::new(config),
+            last_sent: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Notify in the background if this intent is configured and not cooling down
+    pub fn notify(&self, intent: &DetectedIntent, quote: &str, mode: &str, lead: Option<&str>) {
+        let category = format!("{:?}", intent.category);
+        if !self.config.triggers(&category, intent.confidence) {
+            return;
+        }
+
+        {
+            let mut last_sent = self.last_sent.lock();
+            let cooldown = Duration::from_secs(self.config.cooldown_secs);
+            if last_sent.get(&category).is_some_and(|at| at.elapsed() < cooldown) {
+                return;
+            }
+            last_sent.insert(category, Instant::now());
+        }
+
+        let body = payload(intent, quote, mode, lead).to_string();
+        let notifier = self.clone();
+        tokio::spawn(async move {
+            if let Err(e) = with_backoff(MAX_RETRIES, || notifier.deliver(&body)).await {
+                tracing::warn!("Webhook delivery failed: {}", e);
+            }
+        });
+    }
+
+    /// POST one signed payload
+    async fn deliver(&self, body: &str) -> Result<()> {
+        let timestamp = Utc::now().timestamp();
+        let mut request = self
+            .client
+            .post(&self.config.url)
+            .header("Content-Type", "application/json")
+            .header("X-Outreach-Timestamp", timestamp.to_string())
+            .timeout(DELIVERY_TIMEOUT)
+            .body(body.to_string());
+        if !self.config.secret.is_empty() {
+            request = request.header(
+                "X-Outreach-Signature",
+                format!("sha256={}", sign(&self.config.secret, timestamp, body)),
+            );
+        }
+
+        let response = request.send().await?;
+        if !response.status().is_success() {
+            return Err(HttpStatusError {
+                status: response.status().as_u16(),
+                body: response.text().await.unwrap_or_default(),
+            }
+            .into());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::brain::IntentAnalyzer;
+
+    #[test]
+    fn test_sign() {
+        // echo -n '1700000000.{}' | openssl dgst -sha256 -hmac secret
+        assert_eq!(
+            sign("secret", 1_700_000_000, "{}"),
+            "b8569b78799ff9e3cbff0fc2d63a33a2b57f3282abd07c37ae5e8e7d79a5f163"
+        );
+    }
+
+    #[test]
+    fn test_payload() {
+        let intent = IntentAnalyzer::new().analyze("Sounds good, what are the next steps?");
+        let body = payload(&intent, "Sounds good, what are the next steps?", "sales", Some("Acme"));
+        assert_eq!(body["category"], "BuyingSignal");
+        assert_eq!(body["lead"], "Acme");
+        assert!(body["text"].as_str().unwrap().contains("call with Acme"));
+    }
+}
@@ -5,6 +5,7 @@
 
 use anyhow::{anyhow, Result};
 use parking_lot::Mutex;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::sync::Arc;
 use tokio::sync::mpsc;
 
@@ -14,58 +15,105 @@ pub struct CaptureApp {
     pub name: String,
     pub process_name: String,
     pub icon: &'static str,
+    /// Lowercase fragments that identify the app in process or window names
+    pub keywords: &'static [&'static str],
 }
 
 impl CaptureApp {
     /// Get list of known voice/meeting applications
+    ///
+    /// Ordered by how likely each is to be the call app, most likely first.
     pub fn known_apps() -> Vec<CaptureApp> {
         vec![
             CaptureApp {
                 name: "Zoom".to_string(),
                 process_name: "Zoom.exe".to_string(),
                 icon: "📹",
-            },
-            CaptureApp {
-                name: "Discord".to_string(),
-                process_name: "Discord.exe".to_string(),
-                icon: "🎮",
+                keywords: &["zoom"],
             },
             CaptureApp {
                 name: "Microsoft Teams".to_string(),
                 process_name: "Teams.exe".to_string(),
                 icon: "👥",
+                keywords: &["teams"],
+            },
+            CaptureApp {
+                name: "Discord".to_string(),
+                process_name: "Discord.exe".to_string(),
+                icon: "🎮",
+                keywords: &["discord"],
             },
             CaptureApp {
                 name: "Google Meet (Chrome)".to_string(),
                 process_name: "chrome.exe".to_string(),
                 icon: "🌐",
+                keywords: &["chrome", "google meet"],
             },
             CaptureApp {
                 name: "Google Meet (Edge)".to_string(),
                 process_name: "msedge.exe".to_string(),
                 icon: "🌐",
+                keywords: &["msedge", "microsoft edge"],
             },
             CaptureApp {
                 name: "Slack".to_string(),
                 process_name: "slack.exe".to_string(),
                 icon: "💬",
+                keywords: &["slack"],
             },
             CaptureApp {
                 name: "Skype".to_string(),
                 process_name: "Skype.exe".to_string(),
                 icon: "📞",
+                keywords: &["skype"],
             },
             CaptureApp {
                 name: "WebEx".to_string(),
                 process_name: "webexmta.exe".to_string(),
                 icon: "🎥",
+                keywords: &["webex"],
             },
         ]
     }
+
+    /// Check whether a process or window name refers to this app
+    ///
+    /// Case-insensitive, so "zoom.us", "Zoom.exe" and "Zoom Meeting" all match Zoom.
+    pub fn matches(&self, name: &str) -> bool {
+        let name = name.trim().to_lowercase();
+        name == self.process_name.to_lowercase()
+            || self.keywords.iter().any(|keyword| name.contains(keyword))
+    }
+
+    /// Find the known app a process or window name refers to
+    pub fn resolve(name: &str) -> Option<CaptureApp> {
+        Self::known_apps().into_iter().find(|app| app.matches(name))
+    }
+
+    /// Known app by its display name, e.g. "Zoom"
+    pub fn by_name(name: &str) -> Option<CaptureApp> {
+        Self::known_apps().into_iter().find(|app| app.name == name)
+    }
+}
+
+/// Saved as the app's name; icon and keywords come from `known_apps`
+impl Serialize for CaptureApp {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.name)
+    }
+}
+
+impl<'de> Deserialize<'de> for CaptureApp {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        Self::by_name(&name)
+            .or_else(|| Self::resolve(&name))
+            .ok_or_else(|| serde::de::Error::custom(format!("unknown capture app: {}", name)))
+    }
 }
 
 /// Audio source selection
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum AudioSource {
     /// Capture from system default (all audio)
     SystemDefault,
@@ -89,6 +137,34 @@ impl AudioSource {
             AudioSource::Device(name) => format!("🔊 {}", name),
         }
     }
+
+    /// Most likely source for a call: the top running call app, else system audio
+    pub fn best_guess() -> AudioSource {
+        Self::best_guess_in(&get_available_sources())
+    }
+
+    /// Pick the best source from an already-ranked list
+    pub fn best_guess_in(sources: &[AudioSource]) -> AudioSource {
+        sources
+            .iter()
+            .find(|s| matches!(s, AudioSource::SpecificApp(_)))
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Use a remembered source if it's still available
+    ///
+    /// A remembered app that isn't running (or a device that's gone) falls
+    /// back to system audio; the second value then holds its name so the
+    /// UI can ask the user to pick again.
+    pub fn restore_in(saved: &AudioSource, sources: &[AudioSource]) -> (AudioSource, Option<String>) {
+        match saved {
+            AudioSource::SystemDefault => (AudioSource::SystemDefault, None),
+            _ if sources.contains(saved) => (saved.clone(), None),
+            AudioSource::SpecificApp(app) => (AudioSource::SystemDefault, Some(app.name.clone())),
+            AudioSource::Device(name) => (AudioSource::SystemDefault, Some(name.clone())),
+        }
+    }
 }
 
 /// Detects running applications that can be captured
@@ -99,19 +175,22 @@ pub fn detect_running_apps() -> Vec<CaptureApp> {
     let mut sys = System::new_all();
     sys.refresh_all();
 
-    let known = CaptureApp::known_apps();
-    let mut running = Vec::new();
+    let process_names: Vec<String> = sys
+        .processes()
+        .values()
+        .map(|process| process.name().to_string_lossy().to_string())
+        .collect();
 
-    for process in sys.processes().values() {
-        let proc_name = process.name().to_string_lossy().to_lowercase();
-        for app in &known {
-            if proc_name == app.process_name.to_lowercase() && !running.iter().any(|a: &CaptureApp| a.name == app.name) {
-                running.push(app.clone());
-            }
-        }
-    }
+    running_known_apps(&process_names)
+}
 
-    running
+/// Known apps with a matching process, in `known_apps` priority order
+#[cfg(any(target_os = "windows", test))]
+fn running_known_apps(process_names: &[String]) -> Vec<CaptureApp> {
+    CaptureApp::known_apps()
+        .into_iter()
+        .filter(|app| process_names.iter().any(|name| app.matches(name)))
+        .collect()
 }
 
 #[cfg(not(target_os = "windows"))]
@@ -178,13 +257,15 @@ pub fn list_audio_devices() -> Result<Vec<AudioDevice>> {
 }
 
 /// Get available audio sources (apps + devices)
+///
+/// Running call apps come first so the likeliest source is at the top.
 pub fn get_available_sources() -> Vec<AudioSource> {
-    let mut sources = vec![AudioSource::SystemDefault];
+    let mut sources: Vec<AudioSource> = detect_running_apps()
+        .into_iter()
+        .map(AudioSource::SpecificApp)
+        .collect();
 
-    // Add running apps
-    for app in detect_running_apps() {
-        sources.push(AudioSource::SpecificApp(app));
-    }
+    sources.push(AudioSource::SystemDefault);
 
     // Add devices
     if let Ok(devices) = list_audio_devices() {
@@ -209,6 +290,51 @@ mod tests {
         assert!(apps.iter().any(|a| a.name == "Zoom"));
     }
 
+    #[test]
+    fn test_fuzzy_app_matching() {
+        assert_eq!(CaptureApp::resolve("zoom.us").unwrap().name, "Zoom");
+        assert_eq!(CaptureApp::resolve("Zoom Meeting").unwrap().name, "Zoom");
+        assert_eq!(CaptureApp::resolve("ms-teams.exe").unwrap().name, "Microsoft Teams");
+        assert!(CaptureApp::resolve("notepad.exe").is_none());
+    }
+
+    #[test]
+    fn test_running_apps_ranked_and_best_guess() {
+        let processes = vec![
+            "slack.exe".to_string(),
+            "explorer.exe".to_string(),
+            "Zoom Meeting".to_string(),
+            "zoom.us".to_string(),
+        ];
+        let running = running_known_apps(&processes);
+        let names: Vec<&str> = running.iter().map(|a| a.name.as_str()).collect();
+        assert_eq!(names, vec!["Zoom", "Slack"]);
+
+        let mut sources: Vec<AudioSource> = running.into_iter().map(AudioSource::SpecificApp).collect();
+        sources.push(AudioSource::SystemDefault);
+        match AudioSource::best_guess_in(&sources) {
+            AudioSource::SpecificApp(app) => assert_eq!(app.name, "Zoom"),
+            other => panic!("expected Zoom, got {:?}", other),
+        }
+
+        assert_eq!(AudioSource::best_guess_in(&[AudioSource::SystemDefault]), AudioSource::SystemDefault);
+    }
+
+    #[test]
+    fn test_source_round_trip_and_restore() {
+        let zoom = AudioSource::SpecificApp(CaptureApp::by_name("Zoom").unwrap());
+        let json = serde_json::to_string(&zoom).unwrap();
+        assert_eq!(json, r#"{"SpecificApp":"Zoom"}"#);
+        assert_eq!(serde_json::from_str::<AudioSource>(&json).unwrap(), zoom);
+
+        let running = vec![zoom.clone(), AudioSource::SystemDefault];
+        assert_eq!(AudioSource::restore_in(&zoom, &running), (zoom.clone(), None));
+        assert_eq!(
+            AudioSource::restore_in(&zoom, &[AudioSource::SystemDefault]),
+            (AudioSource::SystemDefault, Some("Zoom".to_string()))
+        );
+    }
+
     #[test]
     fn test_list_devices() {
         let devices = list_audio_devices();
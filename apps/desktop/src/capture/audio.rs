@@ -3,31 +3,127 @@
 //! Captures audio from the system output (loopback) to transcribe what others are saying.
 //! On Windows, uses WASAPI loopback mode.
 //! On macOS, requires a virtual audio device like BlackHole.
+//! Devices are captured in their native format, then mixed down to mono
+//! and resampled to the rate STT expects.
 
-use anyhow::{anyhow, Result};
+use anyhow::Result;
 use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
-use cpal::{Device, Host, SampleFormat, SampleRate, Stream, StreamConfig};
+use cpal::{BackendSpecificError, BuildStreamError, DefaultStreamConfigError, Device, PlayStreamError, Stream, StreamConfig};
 use parking_lot::Mutex;
+use rubato::{FftFixedIn, Resampler};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 use tokio::sync::mpsc;
 
+pub use crate::error::CaptureError;
+
 /// Audio capture configuration
 #[derive(Debug, Clone)]
 pub struct AudioConfig {
-    /// Sample rate for capture (16000 Hz is optimal for STT)
+    /// Rate delivered to STT, whatever the device runs at (16000 Hz is optimal)
     pub sample_rate: u32,
-    /// Number of channels (1 = mono, 2 = stereo)
-    pub channels: u16,
     /// Buffer size in samples
     pub buffer_size: usize,
+    /// RMS energy above which a frame counts as speech
+    pub vad_threshold: f32,
+    /// How long to keep streaming after speech stops (ms)
+    pub vad_silence_ms: u32,
 }
 
 impl Default for AudioConfig {
     fn default() -> Self {
         Self {
             sample_rate: 16000,
-            channels: 1,
             buffer_size: 1024,
+            vad_threshold: 0.01,
+            vad_silence_ms: 800,
+        }
+    }
+}
+
+/// Meter floor; anything quieter reads as silence (dBFS)
+pub const SILENCE_DB: f32 = -60.0;
+
+/// Sample magnitude treated as clipped
+pub const CLIP_THRESHOLD: f32 = 0.99;
+
+/// Clipping stays flagged this long after the last clipped sample
+const CLIP_HOLD: Duration = Duration::from_secs(1);
+
+/// RMS is averaged over about this much audio (seconds)
+const LEVEL_WINDOW_SECS: f32 = 0.3;
+
+/// How loud the captured audio is
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AudioLevel {
+    /// Rolling RMS level in dBFS, `SILENCE_DB` to 0
+    pub level_db: f32,
+    /// Recent peak sample magnitude (0.0 to 1.0)
+    pub peak: f32,
+    /// Samples hit full scale recently; the source is too loud
+    pub is_clipping: bool,
+}
+
+impl Default for AudioLevel {
+    fn default() -> Self {
+        Self {
+            level_db: SILENCE_DB,
+            peak: 0.0,
+            is_clipping: false,
+        }
+    }
+}
+
+impl AudioLevel {
+    /// How full a meter should be (0.0 to 1.0)
+    pub fn fraction(&self) -> f32 {
+        ((self.level_db - SILENCE_DB) / -SILENCE_DB).clamp(0.0, 1.0)
+    }
+}
+
+/// Rolling RMS and peak over recent audio
+#[derive(Debug, Clone)]
+pub struct LevelMeter {
+    sample_rate: u32,
+    mean_square: f32,
+    peak: f32,
+    last_clip: Option<Instant>,
+}
+
+impl LevelMeter {
+    pub fn new(sample_rate: u32) -> Self {
+        Self {
+            sample_rate: sample_rate.max(1),
+            mean_square: 0.0,
+            peak: 0.0,
+            last_clip: None,
+        }
+    }
+
+    /// Add a chunk of mono audio and return the updated level
+    pub fn process(&mut self, samples: &[f32]) -> AudioLevel {
+        if !samples.is_empty() {
+            // Weight each chunk by how much of the window it covers, so the
+            // meter moves at the same speed whatever the callback size
+            let weight = (samples.len() as f32 / (self.sample_rate as f32 * LEVEL_WINDOW_SECS)).min(1.0);
+            let mean_square = samples.iter().map(|s| s * s).sum::<f32>() / samples.len() as f32;
+            self.mean_square += (mean_square - self.mean_square) * weight;
+
+            let peak = samples.iter().fold(0.0f32, |max, s| max.max(s.abs()));
+            self.peak = peak.max(self.peak * (1.0 - weight));
+            if peak >= CLIP_THRESHOLD {
+                self.last_clip = Some(Instant::now());
+            }
+        }
+        self.level()
+    }
+
+    pub fn level(&self) -> AudioLevel {
+        let rms = self.mean_square.sqrt();
+        AudioLevel {
+            level_db: if rms > 0.0 { (20.0 * rms.log10()).max(SILENCE_DB) } else { SILENCE_DB },
+            peak: self.peak,
+            is_clipping: self.last_clip.is_some_and(|at| at.elapsed() < CLIP_HOLD),
         }
     }
 }
@@ -38,7 +134,8 @@ pub enum AudioCaptureState {
     #[default]
     Stopped,
     Starting,
-    Running,
+    /// Capturing, with the latest input level
+    Running(AudioLevel),
     Error(String),
 }
 
@@ -46,8 +143,10 @@ pub enum AudioCaptureState {
 pub struct AudioCapture {
     config: AudioConfig,
     state: Arc<Mutex<AudioCaptureState>>,
-    stream: Option<Stream>,
-    audio_tx: Option<mpsc::Sender<Vec<f32>>>,
+    /// Keeps the capture thread (and its stream) alive until dropped
+    stop_tx: Option<std::sync::mpsc::Sender<()>>,
+    /// Record the default microphone instead of system audio
+    microphone: bool,
 }
 
 impl AudioCapture {
@@ -56,16 +155,38 @@ impl AudioCapture {
         Self {
             config,
             state: Arc::new(Mutex::new(AudioCaptureState::Stopped)),
-            stream: None,
-            audio_tx: None,
+            stop_tx: None,
+            microphone: false,
         }
     }
 
+    /// Capture from the default microphone rather than system audio
+    pub fn microphone(config: AudioConfig) -> Self {
+        let mut capture = Self::new(config);
+        capture.microphone = true;
+        capture
+    }
+
     /// Get the current capture state
     pub fn state(&self) -> AudioCaptureState {
         self.state.lock().clone()
     }
 
+    /// Shared state the capture callback keeps up to date
+    ///
+    /// Lets another task follow the level without holding the capture.
+    pub fn state_handle(&self) -> Arc<Mutex<AudioCaptureState>> {
+        self.state.clone()
+    }
+
+    /// Current input level, while capturing
+    pub fn level(&self) -> Option<AudioLevel> {
+        match &*self.state.lock() {
+            AudioCaptureState::Running(level) => Some(*level),
+            _ => None,
+        }
+    }
+
     /// List available audio devices
     pub fn list_devices() -> Result<Vec<String>> {
         let host = cpal::default_host();
@@ -94,125 +215,282 @@ impl AudioCapture {
 
     /// Get the default loopback device for capturing system audio
     #[cfg(target_os = "windows")]
-    pub fn get_loopback_device() -> Result<Device> {
+    pub fn get_loopback_device() -> Result<Device, CaptureError> {
         let host = cpal::default_host();
 
         // On Windows, we need to use WASAPI loopback
         // The default output device can be opened in loopback mode
         host.default_output_device()
-            .ok_or_else(|| anyhow!("No default output device found"))
+            .ok_or_else(|| CaptureError::NoDevice("No default output device found".to_string()))
     }
 
     #[cfg(not(target_os = "windows"))]
-    pub fn get_loopback_device() -> Result<Device> {
+    pub fn get_loopback_device() -> Result<Device, CaptureError> {
         let host = cpal::default_host();
 
         // On macOS/Linux, loopback requires virtual audio device
         // User must configure BlackHole or similar
         host.default_input_device()
-            .ok_or_else(|| anyhow!("No default input device found. On macOS, install BlackHole for system audio capture."))
+            .ok_or_else(|| CaptureError::NoDevice("No default input device found".to_string()))
     }
 
     /// Start capturing audio
     ///
     /// Returns a channel receiver that will receive audio chunks
-    pub fn start(&mut self) -> Result<mpsc::Receiver<Vec<f32>>> {
+    pub fn start(&mut self) -> Result<mpsc::Receiver<Vec<f32>>, CaptureError> {
         *self.state.lock() = AudioCaptureState::Starting;
 
-        let device = Self::get_loopback_device()?;
-        tracing::info!("Using audio device: {:?}", device.name());
-
-        // Get supported config
-        let supported_config = device.default_output_config()?;
-        tracing::info!("Default config: {:?}", supported_config);
-
-        // Create stream config targeting 16kHz mono
-        let stream_config = StreamConfig {
-            channels: self.config.channels,
-            sample_rate: SampleRate(self.config.sample_rate),
-            buffer_size: cpal::BufferSize::Default,
+        let result = self.open_stream();
+        *self.state.lock() = match &result {
+            Ok(_) => AudioCaptureState::Running(AudioLevel::default()),
+            Err(e) => AudioCaptureState::Error(e.message()),
         };
+        result
+    }
 
-        // Channel for sending audio data
-        let (tx, rx) = mpsc::channel::<Vec<f32>>(100);
-        self.audio_tx = Some(tx.clone());
-
+    /// Run the stream on its own thread, holding only a way to stop it
+    fn open_stream(&mut self) -> Result<mpsc::Receiver<Vec<f32>>, CaptureError> {
+        let (ready_tx, ready_rx) = std::sync::mpsc::channel();
+        let (stop_tx, stop_rx) = std::sync::mpsc::channel::<()>();
+        let microphone = self.microphone;
+        let sample_rate = self.config.sample_rate;
         let state = self.state.clone();
-        let error_state = self.state.clone();
-
-        // Build the input stream
-        // Note: For true WASAPI loopback on Windows, we'd need to use the windows crate directly
-        // cpal's loopback support varies by platform
-        let stream = device.build_input_stream(
-            &stream_config,
-            move |data: &[f32], _: &cpal::InputCallbackInfo| {
-                // Send audio chunk to processing pipeline
-                let chunk: Vec<f32> = data.to_vec();
-                if tx.blocking_send(chunk).is_err() {
-                    tracing::warn!("Audio channel closed");
+
+        std::thread::Builder::new()
+            .name("audio-capture".to_string())
+            .spawn(move || match build_stream(microphone, sample_rate, &state) {
+                Ok((stream, rx)) => {
+                    let _ = ready_tx.send(Ok(rx));
+                    // Capture runs until the sender is dropped
+                    let _ = stop_rx.recv();
+                    drop(stream);
+                }
+                Err(e) => {
+                    let _ = ready_tx.send(Err(e));
                 }
-            },
-            move |err| {
-                tracing::error!("Audio stream error: {}", err);
-                *error_state.lock() = AudioCaptureState::Error(err.to_string());
-            },
-            None,
-        )?;
-
-        stream.play()?;
-        self.stream = Some(stream);
-        *state.lock() = AudioCaptureState::Running;
-
-        tracing::info!("Audio capture started");
+            })
+            .map_err(|e| CaptureError::Other(e.to_string()))?;
+
+        let rx = ready_rx
+            .recv()
+            .map_err(|_| CaptureError::Other("Audio capture thread exited".to_string()))??;
+        self.stop_tx = Some(stop_tx);
         Ok(rx)
     }
 
     /// Stop capturing audio
     pub fn stop(&mut self) {
-        if let Some(stream) = self.stream.take() {
-            drop(stream);
-        }
-        self.audio_tx = None;
+        // Ends the capture thread, which drops the stream
+        self.stop_tx = None;
         *self.state.lock() = AudioCaptureState::Stopped;
         tracing::info!("Audio capture stopped");
     }
 }
 
+/// Open and start the capture stream on the calling thread
+///
+/// cpal streams aren't `Send`, so this runs on the capture's own thread
+/// and the stream never leaves it.
+fn build_stream(
+    microphone: bool,
+    sample_rate: u32,
+    state: &Arc<Mutex<AudioCaptureState>>,
+) -> Result<(Stream, mpsc::Receiver<Vec<f32>>), CaptureError> {
+    let device = if microphone {
+        cpal::default_host()
+            .default_input_device()
+            .ok_or_else(|| CaptureError::NoDevice("No default input device found".to_string()))?
+    } else {
+        AudioCapture::get_loopback_device()?
+    };
+    tracing::info!("Using audio device: {:?}", device.name());
+
+    // Capture at the device's own format; asking for 16kHz mono
+    // directly is refused or garbled by most loopback devices
+    #[cfg(target_os = "windows")]
+    let supported_config = if microphone {
+        device.default_input_config()?
+    } else {
+        device.default_output_config()?
+    };
+    #[cfg(not(target_os = "windows"))]
+    let supported_config = device.default_input_config()?;
+    tracing::info!("Default config: {:?}", supported_config);
+
+    let device_channels = supported_config.channels();
+    let mut meter = LevelMeter::new(supported_config.sample_rate().0);
+    let mut resampler = StreamResampler::new(supported_config.sample_rate().0, sample_rate)?;
+    let stream_config: StreamConfig = supported_config.into();
+
+    // Channel for sending audio data
+    let (tx, rx) = mpsc::channel::<Vec<f32>>(100);
+
+    let level_state = state.clone();
+    let error_state = state.clone();
+
+    // Build the input stream
+    // Note: For true WASAPI loopback on Windows, we'd need to use the windows crate directly
+    // cpal's loopback support varies by platform
+    let stream = device.build_input_stream(
+        &stream_config,
+        move |data: &[f32], _: &cpal::InputCallbackInfo| {
+            let mono = downmix(data, device_channels);
+            let level = meter.process(&mono);
+            {
+                let mut state = level_state.lock();
+                if matches!(*state, AudioCaptureState::Running(_)) {
+                    *state = AudioCaptureState::Running(level);
+                }
+            }
+
+            // Send mono audio at the target rate to the processing pipeline
+            let chunk = resampler.process(&mono);
+            if chunk.is_empty() {
+                return;
+            }
+            if tx.blocking_send(chunk).is_err() {
+                tracing::warn!("Audio channel closed");
+            }
+        },
+        move |err| {
+            tracing::error!("Audio stream error: {}", err);
+            *error_state.lock() = AudioCaptureState::Error(err.to_string());
+        },
+        None,
+    )?;
+
+    stream.play()?;
+    tracing::info!("Audio capture started");
+    Ok((stream, rx))
+}
+
+/// Most backends report a refused device only in their own message
+fn is_permission_error(err: &BackendSpecificError) -> bool {
+    let description = err.description.to_lowercase();
+    ["denied", "permission", "not permitted", "0x80070005"]
+        .iter()
+        .any(|needle| description.contains(needle))
+}
+
+impl From<BackendSpecificError> for CaptureError {
+    fn from(err: BackendSpecificError) -> Self {
+        if is_permission_error(&err) {
+            Self::PermissionDenied(err.description)
+        } else {
+            Self::Other(err.description)
+        }
+    }
+}
+
+impl From<DefaultStreamConfigError> for CaptureError {
+    fn from(err: DefaultStreamConfigError) -> Self {
+        match err {
+            DefaultStreamConfigError::DeviceNotAvailable => Self::NoDevice(err.to_string()),
+            DefaultStreamConfigError::StreamTypeNotSupported => Self::FormatUnsupported(err.to_string()),
+            DefaultStreamConfigError::BackendSpecific { err } => err.into(),
+        }
+    }
+}
+
+impl From<BuildStreamError> for CaptureError {
+    fn from(err: BuildStreamError) -> Self {
+        match err {
+            BuildStreamError::DeviceNotAvailable => Self::NoDevice(err.to_string()),
+            BuildStreamError::StreamConfigNotSupported | BuildStreamError::InvalidArgument => {
+                Self::FormatUnsupported(err.to_string())
+            }
+            BuildStreamError::BackendSpecific { err } => err.into(),
+            err => Self::Other(err.to_string()),
+        }
+    }
+}
+
+impl From<PlayStreamError> for CaptureError {
+    fn from(err: PlayStreamError) -> Self {
+        match err {
+            PlayStreamError::DeviceNotAvailable => Self::NoDevice(err.to_string()),
+            PlayStreamError::BackendSpecific { err } => err.into(),
+        }
+    }
+}
+
 impl Drop for AudioCapture {
     fn drop(&mut self) {
         self.stop();
     }
 }
 
-/// Resample audio from one sample rate to another
-pub fn resample(input: &[f32], from_rate: u32, to_rate: u32) -> Vec<f32> {
-    if from_rate == to_rate {
-        return input.to_vec();
+/// Average interleaved channels into mono
+pub fn downmix(interleaved: &[f32], channels: u16) -> Vec<f32> {
+    if channels <= 1 {
+        return interleaved.to_vec();
     }
 
-    use rubato::{FftFixedIn, Resampler};
+    interleaved
+        .chunks(channels as usize)
+        .map(|frame| frame.iter().sum::<f32>() / frame.len() as f32)
+        .collect()
+}
 
-    let ratio = to_rate as f64 / from_rate as f64;
-    let chunk_size = 1024;
+/// Input frames per resampler pass (about 21ms at 48kHz)
+const RESAMPLE_CHUNK: usize = 1024;
+
+/// Band-limited resampler fed in callback-sized chunks
+///
+/// rubato filters out everything above the new Nyquist frequency before
+/// decimating, so high frequencies don't fold back into the speech band.
+/// It wants fixed-size input, so samples are buffered until a full chunk
+/// is in, and the filter's delay is dropped from the start of the output
+/// to keep it aligned with the capture.
+pub struct StreamResampler {
+    /// None when the device already runs at the target rate
+    resampler: Option<FftFixedIn<f32>>,
+    pending: Vec<f32>,
+    /// Output samples still to drop for the filter delay
+    delay: usize,
+}
 
-    let mut resampler = FftFixedIn::<f32>::new(
-        from_rate as usize,
-        to_rate as usize,
-        chunk_size,
-        2,
-        1,
-    ).expect("Failed to create resampler");
+impl StreamResampler {
+    pub fn new(from_rate: u32, to_rate: u32) -> Result<Self, CaptureError> {
+        let resampler = if from_rate == to_rate {
+            None
+        } else {
+            let resampler = FftFixedIn::<f32>::new(from_rate as usize, to_rate as usize, RESAMPLE_CHUNK, 2, 1)
+                .map_err(|e| CaptureError::FormatUnsupported(e.to_string()))?;
+            Some(resampler)
+        };
+        Ok(Self {
+            delay: resampler.as_ref().map_or(0, |r| r.output_delay()),
+            resampler,
+            pending: Vec::with_capacity(RESAMPLE_CHUNK * 2),
+        })
+    }
 
-    let mut output = Vec::new();
-    let input_vec = vec![input.to_vec()];
+    /// Resample one chunk of mono audio
+    ///
+    /// Returns whatever full passes it completes; the rest waits for the
+    /// next chunk.
+    pub fn process(&mut self, input: &[f32]) -> Vec<f32> {
+        let Some(resampler) = &mut self.resampler else {
+            return input.to_vec();
+        };
 
-    if let Ok(resampled) = resampler.process(&input_vec, None) {
-        if !resampled.is_empty() {
-            output.extend_from_slice(&resampled[0]);
+        self.pending.extend_from_slice(input);
+        let mut output = Vec::new();
+        while self.pending.len() >= resampler.input_frames_next() {
+            let frames = resampler.input_frames_next();
+            match resampler.process(&[&self.pending[..frames]], None) {
+                Ok(mut resampled) => output.append(&mut resampled[0]),
+                Err(e) => tracing::warn!("Resampling failed, dropping {} samples: {}", frames, e),
+            }
+            self.pending.drain(..frames);
         }
-    }
 
-    output
+        let skip = self.delay.min(output.len());
+        self.delay -= skip;
+        output.drain(..skip);
+        output
+    }
 }
 
 /// Convert f32 samples to i16 for PCM encoding
@@ -238,6 +516,88 @@ pub fn f32_to_pcm_bytes(samples: &[f32]) -> Vec<u8> {
     bytes
 }
 
+/// Energy-based voice activity detection
+///
+/// Returns true when the RMS level of the frame exceeds `threshold`.
+pub fn is_speech(samples: &[f32], threshold: f32) -> bool {
+    if samples.is_empty() {
+        return false;
+    }
+
+    let energy: f32 = samples.iter().map(|s| s * s).sum::<f32>() / samples.len() as f32;
+    energy.sqrt() > threshold
+}
+
+/// Gates audio so only speech (plus a short silence tail) reaches STT
+///
+/// Frames are forwarded for as long as speech keeps arriving, so a long
+/// utterance is never cut off. A little pre-roll is kept while idle so the
+/// first syllable isn't clipped when speech starts.
+pub struct SpeechGate {
+    threshold: f32,
+    silence_tail_samples: usize,
+    pre_roll_samples: usize,
+    pre_roll: std::collections::VecDeque<Vec<f32>>,
+    silent_samples: usize,
+    active: bool,
+}
+
+impl SpeechGate {
+    /// Create a gate from the capture config
+    pub fn new(config: &AudioConfig) -> Self {
+        let samples_per_ms = config.sample_rate as usize / 1000;
+        Self {
+            threshold: config.vad_threshold,
+            silence_tail_samples: config.vad_silence_ms as usize * samples_per_ms,
+            pre_roll_samples: 300 * samples_per_ms,
+            pre_roll: std::collections::VecDeque::new(),
+            silent_samples: 0,
+            active: false,
+        }
+    }
+
+    /// Whether speech is currently being forwarded
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    /// Feed a frame in, get back the frames that should be sent
+    pub fn process(&mut self, samples: Vec<f32>) -> Vec<Vec<f32>> {
+        if is_speech(&samples, self.threshold) {
+            self.silent_samples = 0;
+
+            if self.active {
+                return vec![samples];
+            }
+
+            self.active = true;
+            let mut out: Vec<Vec<f32>> = self.pre_roll.drain(..).collect();
+            out.push(samples);
+            return out;
+        }
+
+        if self.active {
+            self.silent_samples += samples.len();
+            if self.silent_samples >= self.silence_tail_samples {
+                self.active = false;
+                self.silent_samples = 0;
+            }
+            return vec![samples];
+        }
+
+        // Idle: hold on to recent audio as pre-roll
+        self.pre_roll.push_back(samples);
+        let mut buffered: usize = self.pre_roll.iter().map(|f| f.len()).sum();
+        while buffered > self.pre_roll_samples {
+            if let Some(frame) = self.pre_roll.pop_front() {
+                buffered -= frame.len();
+            }
+        }
+
+        Vec::new()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -249,6 +609,80 @@ mod tests {
         println!("Available devices: {:?}", devices.unwrap());
     }
 
+    #[test]
+    fn test_capture_error_classification() {
+        let denied = BackendSpecificError {
+            description: "Access is denied. (0x80070005)".to_string(),
+        };
+        assert!(matches!(
+            CaptureError::from(BuildStreamError::BackendSpecific { err: denied }),
+            CaptureError::PermissionDenied(_)
+        ));
+        assert!(matches!(
+            CaptureError::from(BuildStreamError::StreamConfigNotSupported),
+            CaptureError::FormatUnsupported(_)
+        ));
+        assert!(matches!(
+            CaptureError::from(DefaultStreamConfigError::DeviceNotAvailable),
+            CaptureError::NoDevice(_)
+        ));
+    }
+
+    #[test]
+    fn test_level_meter() {
+        let mut meter = LevelMeter::new(16_000);
+        assert_eq!(meter.process(&[0.0; 1600]), AudioLevel::default());
+
+        // A steady half-scale square wave settles at about -6 dBFS
+        let square: Vec<f32> = (0..1600).map(|i| if i % 2 == 0 { 0.5 } else { -0.5 }).collect();
+        let mut level = AudioLevel::default();
+        for _ in 0..20 {
+            level = meter.process(&square);
+        }
+        assert!((level.level_db + 6.0).abs() < 0.5, "{}", level.level_db);
+        assert!(!level.is_clipping);
+
+        assert!(meter.process(&[1.0, -1.0]).is_clipping);
+    }
+
+    #[test]
+    fn test_resample_48k_stereo() {
+        // One second of a 440 Hz tone, identical on both channels
+        let stereo: Vec<f32> = (0..48_000)
+            .map(|i| 0.5 * (2.0 * std::f32::consts::PI * 440.0 * i as f32 / 48_000.0).sin())
+            .flat_map(|s| [s, s])
+            .collect();
+        let mono = downmix(&stereo, 2);
+        assert_eq!(mono.len(), 48_000);
+
+        // Fed in callback-sized chunks, as the capture stream does; the
+        // last partial pass is still buffered
+        let mut resampler = StreamResampler::new(48_000, 16_000).unwrap();
+        let output: Vec<f32> = mono.chunks(480).flat_map(|chunk| resampler.process(chunk)).collect();
+        assert!(output.len() > 15_000 && output.len() <= 16_000, "{}", output.len());
+
+        // Still the same tone, in step with the input
+        for (i, sample) in output.iter().enumerate().skip(200).step_by(97) {
+            let expected = 0.5 * (2.0 * std::f32::consts::PI * 440.0 * i as f32 / 16_000.0).sin();
+            assert!((sample - expected).abs() < 0.02, "sample {}: {} vs {}", i, sample, expected);
+        }
+    }
+
+    #[test]
+    fn test_resample_filters_aliasing() {
+        // 12 kHz is above the 8 kHz a 16 kHz stream can hold; unfiltered
+        // it would fold back to 4 kHz, right in the speech band
+        let tone: Vec<f32> = (0..48_000)
+            .map(|i| 0.5 * (2.0 * std::f32::consts::PI * 12_000.0 * i as f32 / 48_000.0).sin())
+            .collect();
+        let mut resampler = StreamResampler::new(48_000, 16_000).unwrap();
+        let output: Vec<f32> = tone.chunks(480).flat_map(|chunk| resampler.process(chunk)).collect();
+
+        let steady = &output[200..output.len() - 200];
+        let rms = (steady.iter().map(|s| s * s).sum::<f32>() / steady.len() as f32).sqrt();
+        assert!(rms < 0.01, "{}", rms);
+    }
+
     #[test]
     fn test_f32_to_i16() {
         let samples = vec![0.0, 0.5, -0.5, 1.0, -1.0];
@@ -259,4 +693,42 @@ mod tests {
         assert_eq!(converted[3], 32767);
         assert_eq!(converted[4], -32767);
     }
+
+    #[test]
+    fn test_is_speech() {
+        assert!(!is_speech(&[], 0.01));
+        assert!(!is_speech(&vec![0.001; 1600], 0.01));
+        assert!(is_speech(&vec![0.2; 1600], 0.01));
+    }
+
+    #[test]
+    fn test_speech_gate_keeps_long_utterances() {
+        let config = AudioConfig {
+            vad_silence_ms: 200,
+            ..AudioConfig::default()
+        };
+        let mut gate = SpeechGate::new(&config);
+        let silence = vec![0.0; 1600]; // 100ms
+        let speech = vec![0.2; 1600];
+
+        // Idle silence is held back
+        assert!(gate.process(silence.clone()).is_empty());
+
+        // Speech flushes the pre-roll along with the frame
+        assert_eq!(gate.process(speech.clone()).len(), 2);
+
+        // Ten seconds of speech all get through
+        for _ in 0..100 {
+            assert_eq!(gate.process(speech.clone()).len(), 1);
+        }
+
+        // A short pause inside the utterance is forwarded
+        assert_eq!(gate.process(silence.clone()).len(), 1);
+        assert!(gate.is_active());
+
+        // Once the silence tail elapses the gate closes
+        assert_eq!(gate.process(silence.clone()).len(), 1);
+        assert!(!gate.is_active());
+        assert!(gate.process(silence).is_empty());
+    }
 }
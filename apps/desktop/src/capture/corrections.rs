@@ -0,0 +1,186 @@
+//! Transcript Corrections
+//!
+//! A small user dictionary of words the STT keeps mishearing, learned from
+//! manual transcript edits and applied to every new segment
+//! (e.g. "coober netties" becomes "Kubernetes").
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// Longest phrase (in words) learned from a single edit
+const MAX_PHRASE_WORDS: usize = 4;
+
+/// One learned correction
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Correction {
+    /// What the STT produced
+    pub heard: String,
+    /// What was actually said
+    pub correct: String,
+}
+
+/// User dictionary of corrections
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CorrectionDictionary {
+    pub entries: Vec<Correction>,
+}
+
+impl CorrectionDictionary {
+    /// Get the dictionary file path
+    pub fn path() -> PathBuf {
+        dirs::config_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join("voice-copilot")
+            .join("corrections.json")
+    }
+
+    /// Load the dictionary from disk
+    pub fn load() -> Result<Self> {
+        let path = Self::path();
+        if path.exists() {
+            let content = std::fs::read_to_string(&path)?;
+            Ok(serde_json::from_str(&content)?)
+        } else {
+            Ok(Self::default())
+        }
+    }
+
+    /// Save the dictionary to disk
+    pub fn save(&self) -> Result<()> {
+        let path = Self::path();
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let content = serde_json::to_string_pretty(self)?;
+        std::fs::write(&path, content)?;
+        Ok(())
+    }
+
+    /// Add a correction, replacing any earlier one for the same phrase
+    pub fn add(&mut self, heard: &str, correct: &str) {
+        let key = normalize(heard);
+        self.entries.retain(|c| normalize(&c.heard) != key);
+        self.entries.push(Correction {
+            heard: heard.to_string(),
+            correct: correct.to_string(),
+        });
+    }
+
+    /// Learn the changed phrase from an edit, if it's short enough to reuse
+    pub fn learn(&mut self, original: &str, corrected: &str) -> Option<Correction> {
+        let (heard, correct) = changed_phrase(original, corrected)?;
+        self.add(&heard, &correct);
+        self.entries.last().cloned()
+    }
+
+    /// Apply every correction to a segment of text
+    pub fn apply(&self, text: &str) -> String {
+        let mut words: Vec<String> = text.split_whitespace().map(str::to_string).collect();
+
+        for correction in &self.entries {
+            let heard: Vec<String> = correction.heard.split_whitespace().map(normalize).collect();
+            if heard.is_empty() {
+                continue;
+            }
+
+            let mut i = 0;
+            while i + heard.len() <= words.len() {
+                let window = &words[i..i + heard.len()];
+                if window.iter().map(|w| normalize(w)).eq(heard.iter().cloned()) {
+                    // Keep punctuation that followed the misheard phrase
+                    let last = &window[window.len() - 1];
+                    let trailing = &last[last.trim_end_matches(|c: char| !c.is_alphanumeric()).len()..];
+                    let mut replacement: Vec<String> =
+                        correction.correct.split_whitespace().map(str::to_string).collect();
+                    if let Some(last) = replacement.last_mut() {
+                        last.push_str(trailing);
+                    }
+                    let len = replacement.len();
+                    words.splice(i..i + heard.len(), replacement);
+                    i += len.max(1);
+                } else {
+                    i += 1;
+                }
+            }
+        }
+
+        words.join(" ")
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// Lowercase a word and strip surrounding punctuation
+fn normalize(word: &str) -> String {
+    word.trim_matches(|c: char| !c.is_alphanumeric() && !c.is_whitespace())
+        .to_lowercase()
+}
+
+/// The differing run of words between two versions of a line
+fn changed_phrase(original: &str, corrected: &str) -> Option<(String, String)> {
+    let before: Vec<&str> = original.split_whitespace().collect();
+    let after: Vec<&str> = corrected.split_whitespace().collect();
+
+    let prefix = before
+        .iter()
+        .zip(&after)
+        .take_while(|(a, b)| normalize(a) == normalize(b))
+        .count();
+    let suffix = before[prefix..]
+        .iter()
+        .rev()
+        .zip(after[prefix..].iter().rev())
+        .take_while(|(a, b)| normalize(a) == normalize(b))
+        .count();
+
+    let heard = &before[prefix..before.len() - suffix];
+    let correct = &after[prefix..after.len() - suffix];
+    if heard.is_empty() || correct.is_empty() || heard.len() > MAX_PHRASE_WORDS || correct.len() > MAX_PHRASE_WORDS {
+        return None;
+    }
+
+    let trim = |words: &[&str]| words.join(" ").trim_matches(|c: char| !c.is_alphanumeric()).to_string();
+    let (heard, correct) = (trim(heard), trim(correct));
+    if heard.is_empty() || correct.is_empty() || normalize(&heard) == normalize(&correct) {
+        return None;
+    }
+    Some((heard, correct))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_learn_from_edit() {
+        let mut dictionary = CorrectionDictionary::default();
+        let learned = dictionary.learn(
+            "Do you run on coober netties or bare metal?",
+            "Do you run on Kubernetes or bare metal?",
+        );
+        assert_eq!(
+            learned,
+            Some(Correction {
+                heard: "coober netties".to_string(),
+                correct: "Kubernetes".to_string(),
+            })
+        );
+
+        // Rewriting the whole line isn't a reusable correction
+        assert_eq!(dictionary.learn("what about price", "I asked something else entirely here"), None);
+    }
+
+    #[test]
+    fn test_apply_corrections() {
+        let mut dictionary = CorrectionDictionary::default();
+        dictionary.add("coober netties", "Kubernetes");
+        assert_eq!(
+            dictionary.apply("We moved to Coober Netties, last year"),
+            "We moved to Kubernetes, last year"
+        );
+        assert_eq!(dictionary.apply("No match here"), "No match here");
+    }
+}
@@ -6,17 +6,42 @@
 use anyhow::{anyhow, Result};
 use futures::{SinkExt, StreamExt};
 use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use tokio::net::TcpStream;
 use tokio::sync::mpsc;
-use tokio_tungstenite::{connect_async, tungstenite::Message};
+use tokio_tungstenite::{connect_async, tungstenite::Message, MaybeTlsStream, WebSocketStream};
 use url::Url;
 
 use super::transcript::TranscriptSegment;
 
+type WsStream = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+/// Reconnect attempts before giving up on a dropped connection
+const MAX_RECONNECT_ATTEMPTS: u32 = 5;
+
+/// Delay before the first reconnect attempt
+const RECONNECT_BASE_DELAY_MS: u64 = 500;
+
+/// Audio buffered while reconnecting (30s of 16kHz mono PCM)
+const MAX_PENDING_AUDIO_BYTES: usize = 16_000 * 2 * 30;
+
 /// Deepgram client for streaming STT
 pub struct DeepgramClient {
     api_key: String,
     model: String,
     language: String,
+    status_tx: Option<mpsc::Sender<DeepgramStatus>>,
+}
+
+/// Connection changes reported while streaming
+#[derive(Debug, Clone, PartialEq)]
+pub enum DeepgramStatus {
+    /// Connection dropped; reconnect attempt `attempt` is starting
+    Reconnecting { attempt: u32 },
+    /// Connection restored after a drop
+    Reconnected,
+    /// Every reconnect attempt failed; transcription has stopped
+    Disconnected,
 }
 
 /// Deepgram streaming configuration
@@ -28,6 +53,8 @@ pub struct DeepgramConfig {
     pub interim_results: bool,
     pub smart_format: bool,
     pub diarize: bool,
+    /// Domain terms to boost (product names, acronyms)
+    pub keywords: Vec<String>,
 }
 
 impl Default for DeepgramConfig {
@@ -39,6 +66,7 @@ impl Default for DeepgramConfig {
             interim_results: true,
             smart_format: true,
             diarize: false, // Speaker diarization (adds latency)
+            keywords: Vec::new(),
         }
     }
 }
@@ -71,6 +99,8 @@ pub struct Word {
     pub start: f32,
     pub end: f32,
     pub confidence: f32,
+    /// Speaker index (only present when diarization is enabled)
+    pub speaker: Option<u32>,
 }
 
 impl DeepgramClient {
@@ -80,6 +110,7 @@ impl DeepgramClient {
             api_key: api_key.into(),
             model: "nova-2".to_string(),
             language: "en".to_string(),
+            status_tx: None,
         }
     }
 
@@ -95,11 +126,20 @@ impl DeepgramClient {
         self
     }
 
+    /// Report connection drops and reconnects on this channel
+    pub fn with_status_channel(mut self, status_tx: mpsc::Sender<DeepgramStatus>) -> Self {
+        self.status_tx = Some(status_tx);
+        self
+    }
+
     /// Start a streaming transcription session
     ///
     /// Returns:
     /// - A sender to push audio data
     /// - A receiver to get transcript segments
+    ///
+    /// A dropped connection is re-established with backoff; audio sent in
+    /// the meantime is buffered and replayed once reconnected.
     pub async fn start_streaming(
         &self,
         config: DeepgramConfig,
@@ -120,70 +160,239 @@ impl DeepgramClient {
             if config.diarize {
                 query.append_pair("diarize", "true");
             }
+
+            // Nova-3 takes key terms; older models take boosted keywords
+            for keyword in &config.keywords {
+                if config.model.starts_with("nova-3") {
+                    query.append_pair("keyterm", keyword);
+                } else {
+                    query.append_pair("keywords", &format!("{}:2", keyword));
+                }
+            }
         }
 
         tracing::info!("Connecting to Deepgram: {}", url);
-
-        // Connect with authorization header
-        let request = http::Request::builder()
-            .uri(url.as_str())
-            .header("Authorization", format!("Token {}", self.api_key))
-            .header("Host", "api.deepgram.com")
-            .header("Connection", "Upgrade")
-            .header("Upgrade", "websocket")
-            .header("Sec-WebSocket-Version", "13")
-            .header("Sec-WebSocket-Key", tungstenite_key())
-            .body(())?;
-
-        let (ws_stream, _) = connect_async(request).await?;
-        let (mut write, mut read) = ws_stream.split();
-
+        let ws_stream = connect(&url, &self.api_key).await?;
         tracing::info!("Connected to Deepgram");
 
         // Channels for audio input and transcript output
-        let (audio_tx, mut audio_rx) = mpsc::channel::<Vec<u8>>(100);
+        let (audio_tx, audio_rx) = mpsc::channel::<Vec<u8>>(100);
         let (transcript_tx, transcript_rx) = mpsc::channel::<TranscriptSegment>(100);
 
-        // Task to send audio data
-        tokio::spawn(async move {
-            while let Some(audio_data) = audio_rx.recv().await {
-                if write.send(Message::Binary(audio_data)).await.is_err() {
-                    tracing::warn!("Failed to send audio to Deepgram");
-                    break;
-                }
+        let connection = Connection {
+            url,
+            api_key: self.api_key.clone(),
+            audio_rx,
+            transcript_tx,
+            status_tx: self.status_tx.clone(),
+            pending: PendingAudio::default(),
+        };
+        tokio::spawn(connection.run(ws_stream));
+
+        Ok((audio_tx, transcript_rx))
+    }
+}
+
+/// Open a WebSocket to Deepgram
+///
+/// The streaming config lives in the URL, so reconnecting with the same URL
+/// re-sends it.
+async fn connect(url: &Url, api_key: &str) -> Result<WsStream> {
+    // Connect with authorization header
+    let request = http::Request::builder()
+        .uri(url.as_str())
+        .header("Authorization", format!("Token {}", api_key))
+        .header("Host", "api.deepgram.com")
+        .header("Connection", "Upgrade")
+        .header("Upgrade", "websocket")
+        .header("Sec-WebSocket-Version", "13")
+        .header("Sec-WebSocket-Key", tungstenite_key())
+        .body(())?;
+
+    let (ws_stream, _) = connect_async(request).await?;
+    Ok(ws_stream)
+}
+
+/// How a connection session ended
+enum SessionEnd {
+    /// Audio sender or transcript receiver was dropped
+    Shutdown,
+    /// Socket errored or was closed by the server
+    Dropped,
+}
+
+/// One streaming session that survives dropped sockets
+struct Connection {
+    url: Url,
+    api_key: String,
+    audio_rx: mpsc::Receiver<Vec<u8>>,
+    transcript_tx: mpsc::Sender<TranscriptSegment>,
+    status_tx: Option<mpsc::Sender<DeepgramStatus>>,
+    pending: PendingAudio,
+}
+
+impl Connection {
+    /// Stream until shutdown, reconnecting whenever the socket drops
+    async fn run(mut self, mut ws_stream: WsStream) {
+        loop {
+            if let SessionEnd::Shutdown = self.stream(ws_stream).await {
+                return;
             }
 
-            // Send close frame
-            let _ = write.send(Message::Close(None)).await;
-        });
+            tracing::warn!("Deepgram connection dropped");
+            ws_stream = match self.reconnect().await {
+                Some(ws_stream) => ws_stream,
+                None => return,
+            };
+            self.notify(DeepgramStatus::Reconnected);
+        }
+    }
+
+    /// Forward audio and transcripts over one socket
+    async fn stream(&mut self, ws_stream: WsStream) -> SessionEnd {
+        let (mut write, mut read) = ws_stream.split();
+
+        // Replay audio that arrived while reconnecting
+        while let Some(chunk) = self.pending.pop() {
+            if write.send(Message::Binary(chunk.clone())).await.is_err() {
+                self.pending.push_front(chunk);
+                return SessionEnd::Dropped;
+            }
+        }
 
-        // Task to receive transcripts
-        tokio::spawn(async move {
-            while let Some(msg) = read.next().await {
-                match msg {
-                    Ok(Message::Text(text)) => {
+        // Deepgram drops the connection after ~10s without data, which
+        // happens whenever the VAD gate holds back silence
+        let mut keepalive = tokio::time::interval(std::time::Duration::from_secs(5));
+        keepalive.tick().await;
+
+        loop {
+            tokio::select! {
+                audio = self.audio_rx.recv() => {
+                    let Some(audio_data) = audio else {
+                        // Send close frame
+                        let _ = write.send(Message::Close(None)).await;
+                        return SessionEnd::Shutdown;
+                    };
+                    if write.send(Message::Binary(audio_data.clone())).await.is_err() {
+                        tracing::warn!("Failed to send audio to Deepgram");
+                        self.pending.push(audio_data);
+                        return SessionEnd::Dropped;
+                    }
+                    keepalive.reset();
+                }
+                _ = keepalive.tick() => {
+                    let message = Message::Text(r#"{"type":"KeepAlive"}"#.to_string());
+                    if write.send(message).await.is_err() {
+                        return SessionEnd::Dropped;
+                    }
+                }
+                msg = read.next() => match msg {
+                    Some(Ok(Message::Text(text))) => {
                         if let Ok(response) = serde_json::from_str::<DeepgramResponse>(&text) {
                             if let Some(segment) = parse_deepgram_response(response) {
-                                if transcript_tx.send(segment).await.is_err() {
-                                    break;
+                                if self.transcript_tx.send(segment).await.is_err() {
+                                    let _ = write.send(Message::Close(None)).await;
+                                    return SessionEnd::Shutdown;
                                 }
                             }
                         }
                     }
-                    Ok(Message::Close(_)) => {
+                    Some(Ok(Message::Close(_))) | None => {
                         tracing::info!("Deepgram connection closed");
-                        break;
+                        return SessionEnd::Dropped;
                     }
-                    Err(e) => {
+                    Some(Err(e)) => {
                         tracing::error!("Deepgram WebSocket error: {}", e);
-                        break;
+                        return SessionEnd::Dropped;
                     }
                     _ => {}
+                },
+                _ = self.transcript_tx.closed() => {
+                    let _ = write.send(Message::Close(None)).await;
+                    return SessionEnd::Shutdown;
                 }
             }
-        });
+        }
+    }
 
-        Ok((audio_tx, transcript_rx))
+    /// Try to reconnect with backoff, buffering audio while waiting
+    ///
+    /// Returns `None` on shutdown or once every attempt has failed.
+    async fn reconnect(&mut self) -> Option<WsStream> {
+        for attempt in 1..=MAX_RECONNECT_ATTEMPTS {
+            self.notify(DeepgramStatus::Reconnecting { attempt });
+
+            let delay = tokio::time::sleep(reconnect_delay(attempt));
+            tokio::pin!(delay);
+
+            loop {
+                tokio::select! {
+                    _ = &mut delay => break,
+                    audio = self.audio_rx.recv() => match audio {
+                        Some(chunk) => self.pending.push(chunk),
+                        None => return None,
+                    },
+                    _ = self.transcript_tx.closed() => return None,
+                }
+            }
+
+            match connect(&self.url, &self.api_key).await {
+                Ok(ws_stream) => {
+                    tracing::info!("Reconnected to Deepgram (attempt {})", attempt);
+                    return Some(ws_stream);
+                }
+                Err(e) => tracing::warn!("Deepgram reconnect attempt {} failed: {}", attempt, e),
+            }
+        }
+
+        tracing::error!("Giving up on Deepgram after {} attempts", MAX_RECONNECT_ATTEMPTS);
+        self.notify(DeepgramStatus::Disconnected);
+        None
+    }
+
+    fn notify(&self, status: DeepgramStatus) {
+        if let Some(tx) = &self.status_tx {
+            let _ = tx.try_send(status);
+        }
+    }
+}
+
+/// Delay before a reconnect attempt (1-based)
+fn reconnect_delay(attempt: u32) -> std::time::Duration {
+    std::time::Duration::from_millis(RECONNECT_BASE_DELAY_MS * 2u64.pow(attempt.saturating_sub(1)))
+}
+
+/// Audio held back while the socket is down
+///
+/// Keeps the most recent audio once the cap is reached.
+#[derive(Default)]
+struct PendingAudio {
+    chunks: VecDeque<Vec<u8>>,
+    bytes: usize,
+}
+
+impl PendingAudio {
+    fn push(&mut self, chunk: Vec<u8>) {
+        self.bytes += chunk.len();
+        self.chunks.push_back(chunk);
+
+        while self.bytes > MAX_PENDING_AUDIO_BYTES {
+            match self.chunks.pop_front() {
+                Some(dropped) => self.bytes -= dropped.len(),
+                None => break,
+            }
+        }
+    }
+
+    fn push_front(&mut self, chunk: Vec<u8>) {
+        self.bytes += chunk.len();
+        self.chunks.push_front(chunk);
+    }
+
+    fn pop(&mut self) -> Option<Vec<u8>> {
+        let chunk = self.chunks.pop_front()?;
+        self.bytes -= chunk.len();
+        Some(chunk)
     }
 }
 
@@ -200,15 +409,40 @@ fn parse_deepgram_response(response: DeepgramResponse) -> Option<TranscriptSegme
         return None;
     }
 
+    let words = alternative.words.as_deref().unwrap_or(&[]);
     Some(TranscriptSegment {
         text: alternative.transcript.clone(),
         confidence: alternative.confidence,
         is_final: response.is_final.unwrap_or(false),
-        speaker: None, // Would be populated if diarization is enabled
+        speaker: dominant_speaker(words),
         timestamp: chrono::Utc::now(),
+        audio_span: words.first().zip(words.last()).map(|(first, last)| (first.start, last.end)),
     })
 }
 
+/// Pick the speaker who said the most words in a result
+///
+/// Deepgram tags each word individually, so a single result can span a
+/// speaker change. The majority speaker wins; ties go to the earliest one.
+fn dominant_speaker(words: &[Word]) -> Option<String> {
+    let mut counts: Vec<(u32, usize)> = Vec::new();
+
+    for speaker in words.iter().filter_map(|w| w.speaker) {
+        match counts.iter_mut().find(|(s, _)| *s == speaker) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((speaker, 1)),
+        }
+    }
+
+    counts
+        .iter()
+        .fold(None, |best: Option<(u32, usize)>, &(speaker, count)| match best {
+            Some((_, best_count)) if best_count >= count => best,
+            _ => Some((speaker, count)),
+        })
+        .map(|(speaker, _)| speaker.to_string())
+}
+
 /// Generate a random WebSocket key
 fn tungstenite_key() -> String {
     use base64::Engine;
@@ -241,5 +475,56 @@ mod tests {
         assert_eq!(segment.text, "Hello, how are you?");
         assert!(segment.is_final);
         assert!(segment.confidence > 0.9);
+        assert_eq!(segment.speaker, None);
+        assert_eq!(segment.audio_span, None);
+    }
+
+    #[test]
+    fn test_parse_diarized_response() {
+        let json = r#"{
+            "type": "Results",
+            "channel": {
+                "alternatives": [{
+                    "transcript": "Sure, what's the price?",
+                    "confidence": 0.93,
+                    "words": [
+                        {"word": "sure", "start": 0.1, "end": 0.3, "confidence": 0.9, "speaker": 0},
+                        {"word": "what's", "start": 0.4, "end": 0.6, "confidence": 0.9, "speaker": 1},
+                        {"word": "the", "start": 0.6, "end": 0.7, "confidence": 0.9, "speaker": 1},
+                        {"word": "price", "start": 0.7, "end": 1.0, "confidence": 0.9, "speaker": 1}
+                    ]
+                }]
+            },
+            "is_final": true
+        }"#;
+
+        let response: DeepgramResponse = serde_json::from_str(json).unwrap();
+        let segment = parse_deepgram_response(response).unwrap();
+
+        assert_eq!(segment.speaker.as_deref(), Some("1"));
+        assert_eq!(segment.audio_span, Some((0.1, 1.0)));
+    }
+
+    #[test]
+    fn test_reconnect_delay() {
+        assert_eq!(reconnect_delay(1).as_millis(), 500);
+        assert_eq!(reconnect_delay(2).as_millis(), 1000);
+        assert_eq!(reconnect_delay(MAX_RECONNECT_ATTEMPTS).as_millis(), 8000);
+    }
+
+    #[test]
+    fn test_pending_audio_keeps_latest() {
+        let mut pending = PendingAudio::default();
+        let chunk = MAX_PENDING_AUDIO_BYTES / 2;
+
+        pending.push(vec![1; chunk]);
+        pending.push(vec![2; chunk]);
+        pending.push(vec![3; chunk]);
+
+        assert_eq!(pending.bytes, MAX_PENDING_AUDIO_BYTES);
+        assert_eq!(pending.pop().unwrap()[0], 2);
+        assert_eq!(pending.pop().unwrap()[0], 3);
+        assert!(pending.pop().is_none());
+        assert_eq!(pending.bytes, 0);
     }
 }
@@ -0,0 +1,188 @@
+//! Wake Word
+//!
+//! Hands-free start: while the copilot is idle, listens on the microphone
+//! for a phrase like "Hey copilot". Bursts of speech are picked out with
+//! the same energy gate the pipeline uses, transcribed with the smallest
+//! downloaded local Whisper model and fuzzy-matched against the phrase, so
+//! no audio leaves the machine before the user asks for it.
+
+use anyhow::{anyhow, bail, Result};
+use tokio::task::JoinHandle;
+
+use super::audio::{AudioCapture, AudioConfig, SpeechGate};
+use super::local_whisper::{LocalWhisperClient, LocalWhisperConfig, WhisperModel};
+
+/// Shorter bursts are clicks and coughs (300ms at 16kHz)
+const MIN_BURST_SAMPLES: usize = 4_800;
+
+/// Longer bursts are conversation, not a wake phrase (4s at 16kHz)
+const MAX_BURST_SAMPLES: usize = 64_000;
+
+/// Smallest first; the phrase is short, so speed matters more than accuracy
+const MODEL_PREFERENCE: [WhisperModel; 5] = [
+    WhisperModel::Tiny,
+    WhisperModel::Base,
+    WhisperModel::Small,
+    WhisperModel::Medium,
+    WhisperModel::Large,
+];
+
+/// What to listen for
+#[derive(Debug, Clone)]
+pub struct HotwordConfig {
+    pub phrase: String,
+    /// 0.0 (exact match) to 1.0 (loose match)
+    pub sensitivity: f32,
+}
+
+/// Microphone listener that fires when the wake phrase is heard
+///
+/// Listening stops when it's dropped, which frees the microphone.
+pub struct HotwordListener {
+    capture: AudioCapture,
+    task: JoinHandle<()>,
+}
+
+impl HotwordListener {
+    /// Start listening; `on_heard` runs each time the phrase is said
+    pub async fn start(config: HotwordConfig, on_heard: impl Fn() + Send + 'static) -> Result<Self> {
+        if config.phrase.trim().is_empty() {
+            bail!("No wake phrase set");
+        }
+
+        let mut whisper = MODEL_PREFERENCE
+            .iter()
+            .map(|&model| {
+                LocalWhisperClient::new(LocalWhisperConfig {
+                    model,
+                    ..LocalWhisperConfig::default()
+                })
+            })
+            .find(|client| client.is_model_downloaded())
+            .ok_or_else(|| anyhow!("The wake word needs a local Whisper model; download one first"))?;
+        whisper.init().await?;
+
+        let audio_config = AudioConfig::default();
+        let mut capture = AudioCapture::microphone(audio_config.clone());
+        let mut audio_rx = capture.start()?;
+
+        let task = tokio::spawn(async move {
+            let mut gate = SpeechGate::new(&audio_config);
+            let mut burst: Vec<f32> = Vec::new();
+            let mut too_long = false;
+
+            while let Some(samples) = audio_rx.recv().await {
+                for frame in gate.process(samples) {
+                    burst.extend(frame);
+                }
+                if burst.len() > MAX_BURST_SAMPLES {
+                    burst.clear();
+                    too_long = true;
+                }
+                if gate.is_active() || (burst.is_empty() && !too_long) {
+                    continue;
+                }
+
+                // The burst has ended
+                let clip = std::mem::take(&mut burst);
+                if std::mem::take(&mut too_long) || clip.len() < MIN_BURST_SAMPLES {
+                    continue;
+                }
+                match whisper.transcribe_samples(clip).await {
+                    Ok(text) if phrase_matches(&text, &config.phrase, config.sensitivity) => {
+                        tracing::info!("Wake phrase heard: {}", text.trim());
+                        on_heard();
+                    }
+                    Ok(_) => {}
+                    Err(e) => tracing::warn!("Wake word transcription failed: {}", e),
+                }
+            }
+        });
+
+        Ok(Self { capture, task })
+    }
+}
+
+impl Drop for HotwordListener {
+    fn drop(&mut self) {
+        self.task.abort();
+        self.capture.stop();
+    }
+}
+
+/// Whether `heard` contains the wake phrase
+///
+/// Punctuation, case and word breaks are ignored ("Hey, co-pilot!" is
+/// "hey copilot"), and higher sensitivity accepts more spelling drift.
+pub fn phrase_matches(heard: &str, phrase: &str, sensitivity: f32) -> bool {
+    let phrase_words = words(phrase);
+    let target = phrase_words.concat();
+    if target.is_empty() {
+        return false;
+    }
+
+    // From 0.95 of characters matching when strict down to 0.6 when loose
+    let threshold = 0.95 - 0.35 * sensitivity.clamp(0.0, 1.0);
+    let heard = words(heard);
+    let sizes = phrase_words.len().saturating_sub(1).max(1)..=phrase_words.len() + 1;
+
+    sizes.into_iter().any(|size| {
+        heard
+            .windows(size)
+            .any(|window| similarity(&window.concat(), &target) >= threshold)
+    })
+}
+
+/// Lowercase alphanumeric words
+fn words(text: &str) -> Vec<String> {
+    text.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+/// 1.0 for identical strings, less by the share of characters edited
+fn similarity(a: &str, b: &str) -> f32 {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let longest = a.len().max(b.len());
+    if longest == 0 {
+        return 1.0;
+    }
+
+    // Levenshtein distance, one row at a time
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.iter().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let above = row[j + 1];
+            row[j + 1] = if ca == cb {
+                diagonal
+            } else {
+                1 + diagonal.min(above).min(row[j])
+            };
+            diagonal = above;
+        }
+    }
+
+    1.0 - row[b.len()] as f32 / longest as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_phrase_matches() {
+        assert!(phrase_matches("Hey, co-pilot!", "Hey copilot", 0.5));
+        assert!(phrase_matches("Okay. Hey Copilot, let's go.", "hey copilot", 0.5));
+        assert!(!phrase_matches("Send me the copy of the deck", "Hey copilot", 0.5));
+        assert!(!phrase_matches("Hey copilot", "  ", 1.0));
+
+        // One letter off only passes when sensitivity is up
+        assert!(!phrase_matches("Hay copilot", "Hey copilot", 0.0));
+        assert!(phrase_matches("Hay copilot", "Hey copilot", 1.0));
+    }
+}
@@ -5,15 +5,32 @@
 
 use anyhow::{anyhow, Result};
 use parking_lot::Mutex;
-use std::path::PathBuf;
+use sha2::{Digest, Sha256};
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
-use tokio::sync::mpsc;
+use tokio::sync::{broadcast, mpsc};
+use whisper_rs::{FullParams, SamplingStrategy, WhisperContext, WhisperContextParameters};
 
 use super::transcript::TranscriptSegment;
 
 /// Default model to use (smaller = faster, larger = more accurate)
 const DEFAULT_MODEL: &str = "base.en";
 
+/// Sample rate whisper.cpp expects
+const WHISPER_SAMPLE_RATE: usize = 16000;
+
+/// Buffered download events (progress is sent at most once per percent)
+const DOWNLOAD_EVENT_CAPACITY: usize = 128;
+
+/// Published SHA-256 of each ggml model, keyed by filename
+const MODEL_SHA256: &[(&str, &str)] = &[
+    ("ggml-tiny.en.bin", "921e4cf8686fdd993dcd081a5da5b6c365bfde1162e72b08d75ac75289920b1f"),
+    ("ggml-base.en.bin", "a03779c86df3323075f5e796cb2ce5029f00ec8869eee3fdfb897afe36c6d002"),
+    ("ggml-small.en.bin", "c6138d6d58ecc8322097e0f987c32f1be8bb0a18532a3f88f734d1bbf9c41e5d"),
+    ("ggml-medium.en.bin", "cc37e93478338ec7700281a7ac30a10128929eb8f427dda2e865faa8f6da4356"),
+    ("ggml-large-v3.bin", "64d182b440b98d5203c4f9bd541544d84c605196c4f7b845dfa11fb23594d1e2"),
+];
+
 /// Whisper model sizes
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum WhisperModel {
@@ -50,6 +67,25 @@ impl WhisperModel {
         }
     }
 
+    /// Exact size of the published ggml file, used to detect truncated downloads
+    pub fn size_bytes(&self) -> u64 {
+        match self {
+            WhisperModel::Tiny => 77_704_715,
+            WhisperModel::Base => 147_964_211,
+            WhisperModel::Small => 487_614_201,
+            WhisperModel::Medium => 1_533_774_781,
+            WhisperModel::Large => 3_095_033_483,
+        }
+    }
+
+    /// Expected SHA-256 (lowercase hex) of the model file
+    pub fn sha256(&self) -> Option<&'static str> {
+        MODEL_SHA256
+            .iter()
+            .find(|(filename, _)| *filename == self.filename())
+            .map(|(_, hash)| *hash)
+    }
+
     pub fn from_str(s: &str) -> Self {
         match s.to_lowercase().as_str() {
             "tiny" => WhisperModel::Tiny,
@@ -109,13 +145,26 @@ pub enum WhisperStatus {
     Error(String),
 }
 
+/// Model download progress
+#[derive(Debug, Clone, PartialEq)]
+pub enum DownloadEvent {
+    /// Download began
+    Started { total_bytes: u64 },
+    /// Bytes received so far
+    Progress { bytes: u64, percent: u8 },
+    /// Model downloaded and verified
+    Done,
+    /// Download failed; no partial file is left behind
+    Failed(String),
+}
+
 /// Local Whisper STT client
 ///
-/// Note: This is a simplified implementation that processes audio in chunks.
-/// For production, consider using whisper-rs with proper VAD (Voice Activity Detection).
+/// Processes audio in 3-second chunks with 1 second of overlap between them.
 pub struct LocalWhisperClient {
     config: LocalWhisperConfig,
     model_path: Option<PathBuf>,
+    context: Option<Arc<WhisperContext>>,
     status: Arc<Mutex<WhisperStatus>>,
 }
 
@@ -125,6 +174,7 @@ impl LocalWhisperClient {
         Self {
             config,
             model_path: None,
+            context: None,
             status: Arc::new(Mutex::new(WhisperStatus::NotDownloaded)),
         }
     }
@@ -152,68 +202,53 @@ impl LocalWhisperClient {
         self.status.lock().clone()
     }
 
-    /// Download model from Hugging Face
-    pub async fn download_model(&self, progress_callback: impl Fn(u8) + Send + 'static) -> Result<()> {
-        let model_dir = Self::models_dir();
-        std::fs::create_dir_all(&model_dir)?;
-
+    /// Check the downloaded model against its published checksum
+    ///
+    /// Returns false for a corrupt file; call before `init()` to avoid a
+    /// cryptic load failure.
+    pub async fn verify_model(&self) -> Result<bool> {
         let model_path = self.model_path();
-        if model_path.exists() {
-            return Ok(());
-        }
-
-        *self.status.lock() = WhisperStatus::Downloading(0);
-
-        // Hugging Face model URLs
-        let url = format!(
-            "https://huggingface.co/ggerganov/whisper.cpp/resolve/main/{}",
-            self.config.model.filename()
-        );
-
-        tracing::info!("Downloading Whisper model from: {}", url);
-
-        let client = reqwest::Client::new();
-        let response = client
-            .get(&url)
-            .send()
-            .await?;
-
-        if !response.status().is_success() {
-            return Err(anyhow!("Failed to download model: HTTP {}", response.status()));
+        if !model_path.exists() {
+            return Err(anyhow!("Model not downloaded. Call download_model() first."));
         }
 
-        let total_size = response.content_length().unwrap_or(0);
-        let mut downloaded: u64 = 0;
-
-        let mut file = tokio::fs::File::create(&model_path).await?;
-        let mut stream = response.bytes_stream();
-
-        use futures::StreamExt;
-        use tokio::io::AsyncWriteExt;
+        let Some(expected) = self.config.model.sha256() else {
+            return Ok(true);
+        };
 
-        while let Some(chunk) = stream.next().await {
-            let chunk = chunk?;
-            file.write_all(&chunk).await?;
+        let actual = tokio::task::spawn_blocking(move || file_sha256(&model_path)).await??;
+        Ok(actual == expected)
+    }
 
-            downloaded += chunk.len() as u64;
-            if total_size > 0 {
-                let progress = ((downloaded as f64 / total_size as f64) * 100.0) as u8;
-                *self.status.lock() = WhisperStatus::Downloading(progress);
-                progress_callback(progress);
+    /// Download model from Hugging Face
+    pub async fn download_model(&self, progress_callback: impl Fn(u8) + Send + 'static) -> Result<()> {
+        download_model_file(self.config.model, self.status.clone(), move |event| {
+            if let DownloadEvent::Progress { percent, .. } = event {
+                progress_callback(percent);
             }
-        }
+        })
+        .await
+    }
 
-        file.flush().await?;
-        *self.status.lock() = WhisperStatus::NotDownloaded; // Will be Loading when init is called
+    /// Download the model in the background, streaming progress events
+    ///
+    /// The receiver ends after `Done` or `Failed`.
+    pub fn download_model_with_events(&self) -> broadcast::Receiver<DownloadEvent> {
+        let (tx, rx) = broadcast::channel(DOWNLOAD_EVENT_CAPACITY);
+        let model = self.config.model;
+        let status = self.status.clone();
 
-        tracing::info!("Downloaded Whisper model to: {:?}", model_path);
-        Ok(())
+        tokio::spawn(async move {
+            let _ = download_model_file(model, status, move |event| {
+                let _ = tx.send(event);
+            })
+            .await;
+        });
+
+        rx
     }
 
     /// Initialize the model (load into memory)
-    ///
-    /// Note: Actual whisper-rs initialization would happen here.
-    /// This is a placeholder that simulates the interface.
     pub async fn init(&mut self) -> Result<()> {
         let model_path = self.model_path();
         if !model_path.exists() {
@@ -222,9 +257,29 @@ impl LocalWhisperClient {
 
         *self.status.lock() = WhisperStatus::Loading;
 
-        // In a real implementation, we would load the model:
-        // let ctx = WhisperContext::new(&model_path.to_string_lossy())?;
+        let path = model_path.to_string_lossy().to_string();
+        let use_gpu = self.config.use_gpu;
+
+        // Loading a ggml model takes a while, keep it off the async runtime
+        let loaded = tokio::task::spawn_blocking(move || {
+            let mut params = WhisperContextParameters::default();
+            params.use_gpu(use_gpu);
+            WhisperContext::new_with_params(&path, params)
+        })
+        .await?;
+
+        let context = match loaded {
+            Ok(context) => context,
+            Err(e) => {
+                let message = format!("Failed to load Whisper model: {:?}", e);
+                *self.status.lock() = WhisperStatus::Error(message.clone());
+                return Err(anyhow!(message));
+            }
+        };
 
+        tracing::info!("Loaded Whisper model from: {:?}", model_path);
+
+        self.context = Some(Arc::new(context));
         self.model_path = Some(model_path);
         *self.status.lock() = WhisperStatus::Ready;
 
@@ -237,79 +292,271 @@ impl LocalWhisperClient {
     /// - A sender to push audio samples (f32, 16kHz)
     /// - A receiver to get transcript segments
     pub async fn start_streaming(&self) -> Result<(mpsc::Sender<Vec<f32>>, mpsc::Receiver<TranscriptSegment>)> {
-        if !matches!(*self.status.lock(), WhisperStatus::Ready) {
-            return Err(anyhow!("Model not initialized. Call init() first."));
-        }
+        let context = self.ready_context()?;
 
         let (audio_tx, mut audio_rx) = mpsc::channel::<Vec<f32>>(100);
         let (transcript_tx, transcript_rx) = mpsc::channel::<TranscriptSegment>(100);
 
         let status = self.status.clone();
         let config = self.config.clone();
-        let model_path = self.model_path.clone();
 
         // Spawn transcription task
         tokio::spawn(async move {
             let mut audio_buffer: Vec<f32> = Vec::new();
-            let sample_rate = 16000;
-            let chunk_samples = sample_rate * 3; // Process every 3 seconds
+            let chunk_samples = WHISPER_SAMPLE_RATE * 3; // Process every 3 seconds
+            let overlap = WHISPER_SAMPLE_RATE; // Keep last second for overlap
+
+            // Wall-clock time of the first sample in the buffer
+            let mut buffer_start = chrono::Utc::now();
+            let mut previous_text = String::new();
 
             while let Some(samples) = audio_rx.recv().await {
+                if audio_buffer.is_empty() {
+                    buffer_start = chrono::Utc::now();
+                }
                 audio_buffer.extend(samples);
 
                 // Process when we have enough audio
-                if audio_buffer.len() >= chunk_samples {
-                    *status.lock() = WhisperStatus::Transcribing;
-
-                    // In a real implementation, we would call whisper:
-                    // let mut state = ctx.create_state()?;
-                    // let mut params = FullParams::new(SamplingStrategy::Greedy { best_of: 1 });
-                    // params.set_language(Some(&config.language));
-                    // state.full(params, &audio_buffer)?;
-                    // let text = state.full_get_segment_text(0)?;
-
-                    // For now, emit a placeholder segment
-                    // This would be replaced with actual whisper transcription
+                if audio_buffer.len() < chunk_samples {
+                    continue;
+                }
+
+                *status.lock() = WhisperStatus::Transcribing;
+
+                let chunk = audio_buffer.clone();
+                let chunk_context = context.clone();
+                let chunk_config = config.clone();
+                let result = tokio::task::spawn_blocking(move || {
+                    run_whisper(&chunk_context, &chunk_config, &chunk)
+                })
+                .await;
+
+                let decoded = match result {
+                    Ok(Ok(decoded)) => decoded,
+                    Ok(Err(e)) => {
+                        tracing::warn!("Whisper transcription failed: {}", e);
+                        Vec::new()
+                    }
+                    Err(e) => {
+                        tracing::warn!("Whisper task failed: {}", e);
+                        Vec::new()
+                    }
+                };
+
+                let mut closed = false;
+                for part in decoded {
+                    let text = strip_overlap(&previous_text, &part.text);
+                    previous_text = part.text;
+
+                    if text.is_empty() {
+                        continue;
+                    }
+
                     let segment = TranscriptSegment {
-                        text: "[Local Whisper - model integration pending]".to_string(),
-                        confidence: 0.0,
+                        text,
+                        confidence: part.confidence,
                         is_final: true,
                         speaker: None,
-                        timestamp: chrono::Utc::now(),
+                        timestamp: buffer_start + chrono::Duration::milliseconds(part.start_ms),
+                        audio_span: None,
                     };
 
                     if transcript_tx.send(segment).await.is_err() {
+                        closed = true;
                         break;
                     }
+                }
 
-                    // Keep last second for overlap
-                    let overlap = sample_rate;
-                    if audio_buffer.len() > overlap {
-                        audio_buffer = audio_buffer[audio_buffer.len() - overlap..].to_vec();
-                    }
+                if closed {
+                    break;
+                }
 
-                    *status.lock() = WhisperStatus::Ready;
+                if audio_buffer.len() > overlap {
+                    let dropped = audio_buffer.len() - overlap;
+                    audio_buffer.drain(..dropped);
+                    buffer_start += chrono::Duration::milliseconds(
+                        (dropped * 1000 / WHISPER_SAMPLE_RATE) as i64,
+                    );
                 }
+
+                *status.lock() = WhisperStatus::Ready;
             }
         });
 
         Ok((audio_tx, transcript_rx))
     }
 
-    /// Transcribe a complete audio file
-    pub async fn transcribe_file(&self, _audio_path: &str) -> Result<String> {
-        if !matches!(*self.status.lock(), WhisperStatus::Ready) {
-            return Err(anyhow!("Model not initialized. Call init() first."));
+    /// Transcribe a complete audio file (16kHz WAV)
+    pub async fn transcribe_file(&self, audio_path: &str) -> Result<String> {
+        let samples = load_wav_samples(audio_path)?;
+        self.transcribe_samples(samples).await
+    }
+
+    /// Transcribe a short clip of 16kHz mono samples
+    pub async fn transcribe_samples(&self, samples: Vec<f32>) -> Result<String> {
+        let context = self.ready_context()?;
+        let config = self.config.clone();
+
+        *self.status.lock() = WhisperStatus::Transcribing;
+        let result = tokio::task::spawn_blocking(move || run_whisper(&context, &config, &samples)).await;
+        *self.status.lock() = WhisperStatus::Ready;
+
+        let decoded = result??;
+
+        Ok(decoded
+            .iter()
+            .map(|part| part.text.as_str())
+            .collect::<Vec<_>>()
+            .join(" "))
+    }
+
+    /// Get the loaded context, or fail if init() hasn't completed
+    fn ready_context(&self) -> Result<Arc<WhisperContext>> {
+        match (&self.context, &*self.status.lock()) {
+            (Some(context), WhisperStatus::Ready) => Ok(context.clone()),
+            _ => Err(anyhow!("Model not initialized. Call init() first.")),
         }
+    }
+}
+
+/// A segment decoded by whisper.cpp
+#[derive(Debug, Clone)]
+struct DecodedSegment {
+    text: String,
+    /// Offset from the start of the processed buffer
+    start_ms: i64,
+    /// Mean token probability
+    confidence: f32,
+}
 
-        // In a real implementation:
-        // let samples = load_audio_file(audio_path)?;
-        // let mut state = ctx.create_state()?;
-        // state.full(params, &samples)?;
-        // let text = collect_all_segments(&state);
+/// Run a full whisper pass over 16kHz mono samples
+fn run_whisper(context: &WhisperContext, config: &LocalWhisperConfig, samples: &[f32]) -> Result<Vec<DecodedSegment>> {
+    let mut state = context
+        .create_state()
+        .map_err(|e| anyhow!("Failed to create Whisper state: {:?}", e))?;
 
-        Ok("[Local Whisper transcription - model integration pending]".to_string())
+    let threads = if config.threads == 0 {
+        std::thread::available_parallelism()
+            .map(|n| n.get().min(8))
+            .unwrap_or(4) as i32
+    } else {
+        config.threads as i32
+    };
+
+    let language = if config.language.is_empty() {
+        "auto"
+    } else {
+        config.language.as_str()
+    };
+
+    let mut params = FullParams::new(SamplingStrategy::Greedy { best_of: 1 });
+    params.set_n_threads(threads);
+    params.set_language(Some(language));
+    params.set_translate(config.translate);
+    params.set_no_context(true);
+    params.set_print_special(false);
+    params.set_print_progress(false);
+    params.set_print_realtime(false);
+    params.set_print_timestamps(false);
+
+    state
+        .full(params, samples)
+        .map_err(|e| anyhow!("Whisper inference failed: {:?}", e))?;
+
+    let count = state
+        .full_n_segments()
+        .map_err(|e| anyhow!("Failed to read Whisper segments: {:?}", e))?;
+
+    let mut decoded = Vec::with_capacity(count.max(0) as usize);
+
+    for i in 0..count {
+        let text = match state.full_get_segment_text(i) {
+            Ok(text) => text.trim().to_string(),
+            Err(_) => continue,
+        };
+
+        if text.is_empty() {
+            continue;
+        }
+
+        // Whisper timestamps are in centiseconds
+        let start_ms = state.full_get_segment_t0(i).unwrap_or(0) * 10;
+
+        let token_count = state.full_n_tokens(i).unwrap_or(0);
+        let probs: Vec<f32> = (0..token_count)
+            .filter_map(|t| state.full_get_token_prob(i, t).ok())
+            .collect();
+        let confidence = if probs.is_empty() {
+            0.0
+        } else {
+            probs.iter().sum::<f32>() / probs.len() as f32
+        };
+
+        decoded.push(DecodedSegment {
+            text,
+            start_ms,
+            confidence,
+        });
     }
+
+    Ok(decoded)
+}
+
+/// Drop leading words of `current` that repeat the end of `previous`
+///
+/// Consecutive chunks share a second of audio, so the words at the
+/// boundary usually get transcribed twice.
+fn strip_overlap(previous: &str, current: &str) -> String {
+    fn normalize(word: &str) -> String {
+        word.chars()
+            .filter(|c| c.is_alphanumeric())
+            .flat_map(|c| c.to_lowercase())
+            .collect()
+    }
+
+    let prev_words: Vec<String> = previous.split_whitespace().map(normalize).collect();
+    let curr_words: Vec<&str> = current.split_whitespace().collect();
+    let curr_norm: Vec<String> = curr_words.iter().map(|w| normalize(w)).collect();
+
+    let max_overlap = prev_words.len().min(curr_words.len());
+    let overlap = (1..=max_overlap)
+        .rev()
+        .find(|&n| prev_words[prev_words.len() - n..] == curr_norm[..n])
+        .unwrap_or(0);
+
+    curr_words[overlap..].join(" ")
+}
+
+/// Load a 16kHz WAV file as mono f32 samples
+fn load_wav_samples(path: &str) -> Result<Vec<f32>> {
+    let mut reader = hound::WavReader::open(path)?;
+    let spec = reader.spec();
+
+    if spec.sample_rate as usize != WHISPER_SAMPLE_RATE {
+        return Err(anyhow!(
+            "Expected {}Hz audio, got {}Hz",
+            WHISPER_SAMPLE_RATE,
+            spec.sample_rate
+        ));
+    }
+
+    let interleaved: Vec<f32> = match spec.sample_format {
+        hound::SampleFormat::Float => reader.samples::<f32>().collect::<Result<_, _>>()?,
+        hound::SampleFormat::Int => {
+            let scale = (1i64 << (spec.bits_per_sample - 1)) as f32;
+            reader
+                .samples::<i32>()
+                .map(|s| s.map(|v| v as f32 / scale))
+                .collect::<Result<_, _>>()?
+        }
+    };
+
+    // Mix down to mono
+    let channels = spec.channels.max(1) as usize;
+    Ok(interleaved
+        .chunks(channels)
+        .map(|frame| frame.iter().sum::<f32>() / channels as f32)
+        .collect())
 }
 
 /// Check if whisper models are available
@@ -351,6 +598,121 @@ impl WhisperModelStatus {
     }
 }
 
+/// Download a model to the models directory, reporting progress
+///
+/// Data goes to a `.part` file that is only renamed once its size matches
+/// the published model, so an interrupted download is never mistaken for
+/// a usable model.
+async fn download_model_file(
+    model: WhisperModel,
+    status: Arc<Mutex<WhisperStatus>>,
+    mut on_event: impl FnMut(DownloadEvent) + Send,
+) -> Result<()> {
+    let model_path = LocalWhisperClient::models_dir().join(model.filename());
+    if model_path.exists() {
+        on_event(DownloadEvent::Done);
+        return Ok(());
+    }
+
+    let partial_path = model_path.with_extension("bin.part");
+    *status.lock() = WhisperStatus::Downloading(0);
+
+    let result = async {
+        std::fs::create_dir_all(LocalWhisperClient::models_dir())?;
+
+        let url = get_model_download_url(model);
+        tracing::info!("Downloading Whisper model from: {}", url);
+
+        let response = reqwest::Client::new().get(&url).send().await?;
+        if !response.status().is_success() {
+            return Err(anyhow!("Failed to download model: HTTP {}", response.status()));
+        }
+
+        let expected = model.size_bytes();
+        let total_size = response.content_length().unwrap_or(expected);
+        on_event(DownloadEvent::Started { total_bytes: total_size });
+
+        let mut downloaded: u64 = 0;
+        let mut last_percent = None;
+        let mut hasher = Sha256::new();
+
+        let mut file = tokio::fs::File::create(&partial_path).await?;
+        let mut stream = response.bytes_stream();
+
+        use futures::StreamExt;
+        use tokio::io::AsyncWriteExt;
+
+        while let Some(chunk) = stream.next().await {
+            let chunk = chunk?;
+            file.write_all(&chunk).await?;
+            hasher.update(&chunk);
+
+            downloaded += chunk.len() as u64;
+            if total_size > 0 {
+                let percent = ((downloaded as f64 / total_size as f64) * 100.0).min(100.0) as u8;
+                if last_percent != Some(percent) {
+                    last_percent = Some(percent);
+                    *status.lock() = WhisperStatus::Downloading(percent);
+                    on_event(DownloadEvent::Progress { bytes: downloaded, percent });
+                }
+            }
+        }
+
+        file.flush().await?;
+        drop(file);
+
+        if downloaded != expected {
+            return Err(anyhow!(
+                "Downloaded model is {} bytes, expected {} (incomplete download)",
+                downloaded,
+                expected
+            ));
+        }
+
+        if let Some(expected_hash) = model.sha256() {
+            let actual_hash = to_hex(&hasher.finalize());
+            if actual_hash != expected_hash {
+                return Err(anyhow!(
+                    "Downloaded {} failed checksum verification and was deleted; please retry",
+                    model.filename()
+                ));
+            }
+        }
+
+        tokio::fs::rename(&partial_path, &model_path).await?;
+        Ok::<(), anyhow::Error>(())
+    }
+    .await;
+
+    match result {
+        Ok(()) => {
+            *status.lock() = WhisperStatus::NotDownloaded; // Will be Loading when init is called
+            tracing::info!("Downloaded Whisper model to: {:?}", model_path);
+            on_event(DownloadEvent::Done);
+            Ok(())
+        }
+        Err(e) => {
+            let _ = tokio::fs::remove_file(&partial_path).await;
+            let message = e.to_string();
+            *status.lock() = WhisperStatus::Error(message.clone());
+            on_event(DownloadEvent::Failed(message));
+            Err(e)
+        }
+    }
+}
+
+/// SHA-256 of a file as lowercase hex
+fn file_sha256(path: &Path) -> Result<String> {
+    let mut file = std::fs::File::open(path)?;
+    let mut hasher = Sha256::new();
+    std::io::copy(&mut file, &mut hasher)?;
+    Ok(to_hex(&hasher.finalize()))
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
 /// Get download URL for a model
 pub fn get_model_download_url(model: WhisperModel) -> String {
     format!(
@@ -370,6 +732,35 @@ mod tests {
         assert!(path.to_string_lossy().contains("ggml-base.en.bin"));
     }
 
+    #[test]
+    fn test_model_sizes_match_listing() {
+        for model in [WhisperModel::Tiny, WhisperModel::Base, WhisperModel::Small, WhisperModel::Medium, WhisperModel::Large] {
+            let listed = model.size_mb() as f64 * 1_000_000.0;
+            let ratio = model.size_bytes() as f64 / listed;
+            assert!((0.9..1.1).contains(&ratio), "{:?} size out of range", model);
+        }
+    }
+
+    #[test]
+    fn test_every_model_has_checksum() {
+        for model in [WhisperModel::Tiny, WhisperModel::Base, WhisperModel::Small, WhisperModel::Medium, WhisperModel::Large] {
+            let hash = model.sha256().unwrap();
+            assert_eq!(hash.len(), 64);
+            assert!(hash.chars().all(|c| c.is_ascii_hexdigit() && !c.is_ascii_uppercase()));
+        }
+    }
+
+    #[test]
+    fn test_file_sha256() {
+        let path = std::env::temp_dir().join("voice_copilot_sha256_test.bin");
+        std::fs::write(&path, b"abc").unwrap();
+        assert_eq!(
+            file_sha256(&path).unwrap(),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        let _ = std::fs::remove_file(&path);
+    }
+
     #[test]
     fn test_model_from_str() {
         assert_eq!(WhisperModel::from_str("tiny"), WhisperModel::Tiny);
@@ -377,4 +768,15 @@ mod tests {
         assert_eq!(WhisperModel::from_str("large"), WhisperModel::Large);
         assert_eq!(WhisperModel::from_str("unknown"), WhisperModel::Base);
     }
+
+    #[test]
+    fn test_strip_overlap() {
+        assert_eq!(
+            strip_overlap("we can ship it next week", "next week, if pricing works"),
+            "if pricing works"
+        );
+        assert_eq!(strip_overlap("", "hello there"), "hello there");
+        assert_eq!(strip_overlap("hello there", "something else"), "something else");
+        assert_eq!(strip_overlap("see you soon", "soon"), "");
+    }
 }
@@ -10,10 +10,16 @@ mod deepgram;
 mod openai_realtime;
 mod local_whisper;
 mod transcript;
+mod corrections;
+mod hotword;
+mod stream_map;
 
-pub use audio::{AudioCapture, AudioCaptureState, AudioConfig};
+pub use audio::{AudioCapture, AudioCaptureState, AudioConfig, AudioLevel, CaptureError, SpeechGate, is_speech, f32_to_pcm_bytes};
 pub use app_audio::{AudioSource, AudioDevice, CaptureApp, detect_running_apps, list_audio_devices, get_available_sources};
-pub use deepgram::{DeepgramClient, DeepgramConfig};
+pub use deepgram::{DeepgramClient, DeepgramConfig, DeepgramStatus};
 pub use openai_realtime::OpenAIRealtimeClient;
-pub use local_whisper::{LocalWhisperClient, LocalWhisperConfig, WhisperModel, WhisperStatus, WhisperModelStatus, check_whisper_status};
-pub use transcript::{TranscriptSegment, TranscriptBuffer};
+pub use local_whisper::{LocalWhisperClient, LocalWhisperConfig, WhisperModel, WhisperStatus, WhisperModelStatus, DownloadEvent, check_whisper_status};
+pub use transcript::{TranscriptSegment, TranscriptBuffer, TranscriptIndex, TranscriptMatch, TranscriptTurn};
+pub use corrections::{Correction, CorrectionDictionary};
+pub use hotword::{HotwordConfig, HotwordListener};
+pub use stream_map::StreamMap;
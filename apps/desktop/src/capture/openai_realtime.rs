@@ -6,6 +6,9 @@
 use anyhow::Result;
 use futures::{SinkExt, StreamExt};
 use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::mpsc;
 use tokio_tungstenite::{connect_async, tungstenite::Message};
 use url::Url;
@@ -28,6 +31,12 @@ pub struct RealtimeConfig {
     pub output_audio_format: String,
     pub input_audio_transcription: Option<InputTranscriptionConfig>,
     pub turn_detection: Option<TurnDetectionConfig>,
+    /// Send a WebSocket ping after this long without audio (not sent to OpenAI)
+    #[serde(skip)]
+    pub heartbeat_interval_secs: u64,
+    /// Commit buffered audio after this long without new audio (not sent to OpenAI)
+    #[serde(skip)]
+    pub commit_after_silence_ms: u64,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -61,6 +70,8 @@ impl Default for RealtimeConfig {
                 prefix_padding_ms: 300,
                 silence_duration_ms: 500,
             }),
+            heartbeat_interval_secs: 20,
+            commit_after_silence_ms: 1000,
         }
     }
 }
@@ -144,6 +155,9 @@ impl OpenAIRealtimeClient {
 
         tracing::info!("Connected to OpenAI Realtime API");
 
+        let heartbeat_every = Duration::from_secs(config.heartbeat_interval_secs.max(1));
+        let commit_after = Duration::from_millis(config.commit_after_silence_ms);
+
         // Send session configuration
         let session_update = ClientEvent::SessionUpdate { session: config };
         let msg = serde_json::to_string(&session_update)?;
@@ -153,18 +167,62 @@ impl OpenAIRealtimeClient {
         let (audio_tx, mut audio_rx) = mpsc::channel::<Vec<u8>>(100);
         let (transcript_tx, transcript_rx) = mpsc::channel::<TranscriptSegment>(100);
 
+        // Audio appended since the last commit (ours or the server VAD's)
+        let uncommitted = Arc::new(AtomicBool::new(false));
+        let uncommitted_reader = uncommitted.clone();
+
         // Task to send audio data
         tokio::spawn(async move {
-            while let Some(audio_data) = audio_rx.recv().await {
-                // OpenAI expects base64 encoded audio
-                use base64::Engine;
-                let audio_base64 = base64::engine::general_purpose::STANDARD.encode(&audio_data);
-
-                let event = ClientEvent::InputAudioBufferAppend { audio: audio_base64 };
-                if let Ok(msg) = serde_json::to_string(&event) {
-                    if write.send(Message::Text(msg)).await.is_err() {
-                        tracing::warn!("Failed to send audio to OpenAI");
-                        break;
+            // The server closes idle sockets, so ping whenever audio stops flowing
+            let mut heartbeat = tokio::time::interval(heartbeat_every);
+            heartbeat.tick().await;
+            let mut commit_at: Option<tokio::time::Instant> = None;
+
+            loop {
+                let commit_due = async move {
+                    match commit_at {
+                        Some(at) => tokio::time::sleep_until(at).await,
+                        None => std::future::pending().await,
+                    }
+                };
+
+                tokio::select! {
+                    audio = audio_rx.recv() => {
+                        let Some(audio_data) = audio else { break };
+
+                        // OpenAI expects base64 encoded audio
+                        use base64::Engine;
+                        let audio_base64 = base64::engine::general_purpose::STANDARD.encode(&audio_data);
+
+                        let event = ClientEvent::InputAudioBufferAppend { audio: audio_base64 };
+                        if let Ok(msg) = serde_json::to_string(&event) {
+                            if write.send(Message::Text(msg)).await.is_err() {
+                                tracing::warn!("Failed to send audio to OpenAI");
+                                break;
+                            }
+                        }
+
+                        uncommitted.store(true, Ordering::SeqCst);
+                        heartbeat.reset();
+                        commit_at = Some(tokio::time::Instant::now() + commit_after);
+                    }
+                    _ = commit_due => {
+                        // Silence: commit so the pending transcription finalizes
+                        commit_at = None;
+                        if uncommitted.swap(false, Ordering::SeqCst) {
+                            let msg = serde_json::to_string(&ClientEvent::InputAudioBufferCommit {})
+                                .unwrap_or_default();
+                            if write.send(Message::Text(msg)).await.is_err() {
+                                tracing::warn!("Failed to commit audio to OpenAI");
+                                break;
+                            }
+                        }
+                    }
+                    _ = heartbeat.tick() => {
+                        if write.send(Message::Ping(Vec::new())).await.is_err() {
+                            tracing::warn!("Failed to send heartbeat to OpenAI");
+                            break;
+                        }
                     }
                 }
             }
@@ -188,12 +246,16 @@ impl OpenAIRealtimeClient {
                                             is_final: true,
                                             speaker: None,
                                             timestamp: chrono::Utc::now(),
+                                            audio_span: None,
                                         };
                                         if transcript_tx.send(segment).await.is_err() {
                                             break;
                                         }
                                     }
                                 }
+                                "input_audio_buffer.committed" => {
+                                    uncommitted_reader.store(false, Ordering::SeqCst);
+                                }
                                 "error" => {
                                     if let Some(error) = event.error {
                                         tracing::error!("OpenAI Realtime error: {}", error.message);
@@ -205,6 +267,9 @@ impl OpenAIRealtimeClient {
                             }
                         }
                     }
+                    Ok(Message::Pong(_)) => {
+                        tracing::trace!("OpenAI Realtime heartbeat acknowledged");
+                    }
                     Ok(Message::Close(_)) => {
                         tracing::info!("OpenAI Realtime connection closed");
                         break;
@@ -229,3 +294,20 @@ fn generate_ws_key() -> String {
     getrandom::getrandom(&mut key).unwrap();
     base64::engine::general_purpose::STANDARD.encode(key)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_local_settings_not_sent() {
+        let session = ClientEvent::SessionUpdate {
+            session: RealtimeConfig::default(),
+        };
+        let json = serde_json::to_string(&session).unwrap();
+
+        assert!(json.contains("\"type\":\"session.update\""));
+        assert!(!json.contains("heartbeat_interval_secs"));
+        assert!(!json.contains("commit_after_silence_ms"));
+    }
+}
@@ -0,0 +1,92 @@
+//! STT Stream Offsets
+//!
+//! Only gated speech is sent to STT, so the word times it reports skip
+//! every silence. This keeps track of where each run of sent audio came
+//! from in the capture stream, so those times can be placed back on the
+//! call's own timeline and line up with the recorded audio.
+
+/// One run of audio sent to STT without a gap
+#[derive(Debug, Clone, Copy)]
+struct Span {
+    /// First sample's position in the audio sent to STT
+    stt_start: u64,
+    /// First sample's position in the capture stream
+    capture_start: u64,
+    len: u64,
+}
+
+/// Maps STT stream times back onto the capture stream
+#[derive(Debug)]
+pub struct StreamMap {
+    sample_rate: u64,
+    spans: Vec<Span>,
+    /// Samples sent on the current STT connection
+    sent: u64,
+}
+
+impl StreamMap {
+    pub fn new(sample_rate: u32) -> Self {
+        Self {
+            sample_rate: sample_rate.max(1) as u64,
+            spans: Vec::new(),
+            sent: 0,
+        }
+    }
+
+    /// Note `len` samples sent to STT, ending at `capture_end` in the
+    /// capture stream
+    pub fn sent(&mut self, len: u64, capture_end: u64) {
+        let capture_start = capture_end.saturating_sub(len);
+        match self.spans.last_mut() {
+            Some(last) if last.stt_start + last.len == self.sent && last.capture_start + last.len == capture_start => {
+                last.len += len;
+            }
+            _ => self.spans.push(Span {
+                stt_start: self.sent,
+                capture_start,
+                len,
+            }),
+        }
+        self.sent += len;
+    }
+
+    /// STT reconnected and counts from zero again
+    ///
+    /// Audio buffered during the drop is re-sent first, so times right
+    /// after a reconnect can be early by up to that much.
+    pub fn restart(&mut self) {
+        self.spans.clear();
+        self.sent = 0;
+    }
+
+    /// Where a time in the STT stream (seconds) falls in the capture
+    /// stream (ms), if that audio was sent on this connection
+    pub fn capture_ms(&self, stt_secs: f32) -> Option<u64> {
+        let sample = (stt_secs.max(0.0) as f64 * self.sample_rate as f64) as u64;
+        let span = self.spans.iter().rev().find(|span| span.stt_start <= sample)?;
+        let capture = span.capture_start + (sample - span.stt_start).min(span.len);
+        Some(capture * 1000 / self.sample_rate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_capture_ms_skips_gated_silence() {
+        let mut map = StreamMap::new(16_000);
+        // 1s of speech at 0-1s, then silence until 5s, then 0.5s more
+        map.sent(8_000, 8_000);
+        map.sent(8_000, 16_000);
+        map.sent(8_000, 88_000);
+
+        assert_eq!(map.spans.len(), 2);
+        assert_eq!(map.capture_ms(0.25), Some(250));
+        // 1.25s into what STT heard is 0.25s into the second run
+        assert_eq!(map.capture_ms(1.25), Some(5_250));
+
+        map.restart();
+        assert_eq!(map.capture_ms(0.5), None);
+    }
+}
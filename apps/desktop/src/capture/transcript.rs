@@ -1,6 +1,7 @@
 //! Transcript Types and Buffer
 //!
-//! Manages the stream of transcripts from STT services.
+//! Manages the stream of transcripts from STT services, and indexes the
+//! turns of a live or recorded transcript for search.
 
 use chrono::{DateTime, Utc};
 use parking_lot::RwLock;
@@ -20,6 +21,9 @@ pub struct TranscriptSegment {
     pub speaker: Option<String>,
     /// When this segment was received
     pub timestamp: DateTime<Utc>,
+    /// First word's start and last word's end in the audio sent to STT
+    /// (seconds), when the provider reports word times
+    pub audio_span: Option<(f32, f32)>,
 }
 
 /// Buffer for managing transcript segments
@@ -84,6 +88,29 @@ impl TranscriptBuffer {
         parts.join(" ")
     }
 
+    /// Get the current transcript grouped into consecutive speaker runs
+    ///
+    /// Adjacent segments from the same speaker are merged, so the result reads
+    /// like a dialogue. Segments without diarization data have speaker `None`.
+    pub fn get_current_text_by_speaker(&self) -> Vec<(Option<String>, String)> {
+        let segments = self.segments.read();
+        let interim = self.interim.read();
+
+        let mut runs: Vec<(Option<String>, String)> = Vec::new();
+
+        for segment in segments.iter().chain(interim.as_ref()) {
+            match runs.last_mut() {
+                Some((speaker, text)) if *speaker == segment.speaker => {
+                    text.push(' ');
+                    text.push_str(&segment.text);
+                }
+                _ => runs.push((segment.speaker.clone(), segment.text.clone())),
+            }
+        }
+
+        runs
+    }
+
     /// Get only the final transcript text
     pub fn get_final_text(&self) -> String {
         let segments = self.segments.read();
@@ -105,6 +132,13 @@ impl TranscriptBuffer {
         self.segments.read().back().cloned()
     }
 
+    /// Replace the text of the latest final segment, returning the old text
+    pub fn replace_latest(&self, text: impl Into<String>) -> Option<String> {
+        let mut segments = self.segments.write();
+        let latest = segments.back_mut()?;
+        Some(std::mem::replace(&mut latest.text, text.into()))
+    }
+
     /// Get the last N characters of the transcript
     pub fn get_tail(&self, char_limit: usize) -> String {
         let text = self.get_current_text();
@@ -120,6 +154,16 @@ impl TranscriptBuffer {
         self.segments.read().iter().cloned().collect()
     }
 
+    /// Get final segments received at or after a point in time
+    pub fn get_segments_since(&self, since: DateTime<Utc>) -> Vec<TranscriptSegment> {
+        self.segments
+            .read()
+            .iter()
+            .filter(|s| s.timestamp >= since)
+            .cloned()
+            .collect()
+    }
+
     /// Clear all segments
     pub fn clear(&self) {
         self.segments.write().clear();
@@ -137,6 +181,84 @@ impl TranscriptBuffer {
     }
 }
 
+/// One speaker turn of a searchable transcript
+#[derive(Debug, Clone, PartialEq)]
+pub struct TranscriptTurn {
+    /// "You", "Them" or "Speaker 2" without a known user speaker
+    pub speaker: String,
+    pub text: String,
+}
+
+/// Where a query matched: byte range within one turn's text
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TranscriptMatch {
+    pub turn: usize,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// Transcript turns with a case-insensitive substring search
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TranscriptIndex {
+    turns: Vec<TranscriptTurn>,
+    /// ASCII-lowercased text, so byte offsets match the original
+    folded: Vec<String>,
+}
+
+impl TranscriptIndex {
+    pub fn new(turns: Vec<TranscriptTurn>) -> Self {
+        let folded = turns.iter().map(|t| t.text.to_ascii_lowercase()).collect();
+        Self { turns, folded }
+    }
+
+    /// Index the buffer's speaker runs, interim text included
+    ///
+    /// `user_speaker` is the diarized ID of the user, when known.
+    pub fn from_buffer(buffer: &TranscriptBuffer, user_speaker: Option<&str>) -> Self {
+        let turns = buffer
+            .get_current_text_by_speaker()
+            .into_iter()
+            .map(|(speaker, text)| TranscriptTurn {
+                speaker: match (speaker.as_deref(), user_speaker) {
+                    (Some(id), Some(user)) if id == user => "You".to_string(),
+                    (Some(id), None) => format!("Speaker {}", id),
+                    _ => "Them".to_string(),
+                },
+                text,
+            })
+            .collect();
+        Self::new(turns)
+    }
+
+    pub fn turns(&self) -> &[TranscriptTurn] {
+        &self.turns
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.turns.is_empty()
+    }
+
+    /// Every occurrence of `query`, in transcript order
+    pub fn search(&self, query: &str) -> Vec<TranscriptMatch> {
+        let query = query.trim().to_ascii_lowercase();
+        if query.is_empty() {
+            return Vec::new();
+        }
+
+        self.folded
+            .iter()
+            .enumerate()
+            .flat_map(|(turn, text)| {
+                text.match_indices(query.as_str()).map(move |(start, found)| TranscriptMatch {
+                    turn,
+                    start,
+                    end: start + found.len(),
+                })
+            })
+            .collect()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -152,6 +274,7 @@ mod tests {
             is_final: false,
             speaker: None,
             timestamp: Utc::now(),
+            audio_span: None,
         });
 
         assert_eq!(buffer.get_current_text(), "Hello");
@@ -164,6 +287,7 @@ mod tests {
             is_final: false,
             speaker: None,
             timestamp: Utc::now(),
+            audio_span: None,
         });
 
         assert_eq!(buffer.get_current_text(), "Hello world");
@@ -175,9 +299,84 @@ mod tests {
             is_final: true,
             speaker: None,
             timestamp: Utc::now(),
+            audio_span: None,
         });
 
         assert_eq!(buffer.get_final_text(), "Hello world!");
         assert_eq!(buffer.len(), 1);
     }
+
+    #[test]
+    fn test_text_by_speaker() {
+        let buffer = TranscriptBuffer::new(10);
+
+        for (text, speaker, is_final) in [
+            ("Hi there.", "0", true),
+            ("Thanks for joining.", "0", true),
+            ("Happy to be here.", "1", true),
+            ("So what", "0", false),
+        ] {
+            buffer.add(TranscriptSegment {
+                text: text.to_string(),
+                confidence: 0.9,
+                is_final,
+                speaker: Some(speaker.to_string()),
+                timestamp: Utc::now(),
+                audio_span: None,
+            });
+        }
+
+        let runs = buffer.get_current_text_by_speaker();
+        assert_eq!(runs.len(), 3);
+        assert_eq!(runs[0], (Some("0".to_string()), "Hi there. Thanks for joining.".to_string()));
+        assert_eq!(runs[1], (Some("1".to_string()), "Happy to be here.".to_string()));
+        assert_eq!(runs[2], (Some("0".to_string()), "So what".to_string()));
+    }
+
+    #[test]
+    fn test_transcript_index() {
+        let buffer = TranscriptBuffer::new(10);
+        for (text, speaker) in [("What does pricing look like?", "1"), ("Pricing is per seat.", "0"), ("Per seat? OK.", "1")] {
+            buffer.add(TranscriptSegment {
+                text: text.to_string(),
+                confidence: 0.9,
+                is_final: true,
+                speaker: Some(speaker.to_string()),
+                timestamp: Utc::now(),
+                audio_span: None,
+            });
+        }
+
+        let index = TranscriptIndex::from_buffer(&buffer, Some("0"));
+        assert_eq!(index.turns()[1].speaker, "You");
+        assert_eq!(index.turns()[2].speaker, "Them");
+
+        let matches = index.search(" PRICING ");
+        assert_eq!(matches.len(), 2);
+        assert_eq!(matches[0], TranscriptMatch { turn: 0, start: 10, end: 17 });
+        assert_eq!(&index.turns()[1].text[matches[1].start..matches[1].end], "Pricing");
+        assert_eq!(index.search("per seat").iter().map(|m| m.turn).collect::<Vec<_>>(), vec![1, 2]);
+        assert!(index.search("").is_empty());
+    }
+
+    #[test]
+    fn test_segments_since() {
+        let buffer = TranscriptBuffer::new(10);
+        let now = Utc::now();
+
+        for (text, age_secs) in [("Old news.", 60), ("Recent question?", 5)] {
+            buffer.add(TranscriptSegment {
+                text: text.to_string(),
+                confidence: 0.9,
+                is_final: true,
+                speaker: None,
+                timestamp: now - chrono::Duration::seconds(age_secs),
+                audio_span: None,
+            });
+        }
+
+        let recent = buffer.get_segments_since(now - chrono::Duration::seconds(30));
+        assert_eq!(recent.len(), 1);
+        assert_eq!(recent[0].text, "Recent question?");
+    }
 }
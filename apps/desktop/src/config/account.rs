@@ -0,0 +1,77 @@
+//! Outreach Account
+//!
+//! Connection to the Outreach API for cloud features: conversation memory
+//! and syncing recorded calls.
+
+use base64::Engine;
+use reqwest::{Client, Method, RequestBuilder};
+
+use super::ApiKeys;
+
+/// Where the user's Outreach account lives and how to authenticate
+#[derive(Debug, Clone, PartialEq)]
+pub struct OutreachAccount {
+    /// Outreach API base URL, e.g. "https://api.example.com"
+    pub api_url: String,
+    /// Access token for the user's account
+    pub token: String,
+    /// Device the token was issued to (sent as X-Device-Id)
+    pub device_id: String,
+}
+
+impl OutreachAccount {
+    /// Account from OUTREACH_* env vars, falling back to the saved token
+    ///
+    /// The device id is the one saved with the token at sign-in, else the
+    /// token's own `deviceId` claim. Tokens without a claim aren't bound,
+    /// so any id is accepted for them.
+    pub fn from_env(keys: &ApiKeys) -> Option<Self> {
+        let api_url = std::env::var("OUTREACH_API_URL").ok()?;
+        let token = std::env::var("OUTREACH_API_TOKEN").ok().or_else(|| keys.outreach.clone())?;
+        let device_id = std::env::var("OUTREACH_DEVICE_ID")
+            .ok()
+            .or_else(|| keys.outreach_device.clone())
+            .or_else(|| token_device(&token))
+            .unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
+        Some(Self { api_url, token, device_id })
+    }
+
+    /// Authenticated request to an API path, e.g. "/recordings"
+    pub fn request(&self, client: &Client, method: Method, path: &str) -> RequestBuilder {
+        let url = format!("{}{}", self.api_url.trim_end_matches('/'), path);
+        client
+            .request(method, url)
+            .bearer_auth(&self.token)
+            .header("X-Device-Id", &self.device_id)
+    }
+}
+
+/// The `deviceId` claim of an access token, if it has one
+///
+/// Read without verifying the signature; the API does that.
+pub fn token_device(token: &str) -> Option<String> {
+    let payload = token.split('.').nth(1)?;
+    let json = base64::engine::general_purpose::URL_SAFE_NO_PAD
+        .decode(payload.trim_end_matches('='))
+        .ok()?;
+    let claims: serde_json::Value = serde_json::from_slice(&json).ok()?;
+    claims["deviceId"].as_str().map(String::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_token_device() {
+        let encode = |claims: serde_json::Value| {
+            base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(claims.to_string())
+        };
+        let bound = format!("eyJhbGciOiJIUzI1NiJ9.{}.sig", encode(serde_json::json!({ "sub": "u1", "deviceId": "dev-1" })));
+        let unbound = format!("eyJhbGciOiJIUzI1NiJ9.{}.sig", encode(serde_json::json!({ "sub": "u1" })));
+
+        assert_eq!(token_device(&bound).as_deref(), Some("dev-1"));
+        assert_eq!(token_device(&unbound), None);
+        assert_eq!(token_device("not-a-token"), None);
+    }
+}
@@ -0,0 +1,173 @@
+//! Competitor Battlecards
+//!
+//! What to say when a prospect names a competitor: where we win and the
+//! "landmine" questions that expose the competitor's weak spots. Cards are
+//! edited as plain text and the matching card is added to the AI context.
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// Counters for one competitor
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Battlecard {
+    /// Name as prospects say it, e.g. "HubSpot"
+    pub competitor: String,
+    /// Where we're better
+    #[serde(default)]
+    pub differentiators: Vec<String>,
+    /// Questions to ask that expose their weaknesses
+    #[serde(default)]
+    pub landmines: Vec<String>,
+}
+
+impl Battlecard {
+    pub fn new(competitor: impl Into<String>) -> Self {
+        Self {
+            competitor: competitor.into(),
+            differentiators: Vec::new(),
+            landmines: Vec::new(),
+        }
+    }
+
+    /// Block for the AI context
+    pub fn prompt_block(&self) -> String {
+        let mut block = format!("They mentioned {}. Battlecard:", self.competitor);
+        if !self.differentiators.is_empty() {
+            block.push_str("\nOur differentiators:");
+            for point in &self.differentiators {
+                block.push_str(&format!("\n- {}", point));
+            }
+        }
+        if !self.landmines.is_empty() {
+            block.push_str("\nQuestions that expose their weaknesses:");
+            for question in &self.landmines {
+                block.push_str(&format!("\n- {}", question));
+            }
+        }
+        block
+    }
+}
+
+/// User battlecards, one per competitor
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Battlecards {
+    pub cards: Vec<Battlecard>,
+}
+
+impl Battlecards {
+    /// Get the battlecards file path
+    pub fn path() -> PathBuf {
+        dirs::config_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join("voice-copilot")
+            .join("battlecards.json")
+    }
+
+    /// Load the battlecards from disk
+    pub fn load() -> Result<Self> {
+        let path = Self::path();
+        if path.exists() {
+            let content = std::fs::read_to_string(&path)?;
+            Ok(serde_json::from_str(&content)?)
+        } else {
+            Ok(Self::default())
+        }
+    }
+
+    /// Save the battlecards to disk
+    pub fn save(&self) -> Result<()> {
+        let path = Self::path();
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let content = serde_json::to_string_pretty(self)?;
+        std::fs::write(&path, content)?;
+        Ok(())
+    }
+
+    /// Parse the editor format
+    ///
+    /// "[Competitor]" starts a card, "? question" adds a landmine and any
+    /// other line (optionally "+ point") adds a differentiator.
+    pub fn from_text(text: &str) -> Self {
+        let mut cards: Vec<Battlecard> = Vec::new();
+
+        for line in text.lines().map(str::trim).filter(|l| !l.is_empty()) {
+            if let Some(name) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                if !name.trim().is_empty() {
+                    cards.push(Battlecard::new(name.trim()));
+                }
+                continue;
+            }
+
+            let Some(card) = cards.last_mut() else {
+                continue;
+            };
+            if let Some(question) = line.strip_prefix('?') {
+                card.landmines.push(question.trim().to_string());
+            } else {
+                card.differentiators.push(line.trim_start_matches('+').trim().to_string());
+            }
+        }
+
+        Self { cards }
+    }
+
+    /// Render in the editor format
+    pub fn to_text(&self) -> String {
+        self.cards
+            .iter()
+            .map(|card| {
+                let mut lines = vec![format!("[{}]", card.competitor)];
+                lines.extend(card.differentiators.iter().map(|d| format!("+ {}", d)));
+                lines.extend(card.landmines.iter().map(|q| format!("? {}", q)));
+                lines.join("\n")
+            })
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
+
+    /// Competitor names to listen for
+    pub fn competitors(&self) -> Vec<String> {
+        self.cards.iter().map(|c| c.competitor.clone()).collect()
+    }
+
+    /// Card for a competitor, ignoring case
+    pub fn find(&self, competitor: &str) -> Option<&Battlecard> {
+        self.cards
+            .iter()
+            .find(|c| c.competitor.eq_ignore_ascii_case(competitor))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cards.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_text_round_trip() {
+        let text = "[HubSpot]\n+ Calls are recorded without an add-on\n? How do you review calls today?\n\n[Gong]\nHalf the price per seat";
+        let cards = Battlecards::from_text(text);
+        assert_eq!(cards.competitors(), vec!["HubSpot", "Gong"]);
+        assert_eq!(cards.cards[0].landmines, vec!["How do you review calls today?"]);
+        assert_eq!(cards.cards[1].differentiators, vec!["Half the price per seat"]);
+        assert_eq!(cards.find("hubspot").unwrap().differentiators.len(), 1);
+
+        assert_eq!(Battlecards::from_text(&cards.to_text()), cards);
+    }
+
+    #[test]
+    fn test_prompt_block() {
+        let mut card = Battlecard::new("Gong");
+        card.landmines.push("Who owns the recordings?".to_string());
+        assert_eq!(
+            card.prompt_block(),
+            "They mentioned Gong. Battlecard:\nQuestions that expose their weaknesses:\n- Who owns the recordings?"
+        );
+    }
+}
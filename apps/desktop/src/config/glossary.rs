@@ -0,0 +1,150 @@
+//! Domain Glossary
+//!
+//! Product names, acronyms and definitions the user cares about. Terms
+//! are sent to the STT as keywords so they're recognized, and a compact
+//! block is added to the AI context so answers use them correctly.
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// Most terms sent to the STT for boosting
+pub const MAX_STT_KEYWORDS: usize = 100;
+
+/// Longest glossary block added to prompts (characters)
+const PROMPT_BLOCK_MAX_CHARS: usize = 800;
+
+/// One glossary entry
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GlossaryTerm {
+    /// Product name or acronym, e.g. "SSO"
+    pub term: String,
+    /// Short definition (may be empty)
+    #[serde(default)]
+    pub definition: String,
+}
+
+/// User glossary of domain terms
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Glossary {
+    pub terms: Vec<GlossaryTerm>,
+}
+
+impl Glossary {
+    /// Get the glossary file path
+    pub fn path() -> PathBuf {
+        dirs::config_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join("voice-copilot")
+            .join("glossary.json")
+    }
+
+    /// Load the glossary from disk
+    pub fn load() -> Result<Self> {
+        let path = Self::path();
+        if path.exists() {
+            let content = std::fs::read_to_string(&path)?;
+            Ok(serde_json::from_str(&content)?)
+        } else {
+            Ok(Self::default())
+        }
+    }
+
+    /// Save the glossary to disk
+    pub fn save(&self) -> Result<()> {
+        let path = Self::path();
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let content = serde_json::to_string_pretty(self)?;
+        std::fs::write(&path, content)?;
+        Ok(())
+    }
+
+    /// Parse one "Term: definition" entry per line
+    pub fn from_lines(text: &str) -> Self {
+        let terms = text
+            .lines()
+            .filter_map(|line| {
+                let (term, definition) = line.split_once(':').unwrap_or((line, ""));
+                let term = term.trim();
+                (!term.is_empty()).then(|| GlossaryTerm {
+                    term: term.to_string(),
+                    definition: definition.trim().to_string(),
+                })
+            })
+            .collect();
+        Self { terms }
+    }
+
+    /// Render as "Term: definition" lines for editing
+    pub fn to_lines(&self) -> String {
+        self.terms
+            .iter()
+            .map(|t| {
+                if t.definition.is_empty() {
+                    t.term.clone()
+                } else {
+                    format!("{}: {}", t.term, t.definition)
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Terms to boost in speech recognition
+    pub fn keywords(&self) -> Vec<String> {
+        self.terms
+            .iter()
+            .map(|t| t.term.clone())
+            .take(MAX_STT_KEYWORDS)
+            .collect()
+    }
+
+    /// Compact block for the AI context, if there are any terms
+    pub fn prompt_block(&self) -> Option<String> {
+        if self.terms.is_empty() {
+            return None;
+        }
+
+        let mut block = String::from("Glossary:");
+        for t in &self.terms {
+            let line = if t.definition.is_empty() {
+                format!("\n- {}", t.term)
+            } else {
+                format!("\n- {}: {}", t.term, t.definition)
+            };
+            if block.len() + line.len() > PROMPT_BLOCK_MAX_CHARS {
+                break;
+            }
+            block.push_str(&line);
+        }
+        Some(block)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.terms.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lines_round_trip() {
+        let glossary = Glossary::from_lines("Acme Vault: our secrets manager\n\nSSO\n");
+        assert_eq!(glossary.terms.len(), 2);
+        assert_eq!(glossary.terms[1].definition, "");
+        assert_eq!(glossary.to_lines(), "Acme Vault: our secrets manager\nSSO");
+        assert_eq!(glossary.keywords(), vec!["Acme Vault", "SSO"]);
+    }
+
+    #[test]
+    fn test_prompt_block() {
+        assert_eq!(Glossary::default().prompt_block(), None);
+
+        let glossary = Glossary::from_lines("SSO: single sign-on");
+        assert_eq!(glossary.prompt_block().unwrap(), "Glossary:\n- SSO: single sign-on");
+    }
+}
@@ -3,5 +3,15 @@
 //! Manages application settings, API keys, and user preferences.
 
 mod settings;
+mod glossary;
+mod battlecards;
+mod account;
+mod webhooks;
+mod objections;
 
-pub use settings::{Settings, ApiKeys, AudioSettings, ModelSettings};
+pub use settings::{Settings, ApiKeys, AudioSettings, ModelSettings, FlashModel, DeepModel, SttProvider, CopyTarget, FollowupSettings, HotkeySettings, AlertLevel, AlertSettings, ResponseMode, ResponseSettings, WakeWordSettings};
+pub use glossary::{Glossary, GlossaryTerm};
+pub use battlecards::{Battlecard, Battlecards};
+pub use account::{token_device, OutreachAccount};
+pub use webhooks::WebhookConfig;
+pub use objections::{ObjectionEntry, ObjectionLibrary};
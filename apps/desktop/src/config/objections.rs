@@ -0,0 +1,268 @@
+//! Objection Library
+//!
+//! The user's best responses to objections they hear often. Each entry
+//! lists the phrases that trigger it; the best match for their latest turn
+//! goes into the Flash context and is shown above the Flash bullets. Like
+//! battlecards it's edited as plain text, and it can be shared as a file
+//! like prompts.
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// Bumped when the export format changes
+const EXPORT_VERSION: u32 = 1;
+
+/// One objection and how to answer it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ObjectionEntry {
+    /// Short name, e.g. "Too expensive"
+    pub objection_name: String,
+    /// Phrases that mean they're raising it
+    #[serde(default)]
+    pub trigger_keywords: Vec<String>,
+    /// What to say
+    pub suggested_response: String,
+}
+
+impl ObjectionEntry {
+    pub fn new(name: &str, keywords: &[&str], response: &str) -> Self {
+        Self {
+            objection_name: name.to_string(),
+            trigger_keywords: keywords.iter().map(|k| k.to_string()).collect(),
+            suggested_response: response.to_string(),
+        }
+    }
+
+    /// Block for the AI context
+    pub fn prompt_block(&self) -> String {
+        format!(
+            "They raised the \"{}\" objection. A response that has worked before:\n{}",
+            self.objection_name, self.suggested_response
+        )
+    }
+
+    /// How strongly their turn matches, counting the words of each trigger found
+    ///
+    /// `text` must already be normalized.
+    fn score(&self, text: &str) -> usize {
+        self.trigger_keywords
+            .iter()
+            .map(|keyword| normalize(keyword))
+            .filter(|keyword| !keyword.trim().is_empty() && text.contains(keyword.as_str()))
+            .map(|keyword| keyword.split_whitespace().count())
+            .sum()
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct ObjectionExport {
+    version: u32,
+    library: ObjectionLibrary,
+}
+
+/// The user's objection responses
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ObjectionLibrary {
+    pub entries: Vec<ObjectionEntry>,
+}
+
+impl Default for ObjectionLibrary {
+    /// Common objections to start from
+    fn default() -> Self {
+        Self {
+            entries: vec![
+                ObjectionEntry::new(
+                    "Too expensive",
+                    &["too expensive", "too much", "over budget", "out of our budget", "can't afford", "cheaper"],
+                    "Compared to what? Walk through what the problem costs them today, then tie the price to that. If budget is fixed, offer a smaller starting scope rather than a discount.",
+                ),
+                ObjectionEntry::new(
+                    "Need to think about it",
+                    &["think about it", "think it over", "sleep on it", "get back to you"],
+                    "Of course. So I can help, what part are you still weighing? Agree on a specific time to reconnect before the call ends.",
+                ),
+                ObjectionEntry::new(
+                    "Happy with the current solution",
+                    &["already use", "already have", "happy with", "works fine", "current vendor"],
+                    "That's great it's working. What would you change about it if you could? Ask what made them take this call.",
+                ),
+                ObjectionEntry::new(
+                    "Bad timing",
+                    &["not the right time", "bad time", "next quarter", "next year", "too busy", "maybe later"],
+                    "Understood. What's happening between now and then? Offer to set up the groundwork now so they're ready when timing improves.",
+                ),
+                ObjectionEntry::new(
+                    "Send me some information",
+                    &["send me info", "send me something", "email me", "send some information"],
+                    "Happy to. So I send what's actually useful, what would you want it to answer? Book the follow-up to go through it together.",
+                ),
+            ],
+        }
+    }
+}
+
+impl ObjectionLibrary {
+    /// Get the library file path
+    pub fn path() -> PathBuf {
+        dirs::config_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join("voice-copilot")
+            .join("objections.json")
+    }
+
+    /// Load the library, starting from the common objections if there's none yet
+    pub fn load() -> Result<Self> {
+        let path = Self::path();
+        if path.exists() {
+            let content = std::fs::read_to_string(&path)?;
+            Ok(serde_json::from_str(&content)?)
+        } else {
+            Ok(Self::default())
+        }
+    }
+
+    /// Save the library to disk
+    pub fn save(&self) -> Result<()> {
+        let path = Self::path();
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let content = serde_json::to_string_pretty(self)?;
+        std::fs::write(&path, content)?;
+        Ok(())
+    }
+
+    /// Write the library to a shareable file
+    pub fn export_to_file(&self, path: impl AsRef<Path>) -> Result<()> {
+        let export = ObjectionExport {
+            version: EXPORT_VERSION,
+            library: self.clone(),
+        };
+        std::fs::write(path, serde_json::to_string_pretty(&export)?)?;
+        Ok(())
+    }
+
+    /// Read a library from a shared file
+    pub fn import_from_file(path: impl AsRef<Path>) -> Result<Self> {
+        let content = std::fs::read_to_string(path)?;
+        let export: ObjectionExport = serde_json::from_str(&content)?;
+        if export.version != EXPORT_VERSION {
+            anyhow::bail!("Unsupported objection file version {}", export.version);
+        }
+        Ok(export.library)
+    }
+
+    /// Parse the editor format
+    ///
+    /// "[Name]" starts an entry, "keywords:" lists its triggers separated by
+    /// commas and any other lines are the response.
+    pub fn from_text(text: &str) -> Self {
+        let mut entries: Vec<ObjectionEntry> = Vec::new();
+
+        for line in text.lines().map(str::trim).filter(|l| !l.is_empty()) {
+            if let Some(name) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                if !name.trim().is_empty() {
+                    entries.push(ObjectionEntry::new(name.trim(), &[], ""));
+                }
+                continue;
+            }
+
+            let Some(entry) = entries.last_mut() else {
+                continue;
+            };
+            if let Some(keywords) = line.strip_prefix("keywords:") {
+                entry.trigger_keywords.extend(
+                    keywords
+                        .split(',')
+                        .map(str::trim)
+                        .filter(|k| !k.is_empty())
+                        .map(String::from),
+                );
+            } else {
+                if !entry.suggested_response.is_empty() {
+                    entry.suggested_response.push('\n');
+                }
+                entry.suggested_response.push_str(line);
+            }
+        }
+
+        Self { entries }
+    }
+
+    /// Render in the editor format
+    pub fn to_text(&self) -> String {
+        self.entries
+            .iter()
+            .map(|entry| {
+                format!(
+                    "[{}]\nkeywords: {}\n{}",
+                    entry.objection_name,
+                    entry.trigger_keywords.join(", "),
+                    entry.suggested_response
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
+
+    /// The entry whose triggers best match what they said
+    ///
+    /// Longer trigger phrases count for more; ties go to the earlier entry.
+    pub fn best_match(&self, text: &str) -> Option<&ObjectionEntry> {
+        let text = normalize(text);
+        let mut best: Option<(&ObjectionEntry, usize)> = None;
+        for entry in &self.entries {
+            let score = entry.score(&text);
+            if score > 0 && best.map_or(true, |(_, top)| score > top) {
+                best = Some((entry, score));
+            }
+        }
+        best.map(|(entry, _)| entry)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// Lowercase words separated by single spaces, padded so a trigger only
+/// matches whole words (" too much " isn't in " too muchness ")
+fn normalize(text: &str) -> String {
+    let words: Vec<String> = text
+        .to_lowercase()
+        .split(|c: char| !c.is_alphanumeric() && c != '\'')
+        .filter(|w| !w.is_empty())
+        .map(String::from)
+        .collect();
+    format!(" {} ", words.join(" "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_text_round_trip() {
+        let text = "[Too expensive]\nkeywords: too expensive, over budget\nAsk what the problem costs today.\nOffer a smaller scope.\n\n[Timing]\nkeywords: next quarter\nWhat changes next quarter?";
+        let library = ObjectionLibrary::from_text(text);
+        assert_eq!(library.entries.len(), 2);
+        assert_eq!(library.entries[0].trigger_keywords, vec!["too expensive", "over budget"]);
+        assert_eq!(library.entries[0].suggested_response, "Ask what the problem costs today.\nOffer a smaller scope.");
+        assert_eq!(ObjectionLibrary::from_text(&library.to_text()), library);
+    }
+
+    #[test]
+    fn test_best_match() {
+        let library = ObjectionLibrary::default();
+        let entry = library.best_match("Honestly it's too expensive for us right now.").unwrap();
+        assert_eq!(entry.objection_name, "Too expensive");
+        assert_eq!(
+            library.best_match("Let me think about it and get back to you").unwrap().objection_name,
+            "Need to think about it"
+        );
+        // Whole words only
+        assert!(library.best_match("We email meeting notes to the team").is_none());
+        assert!(entry.prompt_block().starts_with("They raised the \"Too expensive\" objection."));
+    }
+}
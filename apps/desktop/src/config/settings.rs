@@ -4,8 +4,16 @@
 
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::path::PathBuf;
 
+use crate::brain::ComplexityConfig;
+use crate::capture::AudioSource;
+use crate::deep::DEFAULT_DEEP_MAX_WORDS;
+use crate::flash::{AzureOpenAIConfig, StatementType, Urgency, DEFAULT_GROQ_MODEL};
+use crate::i18n::Language;
+use crate::redaction::RedactionRules;
+
 /// Main settings structure
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Settings {
@@ -19,6 +27,18 @@ pub struct Settings {
     pub ui: UiSettings,
     /// Keyboard shortcuts
     pub hotkeys: HotkeySettings,
+    /// Recording and export privacy
+    #[serde(default)]
+    pub privacy: PrivacySettings,
+    /// Follow-up date suggestions
+    #[serde(default)]
+    pub followup: FollowupSettings,
+    /// How urgent suggestions get attention
+    #[serde(default)]
+    pub alerts: AlertSettings,
+    /// How long suggestions can be
+    #[serde(default)]
+    pub responses: ResponseSettings,
 }
 
 impl Default for Settings {
@@ -29,6 +49,10 @@ impl Default for Settings {
             models: ModelSettings::default(),
             ui: UiSettings::default(),
             hotkeys: HotkeySettings::default(),
+            privacy: PrivacySettings::default(),
+            followup: FollowupSettings::default(),
+            alerts: AlertSettings::default(),
+            responses: ResponseSettings::default(),
         }
     }
 }
@@ -44,6 +68,16 @@ pub struct ApiKeys {
     pub google: Option<String>,
     /// Deepgram API key (for STT)
     pub deepgram: Option<String>,
+    /// Groq API key (for fast hosted Llama)
+    pub groq: Option<String>,
+    /// OpenRouter API key (one key for any hosted model)
+    pub openrouter: Option<String>,
+    /// Outreach account token (for conversation memory)
+    #[serde(default)]
+    pub outreach: Option<String>,
+    /// Device the Outreach token was issued to, as returned by sign-in
+    #[serde(default)]
+    pub outreach_device: Option<String>,
 }
 
 impl ApiKeys {
@@ -55,6 +89,7 @@ impl ApiKeys {
     /// Check if any LLM provider is configured
     pub fn has_llm(&self) -> bool {
         self.openai.is_some() || self.anthropic.is_some() || self.google.is_some()
+            || self.groq.is_some() || self.openrouter.is_some()
     }
 
     /// Save API keys securely using the OS keychain
@@ -89,6 +124,27 @@ pub struct AudioSettings {
     pub capture_system_audio: bool,
     /// Whether to capture microphone
     pub capture_microphone: bool,
+    /// Only analyze on the "analyze now" hotkey instead of every utterance
+    #[serde(default)]
+    pub push_to_talk: bool,
+    /// Final segments below this STT confidence are shown but not analyzed
+    #[serde(default = "default_min_confidence")]
+    pub min_confidence: f32,
+    /// Last source picked (None = guess the running call app)
+    #[serde(default)]
+    pub source: Option<AudioSource>,
+    /// Start listening hands-free on a spoken phrase
+    #[serde(default)]
+    pub wake_word: WakeWordSettings,
+    /// Diarized speaker that is you ("0" = whoever speaks first)
+    ///
+    /// None leaves diarization off and treats every line as theirs.
+    #[serde(default)]
+    pub user_speaker: Option<String>,
+}
+
+fn default_min_confidence() -> f32 {
+    0.6
 }
 
 impl Default for AudioSettings {
@@ -98,6 +154,32 @@ impl Default for AudioSettings {
             sample_rate: 16000,
             capture_system_audio: true,
             capture_microphone: false,
+            push_to_talk: false,
+            min_confidence: default_min_confidence(),
+            source: None,
+            wake_word: WakeWordSettings::default(),
+            user_speaker: None,
+        }
+    }
+}
+
+/// Wake phrase that starts listening while the copilot is idle
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WakeWordSettings {
+    /// Off unless the user turns it on, since it keeps the microphone open
+    pub enabled: bool,
+    pub phrase: String,
+    /// 0.0 (exact match) to 1.0 (loose match)
+    pub sensitivity: f32,
+}
+
+impl Default for WakeWordSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            phrase: "Hey copilot".to_string(),
+            sensitivity: 0.5,
         }
     }
 }
@@ -113,6 +195,31 @@ pub struct ModelSettings {
     pub deep_model: DeepModel,
     /// Whether to use o1 for complex questions
     pub use_o1_for_complex: bool,
+    /// Flash model overrides keyed by mode (e.g. "technical")
+    #[serde(default)]
+    pub per_mode_flash: HashMap<String, FlashModel>,
+    /// Deep model overrides keyed by mode
+    #[serde(default)]
+    pub per_mode_deep: HashMap<String, DeepModel>,
+    /// Keywords that decide local vs cloud routing
+    #[serde(default)]
+    pub complexity: ComplexityConfig,
+    /// Route OpenAI models through Azure OpenAI
+    #[serde(default)]
+    pub use_azure: bool,
+    /// Azure resource endpoint, e.g. https://my-resource.openai.azure.com
+    #[serde(default)]
+    pub azure_endpoint: String,
+    /// Azure deployment name
+    #[serde(default)]
+    pub azure_deployment: String,
+    /// Model used when the flash model is Groq
+    #[serde(default = "default_groq_model")]
+    pub groq_model: String,
+}
+
+fn default_groq_model() -> String {
+    DEFAULT_GROQ_MODEL.to_string()
 }
 
 impl Default for ModelSettings {
@@ -122,10 +229,59 @@ impl Default for ModelSettings {
             flash_model: FlashModel::GeminiFlash,
             deep_model: DeepModel::ClaudeSonnet,
             use_o1_for_complex: false,
+            per_mode_flash: HashMap::new(),
+            per_mode_deep: HashMap::new(),
+            complexity: ComplexityConfig::default(),
+            use_azure: false,
+            azure_endpoint: String::new(),
+            azure_deployment: String::new(),
+            groq_model: default_groq_model(),
         }
     }
 }
 
+impl ModelSettings {
+    /// Azure deployment, if enabled and fully configured
+    pub fn azure_config(&self) -> Option<AzureOpenAIConfig> {
+        if !self.use_azure || self.azure_endpoint.trim().is_empty() || self.azure_deployment.trim().is_empty() {
+            return None;
+        }
+        Some(AzureOpenAIConfig::new(self.azure_endpoint.trim(), self.azure_deployment.trim()))
+    }
+
+    /// Flash model for a mode, falling back to the global default
+    pub fn flash_model_for(&self, mode: &str) -> FlashModel {
+        self.per_mode_flash
+            .get(&mode.to_lowercase())
+            .cloned()
+            .unwrap_or_else(|| self.flash_model.clone())
+    }
+
+    /// Deep model for a mode, falling back to the global default
+    pub fn deep_model_for(&self, mode: &str) -> DeepModel {
+        self.per_mode_deep
+            .get(&mode.to_lowercase())
+            .cloned()
+            .unwrap_or_else(|| self.deep_model.clone())
+    }
+
+    /// Override the flash model for a mode (None clears the override)
+    pub fn set_mode_flash(&mut self, mode: &str, model: Option<FlashModel>) {
+        match model {
+            Some(model) => self.per_mode_flash.insert(mode.to_lowercase(), model),
+            None => self.per_mode_flash.remove(&mode.to_lowercase()),
+        };
+    }
+
+    /// Override the deep model for a mode (None clears the override)
+    pub fn set_mode_deep(&mut self, mode: &str, model: Option<DeepModel>) {
+        match model {
+            Some(model) => self.per_mode_deep.insert(mode.to_lowercase(), model),
+            None => self.per_mode_deep.remove(&mode.to_lowercase()),
+        };
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
 pub enum SttProvider {
     #[default]
@@ -141,6 +297,8 @@ pub enum FlashModel {
     GPT4oMini,
     /// Local Ollama (Llama 3.1 8B or other local models)
     LocalOllama,
+    /// Groq-hosted Llama (fastest cloud option)
+    Groq,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
@@ -164,6 +322,26 @@ pub struct UiSettings {
     pub show_transcript: bool,
     /// Compact mode
     pub compact_mode: bool,
+    /// Read deep responses aloud as they stream in
+    #[serde(default)]
+    pub speak_responses: bool,
+    /// Color theme key, a preset like "light" or a custom theme file name
+    #[serde(default = "default_theme_name")]
+    pub theme_name: String,
+    /// Show Deep responses as formatted markdown rather than raw text
+    #[serde(default = "default_render_markdown")]
+    pub render_markdown: bool,
+    /// Language suggestions are written in and the overlay is shown in
+    #[serde(default)]
+    pub language: Language,
+}
+
+fn default_render_markdown() -> bool {
+    true
+}
+
+fn default_theme_name() -> String {
+    "dark".to_string()
 }
 
 impl Default for UiSettings {
@@ -174,6 +352,10 @@ impl Default for UiSettings {
             default_mode: "sales".to_string(),
             show_transcript: true,
             compact_mode: false,
+            speak_responses: false,
+            theme_name: default_theme_name(),
+            render_markdown: default_render_markdown(),
+            language: Language::default(),
         }
     }
 }
@@ -189,6 +371,42 @@ pub struct HotkeySettings {
     pub switch_mode: String,
     /// Copy last suggestion
     pub copy_suggestion: String,
+    /// Analyze recent transcript now
+    #[serde(default = "default_request_analysis")]
+    pub request_analysis: String,
+    /// Pause/resume transcription and analysis
+    #[serde(default = "default_toggle_pause")]
+    pub toggle_pause: String,
+    /// Turn Flash-only (no Deep responses) on or off
+    #[serde(default = "default_toggle_flash_only")]
+    pub toggle_flash_only: String,
+    /// What the copy shortcut puts on the clipboard
+    #[serde(default)]
+    pub copy_target: CopyTarget,
+}
+
+/// What the copy-suggestion shortcut copies
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq)]
+pub enum CopyTarget {
+    /// Deep response, or the top Flash bullet until one arrives
+    #[default]
+    Deep,
+    /// Every Flash bullet
+    Bullets,
+    /// Suggested question to ask
+    Question,
+}
+
+fn default_request_analysis() -> String {
+    "Ctrl+Shift+A".to_string()
+}
+
+fn default_toggle_pause() -> String {
+    "Ctrl+Shift+P".to_string()
+}
+
+fn default_toggle_flash_only() -> String {
+    "Ctrl+Shift+D".to_string()
 }
 
 impl Default for HotkeySettings {
@@ -198,10 +416,190 @@ impl Default for HotkeySettings {
             toggle_visibility: "Ctrl+Shift+H".to_string(),
             switch_mode: "Ctrl+Shift+M".to_string(),
             copy_suggestion: "Ctrl+Shift+C".to_string(),
+            request_analysis: default_request_analysis(),
+            toggle_pause: default_toggle_pause(),
+            toggle_flash_only: default_toggle_flash_only(),
+            copy_target: CopyTarget::default(),
+        }
+    }
+}
+
+/// Privacy preferences
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct PrivacySettings {
+    /// Mask PII before recording or exporting transcripts
+    pub redact_pii: bool,
+    /// Keep an un-redacted copy in memory (never written to disk)
+    pub keep_unredacted: bool,
+    /// What gets masked
+    pub rules: RedactionRules,
+    /// Hide the window when a call app starts sharing the screen
+    pub auto_hide_on_share: bool,
+    /// Save each call's transcript for reports and minutes
+    pub auto_record: bool,
+    /// Also save the call audio as WAV (only with `auto_record`)
+    pub record_audio: bool,
+}
+
+/// Follow-up date suggestions
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FollowupSettings {
+    /// Pick up dates like "follow up next Tuesday" from responses and summaries
+    pub auto_extract: bool,
+    /// Hour of the day (local time) suggested follow-ups are set for
+    pub hour: u32,
+}
+
+impl Default for FollowupSettings {
+    fn default() -> Self {
+        Self {
+            auto_extract: true,
+            hour: 9,
+        }
+    }
+}
+
+/// How a suggestion gets the user's attention
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq)]
+pub enum AlertLevel {
+    /// Just show it
+    #[default]
+    Silent,
+    /// Flash the window border
+    Visual,
+    /// Flash the border and play a short chime
+    Audio,
+}
+
+/// Alert level per Flash urgency
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AlertSettings {
+    pub answer_now: AlertLevel,
+    pub can_elaborate: AlertLevel,
+    pub just_listening: AlertLevel,
+}
+
+impl Default for AlertSettings {
+    fn default() -> Self {
+        Self {
+            answer_now: AlertLevel::Visual,
+            can_elaborate: AlertLevel::Silent,
+            just_listening: AlertLevel::Silent,
+        }
+    }
+}
+
+impl AlertSettings {
+    pub fn level_for(&self, urgency: &Urgency) -> AlertLevel {
+        match urgency {
+            Urgency::AnswerNow => self.answer_now,
+            Urgency::CanElaborate => self.can_elaborate,
+            Urgency::JustListening => self.just_listening,
+            Urgency::Unknown => AlertLevel::Silent,
         }
     }
 }
 
+/// Length caps for Flash and Deep, so suggestions fit a small overlay
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ResponseSettings {
+    /// Flash bullets kept, highest priority first
+    pub max_bullets: usize,
+    /// Words per Flash bullet before it's cut short
+    pub max_bullet_words: usize,
+    /// Word target for Deep responses
+    pub deep_max_words: usize,
+    /// Which of Flash and Deep run on each turn
+    pub mode: ResponseMode,
+    /// Their turns that get a suggestion, by locally detected type;
+    /// empty means every turn
+    pub trigger_on: Vec<StatementType>,
+}
+
+/// Which responses are generated for a turn
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq)]
+pub enum ResponseMode {
+    /// Bullets only; Deep is skipped, saving its latency and tokens
+    FlashOnly,
+    /// Bullets, then the detailed answer
+    #[default]
+    FlashAndDeep,
+    /// The detailed answer only, without bullets first
+    DeepOnly,
+}
+
+impl ResponseMode {
+    pub fn runs_flash(&self) -> bool {
+        *self != ResponseMode::DeepOnly
+    }
+
+    pub fn runs_deep(&self) -> bool {
+        *self != ResponseMode::FlashOnly
+    }
+
+    /// In or out of Flash only, for the hotkey
+    pub fn toggled(&self) -> Self {
+        match self {
+            ResponseMode::FlashOnly => ResponseMode::FlashAndDeep,
+            _ => ResponseMode::FlashOnly,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ResponseMode::FlashOnly => "Bullets only",
+            ResponseMode::FlashAndDeep => "Bullets and detailed answer",
+            ResponseMode::DeepOnly => "Detailed answer only",
+        }
+    }
+}
+
+impl Default for ResponseSettings {
+    fn default() -> Self {
+        Self {
+            max_bullets: 4,
+            max_bullet_words: 15,
+            deep_max_words: DEFAULT_DEEP_MAX_WORDS,
+            mode: ResponseMode::default(),
+            trigger_on: vec![
+                StatementType::Question,
+                StatementType::Objection,
+                StatementType::BuyingSignal,
+                StatementType::Technical,
+            ],
+        }
+    }
+}
+
+impl ResponseSettings {
+    /// Values for the `{{max_bullets}}`, `{{max_bullet_words}}` and
+    /// `{{deep_max_words}}` prompt variables
+    pub fn prompt_variables(&self) -> HashMap<String, String> {
+        HashMap::from([
+            ("max_bullets".to_string(), self.max_bullets.to_string()),
+            ("max_bullet_words".to_string(), self.max_bullet_words.to_string()),
+            ("deep_max_words".to_string(), self.deep_max_words.to_string()),
+        ])
+    }
+
+    /// Whether a turn of this type is worth a Flash/Deep round
+    pub fn triggers_on(&self, statement: &StatementType) -> bool {
+        self.trigger_on.is_empty() || self.trigger_on.contains(statement)
+    }
+
+    /// Line added to the Flash context so the model aims for the caps
+    pub fn flash_instruction(&self) -> String {
+        format!(
+            "Give at most {} bullets of {} words or fewer.",
+            self.max_bullets, self.max_bullet_words
+        )
+    }
+}
+
 impl Settings {
     /// Get the settings file path
     pub fn path() -> PathBuf {
@@ -261,4 +659,53 @@ mod tests {
         assert!(settings.ui.always_on_top);
         assert_eq!(settings.models.stt_provider, SttProvider::Deepgram);
     }
+
+    #[test]
+    fn test_per_mode_overrides() {
+        let mut models = ModelSettings::default();
+        models.set_mode_deep("Technical", Some(DeepModel::O1Preview));
+        models.set_mode_flash("general", Some(FlashModel::GPT4oMini));
+
+        assert_eq!(models.deep_model_for("technical"), DeepModel::O1Preview);
+        assert_eq!(models.deep_model_for("sales"), DeepModel::ClaudeSonnet);
+        assert_eq!(models.flash_model_for("General"), FlashModel::GPT4oMini);
+
+        // Older settings files have no override maps
+        let json = r#"{"stt_provider":"Deepgram","flash_model":"GeminiFlash","deep_model":"GPT4o","use_o1_for_complex":false}"#;
+        let loaded: ModelSettings = serde_json::from_str(json).unwrap();
+        assert!(loaded.per_mode_deep.is_empty());
+
+        models.set_mode_deep("technical", None);
+        assert_eq!(models.deep_model_for("technical"), DeepModel::ClaudeSonnet);
+    }
+
+    #[test]
+    fn test_response_mode() {
+        assert!(ResponseMode::FlashOnly.runs_flash());
+        assert!(!ResponseMode::FlashOnly.runs_deep());
+        assert!(ResponseMode::FlashAndDeep.runs_flash());
+        assert!(ResponseMode::FlashAndDeep.runs_deep());
+        assert!(!ResponseMode::DeepOnly.runs_flash());
+        assert!(ResponseMode::DeepOnly.runs_deep());
+
+        // The hotkey flips in and out of bullets only
+        assert_eq!(ResponseMode::FlashAndDeep.toggled(), ResponseMode::FlashOnly);
+        assert_eq!(ResponseMode::DeepOnly.toggled(), ResponseMode::FlashOnly);
+        assert_eq!(ResponseMode::FlashOnly.toggled(), ResponseMode::FlashAndDeep);
+    }
+
+    #[test]
+    fn test_azure_config_requires_toggle_and_fields() {
+        let mut models = ModelSettings::default();
+        models.azure_endpoint = "https://acme.openai.azure.com/".to_string();
+        models.azure_deployment = "gpt4o".to_string();
+        assert!(models.azure_config().is_none());
+
+        models.use_azure = true;
+        let azure = models.azure_config().unwrap();
+        assert_eq!(azure.endpoint, "https://acme.openai.azure.com");
+
+        models.azure_deployment.clear();
+        assert!(models.azure_config().is_none());
+    }
 }
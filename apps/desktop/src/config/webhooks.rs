@@ -0,0 +1,98 @@
+//! Webhooks
+//!
+//! Where to send notifications during a live call (e.g. a Slack or Teams
+//! incoming webhook) and which detected intents should trigger them.
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// Outbound notification settings
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WebhookConfig {
+    /// Endpoint to POST to; empty disables notifications
+    pub url: String,
+    /// Shared secret for the HMAC-SHA256 signature (may be empty)
+    pub secret: String,
+    /// Intent categories that trigger a notification, e.g. "BuyingSignal"
+    pub categories: Vec<String>,
+    /// Minimum intent confidence (0.0 to 1.0)
+    pub min_confidence: f32,
+    /// Quiet period per category so one call doesn't flood the channel
+    pub cooldown_secs: u64,
+}
+
+impl Default for WebhookConfig {
+    fn default() -> Self {
+        Self {
+            url: String::new(),
+            secret: String::new(),
+            categories: vec!["BuyingSignal".to_string()],
+            min_confidence: 0.5,
+            cooldown_secs: 300,
+        }
+    }
+}
+
+impl WebhookConfig {
+    /// Get the webhook config file path
+    pub fn path() -> PathBuf {
+        dirs::config_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join("voice-copilot")
+            .join("webhooks.json")
+    }
+
+    /// Load the webhook config from disk
+    pub fn load() -> Result<Self> {
+        let path = Self::path();
+        if path.exists() {
+            let content = std::fs::read_to_string(&path)?;
+            Ok(serde_json::from_str(&content)?)
+        } else {
+            Ok(Self::default())
+        }
+    }
+
+    /// Save the webhook config to disk
+    pub fn save(&self) -> Result<()> {
+        let path = Self::path();
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let content = serde_json::to_string_pretty(self)?;
+        std::fs::write(&path, content)?;
+        Ok(())
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        !self.url.trim().is_empty()
+    }
+
+    /// Whether an intent of this category and confidence should notify
+    pub fn triggers(&self, category: &str, confidence: f32) -> bool {
+        self.is_enabled()
+            && confidence >= self.min_confidence
+            && self.categories.iter().any(|c| c.eq_ignore_ascii_case(category))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_triggers() {
+        assert!(!WebhookConfig::default().triggers("BuyingSignal", 0.9));
+
+        let config = WebhookConfig {
+            url: "https://hooks.example.com/T000".to_string(),
+            ..WebhookConfig::default()
+        };
+        assert!(config.triggers("BuyingSignal", 0.8));
+        assert!(config.triggers("buyingsignal", 0.5));
+        assert!(!config.triggers("BuyingSignal", 0.3));
+        assert!(!config.triggers("Objection", 0.9));
+    }
+}
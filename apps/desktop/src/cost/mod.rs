@@ -0,0 +1,7 @@
+//! Cost Module
+//!
+//! Token accounting across providers with estimated spend per session.
+
+mod tracker;
+
+pub use tracker::{CostTracker, ModelPrice, TokenUsage, UsageRecord, default_prices, LOCAL_PREFIX};
@@ -0,0 +1,251 @@
+//! Cost Tracker
+//!
+//! Records token counts reported by each provider and prices them from a
+//! per-1K-token table. Models are matched by the longest price key they
+//! start with, so "claude-sonnet" covers every dated Sonnet release.
+
+use chrono::{DateTime, Local, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Prefix used for local Ollama models (always free)
+pub const LOCAL_PREFIX: &str = "ollama/";
+
+/// Token counts for a single call
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct TokenUsage {
+    pub input_tokens: u64,
+    pub output_tokens: u64,
+}
+
+impl TokenUsage {
+    pub fn new(input_tokens: u64, output_tokens: u64) -> Self {
+        Self { input_tokens, output_tokens }
+    }
+
+    pub fn total(&self) -> u64 {
+        self.input_tokens + self.output_tokens
+    }
+}
+
+/// Price per 1K tokens in USD
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct ModelPrice {
+    pub input_per_1k: f64,
+    pub output_per_1k: f64,
+}
+
+impl ModelPrice {
+    pub fn new(input_per_1k: f64, output_per_1k: f64) -> Self {
+        Self { input_per_1k, output_per_1k }
+    }
+
+    /// Cost of a call at this price
+    pub fn cost(&self, usage: &TokenUsage) -> f64 {
+        usage.input_tokens as f64 / 1000.0 * self.input_per_1k
+            + usage.output_tokens as f64 / 1000.0 * self.output_per_1k
+    }
+}
+
+/// Default price table (USD per 1K tokens)
+pub fn default_prices() -> HashMap<String, ModelPrice> {
+    [
+        ("gemini-2.0-flash", ModelPrice::new(0.0001, 0.0004)),
+        ("gpt-4o-mini", ModelPrice::new(0.00015, 0.0006)),
+        ("gpt-4o", ModelPrice::new(0.0025, 0.01)),
+        ("o1", ModelPrice::new(0.015, 0.06)),
+        ("claude-3-5-haiku", ModelPrice::new(0.0008, 0.004)),
+        ("claude-sonnet", ModelPrice::new(0.003, 0.015)),
+        ("claude-3-5-sonnet", ModelPrice::new(0.003, 0.015)),
+        ("groq/llama-3.1-8b-instant", ModelPrice::new(0.00005, 0.00008)),
+        // OpenRouter passes through provider pricing
+        ("google/gemini-2.0-flash", ModelPrice::new(0.0001, 0.0004)),
+        ("anthropic/claude-3.5-sonnet", ModelPrice::new(0.003, 0.015)),
+        (LOCAL_PREFIX, ModelPrice::new(0.0, 0.0)),
+    ]
+    .into_iter()
+    .map(|(model, price)| (model.to_string(), price))
+    .collect()
+}
+
+/// One priced call
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UsageRecord {
+    pub model: String,
+    pub usage: TokenUsage,
+    pub cost_usd: f64,
+    pub timestamp: DateTime<Utc>,
+}
+
+/// Running token and cost totals for a session
+#[derive(Debug, Clone)]
+pub struct CostTracker {
+    prices: HashMap<String, ModelPrice>,
+    records: Vec<UsageRecord>,
+}
+
+impl CostTracker {
+    /// Create a tracker with the default price table
+    pub fn new() -> Self {
+        Self::with_prices(default_prices())
+    }
+
+    /// Create a tracker with a custom price table
+    pub fn with_prices(prices: HashMap<String, ModelPrice>) -> Self {
+        Self {
+            prices,
+            records: Vec::new(),
+        }
+    }
+
+    /// Set or replace the price for a model prefix
+    pub fn set_price(&mut self, model: impl Into<String>, price: ModelPrice) {
+        self.prices.insert(model.into(), price);
+    }
+
+    /// Price for a model, matched by longest prefix
+    pub fn price_for(&self, model: &str) -> Option<ModelPrice> {
+        self.prices
+            .iter()
+            .filter(|(key, _)| model.starts_with(key.as_str()))
+            .max_by_key(|(key, _)| key.len())
+            .map(|(_, price)| *price)
+    }
+
+    /// Record a call and return its estimated cost
+    pub fn record(&mut self, model: &str, usage: TokenUsage) -> f64 {
+        let cost_usd = match self.price_for(model) {
+            Some(price) => price.cost(&usage),
+            None => {
+                tracing::warn!("No price configured for {}, counting as $0", model);
+                0.0
+            }
+        };
+
+        self.records.push(UsageRecord {
+            model: model.to_string(),
+            usage,
+            cost_usd,
+            timestamp: Utc::now(),
+        });
+
+        cost_usd
+    }
+
+    /// Record a local Ollama call (free, tracked for volume)
+    pub fn record_local(&mut self, model: &str, usage: TokenUsage) -> f64 {
+        self.record(&format!("{}{}", LOCAL_PREFIX, model), usage)
+    }
+
+    /// All recorded calls
+    pub fn records(&self) -> &[UsageRecord] {
+        &self.records
+    }
+
+    /// Estimated spend so far
+    pub fn total_cost_usd(&self) -> f64 {
+        self.records.iter().map(|r| r.cost_usd).sum()
+    }
+
+    /// Spend recorded since a point in time
+    pub fn cost_since(&self, since: DateTime<Utc>) -> f64 {
+        self.records
+            .iter()
+            .filter(|r| r.timestamp >= since)
+            .map(|r| r.cost_usd)
+            .sum()
+    }
+
+    /// Spend since local midnight (the daily budget window)
+    pub fn today_cost_usd(&self) -> f64 {
+        self.cost_since(local_midnight())
+    }
+
+    /// Tokens used so far (input + output)
+    pub fn total_tokens(&self) -> u64 {
+        self.records.iter().map(|r| r.usage.total()).sum()
+    }
+
+    /// Usage and cost grouped by model
+    pub fn by_model(&self) -> HashMap<String, (TokenUsage, f64)> {
+        let mut totals: HashMap<String, (TokenUsage, f64)> = HashMap::new();
+        for record in &self.records {
+            let entry = totals.entry(record.model.clone()).or_default();
+            entry.0.input_tokens += record.usage.input_tokens;
+            entry.0.output_tokens += record.usage.output_tokens;
+            entry.1 += record.cost_usd;
+        }
+        totals
+    }
+
+    /// Clear recorded calls, keeping the price table
+    pub fn reset(&mut self) {
+        self.records.clear();
+    }
+}
+
+/// Start of the current day in local time
+fn local_midnight() -> DateTime<Utc> {
+    let now = Local::now();
+    now.date_naive()
+        .and_hms_opt(0, 0, 0)
+        .and_then(|midnight| midnight.and_local_timezone(Local).earliest())
+        .map(|midnight| midnight.with_timezone(&Utc))
+        .unwrap_or_else(|| now.with_timezone(&Utc))
+}
+
+impl Default for CostTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_longest_prefix_price() {
+        let tracker = CostTracker::new();
+        assert_eq!(tracker.price_for("gpt-4o-mini"), Some(ModelPrice::new(0.00015, 0.0006)));
+        assert_eq!(tracker.price_for("gpt-4o-2024-08-06"), Some(ModelPrice::new(0.0025, 0.01)));
+        assert_eq!(tracker.price_for("claude-sonnet-4-20250514"), Some(ModelPrice::new(0.003, 0.015)));
+        assert_eq!(tracker.price_for("mystery-model"), None);
+    }
+
+    #[test]
+    fn test_session_totals() {
+        let mut tracker = CostTracker::new();
+        let cost = tracker.record("gpt-4o", TokenUsage::new(1000, 500));
+        assert!((cost - 0.0075).abs() < 1e-9);
+
+        let local = tracker.record_local("llama3.1:8b", TokenUsage::new(800, 200));
+        assert_eq!(local, 0.0);
+
+        assert!((tracker.total_cost_usd() - 0.0075).abs() < 1e-9);
+        assert_eq!(tracker.total_tokens(), 2500);
+        assert_eq!(tracker.by_model()["ollama/llama3.1:8b"].0.output_tokens, 200);
+
+        tracker.reset();
+        assert_eq!(tracker.total_tokens(), 0);
+    }
+
+    #[test]
+    fn test_today_cost_excludes_yesterday() {
+        let mut tracker = CostTracker::new();
+        tracker.record("gpt-4o", TokenUsage::new(1000, 500));
+        tracker.record("gpt-4o", TokenUsage::new(1000, 500));
+        tracker.records[0].timestamp = local_midnight() - chrono::Duration::minutes(1);
+
+        assert!((tracker.total_cost_usd() - 0.015).abs() < 1e-9);
+        assert!((tracker.today_cost_usd() - 0.0075).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_custom_price() {
+        let mut tracker = CostTracker::new();
+        tracker.set_price("gpt-4o", ModelPrice::new(0.01, 0.01));
+        let cost = tracker.record("gpt-4o", TokenUsage::new(500, 500));
+        assert!((cost - 0.01).abs() < 1e-9);
+    }
+}
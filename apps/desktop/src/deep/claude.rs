@@ -4,18 +4,24 @@
 //! Excellent at structured output and following complex instructions.
 
 use anyhow::Result;
+use async_trait::async_trait;
 use futures::StreamExt;
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
 use tokio::sync::mpsc;
 
-use super::streaming::{StreamChunk, StreamingResponse, build_deep_prompt};
+use super::provider::DeepProvider;
+use super::streaming::{StreamChunk, StreamingResponse, build_deep_prompt, deep_max_tokens, DEFAULT_DEEP_MAX_WORDS};
+use crate::cost::TokenUsage;
+use crate::error::DeepError;
 
 /// Claude 3.5 Sonnet client
+#[derive(Clone)]
 pub struct ClaudeSonnet {
     api_key: String,
     client: Client,
     model: String,
+    max_words: usize,
 }
 
 #[derive(Debug, Serialize)]
@@ -37,6 +43,23 @@ struct StreamEvent {
     #[serde(rename = "type")]
     event_type: String,
     delta: Option<Delta>,
+    /// Present on `message_start`
+    message: Option<StreamMessage>,
+    /// Present on `message_delta`
+    usage: Option<StreamUsage>,
+}
+
+#[derive(Debug, Deserialize)]
+struct StreamMessage {
+    usage: Option<StreamUsage>,
+}
+
+#[derive(Debug, Deserialize)]
+struct StreamUsage {
+    #[serde(default)]
+    input_tokens: u64,
+    #[serde(default)]
+    output_tokens: u64,
 }
 
 #[derive(Debug, Deserialize)]
@@ -53,6 +76,7 @@ impl ClaudeSonnet {
             api_key: api_key.into(),
             client: Client::new(),
             model: "claude-sonnet-4-20250514".to_string(), // Claude 3.5 Sonnet
+            max_words: DEFAULT_DEEP_MAX_WORDS,
         }
     }
 
@@ -62,6 +86,12 @@ impl ClaudeSonnet {
         self
     }
 
+    /// Aim for responses of about this many words
+    pub fn with_max_words(mut self, max_words: usize) -> Self {
+        self.max_words = max_words;
+        self
+    }
+
     /// Generate a detailed response with streaming
     pub async fn analyze_streaming(
         &self,
@@ -70,11 +100,11 @@ impl ClaudeSonnet {
         flash_bullets: &[String],
         conversation_history: &str,
     ) -> Result<StreamingResponse> {
-        let prompt = build_deep_prompt(transcript, context, flash_bullets, conversation_history);
+        let prompt = build_deep_prompt(transcript, context, flash_bullets, conversation_history, self.max_words);
 
         let request = ClaudeRequest {
             model: self.model.clone(),
-            max_tokens: 1024,
+            max_tokens: deep_max_tokens(self.max_words),
             messages: vec![ClaudeMessage {
                 role: "user".to_string(),
                 content: prompt,
@@ -86,6 +116,7 @@ impl ClaudeSonnet {
 
         let client = self.client.clone();
         let api_key = self.api_key.clone();
+        let model = self.model.clone();
 
         tokio::spawn(async move {
             let result = client
@@ -98,9 +129,18 @@ impl ClaudeSonnet {
                 .await;
 
             match result {
+                Ok(response) if !response.status().is_success() => {
+                    // e.g. 529 when overloaded; lets the router fall back
+                    let status = response.status().as_u16();
+                    let body = response.text().await.unwrap_or_default();
+                    let _ = tx
+                        .send(StreamChunk::Error(DeepError::from_status(status, &body)))
+                        .await;
+                }
                 Ok(response) => {
                     let mut stream = response.bytes_stream();
                     let mut buffer = String::new();
+                    let mut usage = TokenUsage::default();
 
                     while let Some(chunk) = stream.next().await {
                         match chunk {
@@ -112,9 +152,21 @@ impl ClaudeSonnet {
                                     let event_str = buffer[..event_end].to_string();
                                     buffer = buffer[event_end + 2..].to_string();
 
-                                    // Parse the event
-                                    if let Some(data) = event_str.strip_prefix("data: ") {
+                                    // Parse the event (data follows the `event:` line)
+                                    if let Some(data) = event_str.lines().find_map(|l| l.strip_prefix("data: ")) {
                                         if let Ok(event) = serde_json::from_str::<StreamEvent>(data) {
+                                            if event.event_type == "error" {
+                                                let _ = tx.send(StreamChunk::Error(stream_error(data))).await;
+                                                return;
+                                            }
+
+                                            if let Some(u) = event.message.and_then(|m| m.usage) {
+                                                usage.input_tokens = u.input_tokens;
+                                            }
+                                            if let Some(u) = event.usage {
+                                                usage.output_tokens = u.output_tokens;
+                                            }
+
                                             if let Some(delta) = event.delta {
                                                 if let Some(text) = delta.text {
                                                     if tx.send(StreamChunk::Content(text)).await.is_err() {
@@ -124,6 +176,7 @@ impl ClaudeSonnet {
                                             }
 
                                             if event.event_type == "message_stop" {
+                                                let _ = tx.send(StreamChunk::Usage { model, usage }).await;
                                                 let _ = tx.send(StreamChunk::Done).await;
                                                 return;
                                             }
@@ -132,7 +185,7 @@ impl ClaudeSonnet {
                                 }
                             }
                             Err(e) => {
-                                let _ = tx.send(StreamChunk::Error(e.to_string())).await;
+                                let _ = tx.send(StreamChunk::Error(e.into())).await;
                                 return;
                             }
                         }
@@ -141,7 +194,7 @@ impl ClaudeSonnet {
                     let _ = tx.send(StreamChunk::Done).await;
                 }
                 Err(e) => {
-                    let _ = tx.send(StreamChunk::Error(e.to_string())).await;
+                    let _ = tx.send(StreamChunk::Error(e.into())).await;
                 }
             }
         });
@@ -151,11 +204,11 @@ impl ClaudeSonnet {
 
     /// Generate a response without streaming (for simpler use cases)
     pub async fn analyze(&self, transcript: &str, context: &str) -> Result<String> {
-        let prompt = build_deep_prompt(transcript, context, &[], "");
+        let prompt = build_deep_prompt(transcript, context, &[], "", self.max_words);
 
         let request = ClaudeRequest {
             model: self.model.clone(),
-            max_tokens: 1024,
+            max_tokens: deep_max_tokens(self.max_words),
             messages: vec![ClaudeMessage {
                 role: "user".to_string(),
                 content: prompt,
@@ -188,3 +241,34 @@ impl ClaudeSonnet {
         Ok(result.content.first().map(|c| c.text.clone()).unwrap_or_default())
     }
 }
+
+/// Classify an `error` event sent mid-stream
+fn stream_error(data: &str) -> DeepError {
+    let message = format!("Claude stream error: {}", data);
+    if data.contains("overloaded_error") || data.contains("api_error") {
+        DeepError::Network(message)
+    } else if data.contains("rate_limit_error") {
+        DeepError::RateLimited(message)
+    } else if data.contains("authentication_error") {
+        DeepError::Auth(message)
+    } else {
+        DeepError::Other(message)
+    }
+}
+
+#[async_trait]
+impl DeepProvider for ClaudeSonnet {
+    async fn analyze_streaming(
+        &self,
+        transcript: &str,
+        context: &str,
+        flash_bullets: &[String],
+        conversation_history: &str,
+    ) -> Result<StreamingResponse> {
+        ClaudeSonnet::analyze_streaming(self, transcript, context, flash_bullets, conversation_history).await
+    }
+
+    fn name(&self) -> &str {
+        &self.model
+    }
+}
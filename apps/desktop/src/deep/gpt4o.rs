@@ -4,32 +4,40 @@
 //! Fast and reliable with excellent instruction following.
 
 use anyhow::Result;
-use async_openai::{
-    config::OpenAIConfig,
-    types::{
-        ChatCompletionRequestMessage, ChatCompletionRequestUserMessageArgs,
-        CreateChatCompletionRequestArgs,
-    },
-    Client,
+use async_openai::types::{
+    ChatCompletionRequestMessage, ChatCompletionRequestUserMessageArgs,
+    ChatCompletionStreamOptions, CreateChatCompletionRequestArgs,
 };
-use futures::StreamExt;
-use tokio::sync::mpsc;
+use async_trait::async_trait;
 
-use super::streaming::{StreamChunk, StreamingResponse, build_deep_prompt};
+use super::provider::DeepProvider;
+use super::streaming::{StreamingResponse, build_deep_prompt, deep_max_tokens, stream_chat_completion, DEFAULT_DEEP_MAX_WORDS};
+use crate::flash::{AzureOpenAIConfig, OpenAIClient};
 
 /// GPT-4o client
+#[derive(Clone)]
 pub struct GPT4o {
-    client: Client<OpenAIConfig>,
+    client: OpenAIClient,
     model: String,
+    max_words: usize,
 }
 
 impl GPT4o {
     /// Create a new GPT-4o client
     pub fn new(api_key: impl Into<String>) -> Self {
-        let config = OpenAIConfig::new().with_api_key(api_key);
         Self {
-            client: Client::with_config(config),
+            client: OpenAIClient::new(api_key),
             model: "gpt-4o".to_string(),
+            max_words: DEFAULT_DEEP_MAX_WORDS,
+        }
+    }
+
+    /// Create a client for an Azure OpenAI deployment
+    pub fn azure(api_key: impl Into<String>, azure: &AzureOpenAIConfig) -> Self {
+        Self {
+            client: OpenAIClient::azure(api_key, azure),
+            model: "gpt-4o".to_string(),
+            max_words: DEFAULT_DEEP_MAX_WORDS,
         }
     }
 
@@ -39,6 +47,12 @@ impl GPT4o {
         self
     }
 
+    /// Aim for responses of about this many words
+    pub fn with_max_words(mut self, max_words: usize) -> Self {
+        self.max_words = max_words;
+        self
+    }
+
     /// Generate a detailed response with streaming
     pub async fn analyze_streaming(
         &self,
@@ -47,7 +61,7 @@ impl GPT4o {
         flash_bullets: &[String],
         conversation_history: &str,
     ) -> Result<StreamingResponse> {
-        let prompt = build_deep_prompt(transcript, context, flash_bullets, conversation_history);
+        let prompt = build_deep_prompt(transcript, context, flash_bullets, conversation_history, self.max_words);
 
         let request = CreateChatCompletionRequestArgs::default()
             .model(&self.model)
@@ -56,49 +70,19 @@ impl GPT4o {
                     .content(prompt)
                     .build()?,
             )])
-            .max_tokens(1024u32)
+            .max_tokens(deep_max_tokens(self.max_words))
             .temperature(0.7)
             .stream(true)
+            // Final chunk carries token counts
+            .stream_options(ChatCompletionStreamOptions { include_usage: true })
             .build()?;
 
-        let (tx, rx) = mpsc::channel(100);
-
-        let client = self.client.clone();
-
-        tokio::spawn(async move {
-            match client.chat().create_stream(request).await {
-                Ok(mut stream) => {
-                    while let Some(result) = stream.next().await {
-                        match result {
-                            Ok(response) => {
-                                for choice in response.choices {
-                                    if let Some(content) = choice.delta.content {
-                                        if tx.send(StreamChunk::Content(content)).await.is_err() {
-                                            return;
-                                        }
-                                    }
-                                }
-                            }
-                            Err(e) => {
-                                let _ = tx.send(StreamChunk::Error(e.to_string())).await;
-                                return;
-                            }
-                        }
-                    }
-                    let _ = tx.send(StreamChunk::Done).await;
-                }
-                Err(e) => {
-                    let _ = tx.send(StreamChunk::Error(e.to_string())).await;
-                }
-            }
-        });
-
-        Ok(StreamingResponse::new(rx))
+        Ok(stream_chat_completion(self.client.clone(), request, self.model.clone()))
     }
 
     /// Generate a response without streaming
     pub async fn analyze(&self, transcript: &str, context: &str) -> Result<String> {
-        let prompt = build_deep_prompt(transcript, context, &[], "");
+        let prompt = build_deep_prompt(transcript, context, &[], "", self.max_words);
 
         let request = CreateChatCompletionRequestArgs::default()
             .model(&self.model)
@@ -107,11 +91,11 @@ impl GPT4o {
                     .content(prompt)
                     .build()?,
             )])
-            .max_tokens(1024u32)
+            .max_tokens(deep_max_tokens(self.max_words))
             .temperature(0.7)
             .build()?;
 
-        let response = self.client.chat().create(request).await?;
+        let response = self.client.create(request).await?;
 
         Ok(response
             .choices
@@ -120,3 +104,20 @@ impl GPT4o {
             .unwrap_or_default())
     }
 }
+
+#[async_trait]
+impl DeepProvider for GPT4o {
+    async fn analyze_streaming(
+        &self,
+        transcript: &str,
+        context: &str,
+        flash_bullets: &[String],
+        conversation_history: &str,
+    ) -> Result<StreamingResponse> {
+        GPT4o::analyze_streaming(self, transcript, context, flash_bullets, conversation_history).await
+    }
+
+    fn name(&self) -> &str {
+        &self.model
+    }
+}
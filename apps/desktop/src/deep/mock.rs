@@ -0,0 +1,54 @@
+//! Mock Deep Provider
+//!
+//! Streams a canned response so pipeline tests run without network access.
+
+use anyhow::Result;
+use async_trait::async_trait;
+use tokio::sync::mpsc;
+
+use super::provider::DeepProvider;
+use super::streaming::{StreamChunk, StreamingResponse};
+
+/// Streams fixed content chunks, then an optional question, then `Done`
+#[derive(Debug, Clone)]
+pub struct MockDeepProvider {
+    pub chunks: Vec<String>,
+    pub question: Option<String>,
+}
+
+impl Default for MockDeepProvider {
+    fn default() -> Self {
+        Self {
+            chunks: vec!["Pricing starts at ".to_string(), "$50 per seat.".to_string()],
+            question: Some("How many seats are you planning for?".to_string()),
+        }
+    }
+}
+
+#[async_trait]
+impl DeepProvider for MockDeepProvider {
+    async fn analyze_streaming(
+        &self,
+        _transcript: &str,
+        _context: &str,
+        _flash_bullets: &[String],
+        _conversation_history: &str,
+    ) -> Result<StreamingResponse> {
+        let (tx, rx) = mpsc::channel(self.chunks.len() + 2);
+        let chunks = self
+            .chunks
+            .iter()
+            .cloned()
+            .map(StreamChunk::Content)
+            .chain(self.question.clone().map(StreamChunk::Question))
+            .chain(std::iter::once(StreamChunk::Done));
+        for chunk in chunks {
+            tx.send(chunk).await?;
+        }
+        Ok(StreamingResponse::new(rx))
+    }
+
+    fn name(&self) -> &str {
+        "mock-deep"
+    }
+}
@@ -1,16 +1,26 @@
 //! Deep Module - Stage 3 (Detailed Response)
 //!
-//! Intelligent AI responses using Claude 3.5 Sonnet, GPT-4o, or o1.
+//! Intelligent AI responses using Claude 3.5 Sonnet, GPT-4o, o1, or any OpenRouter model.
 //! Provides comprehensive, structured answers that stream in while you talk.
 
 mod claude;
 mod gpt4o;
 mod o1;
+mod openrouter;
 mod router;
 mod streaming;
+mod provider;
+#[cfg(test)]
+mod mock;
+
+pub use crate::error::DeepError;
 
 pub use claude::ClaudeSonnet;
 pub use gpt4o::GPT4o;
 pub use o1::O1Preview;
+pub use openrouter::{OpenRouterClient, DEFAULT_OPENROUTER_DEEP_MODEL};
 pub use router::{ModelRouter, ModelChoice};
-pub use streaming::{DeepAnalysis, StreamingResponse};
+pub use streaming::{DeepAnalysis, StreamChunk, StreamingResponse, DEFAULT_DEEP_MAX_WORDS};
+pub use provider::DeepProvider;
+#[cfg(test)]
+pub use mock::MockDeepProvider;
@@ -4,29 +4,34 @@
 //! Slower (5-10s) but much better at complex reasoning.
 
 use anyhow::Result;
-use async_openai::{
-    config::OpenAIConfig,
-    types::{
-        ChatCompletionRequestMessage, ChatCompletionRequestUserMessageArgs,
-        CreateChatCompletionRequestArgs,
-    },
-    Client,
+use async_openai::types::{
+    ChatCompletionRequestMessage, ChatCompletionRequestUserMessageArgs,
+    CreateChatCompletionRequestArgs,
 };
 
 use super::streaming::build_deep_prompt;
+use crate::cost::TokenUsage;
+use crate::flash::{AzureOpenAIConfig, OpenAIClient};
 
 /// o1-preview client
 pub struct O1Preview {
-    client: Client<OpenAIConfig>,
+    client: OpenAIClient,
     model: String,
 }
 
 impl O1Preview {
     /// Create a new o1-preview client
     pub fn new(api_key: impl Into<String>) -> Self {
-        let config = OpenAIConfig::new().with_api_key(api_key);
         Self {
-            client: Client::with_config(config),
+            client: OpenAIClient::new(api_key),
+            model: "o1-preview".to_string(),
+        }
+    }
+
+    /// Create a client for an Azure OpenAI deployment
+    pub fn azure(api_key: impl Into<String>, azure: &AzureOpenAIConfig) -> Self {
+        Self {
+            client: OpenAIClient::azure(api_key, azure),
             model: "o1-preview".to_string(),
         }
     }
@@ -37,10 +42,20 @@ impl O1Preview {
         self
     }
 
+    /// Model identifier sent to the API
+    pub fn model_name(&self) -> &str {
+        &self.model
+    }
+
     /// Generate a response for complex questions
     ///
     /// Note: o1 doesn't support streaming, so this is always a blocking call
     pub async fn analyze(&self, transcript: &str, context: &str) -> Result<String> {
+        Ok(self.analyze_with_usage(transcript, context).await?.0)
+    }
+
+    /// Same as `analyze`, also returning the reported token counts
+    pub async fn analyze_with_usage(&self, transcript: &str, context: &str) -> Result<(String, Option<TokenUsage>)> {
         // o1 works best with detailed prompts
         let prompt = format!(
             r#"You are helping someone respond in a live conversation. Think deeply about the best response.
@@ -68,13 +83,20 @@ Be concise but thorough. The user needs to be able to speak this response natura
             )])
             .build()?;
 
-        let response = self.client.chat().create(request).await?;
+        let response = self.client.create(request).await?;
 
-        Ok(response
+        let usage = response
+            .usage
+            .as_ref()
+            .map(|u| TokenUsage::new(u.prompt_tokens as u64, u.completion_tokens as u64));
+
+        let content = response
             .choices
             .first()
             .and_then(|c| c.message.content.clone())
-            .unwrap_or_default())
+            .unwrap_or_default();
+
+        Ok((content, usage))
     }
 }
 
@@ -0,0 +1,117 @@
+//! OpenRouter Integration
+//!
+//! Routes deep responses to any OpenRouter model (e.g.
+//! `anthropic/claude-3.5-sonnet`) with a single API key.
+
+use anyhow::Result;
+use async_openai::types::{
+    ChatCompletionRequestMessage, ChatCompletionRequestUserMessageArgs,
+    ChatCompletionStreamOptions, CreateChatCompletionRequestArgs,
+};
+use async_trait::async_trait;
+
+use super::provider::DeepProvider;
+use super::streaming::{StreamingResponse, build_deep_prompt, deep_max_tokens, stream_chat_completion, DEFAULT_DEEP_MAX_WORDS};
+use crate::flash::OpenAIClient;
+
+/// Default deep model when routing through OpenRouter
+pub const DEFAULT_OPENROUTER_DEEP_MODEL: &str = "anthropic/claude-3.5-sonnet";
+
+/// OpenRouter deep client
+#[derive(Clone)]
+pub struct OpenRouterClient {
+    client: OpenAIClient,
+    model: String,
+    max_words: usize,
+}
+
+impl OpenRouterClient {
+    /// Create a new OpenRouter client using the default deep model
+    pub fn new(api_key: impl Into<String>) -> Self {
+        Self {
+            client: OpenAIClient::openrouter(api_key),
+            model: DEFAULT_OPENROUTER_DEEP_MODEL.to_string(),
+            max_words: DEFAULT_DEEP_MAX_WORDS,
+        }
+    }
+
+    /// Use a specific model, e.g. `openai/gpt-4o`
+    pub fn with_model(mut self, model: impl Into<String>) -> Self {
+        self.model = model.into();
+        self
+    }
+
+    /// Aim for responses of about this many words
+    pub fn with_max_words(mut self, max_words: usize) -> Self {
+        self.max_words = max_words;
+        self
+    }
+
+    /// Generate a detailed response with streaming
+    pub async fn analyze_streaming(
+        &self,
+        transcript: &str,
+        context: &str,
+        flash_bullets: &[String],
+        conversation_history: &str,
+    ) -> Result<StreamingResponse> {
+        let prompt = build_deep_prompt(transcript, context, flash_bullets, conversation_history, self.max_words);
+
+        let request = CreateChatCompletionRequestArgs::default()
+            .model(&self.model)
+            .messages(vec![ChatCompletionRequestMessage::User(
+                ChatCompletionRequestUserMessageArgs::default()
+                    .content(prompt)
+                    .build()?,
+            )])
+            .max_tokens(deep_max_tokens(self.max_words))
+            .temperature(0.7)
+            .stream(true)
+            .stream_options(ChatCompletionStreamOptions { include_usage: true })
+            .build()?;
+
+        Ok(stream_chat_completion(self.client.clone(), request, self.model.clone()))
+    }
+}
+
+#[async_trait]
+impl DeepProvider for OpenRouterClient {
+    async fn analyze_streaming(
+        &self,
+        transcript: &str,
+        context: &str,
+        flash_bullets: &[String],
+        conversation_history: &str,
+    ) -> Result<StreamingResponse> {
+        OpenRouterClient::analyze_streaming(self, transcript, context, flash_bullets, conversation_history).await
+    }
+
+    fn name(&self) -> &str {
+        &self.model
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    #[ignore] // Requires API key
+    async fn test_openrouter_streaming() {
+        let client = OpenRouterClient::new("YOUR_API_KEY");
+        let analysis = client
+            .analyze_streaming(
+                "How much does your enterprise plan cost?",
+                "Sales call for SaaS product",
+                &[],
+                "",
+            )
+            .await
+            .unwrap()
+            .collect()
+            .await;
+
+        assert!(!analysis.is_streaming);
+        assert!(!analysis.content.is_empty());
+    }
+}
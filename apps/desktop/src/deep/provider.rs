@@ -0,0 +1,25 @@
+//! Deep Provider Trait
+//!
+//! Common interface over the deep clients, so the pipeline can be handed
+//! a specific backend (or a test double) instead of routing by model.
+
+use anyhow::Result;
+use async_trait::async_trait;
+
+use super::streaming::StreamingResponse;
+
+/// A backend that streams a detailed response
+#[async_trait]
+pub trait DeepProvider: Send + Sync {
+    /// Stream a detailed response to what they said
+    async fn analyze_streaming(
+        &self,
+        transcript: &str,
+        context: &str,
+        flash_bullets: &[String],
+        conversation_history: &str,
+    ) -> Result<StreamingResponse>;
+
+    /// Model name, e.g. "gpt-4o"
+    fn name(&self) -> &str;
+}
@@ -4,11 +4,17 @@
 //! - Question complexity
 //! - Context mode
 //! - Latency requirements
+//!
+//! If the chosen model fails before producing any content, the next
+//! configured model in the fallback order takes over.
 
-use super::{ClaudeSonnet, GPT4o, O1Preview};
-use super::streaming::StreamingResponse;
-use crate::flash::StatementType;
+use super::{ClaudeSonnet, GPT4o, O1Preview, OpenRouterClient};
+use super::streaming::{StreamChunk, StreamingResponse, DEFAULT_DEEP_MAX_WORDS};
+use super::DeepError;
+use crate::flash::{AzureOpenAIConfig, StatementType};
 use anyhow::Result;
+use std::sync::Arc;
+use tokio::sync::mpsc;
 
 /// Available deep models
 #[derive(Debug, Clone, PartialEq)]
@@ -19,6 +25,8 @@ pub enum ModelChoice {
     GPT4o,
     /// o1-preview - For complex reasoning (slower)
     O1Preview,
+    /// Any OpenRouter model, e.g. "anthropic/claude-3.5-sonnet"
+    OpenRouter(String),
 }
 
 impl ModelChoice {
@@ -27,6 +35,7 @@ impl ModelChoice {
             Self::ClaudeSonnet => "Claude 3.5 Sonnet",
             Self::GPT4o => "GPT-4o",
             Self::O1Preview => "o1-preview",
+            Self::OpenRouter(_) => "OpenRouter",
         }
     }
 
@@ -35,16 +44,31 @@ impl ModelChoice {
             Self::ClaudeSonnet => "1-2s",
             Self::GPT4o => "1-2s",
             Self::O1Preview => "5-10s",
+            Self::OpenRouter(_) => "1-3s",
+        }
+    }
+
+    /// Who to blame in error messages, e.g. "Check your Anthropic key"
+    pub fn provider(&self) -> &'static str {
+        match self {
+            Self::ClaudeSonnet => "Anthropic",
+            Self::GPT4o | Self::O1Preview => "OpenAI",
+            Self::OpenRouter(_) => "OpenRouter",
         }
     }
 }
 
 /// Router for selecting and using deep models
+#[derive(Clone)]
 pub struct ModelRouter {
-    claude: Option<ClaudeSonnet>,
-    gpt4o: Option<GPT4o>,
-    o1: Option<O1Preview>,
+    claude: Option<Arc<ClaudeSonnet>>,
+    gpt4o: Option<Arc<GPT4o>>,
+    o1: Option<Arc<O1Preview>>,
+    openrouter: Option<Arc<OpenRouterClient>>,
     default_model: ModelChoice,
+    fallback_order: Vec<ModelChoice>,
+    /// Word target passed to each model
+    max_words: usize,
 }
 
 impl ModelRouter {
@@ -54,25 +78,46 @@ impl ModelRouter {
             claude: None,
             gpt4o: None,
             o1: None,
+            openrouter: None,
             default_model: ModelChoice::ClaudeSonnet,
+            fallback_order: vec![
+                ModelChoice::ClaudeSonnet,
+                ModelChoice::GPT4o,
+                ModelChoice::O1Preview,
+            ],
+            max_words: DEFAULT_DEEP_MAX_WORDS,
         }
     }
 
     /// Configure Claude
     pub fn with_claude(mut self, api_key: impl Into<String>) -> Self {
-        self.claude = Some(ClaudeSonnet::new(api_key));
+        self.claude = Some(Arc::new(ClaudeSonnet::new(api_key)));
         self
     }
 
     /// Configure GPT-4o
     pub fn with_gpt4o(mut self, api_key: impl Into<String>) -> Self {
-        self.gpt4o = Some(GPT4o::new(api_key));
+        self.gpt4o = Some(Arc::new(GPT4o::new(api_key)));
         self
     }
 
     /// Configure o1
     pub fn with_o1(mut self, api_key: impl Into<String>) -> Self {
-        self.o1 = Some(O1Preview::new(api_key));
+        self.o1 = Some(Arc::new(O1Preview::new(api_key)));
+        self
+    }
+
+    /// Configure GPT-4o and o1 against an Azure OpenAI deployment
+    pub fn with_azure(mut self, api_key: impl Into<String>, azure: &AzureOpenAIConfig) -> Self {
+        let api_key = api_key.into();
+        self.gpt4o = Some(Arc::new(GPT4o::azure(api_key.clone(), azure)));
+        self.o1 = Some(Arc::new(O1Preview::azure(api_key, azure)));
+        self
+    }
+
+    /// Configure OpenRouter (used by `ModelChoice::OpenRouter`)
+    pub fn with_openrouter(mut self, api_key: impl Into<String>) -> Self {
+        self.openrouter = Some(Arc::new(OpenRouterClient::new(api_key)));
         self
     }
 
@@ -82,6 +127,39 @@ impl ModelRouter {
         self
     }
 
+    /// Set the order models are tried in when one fails (empty disables fallback)
+    pub fn with_fallback_order(mut self, order: Vec<ModelChoice>) -> Self {
+        self.fallback_order = order;
+        self
+    }
+
+    /// Aim for responses of about this many words
+    pub fn with_max_words(mut self, max_words: usize) -> Self {
+        self.max_words = max_words;
+        self
+    }
+
+    /// Check whether a model has been configured
+    pub fn is_configured(&self, model: &ModelChoice) -> bool {
+        match model {
+            ModelChoice::ClaudeSonnet => self.claude.is_some(),
+            ModelChoice::GPT4o => self.gpt4o.is_some(),
+            ModelChoice::O1Preview => self.o1.is_some(),
+            ModelChoice::OpenRouter(_) => self.openrouter.is_some(),
+        }
+    }
+
+    /// Models to try for a request, starting with the chosen one
+    pub fn fallback_chain(&self, model_choice: &ModelChoice) -> Vec<ModelChoice> {
+        let mut chain = vec![model_choice.clone()];
+        for model in &self.fallback_order {
+            if !chain.contains(model) && self.is_configured(model) {
+                chain.push(model.clone());
+            }
+        }
+        chain
+    }
+
     /// Automatically select the best model for the given input
     pub fn select_model(
         &self,
@@ -105,12 +183,15 @@ impl ModelRouter {
         match self.default_model {
             ModelChoice::ClaudeSonnet if self.claude.is_some() => ModelChoice::ClaudeSonnet,
             ModelChoice::GPT4o if self.gpt4o.is_some() => ModelChoice::GPT4o,
+            ModelChoice::OpenRouter(_) if self.openrouter.is_some() => self.default_model.clone(),
             _ => {
                 // Fallback to whatever is available
                 if self.claude.is_some() {
                     ModelChoice::ClaudeSonnet
                 } else if self.gpt4o.is_some() {
                     ModelChoice::GPT4o
+                } else if self.openrouter.is_some() {
+                    ModelChoice::OpenRouter(super::DEFAULT_OPENROUTER_DEEP_MODEL.to_string())
                 } else {
                     ModelChoice::O1Preview
                 }
@@ -119,6 +200,10 @@ impl ModelRouter {
     }
 
     /// Generate a streaming response using the selected model
+    ///
+    /// Falls back along the fallback order if the model errors before any
+    /// content is streamed. Once content has been sent, errors are passed
+    /// through so a second answer is never appended to a partial one.
     pub async fn analyze_streaming(
         &self,
         transcript: &str,
@@ -126,18 +211,103 @@ impl ModelRouter {
         flash_bullets: &[String],
         conversation_history: &str,
         model_choice: ModelChoice,
+    ) -> Result<StreamingResponse> {
+        let chain = self.fallback_chain(&model_choice);
+        if chain.len() == 1 {
+            return self
+                .stream_from(&model_choice, transcript, context, flash_bullets, conversation_history)
+                .await;
+        }
+
+        let (tx, rx) = mpsc::channel(100);
+
+        let router = self.clone();
+        let transcript = transcript.to_string();
+        let context = context.to_string();
+        let flash_bullets = flash_bullets.to_vec();
+        let conversation_history = conversation_history.to_string();
+
+        tokio::spawn(async move {
+            let mut streamed = false;
+
+            for (i, model) in chain.iter().enumerate() {
+                let failure = match router
+                    .stream_from(model, &transcript, &context, &flash_bullets, &conversation_history)
+                    .await
+                {
+                    Ok(mut stream) => {
+                        let mut failure = None;
+                        while let Some(chunk) = stream.receiver.recv().await {
+                            match chunk {
+                                StreamChunk::Error(e) => {
+                                    failure = Some(e);
+                                    break;
+                                }
+                                StreamChunk::Done => {
+                                    let _ = tx.send(StreamChunk::Done).await;
+                                    return;
+                                }
+                                chunk => {
+                                    streamed |= matches!(chunk, StreamChunk::Content(_));
+                                    if tx.send(chunk).await.is_err() {
+                                        return;
+                                    }
+                                }
+                            }
+                        }
+
+                        match failure {
+                            Some(e) => e,
+                            None => {
+                                // Stream closed without an explicit Done
+                                let _ = tx.send(StreamChunk::Done).await;
+                                return;
+                            }
+                        }
+                    }
+                    Err(e) => DeepError::classify(&e),
+                };
+
+                let next = match chain.get(i + 1) {
+                    Some(next) if !streamed => next,
+                    _ => {
+                        let _ = tx.send(StreamChunk::Error(failure)).await;
+                        return;
+                    }
+                };
+
+                tracing::warn!("{} failed ({}), falling back to {}", model.label(), failure, next.label());
+                let _ = tx
+                    .send(StreamChunk::Info(format!("falling back to {}", next.label())))
+                    .await;
+            }
+        });
+
+        Ok(StreamingResponse::new(rx))
+    }
+
+    /// Stream a response from one specific model
+    async fn stream_from(
+        &self,
+        model_choice: &ModelChoice,
+        transcript: &str,
+        context: &str,
+        flash_bullets: &[String],
+        conversation_history: &str,
     ) -> Result<StreamingResponse> {
         match model_choice {
             ModelChoice::ClaudeSonnet => {
                 let claude = self.claude.as_ref().ok_or_else(|| {
                     anyhow::anyhow!("Claude not configured")
                 })?;
+                let claude = ClaudeSonnet::clone(claude).with_max_words(self.max_words);
                 claude.analyze_streaming(transcript, context, flash_bullets, conversation_history).await
             }
             ModelChoice::GPT4o => {
                 let gpt4o = self.gpt4o.as_ref().ok_or_else(|| {
                     anyhow::anyhow!("GPT-4o not configured")
                 })?;
+                let gpt4o = GPT4o::clone(gpt4o).with_max_words(self.max_words);
                 gpt4o.analyze_streaming(transcript, context, flash_bullets, conversation_history).await
             }
             ModelChoice::O1Preview => {
@@ -145,16 +315,29 @@ impl ModelRouter {
                 let o1 = self.o1.as_ref().ok_or_else(|| {
                     anyhow::anyhow!("o1 not configured")
                 })?;
-                let response = o1.analyze(transcript, context).await?;
+                let (response, usage) = o1.analyze_with_usage(transcript, context).await?;
+                let model = o1.model_name().to_string();
 
                 let (tx, rx) = tokio::sync::mpsc::channel(10);
                 tokio::spawn(async move {
                     let _ = tx.send(super::streaming::StreamChunk::Content(response)).await;
+                    if let Some(usage) = usage {
+                        let _ = tx.send(super::streaming::StreamChunk::Usage { model, usage }).await;
+                    }
                     let _ = tx.send(super::streaming::StreamChunk::Done).await;
                 });
 
                 Ok(StreamingResponse::new(rx))
             }
+            ModelChoice::OpenRouter(model) => {
+                let openrouter = self.openrouter.as_ref().ok_or_else(|| {
+                    anyhow::anyhow!("OpenRouter not configured")
+                })?;
+                let client = OpenRouterClient::clone(openrouter)
+                    .with_model(model.clone())
+                    .with_max_words(self.max_words);
+                client.analyze_streaming(transcript, context, flash_bullets, conversation_history).await
+            }
         }
     }
 }
@@ -164,3 +347,55 @@ impl Default for ModelRouter {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fallback_chain_skips_unconfigured() {
+        let router = ModelRouter::new().with_claude("key").with_o1("key");
+
+        assert_eq!(
+            router.fallback_chain(&ModelChoice::ClaudeSonnet),
+            vec![ModelChoice::ClaudeSonnet, ModelChoice::O1Preview]
+        );
+        assert_eq!(
+            router.fallback_chain(&ModelChoice::O1Preview),
+            vec![ModelChoice::O1Preview, ModelChoice::ClaudeSonnet]
+        );
+    }
+
+    #[test]
+    fn test_custom_fallback_order() {
+        let router = ModelRouter::new()
+            .with_claude("key")
+            .with_gpt4o("key")
+            .with_o1("key")
+            .with_fallback_order(vec![ModelChoice::O1Preview, ModelChoice::GPT4o]);
+
+        assert_eq!(
+            router.fallback_chain(&ModelChoice::ClaudeSonnet),
+            vec![ModelChoice::ClaudeSonnet, ModelChoice::O1Preview, ModelChoice::GPT4o]
+        );
+
+        let no_fallback = router.with_fallback_order(Vec::new());
+        assert_eq!(
+            no_fallback.fallback_chain(&ModelChoice::GPT4o),
+            vec![ModelChoice::GPT4o]
+        );
+    }
+
+    #[test]
+    fn test_openrouter_selection() {
+        let router = ModelRouter::new().with_openrouter("key");
+        let sonnet = ModelChoice::OpenRouter("anthropic/claude-3.5-sonnet".to_string());
+
+        assert!(router.is_configured(&sonnet));
+        assert_eq!(router.select_model("What's the price?", &StatementType::Question, None), sonnet);
+
+        let gpt4o = ModelChoice::OpenRouter("openai/gpt-4o".to_string());
+        let router = router.with_default(gpt4o.clone());
+        assert_eq!(router.select_model("What's the price?", &StatementType::Question, None), gpt4o);
+    }
+}
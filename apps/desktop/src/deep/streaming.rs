@@ -2,9 +2,15 @@
 //!
 //! Common types for the Deep response stage.
 
+use async_openai::types::CreateChatCompletionRequest;
+use futures::StreamExt;
 use serde::{Deserialize, Serialize};
 use tokio::sync::mpsc;
 
+use crate::cost::TokenUsage;
+use crate::error::DeepError;
+use crate::flash::OpenAIClient;
+
 /// Deep analysis result - streams in over time
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct DeepAnalysis {
@@ -35,10 +41,14 @@ pub enum StreamChunk {
     Pushback(String),
     /// A key point
     KeyPoint(String),
+    /// Status note for the user (e.g. a model fallback)
+    Info(String),
+    /// Tokens used by the model that produced this stream
+    Usage { model: String, usage: TokenUsage },
     /// Stream completed
     Done,
     /// Error occurred
-    Error(String),
+    Error(DeepError),
 }
 
 /// Handle for receiving streaming responses
@@ -73,6 +83,7 @@ impl StreamingResponse {
                 StreamChunk::KeyPoint(kp) => {
                     analysis.key_points.push(kp);
                 }
+                StreamChunk::Info(_) | StreamChunk::Usage { .. } => {}
                 StreamChunk::Done => {
                     analysis.is_streaming = false;
                     break;
@@ -89,12 +100,71 @@ impl StreamingResponse {
     }
 }
 
+/// Stream an OpenAI-compatible chat completion as StreamChunks
+///
+/// Shared by every provider that speaks the chat completions protocol
+/// (OpenAI, Azure, OpenRouter). `model` labels the usage chunk.
+pub(super) fn stream_chat_completion(
+    client: OpenAIClient,
+    request: CreateChatCompletionRequest,
+    model: String,
+) -> StreamingResponse {
+    let (tx, rx) = mpsc::channel(100);
+
+    tokio::spawn(async move {
+        match client.create_stream(request).await {
+            Ok(mut stream) => {
+                while let Some(result) = stream.next().await {
+                    match result {
+                        Ok(response) => {
+                            if let Some(usage) = response.usage {
+                                let usage = TokenUsage::new(usage.prompt_tokens as u64, usage.completion_tokens as u64);
+                                let _ = tx.send(StreamChunk::Usage { model: model.clone(), usage }).await;
+                            }
+
+                            for choice in response.choices {
+                                if let Some(content) = choice.delta.content {
+                                    if tx.send(StreamChunk::Content(content)).await.is_err() {
+                                        return;
+                                    }
+                                }
+                            }
+                        }
+                        Err(e) => {
+                            let _ = tx.send(StreamChunk::Error(e.into())).await;
+                            return;
+                        }
+                    }
+                }
+                let _ = tx.send(StreamChunk::Done).await;
+            }
+            Err(e) => {
+                let _ = tx.send(StreamChunk::Error(e.into())).await;
+            }
+        }
+    });
+
+    StreamingResponse::new(rx)
+}
+
+/// Word target for Deep responses unless the user sets their own
+pub const DEFAULT_DEEP_MAX_WORDS: usize = 150;
+
+/// Output token cap for a word target
+///
+/// About 1.3 tokens per word plus the section headings, with headroom so a
+/// response that runs slightly long isn't cut mid-sentence.
+pub fn deep_max_tokens(max_words: usize) -> u32 {
+    (max_words * 2 + 100).min(4096) as u32
+}
+
 /// Deep prompt template for generating detailed responses
 pub fn build_deep_prompt(
     transcript: &str,
     context: &str,
     flash_bullets: &[String],
     conversation_history: &str,
+    max_words: usize,
 ) -> String {
     let bullets_str = flash_bullets
         .iter()
@@ -139,7 +209,7 @@ RULES:
 - Be conversational, not robotic
 - Use specific examples when possible
 - Match the tone to the context (sales = confident, interview = professional, technical = precise)
-- Keep the total response under 200 words
+- Keep the total response under {max_words} words
 - The "Question to Ask" should advance the conversation"#
     )
 }
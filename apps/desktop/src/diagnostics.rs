@@ -0,0 +1,263 @@
+//! Diagnostics
+//!
+//! Pre-call self-test: checks every configured provider with the smallest
+//! possible request and reports a readiness checklist for the settings panel.
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::future::Future;
+use std::time::Duration;
+
+use crate::capture::{check_whisper_status, WhisperModelStatus};
+use crate::config::{FlashModel, Settings, SttProvider};
+use crate::error::{CopilotError, FlashError};
+use crate::flash::{check_ollama_status, ClaudeFlash, FlashAnalysis, GeminiFlash, GPT4oMini, OllamaStatus};
+use crate::voice::check_tts_availability;
+
+/// How long a single provider check may take
+const CHECK_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// Statement sent to each LLM for the completion check
+const PROBE_TRANSCRIPT: &str = "Can you hear me okay?";
+
+/// Result of one check
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum CheckStatus {
+    Ok,
+    /// Usable, but worth a look (e.g. optional provider not set up)
+    Warn,
+    /// Will break the call if relied on
+    Fail,
+}
+
+/// One line of the readiness checklist
+#[derive(Debug, Clone, Serialize)]
+pub struct ComponentCheck {
+    pub component: String,
+    pub status: CheckStatus,
+    pub message: String,
+}
+
+impl ComponentCheck {
+    fn new(component: &str, status: CheckStatus, message: impl Into<String>) -> Self {
+        Self {
+            component: component.to_string(),
+            status,
+            message: message.into(),
+        }
+    }
+}
+
+/// Outcome of a full self-test
+#[derive(Debug, Clone, Serialize)]
+pub struct SelfTestReport {
+    pub checks: Vec<ComponentCheck>,
+    pub ran_at: DateTime<Utc>,
+}
+
+impl SelfTestReport {
+    /// Worst status across all checks
+    pub fn overall(&self) -> CheckStatus {
+        if self.checks.iter().any(|c| c.status == CheckStatus::Fail) {
+            CheckStatus::Fail
+        } else if self.checks.iter().any(|c| c.status == CheckStatus::Warn) {
+            CheckStatus::Warn
+        } else {
+            CheckStatus::Ok
+        }
+    }
+
+    /// True when nothing failed
+    pub fn is_ready(&self) -> bool {
+        self.overall() != CheckStatus::Fail
+    }
+}
+
+/// Check every configured provider
+///
+/// Keys come from the environment first, then settings, matching how the
+/// pipeline resolves them.
+pub async fn run_self_test(settings: &Settings) -> SelfTestReport {
+    let keys = &settings.api_keys;
+    let deepgram_key = key("DEEPGRAM_API_KEY", &keys.deepgram);
+    let openai_key = key("OPENAI_API_KEY", &keys.openai);
+    let anthropic_key = key("ANTHROPIC_API_KEY", &keys.anthropic);
+    let google_key = key("GOOGLE_AI_API_KEY", &keys.google);
+    let azure = settings.models.azure_config();
+    let local_stt = settings.models.stt_provider == SttProvider::LocalWhisper;
+
+    let (deepgram, gemini, gpt, claude, ollama) = tokio::join!(
+        check_deepgram(deepgram_key, openai_key.is_some() || local_stt),
+        check_llm("Gemini", "Google AI", google_key.clone(), |key| async move {
+            GeminiFlash::new(key).analyze(PROBE_TRANSCRIPT, "Self-test").await
+        }),
+        check_llm("GPT-4o-mini", "OpenAI", openai_key.clone(), |key| async move {
+            let client = match &azure {
+                Some(azure) => GPT4oMini::azure(key, azure),
+                None => GPT4oMini::new(key),
+            };
+            client.analyze(PROBE_TRANSCRIPT, "Self-test").await
+        }),
+        check_llm("Claude", "Anthropic", anthropic_key.clone(), |key| async move {
+            ClaudeFlash::new(key).analyze(PROBE_TRANSCRIPT, "Self-test").await
+        }),
+        check_ollama(settings.models.flash_model == FlashModel::LocalOllama),
+    );
+
+    let mut checks = vec![deepgram, gemini, gpt, claude, ollama];
+    checks.push(check_whisper(local_stt));
+    checks.push(check_tts());
+
+    let any_llm = openai_key.is_some()
+        || anthropic_key.is_some()
+        || google_key.is_some()
+        || key("GROQ_API_KEY", &keys.groq).is_some()
+        || key("OPENROUTER_API_KEY", &keys.openrouter).is_some();
+    if !any_llm {
+        checks.push(ComponentCheck::new("LLM", CheckStatus::Fail, "No AI provider configured"));
+    }
+
+    SelfTestReport {
+        checks,
+        ran_at: Utc::now(),
+    }
+}
+
+fn key(env: &str, saved: &Option<String>) -> Option<String> {
+    std::env::var(env)
+        .ok()
+        .or_else(|| saved.clone())
+        .filter(|k| !k.trim().is_empty())
+}
+
+/// Validate the Deepgram key without opening a stream
+async fn check_deepgram(api_key: Option<String>, has_other_stt: bool) -> ComponentCheck {
+    const NAME: &str = "Deepgram";
+
+    let Some(api_key) = api_key else {
+        return if has_other_stt {
+            ComponentCheck::new(NAME, CheckStatus::Warn, "Not configured - using another transcription provider")
+        } else {
+            ComponentCheck::new(NAME, CheckStatus::Fail, "No speech-to-text provider configured")
+        };
+    };
+
+    let request = reqwest::Client::new()
+        .get("https://api.deepgram.com/v1/projects")
+        .header("Authorization", format!("Token {}", api_key))
+        .timeout(CHECK_TIMEOUT)
+        .send();
+
+    match request.await {
+        Ok(response) if response.status().is_success() => {
+            ComponentCheck::new(NAME, CheckStatus::Ok, "Key accepted")
+        }
+        Ok(response) => {
+            let status = response.status().as_u16();
+            let body = response.text().await.unwrap_or_default();
+            let error = CopilotError::Flash {
+                provider: NAME.to_string(),
+                error: FlashError::from_status(status, &body),
+            };
+            ComponentCheck::new(NAME, CheckStatus::Fail, error.message())
+        }
+        Err(e) => ComponentCheck::new(NAME, CheckStatus::Fail, format!("Can't reach Deepgram: {}", e)),
+    }
+}
+
+/// Run a tiny completion against one LLM provider
+async fn check_llm<F, Fut>(name: &str, provider: &str, api_key: Option<String>, probe: F) -> ComponentCheck
+where
+    F: FnOnce(String) -> Fut,
+    Fut: Future<Output = Result<FlashAnalysis, FlashError>>,
+{
+    let Some(api_key) = api_key else {
+        return ComponentCheck::new(name, CheckStatus::Warn, "No key configured");
+    };
+
+    let error = match tokio::time::timeout(CHECK_TIMEOUT, probe(api_key)).await {
+        Ok(Ok(_)) => return ComponentCheck::new(name, CheckStatus::Ok, "Responded"),
+        Ok(Err(e)) => e,
+        Err(_) => FlashError::Timeout(format!("no response after {}s", CHECK_TIMEOUT.as_secs())),
+    };
+
+    let error = CopilotError::Flash {
+        provider: provider.to_string(),
+        error,
+    };
+    ComponentCheck::new(name, CheckStatus::Fail, error.message())
+}
+
+/// Check the local Ollama server; only a failure if it's the flash model
+async fn check_ollama(required: bool) -> ComponentCheck {
+    const NAME: &str = "Ollama";
+    let missing = if required { CheckStatus::Fail } else { CheckStatus::Warn };
+
+    match check_ollama_status().await {
+        OllamaStatus::Ready { models, .. } => {
+            ComponentCheck::new(NAME, CheckStatus::Ok, format!("{} model(s) installed", models.len()))
+        }
+        OllamaStatus::NoModels => ComponentCheck::new(NAME, missing, "Running, but no models installed"),
+        OllamaStatus::NotRunning => ComponentCheck::new(NAME, missing, "Not running"),
+    }
+}
+
+/// Check for a downloaded Whisper model; only a failure if it's the STT provider
+fn check_whisper(required: bool) -> ComponentCheck {
+    const NAME: &str = "Local Whisper";
+
+    match check_whisper_status() {
+        WhisperModelStatus::Available(models) => {
+            let names: Vec<&str> = models.iter().map(|m| m.filename()).collect();
+            ComponentCheck::new(NAME, CheckStatus::Ok, names.join(", "))
+        }
+        WhisperModelStatus::NoneDownloaded => ComponentCheck::new(
+            NAME,
+            if required { CheckStatus::Fail } else { CheckStatus::Warn },
+            "No model downloaded",
+        ),
+    }
+}
+
+fn check_tts() -> ComponentCheck {
+    const NAME: &str = "Voice output";
+
+    let tts = check_tts_availability();
+    if tts.has_any() {
+        let names: Vec<String> = tts.available.iter().map(|p| format!("{:?}", p)).collect();
+        ComponentCheck::new(NAME, CheckStatus::Ok, names.join(", "))
+    } else {
+        ComponentCheck::new(NAME, CheckStatus::Warn, "No TTS provider available")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_overall_status() {
+        let mut report = SelfTestReport {
+            checks: vec![
+                ComponentCheck::new("Deepgram", CheckStatus::Ok, "Key accepted"),
+                ComponentCheck::new("Ollama", CheckStatus::Warn, "Not running"),
+            ],
+            ran_at: Utc::now(),
+        };
+        assert_eq!(report.overall(), CheckStatus::Warn);
+        assert!(report.is_ready());
+
+        report.checks.push(ComponentCheck::new("Claude", CheckStatus::Fail, "Check your Anthropic key"));
+        assert_eq!(report.overall(), CheckStatus::Fail);
+        assert!(!report.is_ready());
+    }
+
+    #[tokio::test]
+    async fn test_missing_key_is_warning() {
+        let check = check_llm("Claude", "Anthropic", None, |key| async move {
+            ClaudeFlash::new(key).analyze(PROBE_TRANSCRIPT, "Self-test").await
+        })
+        .await;
+        assert_eq!(check.status, CheckStatus::Warn);
+    }
+}
@@ -0,0 +1,313 @@
+//! Error Types
+//!
+//! Classified provider failures so the UI can tell a bad key from a rate
+//! limit or a malformed response instead of showing a raw error string.
+//! Audio capture failures get the same treatment, with a fix to suggest.
+
+use async_openai::error::OpenAIError;
+use serde::Serialize;
+
+use crate::flash::retry::HttpStatusError;
+
+/// Failure from an AI provider
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub enum ProviderError {
+    /// Missing or rejected API key
+    Auth(String),
+    /// Too many requests or quota exhausted
+    RateLimited(String),
+    /// Connection failed or the provider had a server error
+    Network(String),
+    /// Response wasn't in the expected format
+    Parse(String),
+    /// Request took too long
+    Timeout(String),
+    /// Anything that doesn't fit the above
+    Other(String),
+}
+
+/// Error returned by flash clients
+pub type FlashError = ProviderError;
+
+/// Error returned by deep clients
+pub type DeepError = ProviderError;
+
+impl ProviderError {
+    /// Classify an error from a provider call
+    pub fn classify(err: &anyhow::Error) -> Self {
+        if let Some(e) = err.downcast_ref::<ProviderError>() {
+            return e.clone();
+        }
+        if let Some(e) = err.downcast_ref::<HttpStatusError>() {
+            return Self::from_status(e.status, &e.body);
+        }
+        if let Some(e) = err.downcast_ref::<reqwest::Error>() {
+            return Self::from_reqwest(e);
+        }
+        if let Some(e) = err.downcast_ref::<OpenAIError>() {
+            return Self::from_openai(e);
+        }
+        if let Some(e) = err.downcast_ref::<serde_json::Error>() {
+            return Self::Parse(e.to_string());
+        }
+        Self::Other(err.to_string())
+    }
+
+    /// Classify a non-success HTTP status
+    pub fn from_status(status: u16, body: &str) -> Self {
+        let message = format!("HTTP {}: {}", status, body);
+        match status {
+            401 | 403 => Self::Auth(message),
+            429 => Self::RateLimited(message),
+            408 | 504 => Self::Timeout(message),
+            500..=599 => Self::Network(message),
+            _ => Self::Other(message),
+        }
+    }
+
+    fn from_reqwest(e: &reqwest::Error) -> Self {
+        if e.is_timeout() {
+            Self::Timeout(e.to_string())
+        } else if let Some(status) = e.status() {
+            Self::from_status(status.as_u16(), &e.to_string())
+        } else if e.is_decode() {
+            Self::Parse(e.to_string())
+        } else {
+            Self::Network(e.to_string())
+        }
+    }
+
+    fn from_openai(e: &OpenAIError) -> Self {
+        match e {
+            OpenAIError::Reqwest(e) => Self::from_reqwest(e),
+            OpenAIError::ApiError(api) => {
+                let code = api.code.as_deref().unwrap_or_default();
+                let kind = api.r#type.as_deref().unwrap_or_default();
+                if code == "invalid_api_key" || kind == "authentication_error" {
+                    Self::Auth(api.message.clone())
+                } else if code == "rate_limit_exceeded" || code == "insufficient_quota" {
+                    Self::RateLimited(api.message.clone())
+                } else if kind == "server_error" {
+                    Self::Network(api.message.clone())
+                } else {
+                    Self::Other(api.message.clone())
+                }
+            }
+            OpenAIError::JSONDeserialize(e) => Self::Parse(e.to_string()),
+            OpenAIError::StreamError(e) => Self::Network(e.clone()),
+            e => Self::Other(e.to_string()),
+        }
+    }
+
+    /// Whether retrying later could succeed
+    pub fn is_transient(&self) -> bool {
+        matches!(self, Self::RateLimited(_) | Self::Network(_) | Self::Timeout(_))
+    }
+
+    /// Underlying message from the provider
+    pub fn detail(&self) -> &str {
+        match self {
+            Self::Auth(m)
+            | Self::RateLimited(m)
+            | Self::Network(m)
+            | Self::Parse(m)
+            | Self::Timeout(m)
+            | Self::Other(m) => m,
+        }
+    }
+}
+
+impl std::fmt::Display for ProviderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let kind = match self {
+            Self::Auth(_) => "authentication failed",
+            Self::RateLimited(_) => "rate limited",
+            Self::Network(_) => "network error",
+            Self::Parse(_) => "invalid response",
+            Self::Timeout(_) => "timed out",
+            Self::Other(_) => "error",
+        };
+        write!(f, "{}: {}", kind, self.detail())
+    }
+}
+
+impl std::error::Error for ProviderError {}
+
+impl From<anyhow::Error> for ProviderError {
+    fn from(err: anyhow::Error) -> Self {
+        Self::classify(&err)
+    }
+}
+
+impl From<reqwest::Error> for ProviderError {
+    fn from(err: reqwest::Error) -> Self {
+        Self::from_reqwest(&err)
+    }
+}
+
+impl From<serde_json::Error> for ProviderError {
+    fn from(err: serde_json::Error) -> Self {
+        Self::Parse(err.to_string())
+    }
+}
+
+impl From<OpenAIError> for ProviderError {
+    fn from(err: OpenAIError) -> Self {
+        Self::from_openai(&err)
+    }
+}
+
+/// How loudly the UI should surface an error
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum ErrorSeverity {
+    /// Transient - will likely clear up on its own
+    Warning,
+    /// Needs the user to fix something
+    Error,
+}
+
+/// Typed pipeline failure for the UI
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub enum CopilotError {
+    /// Flash analysis failed
+    Flash { provider: String, error: FlashError },
+    /// Deep analysis failed
+    Deep { provider: String, error: DeepError },
+}
+
+impl CopilotError {
+    pub fn error(&self) -> &ProviderError {
+        match self {
+            Self::Flash { error, .. } | Self::Deep { error, .. } => error,
+        }
+    }
+
+    pub fn provider(&self) -> &str {
+        match self {
+            Self::Flash { provider, .. } | Self::Deep { provider, .. } => provider,
+        }
+    }
+
+    pub fn severity(&self) -> ErrorSeverity {
+        if self.error().is_transient() {
+            ErrorSeverity::Warning
+        } else {
+            ErrorSeverity::Error
+        }
+    }
+
+    /// Short, actionable message for the user
+    pub fn message(&self) -> String {
+        let provider = self.provider();
+        match self.error() {
+            ProviderError::Auth(_) => format!("Check your {} key", provider),
+            ProviderError::RateLimited(_) => format!("{} rate limit hit - slow down", provider),
+            ProviderError::Network(_) => format!("Can't reach {}", provider),
+            ProviderError::Parse(_) => format!("{} returned an unexpected response", provider),
+            ProviderError::Timeout(_) => format!("{} timed out", provider),
+            ProviderError::Other(detail) => format!("{} error: {}", provider, detail),
+        }
+    }
+}
+
+impl std::fmt::Display for CopilotError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message())
+    }
+}
+
+/// Why system audio capture couldn't start
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub enum CaptureError {
+    /// No output (Windows) or input (macOS/Linux) device to capture from
+    NoDevice(String),
+    /// The OS refused access to the device
+    PermissionDenied(String),
+    /// The device can't provide audio in a format we can stream
+    FormatUnsupported(String),
+    /// Anything that doesn't fit the above
+    Other(String),
+}
+
+impl CaptureError {
+    /// Underlying message from the audio backend
+    pub fn detail(&self) -> &str {
+        match self {
+            Self::NoDevice(m) | Self::PermissionDenied(m) | Self::FormatUnsupported(m) | Self::Other(m) => m,
+        }
+    }
+
+    /// What the user can do about it, for this platform
+    pub fn remedy(&self) -> &'static str {
+        match self {
+            Self::NoDevice(_) if cfg!(target_os = "windows") => {
+                "Connect speakers or headphones, or enable Stereo Mix in Sound settings > Recording"
+            }
+            Self::NoDevice(_) if cfg!(target_os = "macos") => {
+                "Install BlackHole and select it as the input device"
+            }
+            Self::NoDevice(_) => "Select a monitor source as the default input device",
+            Self::PermissionDenied(_) if cfg!(target_os = "macos") => {
+                "Allow Voice Copilot in System Settings > Privacy & Security > Screen & System Audio Recording"
+            }
+            Self::PermissionDenied(_) if cfg!(target_os = "windows") => {
+                "Allow apps to access your microphone in Settings > Privacy & security"
+            }
+            Self::PermissionDenied(_) => "Check that your user can access the audio device",
+            Self::FormatUnsupported(_) => "Pick a different audio device or set it to 16 or 48 kHz",
+            Self::Other(_) => "Check your audio device and try again",
+        }
+    }
+
+    /// Short, actionable message for the user
+    pub fn message(&self) -> String {
+        let problem = match self {
+            Self::NoDevice(_) => "No audio device to capture",
+            Self::PermissionDenied(_) => "Audio capture was blocked",
+            Self::FormatUnsupported(_) => "Audio format not supported",
+            Self::Other(_) => "Audio capture failed",
+        };
+        format!("{}. {}", problem, self.remedy())
+    }
+}
+
+impl std::fmt::Display for CaptureError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({})", self.message(), self.detail())
+    }
+}
+
+impl std::error::Error for CaptureError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify_status() {
+        let err: anyhow::Error = HttpStatusError { status: 401, body: "bad key".to_string() }.into();
+        assert!(matches!(ProviderError::classify(&err), ProviderError::Auth(_)));
+
+        let err: anyhow::Error = HttpStatusError { status: 429, body: String::new() }.into();
+        assert!(matches!(ProviderError::classify(&err), ProviderError::RateLimited(_)));
+
+        let err: anyhow::Error = serde_json::from_str::<serde_json::Value>("{").unwrap_err().into();
+        assert!(matches!(ProviderError::classify(&err), ProviderError::Parse(_)));
+    }
+
+    #[test]
+    fn test_user_message() {
+        let auth = CopilotError::Flash {
+            provider: "OpenAI".to_string(),
+            error: ProviderError::Auth("HTTP 401".to_string()),
+        };
+        assert_eq!(auth.message(), "Check your OpenAI key");
+        assert_eq!(auth.severity(), ErrorSeverity::Error);
+
+        let limited = CopilotError::Deep {
+            provider: "Anthropic".to_string(),
+            error: ProviderError::RateLimited("HTTP 429".to_string()),
+        };
+        assert_eq!(limited.severity(), ErrorSeverity::Warning);
+    }
+}
@@ -4,6 +4,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::cost::TokenUsage;
+
 /// Flash analysis result from the fast model
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FlashAnalysis {
@@ -19,6 +21,10 @@ pub struct FlashAnalysis {
 
     /// How urgently you need to respond
     pub urgency: Urgency,
+
+    /// Tokens reported by the provider (not part of the model output)
+    #[serde(skip)]
+    pub usage: Option<TokenUsage>,
 }
 
 /// A single bullet point suggestion
@@ -95,11 +101,71 @@ pub fn extract_bullets(analysis: &FlashAnalysis) -> Vec<&Bullet> {
     bullets
 }
 
+/// Keep the `max_bullets` highest-priority bullets, each cut to `max_words`
+///
+/// Models don't always stick to the limits in the prompt. A cut bullet ends
+/// with "…"; zero means no limit.
+pub fn limit_bullets(analysis: &mut FlashAnalysis, max_bullets: usize, max_words: usize) {
+    analysis.bullets.sort_by_key(|b| b.priority);
+    if max_bullets > 0 {
+        analysis.bullets.truncate(max_bullets);
+    }
+    if max_words == 0 {
+        return;
+    }
+
+    for bullet in &mut analysis.bullets {
+        let words: Vec<&str> = bullet.point.split_whitespace().collect();
+        if words.len() > max_words {
+            let kept = words[..max_words].join(" ");
+            bullet.point = format!("{}…", kept.trim_end_matches(&[',', ';', ':', '.', '-'][..]));
+        }
+    }
+}
+
 /// Get the top bullet (priority 1)
 pub fn get_top_bullet(analysis: &FlashAnalysis) -> Option<&Bullet> {
     analysis.bullets.iter().find(|b| b.priority == 1)
 }
 
+/// Locate the JSON object inside raw model output
+///
+/// Models often wrap JSON in ```json fences or add a sentence before or
+/// after it. Returns the first balanced `{...}` object, skipping braces that
+/// appear inside string literals.
+pub fn extract_json(raw: &str) -> Option<&str> {
+    let start = raw.find('{')?;
+    let mut depth = 0usize;
+    let mut in_string = false;
+    let mut escaped = false;
+
+    for (offset, c) in raw[start..].char_indices() {
+        if in_string {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+
+        match c {
+            '"' => in_string = true,
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(&raw[start..=start + offset]);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    None
+}
+
 impl Default for FlashAnalysis {
     fn default() -> Self {
         Self {
@@ -107,6 +173,7 @@ impl Default for FlashAnalysis {
             bullets: Vec::new(),
             statement_type: StatementType::Unknown,
             urgency: Urgency::Unknown,
+            usage: None,
         }
     }
 }
@@ -144,6 +211,7 @@ mod tests {
             ],
             statement_type: StatementType::Question,
             urgency: Urgency::AnswerNow,
+            usage: None,
         };
 
         let sorted = extract_bullets(&analysis);
@@ -151,4 +219,43 @@ mod tests {
         assert_eq!(sorted[1].point, "Second");
         assert_eq!(sorted[2].point, "Third");
     }
+
+    #[test]
+    fn test_limit_bullets() {
+        let mut analysis = FlashAnalysis {
+            bullets: vec![
+                Bullet { point: "Third".to_string(), priority: 3 },
+                Bullet { point: "Lead with the two-week pilot, then ask who signs off".to_string(), priority: 1 },
+                Bullet { point: "Mention SSO".to_string(), priority: 2 },
+            ],
+            ..FlashAnalysis::default()
+        };
+
+        limit_bullets(&mut analysis, 2, 5);
+        let points: Vec<&str> = analysis.bullets.iter().map(|b| b.point.as_str()).collect();
+        assert_eq!(points, vec!["Lead with the two-week pilot…", "Mention SSO"]);
+    }
+
+    #[test]
+    fn test_extract_json_fenced() {
+        let raw = "```json\n{\"summary\": \"hi\", \"bullets\": []}\n```";
+        assert_eq!(extract_json(raw), Some("{\"summary\": \"hi\", \"bullets\": []}"));
+    }
+
+    #[test]
+    fn test_extract_json_prefixed() {
+        let raw = "Sure! Here is the analysis:\n{\"summary\": \"uses {braces}\", \"nested\": {\"a\": 1}}";
+        assert_eq!(
+            extract_json(raw),
+            Some("{\"summary\": \"uses {braces}\", \"nested\": {\"a\": 1}}")
+        );
+    }
+
+    #[test]
+    fn test_extract_json_trailing_text() {
+        let raw = "{\"summary\": \"quote \\\" inside\"} Let me know if you need more.";
+        assert_eq!(extract_json(raw), Some("{\"summary\": \"quote \\\" inside\"}"));
+        assert_eq!(extract_json("no json here"), None);
+        assert_eq!(extract_json("{\"unterminated\": true"), None);
+    }
 }
@@ -0,0 +1,183 @@
+//! Claude Flash Integration
+//!
+//! Quick bullet extraction using Claude via the Anthropic Messages API.
+//! Used when routing prefers quality over raw speed.
+
+use async_trait::async_trait;
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+
+use super::bullet_extractor::{extract_json, FlashAnalysis};
+use super::provider::FlashProvider;
+use crate::cost::TokenUsage;
+use crate::error::FlashError;
+
+/// Claude flash client
+pub struct ClaudeFlash {
+    api_key: String,
+    client: Client,
+    model: String,
+}
+
+#[derive(Debug, Serialize)]
+struct ClaudeRequest {
+    model: String,
+    max_tokens: u32,
+    temperature: f32,
+    system: String,
+    messages: Vec<ClaudeMessage>,
+}
+
+#[derive(Debug, Serialize)]
+struct ClaudeMessage {
+    role: String,
+    content: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct ClaudeResponse {
+    content: Vec<ContentBlock>,
+    #[serde(default)]
+    usage: Option<ClaudeUsage>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ClaudeUsage {
+    #[serde(default)]
+    input_tokens: u64,
+    #[serde(default)]
+    output_tokens: u64,
+}
+
+#[derive(Debug, Deserialize)]
+struct ContentBlock {
+    text: Option<String>,
+}
+
+impl ClaudeFlash {
+    /// Create a new Claude flash client
+    pub fn new(api_key: impl Into<String>) -> Self {
+        Self {
+            api_key: api_key.into(),
+            client: Client::new(),
+            model: "claude-3-5-haiku-20241022".to_string(), // Fastest Claude
+        }
+    }
+
+    /// Use a specific model
+    pub fn with_model(mut self, model: impl Into<String>) -> Self {
+        self.model = model.into();
+        self
+    }
+
+    /// Analyze transcript and extract quick response bullets
+    pub async fn analyze(&self, transcript: &str, context: &str) -> Result<FlashAnalysis, FlashError> {
+        let system_prompt = r#"You are an instant analysis engine. Respond in <200ms.
+
+OUTPUT: JSON only, no explanation
+
+{
+  "summary": "One sentence: what they're asking/saying",
+  "bullets": [
+    {"point": "Key thing to mention", "priority": 1},
+    {"point": "Another point", "priority": 2},
+    {"point": "Supporting detail", "priority": 3}
+  ],
+  "type": "question|objection|statement|buying_signal|technical|small_talk",
+  "urgency": "answer_now|can_elaborate|just_listening"
+}
+
+Rules:
+- Max 5 bullets
+- Priority 1 = say this first (most important)
+- Be specific, not generic
+- Under 50 tokens total"#;
+
+        let user_prompt = format!(
+            "CONTEXT: {}\n\nTHEIR STATEMENT: \"{}\"",
+            context, transcript
+        );
+
+        // Claude has no JSON mode, so prefill the opening brace
+        let request = ClaudeRequest {
+            model: self.model.clone(),
+            max_tokens: 200,
+            temperature: 0.3,
+            system: system_prompt.to_string(),
+            messages: vec![
+                ClaudeMessage {
+                    role: "user".to_string(),
+                    content: user_prompt,
+                },
+                ClaudeMessage {
+                    role: "assistant".to_string(),
+                    content: "{".to_string(),
+                },
+            ],
+        };
+
+        let response = self
+            .client
+            .post("https://api.anthropic.com/v1/messages")
+            .header("x-api-key", &self.api_key)
+            .header("anthropic-version", "2023-06-01")
+            .header("content-type", "application/json")
+            .json(&request)
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status().as_u16();
+            let body = response.text().await.unwrap_or_default();
+            return Err(FlashError::from_status(status, &body));
+        }
+
+        let claude_response: ClaudeResponse = response.json().await?;
+
+        if let Some(text) = claude_response.content.first().and_then(|c| c.text.as_ref()) {
+            let raw = format!("{{{}", text);
+            let mut analysis: FlashAnalysis = serde_json::from_str(extract_json(&raw).unwrap_or(&raw))
+                .map_err(|e| FlashError::Parse(e.to_string()))?;
+            analysis.usage = claude_response
+                .usage
+                .as_ref()
+                .map(|u| TokenUsage::new(u.input_tokens, u.output_tokens));
+            return Ok(analysis);
+        }
+
+        Err(FlashError::Parse("No response from Claude".to_string()))
+    }
+}
+
+#[async_trait]
+impl FlashProvider for ClaudeFlash {
+    async fn analyze(&self, transcript: &str, context: &str) -> Result<FlashAnalysis, FlashError> {
+        ClaudeFlash::analyze(self, transcript, context).await
+    }
+
+    fn name(&self) -> &str {
+        &self.model
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    #[ignore] // Requires API key
+    async fn test_claude_flash_analyze() {
+        let client = ClaudeFlash::new("YOUR_API_KEY");
+        let result = client
+            .analyze(
+                "How much does your enterprise plan cost?",
+                "Sales call for SaaS product",
+            )
+            .await;
+
+        assert!(result.is_ok());
+        let analysis = result.unwrap();
+        assert!(!analysis.summary.is_empty());
+        assert!(!analysis.bullets.is_empty());
+    }
+}
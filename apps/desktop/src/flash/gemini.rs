@@ -4,16 +4,22 @@
 //! Provides responses in ~200-300ms.
 
 use anyhow::Result;
+use async_trait::async_trait;
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
 
-use super::bullet_extractor::FlashAnalysis;
+use super::bullet_extractor::{extract_json, FlashAnalysis};
+use super::provider::FlashProvider;
+use super::retry::{with_backoff, HttpStatusError, DEFAULT_MAX_RETRIES};
+use crate::cost::TokenUsage;
+use crate::error::FlashError;
 
 /// Gemini 2.0 Flash client
 pub struct GeminiFlash {
     api_key: String,
     client: Client,
     model: String,
+    max_retries: u32,
 }
 
 #[derive(Debug, Serialize)]
@@ -43,6 +49,16 @@ struct GenerationConfig {
 #[derive(Debug, Deserialize)]
 struct GeminiResponse {
     candidates: Vec<Candidate>,
+    #[serde(rename = "usageMetadata", default)]
+    usage_metadata: Option<UsageMetadata>,
+}
+
+#[derive(Debug, Deserialize)]
+struct UsageMetadata {
+    #[serde(rename = "promptTokenCount", default)]
+    prompt_token_count: u64,
+    #[serde(rename = "candidatesTokenCount", default)]
+    candidates_token_count: u64,
 }
 
 #[derive(Debug, Deserialize)]
@@ -67,6 +83,7 @@ impl GeminiFlash {
             api_key: api_key.into(),
             client: Client::new(),
             model: "gemini-2.0-flash-exp".to_string(), // Latest experimental Flash
+            max_retries: DEFAULT_MAX_RETRIES,
         }
     }
 
@@ -76,8 +93,21 @@ impl GeminiFlash {
         self
     }
 
+    /// Set how many times transient failures are retried
+    pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
     /// Analyze transcript and extract quick response bullets
-    pub async fn analyze(&self, transcript: &str, context: &str) -> Result<FlashAnalysis> {
+    pub async fn analyze(&self, transcript: &str, context: &str) -> Result<FlashAnalysis, FlashError> {
+        with_backoff(self.max_retries, || self.analyze_once(transcript, context))
+            .await
+            .map_err(FlashError::from)
+    }
+
+    /// Single analysis request without retries
+    async fn analyze_once(&self, transcript: &str, context: &str) -> Result<FlashAnalysis> {
         let prompt = format!(
             r#"You are an instant analysis engine. Respond in <200ms.
 
@@ -132,17 +162,38 @@ Rules:
             .send()
             .await?;
 
+        if !response.status().is_success() {
+            let status = response.status().as_u16();
+            let body = response.text().await.unwrap_or_default();
+            return Err(HttpStatusError { status, body }.into());
+        }
+
         let gemini_response: GeminiResponse = response.json().await?;
 
         // Extract the JSON from the response
         if let Some(candidate) = gemini_response.candidates.first() {
             if let Some(part) = candidate.content.parts.first() {
-                let analysis: FlashAnalysis = serde_json::from_str(&part.text)?;
+                let mut analysis: FlashAnalysis = serde_json::from_str(extract_json(&part.text).unwrap_or(&part.text))?;
+                analysis.usage = gemini_response
+                    .usage_metadata
+                    .as_ref()
+                    .map(|u| TokenUsage::new(u.prompt_token_count, u.candidates_token_count));
                 return Ok(analysis);
             }
         }
 
-        Err(anyhow::anyhow!("No response from Gemini"))
+        Err(FlashError::Parse("No response from Gemini".to_string()).into())
+    }
+}
+
+#[async_trait]
+impl FlashProvider for GeminiFlash {
+    async fn analyze(&self, transcript: &str, context: &str) -> Result<FlashAnalysis, FlashError> {
+        GeminiFlash::analyze(self, transcript, context).await
+    }
+
+    fn name(&self) -> &str {
+        &self.model
     }
 }
 
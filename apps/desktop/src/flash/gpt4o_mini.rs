@@ -4,31 +4,43 @@
 //! Slightly slower than Gemini Flash but very reliable.
 
 use anyhow::Result;
-use async_openai::{
-    config::OpenAIConfig,
-    types::{
-        ChatCompletionRequestMessage, ChatCompletionRequestSystemMessageArgs,
-        ChatCompletionRequestUserMessageArgs, CreateChatCompletionRequestArgs,
-        ResponseFormat, ResponseFormatType,
-    },
-    Client,
+use async_trait::async_trait;
+use async_openai::types::{
+    ChatCompletionRequestMessage, ChatCompletionRequestSystemMessageArgs,
+    ChatCompletionRequestUserMessageArgs, CreateChatCompletionRequestArgs,
+    ResponseFormat, ResponseFormatType,
 };
 
-use super::bullet_extractor::FlashAnalysis;
+use super::bullet_extractor::{extract_json, FlashAnalysis};
+use super::provider::FlashProvider;
+use super::openai_client::{AzureOpenAIConfig, OpenAIClient};
+use super::retry::{with_backoff, DEFAULT_MAX_RETRIES};
+use crate::cost::TokenUsage;
+use crate::error::FlashError;
 
 /// GPT-4o-mini client
 pub struct GPT4oMini {
-    client: Client<OpenAIConfig>,
+    client: OpenAIClient,
     model: String,
+    max_retries: u32,
 }
 
 impl GPT4oMini {
     /// Create a new GPT-4o-mini client
     pub fn new(api_key: impl Into<String>) -> Self {
-        let config = OpenAIConfig::new().with_api_key(api_key);
         Self {
-            client: Client::with_config(config),
+            client: OpenAIClient::new(api_key),
             model: "gpt-4o-mini".to_string(),
+            max_retries: DEFAULT_MAX_RETRIES,
+        }
+    }
+
+    /// Create a client for an Azure OpenAI deployment
+    pub fn azure(api_key: impl Into<String>, azure: &AzureOpenAIConfig) -> Self {
+        Self {
+            client: OpenAIClient::azure(api_key, azure),
+            model: "gpt-4o-mini".to_string(),
+            max_retries: DEFAULT_MAX_RETRIES,
         }
     }
 
@@ -38,8 +50,21 @@ impl GPT4oMini {
         self
     }
 
+    /// Set how many times transient failures are retried
+    pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
     /// Analyze transcript and extract quick response bullets
-    pub async fn analyze(&self, transcript: &str, context: &str) -> Result<FlashAnalysis> {
+    pub async fn analyze(&self, transcript: &str, context: &str) -> Result<FlashAnalysis, FlashError> {
+        with_backoff(self.max_retries, || self.analyze_once(transcript, context))
+            .await
+            .map_err(FlashError::from)
+    }
+
+    /// Single analysis request without retries
+    async fn analyze_once(&self, transcript: &str, context: &str) -> Result<FlashAnalysis> {
         let system_prompt = r#"You are an instant analysis engine. Respond in <200ms.
 
 OUTPUT: JSON only, no explanation
@@ -87,15 +112,30 @@ Rules:
             .temperature(0.3)
             .build()?;
 
-        let response = self.client.chat().create(request).await?;
+        let response = self.client.create(request).await?;
 
         if let Some(choice) = response.choices.first() {
             if let Some(content) = &choice.message.content {
-                let analysis: FlashAnalysis = serde_json::from_str(content)?;
+                let mut analysis: FlashAnalysis = serde_json::from_str(extract_json(content).unwrap_or(content))?;
+                analysis.usage = response
+                    .usage
+                    .as_ref()
+                    .map(|u| TokenUsage::new(u.prompt_tokens as u64, u.completion_tokens as u64));
                 return Ok(analysis);
             }
         }
 
-        Err(anyhow::anyhow!("No response from GPT-4o-mini"))
+        Err(FlashError::Parse("No response from GPT-4o-mini".to_string()).into())
+    }
+}
+
+#[async_trait]
+impl FlashProvider for GPT4oMini {
+    async fn analyze(&self, transcript: &str, context: &str) -> Result<FlashAnalysis, FlashError> {
+        GPT4oMini::analyze(self, transcript, context).await
+    }
+
+    fn name(&self) -> &str {
+        &self.model
     }
 }
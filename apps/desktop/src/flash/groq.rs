@@ -0,0 +1,160 @@
+//! Groq Flash Integration
+//!
+//! Hosted Llama on Groq's LPUs via their OpenAI-compatible API.
+//! Typically the fastest cloud option at ~200ms per analysis.
+
+use anyhow::Result;
+use async_trait::async_trait;
+use async_openai::types::{
+    ChatCompletionRequestMessage, ChatCompletionRequestSystemMessageArgs,
+    ChatCompletionRequestUserMessageArgs, CreateChatCompletionRequestArgs,
+    ResponseFormat, ResponseFormatType,
+};
+
+use super::bullet_extractor::{extract_json, FlashAnalysis};
+use super::provider::FlashProvider;
+use super::openai_client::OpenAIClient;
+use super::retry::{with_backoff, DEFAULT_MAX_RETRIES};
+use crate::cost::TokenUsage;
+use crate::error::FlashError;
+
+/// Groq's OpenAI-compatible API base
+pub const GROQ_API_BASE: &str = "https://api.groq.com/openai/v1";
+
+/// Default Groq model for flash analysis
+pub const DEFAULT_GROQ_MODEL: &str = "llama-3.1-8b-instant";
+
+/// Groq flash client
+pub struct GroqFlash {
+    client: OpenAIClient,
+    model: String,
+    max_retries: u32,
+}
+
+impl GroqFlash {
+    /// Create a new Groq client using the default model
+    pub fn new(api_key: impl Into<String>) -> Self {
+        Self {
+            client: OpenAIClient::compatible(GROQ_API_BASE, api_key),
+            model: DEFAULT_GROQ_MODEL.to_string(),
+            max_retries: DEFAULT_MAX_RETRIES,
+        }
+    }
+
+    /// Use a specific model, e.g. `llama-3.3-70b-versatile`
+    pub fn with_model(mut self, model: impl Into<String>) -> Self {
+        self.model = model.into();
+        self
+    }
+
+    /// Set how many times transient failures are retried
+    pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Analyze transcript and extract quick response bullets
+    pub async fn analyze(&self, transcript: &str, context: &str) -> Result<FlashAnalysis, FlashError> {
+        with_backoff(self.max_retries, || self.analyze_once(transcript, context))
+            .await
+            .map_err(FlashError::from)
+    }
+
+    /// Single analysis request without retries
+    async fn analyze_once(&self, transcript: &str, context: &str) -> Result<FlashAnalysis> {
+        let system_prompt = r#"You are an instant analysis engine. Respond in <200ms.
+
+OUTPUT: JSON only, no explanation
+
+{
+  "summary": "One sentence: what they're asking/saying",
+  "bullets": [
+    {"point": "Key thing to mention", "priority": 1},
+    {"point": "Another point", "priority": 2},
+    {"point": "Supporting detail", "priority": 3}
+  ],
+  "type": "question|objection|statement|buying_signal|technical|small_talk",
+  "urgency": "answer_now|can_elaborate|just_listening"
+}
+
+Rules:
+- Max 5 bullets
+- Priority 1 = say this first (most important)
+- Be specific, not generic
+- Under 50 tokens total"#;
+
+        let user_prompt = format!(
+            "CONTEXT: {}\n\nTHEIR STATEMENT: \"{}\"",
+            context, transcript
+        );
+
+        let request = CreateChatCompletionRequestArgs::default()
+            .model(&self.model)
+            .messages(vec![
+                ChatCompletionRequestMessage::System(
+                    ChatCompletionRequestSystemMessageArgs::default()
+                        .content(system_prompt)
+                        .build()?,
+                ),
+                ChatCompletionRequestMessage::User(
+                    ChatCompletionRequestUserMessageArgs::default()
+                        .content(user_prompt)
+                        .build()?,
+                ),
+            ])
+            .response_format(ResponseFormat {
+                r#type: ResponseFormatType::JsonObject,
+            })
+            .max_tokens(200u32)
+            .temperature(0.3)
+            .build()?;
+
+        let response = self.client.create(request).await?;
+
+        if let Some(choice) = response.choices.first() {
+            if let Some(content) = &choice.message.content {
+                let mut analysis: FlashAnalysis = serde_json::from_str(extract_json(content).unwrap_or(content))?;
+                analysis.usage = response
+                    .usage
+                    .as_ref()
+                    .map(|u| TokenUsage::new(u.prompt_tokens as u64, u.completion_tokens as u64));
+                return Ok(analysis);
+            }
+        }
+
+        Err(FlashError::Parse("No response from Groq".to_string()).into())
+    }
+}
+
+#[async_trait]
+impl FlashProvider for GroqFlash {
+    async fn analyze(&self, transcript: &str, context: &str) -> Result<FlashAnalysis, FlashError> {
+        GroqFlash::analyze(self, transcript, context).await
+    }
+
+    fn name(&self) -> &str {
+        &self.model
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    #[ignore] // Requires API key
+    async fn test_groq_analyze() {
+        let client = GroqFlash::new("YOUR_API_KEY");
+        let result = client
+            .analyze(
+                "How much does your enterprise plan cost?",
+                "Sales call for SaaS product",
+            )
+            .await;
+
+        assert!(result.is_ok());
+        let analysis = result.unwrap();
+        assert!(!analysis.summary.is_empty());
+        assert!(!analysis.bullets.is_empty());
+    }
+}
@@ -0,0 +1,54 @@
+//! Mock Flash Provider
+//!
+//! Returns a canned analysis so pipeline tests run without network access.
+
+use async_trait::async_trait;
+use std::time::Duration;
+
+use super::bullet_extractor::{Bullet, FlashAnalysis, StatementType, Urgency};
+use super::provider::FlashProvider;
+use crate::error::FlashError;
+
+/// Answers every transcript with the same analysis
+#[derive(Debug, Clone)]
+pub struct MockFlashProvider {
+    pub analysis: FlashAnalysis,
+    /// How long each answer takes, to have one in flight
+    pub delay: Duration,
+}
+
+impl Default for MockFlashProvider {
+    fn default() -> Self {
+        Self {
+            analysis: FlashAnalysis {
+                summary: "They asked about pricing".to_string(),
+                bullets: vec![
+                    Bullet { point: "Starts at $50 per seat".to_string(), priority: 1 },
+                    Bullet { point: "Annual plans get two months free".to_string(), priority: 2 },
+                ],
+                statement_type: StatementType::Question,
+                urgency: Urgency::CanElaborate,
+                usage: None,
+            },
+            delay: Duration::ZERO,
+        }
+    }
+}
+
+#[async_trait]
+impl FlashProvider for MockFlashProvider {
+    async fn analyze(&self, _transcript: &str, _context: &str) -> Result<FlashAnalysis, FlashError> {
+        if !self.delay.is_zero() {
+            tokio::time::sleep(self.delay).await;
+        }
+        Ok(self.analysis.clone())
+    }
+
+    fn name(&self) -> &str {
+        "mock-flash"
+    }
+
+    fn is_local(&self) -> bool {
+        true
+    }
+}
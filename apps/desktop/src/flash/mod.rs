@@ -1,14 +1,31 @@
 //! Flash Module - Stage 2 (Quick Response)
 //!
-//! Fast AI responses using Gemini 2.0 Flash, GPT-4o-mini, or local Ollama.
+//! Fast AI responses using Gemini 2.0 Flash, GPT-4o-mini, Claude, Groq, OpenRouter, or local Ollama.
 //! Provides instant bullet points within ~500-1000ms.
 
 mod gemini;
 mod gpt4o_mini;
+mod claude_flash;
+mod groq;
 mod ollama;
+mod openrouter;
 mod bullet_extractor;
+mod openai_client;
+mod provider;
+#[cfg(test)]
+mod mock;
+pub mod retry;
+
+pub use crate::error::FlashError;
 
 pub use gemini::GeminiFlash;
 pub use gpt4o_mini::GPT4oMini;
-pub use ollama::{OllamaFlash, OllamaStatus, OllamaModel, check_ollama_status};
-pub use bullet_extractor::{FlashAnalysis, Bullet, StatementType, Urgency, extract_bullets};
+pub use claude_flash::ClaudeFlash;
+pub use groq::{GroqFlash, GROQ_API_BASE, DEFAULT_GROQ_MODEL};
+pub use ollama::{OllamaFlash, OllamaStatus, OllamaModel, check_ollama_status, DEFAULT_OLLAMA_URL};
+pub use openrouter::{OpenRouterClient, DEFAULT_OPENROUTER_FLASH_MODEL};
+pub use openai_client::{OpenAIClient, AzureOpenAIConfig, DEFAULT_AZURE_API_VERSION, OPENROUTER_API_BASE};
+pub use provider::FlashProvider;
+#[cfg(test)]
+pub use mock::MockFlashProvider;
+pub use bullet_extractor::{FlashAnalysis, Bullet, StatementType, Urgency, extract_bullets, extract_json, limit_bullets};
@@ -4,14 +4,20 @@
 //! No API costs, works offline, typically ~500-1000ms response time.
 
 use anyhow::Result;
+use async_trait::async_trait;
+use futures::StreamExt;
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
 use std::time::Duration;
+use tokio::sync::mpsc;
 
-use super::bullet_extractor::FlashAnalysis;
+use super::bullet_extractor::{extract_json, Bullet, FlashAnalysis};
+use super::provider::FlashProvider;
+use crate::cost::TokenUsage;
+use crate::error::FlashError;
 
 /// Default Ollama server URL
-const DEFAULT_OLLAMA_URL: &str = "http://localhost:11434";
+pub const DEFAULT_OLLAMA_URL: &str = "http://localhost:11434";
 
 /// Ollama client for local LLM inference
 pub struct OllamaFlash {
@@ -43,6 +49,22 @@ struct OllamaResponse {
     #[serde(default)]
     total_duration: u64,
     #[serde(default)]
+    prompt_eval_count: u32,
+    #[serde(default)]
+    eval_count: u32,
+}
+
+/// One NDJSON line from a streaming `/api/generate` call
+#[derive(Debug, Deserialize)]
+struct OllamaStreamChunk {
+    #[serde(default)]
+    response: String,
+    #[serde(default)]
+    done: bool,
+    /// Only on the final line
+    #[serde(default)]
+    prompt_eval_count: u32,
+    #[serde(default)]
     eval_count: u32,
 }
 
@@ -111,36 +133,8 @@ impl OllamaFlash {
     }
 
     /// Analyze transcript and extract quick response bullets
-    pub async fn analyze(&self, transcript: &str, context: &str) -> Result<FlashAnalysis> {
-        let prompt = format!(
-            r#"You are an instant analysis engine for a voice assistant. Be extremely concise.
-
-INPUT: What someone just said in a conversation
-CONTEXT: {context}
-
-THEIR STATEMENT: "{transcript}"
-
-Respond with ONLY valid JSON, no explanation, no markdown:
-
-{{
-  "summary": "One short sentence: what they're asking/saying",
-  "bullets": [
-    {{"point": "Most important thing to say", "priority": 1}},
-    {{"point": "Second point", "priority": 2}},
-    {{"point": "Third point if needed", "priority": 3}}
-  ],
-  "type": "question",
-  "urgency": "answer_now"
-}}
-
-Rules:
-- type must be one of: question, objection, statement, buying_signal, technical, small_talk
-- urgency must be one of: answer_now, can_elaborate, just_listening
-- Max 4 bullets, keep each under 15 words
-- Priority 1 = most important
-- Be specific to their actual words
-- Output ONLY the JSON, nothing else"#
-        );
+    pub async fn analyze(&self, transcript: &str, context: &str) -> Result<FlashAnalysis, FlashError> {
+        let prompt = flash_prompt(transcript, context);
 
         let request = OllamaRequest {
             model: self.model.clone(),
@@ -164,27 +158,14 @@ Rules:
             .await?;
 
         if !response.status().is_success() {
-            let status = response.status();
+            let status = response.status().as_u16();
             let body = response.text().await.unwrap_or_default();
-            return Err(anyhow::anyhow!(
-                "Ollama request failed ({}): {}",
-                status,
-                body
-            ));
+            return Err(FlashError::from_status(status, &body));
         }
 
         let ollama_response: OllamaResponse = response.json().await?;
 
-        // Parse the JSON response
-        let analysis: FlashAnalysis = serde_json::from_str(&ollama_response.response)
-            .map_err(|e| {
-                tracing::warn!(
-                    "Failed to parse Ollama response as JSON: {}\nRaw response: {}",
-                    e,
-                    ollama_response.response
-                );
-                anyhow::anyhow!("Invalid JSON from Ollama: {}", e)
-            })?;
+        let mut analysis = parse_analysis(&ollama_response.response)?;
 
         tracing::debug!(
             "Ollama analysis completed in {}ms, {} tokens",
@@ -192,6 +173,80 @@ Rules:
             ollama_response.eval_count
         );
 
+        analysis.usage = Some(TokenUsage::new(
+            ollama_response.prompt_eval_count as u64,
+            ollama_response.eval_count as u64,
+        ));
+
+        Ok(analysis)
+    }
+
+    /// Analyze transcript, sending each bullet to `bullets` as soon as it's
+    /// generated
+    ///
+    /// Returns the whole analysis once the model finishes, as `analyze` does.
+    pub async fn analyze_streaming(
+        &self,
+        transcript: &str,
+        context: &str,
+        bullets: mpsc::Sender<Bullet>,
+    ) -> Result<FlashAnalysis, FlashError> {
+        let request = OllamaRequest {
+            model: self.model.clone(),
+            prompt: flash_prompt(transcript, context),
+            stream: true,
+            format: Some("json".to_string()),
+            options: OllamaOptions {
+                temperature: 0.3,
+                num_predict: 300,
+                top_p: 0.9,
+            },
+        };
+
+        let url = format!("{}/api/generate", self.base_url);
+
+        let response = self
+            .client
+            .post(&url)
+            .json(&request)
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status().as_u16();
+            let body = response.text().await.unwrap_or_default();
+            return Err(FlashError::from_status(status, &body));
+        }
+
+        let mut bytes = response.bytes_stream();
+        let mut line_buffer: Vec<u8> = Vec::new();
+        let mut parser = BulletStreamParser::default();
+        let mut usage = None;
+
+        'stream: while let Some(chunk) = bytes.next().await {
+            line_buffer.extend_from_slice(&chunk?);
+
+            // NDJSON lines can be split across network chunks
+            while let Some(newline) = line_buffer.iter().position(|&b| b == b'\n') {
+                let line: Vec<u8> = line_buffer.drain(..=newline).collect();
+                let Ok(parsed) = serde_json::from_slice::<OllamaStreamChunk>(&line) else {
+                    continue;
+                };
+
+                for bullet in parser.push(&parsed.response) {
+                    // Nobody watching; the full analysis is still returned
+                    let _ = bullets.send(bullet).await;
+                }
+
+                if parsed.done {
+                    usage = Some(TokenUsage::new(parsed.prompt_eval_count as u64, parsed.eval_count as u64));
+                    break 'stream;
+                }
+            }
+        }
+
+        let mut analysis = parse_analysis(&parser.buffer)?;
+        analysis.usage = usage;
         Ok(analysis)
     }
 
@@ -217,12 +272,116 @@ Rules:
     }
 }
 
+/// The model's JSON output as an analysis
+fn parse_analysis(text: &str) -> Result<FlashAnalysis, FlashError> {
+    serde_json::from_str(extract_json(text).unwrap_or(text)).map_err(|e| {
+        tracing::warn!("Failed to parse Ollama response as JSON: {}\nRaw response: {}", e, text);
+        FlashError::Parse(format!("Invalid JSON from Ollama: {}", e))
+    })
+}
+
+/// Prompt shared by the blocking and streaming analysis calls
+fn flash_prompt(transcript: &str, context: &str) -> String {
+    format!(
+        r#"You are an instant analysis engine for a voice assistant. Be extremely concise.
+
+INPUT: What someone just said in a conversation
+CONTEXT: {context}
+
+THEIR STATEMENT: "{transcript}"
+
+Respond with ONLY valid JSON, no explanation, no markdown:
+
+{{
+  "summary": "One short sentence: what they're asking/saying",
+  "bullets": [
+    {{"point": "Most important thing to say", "priority": 1}},
+    {{"point": "Second point", "priority": 2}},
+    {{"point": "Third point if needed", "priority": 3}}
+  ],
+  "type": "question",
+  "urgency": "answer_now"
+}}
+
+Rules:
+- type must be one of: question, objection, statement, buying_signal, technical, small_talk
+- urgency must be one of: answer_now, can_elaborate, just_listening
+- Max 4 bullets, keep each under 15 words
+- Priority 1 = most important
+- Be specific to their actual words
+- Output ONLY the JSON, nothing else"#
+    )
+}
+
 impl Default for OllamaFlash {
     fn default() -> Self {
         Self::new()
     }
 }
 
+/// Incrementally pulls completed bullets out of partially generated JSON
+#[derive(Debug, Default)]
+struct BulletStreamParser {
+    /// Generated text so far
+    buffer: String,
+    /// Byte offset just inside the bullets array, once found
+    cursor: Option<usize>,
+    /// Closing bracket of the bullets array has been seen
+    finished: bool,
+}
+
+impl BulletStreamParser {
+    /// Append generated text and return any bullets completed by it
+    fn push(&mut self, text: &str) -> Vec<Bullet> {
+        self.buffer.push_str(text);
+
+        let mut bullets = Vec::new();
+        if self.finished {
+            return bullets;
+        }
+
+        if self.cursor.is_none() {
+            self.cursor = self.find_array_start();
+        }
+
+        while let Some(cursor) = self.cursor {
+            let rest = &self.buffer[cursor..];
+            let trimmed = rest.trim_start_matches(|c: char| c.is_whitespace() || c == ',');
+            let skipped = rest.len() - trimmed.len();
+
+            if trimmed.starts_with(']') {
+                self.finished = true;
+                break;
+            }
+            if !trimmed.starts_with('{') {
+                break;
+            }
+
+            // Object still being generated
+            let Some(object) = extract_json(trimmed) else {
+                break;
+            };
+
+            if let Ok(bullet) = serde_json::from_str::<Bullet>(object) {
+                bullets.push(bullet);
+            }
+            self.cursor = Some(cursor + skipped + object.len());
+        }
+
+        bullets
+    }
+
+    /// Locate the position just after `"bullets": [`
+    fn find_array_start(&self) -> Option<usize> {
+        let key = self.buffer.find("\"bullets\"")? + "\"bullets\"".len();
+        let rest = self.buffer[key..].trim_start();
+        let rest = rest.strip_prefix(':')?.trim_start();
+        rest.strip_prefix('[')?;
+
+        Some(self.buffer.len() - rest.len() + 1)
+    }
+}
+
 /// Check Ollama server status and available models
 pub async fn check_ollama_status() -> OllamaStatus {
     let client = OllamaFlash::new();
@@ -287,6 +446,21 @@ impl OllamaStatus {
     }
 }
 
+#[async_trait]
+impl FlashProvider for OllamaFlash {
+    async fn analyze(&self, transcript: &str, context: &str) -> Result<FlashAnalysis, FlashError> {
+        OllamaFlash::analyze(self, transcript, context).await
+    }
+
+    fn name(&self) -> &str {
+        &self.model
+    }
+
+    fn is_local(&self) -> bool {
+        true
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -322,6 +496,57 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_bullet_stream_parser_across_chunks() {
+        let full = r#"{"summary": "Asking about {price}", "bullets": [{"point": "Quote the [tier]", "priority": 1}, {"point": "Mention \"discounts\"", "priority": 2}], "type": "question", "urgency": "answer_now"}"#;
+
+        // Feed a few characters at a time to split objects mid-string
+        let mut parser = BulletStreamParser::default();
+        let mut bullets = Vec::new();
+        let chars: Vec<char> = full.chars().collect();
+        for piece in chars.chunks(3) {
+            bullets.extend(parser.push(&piece.iter().collect::<String>()));
+        }
+
+        assert_eq!(bullets.len(), 2);
+        assert_eq!(bullets[0].point, "Quote the [tier]");
+        assert_eq!(bullets[1].point, "Mention \"discounts\"");
+        assert_eq!(bullets[1].priority, 2);
+        assert!(parser.finished);
+    }
+
+    #[test]
+    fn test_bullet_stream_parser_emits_before_done() {
+        let mut parser = BulletStreamParser::default();
+        assert!(parser.push(r#"{"summary": "x", "bullets": [{"point": "First","#).is_empty());
+
+        let bullets = parser.push(r#" "priority": 1}, {"point": "Sec"#);
+        assert_eq!(bullets.len(), 1);
+        assert_eq!(bullets[0].point, "First");
+    }
+
+    #[tokio::test]
+    #[ignore] // Requires Ollama running with llama3.1
+    async fn test_ollama_analyze_streaming() {
+        let client = OllamaFlash::new();
+        let (tx, mut rx) = mpsc::channel(8);
+        let analysis = client
+            .analyze_streaming(
+                "How much does your enterprise plan cost?",
+                "Sales call for SaaS product",
+                tx,
+            )
+            .await
+            .unwrap();
+
+        let mut count = 0;
+        while let Some(bullet) = rx.recv().await {
+            println!("Bullet: {:?}", bullet);
+            count += 1;
+        }
+        assert_eq!(count, analysis.bullets.len());
+    }
+
     #[tokio::test]
     #[ignore] // Requires Ollama running
     async fn test_ollama_status() {
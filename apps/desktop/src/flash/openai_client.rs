@@ -0,0 +1,136 @@
+//! OpenAI Client
+//!
+//! Chat completions against api.openai.com (bearer auth), an Azure
+//! OpenAI deployment (`api-key` header, per-deployment URL), or OpenRouter
+//! (OpenAI-compatible API with attribution headers).
+
+use async_openai::{
+    config::{AzureConfig, OpenAIConfig},
+    error::OpenAIError,
+    types::{ChatCompletionResponseStream, CreateChatCompletionRequest, CreateChatCompletionResponse},
+    Client,
+};
+use reqwest::header::{HeaderMap, HeaderValue};
+use serde::{Deserialize, Serialize};
+
+/// Azure OpenAI REST API version
+pub const DEFAULT_AZURE_API_VERSION: &str = "2024-06-01";
+
+/// OpenRouter's OpenAI-compatible API base (chat completions at `/chat/completions`)
+pub const OPENROUTER_API_BASE: &str = "https://openrouter.ai/api/v1";
+
+/// Sent as `HTTP-Referer` so OpenRouter can attribute requests to the app
+const OPENROUTER_REFERER: &str = "https://github.com/AhmediHarhash/voice-copilot";
+
+/// Sent as `X-Title`
+const OPENROUTER_TITLE: &str = "Voice Copilot";
+
+/// Azure OpenAI deployment settings
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AzureOpenAIConfig {
+    /// Resource endpoint, e.g. `https://my-resource.openai.azure.com`
+    pub endpoint: String,
+    /// Deployment name; Azure picks the model from this, not the request
+    pub deployment: String,
+    pub api_version: String,
+}
+
+impl AzureOpenAIConfig {
+    pub fn new(endpoint: impl Into<String>, deployment: impl Into<String>) -> Self {
+        Self {
+            endpoint: endpoint.into().trim_end_matches('/').to_string(),
+            deployment: deployment.into(),
+            api_version: DEFAULT_AZURE_API_VERSION.to_string(),
+        }
+    }
+
+    /// Full chat completions URL for this deployment
+    pub fn chat_completions_url(&self) -> String {
+        format!(
+            "{}/openai/deployments/{}/chat/completions?api-version={}",
+            self.endpoint, self.deployment, self.api_version
+        )
+    }
+}
+
+/// OpenAI or Azure OpenAI chat client
+#[derive(Clone)]
+pub enum OpenAIClient {
+    OpenAI(Client<OpenAIConfig>),
+    Azure(Client<AzureConfig>),
+}
+
+impl OpenAIClient {
+    /// Standard OpenAI client
+    pub fn new(api_key: impl Into<String>) -> Self {
+        Self::OpenAI(Client::with_config(OpenAIConfig::new().with_api_key(api_key)))
+    }
+
+    /// Client for an Azure deployment
+    pub fn azure(api_key: impl Into<String>, azure: &AzureOpenAIConfig) -> Self {
+        let config = AzureConfig::new()
+            .with_api_base(&azure.endpoint)
+            .with_deployment_id(&azure.deployment)
+            .with_api_version(&azure.api_version)
+            .with_api_key(api_key);
+        Self::Azure(Client::with_config(config))
+    }
+
+    /// Client for any other OpenAI-compatible API (bearer auth)
+    pub fn compatible(api_base: &str, api_key: impl Into<String>) -> Self {
+        let config = OpenAIConfig::new()
+            .with_api_base(api_base)
+            .with_api_key(api_key);
+        Self::OpenAI(Client::with_config(config))
+    }
+
+    /// Client for OpenRouter; the model string picks the provider
+    pub fn openrouter(api_key: impl Into<String>) -> Self {
+        let config = OpenAIConfig::new()
+            .with_api_base(OPENROUTER_API_BASE)
+            .with_api_key(api_key);
+        Self::OpenAI(Client::with_config(config).with_http_client(openrouter_http_client()))
+    }
+
+    /// Create a chat completion
+    pub async fn create(&self, request: CreateChatCompletionRequest) -> Result<CreateChatCompletionResponse, OpenAIError> {
+        match self {
+            Self::OpenAI(client) => client.chat().create(request).await,
+            Self::Azure(client) => client.chat().create(request).await,
+        }
+    }
+
+    /// Create a streaming chat completion
+    pub async fn create_stream(&self, request: CreateChatCompletionRequest) -> Result<ChatCompletionResponseStream, OpenAIError> {
+        match self {
+            Self::OpenAI(client) => client.chat().create_stream(request).await,
+            Self::Azure(client) => client.chat().create_stream(request).await,
+        }
+    }
+}
+
+/// HTTP client that adds OpenRouter's attribution headers to every request
+fn openrouter_http_client() -> reqwest::Client {
+    let mut headers = HeaderMap::new();
+    headers.insert("http-referer", HeaderValue::from_static(OPENROUTER_REFERER));
+    headers.insert("x-title", HeaderValue::from_static(OPENROUTER_TITLE));
+
+    reqwest::Client::builder()
+        .default_headers(headers)
+        .build()
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_azure_url() {
+        let azure = AzureOpenAIConfig::new("https://acme.openai.azure.com/", "gpt4o-prod");
+        assert_eq!(
+            azure.chat_completions_url(),
+            "https://acme.openai.azure.com/openai/deployments/gpt4o-prod/chat/completions?api-version=2024-06-01"
+        );
+    }
+}
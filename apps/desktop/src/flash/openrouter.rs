@@ -0,0 +1,148 @@
+//! OpenRouter Flash Integration
+//!
+//! Quick bullet extraction through OpenRouter, so any hosted model
+//! (e.g. `google/gemini-2.0-flash-001`) works with a single API key.
+
+use anyhow::Result;
+use async_trait::async_trait;
+use async_openai::types::{
+    ChatCompletionRequestMessage, ChatCompletionRequestSystemMessageArgs,
+    ChatCompletionRequestUserMessageArgs, CreateChatCompletionRequestArgs,
+};
+
+use super::bullet_extractor::{extract_json, FlashAnalysis};
+use super::provider::FlashProvider;
+use super::openai_client::OpenAIClient;
+use super::retry::{with_backoff, DEFAULT_MAX_RETRIES};
+use crate::cost::TokenUsage;
+use crate::error::FlashError;
+
+/// Default flash model when routing through OpenRouter
+pub const DEFAULT_OPENROUTER_FLASH_MODEL: &str = "google/gemini-2.0-flash-001";
+
+/// OpenRouter flash client
+pub struct OpenRouterClient {
+    client: OpenAIClient,
+    model: String,
+    max_retries: u32,
+}
+
+impl OpenRouterClient {
+    /// Create a new OpenRouter client for a model like `anthropic/claude-3.5-haiku`
+    pub fn new(api_key: impl Into<String>, model: impl Into<String>) -> Self {
+        Self {
+            client: OpenAIClient::openrouter(api_key),
+            model: model.into(),
+            max_retries: DEFAULT_MAX_RETRIES,
+        }
+    }
+
+    /// Set how many times transient failures are retried
+    pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Analyze transcript and extract quick response bullets
+    pub async fn analyze(&self, transcript: &str, context: &str) -> Result<FlashAnalysis, FlashError> {
+        with_backoff(self.max_retries, || self.analyze_once(transcript, context))
+            .await
+            .map_err(FlashError::from)
+    }
+
+    /// Single analysis request without retries
+    async fn analyze_once(&self, transcript: &str, context: &str) -> Result<FlashAnalysis> {
+        let system_prompt = r#"You are an instant analysis engine. Respond in <200ms.
+
+OUTPUT: JSON only, no explanation
+
+{
+  "summary": "One sentence: what they're asking/saying",
+  "bullets": [
+    {"point": "Key thing to mention", "priority": 1},
+    {"point": "Another point", "priority": 2},
+    {"point": "Supporting detail", "priority": 3}
+  ],
+  "type": "question|objection|statement|buying_signal|technical|small_talk",
+  "urgency": "answer_now|can_elaborate|just_listening"
+}
+
+Rules:
+- Max 5 bullets
+- Priority 1 = say this first (most important)
+- Be specific, not generic
+- Under 50 tokens total"#;
+
+        let user_prompt = format!(
+            "CONTEXT: {}\n\nTHEIR STATEMENT: \"{}\"",
+            context, transcript
+        );
+
+        // Not every routed model supports JSON mode, so rely on extract_json
+        let request = CreateChatCompletionRequestArgs::default()
+            .model(&self.model)
+            .messages(vec![
+                ChatCompletionRequestMessage::System(
+                    ChatCompletionRequestSystemMessageArgs::default()
+                        .content(system_prompt)
+                        .build()?,
+                ),
+                ChatCompletionRequestMessage::User(
+                    ChatCompletionRequestUserMessageArgs::default()
+                        .content(user_prompt)
+                        .build()?,
+                ),
+            ])
+            .max_tokens(200u32)
+            .temperature(0.3)
+            .build()?;
+
+        let response = self.client.create(request).await?;
+
+        if let Some(choice) = response.choices.first() {
+            if let Some(content) = &choice.message.content {
+                let mut analysis: FlashAnalysis = serde_json::from_str(extract_json(content).unwrap_or(content))?;
+                analysis.usage = response
+                    .usage
+                    .as_ref()
+                    .map(|u| TokenUsage::new(u.prompt_tokens as u64, u.completion_tokens as u64));
+                return Ok(analysis);
+            }
+        }
+
+        Err(FlashError::Parse(format!("No response from OpenRouter ({})", self.model)).into())
+    }
+}
+
+#[async_trait]
+impl FlashProvider for OpenRouterClient {
+    async fn analyze(&self, transcript: &str, context: &str) -> Result<FlashAnalysis, FlashError> {
+        OpenRouterClient::analyze(self, transcript, context).await
+    }
+
+    fn name(&self) -> &str {
+        &self.model
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    #[ignore] // Requires API key
+    async fn test_openrouter_flash_analyze() {
+        let client = OpenRouterClient::new("YOUR_API_KEY", DEFAULT_OPENROUTER_FLASH_MODEL);
+        let result = client
+            .analyze(
+                "How much does your enterprise plan cost?",
+                "Sales call for SaaS product",
+            )
+            .await;
+
+        assert!(result.is_ok());
+        let analysis = result.unwrap();
+        assert!(!analysis.summary.is_empty());
+        assert!(!analysis.bullets.is_empty());
+    }
+}
@@ -0,0 +1,25 @@
+//! Flash Provider Trait
+//!
+//! Common interface over the flash clients, so a router can hold any
+//! backend as a trait object and new providers drop in without new
+//! match arms.
+
+use async_trait::async_trait;
+
+use super::bullet_extractor::FlashAnalysis;
+use crate::error::FlashError;
+
+/// A backend that extracts quick response bullets
+#[async_trait]
+pub trait FlashProvider: Send + Sync {
+    /// Analyze transcript and extract quick response bullets
+    async fn analyze(&self, transcript: &str, context: &str) -> Result<FlashAnalysis, FlashError>;
+
+    /// Model name, e.g. "gemini-2.0-flash"
+    fn name(&self) -> &str;
+
+    /// Whether the model runs on this machine
+    fn is_local(&self) -> bool {
+        false
+    }
+}
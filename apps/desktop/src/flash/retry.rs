@@ -0,0 +1,169 @@
+//! Retry With Backoff
+//!
+//! Shared retry helper for flash clients. Transient failures (rate limits,
+//! 5xx) are retried with jittered exponential backoff; config errors fail fast.
+
+use anyhow::Result;
+use std::future::Future;
+use std::time::Duration;
+
+/// Default number of retries after the first attempt
+pub const DEFAULT_MAX_RETRIES: u32 = 3;
+
+/// Delay before the first retry
+const BASE_DELAY_MS: u64 = 250;
+
+/// Status codes worth retrying
+const RETRYABLE_STATUS: [u16; 4] = [429, 500, 502, 503];
+
+/// Non-success HTTP response from a provider
+#[derive(Debug)]
+pub struct HttpStatusError {
+    pub status: u16,
+    pub body: String,
+}
+
+impl std::fmt::Display for HttpStatusError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "HTTP {}: {}", self.status, self.body)
+    }
+}
+
+impl std::error::Error for HttpStatusError {}
+
+/// Run an operation, retrying transient failures
+///
+/// Makes at most `max_retries + 1` attempts and returns the last error.
+pub async fn with_backoff<T, F, Fut>(max_retries: u32, mut operation: F) -> Result<T>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    let mut attempt = 0;
+
+    loop {
+        match operation().await {
+            Ok(value) => return Ok(value),
+            Err(e) if attempt < max_retries && is_retryable(&e) => {
+                let delay = backoff_delay(attempt);
+                tracing::warn!(
+                    "Flash request failed ({}), retrying in {}ms ({}/{})",
+                    e,
+                    delay.as_millis(),
+                    attempt + 1,
+                    max_retries
+                );
+                tokio::time::sleep(delay).await;
+                attempt += 1;
+            }
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+/// Check whether an error is transient
+pub fn is_retryable(err: &anyhow::Error) -> bool {
+    if let Some(e) = err.downcast_ref::<HttpStatusError>() {
+        return RETRYABLE_STATUS.contains(&e.status);
+    }
+
+    if let Some(e) = err.downcast_ref::<reqwest::Error>() {
+        return is_retryable_reqwest(e);
+    }
+
+    if let Some(e) = err.downcast_ref::<async_openai::error::OpenAIError>() {
+        use async_openai::error::OpenAIError;
+
+        return match e {
+            OpenAIError::Reqwest(e) => is_retryable_reqwest(e),
+            OpenAIError::ApiError(api) => {
+                api.code.as_deref() == Some("rate_limit_exceeded")
+                    || api.r#type.as_deref() == Some("server_error")
+            }
+            _ => false,
+        };
+    }
+
+    false
+}
+
+fn is_retryable_reqwest(e: &reqwest::Error) -> bool {
+    match e.status() {
+        Some(status) => RETRYABLE_STATUS.contains(&status.as_u16()),
+        None => e.is_timeout() || e.is_connect(),
+    }
+}
+
+/// Exponential delay for a retry attempt with up to 50% random jitter
+fn backoff_delay(attempt: u32) -> Duration {
+    let base = BASE_DELAY_MS * 2u64.pow(attempt);
+
+    let mut bytes = [0u8; 4];
+    let jitter = match getrandom::getrandom(&mut bytes) {
+        Ok(()) => u32::from_le_bytes(bytes) as u64 % (base / 2 + 1),
+        Err(_) => 0,
+    };
+
+    Duration::from_millis(base + jitter)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    fn status_error(status: u16) -> anyhow::Error {
+        HttpStatusError {
+            status,
+            body: String::new(),
+        }
+        .into()
+    }
+
+    #[test]
+    fn test_retryable_status() {
+        assert!(is_retryable(&status_error(429)));
+        assert!(is_retryable(&status_error(503)));
+        assert!(!is_retryable(&status_error(400)));
+        assert!(!is_retryable(&status_error(401)));
+        assert!(!is_retryable(&anyhow::anyhow!("bad json")));
+    }
+
+    #[test]
+    fn test_backoff_delay() {
+        for attempt in 0..3 {
+            let base = BASE_DELAY_MS * 2u64.pow(attempt);
+            let delay = backoff_delay(attempt).as_millis() as u64;
+            assert!(delay >= base && delay <= base + base / 2);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_no_retry_on_client_error() {
+        let attempts = AtomicU32::new(0);
+        let result: Result<()> = with_backoff(3, || async {
+            attempts.fetch_add(1, Ordering::SeqCst);
+            Err(status_error(401))
+        })
+        .await;
+
+        assert!(result.is_err());
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_retries_then_succeeds() {
+        let attempts = AtomicU32::new(0);
+        let result = with_backoff(1, || async {
+            if attempts.fetch_add(1, Ordering::SeqCst) == 0 {
+                Err(status_error(503))
+            } else {
+                Ok(42)
+            }
+        })
+        .await;
+
+        assert_eq!(result.unwrap(), 42);
+        assert_eq!(attempts.load(Ordering::SeqCst), 2);
+    }
+}
@@ -0,0 +1,189 @@
+//! Localization
+//!
+//! The languages calls can be run in and the overlay text shown during a
+//! call. Lookups fall back to English, so an untranslated key shows the
+//! English text rather than nothing.
+
+use serde::{Deserialize, Serialize};
+
+/// Language for suggestions and the overlay
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Language {
+    #[default]
+    #[serde(rename = "en")]
+    English,
+    #[serde(rename = "es")]
+    Spanish,
+    #[serde(rename = "de")]
+    German,
+}
+
+impl Language {
+    /// All languages, in picker order
+    pub fn all() -> Vec<Self> {
+        vec![Self::English, Self::Spanish, Self::German]
+    }
+
+    /// ISO 639-1 code, e.g. "es"
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::English => "en",
+            Self::Spanish => "es",
+            Self::German => "de",
+        }
+    }
+
+    /// Language for a code, English if it isn't supported
+    pub fn from_code(code: &str) -> Self {
+        Self::all()
+            .into_iter()
+            .find(|language| language.code().eq_ignore_ascii_case(code.trim()))
+            .unwrap_or_default()
+    }
+
+    /// Name in the language itself, for the picker and the
+    /// `{{language}}` prompt variable
+    pub fn native_name(&self) -> &'static str {
+        match self {
+            Self::English => "English",
+            Self::Spanish => "español",
+            Self::German => "Deutsch",
+        }
+    }
+
+    /// Line added to the built-in English prompts (None for English)
+    pub fn instruction(&self) -> Option<String> {
+        let name = match self {
+            Self::English => return None,
+            Self::Spanish => "Spanish",
+            Self::German => "German",
+        };
+        Some(format!(
+            "Write your whole answer in {}, keeping JSON keys and enum values in English.",
+            name
+        ))
+    }
+}
+
+const EN: &[(&str, &str)] = &[
+    ("status.ready", "Ready"),
+    ("status.connecting", "Connecting..."),
+    ("status.listening", "Listening"),
+    ("status.reconnecting", "Reconnecting..."),
+    ("status.paused", "Paused"),
+    ("status.error", "Error"),
+    ("mode.label", "Mode:"),
+    ("mode.sales", "Sales"),
+    ("mode.interview", "Interview"),
+    ("mode.technical", "Technical"),
+    ("mode.meeting", "Meeting"),
+    ("view.full", "Full"),
+    ("view.overlay", "Overlay"),
+    ("view.mini", "Mini"),
+    ("button.start", "▶ Start Listening"),
+    ("button.stop", "⏹ Stop Listening"),
+    ("button.pause", "⏸ Pause"),
+    ("button.resume", "▶ Resume"),
+    ("button.raw", "Raw"),
+    ("button.formatted", "Formatted"),
+    ("flash.header", "QUICK RESPONSE"),
+    ("deep.header", "DETAILED ANSWER"),
+    ("deep.ask", "🔄 ASK THEM:"),
+    ("empty.title", "Select an audio source and click Start"),
+    ("empty.hint", "Pick Zoom, Discord, Teams, or any app to capture only that audio"),
+];
+
+const ES: &[(&str, &str)] = &[
+    ("status.ready", "Listo"),
+    ("status.connecting", "Conectando..."),
+    ("status.listening", "Escuchando"),
+    ("status.reconnecting", "Reconectando..."),
+    ("status.paused", "En pausa"),
+    ("status.error", "Error"),
+    ("mode.label", "Modo:"),
+    ("mode.sales", "Ventas"),
+    ("mode.interview", "Entrevista"),
+    ("mode.technical", "Técnico"),
+    ("mode.meeting", "Reunión"),
+    ("view.full", "Completo"),
+    ("view.overlay", "Superpuesto"),
+    ("view.mini", "Mini"),
+    ("button.start", "▶ Empezar a escuchar"),
+    ("button.stop", "⏹ Dejar de escuchar"),
+    ("button.pause", "⏸ Pausar"),
+    ("button.resume", "▶ Reanudar"),
+    ("button.raw", "Texto"),
+    ("button.formatted", "Formato"),
+    ("flash.header", "RESPUESTA RÁPIDA"),
+    ("deep.header", "RESPUESTA DETALLADA"),
+    ("deep.ask", "🔄 PREGÚNTALES:"),
+    ("empty.title", "Elige una fuente de audio y pulsa Empezar"),
+    ("empty.hint", "Elige Zoom, Discord, Teams o cualquier app para capturar solo ese audio"),
+];
+
+const DE: &[(&str, &str)] = &[
+    ("status.ready", "Bereit"),
+    ("status.connecting", "Verbinde..."),
+    ("status.listening", "Hört zu"),
+    ("status.reconnecting", "Verbinde neu..."),
+    ("status.paused", "Pausiert"),
+    ("status.error", "Fehler"),
+    ("mode.label", "Modus:"),
+    ("mode.sales", "Vertrieb"),
+    ("mode.interview", "Interview"),
+    ("mode.technical", "Technik"),
+    ("mode.meeting", "Meeting"),
+    ("view.full", "Voll"),
+    ("view.overlay", "Overlay"),
+    ("view.mini", "Mini"),
+    ("button.start", "▶ Zuhören starten"),
+    ("button.stop", "⏹ Zuhören beenden"),
+    ("button.pause", "⏸ Pause"),
+    ("button.resume", "▶ Fortsetzen"),
+    ("button.raw", "Rohtext"),
+    ("button.formatted", "Formatiert"),
+    ("flash.header", "SCHNELLE ANTWORT"),
+    ("deep.header", "AUSFÜHRLICHE ANTWORT"),
+    ("deep.ask", "🔄 FRAG SIE:"),
+    ("empty.title", "Audioquelle wählen und auf Starten klicken"),
+    ("empty.hint", "Wähle Zoom, Discord, Teams oder eine andere App, um nur deren Audio aufzunehmen"),
+];
+
+fn lookup(table: &[(&str, &'static str)], key: &str) -> Option<&'static str> {
+    table.iter().find(|(k, _)| *k == key).map(|(_, text)| *text)
+}
+
+/// Overlay text for a key, falling back to English and then the key itself
+pub fn tr(language: Language, key: &'static str) -> &'static str {
+    let table = match language {
+        Language::English => EN,
+        Language::Spanish => ES,
+        Language::German => DE,
+    };
+    lookup(table, key).or_else(|| lookup(EN, key)).unwrap_or(key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tr_falls_back_to_english() {
+        assert_eq!(tr(Language::Spanish, "status.listening"), "Escuchando");
+        assert_eq!(tr(Language::German, "flash.header"), "SCHNELLE ANTWORT");
+        assert_eq!(tr(Language::German, "no.such.key"), "no.such.key");
+
+        // Every translated key exists in English
+        for (key, _) in ES.iter().chain(DE.iter()) {
+            assert!(lookup(EN, key).is_some(), "{}", key);
+        }
+    }
+
+    #[test]
+    fn test_language_codes() {
+        assert_eq!(Language::from_code("DE"), Language::German);
+        assert_eq!(Language::from_code("fr"), Language::English);
+        assert_eq!(serde_json::to_string(&Language::Spanish).unwrap(), "\"es\"");
+        assert!(Language::English.instruction().is_none());
+    }
+}
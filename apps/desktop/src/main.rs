@@ -13,6 +13,11 @@ mod voice;
 mod analytics;
 mod prompts;
 mod recording;
+mod cost;
+mod redaction;
+mod i18n;
+mod error;
+mod diagnostics;
 pub mod updater;
 
 use anyhow::Result;
@@ -2,9 +2,11 @@
 //!
 //! Utilities for editing and validating prompts.
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use regex::Regex;
 
+use super::{apply_variables, PromptCategory};
+
 /// Prompt editor utilities
 pub struct PromptEditor;
 
@@ -23,8 +25,73 @@ impl PromptEditor {
         variables.into_iter().collect()
     }
 
-    /// Validate a prompt template
-    pub fn validate(template: &str) -> ValidationResult {
+    /// Validate a prompt template against the variables every prompt needs
+    pub fn validate(template: &str) -> Result<(), Vec<PromptError>> {
+        Self::validate_for(template, &PromptCategory::Custom)
+    }
+
+    /// Validate a prompt template for a category
+    ///
+    /// Reports unclosed `{{`, malformed or unknown variables, and required
+    /// variables the category expects but the template never uses.
+    pub fn validate_for(template: &str, category: &PromptCategory) -> Result<(), Vec<PromptError>> {
+        if template.trim().is_empty() {
+            return Err(vec![PromptError::Empty]);
+        }
+
+        let known: Vec<String> = PromptVariable::standard_variables()
+            .into_iter()
+            .map(|v| v.name)
+            .collect();
+
+        let mut errors = Vec::new();
+        let mut used: HashSet<&str> = HashSet::new();
+        let mut rest = template;
+        let mut offset = 0;
+
+        while let Some(start) = rest.find("{{") {
+            let after = &rest[start + 2..];
+            let next_open = after.find("{{").unwrap_or(after.len());
+
+            // A closer only counts if it comes before the next opener
+            let end = match after.find("}}") {
+                Some(end) if end < next_open => end,
+                _ => {
+                    errors.push(PromptError::UnclosedBraces { position: offset + start });
+                    offset += start + 2;
+                    rest = after;
+                    continue;
+                }
+            };
+
+            let name = &after[..end];
+            if name.is_empty() || !name.chars().all(|c| c.is_alphanumeric() || c == '_') {
+                errors.push(PromptError::MalformedVariable(name.to_string()));
+            } else if !known.iter().any(|k| k == name) {
+                errors.push(PromptError::UnknownVariable(name.to_string()));
+            } else {
+                used.insert(name);
+            }
+
+            offset += start + 2 + end + 2;
+            rest = &after[end + 2..];
+        }
+
+        for required in category.required_variables() {
+            if !used.contains(required) {
+                errors.push(PromptError::MissingVariable(required.to_string()));
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Lint a prompt template for style problems
+    pub fn lint(template: &str) -> ValidationResult {
         let mut errors = Vec::new();
         let mut warnings = Vec::new();
 
@@ -78,16 +145,16 @@ impl PromptEditor {
     }
 
     /// Preview a prompt with sample data
-    pub fn preview(template: &str) -> String {
-        let mut result = template.to_string();
-
-        // Replace with sample data
-        result = result.replace("{{transcript}}", "[What they said...]");
-        result = result.replace("{{context}}", "[Sales call for SaaS product]");
-        result = result.replace("{{history}}", "[Previous conversation turns...]");
-        result = result.replace("{{bullets}}", "- Key point 1\n- Key point 2");
+    ///
+    /// Variables missing from `sample_vars` use each standard variable's example.
+    pub fn preview(template: &str, sample_vars: &HashMap<String, String>) -> String {
+        let mut variables: HashMap<String, String> = PromptVariable::standard_variables()
+            .into_iter()
+            .map(|v| (v.name, v.example))
+            .collect();
+        variables.extend(sample_vars.iter().map(|(k, v)| (k.clone(), v.clone())));
 
-        result
+        apply_variables(template, &variables)
     }
 
     /// Format a prompt for display
@@ -147,10 +214,67 @@ impl PromptVariable {
                 example: "sales".to_string(),
                 required: false,
             },
+            Self {
+                name: "language".to_string(),
+                description: "Language to respond in".to_string(),
+                example: "English".to_string(),
+                required: false,
+            },
+            Self {
+                name: "max_bullets".to_string(),
+                description: "Most quick bullets to give".to_string(),
+                example: "4".to_string(),
+                required: false,
+            },
+            Self {
+                name: "max_bullet_words".to_string(),
+                description: "Most words per quick bullet".to_string(),
+                example: "15".to_string(),
+                required: false,
+            },
+            Self {
+                name: "deep_max_words".to_string(),
+                description: "Word target for the detailed response".to_string(),
+                example: "150".to_string(),
+                required: false,
+            },
         ]
     }
 }
 
+/// Problem that stops a prompt from being saved
+#[derive(Debug, Clone, PartialEq)]
+pub enum PromptError {
+    /// Template has no content
+    Empty,
+    /// `{{` with no matching `}}` (byte offset of the opener)
+    UnclosedBraces { position: usize },
+    /// Braced text that isn't a plain variable name
+    MalformedVariable(String),
+    /// Variable the pipeline never fills in
+    UnknownVariable(String),
+    /// Required variable the template never uses
+    MissingVariable(String),
+}
+
+impl std::fmt::Display for PromptError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PromptError::Empty => write!(f, "Template cannot be empty"),
+            PromptError::UnclosedBraces { position } => {
+                write!(f, "Unclosed {{{{ at character {}", position)
+            }
+            PromptError::MalformedVariable(name) => {
+                write!(f, "Invalid variable {{{{{}}}}} - use letters, digits or _", name)
+            }
+            PromptError::UnknownVariable(name) => write!(f, "Unknown variable {{{{{}}}}}", name),
+            PromptError::MissingVariable(name) => {
+                write!(f, "Missing required variable {{{{{}}}}}", name)
+            }
+        }
+    }
+}
+
 /// Validation result
 #[derive(Debug, Clone)]
 pub struct ValidationResult {
@@ -182,22 +306,58 @@ mod tests {
         let template = r#"Analyze this: "{{transcript}}"
 Context: {{context}}
 Respond in JSON."#;
-        let result = PromptEditor::validate(template);
+        let result = PromptEditor::lint(template);
         assert!(result.is_valid);
     }
 
     #[test]
     fn test_validate_mismatched_braces() {
         let template = "Hello {{name}, your item is ready.";
-        let result = PromptEditor::validate(template);
+        let result = PromptEditor::lint(template);
         assert!(!result.is_valid);
         assert!(result.errors.iter().any(|e| e.contains("Mismatched")));
     }
 
     #[test]
     fn test_validate_empty() {
-        let result = PromptEditor::validate("");
+        let result = PromptEditor::lint("");
         assert!(!result.is_valid);
+        assert_eq!(PromptEditor::validate(""), Err(vec![PromptError::Empty]));
+    }
+
+    #[test]
+    fn test_validate_errors() {
+        assert!(PromptEditor::validate("They said: \"{{transcript}}\"").is_ok());
+
+        let errors = PromptEditor::validate("Hi {{name}}, about {{context").unwrap_err();
+        assert!(errors.contains(&PromptError::UnknownVariable("name".to_string())));
+        assert!(errors.contains(&PromptError::UnclosedBraces { position: 19 }));
+        assert!(errors.contains(&PromptError::MissingVariable("transcript".to_string())));
+
+        // Sales prompts also need the conversation context
+        let errors = PromptEditor::validate_for("\"{{transcript}}\"", &PromptCategory::Sales).unwrap_err();
+        assert_eq!(errors, vec![PromptError::MissingVariable("context".to_string())]);
+
+        // Single braces in JSON examples are fine
+        assert!(PromptEditor::validate(r#"{"said": "{{transcript}}"}"#).is_ok());
+    }
+
+    #[test]
+    fn test_default_prompts_validate() {
+        let prompts = crate::prompts::CustomPrompts::default();
+        for (mode, template) in prompts.flash.iter().chain(prompts.deep.iter()) {
+            let category = PromptCategory::from_mode(mode);
+            assert!(PromptEditor::validate_for(template, &category).is_ok(), "{}", mode);
+        }
+    }
+
+    #[test]
+    fn test_preview() {
+        let mut sample = HashMap::new();
+        sample.insert("transcript".to_string(), "Can we start next week?".to_string());
+
+        let preview = PromptEditor::preview("{{context}}: \"{{transcript}}\"", &sample);
+        assert_eq!(preview, "Sales call for SaaS product: \"Can we start next week?\"");
     }
 
     #[test]
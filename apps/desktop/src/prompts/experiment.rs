@@ -0,0 +1,311 @@
+//! Prompt Experiments
+//!
+//! Runs two flash prompt variants over the same transcripts and compares
+//! how many bullets they produce, how often the output parses as flash
+//! JSON and how long the model takes. Results are saved so wording changes
+//! can be compared across sessions.
+
+use anyhow::{anyhow, Result};
+use async_openai::types::{ChatCompletionRequestMessage, ChatCompletionRequestUserMessageArgs, CreateChatCompletionRequestArgs};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::time::Instant;
+
+use super::apply_variables;
+use crate::config::ResponseSettings;
+use crate::i18n::Language;
+use crate::flash::{extract_json, FlashAnalysis, FlashProvider, OllamaFlash, OpenAIClient};
+
+/// Context given to both variants, so only the wording differs
+const EXPERIMENT_CONTEXT: &str = "Sales call";
+
+/// A model that completes a fully rendered prompt
+#[async_trait]
+pub trait PromptProvider: Send + Sync {
+    /// Raw model output
+    async fn complete(&self, prompt: &str) -> Result<String>;
+
+    /// Model name, e.g. "llama3.1:8b"
+    fn name(&self) -> &str;
+}
+
+#[async_trait]
+impl PromptProvider for OllamaFlash {
+    async fn complete(&self, prompt: &str) -> Result<String> {
+        OllamaFlash::complete(self, prompt).await
+    }
+
+    fn name(&self) -> &str {
+        FlashProvider::name(self)
+    }
+}
+
+/// Any OpenAI-compatible chat model (OpenAI, Azure, Groq, OpenRouter)
+pub struct ChatPromptProvider {
+    client: OpenAIClient,
+    model: String,
+}
+
+impl ChatPromptProvider {
+    pub fn new(client: OpenAIClient, model: impl Into<String>) -> Self {
+        Self {
+            client,
+            model: model.into(),
+        }
+    }
+}
+
+#[async_trait]
+impl PromptProvider for ChatPromptProvider {
+    async fn complete(&self, prompt: &str) -> Result<String> {
+        let request = CreateChatCompletionRequestArgs::default()
+            .model(&self.model)
+            .messages(vec![ChatCompletionRequestMessage::User(
+                ChatCompletionRequestUserMessageArgs::default()
+                    .content(prompt)
+                    .build()?,
+            )])
+            .max_tokens(200u32)
+            .temperature(0.3)
+            .build()?;
+
+        let response = self.client.create(request).await?;
+        response
+            .choices
+            .first()
+            .and_then(|choice| choice.message.content.clone())
+            .ok_or_else(|| anyhow!("No response from {}", self.model))
+    }
+
+    fn name(&self) -> &str {
+        &self.model
+    }
+}
+
+/// How one prompt variant did
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct VariantStats {
+    pub prompt: String,
+    /// Transcripts run
+    pub runs: usize,
+    /// Outputs that parsed as flash JSON
+    pub parsed: usize,
+    /// Requests that failed outright
+    pub errors: usize,
+    /// Mean bullets per parsed output
+    pub avg_bullets: f32,
+    /// Mean model latency per completed request
+    pub avg_latency_ms: u64,
+}
+
+impl VariantStats {
+    /// Share of runs whose output parsed (0.0 to 1.0)
+    pub fn parse_rate(&self) -> f32 {
+        if self.runs == 0 {
+            return 0.0;
+        }
+        self.parsed as f32 / self.runs as f32
+    }
+}
+
+/// Running totals while an experiment is in progress
+#[derive(Default)]
+struct Tally {
+    runs: usize,
+    parsed: usize,
+    errors: usize,
+    bullets: usize,
+    completed: u64,
+    latency_ms: u64,
+}
+
+impl Tally {
+    fn record(&mut self, output: Result<String>, latency_ms: u64) {
+        self.runs += 1;
+        let raw = match output {
+            Ok(raw) => raw,
+            Err(e) => {
+                tracing::debug!("Experiment request failed: {}", e);
+                self.errors += 1;
+                return;
+            }
+        };
+
+        self.completed += 1;
+        self.latency_ms += latency_ms;
+        if let Ok(analysis) = serde_json::from_str::<FlashAnalysis>(extract_json(&raw).unwrap_or(&raw)) {
+            self.parsed += 1;
+            self.bullets += analysis.bullets.len();
+        }
+    }
+
+    fn stats(&self, prompt: &str) -> VariantStats {
+        VariantStats {
+            prompt: prompt.to_string(),
+            runs: self.runs,
+            parsed: self.parsed,
+            errors: self.errors,
+            avg_bullets: if self.parsed == 0 { 0.0 } else { self.bullets as f32 / self.parsed as f32 },
+            avg_latency_ms: if self.completed == 0 { 0 } else { self.latency_ms / self.completed },
+        }
+    }
+}
+
+/// A finished A/B comparison
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AbResult {
+    pub id: String,
+    pub run_at: DateTime<Utc>,
+    /// Model both variants ran on
+    pub provider: String,
+    pub transcripts: Vec<String>,
+    pub a: VariantStats,
+    pub b: VariantStats,
+}
+
+impl AbResult {
+    /// Directory experiment results are saved in
+    pub fn dir() -> PathBuf {
+        dirs::config_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join("voice-copilot")
+            .join("experiments")
+    }
+
+    /// Save the result, returning its path
+    pub fn save(&self) -> Result<PathBuf> {
+        let dir = Self::dir();
+        std::fs::create_dir_all(&dir)?;
+        let path = dir.join(format!("{}.json", self.id));
+        std::fs::write(&path, serde_json::to_string_pretty(self)?)?;
+        Ok(path)
+    }
+
+    /// Saved results, newest first
+    pub fn list() -> Result<Vec<Self>> {
+        let dir = Self::dir();
+        if !dir.exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut results: Vec<Self> = std::fs::read_dir(dir)?
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.path().extension().is_some_and(|ext| ext == "json"))
+            .filter_map(|entry| std::fs::read_to_string(entry.path()).ok())
+            .filter_map(|content| serde_json::from_str(&content).ok())
+            .collect();
+        results.sort_by(|a, b| b.run_at.cmp(&a.run_at));
+        Ok(results)
+    }
+
+    /// One line per variant, e.g. "A: 100% parsed, 3.0 bullets, 640ms"
+    pub fn report(&self) -> String {
+        [("A", &self.a), ("B", &self.b)]
+            .iter()
+            .map(|(label, stats)| {
+                format!(
+                    "{}: {:.0}% parsed, {:.1} bullets, {}ms",
+                    label,
+                    stats.parse_rate() * 100.0,
+                    stats.avg_bullets,
+                    stats.avg_latency_ms
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// Fill a flash prompt's variables for one transcript, with the default
+/// length caps and English
+fn render(prompt: &str, transcript: &str) -> String {
+    let mut variables = ResponseSettings::default().prompt_variables();
+    variables.extend([
+        ("transcript".to_string(), transcript.to_string()),
+        ("context".to_string(), EXPERIMENT_CONTEXT.to_string()),
+        ("language".to_string(), Language::English.native_name().to_string()),
+    ]);
+    apply_variables(prompt, &variables)
+}
+
+async fn timed(provider: &dyn PromptProvider, prompt: &str) -> (Result<String>, u64) {
+    let started = Instant::now();
+    let output = provider.complete(prompt).await;
+    (output, started.elapsed().as_millis() as u64)
+}
+
+/// Run both prompts over the same transcripts on one provider
+///
+/// Variants alternate per transcript so warm-up and rate limits affect
+/// both about equally. Failed requests count against the parse rate but
+/// not the latency.
+pub async fn run_ab(
+    transcripts: &[String],
+    prompt_a: &str,
+    prompt_b: &str,
+    provider: &dyn PromptProvider,
+) -> AbResult {
+    let run_at = Utc::now();
+    let mut a = Tally::default();
+    let mut b = Tally::default();
+
+    for transcript in transcripts {
+        let (output, ms) = timed(provider, &render(prompt_a, transcript)).await;
+        a.record(output, ms);
+        let (output, ms) = timed(provider, &render(prompt_b, transcript)).await;
+        b.record(output, ms);
+    }
+
+    AbResult {
+        id: run_at.format("%Y%m%d-%H%M%S").to_string(),
+        run_at,
+        provider: provider.name().to_string(),
+        transcripts: transcripts.to_vec(),
+        a: a.stats(prompt_a),
+        b: b.stats(prompt_b),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Answers JSON only when asked for it
+    struct MockProvider;
+
+    #[async_trait]
+    impl PromptProvider for MockProvider {
+        async fn complete(&self, prompt: &str) -> Result<String> {
+            if prompt.contains("FAIL") {
+                return Err(anyhow!("rate limited"));
+            }
+            if prompt.starts_with("JSON") {
+                Ok(r#"```json
+{"summary": "s", "bullets": [{"point": "a", "priority": 1}, {"point": "b", "priority": 2}], "type": "question", "urgency": "answer_now"}
+```"#.to_string())
+            } else {
+                Ok("Sure! Here are some ideas: mention the pilot.".to_string())
+            }
+        }
+
+        fn name(&self) -> &str {
+            "mock"
+        }
+    }
+
+    #[tokio::test]
+    async fn test_run_ab() {
+        let transcripts = vec!["How much is it?".to_string(), "FAIL".to_string()];
+        let result = run_ab(&transcripts, "JSON only: {{transcript}}", "Reply to {{transcript}}", &MockProvider).await;
+
+        assert_eq!(result.provider, "mock");
+        assert_eq!((result.a.runs, result.a.parsed, result.a.errors), (2, 1, 1));
+        assert_eq!(result.a.avg_bullets, 2.0);
+        assert_eq!(result.a.parse_rate(), 0.5);
+        assert_eq!((result.b.parsed, result.b.avg_bullets), (0, 0.0));
+        assert!(result.report().starts_with("A: 50% parsed, 2.0 bullets"));
+    }
+}
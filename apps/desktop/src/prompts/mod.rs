@@ -5,26 +5,44 @@
 
 mod templates;
 mod editor;
+mod experiment;
+mod locales;
 
 pub use templates::{PromptTemplate, PromptLibrary, PromptCategory};
-pub use editor::{PromptEditor, PromptVariable};
+pub use editor::{PromptEditor, PromptError, PromptVariable};
+pub use experiment::{run_ab, AbResult, ChatPromptProvider, PromptProvider, VariantStats};
 
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+
+use crate::i18n::Language;
+
+/// Version written by `CustomPrompts::export_to_file`
+const EXPORT_VERSION: u32 = 1;
 
 /// Custom prompts configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CustomPrompts {
     /// Flash stage prompts by mode
+    #[serde(default)]
     pub flash: HashMap<String, String>,
     /// Deep stage prompts by mode
+    #[serde(default)]
     pub deep: HashMap<String, String>,
     /// System prompts
+    #[serde(default)]
     pub system: HashMap<String, String>,
 }
 
+/// Shareable prompt set file
+#[derive(Debug, Serialize, Deserialize)]
+struct PromptExport {
+    version: u32,
+    prompts: CustomPrompts,
+}
+
 impl Default for CustomPrompts {
     fn default() -> Self {
         let mut flash = HashMap::new();
@@ -51,7 +69,8 @@ Respond with ONLY valid JSON:
 }
 
 Rules:
-- Max 4 bullets, priority 1 = most important
+- Max {{max_bullets}} bullets of {{max_bullet_words}} words or fewer, priority 1 = most important
+- Write the summary and bullets in {{language}}
 - Be specific to their actual words
 - Focus on sales outcomes"#.to_string());
 
@@ -74,6 +93,8 @@ Respond with ONLY valid JSON:
 }
 
 Rules:
+- Max {{max_bullets}} bullets of {{max_bullet_words}} words or fewer
+- Write the summary and bullets in {{language}}
 - Use STAR method hints where applicable
 - Be specific and relevant"#.to_string());
 
@@ -96,9 +117,83 @@ Respond with ONLY valid JSON:
 }
 
 Rules:
+- Max {{max_bullets}} bullets of {{max_bullet_words}} words or fewer
+- Write the summary and bullets in {{language}}
 - Focus on technical accuracy
 - Include relevant terminology"#.to_string());
 
+        flash.insert("negotiation".to_string(), r#"You are an instant analysis engine for a negotiation. Be extremely concise.
+
+INPUT: What the other side just said
+CONTEXT: {{context}}
+
+THEIR STATEMENT: "{{transcript}}"
+
+Respond with ONLY valid JSON:
+{
+  "summary": "One sentence: their position or ask",
+  "bullets": [
+    {"point": "Key thing to say or ask", "priority": 1},
+    {"point": "Supporting point", "priority": 2}
+  ],
+  "type": "question|objection|statement|buying_signal|technical|small_talk",
+  "urgency": "answer_now|can_elaborate|just_listening"
+}
+
+Rules:
+- Max {{max_bullets}} bullets of {{max_bullet_words}} words or fewer
+- Write the summary and bullets in {{language}}
+- Spot anchors, concessions and hidden interests
+- Never suggest conceding without asking for something back"#.to_string());
+
+        flash.insert("customer_support".to_string(), r#"You are an instant analysis engine for a customer support call. Be extremely concise.
+
+INPUT: What the customer just said
+CONTEXT: {{context}}
+
+THEIR STATEMENT: "{{transcript}}"
+
+Respond with ONLY valid JSON:
+{
+  "summary": "One sentence: their problem",
+  "bullets": [
+    {"point": "Next thing to say or check", "priority": 1},
+    {"point": "Supporting step", "priority": 2}
+  ],
+  "type": "question|objection|statement|technical|small_talk",
+  "urgency": "answer_now|can_elaborate|just_listening"
+}
+
+Rules:
+- Max {{max_bullets}} bullets of {{max_bullet_words}} words or fewer
+- Write the summary and bullets in {{language}}
+- Acknowledge frustration before troubleshooting
+- One troubleshooting step at a time"#.to_string());
+
+        flash.insert("meeting".to_string(), r#"You are an instant analysis engine for a meeting. Be extremely concise.
+
+INPUT: What someone just said
+CONTEXT: {{context}}
+
+THEIR STATEMENT: "{{transcript}}"
+
+Respond with ONLY valid JSON:
+{
+  "summary": "One sentence summary",
+  "bullets": [
+    {"point": "Key point or action item", "priority": 1},
+    {"point": "Supporting point", "priority": 2}
+  ],
+  "type": "question|statement|technical|small_talk",
+  "urgency": "answer_now|can_elaborate|just_listening"
+}
+
+Rules:
+- Max {{max_bullets}} bullets of {{max_bullet_words}} words or fewer
+- Write the summary and bullets in {{language}}
+- Flag decisions and action items
+- Suggest parking tangents"#.to_string());
+
         // Default Deep prompts (for detailed responses)
         deep.insert("sales".to_string(), r#"You are a world-class sales coach providing real-time guidance.
 
@@ -116,7 +211,7 @@ Provide a detailed response that:
 3. Uses social proof where relevant
 4. Ends with a discovery question
 
-Keep it conversational and natural. Max 150 words."#.to_string());
+Keep it conversational and natural. Max {{deep_max_words}} words. Respond in {{language}}."#.to_string());
 
         deep.insert("interview".to_string(), r#"You are an expert interview coach providing real-time guidance.
 
@@ -134,7 +229,7 @@ Craft a response that:
 3. Relates experience to the role
 4. Shows enthusiasm and cultural fit
 
-Keep it natural and confident. Max 150 words."#.to_string());
+Keep it natural and confident. Max {{deep_max_words}} words. Respond in {{language}}."#.to_string());
 
         deep.insert("technical".to_string(), r#"You are a senior technical expert providing real-time guidance.
 
@@ -152,7 +247,61 @@ Provide a response that:
 3. Suggests best practices where relevant
 4. Asks clarifying questions if needed
 
-Keep it clear and professional. Max 150 words."#.to_string());
+Keep it clear and professional. Max {{deep_max_words}} words. Respond in {{language}}."#.to_string());
+
+        deep.insert("negotiation".to_string(), r#"You are an expert negotiator providing real-time guidance.
+
+Context: {{context}}
+Conversation history:
+{{history}}
+
+They just said: "{{transcript}}"
+
+Quick analysis: {{bullets}}
+
+Provide a response that:
+1. Uncovers the interest behind their position
+2. Protects our walk-away point
+3. Trades any concession for something in return
+4. Moves toward an agreement both sides can commit to
+
+Keep it calm and confident. Max {{deep_max_words}} words. Respond in {{language}}."#.to_string());
+
+        deep.insert("customer_support".to_string(), r#"You are a senior support specialist providing real-time guidance.
+
+Context: {{context}}
+Conversation history:
+{{history}}
+
+The customer said: "{{transcript}}"
+
+Quick analysis: {{bullets}}
+
+Provide a response that:
+1. Acknowledges how they feel
+2. Confirms the exact problem
+3. Gives clear, one-at-a-time steps to fix it
+4. Sets expectations if it needs escalation
+
+Keep it warm and jargon-free. Max {{deep_max_words}} words. Respond in {{language}}."#.to_string());
+
+        deep.insert("meeting".to_string(), r#"You are an experienced facilitator providing real-time meeting guidance.
+
+Context: {{context}}
+Conversation history:
+{{history}}
+
+Someone said: "{{transcript}}"
+
+Quick analysis: {{bullets}}
+
+Provide a response that:
+1. Keeps the discussion on the agenda
+2. Restates any decision that was made
+3. Turns commitments into an owner and a deadline
+4. Suggests the next step
+
+Keep it brief and neutral. Max {{deep_max_words}} words. Respond in {{language}}."#.to_string());
 
         // System prompts
         system.insert("default".to_string(),
@@ -167,6 +316,23 @@ Keep it clear and professional. Max 150 words."#.to_string());
 }
 
 impl CustomPrompts {
+    /// Default prompts written in a language
+    ///
+    /// Modes without a translation keep the English prompt.
+    pub fn default_for_lang(language: Language) -> Self {
+        let mut prompts = Self::default();
+        if let Some(localized) = locales::for_language(language) {
+            for (mode, prompt) in localized.flash {
+                prompts.flash.insert(mode.to_string(), prompt.to_string());
+            }
+            for (mode, prompt) in localized.deep {
+                prompts.deep.insert(mode.to_string(), prompt.to_string());
+            }
+            prompts.system.insert("default".to_string(), localized.system.to_string());
+        }
+        prompts
+    }
+
     /// Get the prompts file path
     pub fn path() -> PathBuf {
         dirs::config_dir()
@@ -177,12 +343,17 @@ impl CustomPrompts {
 
     /// Load prompts from disk
     pub fn load() -> Result<Self> {
+        Self::load_for_lang(Language::default())
+    }
+
+    /// Load prompts from disk, or the defaults for a language if none are saved
+    pub fn load_for_lang(language: Language) -> Result<Self> {
         let path = Self::path();
         if path.exists() {
             let content = std::fs::read_to_string(&path)?;
             Ok(serde_json::from_str(&content)?)
         } else {
-            Ok(Self::default())
+            Ok(Self::default_for_lang(language))
         }
     }
 
@@ -197,6 +368,54 @@ impl CustomPrompts {
         Ok(())
     }
 
+    /// Write prompts to a shareable file
+    pub fn export_to_file(&self, path: impl AsRef<Path>) -> Result<()> {
+        let export = PromptExport {
+            version: EXPORT_VERSION,
+            prompts: self.clone(),
+        };
+        std::fs::write(path, serde_json::to_string_pretty(&export)?)?;
+        Ok(())
+    }
+
+    /// Read prompts from a shared file
+    ///
+    /// Only the modes present in the file are returned; use `merge` to layer
+    /// them over an existing set.
+    pub fn import_from_file(path: impl AsRef<Path>) -> Result<Self> {
+        let content = std::fs::read_to_string(path)?;
+        let export: PromptExport = serde_json::from_str(&content)?;
+
+        if export.version != EXPORT_VERSION {
+            anyhow::bail!("Unsupported prompt file version {}", export.version);
+        }
+
+        let prompts = export.prompts;
+        let mut missing: Vec<String> = prompts.flash
+            .iter()
+            .map(|(mode, prompt)| (mode, prompt, "flash"))
+            .chain(prompts.deep.iter().map(|(mode, prompt)| (mode, prompt, "deep")))
+            .filter(|(_, prompt, _)| !prompt.contains("{{transcript}}"))
+            .map(|(mode, _, stage)| format!("{} {}", mode, stage))
+            .collect();
+
+        if !missing.is_empty() {
+            missing.sort();
+            anyhow::bail!("Prompts missing {{{{transcript}}}}: {}", missing.join(", "));
+        }
+
+        Ok(prompts)
+    }
+
+    /// Layer another prompt set over this one
+    ///
+    /// Modes the other set doesn't define are kept as they are.
+    pub fn merge(&mut self, other: &CustomPrompts) {
+        self.flash.extend(other.flash.iter().map(|(k, v)| (k.clone(), v.clone())));
+        self.deep.extend(other.deep.iter().map(|(k, v)| (k.clone(), v.clone())));
+        self.system.extend(other.system.iter().map(|(k, v)| (k.clone(), v.clone())));
+    }
+
     /// Get flash prompt for mode
     pub fn get_flash(&self, mode: &str) -> &str {
         self.flash.get(mode).map(|s| s.as_str()).unwrap_or_else(|| {
@@ -259,6 +478,18 @@ mod tests {
         assert!(prompts.flash.contains_key("sales"));
     }
 
+    #[test]
+    fn test_default_for_lang() {
+        let german = CustomPrompts::default_for_lang(Language::German);
+        assert!(german.get_flash("sales").starts_with("Du bist"));
+        assert!(german.get_deep("meeting").contains("{{language}}"));
+        assert_eq!(german.flash.len(), CustomPrompts::default().flash.len());
+        assert!(german.flash.values().all(|p| p.contains("{{transcript}}")));
+
+        let english = CustomPrompts::default_for_lang(Language::English);
+        assert_eq!(english.get_flash("sales"), CustomPrompts::default().get_flash("sales"));
+    }
+
     #[test]
     fn test_apply_variables() {
         let template = "Hello {{name}}, your score is {{score}}.";
@@ -269,4 +500,51 @@ mod tests {
         let result = apply_variables(template, &vars);
         assert_eq!(result, "Hello Alice, your score is 100.");
     }
+
+    #[test]
+    fn test_export_import_round_trip() {
+        let path = std::env::temp_dir().join("voice_copilot_prompts_round_trip.json");
+        let mut prompts = CustomPrompts::default();
+        prompts.set_flash("sales", "Quick take on \"{{transcript}}\" for {{context}}");
+
+        prompts.export_to_file(&path).unwrap();
+        let imported = CustomPrompts::import_from_file(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+
+        assert_eq!(imported.flash, prompts.flash);
+        assert_eq!(imported.deep, prompts.deep);
+        assert_eq!(imported.system, prompts.system);
+    }
+
+    #[test]
+    fn test_import_merge_keeps_other_modes() {
+        let path = std::env::temp_dir().join("voice_copilot_prompts_merge.json");
+        std::fs::write(
+            &path,
+            r#"{"version": 1, "prompts": {"flash": {"sales": "Team take: {{transcript}}"}}}"#,
+        ).unwrap();
+        let imported = CustomPrompts::import_from_file(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+
+        let mut prompts = CustomPrompts::default();
+        prompts.merge(&imported);
+
+        assert_eq!(prompts.get_flash("sales"), "Team take: {{transcript}}");
+        assert_eq!(prompts.get_flash("interview"), CustomPrompts::default().get_flash("interview"));
+        assert_eq!(prompts.deep, CustomPrompts::default().deep);
+    }
+
+    #[test]
+    fn test_import_rejects_invalid_files() {
+        let path = std::env::temp_dir().join("voice_copilot_prompts_invalid.json");
+
+        std::fs::write(&path, r#"{"version": 1, "prompts": {"deep": {"sales": "No speech here"}}}"#).unwrap();
+        let err = CustomPrompts::import_from_file(&path).unwrap_err();
+        assert!(err.to_string().contains("sales deep"));
+
+        std::fs::write(&path, r#"{"version": 2, "prompts": {}}"#).unwrap();
+        assert!(CustomPrompts::import_from_file(&path).is_err());
+
+        let _ = std::fs::remove_file(&path);
+    }
 }
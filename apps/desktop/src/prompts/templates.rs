@@ -43,6 +43,24 @@ impl PromptCategory {
         }
     }
 
+    /// Category for a copilot mode key, with unknown modes treated as custom
+    pub fn from_mode(mode: &str) -> PromptCategory {
+        match mode.to_lowercase().as_str() {
+            "sales" => PromptCategory::Sales,
+            "interview" => PromptCategory::Interview,
+            "technical" => PromptCategory::Technical,
+            _ => PromptCategory::Custom,
+        }
+    }
+
+    /// Variables a prompt in this category must use
+    pub fn required_variables(&self) -> &'static [&'static str] {
+        match self {
+            PromptCategory::Custom => &["transcript"],
+            _ => &["transcript", "context"],
+        }
+    }
+
     pub fn all() -> Vec<PromptCategory> {
         vec![
             PromptCategory::Sales,
@@ -0,0 +1,115 @@
+//! Audio Recording
+//!
+//! Writes captured call audio to a 16kHz mono WAV file so calls can be
+//! re-listened to or re-transcribed later.
+
+use anyhow::{Context, Result};
+use hound::{SampleFormat, WavSpec, WavWriter};
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::Path;
+
+/// Sample rate of recorded audio (matches capture)
+pub const AUDIO_SAMPLE_RATE: u32 = 16_000;
+
+/// Default audio size cap per recording (one hour of 16-bit mono)
+pub const DEFAULT_MAX_AUDIO_BYTES: u64 = AUDIO_SAMPLE_RATE as u64 * 2 * 60 * 60;
+
+/// Outcome of writing a block of samples
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AudioWrite {
+    /// Samples were written
+    Written,
+    /// Nothing was written (not recording, paused, or already full)
+    Skipped,
+    /// The size cap was hit by this block; later samples are skipped
+    LimitReached,
+}
+
+/// WAV writer with a size cap
+pub struct AudioRecorder {
+    writer: WavWriter<BufWriter<File>>,
+    bytes_written: u64,
+    max_bytes: u64,
+    full: bool,
+}
+
+impl AudioRecorder {
+    /// Create a WAV file, including missing parent directories
+    pub fn create(path: &Path, max_bytes: u64) -> Result<Self> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).context("Failed to create recordings directory")?;
+        }
+
+        let spec = WavSpec {
+            channels: 1,
+            sample_rate: AUDIO_SAMPLE_RATE,
+            bits_per_sample: 16,
+            sample_format: SampleFormat::Int,
+        };
+        let writer = WavWriter::create(path, spec).context("Failed to create audio file")?;
+
+        Ok(Self {
+            writer,
+            bytes_written: 0,
+            max_bytes,
+            full: false,
+        })
+    }
+
+    /// Append f32 samples as 16-bit PCM, stopping at the size cap
+    pub fn write(&mut self, samples: &[f32]) -> Result<AudioWrite> {
+        if self.full {
+            return Ok(AudioWrite::Skipped);
+        }
+
+        let room = ((self.max_bytes - self.bytes_written) / 2) as usize;
+        let take = samples.len().min(room);
+
+        for &sample in &samples[..take] {
+            self.writer.write_sample((sample.clamp(-1.0, 1.0) * i16::MAX as f32) as i16)?;
+        }
+        self.bytes_written += take as u64 * 2;
+
+        if take < samples.len() {
+            self.full = true;
+            return Ok(AudioWrite::LimitReached);
+        }
+
+        Ok(AudioWrite::Written)
+    }
+
+    /// Audio bytes written so far (excluding the WAV header)
+    pub fn bytes_written(&self) -> u64 {
+        self.bytes_written
+    }
+
+    /// Flush and write the final WAV header
+    pub fn finalize(self) -> Result<()> {
+        self.writer.finalize().context("Failed to finalize audio file")?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_stops_at_cap() {
+        let path = std::env::temp_dir().join("voice_copilot_recording_cap.wav");
+
+        let mut recorder = AudioRecorder::create(&path, 8).unwrap();
+        assert_eq!(recorder.write(&[0.0, 0.5]).unwrap(), AudioWrite::Written);
+        assert_eq!(recorder.write(&[0.5, -0.5, 1.0]).unwrap(), AudioWrite::LimitReached);
+        assert_eq!(recorder.write(&[0.1]).unwrap(), AudioWrite::Skipped);
+        assert_eq!(recorder.bytes_written(), 8);
+        recorder.finalize().unwrap();
+
+        let reader = hound::WavReader::open(&path).unwrap();
+        assert_eq!(reader.spec().sample_rate, AUDIO_SAMPLE_RATE);
+        assert_eq!(reader.spec().channels, 1);
+        assert_eq!(reader.len(), 4);
+        let _ = std::fs::remove_file(&path);
+    }
+}
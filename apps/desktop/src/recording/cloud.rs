@@ -0,0 +1,153 @@
+//! Cloud Sync
+//!
+//! Uploads finished recordings to the Outreach API so calls can be reviewed
+//! from any device. Uploads are keyed by session ID, so retrying is safe.
+//! The server can also generate the call summary, so no provider key is
+//! needed on the desktop, and meeting action items can be added as tasks.
+
+use anyhow::{anyhow, Result};
+use reqwest::{Client, Method};
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use super::{ActionItem, CallSummary, RecordingSession};
+use crate::config::OutreachAccount;
+
+#[derive(Deserialize)]
+struct UploadResponse {
+    id: String,
+}
+
+/// Request body for a session and its optional summary
+fn upload_body(session: &RecordingSession, summary: Option<&CallSummary>) -> Value {
+    json!({
+        "session": session,
+        "summary": summary,
+    })
+}
+
+/// Upload a recording, returning its ID on the server
+pub async fn upload_session(
+    account: &OutreachAccount,
+    session: &RecordingSession,
+    summary: Option<&CallSummary>,
+) -> Result<String> {
+    let response = account
+        .request(&Client::new(), Method::POST, "/recordings")
+        .json(&upload_body(session, summary))
+        .send()
+        .await?;
+
+    if !response.status().is_success() {
+        let status = response.status();
+        let body = response.text().await.unwrap_or_default();
+        return Err(anyhow!("Recording upload failed ({}): {}", status, body));
+    }
+
+    let uploaded: UploadResponse = response.json().await?;
+    tracing::info!("Uploaded recording {} as {}", session.id, uploaded.id);
+    Ok(uploaded.id)
+}
+
+/// Have the server generate and store the summary for an uploaded recording
+pub async fn request_summary(account: &OutreachAccount, recording_id: &str) -> Result<CallSummary> {
+    let response = account
+        .request(&Client::new(), Method::POST, &format!("/recordings/{}/summary", recording_id))
+        .send()
+        .await?;
+
+    if !response.status().is_success() {
+        let status = response.status();
+        let body = response.text().await.unwrap_or_default();
+        return Err(anyhow!("Summary request failed ({}): {}", status, body));
+    }
+
+    Ok(response.json().await?)
+}
+
+/// Request body creating one task per action item
+fn tasks_body(session: &RecordingSession, lead_id: Option<&str>, items: &[ActionItem]) -> Value {
+    let tasks: Vec<Value> = items
+        .iter()
+        .map(|item| {
+            let mut task = json!({ "title": item.task });
+            if let Some(owner) = &item.owner {
+                task["owner"] = json!(owner);
+            }
+            if let Some(due) = item.due {
+                task["dueDate"] = json!(due.format("%Y-%m-%d").to_string());
+            }
+            task
+        })
+        .collect();
+
+    let mut body = json!({
+        "sessionId": session.id,
+        "tasks": tasks,
+    });
+    if let Some(lead_id) = lead_id {
+        body["leadId"] = json!(lead_id);
+    }
+    body
+}
+
+/// Add meeting action items to the user's tasks, returning how many were created
+pub async fn push_action_items(
+    account: &OutreachAccount,
+    session: &RecordingSession,
+    lead_id: Option<&str>,
+    items: &[ActionItem],
+) -> Result<usize> {
+    if items.is_empty() {
+        return Ok(0);
+    }
+
+    let response = account
+        .request(&Client::new(), Method::POST, "/tasks")
+        .json(&tasks_body(session, lead_id, items))
+        .send()
+        .await?;
+
+    if !response.status().is_success() {
+        let status = response.status();
+        let body = response.text().await.unwrap_or_default();
+        return Err(anyhow!("Task creation failed ({}): {}", status, body));
+    }
+
+    let created: Value = response.json().await?;
+    let count = created["tasks"].as_array().map_or(0, Vec::len);
+    tracing::info!("Created {} tasks from recording {}", count, session.id);
+    Ok(count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_upload_body() {
+        let session = RecordingSession::new("sales");
+        let body = upload_body(&session, None);
+        assert_eq!(body["session"]["id"], session.id.as_str());
+        assert_eq!(body["session"]["mode"], "sales");
+        assert!(body["summary"].is_null());
+    }
+
+    #[test]
+    fn test_tasks_body() {
+        let session = RecordingSession::new("meeting");
+        let items = [
+            ActionItem {
+                owner: Some("Sam".to_string()),
+                task: "Send the rollout plan".to_string(),
+                due: chrono::NaiveDate::from_ymd_opt(2026, 10, 16),
+            },
+            ActionItem { owner: None, task: "Update pricing".to_string(), due: None },
+        ];
+        let body = tasks_body(&session, None, &items);
+        assert_eq!(body["sessionId"], session.id.as_str());
+        assert_eq!(body["tasks"][0]["dueDate"], "2026-10-16");
+        assert!(body["tasks"][1].get("owner").is_none());
+        assert!(body.get("leadId").is_none());
+    }
+}
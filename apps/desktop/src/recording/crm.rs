@@ -0,0 +1,180 @@
+//! CRM Export
+//!
+//! Attaches a finished call to a lead in the Outreach API: the note is
+//! appended to the lead's notes and the contact dates are updated. Leads
+//! can also pre-fill the brief for an upcoming call.
+
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Utc};
+use reqwest::{Client, Method};
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use super::{CallSummary, RecordingSession};
+use crate::config::OutreachAccount;
+
+/// Leads shown per search
+const SEARCH_LIMIT: usize = 20;
+
+/// Notes kept in a lead's brief; earlier call notes are the first to go
+const BRIEF_NOTES_CHARS: usize = 800;
+
+/// A lead to attach a call to
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LeadOption {
+    pub id: String,
+    pub company_name: String,
+    #[serde(default)]
+    pub contact_name: Option<String>,
+}
+
+impl LeadOption {
+    /// "Company (Contact)" for pickers
+    pub fn label(&self) -> String {
+        match &self.contact_name {
+            Some(contact) if !contact.is_empty() => format!("{} ({})", self.company_name, contact),
+            _ => self.company_name.clone(),
+        }
+    }
+}
+
+/// Lead fields that describe who the call is with
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LeadDetails {
+    pub company_name: String,
+    #[serde(default)]
+    pub industry: Option<String>,
+    #[serde(default)]
+    pub notes: Option<String>,
+}
+
+impl LeadDetails {
+    /// Text for the call brief, keeping the most recent notes
+    pub fn brief(&self) -> String {
+        let mut brief = match self.industry.as_deref().map(str::trim) {
+            Some(industry) if !industry.is_empty() => format!("Company: {} ({})", self.company_name, industry),
+            _ => format!("Company: {}", self.company_name),
+        };
+
+        if let Some(notes) = self.notes.as_deref().map(str::trim).filter(|n| !n.is_empty()) {
+            let skip = notes.chars().count().saturating_sub(BRIEF_NOTES_CHARS);
+            let start = notes.char_indices().nth(skip).map_or(0, |(i, _)| i);
+            brief.push_str("\nNotes:\n");
+            if start > 0 {
+                brief.push_str("...");
+            }
+            brief.push_str(&notes[start..]);
+        }
+        brief
+    }
+}
+
+#[derive(Deserialize)]
+struct LeadList {
+    leads: Vec<LeadOption>,
+}
+
+/// Leads matching a search, most relevant first (recent first when empty)
+pub async fn search_leads(account: &OutreachAccount, query: &str) -> Result<Vec<LeadOption>> {
+    let mut request = account
+        .request(&Client::new(), Method::GET, "/leads")
+        .query(&[("perPage", SEARCH_LIMIT.to_string())]);
+    if !query.trim().is_empty() {
+        request = request.query(&[("search", query.trim())]);
+    }
+
+    let response = request.send().await?;
+    if !response.status().is_success() {
+        return Err(anyhow!("Lead search failed: {}", response.status()));
+    }
+
+    Ok(response.json::<LeadList>().await?.leads)
+}
+
+/// A lead's company, industry and notes
+pub async fn fetch_lead(account: &OutreachAccount, lead_id: &str) -> Result<LeadDetails> {
+    let response = account
+        .request(&Client::new(), Method::GET, &format!("/leads/{}", lead_id))
+        .send()
+        .await?;
+    if !response.status().is_success() {
+        return Err(anyhow!("Lead lookup failed: {}", response.status()));
+    }
+
+    // Some API versions wrap the lead in {"lead": ...}
+    let mut body: Value = response.json().await?;
+    let lead = body.get_mut("lead").map(Value::take).unwrap_or(body);
+    Ok(serde_json::from_value(lead)?)
+}
+
+/// Lead update that appends the call note
+fn export_body(
+    session: &RecordingSession,
+    summary: Option<&CallSummary>,
+    next_followup: Option<DateTime<Utc>>,
+) -> Value {
+    let mut body = json!({
+        "appendNotes": session.to_lead_note(summary),
+        "lastContactedAt": session.end_time.unwrap_or(session.start_time).to_rfc3339(),
+    });
+    if let Some(at) = next_followup {
+        body["nextFollowupAt"] = json!(at.to_rfc3339());
+    }
+    body
+}
+
+/// Append a call note to a lead and update its contact dates
+pub async fn export_to_lead(
+    account: &OutreachAccount,
+    lead_id: &str,
+    session: &RecordingSession,
+    summary: Option<&CallSummary>,
+    next_followup: Option<DateTime<Utc>>,
+) -> Result<()> {
+    let response = account
+        .request(&Client::new(), Method::PUT, &format!("/leads/{}", lead_id))
+        .json(&export_body(session, summary, next_followup))
+        .send()
+        .await?;
+
+    if !response.status().is_success() {
+        let status = response.status();
+        let body = response.text().await.unwrap_or_default();
+        return Err(anyhow!("Lead update failed ({}): {}", status, body));
+    }
+
+    tracing::info!("Attached recording {} to lead {}", session.id, lead_id);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_export_body() {
+        let session = RecordingSession::new("sales");
+        let body = export_body(&session, None, None);
+        assert!(body["appendNotes"].as_str().unwrap().starts_with("### sales call"));
+        assert_eq!(body["lastContactedAt"], session.start_time.to_rfc3339());
+        assert!(body.get("nextFollowupAt").is_none());
+    }
+
+    #[test]
+    fn test_lead_brief() {
+        let lead: LeadDetails = serde_json::from_value(json!({
+            "companyName": "Acme",
+            "industry": "Logistics",
+            "notes": format!("{}Wants SSO.", "x".repeat(BRIEF_NOTES_CHARS)),
+        }))
+        .unwrap();
+        let brief = lead.brief();
+        assert!(brief.starts_with("Company: Acme (Logistics)\nNotes:\n..."));
+        assert!(brief.ends_with("Wants SSO."));
+
+        let bare = LeadDetails { company_name: "Acme".to_string(), ..LeadDetails::default() };
+        assert_eq!(bare.brief(), "Company: Acme");
+    }
+}
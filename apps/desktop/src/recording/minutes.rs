@@ -0,0 +1,320 @@
+//! Meeting Minutes
+//!
+//! Minutes for Meeting mode in place of sales coaching: who attended, what
+//! was decided, action items with their owner and due date, and what was
+//! left open. Relative due dates ("by Friday") are resolved against the
+//! day of the meeting.
+
+use anyhow::Result;
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::providers::SummaryProvider;
+use super::session::RecordingSession;
+use super::summary::SummaryBackend;
+use super::Speaker;
+use crate::brain::due_date;
+
+/// Phrases that mark a commitment in the quick minutes
+const ACTION_CUES: &[&str] = &["i'll ", "i will ", "we'll ", "we will ", "action item", "can you ", "could you ", "please "];
+
+/// Phrases that mark a decision in the quick minutes
+const DECISION_CUES: &[&str] = &["we decided", "we agreed", "decision is", "let's go with", "we'll go with", "agreed to"];
+
+/// System prompt for the minutes model
+const MINUTES_SYSTEM_PROMPT: &str = "You are a precise meeting secretary. Record only what was actually said: decisions, commitments and unanswered questions.";
+
+/// One thing someone agreed to do
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ActionItem {
+    /// Who took it on, as named in the meeting
+    pub owner: Option<String>,
+    pub task: String,
+    pub due: Option<NaiveDate>,
+}
+
+impl ActionItem {
+    /// Markdown checklist line, e.g. "- [ ] Send the deck (Priya, due 2026-10-16)"
+    pub fn checklist_line(&self) -> String {
+        let details: Vec<String> = self
+            .owner
+            .iter()
+            .cloned()
+            .chain(self.due.map(|due| format!("due {}", due.format("%Y-%m-%d"))))
+            .collect();
+        if details.is_empty() {
+            format!("- [ ] {}", self.task)
+        } else {
+            format!("- [ ] {} ({})", self.task, details.join(", "))
+        }
+    }
+}
+
+/// Minutes of a recorded meeting
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Minutes {
+    pub session_id: String,
+    pub generated_at: DateTime<Utc>,
+    pub attendees: Vec<String>,
+    pub decisions: Vec<String>,
+    pub action_items: Vec<ActionItem>,
+    pub open_questions: Vec<String>,
+}
+
+impl Minutes {
+    /// Action items as a markdown checklist
+    pub fn checklist(&self) -> String {
+        self.action_items
+            .iter()
+            .map(ActionItem::checklist_line)
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// The minutes as a markdown document
+    pub fn to_markdown(&self, session: &RecordingSession) -> String {
+        let mut md = String::new();
+        md.push_str("# Meeting Minutes\n\n");
+        md.push_str(&format!("**Date:** {}\n", session.start_time.format("%Y-%m-%d %H:%M")));
+        md.push_str(&format!("**Duration:** {} minutes\n", session.duration().num_minutes()));
+        if !self.attendees.is_empty() {
+            md.push_str(&format!("**Attendees:** {}\n", self.attendees.join(", ")));
+        }
+
+        md.push_str("\n## Decisions\n\n");
+        md.push_str(&bullet_list(&self.decisions, "No decisions recorded."));
+
+        md.push_str("\n## Action Items\n\n");
+        if self.action_items.is_empty() {
+            md.push_str("No action items recorded.\n");
+        } else {
+            md.push_str(&self.checklist());
+            md.push('\n');
+        }
+
+        md.push_str("\n## Open Questions\n\n");
+        md.push_str(&bullet_list(&self.open_questions, "None."));
+
+        md.push_str("\n---\n");
+        md.push_str("*Recorded with Voice Copilot*\n");
+        md
+    }
+}
+
+fn bullet_list(items: &[String], empty: &str) -> String {
+    if items.is_empty() {
+        return format!("{}\n", empty);
+    }
+    items.iter().map(|item| format!("- {}\n", item)).collect()
+}
+
+/// Generate minutes with the configured backend
+pub async fn generate_minutes(session: &RecordingSession, backend: &SummaryBackend) -> Result<Minutes> {
+    match backend.provider() {
+        Some(provider) => generate_minutes_with(session, provider.as_ref()).await,
+        None => Ok(generate_quick_minutes(session)),
+    }
+}
+
+/// Generate minutes with any provider
+pub async fn generate_minutes_with(session: &RecordingSession, provider: &dyn SummaryProvider) -> Result<Minutes> {
+    let prompt = build_minutes_prompt(session);
+    let analysis = provider.complete_json(MINUTES_SYSTEM_PROMPT, &prompt).await?;
+    Ok(parse_minutes(session, &analysis))
+}
+
+/// Build the minutes prompt for a session
+fn build_minutes_prompt(session: &RecordingSession) -> String {
+    format!(
+        r#"Write the minutes of this meeting.
+
+TRANSCRIPT:
+{transcript}
+
+MEETING INFO:
+- Date: {date}
+- Duration: {duration_mins} minutes
+- "You" is the person taking the minutes
+
+Respond in the following JSON format:
+{{
+    "attendees": ["names or roles of the people who spoke"],
+    "decisions": ["what was decided, one per item"],
+    "action_items": [
+        {{
+            "owner": "who committed to it, or null if nobody did",
+            "task": "what they will do, starting with a verb",
+            "due": "YYYY-MM-DD, or the words used such as \"by Friday\", or null"
+        }}
+    ],
+    "open_questions": ["questions raised but not answered"]
+}}
+
+Only include commitments someone actually made. Leave a list empty rather than guessing."#,
+        transcript = session.full_transcript(),
+        date = session.start_time.format("%A %Y-%m-%d"),
+        duration_mins = session.duration().num_minutes(),
+    )
+}
+
+/// Turn the model's JSON into Minutes
+fn parse_minutes(session: &RecordingSession, analysis: &serde_json::Value) -> Minutes {
+    let meeting_day = session.start_time.date_naive();
+    let action_items = analysis["action_items"]
+        .as_array()
+        .map(|items| {
+            items
+                .iter()
+                .filter_map(|item| {
+                    let task = item["task"].as_str()?.trim();
+                    if task.is_empty() {
+                        return None;
+                    }
+                    Some(ActionItem {
+                        owner: item["owner"]
+                            .as_str()
+                            .map(str::trim)
+                            .filter(|owner| !owner.is_empty())
+                            .map(String::from),
+                        task: task.to_string(),
+                        due: item["due"].as_str().and_then(|due| parse_due(due, meeting_day)),
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Minutes {
+        session_id: session.id.clone(),
+        generated_at: Utc::now(),
+        attendees: string_array(&analysis["attendees"]),
+        decisions: string_array(&analysis["decisions"]),
+        action_items,
+        open_questions: string_array(&analysis["open_questions"]),
+    }
+}
+
+/// An ISO date, or a phrase like "by Friday" relative to the meeting
+fn parse_due(text: &str, meeting_day: NaiveDate) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(text.trim(), "%Y-%m-%d")
+        .ok()
+        .or_else(|| due_date(text, meeting_day))
+}
+
+fn string_array(value: &serde_json::Value) -> Vec<String> {
+    value
+        .as_array()
+        .map(|a| a.iter().filter_map(|v| v.as_str().map(String::from)).collect())
+        .unwrap_or_default()
+}
+
+/// Minutes without AI, from commitment and decision phrases in the transcript
+///
+/// Questions count as open when the next turn isn't from someone else.
+pub fn generate_quick_minutes(session: &RecordingSession) -> Minutes {
+    let meeting_day = session.start_time.date_naive();
+    let mut attendees: Vec<String> = Vec::new();
+    let mut decisions = Vec::new();
+    let mut action_items = Vec::new();
+    let mut open_questions = Vec::new();
+
+    for (i, turn) in session.turns.iter().enumerate() {
+        if turn.speaker == Speaker::System {
+            continue;
+        }
+        let label = turn.speaker.label().to_string();
+        if !attendees.contains(&label) {
+            attendees.push(label.clone());
+        }
+
+        let text = turn.text.trim();
+        let lower = format!("{} ", text.to_lowercase());
+        if DECISION_CUES.iter().any(|cue| lower.contains(cue)) {
+            decisions.push(text.to_string());
+        } else if ACTION_CUES.iter().any(|cue| lower.contains(cue)) {
+            action_items.push(ActionItem {
+                owner: Some(label),
+                task: text.to_string(),
+                due: due_date(text, meeting_day),
+            });
+        }
+
+        let answered = session.turns.get(i + 1).is_some_and(|next| next.speaker != turn.speaker);
+        if text.ends_with('?') && !answered {
+            open_questions.push(text.to_string());
+        }
+    }
+
+    Minutes {
+        session_id: session.id.clone(),
+        generated_at: Utc::now(),
+        attendees,
+        decisions,
+        action_items,
+        open_questions,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::recording::RecordedTurn;
+    use chrono::TimeZone;
+
+    fn meeting() -> RecordingSession {
+        let mut session = RecordingSession::new("meeting");
+        // Wednesday
+        session.start_time = Utc.with_ymd_and_hms(2026, 10, 14, 15, 0, 0).unwrap();
+        for (speaker, text) in [
+            (Speaker::Other, "We agreed to ship the beta to five customers."),
+            (Speaker::User, "I'll send the rollout plan by Friday."),
+            (Speaker::Other, "Who owns the pricing page?"),
+        ] {
+            session.add_turn(RecordedTurn {
+                timestamp: session.start_time,
+                speaker,
+                text: text.to_string(),
+                duration_ms: 2000,
+                start_ms: None,
+                end_ms: None,
+            });
+        }
+        session
+    }
+
+    #[test]
+    fn test_parse_minutes() {
+        let session = meeting();
+        let analysis = serde_json::json!({
+            "attendees": ["Priya", "Sam"],
+            "decisions": ["Ship the beta to five customers"],
+            "action_items": [
+                { "owner": "Sam", "task": "Send the rollout plan", "due": "by Friday" },
+                { "owner": null, "task": "Update the pricing page", "due": "2026-10-30" },
+                { "owner": "Priya", "task": " " }
+            ],
+            "open_questions": ["Who owns the pricing page?"]
+        });
+
+        let minutes = parse_minutes(&session, &analysis);
+        assert_eq!(minutes.attendees, vec!["Priya", "Sam"]);
+        assert_eq!(minutes.action_items.len(), 2);
+        assert_eq!(minutes.action_items[0].due, NaiveDate::from_ymd_opt(2026, 10, 16));
+        assert_eq!(minutes.action_items[1].owner, None);
+        assert_eq!(
+            minutes.checklist(),
+            "- [ ] Send the rollout plan (Sam, due 2026-10-16)\n- [ ] Update the pricing page (due 2026-10-30)"
+        );
+        assert!(minutes.to_markdown(&session).contains("## Open Questions\n\n- Who owns the pricing page?\n"));
+    }
+
+    #[test]
+    fn test_quick_minutes() {
+        let minutes = generate_quick_minutes(&meeting());
+        assert_eq!(minutes.attendees, vec!["Them", "You"]);
+        assert_eq!(minutes.decisions, vec!["We agreed to ship the beta to five customers."]);
+        assert_eq!(minutes.action_items[0].owner.as_deref(), Some("You"));
+        assert_eq!(minutes.action_items[0].due, NaiveDate::from_ymd_opt(2026, 10, 16));
+        assert_eq!(minutes.open_questions, vec!["Who owns the pricing page?"]);
+    }
+}
@@ -5,16 +5,34 @@
 
 mod session;
 mod summary;
+mod providers;
 mod storage;
+mod audio;
+mod cloud;
+mod crm;
+mod usage;
+mod report;
+mod pdf;
+mod minutes;
+mod subtitles;
 
 pub use session::{RecordingSession, RecordingState, RecordedTurn, RecordedSuggestion};
-pub use summary::{CallSummary, SelfAnalysis, PerformanceScore, generate_call_summary};
-pub use storage::{save_recording, load_recording, list_recordings, delete_recording};
+pub use summary::{CallSummary, SelfAnalysis, ResponseComparison, PerformanceScore, SummaryBackend, generate_call_summary, generate_call_summary_ollama, generate_call_summary_with, generate_summary};
+pub use providers::{SummaryProvider, OpenAiSummaryProvider, AnthropicSummaryProvider, OllamaSummaryProvider};
+pub use storage::{save_recording, save_html_report, save_minutes_markdown, save_subtitles, save_summary_pdf, load_recording, list_recordings, delete_recording, audio_path};
+pub use audio::{AudioRecorder, AudioWrite, AUDIO_SAMPLE_RATE, DEFAULT_MAX_AUDIO_BYTES};
+pub use cloud::{push_action_items, request_summary, upload_session};
+pub use crm::{export_to_lead, fetch_lead, search_leads, LeadDetails, LeadOption};
+pub use report::export_html;
+pub use pdf::export_pdf;
+pub use minutes::{ActionItem, Minutes, generate_minutes};
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
-use parking_lot::RwLock;
+use parking_lot::{Mutex, RwLock};
+
+use crate::redaction::{redact, RedactionRules};
 
 /// Recording manager - controls recording state
 pub struct RecordingManager {
@@ -22,6 +40,19 @@ pub struct RecordingManager {
     is_recording: Arc<RwLock<bool>>,
     is_paused: Arc<RwLock<bool>>,
     auto_record: bool,
+    /// Also save the raw call audio (opt-in)
+    record_audio: bool,
+    max_audio_bytes: u64,
+    audio: Arc<Mutex<Option<AudioRecorder>>>,
+    /// Samples captured since the session started, paused or not; turn
+    /// offsets are read from this so debouncing doesn't shift them
+    stream_samples: Arc<RwLock<u64>>,
+    /// Mask PII in turns before they're stored
+    redact_pii: bool,
+    redaction_rules: RedactionRules,
+    /// Keep original turn text in memory; only when the user allows it
+    keep_unredacted: bool,
+    unredacted_turns: Arc<RwLock<Vec<RecordedTurn>>>,
 }
 
 impl RecordingManager {
@@ -31,6 +62,14 @@ impl RecordingManager {
             is_recording: Arc::new(RwLock::new(false)),
             is_paused: Arc::new(RwLock::new(false)),
             auto_record: false,
+            record_audio: false,
+            max_audio_bytes: DEFAULT_MAX_AUDIO_BYTES,
+            audio: Arc::new(Mutex::new(None)),
+            stream_samples: Arc::new(RwLock::new(0)),
+            redact_pii: false,
+            redaction_rules: RedactionRules::default(),
+            keep_unredacted: false,
+            unredacted_turns: Arc::new(RwLock::new(Vec::new())),
         }
     }
 
@@ -39,10 +78,64 @@ impl RecordingManager {
         self.auto_record = enabled;
     }
 
+    /// Whether sessions are recorded without being asked
+    pub fn is_auto_record(&self) -> bool {
+        self.auto_record
+    }
+
+    /// Save call audio as WAV alongside the transcript
+    pub fn set_record_audio(&mut self, enabled: bool) {
+        self.record_audio = enabled;
+    }
+
+    /// Cap the size of each recording's audio
+    pub fn set_max_audio_bytes(&mut self, max_bytes: u64) {
+        self.max_audio_bytes = max_bytes;
+    }
+
+    /// Mask PII in recorded turns
+    pub fn set_redact_pii(&mut self, enabled: bool) {
+        self.redact_pii = enabled;
+    }
+
+    /// Choose what gets masked
+    pub fn set_redaction_rules(&mut self, rules: RedactionRules) {
+        self.redaction_rules = rules;
+    }
+
+    /// Allow keeping the original text in memory while redacting
+    pub fn set_keep_unredacted(&mut self, allowed: bool) {
+        self.keep_unredacted = allowed;
+        if !allowed {
+            self.unredacted_turns.write().clear();
+        }
+    }
+
+    /// Original turns of the latest recording, if keeping them is allowed
+    pub fn unredacted_turns(&self) -> Vec<RecordedTurn> {
+        self.unredacted_turns.read().clone()
+    }
+
     /// Start a new recording session
     pub fn start_recording(&self, mode: &str) {
+        let mut new_session = RecordingSession::new(mode);
+
+        if self.record_audio {
+            let path = storage::audio_path(&new_session);
+            match AudioRecorder::create(&path, self.max_audio_bytes) {
+                Ok(recorder) => {
+                    *self.audio.lock() = Some(recorder);
+                    new_session.audio_path = Some(path);
+                }
+                Err(e) => tracing::warn!("Audio recording disabled: {}", e),
+            }
+        }
+
+        self.unredacted_turns.write().clear();
+        *self.stream_samples.write() = 0;
+
         let mut session = self.current_session.write();
-        *session = Some(RecordingSession::new(mode));
+        *session = Some(new_session);
         *self.is_recording.write() = true;
         *self.is_paused.write() = false;
         tracing::info!("Recording started for mode: {}", mode);
@@ -89,19 +182,96 @@ impl RecordingManager {
         *self.is_paused.read()
     }
 
-    /// Add a turn to the recording
+    /// Samples captured since the session started
+    pub fn stream_samples(&self) -> u64 {
+        *self.stream_samples.read()
+    }
+
+    /// Where the audio stream is now, ms from the session start
+    ///
+    /// Falls back to the clock when no audio has been fed in.
+    fn stream_position_ms(&self) -> u64 {
+        let samples = *self.stream_samples.read();
+        if samples > 0 {
+            return samples * 1000 / AUDIO_SAMPLE_RATE as u64;
+        }
+        self.current_session
+            .read()
+            .as_ref()
+            .map(|s| (Utc::now() - s.start_time).num_milliseconds().max(0) as u64)
+            .unwrap_or_default()
+    }
+
+    /// Add a turn that has just ended to the recording
+    ///
+    /// Without word times its offsets are estimated from the audio stream:
+    /// it ends at the current position and started `duration_ms` before.
     pub fn add_turn(&self, speaker: Speaker, text: &str, duration_ms: u64) {
+        let end_ms = self.stream_position_ms();
+        self.add_turn_at(speaker, text, end_ms.saturating_sub(duration_ms), end_ms);
+    }
+
+    /// Add a turn at known offsets in the audio stream (ms)
+    pub fn add_turn_at(&self, speaker: Speaker, text: &str, start_ms: u64, end_ms: u64) {
         if !*self.is_recording.read() || *self.is_paused.read() {
             return;
         }
 
+        let turn = RecordedTurn {
+            timestamp: Utc::now(),
+            speaker,
+            text: text.to_string(),
+            duration_ms: end_ms.saturating_sub(start_ms),
+            start_ms: Some(start_ms),
+            end_ms: Some(end_ms),
+        };
+
+        let turn = if self.redact_pii {
+            let redacted = RecordedTurn {
+                text: redact(text, &self.redaction_rules),
+                ..turn.clone()
+            };
+            if self.keep_unredacted {
+                self.unredacted_turns.write().push(turn);
+            }
+            redacted
+        } else {
+            turn
+        };
+
         if let Some(ref mut session) = *self.current_session.write() {
-            session.add_turn(RecordedTurn {
-                timestamp: Utc::now(),
-                speaker,
-                text: text.to_string(),
-                duration_ms,
-            });
+            session.add_turn(turn);
+        }
+    }
+
+    /// Add captured audio (16kHz mono) to the recording
+    pub fn add_audio(&self, samples: &[f32]) -> AudioWrite {
+        if !*self.is_recording.read() {
+            return AudioWrite::Skipped;
+        }
+        *self.stream_samples.write() += samples.len() as u64;
+        if *self.is_paused.read() {
+            return AudioWrite::Skipped;
+        }
+
+        let mut audio = self.audio.lock();
+        let Some(recorder) = audio.as_mut() else {
+            return AudioWrite::Skipped;
+        };
+
+        match recorder.write(samples) {
+            Ok(AudioWrite::LimitReached) => {
+                tracing::warn!("Audio recording reached its size limit; audio is no longer saved");
+                if let Some(ref mut session) = *self.current_session.write() {
+                    session.add_event(SessionEvent::AudioLimitReached);
+                }
+                AudioWrite::LimitReached
+            }
+            Ok(result) => result,
+            Err(e) => {
+                tracing::warn!("Failed to write audio: {}", e);
+                AudioWrite::Skipped
+            }
         }
     }
 
@@ -117,6 +287,7 @@ impl RecordingManager {
                 suggestion_type,
                 content: content.to_string(),
                 was_used,
+                similarity: None,
             });
         }
     }
@@ -126,6 +297,12 @@ impl RecordingManager {
         *self.is_recording.write() = false;
         *self.is_paused.write() = false;
 
+        if let Some(recorder) = self.audio.lock().take() {
+            if let Err(e) = recorder.finalize() {
+                tracing::warn!("{}", e);
+            }
+        }
+
         let mut session = self.current_session.write();
         if let Some(ref mut s) = *session {
             s.end_session();
@@ -191,5 +368,32 @@ pub enum SessionEvent {
     Paused,
     Resumed,
     ModeChanged(String),
+    /// Audio hit the size cap and stopped being saved
+    AudioLimitReached,
     Ended,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_turn_redacts_pii() {
+        let mut manager = RecordingManager::new();
+        manager.set_redact_pii(true);
+        manager.start_recording("sales");
+        manager.add_turn(Speaker::Other, "My number is 555-123-4567", 1000);
+        assert!(manager.unredacted_turns().is_empty());
+
+        manager.set_keep_unredacted(true);
+        manager.add_turn(Speaker::Other, "Email me at kim@example.com", 1000);
+
+        let session = manager.stop_recording().unwrap();
+        assert_eq!(session.turns[0].text, "My number is [PHONE]");
+        assert_eq!(session.turns[1].text, "Email me at [EMAIL]");
+
+        let originals = manager.unredacted_turns();
+        assert_eq!(originals.len(), 1);
+        assert_eq!(originals[0].text, "Email me at kim@example.com");
+    }
+}
@@ -0,0 +1,298 @@
+//! PDF Summary
+//!
+//! Lays out a `CallSummary` as an A4 PDF for people who want something
+//! to print or forward: a score gauge, the grade, score breakdown, what
+//! went well and what to improve, next steps and the executive summary.
+//! Uses the built-in Helvetica fonts, so nothing is embedded.
+
+use anyhow::{anyhow, Result};
+use printpdf::{
+    BuiltinFont, Color, IndirectFontRef, Line, Mm, PdfDocument, PdfDocumentReference, PdfLayerReference, Point, Rgb,
+};
+
+use super::summary::CallSummary;
+
+const PAGE_WIDTH: f32 = 210.0;
+const PAGE_HEIGHT: f32 = 297.0;
+const MARGIN: f32 = 20.0;
+
+/// Gauge radius and stroke (mm)
+const GAUGE_RADIUS: f32 = 28.0;
+const GAUGE_STROKE_PT: f32 = 14.0;
+
+/// Helvetica's mean glyph width as a share of the font size, for wrapping
+const AVG_CHAR_WIDTH: f32 = 0.5;
+
+const PT_TO_MM: f32 = 0.3528;
+
+/// The summary as PDF bytes
+pub fn export_pdf(summary: &CallSummary) -> Result<Vec<u8>> {
+    let (doc, page, layer) = PdfDocument::new("Call Summary", Mm(PAGE_WIDTH), Mm(PAGE_HEIGHT), "Layer 1");
+    let regular = doc.add_builtin_font(BuiltinFont::Helvetica)?;
+    let bold = doc.add_builtin_font(BuiltinFont::HelveticaBold)?;
+    let layer = doc.get_page(page).get_layer(layer);
+
+    let mut writer = PdfWriter {
+        doc,
+        layer,
+        regular,
+        bold,
+        y: PAGE_HEIGHT - MARGIN,
+    };
+
+    writer.text("Call Summary", 22.0, true);
+    writer.text(&format!("Generated {}", summary.generated_at.format("%Y-%m-%d %H:%M UTC")), 10.0, false);
+    writer.gap(6.0);
+
+    let score = &summary.score;
+    writer.gauge(score.overall, &score.grade);
+    writer.text(&score.assessment, 12.0, false);
+    writer.gap(4.0);
+
+    writer.heading("Scores");
+    for (label, value) in [
+        ("Listening", score.listening),
+        ("Response quality", score.response_quality),
+        ("Delivery", score.delivery),
+        ("Suggestion usage", score.suggestion_usage),
+        ("Outcome", score.outcome),
+    ] {
+        writer.score_bar(label, value);
+    }
+
+    writer.heading("Summary");
+    writer.paragraph(summary.executive_summary.trim());
+    writer.paragraph(&format!(
+        "{} - {}",
+        summary.outcome.goal_achieved.label(),
+        summary.outcome.likely_outcome
+    ));
+
+    for (title, items) in [
+        ("Did well", &summary.did_well),
+        ("Could improve", &summary.could_improve),
+        ("Next steps", &summary.next_steps),
+    ] {
+        if items.is_empty() {
+            continue;
+        }
+        writer.heading(title);
+        for item in items {
+            writer.bullet(item);
+        }
+    }
+
+    writer
+        .doc
+        .save_to_bytes()
+        .map_err(|e| anyhow!("Failed to write PDF: {}", e))
+}
+
+/// Writes top to bottom, starting a new page when one fills up
+struct PdfWriter {
+    doc: PdfDocumentReference,
+    layer: PdfLayerReference,
+    regular: IndirectFontRef,
+    bold: IndirectFontRef,
+    /// Baseline of the next line (mm from the bottom)
+    y: f32,
+}
+
+impl PdfWriter {
+    /// Make room for `height` mm, breaking the page if needed
+    fn reserve(&mut self, height: f32) {
+        if self.y - height >= MARGIN {
+            return;
+        }
+        let (page, layer) = self.doc.add_page(Mm(PAGE_WIDTH), Mm(PAGE_HEIGHT), "Layer 1");
+        self.layer = self.doc.get_page(page).get_layer(layer);
+        self.y = PAGE_HEIGHT - MARGIN;
+    }
+
+    fn gap(&mut self, mm: f32) {
+        self.y -= mm;
+    }
+
+    fn line_height(size: f32) -> f32 {
+        size * PT_TO_MM * 1.4
+    }
+
+    fn text_at(&mut self, text: &str, size: f32, bold: bool, x: f32) {
+        let height = Self::line_height(size);
+        self.reserve(height);
+        self.y -= height;
+        let font = if bold { &self.bold } else { &self.regular };
+        self.layer.set_fill_color(black());
+        self.layer.use_text(pdf_text(text), size, Mm(x), Mm(self.y), font);
+    }
+
+    fn text(&mut self, text: &str, size: f32, bold: bool) {
+        self.text_at(text, size, bold, MARGIN);
+    }
+
+    fn heading(&mut self, title: &str) {
+        self.gap(4.0);
+        self.text(title, 14.0, true);
+        self.gap(1.0);
+    }
+
+    fn paragraph(&mut self, text: &str) {
+        for line in wrap(text, PAGE_WIDTH - 2.0 * MARGIN, 11.0) {
+            self.text(&line, 11.0, false);
+        }
+        self.gap(2.0);
+    }
+
+    fn bullet(&mut self, text: &str) {
+        let indent = 5.0;
+        for (i, line) in wrap(text, PAGE_WIDTH - 2.0 * MARGIN - indent, 11.0).iter().enumerate() {
+            self.text_at(line, 11.0, false, MARGIN + indent);
+            if i == 0 {
+                self.layer.use_text("-", 11.0, Mm(MARGIN), Mm(self.y), &self.regular);
+            }
+        }
+    }
+
+    /// Label, a 0-100 bar, and the number
+    fn score_bar(&mut self, label: &str, value: u32) {
+        self.text(label, 11.0, false);
+        let track_x = MARGIN + 45.0;
+        let track_width = PAGE_WIDTH - 2.0 * MARGIN - 60.0;
+        let bar_y = self.y + 1.2;
+        let fill = track_width * value.min(100) as f32 / 100.0;
+
+        self.stroke(&[(track_x, bar_y), (track_x + track_width, bar_y)], gray(), 8.0);
+        if fill > 0.0 {
+            self.stroke(&[(track_x, bar_y), (track_x + fill, bar_y)], score_color(value), 8.0);
+        }
+        self.layer.use_text(
+            value.to_string(),
+            11.0,
+            Mm(track_x + track_width + 4.0),
+            Mm(self.y),
+            &self.regular,
+        );
+        self.gap(1.5);
+    }
+
+    /// Half-circle gauge filled to the overall score, with it and the grade inside
+    fn gauge(&mut self, score: u32, grade: &str) {
+        let height = GAUGE_RADIUS + 12.0;
+        self.reserve(height);
+        let center = (PAGE_WIDTH / 2.0, self.y - GAUGE_RADIUS - 4.0);
+
+        let arc = |from: f32, to: f32| -> Vec<(f32, f32)> {
+            let steps = ((from - to).abs() / 3.0).ceil().max(1.0) as usize;
+            (0..=steps)
+                .map(|i| {
+                    let angle = (from + (to - from) * i as f32 / steps as f32).to_radians();
+                    (center.0 + GAUGE_RADIUS * angle.cos(), center.1 + GAUGE_RADIUS * angle.sin())
+                })
+                .collect()
+        };
+
+        self.stroke(&arc(180.0, 0.0), gray(), GAUGE_STROKE_PT);
+        let filled = 180.0 * score.min(100) as f32 / 100.0;
+        if filled > 0.0 {
+            self.stroke(&arc(180.0, 180.0 - filled), score_color(score), GAUGE_STROKE_PT);
+        }
+
+        let number = score.to_string();
+        let size = 26.0;
+        let width = text_width(&number, size);
+        self.layer.set_fill_color(black());
+        self.layer.use_text(number, size, Mm(center.0 - width / 2.0), Mm(center.1 + 2.0), &self.bold);
+        let label = format!("Grade {}", grade);
+        let width = text_width(&label, 11.0);
+        self.layer.use_text(pdf_text(&label), 11.0, Mm(center.0 - width / 2.0), Mm(center.1 - 5.0), &self.regular);
+
+        self.y = center.1 - 10.0;
+    }
+
+    fn stroke(&self, points: &[(f32, f32)], color: Color, thickness_pt: f32) {
+        self.layer.set_outline_color(color);
+        self.layer.set_outline_thickness(thickness_pt);
+        self.layer.add_line(Line {
+            points: points.iter().map(|&(x, y)| (Point::new(Mm(x), Mm(y)), false)).collect(),
+            is_closed: false,
+        });
+    }
+}
+
+fn black() -> Color {
+    Color::Rgb(Rgb::new(0.1, 0.1, 0.12, None))
+}
+
+fn gray() -> Color {
+    Color::Rgb(Rgb::new(0.88, 0.88, 0.9, None))
+}
+
+/// Green from a B up, yellow for C and D, red below
+fn score_color(score: u32) -> Color {
+    match score {
+        s if s >= 80 => Color::Rgb(Rgb::new(0.2, 0.7, 0.4, None)),
+        60..=79 => Color::Rgb(Rgb::new(0.95, 0.7, 0.2, None)),
+        _ => Color::Rgb(Rgb::new(0.9, 0.3, 0.3, None)),
+    }
+}
+
+/// Estimated width of a line of Helvetica (mm)
+fn text_width(text: &str, size: f32) -> f32 {
+    text.chars().count() as f32 * size * AVG_CHAR_WIDTH * PT_TO_MM
+}
+
+/// Greedy word wrap to an estimated width
+fn wrap(text: &str, width: f32, size: f32) -> Vec<String> {
+    let max_chars = ((width / (size * AVG_CHAR_WIDTH * PT_TO_MM)) as usize).max(1);
+    let mut lines = Vec::new();
+    let mut line = String::new();
+    for word in text.split_whitespace() {
+        if !line.is_empty() && line.chars().count() + 1 + word.chars().count() > max_chars {
+            lines.push(std::mem::take(&mut line));
+        }
+        if !line.is_empty() {
+            line.push(' ');
+        }
+        line.push_str(word);
+    }
+    if !line.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
+
+/// The built-in fonts only cover Latin-1; swap typographic punctuation for
+/// ASCII and anything else outside it for "?"
+fn pdf_text(text: &str) -> String {
+    text.chars()
+        .map(|c| match c {
+            '‘' | '’' => '\'',
+            '“' | '”' => '"',
+            '–' | '—' => '-',
+            '•' => '*',
+            c if (c as u32) < 0x100 => c,
+            _ => '?',
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::recording::summary::generate_quick_summary;
+    use crate::recording::RecordingSession;
+
+    #[test]
+    fn test_export_pdf() {
+        let mut summary = generate_quick_summary(&RecordingSession::new("sales"));
+        // Enough next steps to spill onto a second page
+        summary.next_steps = (0..60).map(|i| format!("Follow up on item {} — send the deck", i)).collect();
+
+        let bytes = export_pdf(&summary).unwrap();
+        assert!(bytes.starts_with(b"%PDF"));
+
+        let lines = wrap("one two three four five six", 12.0 * 11.0 * AVG_CHAR_WIDTH * PT_TO_MM, 11.0);
+        assert_eq!(lines, vec!["one two", "three four", "five six"]);
+        assert_eq!(pdf_text("“Yes” — €5"), "\"Yes\" - ?5");
+    }
+}
@@ -0,0 +1,188 @@
+//! Summary Providers
+//!
+//! LLM backends that can return a JSON object for call summaries:
+//! - OpenAI (chat completions, JSON mode)
+//! - Anthropic (Messages API, prefilled brace)
+//! - Ollama (local `/api/generate`, `format: json`)
+
+use anyhow::Result;
+use async_trait::async_trait;
+use reqwest::Client;
+use serde_json::{json, Value};
+
+use crate::flash::{extract_json, DEFAULT_OLLAMA_URL};
+
+/// Max tokens requested for a summary
+const MAX_SUMMARY_TOKENS: u32 = 2000;
+
+/// A model that answers with a JSON object
+#[async_trait]
+pub trait SummaryProvider: Send + Sync {
+    /// Send a system and user prompt and parse the reply as JSON
+    async fn complete_json(&self, system: &str, user: &str) -> Result<Value>;
+}
+
+/// OpenAI chat completions
+pub struct OpenAiSummaryProvider {
+    api_key: String,
+    model: String,
+    client: Client,
+}
+
+impl OpenAiSummaryProvider {
+    pub fn new(api_key: impl Into<String>, model: impl Into<String>) -> Self {
+        Self {
+            api_key: api_key.into(),
+            model: model.into(),
+            client: Client::new(),
+        }
+    }
+}
+
+#[async_trait]
+impl SummaryProvider for OpenAiSummaryProvider {
+    async fn complete_json(&self, system: &str, user: &str) -> Result<Value> {
+        let response = self
+            .client
+            .post("https://api.openai.com/v1/chat/completions")
+            .header("Authorization", format!("Bearer {}", self.api_key))
+            .header("Content-Type", "application/json")
+            .json(&json!({
+                "model": self.model,
+                "messages": [
+                    { "role": "system", "content": system },
+                    { "role": "user", "content": user }
+                ],
+                "temperature": 0.7,
+                "max_tokens": MAX_SUMMARY_TOKENS,
+                "response_format": { "type": "json_object" }
+            }))
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            return Err(anyhow::anyhow!("OpenAI API error ({}): {}", status, body));
+        }
+
+        let response_json: Value = response.json().await?;
+        let content = response_json["choices"][0]["message"]["content"]
+            .as_str()
+            .ok_or_else(|| anyhow::anyhow!("No content in response"))?;
+
+        Ok(serde_json::from_str(content)?)
+    }
+}
+
+/// Anthropic Messages API
+pub struct AnthropicSummaryProvider {
+    api_key: String,
+    model: String,
+    client: Client,
+}
+
+impl AnthropicSummaryProvider {
+    pub fn new(api_key: impl Into<String>, model: impl Into<String>) -> Self {
+        Self {
+            api_key: api_key.into(),
+            model: model.into(),
+            client: Client::new(),
+        }
+    }
+}
+
+#[async_trait]
+impl SummaryProvider for AnthropicSummaryProvider {
+    async fn complete_json(&self, system: &str, user: &str) -> Result<Value> {
+        // Claude has no JSON mode, so prefill the opening brace
+        let response = self
+            .client
+            .post("https://api.anthropic.com/v1/messages")
+            .header("x-api-key", &self.api_key)
+            .header("anthropic-version", "2023-06-01")
+            .header("content-type", "application/json")
+            .json(&json!({
+                "model": self.model,
+                "max_tokens": MAX_SUMMARY_TOKENS,
+                "temperature": 0.7,
+                "system": system,
+                "messages": [
+                    { "role": "user", "content": user },
+                    { "role": "assistant", "content": "{" }
+                ]
+            }))
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            return Err(anyhow::anyhow!("Claude API error ({}): {}", status, body));
+        }
+
+        let response_json: Value = response.json().await?;
+        let text = response_json["content"][0]["text"]
+            .as_str()
+            .ok_or_else(|| anyhow::anyhow!("No content in Claude response"))?;
+
+        let raw = format!("{{{}", text);
+        Ok(serde_json::from_str(extract_json(&raw).unwrap_or(&raw))?)
+    }
+}
+
+/// Local Ollama server
+pub struct OllamaSummaryProvider {
+    base_url: String,
+    model: String,
+    client: Client,
+}
+
+impl OllamaSummaryProvider {
+    pub fn new(model: impl Into<String>) -> Self {
+        Self {
+            base_url: DEFAULT_OLLAMA_URL.to_string(),
+            model: model.into(),
+            client: Client::new(),
+        }
+    }
+
+    /// Use a non-default Ollama server
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+}
+
+#[async_trait]
+impl SummaryProvider for OllamaSummaryProvider {
+    async fn complete_json(&self, system: &str, user: &str) -> Result<Value> {
+        let response = self
+            .client
+            .post(format!("{}/api/generate", self.base_url))
+            .json(&json!({
+                "model": self.model,
+                "system": system,
+                "prompt": user,
+                "stream": false,
+                "format": "json",
+                "options": { "temperature": 0.7, "num_predict": MAX_SUMMARY_TOKENS }
+            }))
+            .send()
+            .await
+            .map_err(|e| anyhow::anyhow!("Ollama not reachable: {}", e))?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            return Err(anyhow::anyhow!("Ollama error ({}): {}", status, body));
+        }
+
+        let response_json: Value = response.json().await?;
+        let content = response_json["response"]
+            .as_str()
+            .ok_or_else(|| anyhow::anyhow!("No content in Ollama response"))?;
+
+        Ok(serde_json::from_str(extract_json(content).unwrap_or(content))?)
+    }
+}
@@ -0,0 +1,380 @@
+//! HTML Report
+//!
+//! Renders a finished session as one self-contained HTML page to share,
+//! e.g. with a coach: transcript with the suggestions shown where they
+//! arrived, summary scores, key moments, and talk-ratio and sentiment
+//! charts. Styles and charts are inline, so it opens offline.
+
+use chrono::{DateTime, Utc};
+
+use super::session::RecordingSession;
+use super::summary::{CallSummary, MomentSentiment};
+use super::{Speaker, SuggestionType};
+use crate::analytics::SentimentAnalyzer;
+use crate::config::Settings;
+use crate::ui::Theme;
+
+/// Chart size in SVG user units; the page scales it to fit
+const CHART_WIDTH: f32 = 600.0;
+const CHART_HEIGHT: f32 = 120.0;
+
+const REPORT_CSS: &str = r#"
+body { margin: 0; padding: 32px 16px; background: var(--bg-primary); color: var(--text-primary);
+    font: 15px/1.5 -apple-system, "Segoe UI", Roboto, sans-serif; }
+main { max-width: 760px; margin: 0 auto; }
+h1 { margin: 0 0 4px; font-size: 24px; }
+h2 { margin: 32px 0 12px; font-size: 17px; color: var(--text-secondary); }
+.meta { color: var(--text-muted); font-size: 13px; }
+.card { background: var(--bg-secondary); border: 1px solid var(--border-color); border-radius: 8px; padding: 12px 16px; }
+.scores { display: grid; grid-template-columns: 140px 1fr 40px; gap: 6px 12px; align-items: center; }
+.score-bar { height: 8px; border-radius: 4px; background: var(--bg-tertiary); overflow: hidden; }
+.score-bar span { display: block; height: 100%; background: var(--accent-blue); }
+.grade { font-size: 32px; font-weight: 700; color: var(--accent-green); }
+.chart { width: 100%; height: auto; }
+.legend { display: flex; gap: 16px; font-size: 13px; color: var(--text-secondary); }
+.turn { margin: 10px 0; }
+.turn .who { font-weight: 600; margin-right: 6px; }
+.turn.you .who { color: var(--accent-blue); }
+.turn.them .who { color: var(--accent-purple); }
+.turn.system { color: var(--text-muted); font-style: italic; }
+.time { color: var(--text-muted); font-size: 12px; margin-right: 6px; font-variant-numeric: tabular-nums; }
+.suggestion { margin: 6px 0 6px 24px; padding: 6px 10px; border-left: 3px solid var(--color-flash); background: var(--bg-tertiary); border-radius: 4px; font-size: 14px; white-space: pre-wrap; }
+.suggestion.deep { border-left-color: var(--color-deep); }
+.suggestion.question { border-left-color: var(--color-question); }
+.suggestion.warning { border-left-color: var(--color-warning); }
+.suggestion .kind { font-size: 11px; text-transform: uppercase; letter-spacing: 0.05em; color: var(--text-muted); margin-right: 6px; }
+.used { color: var(--color-success); font-size: 12px; margin-left: 6px; }
+.moment-flag { margin-left: 6px; color: var(--accent-yellow); }
+.moment { margin: 8px 0; padding-left: 10px; border-left: 3px solid var(--text-muted); }
+.moment.positive { border-left-color: var(--accent-green); }
+.moment.negative { border-left-color: var(--accent-orange); }
+.moment.critical { border-left-color: var(--accent-red); }
+.moment .ideal { color: var(--text-secondary); font-size: 14px; }
+ul { margin: 4px 0; padding-left: 20px; }
+footer { margin-top: 40px; color: var(--text-muted); font-size: 12px; text-align: center; }
+"#;
+
+/// The session as a standalone HTML page, styled with the user's theme
+pub fn export_html(session: &RecordingSession, summary: Option<&CallSummary>) -> String {
+    let theme_name = Settings::load().map(|s| s.ui.theme_name).unwrap_or_default();
+    let theme = Theme::by_key(&theme_name).unwrap_or_default();
+    render_html(session, summary, &theme)
+}
+
+fn render_html(session: &RecordingSession, summary: Option<&CallSummary>, theme: &Theme) -> String {
+    let mut html = String::new();
+    html.push_str("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n");
+    html.push_str("<meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n");
+    html.push_str(&format!(
+        "<title>{} call - {}</title>\n",
+        escape(&session.mode),
+        session.start_time.format("%Y-%m-%d %H:%M")
+    ));
+    html.push_str(&format!("<style>{}{}</style>\n</head>\n<body>\n<main>\n", theme.to_css_root(), REPORT_CSS));
+
+    html.push_str(&format!("<h1>{} call</h1>\n", escape(&capitalize(&session.mode))));
+    html.push_str(&format!(
+        "<div class=\"meta\">{} UTC · {} min · {} turns · {} of {} suggestions used</div>\n",
+        session.start_time.format("%Y-%m-%d %H:%M"),
+        session.duration().num_minutes(),
+        session.turns.iter().filter(|t| t.speaker != Speaker::System).count(),
+        session.metadata.suggestions_used,
+        session.metadata.total_suggestions
+    ));
+
+    if let Some(summary) = summary {
+        push_summary(&mut html, summary);
+    }
+
+    html.push_str("<h2>Talk ratio</h2>\n<div class=\"card\">\n");
+    html.push_str(&talk_ratio_svg(session.talk_ratio()));
+    html.push_str("</div>\n");
+
+    if let Some(chart) = sentiment_svg(session) {
+        html.push_str("<h2>Sentiment</h2>\n<div class=\"card\">\n");
+        html.push_str(&chart);
+        html.push_str("</div>\n");
+    }
+
+    if let Some(summary) = summary.filter(|s| !s.key_moments.is_empty()) {
+        html.push_str("<h2>Key moments</h2>\n");
+        for moment in &summary.key_moments {
+            let class = match moment.sentiment {
+                MomentSentiment::Positive => "positive",
+                MomentSentiment::Neutral => "neutral",
+                MomentSentiment::Negative => "negative",
+                MomentSentiment::Critical => "critical",
+            };
+            html.push_str(&format!("<div class=\"moment {}\">", class));
+            if let Some(at) = moment.timestamp {
+                html.push_str(&format!("<span class=\"time\">{}</span>", offset(moment_offset(session, at))));
+            }
+            html.push_str(&format!(
+                "“{}” — {}",
+                escape(&moment.quote),
+                escape(&moment.significance)
+            ));
+            if let Some(ideal) = &moment.ideal_response {
+                html.push_str(&format!("<div class=\"ideal\">Better: {}</div>", escape(ideal)));
+            }
+            html.push_str("</div>\n");
+        }
+    }
+
+    html.push_str("<h2>Transcript</h2>\n");
+    push_transcript(&mut html, session, summary);
+
+    html.push_str("<footer>Recorded with Voice Copilot</footer>\n</main>\n</body>\n</html>\n");
+    html
+}
+
+fn push_summary(html: &mut String, summary: &CallSummary) {
+    let score = &summary.score;
+    html.push_str("<h2>Summary</h2>\n<div class=\"card\">\n");
+    html.push_str(&format!(
+        "<div><span class=\"grade\">{}</span> {} / 100 — {}</div>\n",
+        escape(&score.grade),
+        score.overall,
+        escape(&score.assessment)
+    ));
+    html.push_str(&format!("<p>{}</p>\n", escape(summary.executive_summary.trim())));
+    html.push_str(&format!(
+        "<p>{} {}</p>\n",
+        summary.outcome.goal_achieved.emoji(),
+        escape(summary.outcome.goal_achieved.label())
+    ));
+
+    html.push_str("<div class=\"scores\">\n");
+    for (label, value) in [
+        ("Listening", score.listening),
+        ("Response quality", score.response_quality),
+        ("Delivery", score.delivery),
+        ("Suggestion usage", score.suggestion_usage),
+        ("Outcome", score.outcome),
+    ] {
+        html.push_str(&format!(
+            "<span>{}</span><div class=\"score-bar\"><span style=\"width: {}%\"></span></div><span>{}</span>\n",
+            label,
+            value.min(100),
+            value
+        ));
+    }
+    html.push_str("</div>\n");
+
+    for (title, items) in [
+        ("Did well", &summary.did_well),
+        ("Could improve", &summary.could_improve),
+        ("Next steps", &summary.next_steps),
+    ] {
+        if items.is_empty() {
+            continue;
+        }
+        html.push_str(&format!("<h3>{}</h3>\n<ul>\n", title));
+        for item in items {
+            html.push_str(&format!("<li>{}</li>\n", escape(item)));
+        }
+        html.push_str("</ul>\n");
+    }
+    html.push_str("</div>\n");
+}
+
+/// Turns with each suggestion placed after the turn it arrived during
+fn push_transcript(html: &mut String, session: &RecordingSession, summary: Option<&CallSummary>) {
+    let moments: Vec<DateTime<Utc>> = summary
+        .map(|s| s.key_moments.iter().filter_map(|m| m.timestamp).collect())
+        .unwrap_or_default();
+
+    let mut suggestions = session.suggestions.iter().peekable();
+    for (i, turn) in session.turns.iter().enumerate() {
+        let class = match turn.speaker {
+            Speaker::User => "you",
+            Speaker::Other => "them",
+            Speaker::System => "system",
+        };
+        html.push_str(&format!(
+            "<div class=\"turn {}\"><span class=\"time\">{}</span><span class=\"who\">{}</span>{}",
+            class,
+            offset(session.turn_span(turn).0),
+            turn.speaker.label(),
+            escape(&turn.text)
+        ));
+        if moments.contains(&turn.timestamp) {
+            html.push_str("<span class=\"moment-flag\" title=\"Key moment\">★</span>");
+        }
+        html.push_str("</div>\n");
+
+        let next_turn = session.turns.get(i + 1).map(|t| t.timestamp);
+        while let Some(suggestion) = suggestions.next_if(|s| next_turn.map_or(true, |next| s.timestamp < next)) {
+            let (class, kind) = match suggestion.suggestion_type {
+                SuggestionType::Flash => ("flash", "Flash"),
+                SuggestionType::Deep => ("deep", "Deep"),
+                SuggestionType::Question => ("question", "Ask"),
+                SuggestionType::Warning => ("warning", "Warning"),
+            };
+            html.push_str(&format!(
+                "<div class=\"suggestion {}\"><span class=\"kind\">{}</span>{}{}</div>\n",
+                class,
+                kind,
+                escape(&suggestion.content),
+                if suggestion.was_used { "<span class=\"used\">✓ used</span>" } else { "" }
+            ));
+        }
+    }
+}
+
+/// One bar split between you and them
+fn talk_ratio_svg(ratio: f32) -> String {
+    let yours = (ratio.clamp(0.0, 1.0) * CHART_WIDTH).round();
+    format!(
+        concat!(
+            "<svg class=\"chart\" viewBox=\"0 0 {w} 24\" role=\"img\" aria-label=\"Talk ratio\">",
+            "<rect x=\"0\" y=\"0\" width=\"{yours}\" height=\"24\" style=\"fill: var(--accent-blue)\"/>",
+            "<rect x=\"{yours}\" y=\"0\" width=\"{theirs}\" height=\"24\" style=\"fill: var(--accent-purple)\"/>",
+            "</svg>\n",
+            "<div class=\"legend\"><span>You {you_pct}%</span><span>Them {them_pct}%</span></div>\n"
+        ),
+        w = CHART_WIDTH,
+        yours = yours,
+        theirs = CHART_WIDTH - yours,
+        you_pct = (ratio * 100.0).round() as u32,
+        them_pct = 100 - (ratio * 100.0).round() as u32,
+    )
+}
+
+/// Polarity of each speaker's turns over the call, or None with too few turns
+fn sentiment_svg(session: &RecordingSession) -> Option<String> {
+    let span = session.duration().num_milliseconds().max(1) as f32;
+    let line = |speaker: Speaker| -> Vec<(f32, f32)> {
+        session
+            .turns
+            .iter()
+            .filter(|t| t.speaker == speaker)
+            .map(|t| {
+                let x = (session.turn_span(t).0 as f32 / span).clamp(0.0, 1.0) * CHART_WIDTH;
+                let polarity = SentimentAnalyzer::analyze_scored(&t.text).polarity;
+                let y = (1.0 - polarity.clamp(-1.0, 1.0)) / 2.0 * CHART_HEIGHT;
+                (x, y)
+            })
+            .collect()
+    };
+    let theirs = line(Speaker::Other);
+    let yours = line(Speaker::User);
+    if theirs.len() < 2 && yours.len() < 2 {
+        return None;
+    }
+
+    let polyline = |points: &[(f32, f32)], color: &str| {
+        if points.len() < 2 {
+            return String::new();
+        }
+        let points: Vec<String> = points.iter().map(|(x, y)| format!("{:.1},{:.1}", x, y)).collect();
+        format!(
+            "<polyline points=\"{}\" style=\"fill: none; stroke: var({}); stroke-width: 2\"/>",
+            points.join(" "),
+            color
+        )
+    };
+
+    Some(format!(
+        concat!(
+            "<svg class=\"chart\" viewBox=\"0 0 {w} {h}\" role=\"img\" aria-label=\"Sentiment over the call\">",
+            "<line x1=\"0\" y1=\"{mid}\" x2=\"{w}\" y2=\"{mid}\" style=\"stroke: var(--border-color); stroke-dasharray: 4 4\"/>",
+            "{theirs}{yours}</svg>\n",
+            "<div class=\"legend\"><span style=\"color: var(--accent-purple)\">Them</span>",
+            "<span style=\"color: var(--accent-blue)\">You</span><span>Above the line is positive</span></div>\n"
+        ),
+        w = CHART_WIDTH,
+        h = CHART_HEIGHT,
+        mid = CHART_HEIGHT / 2.0,
+        theirs = polyline(&theirs, "--accent-purple"),
+        yours = polyline(&yours, "--accent-blue"),
+    ))
+}
+
+/// Where a key moment sits in the call; moments are stamped with their turn's timestamp
+fn moment_offset(session: &RecordingSession, at: DateTime<Utc>) -> u64 {
+    match session.turns.iter().find(|t| t.timestamp == at) {
+        Some(turn) => session.turn_span(turn).0,
+        None => (at - session.start_time).num_milliseconds().max(0) as u64,
+    }
+}
+
+/// Time into the call, e.g. "12:05"
+fn offset(ms: u64) -> String {
+    let secs = ms / 1000;
+    format!("{}:{:02}", secs / 60, secs % 60)
+}
+
+fn capitalize(text: &str) -> String {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::recording::{RecordedSuggestion, RecordedTurn};
+    use crate::recording::summary::generate_quick_summary;
+
+    #[test]
+    fn test_render_html() {
+        let mut session = RecordingSession::new("sales");
+        let start = session.start_time;
+        let turn = |secs, speaker, text: &str| RecordedTurn {
+            timestamp: start + chrono::Duration::seconds(secs),
+            speaker,
+            text: text.to_string(),
+            duration_ms: 3000,
+            start_ms: None,
+            end_ms: None,
+        };
+        session.add_turn(turn(5, Speaker::Other, "This looks great, but is <script> safe?"));
+        session.add_suggestion(RecordedSuggestion {
+            timestamp: start + chrono::Duration::seconds(7),
+            suggestion_type: SuggestionType::Flash,
+            content: "Mention the SOC 2 report".to_string(),
+            was_used: false,
+            similarity: None,
+        });
+        session.add_turn(turn(12, Speaker::User, "Yes, we have a SOC 2 report I can send"));
+        session.add_turn(turn(20, Speaker::Other, "I hate the pricing though"));
+        session.end_session();
+
+        let summary = generate_quick_summary(&session);
+        let html = render_html(&session, Some(&summary), &Theme::default());
+
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("is &lt;script&gt; safe?"));
+        assert!(!html.contains("<script"));
+        // No external assets
+        assert!(!html.contains("src=") && !html.contains("href="));
+
+        // The suggestion sits between the turn it followed and your reply
+        let their_turn = html.find("safe?").unwrap();
+        let suggestion = html.find("Mention the SOC 2 report").unwrap();
+        let reply = html.find("we have a SOC 2 report").unwrap();
+        assert!(their_turn < suggestion && suggestion < reply);
+
+        assert!(html.contains("<polyline"));
+        assert!(html.contains("You 33%"));
+        assert!(html.contains(&format!("{} / 100", summary.score.overall)));
+    }
+}
@@ -4,9 +4,12 @@
 
 use chrono::{DateTime, Utc, Duration};
 use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
 use uuid::Uuid;
 
-use super::{Speaker, SuggestionType, SessionEvent};
+use super::usage::{is_substantive, similarity, USED_THRESHOLD};
+use crate::capture::{TranscriptIndex, TranscriptTurn};
+use super::{CallSummary, Speaker, SuggestionType, SessionEvent};
 
 /// A complete recording session
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -37,6 +40,10 @@ pub struct RecordingSession {
 
     /// Metadata
     pub metadata: SessionMetadata,
+
+    /// WAV file with the call audio, if audio recording was enabled
+    #[serde(default)]
+    pub audio_path: Option<PathBuf>,
 }
 
 impl RecordingSession {
@@ -51,6 +58,7 @@ impl RecordingSession {
             events: vec![(Utc::now(), SessionEvent::Started)],
             state: RecordingState::Recording,
             metadata: SessionMetadata::default(),
+            audio_path: None,
         }
     }
 
@@ -61,6 +69,7 @@ impl RecordingSession {
             Speaker::User => {
                 self.metadata.user_word_count += turn.text.split_whitespace().count();
                 self.metadata.user_talk_time_ms += turn.duration_ms;
+                self.score_suggestions(&turn.text);
             }
             Speaker::Other => {
                 self.metadata.other_word_count += turn.text.split_whitespace().count();
@@ -72,6 +81,22 @@ impl RecordingSession {
         self.turns.push(turn);
     }
 
+    /// Start and end of a turn, ms from the session start
+    ///
+    /// Older sessions only have `timestamp`, taken when the turn was
+    /// stored after speech ended (plus any debounce), so the start is
+    /// worked back from its duration.
+    pub fn turn_span(&self, turn: &RecordedTurn) -> (u64, u64) {
+        match (turn.start_ms, turn.end_ms) {
+            (Some(start), Some(end)) => (start, end.max(start)),
+            (Some(start), None) => (start, start + turn.duration_ms),
+            _ => {
+                let stored = (turn.timestamp - self.start_time).num_milliseconds().max(0) as u64;
+                (stored.saturating_sub(turn.duration_ms), stored)
+            }
+        }
+    }
+
     /// Add an AI suggestion
     pub fn add_suggestion(&mut self, suggestion: RecordedSuggestion) {
         self.metadata.total_suggestions += 1;
@@ -81,6 +106,25 @@ impl RecordingSession {
         self.suggestions.push(suggestion);
     }
 
+    /// Mark pending suggestions used if the user's turn follows them closely
+    ///
+    /// Each suggestion is judged once, by the first substantive turn the
+    /// user speaks after it.
+    fn score_suggestions(&mut self, spoken: &str) {
+        if !is_substantive(spoken) {
+            return;
+        }
+
+        for suggestion in self.suggestions.iter_mut().filter(|s| s.similarity.is_none()) {
+            let score = similarity(&suggestion.content, spoken);
+            suggestion.similarity = Some(score);
+            if score >= USED_THRESHOLD && !suggestion.was_used {
+                suggestion.was_used = true;
+                self.metadata.suggestions_used += 1;
+            }
+        }
+    }
+
     /// Add a session event
     pub fn add_event(&mut self, event: SessionEvent) {
         self.events.push((Utc::now(), event));
@@ -108,6 +152,20 @@ impl RecordingSession {
             .join("\n\n")
     }
 
+    /// Turns indexed for transcript search
+    pub fn transcript_index(&self) -> TranscriptIndex {
+        TranscriptIndex::new(
+            self.turns
+                .iter()
+                .filter(|t| t.speaker != Speaker::System)
+                .map(|t| TranscriptTurn {
+                    speaker: t.speaker.label().to_string(),
+                    text: t.text.clone(),
+                })
+                .collect(),
+        )
+    }
+
     /// Get user turns only
     pub fn user_turns(&self) -> Vec<&RecordedTurn> {
         self.turns.iter().filter(|t| t.speaker == Speaker::User).collect()
@@ -134,6 +192,49 @@ impl RecordingSession {
         }
         self.metadata.user_talk_time_ms as f32 / total as f32
     }
+
+    /// Markdown note for the lead's CRM record
+    ///
+    /// Uses the summary when there is one, otherwise lists the questions
+    /// they asked.
+    pub fn to_lead_note(&self, summary: Option<&CallSummary>) -> String {
+        let mut note = format!(
+            "### {} call - {}\n\nDuration: {} min",
+            self.mode,
+            self.start_time.format("%Y-%m-%d %H:%M UTC"),
+            self.duration().num_minutes()
+        );
+
+        let Some(summary) = summary else {
+            let questions = self.questions_asked();
+            if !questions.is_empty() {
+                note.push_str("\n\n**Their questions**");
+                for turn in questions {
+                    note.push_str(&format!("\n- {}", turn.text.trim()));
+                }
+            }
+            return note;
+        };
+
+        note.push_str(&format!(" | Score: {} ({})", summary.score.overall, summary.score.grade));
+        note.push_str(&format!("\n\n{}", summary.executive_summary.trim()));
+
+        if !summary.next_steps.is_empty() {
+            note.push_str("\n\n**Next steps**");
+            for step in &summary.next_steps {
+                note.push_str(&format!("\n- {}", step));
+            }
+        }
+
+        if !summary.key_moments.is_empty() {
+            note.push_str("\n\n**Key moments**");
+            for moment in &summary.key_moments {
+                note.push_str(&format!("\n- \"{}\" - {}", moment.quote, moment.significance));
+            }
+        }
+
+        note
+    }
 }
 
 /// Recording state
@@ -147,10 +248,20 @@ pub enum RecordingState {
 /// A single conversation turn
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RecordedTurn {
+    /// When the turn was stored, which is after it ended
     pub timestamp: DateTime<Utc>,
     pub speaker: Speaker,
     pub text: String,
     pub duration_ms: u64,
+    /// Start in the audio stream, ms from the session start
+    ///
+    /// Missing in sessions recorded before it was tracked; see
+    /// [`RecordingSession::turn_span`].
+    #[serde(default)]
+    pub start_ms: Option<u64>,
+    /// End in the audio stream, ms from the session start
+    #[serde(default)]
+    pub end_ms: Option<u64>,
 }
 
 impl RecordedTurn {
@@ -170,6 +281,9 @@ pub struct RecordedSuggestion {
     pub suggestion_type: SuggestionType,
     pub content: String,
     pub was_used: bool,
+    /// How closely the user's next turn matched (set once they've spoken)
+    #[serde(default)]
+    pub similarity: Option<f32>,
 }
 
 /// Session metadata
@@ -209,3 +323,90 @@ impl SessionMetadata {
         self.user_word_count as f32 / minutes
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::recording::summary::generate_quick_summary;
+
+    #[test]
+    fn test_lead_note() {
+        let mut session = RecordingSession::new("sales");
+        session.add_turn(RecordedTurn {
+            timestamp: Utc::now(),
+            speaker: Speaker::Other,
+            text: "Can we start with a pilot?".to_string(),
+            duration_ms: 2000,
+            start_ms: None,
+            end_ms: None,
+        });
+
+        let note = session.to_lead_note(None);
+        assert!(note.starts_with("### sales call - "));
+        assert!(note.ends_with("**Their questions**\n- Can we start with a pilot?"));
+
+        let summary = generate_quick_summary(&session);
+        let note = session.to_lead_note(Some(&summary));
+        assert!(note.contains("**Next steps**\n- Review the full transcript"));
+        assert!(!note.contains("Their questions"));
+    }
+
+    #[test]
+    fn test_paraphrased_suggestion_is_used() {
+        let mut session = RecordingSession::new("sales");
+        for content in ["Offer a 30-day pilot with no setup fee", "Ask who signs off on the budget"] {
+            session.add_suggestion(RecordedSuggestion {
+                timestamp: Utc::now(),
+                suggestion_type: SuggestionType::Flash,
+                content: content.to_string(),
+                was_used: false,
+                similarity: None,
+            });
+        }
+
+        let say = |session: &mut RecordingSession, text: &str| {
+            session.add_turn(RecordedTurn {
+                timestamp: Utc::now(),
+                speaker: Speaker::User,
+                text: text.to_string(),
+                duration_ms: 3000,
+                start_ms: None,
+                end_ms: None,
+            });
+        };
+
+        // Too short to judge; both stay pending
+        say(&mut session, "Sure, yeah.");
+        assert!(session.suggestions.iter().all(|s| s.similarity.is_none()));
+
+        say(&mut session, "We could start you on a thirty day pilot, and there's no setup fee at all.");
+        assert!(session.suggestions[0].was_used);
+        assert!(!session.suggestions[1].was_used);
+        assert!(session.suggestions[1].similarity.is_some());
+        assert_eq!(session.metadata.suggestion_usage_rate(), 0.5);
+    }
+
+    #[test]
+    fn test_turn_span() {
+        let mut session = RecordingSession::new("sales");
+        let stored = session.start_time + Duration::seconds(10);
+        session.add_turn(RecordedTurn {
+            timestamp: stored,
+            speaker: Speaker::Other,
+            text: "What does onboarding look like?".to_string(),
+            duration_ms: 2500,
+            start_ms: Some(6_800),
+            end_ms: Some(9_300),
+        });
+        assert_eq!(session.turn_span(&session.turns[0]), (6_800, 9_300));
+
+        // Saved before offsets were tracked: only the time it was stored
+        let json = format!(
+            r#"{{"timestamp": "{}", "speaker": "Other", "text": "Hi", "duration_ms": 2500}}"#,
+            stored.to_rfc3339()
+        );
+        let old: RecordedTurn = serde_json::from_str(&json).unwrap();
+        assert_eq!(old.start_ms, None);
+        assert_eq!(session.turn_span(&old), (7_500, 10_000));
+    }
+}
@@ -7,7 +7,12 @@ use chrono::{DateTime, Utc};
 use std::path::PathBuf;
 use tokio::fs;
 
+use super::pdf::export_pdf;
+use super::report::export_html;
+use super::minutes::Minutes;
 use super::session::RecordingSession;
+use super::subtitles::{export_srt, export_vtt};
+use super::summary::CallSummary;
 
 /// Get the recordings directory
 pub fn recordings_dir() -> PathBuf {
@@ -16,18 +21,27 @@ pub fn recordings_dir() -> PathBuf {
     base.join("VoiceCopilot").join("recordings")
 }
 
+/// File name shared by a session's JSON and audio files
+fn file_stem(session: &RecordingSession) -> String {
+    format!(
+        "{}_{}",
+        session.start_time.format("%Y%m%d_%H%M%S"),
+        &session.id[..8]
+    )
+}
+
+/// Where a session's audio is stored, next to its JSON
+pub fn audio_path(session: &RecordingSession) -> PathBuf {
+    recordings_dir().join(format!("{}.wav", file_stem(session)))
+}
+
 /// Save a recording to disk
 pub async fn save_recording(session: &RecordingSession) -> Result<PathBuf> {
     let dir = recordings_dir();
     fs::create_dir_all(&dir).await
         .context("Failed to create recordings directory")?;
 
-    let filename = format!(
-        "{}_{}.json",
-        session.start_time.format("%Y%m%d_%H%M%S"),
-        &session.id[..8]
-    );
-    let path = dir.join(&filename);
+    let path = dir.join(format!("{}.json", file_stem(session)));
 
     let json = serde_json::to_string_pretty(session)
         .context("Failed to serialize recording")?;
@@ -39,6 +53,67 @@ pub async fn save_recording(session: &RecordingSession) -> Result<PathBuf> {
     Ok(path)
 }
 
+/// Save the session as a standalone HTML report next to its JSON
+pub async fn save_html_report(session: &RecordingSession, summary: Option<&CallSummary>) -> Result<PathBuf> {
+    let dir = recordings_dir();
+    fs::create_dir_all(&dir).await
+        .context("Failed to create recordings directory")?;
+
+    let path = dir.join(format!("{}.html", file_stem(session)));
+    fs::write(&path, export_html(session, summary)).await
+        .context("Failed to write report")?;
+
+    tracing::info!("Report saved to: {:?}", path);
+    Ok(path)
+}
+
+/// Save the session's captions as `.srt` or, if `vtt`, `.vtt` next to its JSON
+pub async fn save_subtitles(session: &RecordingSession, vtt: bool) -> Result<PathBuf> {
+    let dir = recordings_dir();
+    fs::create_dir_all(&dir).await
+        .context("Failed to create recordings directory")?;
+
+    let (extension, content) = if vtt {
+        ("vtt", export_vtt(session))
+    } else {
+        ("srt", export_srt(session))
+    };
+    let path = dir.join(format!("{}.{}", file_stem(session), extension));
+    fs::write(&path, content).await
+        .context("Failed to write subtitles")?;
+
+    tracing::info!("Subtitles saved to: {:?}", path);
+    Ok(path)
+}
+
+/// Save meeting minutes as markdown next to the session's JSON
+pub async fn save_minutes_markdown(session: &RecordingSession, minutes: &Minutes) -> Result<PathBuf> {
+    let dir = recordings_dir();
+    fs::create_dir_all(&dir).await
+        .context("Failed to create recordings directory")?;
+
+    let path = dir.join(format!("{}_minutes.md", file_stem(session)));
+    fs::write(&path, minutes.to_markdown(session)).await
+        .context("Failed to write minutes")?;
+
+    tracing::info!("Minutes saved to: {:?}", path);
+    Ok(path)
+}
+
+/// Save the summary as a PDF next to the session's JSON
+pub async fn save_summary_pdf(session: &RecordingSession, summary: &CallSummary) -> Result<PathBuf> {
+    let dir = recordings_dir();
+    fs::create_dir_all(&dir).await
+        .context("Failed to create recordings directory")?;
+
+    let path = dir.join(format!("{}_summary.pdf", file_stem(session)));
+    fs::write(&path, export_pdf(summary)?).await
+        .context("Failed to write summary PDF")?;
+
+    tracing::info!("Summary PDF saved to: {:?}", path);
+    Ok(path)
+}
+
 /// Load a recording from disk
 pub async fn load_recording(id: &str) -> Result<RecordingSession> {
     let dir = recordings_dir();
@@ -49,6 +124,9 @@ pub async fn load_recording(id: &str) -> Result<RecordingSession> {
 
     while let Some(entry) = entries.next_entry().await? {
         let path = entry.path();
+        if path.extension().map(|e| e != "json").unwrap_or(true) {
+            continue;
+        }
         if let Some(name) = path.file_name().and_then(|n| n.to_str()) {
             if name.contains(id) {
                 let content = fs::read_to_string(&path).await
@@ -100,13 +178,14 @@ pub async fn list_recordings() -> Result<Vec<RecordingInfo>> {
     Ok(recordings)
 }
 
-/// Delete a recording
+/// Delete a recording and its audio
 pub async fn delete_recording(id: &str) -> Result<()> {
     let dir = recordings_dir();
 
     let mut entries = fs::read_dir(&dir).await
         .context("Failed to read recordings directory")?;
 
+    let mut deleted = false;
     while let Some(entry) = entries.next_entry().await? {
         let path = entry.path();
         if let Some(name) = path.file_name().and_then(|n| n.to_str()) {
@@ -114,12 +193,15 @@ pub async fn delete_recording(id: &str) -> Result<()> {
                 fs::remove_file(&path).await
                     .context("Failed to delete recording")?;
                 tracing::info!("Recording deleted: {:?}", path);
-                return Ok(());
+                deleted = true;
             }
         }
     }
 
-    anyhow::bail!("Recording not found: {}", id)
+    if !deleted {
+        anyhow::bail!("Recording not found: {}", id)
+    }
+    Ok(())
 }
 
 /// Recording info for list display
@@ -158,6 +240,9 @@ pub async fn export_recording(session: &RecordingSession, format: ExportFormat)
         ExportFormat::PlainText => {
             Ok(export_plain_text(session))
         }
+        ExportFormat::Html => {
+            Ok(export_html(session, None))
+        }
     }
 }
 
@@ -166,6 +251,8 @@ pub enum ExportFormat {
     Json,
     Markdown,
     PlainText,
+    /// Self-contained page; use `save_html_report` to include a summary
+    Html,
 }
 
 fn export_markdown(session: &RecordingSession) -> String {
@@ -0,0 +1,64 @@
+//! Recording Subtitles
+//!
+//! SRT and WebVTT captions for a recorded call, timed from each turn's
+//! offsets in the audio stream so they line up with the saved audio.
+
+use crate::analytics::{cues_to_srt, cues_to_vtt, Cue};
+use super::session::RecordingSession;
+use super::Speaker;
+
+/// Spoken turns as captions
+fn cues(session: &RecordingSession) -> impl Iterator<Item = Cue<'_>> {
+    session
+        .turns
+        .iter()
+        .filter(|turn| turn.speaker != Speaker::System)
+        .map(move |turn| {
+            let (start, end) = session.turn_span(turn);
+            (start, end, turn.speaker.label(), turn.text.as_str())
+        })
+}
+
+/// The call as SRT subtitles
+pub fn export_srt(session: &RecordingSession) -> String {
+    cues_to_srt(cues(session))
+}
+
+/// The call as WebVTT subtitles
+pub fn export_vtt(session: &RecordingSession) -> String {
+    cues_to_vtt(cues(session))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::recording::RecordedTurn;
+
+    #[test]
+    fn test_cues_use_stream_offsets() {
+        let mut session = RecordingSession::new("sales");
+        let stored = session.start_time + chrono::Duration::seconds(30);
+        for (speaker, text, start_ms, end_ms) in [
+            (Speaker::Other, "What does it cost?", Some(61_200), Some(63_000)),
+            (Speaker::System, "Paused", None, None),
+            (Speaker::User, "Fifty a seat.", None, None),
+        ] {
+            session.add_turn(RecordedTurn {
+                timestamp: stored,
+                speaker,
+                text: text.to_string(),
+                duration_ms: 1500,
+                start_ms,
+                end_ms,
+            });
+        }
+
+        let srt = export_srt(&session);
+        assert!(srt.starts_with("1\n00:01:01,200 --> 00:01:03,000\nThem: What does it cost?\n\n"));
+        // No offsets: worked back from when the turn was stored
+        assert!(srt.contains("2\n00:00:28,500 --> 00:00:30,000\nYou: Fifty a seat."));
+        assert!(!srt.contains("Paused"));
+
+        assert!(export_vtt(&session).contains("00:01:01.200 --> 00:01:03.000\n<v Them>What does it cost?"));
+    }
+}
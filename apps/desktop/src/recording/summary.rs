@@ -10,8 +10,12 @@
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use chrono::{DateTime, Utc};
+use std::collections::HashSet;
 
 use super::session::RecordingSession;
+use super::Speaker;
+use super::providers::{AnthropicSummaryProvider, OllamaSummaryProvider, OpenAiSummaryProvider, SummaryProvider};
+use crate::config::{ApiKeys, FlashModel, ModelSettings};
 
 /// Complete call summary with self-analysis
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -232,8 +236,8 @@ impl GoalStatus {
 /// Key moment in the conversation
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct KeyMoment {
-    /// When it happened
-    pub timestamp: DateTime<Utc>,
+    /// When it happened (None if the quote couldn't be matched to a turn)
+    pub timestamp: Option<DateTime<Utc>>,
 
     /// What was said
     pub quote: String,
@@ -275,18 +279,156 @@ pub struct SelfAnalysis {
     pub ideal_comparison: String,
 }
 
-/// Generate a comprehensive call summary using AI
+/// What you said next to what you could have said
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResponseComparison {
+    /// The moment you were responding to
+    pub moment: KeyMoment,
+
+    /// Your actual reply (the next turn you spoke)
+    pub what_you_said: String,
+
+    /// The suggested better reply
+    pub ideal: String,
+
+    /// Why the moment mattered
+    pub reason: String,
+
+    /// How close your reply was to the ideal (0-100)
+    pub similarity: u32,
+}
+
+impl SelfAnalysis {
+    /// Pair each key moment's ideal response with what you actually said
+    ///
+    /// Sorted by similarity, lowest first, so the biggest gaps come first.
+    /// Moments without an ideal response or a reply from you are skipped.
+    pub fn response_comparisons(summary: &CallSummary, session: &RecordingSession) -> Vec<ResponseComparison> {
+        let mut comparisons: Vec<ResponseComparison> = summary
+            .key_moments
+            .iter()
+            .filter_map(|moment| {
+                let ideal = moment.ideal_response.as_ref()?;
+                let index = find_quote_turn(session, &moment.quote)?;
+                let reply = session.turns[index + 1..]
+                    .iter()
+                    .find(|t| t.speaker == Speaker::User)?;
+
+                Some(ResponseComparison {
+                    moment: moment.clone(),
+                    what_you_said: reply.text.clone(),
+                    ideal: ideal.clone(),
+                    reason: moment.significance.clone(),
+                    similarity: response_similarity(&reply.text, ideal),
+                })
+            })
+            .collect();
+
+        comparisons.sort_by_key(|c| c.similarity);
+        comparisons
+    }
+}
+
+/// System prompt shared by every summary provider
+const SUMMARY_SYSTEM_PROMPT: &str = "You are an expert conversation analyst and coach. Analyze conversations and provide actionable, honest feedback.";
+
+/// Which backend produces call summaries
+#[derive(Debug, Clone, PartialEq)]
+pub enum SummaryBackend {
+    /// OpenAI chat completions
+    OpenAi { api_key: String, model: String },
+    /// Anthropic Messages API
+    Anthropic { api_key: String, model: String },
+    /// Local Ollama server; works offline
+    Ollama { model: String },
+    /// No AI available; heuristic summary only
+    Quick,
+}
+
+impl SummaryBackend {
+    /// Pick a backend from the configured providers
+    ///
+    /// Users on local flash get a local summary; otherwise OpenAI is used,
+    /// then Anthropic, depending on which key is available.
+    pub fn from_settings(models: &ModelSettings, api_keys: &ApiKeys) -> Self {
+        if models.flash_model == FlashModel::LocalOllama {
+            return Self::Ollama {
+                model: DEFAULT_OLLAMA_SUMMARY_MODEL.to_string(),
+            };
+        }
+
+        let usable = |key: &Option<String>| key.clone().filter(|k| !k.is_empty());
+
+        if let Some(api_key) = usable(&api_keys.openai) {
+            return Self::OpenAi {
+                api_key,
+                model: "gpt-4o".to_string(),
+            };
+        }
+
+        if let Some(api_key) = usable(&api_keys.anthropic) {
+            return Self::Anthropic {
+                api_key,
+                model: "claude-3-5-sonnet-20241022".to_string(),
+            };
+        }
+
+        Self::Quick
+    }
+
+    /// Provider for this backend (None for the heuristic summary)
+    pub fn provider(&self) -> Option<Box<dyn SummaryProvider>> {
+        match self {
+            Self::OpenAi { api_key, model } => Some(Box::new(OpenAiSummaryProvider::new(api_key, model))),
+            Self::Anthropic { api_key, model } => Some(Box::new(AnthropicSummaryProvider::new(api_key, model))),
+            Self::Ollama { model } => Some(Box::new(OllamaSummaryProvider::new(model))),
+            Self::Quick => None,
+        }
+    }
+}
+
+/// Ollama model used for summaries by default
+pub const DEFAULT_OLLAMA_SUMMARY_MODEL: &str = "llama3.1:8b";
+
+/// Generate a call summary with the configured backend
+pub async fn generate_summary(session: &RecordingSession, backend: &SummaryBackend) -> Result<CallSummary> {
+    match backend.provider() {
+        Some(provider) => generate_call_summary_with(session, provider.as_ref()).await,
+        None => Ok(generate_quick_summary(session)),
+    }
+}
+
+/// Generate a comprehensive call summary with any provider
+pub async fn generate_call_summary_with(
+    session: &RecordingSession,
+    provider: &dyn SummaryProvider,
+) -> Result<CallSummary> {
+    let prompt = build_summary_prompt(session);
+    let analysis = provider.complete_json(SUMMARY_SYSTEM_PROMPT, &prompt).await?;
+    Ok(parse_summary(session, &analysis))
+}
+
+/// Generate a comprehensive call summary using OpenAI
 pub async fn generate_call_summary(
     session: &RecordingSession,
     api_key: &str,
     model: &str,
 ) -> Result<CallSummary> {
+    generate_call_summary_with(session, &OpenAiSummaryProvider::new(api_key, model)).await
+}
+
+/// Generate a call summary with a local Ollama model
+pub async fn generate_call_summary_ollama(session: &RecordingSession, model: &str) -> Result<CallSummary> {
+    generate_call_summary_with(session, &OllamaSummaryProvider::new(model)).await
+}
+
+/// Build the analysis prompt for a session
+fn build_summary_prompt(session: &RecordingSession) -> String {
     let transcript = session.full_transcript();
     let mode = &session.mode;
     let duration = session.duration();
 
-    // Build the analysis prompt
-    let prompt = format!(
+    format!(
         r#"Analyze this {mode} conversation and provide a comprehensive assessment.
 
 TRANSCRIPT:
@@ -345,43 +487,11 @@ Be honest and constructive. Focus on actionable insights."#,
         other_pct = ((1.0 - session.talk_ratio()) * 100.0) as u32,
         suggestions = session.metadata.total_suggestions,
         used = session.metadata.suggestions_used,
-    );
+    )
+}
 
-    // Call the AI API (using OpenAI format)
-    let client = reqwest::Client::new();
-    let response = client
-        .post("https://api.openai.com/v1/chat/completions")
-        .header("Authorization", format!("Bearer {}", api_key))
-        .header("Content-Type", "application/json")
-        .json(&serde_json::json!({
-            "model": model,
-            "messages": [
-                {
-                    "role": "system",
-                    "content": "You are an expert conversation analyst and coach. Analyze conversations and provide actionable, honest feedback."
-                },
-                {
-                    "role": "user",
-                    "content": prompt
-                }
-            ],
-            "temperature": 0.7,
-            "max_tokens": 2000,
-            "response_format": { "type": "json_object" }
-        }))
-        .send()
-        .await?;
-
-    let response_json: serde_json::Value = response.json().await?;
-
-    // Parse the response
-    let content = response_json["choices"][0]["message"]["content"]
-        .as_str()
-        .ok_or_else(|| anyhow::anyhow!("No content in response"))?;
-
-    let analysis: serde_json::Value = serde_json::from_str(content)?;
-
-    // Build the CallSummary
+/// Turn the model's JSON analysis into a CallSummary
+fn parse_summary(session: &RecordingSession, analysis: &serde_json::Value) -> CallSummary {
     let scores = &analysis["scores"];
     let score = PerformanceScore::calculate(
         scores["listening"].as_u64().unwrap_or(70) as u32,
@@ -429,7 +539,8 @@ Be honest and constructive. Focus on actionable insights."#,
             moments
                 .iter()
                 .map(|m| KeyMoment {
-                    timestamp: Utc::now(), // Would need actual timestamps
+                    timestamp: find_quote_turn(session, m["quote"].as_str().unwrap_or(""))
+                        .map(|i| session.turns[i].timestamp),
                     quote: m["quote"].as_str().unwrap_or("").to_string(),
                     significance: m["significance"].as_str().unwrap_or("").to_string(),
                     sentiment: match m["sentiment"].as_str().unwrap_or("neutral") {
@@ -444,7 +555,7 @@ Be honest and constructive. Focus on actionable insights."#,
         })
         .unwrap_or_default();
 
-    Ok(CallSummary {
+    CallSummary {
         session_id: session.id.clone(),
         generated_at: Utc::now(),
         score,
@@ -461,7 +572,71 @@ Be honest and constructive. Focus on actionable insights."#,
             .as_str()
             .unwrap_or("Summary not available")
             .to_string(),
-    })
+    }
+}
+
+/// Share of quote words a turn must contain to count as a match
+const QUOTE_MATCH_THRESHOLD: f32 = 0.6;
+
+/// Find the index of the recorded turn a key-moment quote came from
+///
+/// Models often trim or paraphrase quotes, so this tries a normalized
+/// substring match first and falls back to word overlap.
+fn find_quote_turn(session: &RecordingSession, quote: &str) -> Option<usize> {
+    let quote = normalize_quote(quote);
+    if quote.is_empty() {
+        return None;
+    }
+
+    if let Some(index) = session
+        .turns
+        .iter()
+        .position(|t| normalize_quote(&t.text).contains(&quote))
+    {
+        return Some(index);
+    }
+
+    let quote_words: Vec<&str> = quote.split(' ').collect();
+    session
+        .turns
+        .iter()
+        .enumerate()
+        .rev() // max_by keeps the last maximum; prefer the earliest turn
+        .map(|(index, turn)| {
+            let text = normalize_quote(&turn.text);
+            let words: HashSet<&str> = text.split(' ').collect();
+            let hits = quote_words.iter().filter(|w| words.contains(*w)).count();
+            (index, hits as f32 / quote_words.len() as f32)
+        })
+        .filter(|(_, score)| *score >= QUOTE_MATCH_THRESHOLD)
+        .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal))
+        .map(|(index, _)| index)
+}
+
+/// Word-overlap similarity between two responses (0-100, Dice coefficient)
+fn response_similarity(a: &str, b: &str) -> u32 {
+    let a = normalize_quote(a);
+    let b = normalize_quote(b);
+    let a_words: HashSet<&str> = a.split(' ').filter(|w| !w.is_empty()).collect();
+    let b_words: HashSet<&str> = b.split(' ').filter(|w| !w.is_empty()).collect();
+
+    if a_words.is_empty() || b_words.is_empty() {
+        return 0;
+    }
+
+    let shared = a_words.intersection(&b_words).count();
+    (200 * shared / (a_words.len() + b_words.len())) as u32
+}
+
+/// Lowercase and strip punctuation so quotes compare loosely
+fn normalize_quote(text: &str) -> String {
+    text.to_lowercase()
+        .chars()
+        .map(|c| if c.is_alphanumeric() || c == '\'' { c } else { ' ' })
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
 }
 
 fn extract_string_array(value: &serde_json::Value) -> Vec<String> {
@@ -528,3 +703,151 @@ pub fn generate_quick_summary(session: &RecordingSession) -> CallSummary {
         ),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backend_follows_flash_provider() {
+        let mut models = ModelSettings::default();
+        let mut keys = ApiKeys::default();
+        assert_eq!(SummaryBackend::from_settings(&models, &keys), SummaryBackend::Quick);
+
+        keys.openai = Some("sk-test".to_string());
+        assert!(matches!(SummaryBackend::from_settings(&models, &keys), SummaryBackend::OpenAi { .. }));
+
+        models.flash_model = FlashModel::LocalOllama;
+        assert_eq!(
+            SummaryBackend::from_settings(&models, &keys),
+            SummaryBackend::Ollama { model: DEFAULT_OLLAMA_SUMMARY_MODEL.to_string() }
+        );
+    }
+
+    #[test]
+    fn test_parse_summary() {
+        let session = RecordingSession::new("sales");
+        let analysis = serde_json::json!({
+            "caller_needs": ["Lower price"],
+            "delivery": { "pace": "too_fast" },
+            "outcome": { "status": "partial", "proximity": 60 },
+            "scores": { "listening": 90, "response_quality": 80, "delivery": 70, "suggestion_usage": 60, "outcome": 50 },
+            "executive_summary": "Good call."
+        });
+
+        let summary = parse_summary(&session, &analysis);
+        assert_eq!(summary.session_id, session.id);
+        assert_eq!(summary.caller_needs, vec!["Lower price"]);
+        assert_eq!(summary.score.overall, 70);
+        assert!(matches!(summary.delivery_analysis.pace, PaceAssessment::TooFast));
+        assert!(matches!(summary.outcome.goal_achieved, GoalStatus::PartiallyAchieved));
+        assert_eq!(summary.executive_summary, "Good call.");
+    }
+
+    use crate::recording::RecordedTurn;
+
+    #[test]
+    fn test_key_moment_timestamps_match_turns() {
+        let mut session = RecordingSession::new("sales");
+        let start = session.start_time;
+        let texts = [
+            (Speaker::Other, "Honestly, your price is way higher than the competition."),
+            (Speaker::User, "I hear you. Let me walk through what's included."),
+            (Speaker::Other, "Okay, if you can do a pilot we could sign this quarter!"),
+        ];
+        for (i, (speaker, text)) in texts.into_iter().enumerate() {
+            session.add_turn(RecordedTurn {
+                timestamp: start + chrono::Duration::seconds(i as i64 * 30),
+                speaker,
+                text: text.to_string(),
+                duration_ms: 3000,
+                start_ms: None,
+                end_ms: None,
+            });
+        }
+
+        let analysis = serde_json::json!({
+            "key_moments": [
+                { "quote": "Your price is WAY higher than the competition" },
+                { "quote": "we could sign this quarter if you do a pilot" },
+                { "quote": "Tell me about your roadmap" }
+            ]
+        });
+
+        let moments = parse_summary(&session, &analysis).key_moments;
+        assert_eq!(moments[0].timestamp, Some(start));
+        assert_eq!(moments[1].timestamp, Some(start + chrono::Duration::seconds(60)));
+        assert_eq!(moments[2].timestamp, None);
+    }
+
+    #[test]
+    fn test_response_comparisons() {
+        let mut session = RecordingSession::new("sales");
+        for (speaker, text) in [
+            (Speaker::Other, "This is too expensive for us."),
+            (Speaker::User, "Well, it is what it is."),
+            (Speaker::Other, "Can you send the contract?"),
+            (Speaker::User, "Sure, I'll send the contract today."),
+        ] {
+            session.add_turn(RecordedTurn {
+                timestamp: Utc::now(),
+                speaker,
+                text: text.to_string(),
+                duration_ms: 2000,
+                start_ms: None,
+                end_ms: None,
+            });
+        }
+
+        let analysis = serde_json::json!({
+            "key_moments": [
+                {
+                    "quote": "Can you send the contract?",
+                    "significance": "Buying signal",
+                    "ideal_response": "Sure, I'll send the contract today."
+                },
+                {
+                    "quote": "This is too expensive for us",
+                    "significance": "Price objection",
+                    "ideal_response": "Compared to the cost of the problem, most teams see payback in a quarter."
+                },
+                { "quote": "Can you send the contract?", "significance": "No ideal given" }
+            ]
+        });
+        let summary = parse_summary(&session, &analysis);
+
+        let comparisons = SelfAnalysis::response_comparisons(&summary, &session);
+        assert_eq!(comparisons.len(), 2);
+        assert_eq!(comparisons[0].reason, "Price objection");
+        assert_eq!(comparisons[0].what_you_said, "Well, it is what it is.");
+        assert!(comparisons[0].similarity < 30);
+        assert_eq!(comparisons[1].similarity, 100);
+    }
+
+    struct FixedProvider(serde_json::Value);
+
+    #[async_trait::async_trait]
+    impl SummaryProvider for FixedProvider {
+        async fn complete_json(&self, _system: &str, user: &str) -> Result<serde_json::Value> {
+            assert!(user.contains("TRANSCRIPT:"));
+            Ok(self.0.clone())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_summary_with_custom_provider() {
+        let session = RecordingSession::new("interview");
+        let provider = FixedProvider(serde_json::json!({ "next_steps": ["Send thank-you note"] }));
+
+        let summary = generate_call_summary_with(&session, &provider).await.unwrap();
+        assert_eq!(summary.next_steps, vec!["Send thank-you note"]);
+    }
+
+    #[tokio::test]
+    #[ignore] // Requires Ollama running
+    async fn test_ollama_summary() {
+        let session = RecordingSession::new("sales");
+        let summary = generate_call_summary_ollama(&session, DEFAULT_OLLAMA_SUMMARY_MODEL).await;
+        assert!(summary.is_ok());
+    }
+}
@@ -0,0 +1,88 @@
+//! Suggestion Usage
+//!
+//! Decides whether the user actually said a suggestion by comparing their
+//! next turn with it. Scoring is cosine similarity over content words
+//! (stop words dropped, plurals and -ing/-ed trimmed), taken per sentence
+//! so one line lifted from a long deep response still counts.
+
+use std::collections::HashSet;
+
+/// Similarity at which a suggestion counts as used
+pub const USED_THRESHOLD: f32 = 0.4;
+
+/// Turns with fewer content words ("yeah, sure") don't settle anything
+const MIN_SPOKEN_WORDS: usize = 3;
+
+const STOP_WORDS: &[&str] = &[
+    "a", "also", "am", "an", "and", "are", "as", "at", "be", "but", "by", "can", "could", "do",
+    "does", "for", "from", "have", "i", "if", "in", "is", "it", "it's", "its", "just", "let",
+    "me", "my", "of", "on", "or", "our", "so", "that", "the", "their", "there", "they", "this",
+    "to", "was", "we", "we'll", "we're", "will", "with", "would", "yeah", "yes", "you", "your",
+];
+
+/// Content words, lightly stemmed
+fn content_words(text: &str) -> HashSet<String> {
+    text.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric() && c != '\'')
+        .map(|w| w.trim_matches('\''))
+        .filter(|w| !w.is_empty() && !STOP_WORDS.contains(w))
+        .map(stem)
+        .collect()
+}
+
+fn stem(word: &str) -> String {
+    for suffix in ["ing", "ed", "es", "s"] {
+        if let Some(root) = word.strip_suffix(suffix) {
+            if root.chars().count() >= 3 {
+                return root.to_string();
+            }
+        }
+    }
+    word.to_string()
+}
+
+fn cosine(a: &HashSet<String>, b: &HashSet<String>) -> f32 {
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+    a.intersection(b).count() as f32 / ((a.len() * b.len()) as f32).sqrt()
+}
+
+/// Whether a turn says enough to judge the pending suggestions by
+pub fn is_substantive(spoken: &str) -> bool {
+    content_words(spoken).len() >= MIN_SPOKEN_WORDS
+}
+
+/// How closely what they said follows a suggestion (0.0 to 1.0)
+pub fn similarity(suggestion: &str, spoken: &str) -> f32 {
+    let spoken = content_words(spoken);
+    suggestion
+        .split(|c| matches!(c, '.' | '!' | '?' | '\n'))
+        .chain(std::iter::once(suggestion))
+        .map(|sentence| cosine(&content_words(sentence), &spoken))
+        .fold(0.0, f32::max)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_similarity() {
+        // Paraphrased
+        let score = similarity(
+            "Offer a 30-day pilot with no setup fee",
+            "We could start you on a 30 day pilot, and there's no setup fee",
+        );
+        assert!(score >= USED_THRESHOLD, "{}", score);
+
+        // One sentence of a longer deep response
+        let deep = "Acknowledge the concern. We're SOC 2 Type II certified and run annual penetration tests. Offer to send the report.";
+        assert!(similarity(deep, "Good question - we are SOC 2 Type II certified, and we run penetration tests every year") >= USED_THRESHOLD);
+
+        // Unrelated
+        assert!(similarity("Offer a 30-day pilot with no setup fee", "Let me share my screen and walk through the dashboard") < 0.1);
+
+        assert!(!is_substantive("Yeah, sure"));
+    }
+}
@@ -0,0 +1,117 @@
+//! PII Redaction
+//!
+//! Masks emails, phone numbers, card numbers, SSNs and custom keywords
+//! before transcripts are recorded or exported.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+static EMAIL: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?i)\b[A-Z0-9._%+-]+@[A-Z0-9.-]+\.[A-Z]{2,}\b").unwrap());
+static SSN: Lazy<Regex> = Lazy::new(|| Regex::new(r"\b\d{3}-\d{2}-\d{4}\b").unwrap());
+static CARD: Lazy<Regex> = Lazy::new(|| Regex::new(r"\b\d(?:[ -]?\d){12,18}\b").unwrap());
+static PHONE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?:\+\d{1,3}[ .-]?)?(?:\(\d{3}\)|\b\d{3})[ .-]?\d{3}[ .-]?\d{4}\b").unwrap()
+});
+
+/// What to mask
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RedactionRules {
+    pub emails: bool,
+    pub phone_numbers: bool,
+    pub card_numbers: bool,
+    pub ssns: bool,
+    /// Extra words or phrases to mask (case-insensitive)
+    pub keywords: Vec<String>,
+}
+
+impl Default for RedactionRules {
+    fn default() -> Self {
+        Self {
+            emails: true,
+            phone_numbers: true,
+            card_numbers: true,
+            ssns: true,
+            keywords: Vec::new(),
+        }
+    }
+}
+
+/// Mask sensitive data in text
+pub fn redact(text: &str, rules: &RedactionRules) -> String {
+    let mut out = text.to_string();
+
+    if let Some(re) = keyword_regex(&rules.keywords) {
+        out = re.replace_all(&out, "[REDACTED]").into_owned();
+    }
+    if rules.emails {
+        out = EMAIL.replace_all(&out, "[EMAIL]").into_owned();
+    }
+    // SSNs and cards before phones so their digits aren't half-matched
+    if rules.ssns {
+        out = SSN.replace_all(&out, "[SSN]").into_owned();
+    }
+    if rules.card_numbers {
+        out = CARD.replace_all(&out, "[CARD]").into_owned();
+    }
+    if rules.phone_numbers {
+        out = PHONE.replace_all(&out, "[PHONE]").into_owned();
+    }
+
+    out
+}
+
+fn keyword_regex(keywords: &[String]) -> Option<Regex> {
+    let alternatives: Vec<String> = keywords
+        .iter()
+        .map(|k| k.trim())
+        .filter(|k| !k.is_empty())
+        .map(regex::escape)
+        .collect();
+
+    if alternatives.is_empty() {
+        return None;
+    }
+
+    Regex::new(&format!(r"(?i)\b(?:{})\b", alternatives.join("|"))).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_redacts_contact_details() {
+        let rules = RedactionRules::default();
+        assert_eq!(
+            redact("Email jane.doe@example.com or call (555) 123-4567", &rules),
+            "Email [EMAIL] or call [PHONE]"
+        );
+        assert_eq!(redact("Reach me on +1 555.123.4567", &rules), "Reach me on [PHONE]");
+    }
+
+    #[test]
+    fn test_redacts_cards_and_ssns() {
+        let rules = RedactionRules::default();
+        assert_eq!(
+            redact("Card 4111 1111 1111 1111, SSN 123-45-6789", &rules),
+            "Card [CARD], SSN [SSN]"
+        );
+        assert_eq!(redact("We need 25 seats by 2025", &rules), "We need 25 seats by 2025");
+    }
+
+    #[test]
+    fn test_keywords_and_disabled_rules() {
+        let rules = RedactionRules {
+            emails: false,
+            keywords: vec!["Project Falcon".to_string(), " ".to_string()],
+            ..RedactionRules::default()
+        };
+        assert_eq!(
+            redact("project falcon ships to bob@acme.io", &rules),
+            "[REDACTED] ships to bob@acme.io"
+        );
+    }
+}
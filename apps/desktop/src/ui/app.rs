@@ -15,9 +15,18 @@ use parking_lot::RwLock;
 use crate::brain::{CopilotPipeline, PipelineConfig, CopilotState as PipelineCopilotState, PipelineEvent, FlashModelChoice};
 use crate::deep::ModelChoice;
 use crate::flash::{FlashAnalysis, Bullet as FlashBullet};
-use crate::capture::{AudioCaptureState, AudioSource, CaptureApp, get_available_sources, detect_running_apps};
-use crate::config::Settings;
-use super::runtime::SharedState;
+use crate::capture::{AudioCaptureState, AudioLevel, AudioSource, CaptureApp, TranscriptIndex, get_available_sources, detect_running_apps};
+use crate::config::{ObjectionEntry, OutreachAccount, ResponseMode, Settings, WakeWordSettings};
+use crate::recording::ActionItem;
+use crate::i18n::{tr, Language};
+use super::runtime::{OverlayWindow, SharedState, MIN_OVERLAY_OPACITY};
+use super::hotkeys::HotkeyAction;
+use super::markdown::render_markdown;
+use super::styles::get_theme_vars;
+use super::stealth::{commands as stealth_commands, StealthMode};
+use super::screen_share::ScreenShareEvent;
+use super::tray::TrayAction;
+use super::components::{CallBrief, ExportReport, ExportToLead, MeetingMinutes, TranscriptView};
 
 /// UI display mode
 #[derive(Debug, Clone, Default, PartialEq)]
@@ -55,14 +64,32 @@ pub struct AppState {
     pub mode: CopilotMode,
     /// Current transcript from the other person
     pub transcript: String,
+    /// Transcript line was too unreliable to analyze
+    pub transcript_low_confidence: bool,
+    /// Turns of the current or last call, for search
+    pub transcript_index: TranscriptIndex,
+    /// Concerns detected in their latest turn, best first
+    pub intents: Vec<IntentBadge>,
+    /// Competitor named in their latest turn, highlighted in the transcript
+    pub competitor: Option<String>,
+    /// Saved response to the objection in their latest turn
+    pub objection: Option<ObjectionEntry>,
+    /// Commitments picked up so far in a Meeting or Coaching call
+    pub action_items: Vec<ActionItem>,
     /// Flash response (quick bullets)
     pub flash_response: Option<FlashResponse>,
     /// Deep response (detailed answer, streams in)
     pub deep_response: Option<DeepResponse>,
+    /// Whether Deep responses are shown
+    pub response_mode: ResponseMode,
+    /// Listening without any model to analyze with
+    pub transcription_only: bool,
     /// Connection status
     pub status: ConnectionStatus,
     /// Selected audio source
     pub audio_source: AudioSource,
+    /// Remembered source that wasn't available at launch, e.g. "Zoom"
+    pub missing_source: Option<String>,
     /// Available audio sources
     pub available_sources: Vec<AudioSource>,
     /// UI display mode
@@ -71,6 +98,32 @@ pub struct AppState {
     pub settings_open: bool,
     /// Whether audio source picker is open
     pub source_picker_open: bool,
+    /// Latency of the last turn, e.g. "Flash 680ms / Deep 1.2s"
+    pub latency: Option<String>,
+    /// Brief confirmation, e.g. "Copied to clipboard"
+    pub notice: Option<String>,
+    /// Opacity and click-through for overlay mode
+    pub overlay: OverlayWindow,
+    /// Call app sharing the screen, while the window could be visible on it
+    pub screen_share: Option<String>,
+    /// Follow-up date suggested during the last call
+    pub suggested_followup: Option<chrono::DateTime<chrono::Utc>>,
+    /// Border is flashing for an urgent suggestion
+    pub alert: bool,
+    /// Capture input level while listening, for the meter
+    pub audio_level: Option<AudioLevel>,
+    /// Shortcuts the OS refused to register
+    pub hotkey_errors: Vec<String>,
+    /// Background on who the call is with
+    pub brief: String,
+    /// Active theme key, e.g. "light"
+    pub theme_name: String,
+    /// `:root` overrides for the active theme
+    pub theme_css: String,
+    /// Format Deep responses as markdown (off shows the raw text)
+    pub render_markdown: bool,
+    /// Language the overlay is shown in
+    pub language: Language,
 }
 
 #[derive(Debug, Clone, Default, PartialEq)]
@@ -79,15 +132,47 @@ pub enum CopilotMode {
     Sales,
     Interview,
     Technical,
+    /// Minutes and action items instead of coaching
+    Meeting,
     General,
 }
 
 impl CopilotMode {
+    /// All modes, in selector order
+    pub fn all() -> Vec<Self> {
+        vec![
+            CopilotMode::Sales,
+            CopilotMode::Interview,
+            CopilotMode::Technical,
+            CopilotMode::Meeting,
+            CopilotMode::General,
+        ]
+    }
+
+    /// Next mode in selector order, wrapping around
+    pub fn next(&self) -> Self {
+        let all = Self::all();
+        let index = all.iter().position(|m| m == self).unwrap_or(0);
+        all[(index + 1) % all.len()].clone()
+    }
+
+    /// Stable identifier used as a settings key
+    pub fn key(&self) -> &'static str {
+        match self {
+            CopilotMode::Sales => "sales",
+            CopilotMode::Interview => "interview",
+            CopilotMode::Technical => "technical",
+            CopilotMode::Meeting => "meeting",
+            CopilotMode::General => "general",
+        }
+    }
+
     pub fn label(&self) -> &'static str {
         match self {
             CopilotMode::Sales => "Sales Call",
             CopilotMode::Interview => "Interview",
             CopilotMode::Technical => "Technical",
+            CopilotMode::Meeting => "Meeting",
             CopilotMode::General => "General",
         }
     }
@@ -106,9 +191,20 @@ pub struct Bullet {
     pub priority: u8,
 }
 
+#[derive(Debug, Clone)]
+pub struct IntentBadge {
+    pub icon: &'static str,
+    pub label: String,
+    pub confidence: f32,
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct DeepResponse {
     pub content: String,
+    /// `content` rendered from markdown, with the cursor while streaming
+    pub html: String,
+    /// Code blocks in `html` are syntax highlighted (Technical mode)
+    pub highlight_code: bool,
     pub is_streaming: bool,
     pub question_to_ask: Option<String>,
 }
@@ -119,23 +215,56 @@ pub enum ConnectionStatus {
     Disconnected,
     Connecting,
     Connected,
+    /// Listening, but the STT connection is being re-established
+    Reconnecting,
+    /// Listening, but audio isn't transcribed or analyzed
+    Paused,
     Error(String),
 }
 
 impl Default for AppState {
     fn default() -> Self {
+        let available_sources = get_available_sources();
+        let settings = Settings::load().unwrap_or_default();
+        let (audio_source, missing_source) = match settings.audio.source {
+            Some(saved) => AudioSource::restore_in(&saved, &available_sources),
+            None => (AudioSource::best_guess_in(&available_sources), None),
+        };
+
         Self {
             is_listening: false,
             mode: CopilotMode::default(),
             transcript: String::new(),
+            transcript_low_confidence: false,
+            transcript_index: TranscriptIndex::default(),
+            intents: Vec::new(),
+            objection: None,
+            action_items: Vec::new(),
+            competitor: None,
             flash_response: None,
             deep_response: None,
+            response_mode: ResponseMode::default(),
+            transcription_only: false,
             status: ConnectionStatus::default(),
-            audio_source: AudioSource::SystemDefault,
-            available_sources: get_available_sources(),
+            audio_source,
+            missing_source,
+            available_sources,
             ui_mode: UIMode::default(),
             settings_open: false,
             source_picker_open: false,
+            latency: None,
+            notice: None,
+            overlay: OverlayWindow::default(),
+            screen_share: None,
+            suggested_followup: None,
+            alert: false,
+            audio_level: None,
+            hotkey_errors: Vec::new(),
+            brief: String::new(),
+            theme_css: get_theme_vars(&settings.ui.theme_name),
+            theme_name: settings.ui.theme_name,
+            render_markdown: settings.ui.render_markdown,
+            language: settings.ui.language,
         }
     }
 }
@@ -150,7 +279,8 @@ pub fn launch_app() {
                 .with_min_inner_size(dioxus::desktop::LogicalSize::new(380.0, 400.0))
                 .with_always_on_top(true)
                 .with_decorations(true)
-                .with_transparent(false)
+                // Outside Windows, overlay opacity is drawn by the page itself
+                .with_transparent(cfg!(not(target_os = "windows")))
         )
         .with_custom_head(r#"
             <style>
@@ -188,6 +318,11 @@ pub fn launch_app() {
                     height: 100vh;
                     padding: 12px;
                     gap: 12px;
+                    transition: box-shadow 0.2s;
+                }
+
+                .app-container.urgent-alert {
+                    box-shadow: inset 0 0 0 3px var(--accent-red);
                 }
 
                 .status-bar {
@@ -206,6 +341,166 @@ pub fn launch_app() {
                     gap: 8px;
                 }
 
+                .latency-label {
+                    font-size: 11px;
+                    color: var(--text-secondary);
+                }
+
+                .notice-label {
+                    font-size: 11px;
+                    color: var(--accent-green);
+                }
+
+                .error-label {
+                    font-size: 11px;
+                    color: var(--accent-red);
+                    max-width: 360px;
+                }
+
+                .share-warning {
+                    display: flex;
+                    align-items: center;
+                    justify-content: space-between;
+                    gap: 8px;
+                    padding: 6px 12px;
+                    font-size: 12px;
+                    color: var(--text-primary);
+                    background: var(--accent-red);
+                }
+
+                .source-missing,
+                .transcription-only {
+                    display: flex;
+                    align-items: center;
+                    justify-content: space-between;
+                    gap: 8px;
+                    padding: 6px 12px;
+                    font-size: 12px;
+                    color: var(--text-secondary);
+                    border: 1px dashed var(--border-color);
+                    border-radius: 8px;
+                }
+
+                .overlay-controls {
+                    display: flex;
+                    align-items: center;
+                    gap: 8px;
+                    padding: 4px 12px;
+                }
+
+                .overlay-controls input[type="range"] {
+                    flex: 1;
+                }
+
+                .lead-export {
+                    display: flex;
+                    flex-direction: column;
+                    gap: 6px;
+                    padding: 4px 12px;
+                }
+
+                .lead-export-status, .lead-empty {
+                    font-size: 11px;
+                    color: var(--text-secondary);
+                }
+
+                .lead-picker-search {
+                    display: flex;
+                    gap: 6px;
+                }
+
+                .call-brief {
+                    display: flex;
+                    flex-direction: column;
+                    gap: 6px;
+                    padding: 4px 12px;
+                }
+
+                .call-brief-header {
+                    display: flex;
+                    gap: 6px;
+                    align-items: center;
+                    background: none;
+                    border: none;
+                    color: var(--text-secondary);
+                    font-size: 12px;
+                    cursor: pointer;
+                    padding: 0;
+                    text-align: left;
+                }
+
+                .call-brief-summary {
+                    color: var(--text-muted, #6e7681);
+                    overflow: hidden;
+                    text-overflow: ellipsis;
+                    white-space: nowrap;
+                }
+
+                .call-brief-text {
+                    width: 100%;
+                    box-sizing: border-box;
+                    padding: 8px;
+                    background: var(--bg-secondary);
+                    border: 1px solid var(--border-color);
+                    border-radius: 6px;
+                    color: var(--text-primary);
+                    font: inherit;
+                    font-size: 13px;
+                    resize: vertical;
+                }
+
+                .report-export-buttons {
+                    display: flex;
+                    flex-wrap: wrap;
+                    gap: 6px;
+                }
+
+                .meeting-minutes {
+                    font-size: 12px;
+                    color: var(--text-primary);
+                }
+
+                .meeting-minutes h4 {
+                    margin: 8px 0 4px;
+                    font-size: 12px;
+                    color: var(--text-secondary);
+                    text-transform: uppercase;
+                }
+
+                .meeting-minutes ul {
+                    margin: 0;
+                    padding-left: 18px;
+                }
+
+                .meeting-item-meta {
+                    color: var(--text-muted, #6e7681);
+                }
+
+                .lead-picker-search input {
+                    flex: 1;
+                }
+
+                .lead-list {
+                    max-height: 160px;
+                    overflow-y: auto;
+                }
+
+                .lead-item {
+                    padding: 4px 8px;
+                    font-size: 12px;
+                    cursor: pointer;
+                    border-radius: 4px;
+                }
+
+                .lead-item:hover {
+                    background: var(--bg-secondary);
+                }
+
+                .overlay-label {
+                    font-size: 11px;
+                    color: var(--text-secondary);
+                }
+
                 .status-dot {
                     width: 8px;
                     height: 8px;
@@ -218,6 +513,15 @@ pub fn launch_app() {
                     animation: pulse 2s infinite;
                 }
 
+                .status-dot.reconnecting {
+                    background: var(--accent-yellow);
+                    animation: pulse 1s infinite;
+                }
+
+                .status-dot.paused {
+                    background: var(--accent-yellow);
+                }
+
                 @keyframes pulse {
                     0%, 100% { opacity: 1; }
                     50% { opacity: 0.5; }
@@ -271,6 +575,135 @@ pub fn launch_app() {
                     min-height: 40px;
                 }
 
+                .transcript-edit-btn {
+                    margin-left: auto;
+                    background: none;
+                    border: none;
+                    cursor: pointer;
+                    font-size: 12px;
+                }
+
+                .transcript-edit-btn + .transcript-edit-btn {
+                    margin-left: 0;
+                }
+
+                .transcript-edit {
+                    display: flex;
+                    gap: 6px;
+                }
+
+                .transcript-search {
+                    display: flex;
+                    flex-direction: column;
+                    gap: 6px;
+                    margin-bottom: 8px;
+                }
+
+                .transcript-search-bar {
+                    display: flex;
+                    align-items: center;
+                    gap: 6px;
+                }
+
+                .transcript-search-bar input {
+                    flex: 1;
+                }
+
+                .search-count {
+                    font-size: 11px;
+                    color: var(--text-secondary);
+                    white-space: nowrap;
+                }
+
+                .transcript-history {
+                    max-height: 160px;
+                    overflow-y: auto;
+                    display: flex;
+                    flex-direction: column;
+                    gap: 4px;
+                    font-size: 12px;
+                }
+
+                .transcript-turn {
+                    padding: 4px 6px;
+                    border-radius: 4px;
+                    color: var(--text-secondary);
+                }
+
+                .transcript-turn.current {
+                    background: var(--bg-tertiary);
+                    color: var(--text-primary);
+                }
+
+                .turn-speaker {
+                    margin-right: 6px;
+                    font-weight: 600;
+                    color: var(--accent-blue);
+                }
+
+                .search-match {
+                    background: rgba(234, 179, 8, 0.3);
+                    color: inherit;
+                    border-radius: 2px;
+                }
+
+                .search-match.current {
+                    background: var(--accent-yellow);
+                    color: var(--bg-primary);
+                }
+
+                .transcript-edit input {
+                    flex: 1;
+                }
+
+                .transcript-text.low-confidence {
+                    color: var(--text-secondary);
+                    opacity: 0.6;
+                }
+
+                .competitor-mention {
+                    color: var(--accent-red);
+                    font-weight: 600;
+                }
+
+                .intent-badges {
+                    display: flex;
+                    flex-wrap: wrap;
+                    gap: 6px;
+                }
+
+                .intent-badge {
+                    padding: 2px 8px;
+                    border-radius: 10px;
+                    background: var(--bg-tertiary);
+                    color: var(--text-secondary);
+                    font-size: 11px;
+                }
+
+                .live-action-items {
+                    background: var(--bg-secondary);
+                    border-radius: 8px;
+                    border-left: 3px solid var(--accent-green);
+                    padding: 8px 12px;
+                    font-size: 12px;
+                }
+
+                .live-action-items-header {
+                    color: var(--accent-green);
+                    font-weight: 600;
+                    margin-bottom: 4px;
+                }
+
+                .live-action-items ul {
+                    padding-left: 16px;
+                }
+
+                .live-action-items li {
+                    display: flex;
+                    justify-content: space-between;
+                    gap: 8px;
+                }
+
                 .flash-section {
                     background: var(--bg-secondary);
                     border-radius: 8px;
@@ -320,6 +753,20 @@ pub fn launch_app() {
                     font-weight: bold;
                 }
 
+                .bullet-item.library-response {
+                    background: var(--bg-secondary);
+                    border-left: 2px solid var(--accent-red);
+                    flex-direction: column;
+                    gap: 2px;
+                }
+
+                .library-response-name {
+                    font-size: 11px;
+                    font-weight: 600;
+                    text-transform: uppercase;
+                    color: var(--accent-red);
+                }
+
                 .deep-section {
                     flex: 1;
                     background: var(--bg-secondary);
@@ -330,24 +777,122 @@ pub fn launch_app() {
                     overflow-y: auto;
                 }
 
-                .deep-header {
-                    display: flex;
-                    align-items: center;
-                    gap: 6px;
-                    color: var(--accent-blue);
-                    font-size: 12px;
-                    font-weight: 600;
-                    margin-bottom: 8px;
+                .deep-header {
+                    display: flex;
+                    align-items: center;
+                    gap: 6px;
+                    color: var(--accent-blue);
+                    font-size: 12px;
+                    font-weight: 600;
+                    margin-bottom: 8px;
+                }
+
+                .deep-content {
+                    color: var(--text-primary);
+                    white-space: pre-wrap;
+                }
+
+                .deep-content.streaming::after,
+                .stream-cursor::after {
+                    content: '|';
+                    animation: blink 1s infinite;
+                }
+
+                .deep-content.markdown {
+                    white-space: normal;
+                }
+
+                .deep-content.markdown p,
+                .deep-content.markdown ul,
+                .deep-content.markdown ol,
+                .deep-content.markdown pre {
+                    margin-bottom: 8px;
+                }
+
+                .deep-content.markdown ul,
+                .deep-content.markdown ol {
+                    padding-left: 18px;
+                }
+
+                .deep-content.markdown h1,
+                .deep-content.markdown h2,
+                .deep-content.markdown h3 {
+                    margin: 6px 0 4px;
+                    font-size: 13px;
+                    color: var(--accent-blue);
+                }
+
+                .deep-content.markdown code {
+                    padding: 1px 4px;
+                    background: var(--bg-tertiary);
+                    border-radius: 3px;
+                    font-family: monospace;
+                    font-size: 12px;
+                }
+
+                .deep-content.markdown pre {
+                    padding: 8px;
+                    background: var(--bg-tertiary);
+                    border-radius: 4px;
+                    overflow-x: auto;
+                }
+
+                .deep-content.markdown pre code {
+                    padding: 0;
+                    background: none;
+                }
+
+                .code-block {
+                    margin-bottom: 8px;
+                    background: var(--bg-tertiary);
+                    border-radius: 4px;
+                    overflow: hidden;
+                }
+
+                .code-block-header {
+                    display: flex;
+                    justify-content: space-between;
+                    align-items: center;
+                    padding: 4px 8px;
+                    font-size: 10px;
+                    color: var(--text-secondary);
+                    border-bottom: 1px solid var(--border-color);
+                }
+
+                .copy-code {
+                    padding: 1px 6px;
+                    background: none;
+                    border: 1px solid var(--border-color);
+                    border-radius: 3px;
+                    color: var(--text-secondary);
+                    font-size: 10px;
+                    cursor: pointer;
                 }
 
-                .deep-content {
-                    color: var(--text-primary);
-                    white-space: pre-wrap;
+                .deep-content.markdown .code-block pre {
+                    margin: 0;
+                    border-radius: 0;
                 }
 
-                .deep-content.streaming::after {
-                    content: '|';
-                    animation: blink 1s infinite;
+                /* Syntax scopes, mapped onto the theme palette */
+                .hl-comment { color: var(--text-muted, #6e7681); font-style: italic; }
+                .hl-keyword, .hl-storage { color: var(--accent-purple, #a371f7); }
+                .hl-string { color: var(--accent-green); }
+                .hl-constant { color: var(--accent-orange, #db6d28); }
+                .hl-entity.hl-name, .hl-support.hl-function { color: var(--accent-blue); }
+                .hl-support.hl-type, .hl-entity.hl-type { color: var(--accent-yellow); }
+                .hl-variable.hl-parameter { color: var(--accent-cyan, #39c5cf); }
+                .hl-invalid { color: var(--accent-red); }
+
+                .format-toggle {
+                    margin-left: auto;
+                    padding: 2px 6px;
+                    background: none;
+                    border: 1px solid var(--border-color);
+                    border-radius: 4px;
+                    color: var(--text-secondary);
+                    font-size: 10px;
+                    cursor: pointer;
                 }
 
                 @keyframes blink {
@@ -394,6 +939,21 @@ pub fn launch_app() {
                     background: var(--accent-red);
                 }
 
+                .pause-btn {
+                    padding: 12px 16px;
+                    background: var(--bg-secondary);
+                    border: 1px solid var(--border-color);
+                    border-radius: 8px;
+                    color: var(--text-primary);
+                    font-weight: 600;
+                    cursor: pointer;
+                }
+
+                .pause-btn.paused {
+                    border-color: var(--accent-yellow);
+                    color: var(--accent-yellow);
+                }
+
                 .settings-btn {
                     padding: 12px;
                     background: var(--bg-secondary);
@@ -573,6 +1133,24 @@ pub fn launch_app() {
                     font-size: 16px;
                 }
 
+                .level-meter {
+                    width: 48px;
+                    height: 6px;
+                    border-radius: 3px;
+                    background: var(--bg-tertiary);
+                    overflow: hidden;
+                }
+
+                .level-meter-fill {
+                    height: 100%;
+                    background: var(--accent-green);
+                    transition: width 0.1s linear;
+                }
+
+                .level-meter.clipping .level-meter-fill {
+                    background: var(--accent-red);
+                }
+
                 .selected-source-text {
                     flex: 1;
                 }
@@ -741,6 +1319,32 @@ pub fn launch_app() {
                     color: var(--accent-yellow);
                 }
 
+                .prompt-editor textarea {
+                    padding: 10px 12px;
+                    background: var(--bg-secondary);
+                    border: 1px solid var(--border-color);
+                    border-radius: 6px;
+                    color: var(--text-primary);
+                    font-family: monospace;
+                    font-size: 12px;
+                    resize: vertical;
+                }
+
+                .prompt-errors p {
+                    margin: 0;
+                }
+
+                .prompt-preview {
+                    max-height: 200px;
+                    overflow-y: auto;
+                    padding: 10px 12px;
+                    background: var(--bg-tertiary);
+                    border-radius: 6px;
+                    font-size: 11px;
+                    white-space: pre-wrap;
+                    color: var(--text-secondary);
+                }
+
                 .shortcut-list {
                     display: flex;
                     flex-direction: column;
@@ -757,12 +1361,107 @@ pub fn launch_app() {
                 }
 
                 .shortcut-key {
+                    min-width: 110px;
                     font-family: monospace;
                     font-size: 11px;
                     padding: 4px 8px;
                     background: var(--bg-tertiary);
+                    border: 1px solid transparent;
                     border-radius: 4px;
                     color: var(--accent-blue);
+                    cursor: pointer;
+                }
+
+                .theme-editor {
+                    display: flex;
+                    flex-direction: column;
+                    gap: 10px;
+                    margin-top: 10px;
+                    padding: 12px;
+                    background: var(--bg-secondary);
+                    border-radius: 8px;
+                }
+
+                .theme-preview {
+                    display: flex;
+                    flex-direction: column;
+                    gap: 6px;
+                    padding: 10px;
+                    background: var(--bg-primary);
+                    border: 1px solid var(--border-color);
+                    border-radius: 6px;
+                    font-size: 12px;
+                }
+
+                .theme-preview-transcript {
+                    color: var(--color-transcript);
+                }
+
+                .theme-preview-flash {
+                    color: var(--color-flash);
+                }
+
+                .theme-preview-deep {
+                    padding: 6px 8px;
+                    background: var(--bg-tertiary);
+                    border-left: 3px solid var(--color-deep);
+                    color: var(--text-primary);
+                }
+
+                .theme-preview-badge {
+                    align-self: flex-start;
+                    padding: 2px 6px;
+                    border-radius: 4px;
+                    background: var(--accent-red);
+                    color: var(--bg-primary);
+                }
+
+                .theme-fields {
+                    display: grid;
+                    grid-template-columns: 1fr 1fr;
+                    gap: 6px;
+                    max-height: 260px;
+                    overflow-y: auto;
+                }
+
+                .theme-field {
+                    display: flex;
+                    align-items: center;
+                    gap: 6px;
+                    font-size: 11px;
+                }
+
+                .theme-field label {
+                    flex: 1;
+                    color: var(--text-secondary);
+                }
+
+                .theme-field input[type="color"] {
+                    width: 28px;
+                    height: 22px;
+                    padding: 0;
+                    border: none;
+                    background: none;
+                }
+
+                .theme-field input[type="text"] {
+                    width: 110px;
+                    font-family: monospace;
+                    font-size: 11px;
+                }
+
+                .theme-field.invalid input[type="text"] {
+                    border-color: var(--accent-red);
+                }
+
+                .theme-editor-actions {
+                    display: flex;
+                    gap: 8px;
+                }
+
+                .shortcut-key.capturing {
+                    border-color: var(--accent-blue);
+                    color: var(--text-secondary);
                 }
 
                 .settings-footer {
@@ -875,26 +1574,196 @@ pub fn launch_app() {
                     color: var(--text-secondary);
                 }
             </style>
+            <script>
+                // Copy buttons on highlighted code blocks (the HTML is generated, not model output)
+                function copyCode(button) {
+                    const code = button.closest('.code-block').querySelector('code').innerText;
+                    const done = () => {
+                        button.textContent = 'Copied';
+                        setTimeout(() => { button.textContent = 'Copy'; }, 1500);
+                    };
+                    if (navigator.clipboard) {
+                        navigator.clipboard.writeText(code).then(done, () => fallbackCopy(code, done));
+                    } else {
+                        fallbackCopy(code, done);
+                    }
+                }
+
+                function fallbackCopy(text, done) {
+                    const area = document.createElement('textarea');
+                    area.value = text;
+                    document.body.appendChild(area);
+                    area.select();
+                    document.execCommand('copy');
+                    area.remove();
+                    done();
+                }
+            </script>
         "#.to_string());
 
+    #[cfg(not(target_os = "windows"))]
+    let config = config.with_background_color((0, 0, 0, 0));
+
     dioxus::LaunchBuilder::desktop()
         .with_cfg(config)
         .launch(App);
 }
 
+/// Apply overlay opacity and click-through to the window
+fn apply_overlay_window(window: &dioxus::desktop::DesktopContext, overlay: &OverlayWindow) {
+    // Windows keeps using the layered-window path
+    #[cfg(target_os = "windows")]
+    {
+        let _ = window;
+        let stealth = StealthMode::new();
+        let result = stealth.set_opacity(overlay.opacity).and_then(|_| {
+            if overlay.click_through {
+                stealth.enable_click_through()
+            } else {
+                stealth.disable_click_through()
+            }
+        });
+        if let Err(e) = result {
+            tracing::warn!("Failed to update overlay window: {}", e);
+        }
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        if let Err(e) = window.window.set_ignore_cursor_events(overlay.click_through) {
+            tracing::warn!("Failed to set click-through: {}", e);
+        }
+        // The window is transparent, so fading the page fades the overlay
+        let _ = eval(&format!("document.documentElement.style.opacity = '{}';", overlay.opacity));
+    }
+}
+
+/// "You · Oct 16" for the live action item list
+fn action_item_meta(item: &ActionItem) -> String {
+    item.owner
+        .iter()
+        .cloned()
+        .chain(item.due.map(|due| due.format("%b %-d").to_string()))
+        .collect::<Vec<_>>()
+        .join(" · ")
+}
+
 /// Runtime handle stored in context
 static RUNTIME: std::sync::OnceLock<super::runtime::RuntimeHandle> = std::sync::OnceLock::new();
 
+/// Stealth state shared by the tray, screen-share watcher and UI
+static STEALTH: std::sync::OnceLock<StealthMode> = std::sync::OnceLock::new();
+
+fn stealth() -> &'static StealthMode {
+    STEALTH.get_or_init(StealthMode::new)
+}
+
 /// Initialize runtime (call once at startup)
 fn init_runtime() -> super::runtime::RuntimeHandle {
     let settings = Settings::load().unwrap_or_default();
     let (handle, service) = super::runtime::RuntimeHandle::new(settings);
 
+    // Tray has to be created on the UI thread
+    let (tray_tx, mut tray_rx) = tokio::sync::mpsc::channel(8);
+    super::tray::spawn_tray_listener(tray_tx, handle.state_ref());
+
     // Spawn the runtime service in a background thread with its own tokio runtime
     let service_handle = handle.clone();
+    let tray_handle = handle.clone();
     std::thread::spawn(move || {
         let rt = tokio::runtime::Runtime::new().expect("Failed to create tokio runtime");
-        rt.block_on(service.run());
+        rt.block_on(async move {
+            // Global hotkeys that drive the pipeline directly
+            let (action_tx, mut action_rx) = tokio::sync::mpsc::channel(8);
+            super::hotkeys::spawn_hotkey_listener(action_tx, service_handle.state_ref());
+            tokio::spawn(async move {
+                while let Some(action) = action_rx.recv().await {
+                    match action {
+                        HotkeyAction::RequestAnalysis => service_handle.request_analysis(),
+                        HotkeyAction::CycleMode => service_handle.cycle_mode(),
+                        HotkeyAction::TogglePause => service_handle.toggle_pause(),
+                        HotkeyAction::ToggleFlashOnly => service_handle.toggle_flash_only(),
+                        HotkeyAction::CopySuggestion => {
+                            // Read fresh so a change in settings applies right away
+                            let target = Settings::load().unwrap_or_default().hotkeys.copy_target;
+                            match super::hotkeys::copy_suggestion(&service_handle.state(), target) {
+                                Ok(_) => service_handle.notify("Copied to clipboard"),
+                                Err(e) => service_handle.notify(e.to_string()),
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+            });
+
+            // Screen sharing, to hide the window before others see it
+            let (share_tx, mut share_rx) = tokio::sync::mpsc::channel(4);
+            super::screen_share::spawn_screen_share_monitor(share_tx);
+            let share_handle = handle.clone();
+            tokio::spawn(async move {
+                let stealth = stealth();
+                let mut hidden_for_share = false;
+                while let Some(event) = share_rx.recv().await {
+                    match event {
+                        ScreenShareEvent::Started(app) => {
+                            share_handle.set_screen_share(Some(app.clone()));
+                            let auto_hide = Settings::load().unwrap_or_default().privacy.auto_hide_on_share;
+                            if auto_hide && stealth.is_visible() {
+                                match stealth_commands::vanish(stealth) {
+                                    Ok(_) => {
+                                        hidden_for_share = true;
+                                        share_handle.notify(format!("Hidden while {} shares your screen", app));
+                                    }
+                                    Err(e) => tracing::warn!("Failed to hide for screen share: {}", e),
+                                }
+                            }
+                        }
+                        ScreenShareEvent::Stopped => {
+                            share_handle.set_screen_share(None);
+                            // Only bring back what we hid; a manual show from the tray wins
+                            if std::mem::take(&mut hidden_for_share) && !stealth.is_visible() {
+                                let result = stealth_commands::appear(stealth).and_then(|_| stealth.disable());
+                                if let Err(e) = result {
+                                    tracing::warn!("Failed to show after screen share: {}", e);
+                                }
+                            }
+                        }
+                    }
+                }
+            });
+
+            // Tray menu, for control without bringing the window forward
+            tokio::spawn(async move {
+                let stealth = stealth();
+                while let Some(action) = tray_rx.recv().await {
+                    match action {
+                        TrayAction::StartListening => tray_handle.start(),
+                        TrayAction::StopListening => tray_handle.stop(),
+                        TrayAction::SetMode(mode) => tray_handle.set_mode(&mode),
+                        TrayAction::ToggleClickThrough => {
+                            tray_handle.set_click_through(!tray_handle.state().overlay.click_through);
+                        }
+                        TrayAction::ToggleStealth => {
+                            let result = if stealth.is_active() {
+                                stealth_commands::appear(stealth).and_then(|_| stealth.disable())
+                            } else {
+                                stealth.enable()
+                            };
+                            if let Err(e) = result {
+                                tracing::warn!("Failed to toggle stealth: {}", e);
+                            }
+                        }
+                        TrayAction::Quit => {
+                            tray_handle.stop();
+                            std::process::exit(0);
+                        }
+                        _ => {}
+                    }
+                }
+            });
+
+            service.run().await
+        });
     });
 
     handle
@@ -914,11 +1783,23 @@ fn App() -> Element {
     // Get runtime handle
     let runtime = get_runtime();
 
+    // Start with the source picked on launch
+    use_hook(|| runtime.set_audio_source(app_state.peek().audio_source.clone()));
+
+    // Outreach account for attaching calls to leads, if configured
+    let (outreach, followup) = use_hook(|| {
+        let settings = Settings::load().unwrap_or_default();
+        (OutreachAccount::from_env(&settings.api_keys), settings.followup)
+    });
+
     // Poll runtime state periodically
     let runtime_state = runtime.state_ref();
+    let window = dioxus::desktop::use_window();
     use_future(move || {
         let runtime_state = runtime_state.clone();
+        let window = window.clone();
         async move {
+            let mut applied_overlay: Option<OverlayWindow> = None;
             loop {
                 tokio::time::sleep(std::time::Duration::from_millis(100)).await;
                 let state = runtime_state.read().clone();
@@ -927,6 +1808,42 @@ fn App() -> Element {
                 let mut ui_state = app_state.write();
                 ui_state.is_listening = state.is_running;
                 ui_state.transcript = state.transcript.clone();
+                ui_state.transcript_low_confidence = state.transcript_low_confidence;
+                ui_state.transcript_index = state.transcript_index.clone();
+                ui_state.objection = state.objection.clone();
+                ui_state.action_items = state.action_items.clone();
+                ui_state.intents = state.intents.iter().map(|i| IntentBadge {
+                    icon: i.category.emoji(),
+                    label: match &i.competitor {
+                        Some(name) => format!("{}: {}", i.category.label(), name),
+                        None => i.category.label().to_string(),
+                    },
+                    confidence: i.confidence,
+                }).collect();
+                ui_state.competitor = state.intents.iter().find_map(|i| i.competitor.clone());
+                ui_state.latency = state.latency.clone();
+                ui_state.notice = state.active_notice();
+                ui_state.mode = state.mode.clone();
+                ui_state.overlay = state.overlay.clone();
+                ui_state.screen_share = state.screen_share.clone();
+                ui_state.suggested_followup = state.suggested_followup;
+                ui_state.alert = state.alert_active();
+                ui_state.audio_level = state.audio_level;
+                ui_state.hotkey_errors = state.hotkey_errors.clone();
+                ui_state.brief = state.brief.clone();
+                ui_state.response_mode = state.response_mode;
+                ui_state.transcription_only = state.transcription_only;
+
+                // Opacity and click-through only apply in overlay mode
+                let overlay = if ui_state.ui_mode == UIMode::Overlay {
+                    state.overlay.clone()
+                } else {
+                    OverlayWindow::default()
+                };
+                if applied_overlay.as_ref() != Some(&overlay) {
+                    apply_overlay_window(&window, &overlay);
+                    applied_overlay = Some(overlay);
+                }
 
                 // Update flash response
                 if let Some(flash) = &state.flash {
@@ -944,8 +1861,18 @@ fn App() -> Element {
 
                 // Update deep response
                 if !state.deep_content.is_empty() || state.deep_streaming {
+                    // Only re-render when a chunk arrived, streaming ended or the mode changed
+                    let highlight_code = state.mode == CopilotMode::Technical;
+                    let html = match &ui_state.deep_response {
+                        Some(prev) if prev.content == state.deep_content
+                            && prev.is_streaming == state.deep_streaming
+                            && prev.highlight_code == highlight_code => prev.html.clone(),
+                        _ => render_markdown(&state.deep_content, state.deep_streaming, highlight_code),
+                    };
                     ui_state.deep_response = Some(DeepResponse {
                         content: state.deep_content.clone(),
+                        html,
+                        highlight_code,
                         is_streaming: state.deep_streaming,
                         question_to_ask: state.question.clone(),
                     });
@@ -954,7 +1881,11 @@ fn App() -> Element {
                 }
 
                 // Update status
-                ui_state.status = if state.is_running {
+                ui_state.status = if state.is_running && state.reconnecting {
+                    ConnectionStatus::Reconnecting
+                } else if state.is_running && state.is_paused {
+                    ConnectionStatus::Paused
+                } else if state.is_running {
                     ConnectionStatus::Connected
                 } else if state.error.is_some() {
                     ConnectionStatus::Error(state.error.clone().unwrap_or_default())
@@ -983,7 +1914,7 @@ fn App() -> Element {
     let change_mode = move |mode: CopilotMode| {
         let runtime = get_runtime();
         app_state.write().mode = mode.clone();
-        runtime.set_mode(mode.label());
+        runtime.set_mode(&mode);
     };
 
     // Change UI mode
@@ -1006,8 +1937,57 @@ fn App() -> Element {
         let runtime = get_runtime();
         let mut state = app_state.write();
         state.audio_source = source.clone();
+        state.missing_source = None;
         state.source_picker_open = false;
-        runtime.set_audio_source(source);
+        runtime.set_audio_source(source.clone());
+
+        // Remember it for the next launch
+        let mut settings = Settings::load().unwrap_or_default();
+        settings.audio.source = Some(source);
+        if let Err(e) = settings.save() {
+            tracing::warn!("Failed to save audio source: {}", e);
+        }
+    };
+
+    // Switch theme live and remember it
+    let select_theme = move |name: String| {
+        {
+            let mut state = app_state.write();
+            state.theme_css = get_theme_vars(&name);
+            state.theme_name = name.clone();
+        }
+
+        let mut settings = Settings::load().unwrap_or_default();
+        settings.ui.theme_name = name;
+        if let Err(e) = settings.save() {
+            tracing::warn!("Failed to save theme: {}", e);
+        }
+    };
+
+    // Switch the overlay language live and remember it
+    let select_language = move |language: Language| {
+        app_state.write().language = language;
+
+        let mut settings = Settings::load().unwrap_or_default();
+        settings.ui.language = language;
+        if let Err(e) = settings.save() {
+            tracing::warn!("Failed to save language: {}", e);
+        }
+    };
+
+    // Markdown or raw text for Deep responses, remembered
+    let toggle_markdown = move |_| {
+        let enabled = {
+            let mut state = app_state.write();
+            state.render_markdown = !state.render_markdown;
+            state.render_markdown
+        };
+
+        let mut settings = Settings::load().unwrap_or_default();
+        settings.ui.render_markdown = enabled;
+        if let Err(e) = settings.save() {
+            tracing::warn!("Failed to save markdown setting: {}", e);
+        }
     };
 
     // Refresh sources
@@ -1016,6 +1996,7 @@ fn App() -> Element {
     };
 
     let state = app_state.read();
+    let lang = state.language;
 
     // Get source icon
     let source_icon = match &state.audio_source {
@@ -1025,20 +2006,37 @@ fn App() -> Element {
     };
 
     rsx! {
-        div { class: "app-container",
+        style { "{state.theme_css}" }
+        div { class: if state.alert { "app-container urgent-alert" } else { "app-container" },
             // UI Mode Bar
             div { class: "ui-mode-bar",
                 div { class: "status-indicator",
                     div {
-                        class: if state.status == ConnectionStatus::Connected { "status-dot connected" } else { "status-dot" }
+                        class: match state.status {
+                            ConnectionStatus::Connected => "status-dot connected",
+                            ConnectionStatus::Reconnecting => "status-dot reconnecting",
+                            ConnectionStatus::Paused => "status-dot paused",
+                            _ => "status-dot",
+                        }
                     }
                     span {
-                        {match &state.status {
-                            ConnectionStatus::Disconnected => "Ready",
-                            ConnectionStatus::Connecting => "Connecting...",
-                            ConnectionStatus::Connected => "Listening",
-                            ConnectionStatus::Error(_) => "Error",
-                        }}
+                        {tr(lang, match &state.status {
+                            ConnectionStatus::Disconnected => "status.ready",
+                            ConnectionStatus::Connecting => "status.connecting",
+                            ConnectionStatus::Connected => "status.listening",
+                            ConnectionStatus::Reconnecting => "status.reconnecting",
+                            ConnectionStatus::Paused => "status.paused",
+                            ConnectionStatus::Error(_) => "status.error",
+                        })}
+                    }
+                    if let Some(latency) = &state.latency {
+                        span { class: "latency-label", "{latency}" }
+                    }
+                    if let Some(notice) = &state.notice {
+                        span { class: "notice-label", "{notice}" }
+                    }
+                    if let ConnectionStatus::Error(message) = &state.status {
+                        span { class: "error-label", "{message}" }
                     }
                     // Update button
                     super::update_button::UpdateButton {}
@@ -1048,21 +2046,74 @@ fn App() -> Element {
                         class: if state.ui_mode == UIMode::FullWindow { "ui-mode-btn active" } else { "ui-mode-btn" },
                         onclick: move |_| change_ui_mode(UIMode::FullWindow),
                         span { "🪟" }
-                        span { "Full" }
+                        span { {tr(lang, "view.full")} }
                     }
                     button {
                         class: if state.ui_mode == UIMode::Overlay { "ui-mode-btn active" } else { "ui-mode-btn" },
                         onclick: move |_| change_ui_mode(UIMode::Overlay),
                         span { "📌" }
-                        span { "Overlay" }
+                        span { {tr(lang, "view.overlay")} }
                     }
                     button {
                         class: if state.ui_mode == UIMode::Minimized { "ui-mode-btn active" } else { "ui-mode-btn" },
                         onclick: move |_| change_ui_mode(UIMode::Minimized),
                         span { "➖" }
-                        span { "Mini" }
+                        span { {tr(lang, "view.mini")} }
+                    }
+                }
+            }
+
+            // Warn while the window could be showing up in a screen share
+            if let Some(app) = &state.screen_share {
+                div { class: "share-warning",
+                    span { "⚠️ {app} is sharing your screen - this window may be visible to others" }
+                    button {
+                        class: "ui-mode-btn",
+                        onclick: move |_| {
+                            if let Err(e) = stealth_commands::vanish(stealth()) {
+                                tracing::warn!("Failed to hide window: {}", e);
+                            }
+                        },
+                        "Hide now"
+                    }
+                }
+            }
+
+            // Overlay controls
+            if state.ui_mode == UIMode::Overlay {
+                div { class: "overlay-controls",
+                    span { class: "overlay-label", "Opacity" }
+                    input {
+                        r#type: "range",
+                        min: "{MIN_OVERLAY_OPACITY}",
+                        max: "1",
+                        step: "0.05",
+                        value: "{state.overlay.opacity}",
+                        oninput: move |e| get_runtime().set_overlay_opacity(e.value().parse().unwrap_or(1.0)),
+                    }
+                    button {
+                        class: "ui-mode-btn",
+                        title: "Clicks pass through the overlay. Turn off from the tray menu.",
+                        onclick: move |_| get_runtime().set_click_through(true),
+                        span { "👻" }
+                        span { "Click-through" }
+                    }
+                }
+            }
+
+            // Share the last call or attach it to a lead between calls
+            if !state.is_listening {
+                if let Some(account) = outreach.clone() {
+                    ExportToLead {
+                        account,
+                        followup: followup.clone(),
+                        suggested_followup: state.suggested_followup,
                     }
                 }
+                ExportReport {}
+                if state.mode == CopilotMode::Meeting {
+                    MeetingMinutes { account: outreach.clone() }
+                }
             }
 
             // Audio Source Selector (click to expand)
@@ -1072,9 +2123,38 @@ fn App() -> Element {
                     div { class: "selected-source-label", "Audio Source" }
                     div { class: "selected-source-name", "{state.audio_source.display_name()}" }
                 }
+                if let Some(level) = state.audio_level {
+                    div {
+                        class: if level.is_clipping { "level-meter clipping" } else { "level-meter" },
+                        title: if level.is_clipping { "Clipping - lower the system volume" } else { "Input level" },
+                        div { class: "level-meter-fill", style: format!("width: {:.0}%", level.fraction() * 100.0) }
+                    }
+                }
                 span { class: "dropdown-arrow", {if state.source_picker_open { "▲" } else { "▼" }} }
             }
 
+            // Nothing to generate suggestions with this call
+            if state.transcription_only {
+                div { class: "transcription-only",
+                    span { "Transcript only - add an OpenAI, Anthropic, Google, Groq or OpenRouter key, or start Ollama, for suggestions" }
+                    button {
+                        class: "ui-mode-btn",
+                        onclick: move |_| app_state.write().settings_open = true,
+                        "Settings"
+                    }
+                }
+            }
+
+            // The remembered app isn't running; system audio is used meanwhile
+            if let Some(missing) = &state.missing_source {
+                if !state.source_picker_open {
+                    div { class: "source-missing",
+                        span { "{missing} isn't available - capturing system audio instead" }
+                        button { class: "ui-mode-btn", onclick: toggle_source_picker, "Pick source" }
+                    }
+                }
+            }
+
             // Source Picker (expanded)
             if state.source_picker_open {
                 div { class: "source-picker",
@@ -1115,51 +2195,97 @@ fn App() -> Element {
 
             // Mode Selector
             div { class: "status-bar",
-                span { style: "font-size: 12px; color: var(--text-secondary);", "Mode:" }
+                span { style: "font-size: 12px; color: var(--text-secondary);", {tr(lang, "mode.label")} }
                 div { class: "mode-selector",
                     button {
                         class: if state.mode == CopilotMode::Sales { "mode-btn active" } else { "mode-btn" },
                         onclick: move |_| change_mode(CopilotMode::Sales),
-                        "Sales"
+                        {tr(lang, "mode.sales")}
                     }
                     button {
                         class: if state.mode == CopilotMode::Interview { "mode-btn active" } else { "mode-btn" },
                         onclick: move |_| change_mode(CopilotMode::Interview),
-                        "Interview"
+                        {tr(lang, "mode.interview")}
                     }
                     button {
                         class: if state.mode == CopilotMode::Technical { "mode-btn active" } else { "mode-btn" },
                         onclick: move |_| change_mode(CopilotMode::Technical),
-                        "Technical"
+                        {tr(lang, "mode.technical")}
+                    }
+                    button {
+                        class: if state.mode == CopilotMode::Meeting { "mode-btn active" } else { "mode-btn" },
+                        onclick: move |_| change_mode(CopilotMode::Meeting),
+                        {tr(lang, "mode.meeting")}
                     }
                 }
             }
 
+            // Background for the suggestions, before or during a call
+            CallBrief {
+                brief: state.brief.clone(),
+                account: outreach.clone(),
+                on_change: move |brief: String| get_runtime().set_brief(brief),
+                on_lead: move |lead: String| get_runtime().set_lead(Some(lead)),
+            }
+
             // Transcript Section
-            div { class: "transcript-section",
-                div { class: "transcript-label",
-                    span { "🎤" }
-                    span { "They said:" }
-                }
-                div { class: "transcript-text",
-                    {if state.transcript.is_empty() {
-                        "Waiting for speech..."
-                    } else {
-                        state.transcript.as_str()
-                    }}
+            TranscriptView {
+                text: state.transcript.clone(),
+                is_listening: state.is_listening,
+                low_confidence: state.transcript_low_confidence,
+                highlight: state.competitor.clone(),
+                history: state.transcript_index.clone(),
+                on_correct: move |text: String| get_runtime().correct_transcript(text),
+            }
+
+            // What they're asking about, most confident first
+            if !state.intents.is_empty() {
+                div { class: "intent-badges",
+                    for badge in state.intents.iter() {
+                        span {
+                            class: "intent-badge",
+                            title: "{(badge.confidence * 100.0).round()}% confidence",
+                            "{badge.icon} {badge.label}"
+                        }
+                    }
+                }
+            }
+
+            // Commitments so far, newest last
+            if !state.action_items.is_empty() {
+                div { class: "live-action-items",
+                    div { class: "live-action-items-header", "✅ Action items" }
+                    ul {
+                        for (idx, item) in state.action_items.iter().enumerate() {
+                            li { key: "{idx}",
+                                span { "{item.task}" }
+                                span { class: "meeting-item-meta",
+                                    {action_item_meta(item)}
+                                }
+                            }
+                        }
+                    }
                 }
             }
 
-            // Flash Response (Quick Bullets)
-            if let Some(flash) = &state.flash_response {
+            // Flash Response (Quick Bullets), led by a saved objection response
+            if state.flash_response.is_some() || state.objection.is_some() {
                 div { class: "flash-section",
                     div { class: "flash-header",
                         span { "⚡" }
-                        span { "QUICK RESPONSE" }
+                        span { {tr(lang, "flash.header")} }
+                    }
+                    if let Some(flash) = &state.flash_response {
+                        div { class: "flash-summary", "{flash.summary}" }
                     }
-                    div { class: "flash-summary", "{flash.summary}" }
                     ul { class: "bullet-list",
-                        for (idx, bullet) in flash.bullets.iter().enumerate() {
+                        if let Some(entry) = &state.objection {
+                            li { class: "bullet-item library-response",
+                                span { class: "library-response-name", "🛡 {entry.objection_name}" }
+                                span { "{entry.suggested_response}" }
+                            }
+                        }
+                        for (idx, bullet) in state.flash_response.iter().flat_map(|f| f.bullets.iter()).enumerate() {
                             li {
                                 class: if bullet.priority == 1 { "bullet-item priority-1" } else { "bullet-item" },
                                 key: "{idx}",
@@ -1173,20 +2299,33 @@ fn App() -> Element {
                 }
             }
 
-            // Deep Response (Detailed Answer)
-            if let Some(deep) = &state.deep_response {
+            // Deep Response (Detailed Answer), hidden in bullets-only mode
+            if let Some(deep) = state.deep_response.as_ref().filter(|_| state.response_mode.runs_deep()) {
                 div { class: "deep-section",
                     div { class: "deep-header",
                         span { "🧠" }
-                        span { "DETAILED ANSWER" }
+                        span { {tr(lang, "deep.header")} }
+                        button {
+                            class: "format-toggle",
+                            title: if state.render_markdown { "Show raw text" } else { "Show formatted" },
+                            onclick: toggle_markdown,
+                            {tr(lang, if state.render_markdown { "button.raw" } else { "button.formatted" })}
+                        }
                     }
-                    div {
-                        class: if deep.is_streaming { "deep-content streaming" } else { "deep-content" },
-                        "{deep.content}"
+                    if state.render_markdown {
+                        div {
+                            class: "deep-content markdown",
+                            dangerous_inner_html: "{deep.html}",
+                        }
+                    } else {
+                        div {
+                            class: if deep.is_streaming { "deep-content streaming" } else { "deep-content" },
+                            "{deep.content}"
+                        }
                     }
                     if let Some(question) = &deep.question_to_ask {
                         div { class: "question-back",
-                            div { class: "question-label", "🔄 ASK THEM:" }
+                            div { class: "question-label", {tr(lang, "deep.ask")} }
                             div { "{question}" }
                         }
                     }
@@ -1197,9 +2336,9 @@ fn App() -> Element {
             if state.flash_response.is_none() && state.deep_response.is_none() && !state.is_listening {
                 div { class: "empty-state",
                     div { class: "empty-state-icon", "🎯" }
-                    div { "Select an audio source and click Start" }
+                    div { {tr(lang, "empty.title")} }
                     div { style: "font-size: 12px; margin-top: 8px;",
-                        "Pick Zoom, Discord, Teams, or any app to capture only that audio"
+                        {tr(lang, "empty.hint")}
                     }
                 }
             }
@@ -1209,7 +2348,15 @@ fn App() -> Element {
                 button {
                     class: if state.is_listening { "listen-btn listening" } else { "listen-btn" },
                     onclick: toggle_listening,
-                    {if state.is_listening { "⏹ Stop Listening" } else { "▶ Start Listening" }}
+                    {tr(lang, if state.is_listening { "button.stop" } else { "button.start" })}
+                }
+                if state.is_listening {
+                    button {
+                        class: if state.status == ConnectionStatus::Paused { "pause-btn paused" } else { "pause-btn" },
+                        title: "Stop transcribing and analyzing without hanging up",
+                        onclick: move |_| get_runtime().toggle_pause(),
+                        {tr(lang, if state.status == ConnectionStatus::Paused { "button.resume" } else { "button.pause" })}
+                    }
                 }
                 button {
                     class: "settings-btn",
@@ -1221,6 +2368,13 @@ fn App() -> Element {
             // Settings Panel
             super::settings::SettingsPanel {
                 is_open: state.settings_open,
+                hotkey_errors: state.hotkey_errors.clone(),
+                theme_name: state.theme_name.clone(),
+                on_theme: select_theme,
+                language: state.language,
+                on_language: select_language,
+                on_wake_word: move |wake_word: WakeWordSettings| get_runtime().set_wake_word(wake_word),
+                on_response_mode: move |mode: ResponseMode| get_runtime().set_response_mode(mode),
                 on_close: move |_| app_state.write().settings_open = false,
             }
         }
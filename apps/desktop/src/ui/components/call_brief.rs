@@ -0,0 +1,99 @@
+//! Call Brief Component
+//!
+//! A collapsible box for background on who the call is with, included in
+//! every suggestion prompt. It can be filled from an Outreach lead.
+
+use dioxus::prelude::*;
+
+use super::LeadPicker;
+use crate::config::OutreachAccount;
+use crate::recording::{fetch_lead, LeadOption};
+
+#[derive(Props, Clone, PartialEq)]
+pub struct CallBriefProps {
+    /// Brief the runtime is using
+    pub brief: String,
+    /// Leads to fill from, if an Outreach account is configured
+    pub account: Option<OutreachAccount>,
+    /// Called with the edited brief when the box loses focus
+    pub on_change: EventHandler<String>,
+    /// Called with the company name when the brief is filled from a lead
+    pub on_lead: EventHandler<String>,
+}
+
+/// Editable brief with an optional "Fill from lead" picker
+#[component]
+pub fn CallBrief(props: CallBriefProps) -> Element {
+    let initial = props.brief.clone();
+    let mut draft = use_signal(move || initial);
+    let mut open = use_signal(|| false);
+    let mut picking = use_signal(|| false);
+    let mut status = use_signal(|| None::<String>);
+
+    let account = props.account.clone();
+    let on_change = props.on_change;
+    let on_lead = props.on_lead;
+    let on_select = move |lead: LeadOption| {
+        picking.set(false);
+        let Some(account) = account.clone() else {
+            return;
+        };
+        status.set(Some(format!("Loading {}...", lead.company_name)));
+        spawn(async move {
+            match fetch_lead(&account, &lead.id).await {
+                Ok(details) => {
+                    let brief = details.brief();
+                    draft.set(brief.clone());
+                    on_change.call(brief);
+                    on_lead.call(details.company_name);
+                    status.set(None);
+                }
+                Err(e) => status.set(Some(format!("Couldn't load lead: {}", e))),
+            }
+        });
+    };
+
+    let summary = props.brief.lines().next().unwrap_or_default().to_string();
+
+    rsx! {
+        div { class: "call-brief",
+            button {
+                class: "call-brief-header",
+                onclick: move |_| open.set(!open()),
+                span { if open() { "▾" } else { "▸" } }
+                span { "📋 Call brief" }
+                if !open() && !summary.is_empty() {
+                    span { class: "call-brief-summary", "{summary}" }
+                }
+            }
+            if open() {
+                textarea {
+                    class: "call-brief-text",
+                    rows: "4",
+                    placeholder: "Who you're talking to: company, role, what they care about...",
+                    value: "{draft}",
+                    oninput: move |e| draft.set(e.value()),
+                    onchange: move |e| on_change.call(e.value()),
+                }
+                if picking() {
+                    if let Some(account) = props.account.clone() {
+                        LeadPicker {
+                            account,
+                            on_select: on_select,
+                            on_cancel: move |_| picking.set(false),
+                        }
+                    }
+                } else if props.account.is_some() {
+                    button {
+                        class: "ui-mode-btn",
+                        onclick: move |_| picking.set(true),
+                        "Fill from lead"
+                    }
+                }
+                if let Some(message) = status() {
+                    span { class: "lead-export-status", "{message}" }
+                }
+            }
+        }
+    }
+}
@@ -9,6 +9,9 @@ use crate::ui::app::DeepResponse;
 #[derive(Props, Clone, PartialEq)]
 pub struct DeepCardProps {
     pub response: DeepResponse,
+    /// Show the rendered markdown instead of the raw text
+    #[props(default = true)]
+    pub markdown: bool,
 }
 
 #[component]
@@ -24,9 +27,16 @@ pub fn DeepCard(props: DeepCardProps) -> Element {
             }
 
             // Main content (streams in)
-            div {
-                class: if props.response.is_streaming { "deep-content streaming" } else { "deep-content" },
-                "{props.response.content}"
+            if props.markdown {
+                div {
+                    class: "deep-content markdown",
+                    dangerous_inner_html: "{props.response.html}",
+                }
+            } else {
+                div {
+                    class: if props.response.is_streaming { "deep-content streaming" } else { "deep-content" },
+                    "{props.response.content}"
+                }
             }
 
             // Question to ask them (appears at end)
@@ -0,0 +1,164 @@
+//! Lead Export Component
+//!
+//! Saves the most recent recorded call to a lead picked from a searchable
+//! list of the user's Outreach leads.
+
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Local, Utc};
+use dioxus::prelude::*;
+
+use crate::brain::followup_from_steps;
+use crate::config::{FollowupSettings, OutreachAccount};
+use crate::recording::{export_to_lead, list_recordings, load_recording, request_summary, search_leads, upload_session, LeadOption};
+
+#[derive(Props, Clone, PartialEq)]
+pub struct LeadPickerProps {
+    pub account: OutreachAccount,
+    pub on_select: EventHandler<LeadOption>,
+    pub on_cancel: EventHandler<()>,
+}
+
+/// Search box with matching leads
+#[component]
+pub fn LeadPicker(props: LeadPickerProps) -> Element {
+    let mut query = use_signal(String::new);
+    let account = props.account.clone();
+    let leads = use_resource(move || {
+        let account = account.clone();
+        let query = query();
+        async move { search_leads(&account, &query).await }
+    });
+
+    rsx! {
+        div { class: "lead-picker",
+            div { class: "lead-picker-search",
+                input {
+                    r#type: "text",
+                    placeholder: "Search leads...",
+                    value: "{query}",
+                    autofocus: true,
+                    oninput: move |e| query.set(e.value()),
+                    onkeydown: move |e| if e.key() == Key::Escape { props.on_cancel.call(()) },
+                }
+                button { onclick: move |_| props.on_cancel.call(()), "Cancel" }
+            }
+            div { class: "lead-list",
+                {match &*leads.read() {
+                    None => rsx! { div { class: "lead-empty", "Searching..." } },
+                    Some(Err(e)) => rsx! { div { class: "lead-empty", "Couldn't load leads: {e}" } },
+                    Some(Ok(list)) if list.is_empty() => rsx! { div { class: "lead-empty", "No matching leads" } },
+                    Some(Ok(list)) => rsx! {
+                        for lead in list.iter().cloned() {
+                            div {
+                                class: "lead-item",
+                                onclick: move |_| props.on_select.call(lead.clone()),
+                                "{lead.label()}"
+                            }
+                        }
+                    },
+                }}
+            }
+        }
+    }
+}
+
+/// Upload the latest recording, summarize it on the server and attach it to a lead
+///
+/// The lead's next follow-up comes from the summary's next steps, falling
+/// back to the date suggested during the call.
+async fn export_latest(
+    account: &OutreachAccount,
+    lead: &LeadOption,
+    followup: &FollowupSettings,
+    suggested_followup: Option<DateTime<Utc>>,
+) -> Result<()> {
+    let latest = list_recordings()
+        .await?
+        .into_iter()
+        .next()
+        .ok_or_else(|| anyhow!("No recorded calls yet"))?;
+    let session = load_recording(&latest.id).await?;
+
+    // The note still has the transcript highlights if summarizing fails
+    let summary = match upload_session(account, &session, None).await {
+        Ok(recording_id) => request_summary(account, &recording_id)
+            .await
+            .map_err(|e| tracing::warn!("Server summary failed: {}", e))
+            .ok(),
+        Err(e) => {
+            tracing::warn!("Recording upload failed: {}", e);
+            None
+        }
+    };
+
+    let next_followup = if followup.auto_extract {
+        summary
+            .as_ref()
+            .and_then(|s| followup_from_steps(&s.next_steps, Local::now(), followup.hour))
+            .or(suggested_followup)
+    } else {
+        None
+    };
+
+    export_to_lead(account, &lead.id, &session, summary.as_ref(), next_followup).await
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct ExportToLeadProps {
+    pub account: OutreachAccount,
+    pub followup: FollowupSettings,
+    /// Follow-up date suggested during the call
+    pub suggested_followup: Option<DateTime<Utc>>,
+}
+
+/// "Save call to lead" button that opens the picker
+#[component]
+pub fn ExportToLead(props: ExportToLeadProps) -> Element {
+    let mut picking = use_signal(|| false);
+    let mut status = use_signal(|| None::<String>);
+    let account = props.account.clone();
+    let followup = props.followup.clone();
+    let suggested_followup = props.suggested_followup;
+    let followup_label = suggested_followup.map(|at| at.with_timezone(&Local).format("%a %b %-d").to_string());
+
+    let on_select = move |lead: LeadOption| {
+        picking.set(false);
+        status.set(Some(format!("Saving to {}...", lead.company_name)));
+        let account = account.clone();
+        let followup = followup.clone();
+        spawn(async move {
+            match export_latest(&account, &lead, &followup, suggested_followup).await {
+                Ok(()) => status.set(Some(format!("Saved to {}", lead.label()))),
+                Err(e) => status.set(Some(format!("Couldn't save call: {}", e))),
+            }
+        });
+    };
+
+    rsx! {
+        div { class: "lead-export",
+            if picking() {
+                LeadPicker {
+                    account: props.account.clone(),
+                    on_select: on_select,
+                    on_cancel: move |_| picking.set(false),
+                }
+            } else {
+                button {
+                    class: "ui-mode-btn",
+                    title: "Attach the last recorded call to a lead",
+                    onclick: move |_| {
+                        status.set(None);
+                        picking.set(true);
+                    },
+                    span { "📝" }
+                    span { "Save call to lead" }
+                }
+                if let Some(message) = status() {
+                    span { class: "lead-export-status", "{message}" }
+                } else if let Some(date) = followup_label {
+                    span { class: "lead-export-status", "Suggested follow-up: {date}" }
+                }
+            }
+        }
+    }
+}
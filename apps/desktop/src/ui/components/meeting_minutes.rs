@@ -0,0 +1,188 @@
+//! Meeting Minutes Component
+//!
+//! Review view for the last recorded meeting: attendees, decisions, action
+//! items and open questions, with the minutes saved as markdown, the action
+//! items copied as a checklist or pushed to the Outreach API as tasks.
+
+use anyhow::{anyhow, Result};
+use dioxus::prelude::*;
+
+use crate::config::{OutreachAccount, Settings};
+use crate::recording::{generate_minutes, list_recordings, load_recording, push_action_items, save_minutes_markdown, ActionItem, Minutes, RecordingSession, SummaryBackend};
+
+/// Minutes of the latest recording with the configured backend
+async fn minutes_for_latest() -> Result<(RecordingSession, Minutes)> {
+    let latest = list_recordings()
+        .await?
+        .into_iter()
+        .next()
+        .ok_or_else(|| anyhow!("No recorded meetings yet"))?;
+    let session = load_recording(&latest.id).await?;
+
+    let settings = Settings::load().unwrap_or_default();
+    let backend = SummaryBackend::from_settings(&settings.models, &settings.api_keys);
+    let minutes = generate_minutes(&session, &backend).await?;
+    Ok((session, minutes))
+}
+
+fn copy_checklist(minutes: &Minutes) -> Result<()> {
+    if minutes.action_items.is_empty() {
+        return Err(anyhow!("No action items"));
+    }
+    arboard::Clipboard::new()?.set_text(minutes.checklist())?;
+    Ok(())
+}
+
+/// " · Sam · due Oct 16", or nothing when neither is known
+fn item_meta(item: &ActionItem) -> String {
+    let mut meta = String::new();
+    if let Some(owner) = &item.owner {
+        meta.push_str(&format!(" · {}", owner));
+    }
+    if let Some(due) = item.due {
+        meta.push_str(&format!(" · due {}", due.format("%b %-d")));
+    }
+    meta
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct MeetingMinutesProps {
+    /// Signed-in account, for pushing action items as tasks
+    pub account: Option<OutreachAccount>,
+}
+
+/// "Meeting minutes" button and the minutes of the last meeting
+#[component]
+pub fn MeetingMinutes(props: MeetingMinutesProps) -> Element {
+    let mut minutes = use_signal(|| None::<(RecordingSession, Minutes)>);
+    let mut status = use_signal(|| None::<String>);
+    let mut busy = use_signal(|| false);
+
+    let generate = move |_| {
+        busy.set(true);
+        status.set(Some("Writing minutes for the last meeting...".to_string()));
+        spawn(async move {
+            match minutes_for_latest().await {
+                Ok(result) => {
+                    minutes.set(Some(result));
+                    status.set(None);
+                }
+                Err(e) => status.set(Some(format!("Couldn't write minutes: {}", e))),
+            }
+            busy.set(false);
+        });
+    };
+
+    let save_markdown = move |_| {
+        let Some((session, current)) = minutes() else {
+            return;
+        };
+        spawn(async move {
+            match save_minutes_markdown(&session, &current).await {
+                Ok(path) => status.set(Some(format!("Saved to {}", path.display()))),
+                Err(e) => status.set(Some(format!("Couldn't save minutes: {}", e))),
+            }
+        });
+    };
+
+    let copy = move |_| {
+        if let Some((_, current)) = minutes() {
+            match copy_checklist(&current) {
+                Ok(()) => status.set(Some("Checklist copied".to_string())),
+                Err(e) => status.set(Some(format!("Couldn't copy: {}", e))),
+            }
+        }
+    };
+
+    let account = props.account.clone();
+    let push_tasks = move |_| {
+        let (Some(account), Some((session, current))) = (account.clone(), minutes()) else {
+            return;
+        };
+        busy.set(true);
+        spawn(async move {
+            match push_action_items(&account, &session, None, &current.action_items).await {
+                Ok(count) => status.set(Some(format!("Added {} tasks", count))),
+                Err(e) => status.set(Some(format!("Couldn't add tasks: {}", e))),
+            }
+            busy.set(false);
+        });
+    };
+
+    rsx! {
+        div { class: "lead-export",
+            div { class: "report-export-buttons",
+                button {
+                    class: "ui-mode-btn",
+                    title: "Attendees, decisions and action items from the last meeting",
+                    disabled: busy(),
+                    onclick: generate,
+                    span { "📋" }
+                    span { "Meeting minutes" }
+                }
+                if minutes().is_some() {
+                    button {
+                        class: "ui-mode-btn",
+                        title: "Save the minutes as markdown",
+                        onclick: save_markdown,
+                        span { "Save Markdown" }
+                    }
+                    button {
+                        class: "ui-mode-btn",
+                        title: "Copy the action items as a checklist",
+                        onclick: copy,
+                        span { "Copy checklist" }
+                    }
+                    if props.account.is_some() {
+                        button {
+                            class: "ui-mode-btn",
+                            title: "Add the action items to your Outreach tasks",
+                            disabled: busy(),
+                            onclick: push_tasks,
+                            span { "Push tasks" }
+                        }
+                    }
+                }
+            }
+            if let Some(message) = status() {
+                span { class: "lead-export-status", "{message}" }
+            }
+            if let Some((_, current)) = minutes() {
+                div { class: "meeting-minutes",
+                    if !current.attendees.is_empty() {
+                        p { class: "lead-export-status", {format!("Attendees: {}", current.attendees.join(", "))} }
+                    }
+                    h4 { "Decisions" }
+                    if current.decisions.is_empty() {
+                        p { class: "lead-export-status", "No decisions recorded" }
+                    }
+                    ul {
+                        for decision in current.decisions.iter() {
+                            li { "{decision}" }
+                        }
+                    }
+                    h4 { "Action items" }
+                    if current.action_items.is_empty() {
+                        p { class: "lead-export-status", "No action items recorded" }
+                    }
+                    ul { class: "meeting-action-items",
+                        for item in current.action_items.iter() {
+                            li {
+                                span { "{item.task}" }
+                                span { class: "meeting-item-meta", {item_meta(item)} }
+                            }
+                        }
+                    }
+                    if !current.open_questions.is_empty() {
+                        h4 { "Open questions" }
+                        ul {
+                            for question in current.open_questions.iter() {
+                                li { "{question}" }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
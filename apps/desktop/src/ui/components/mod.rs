@@ -7,9 +7,19 @@ mod flash_card;
 mod deep_card;
 mod status_bar;
 mod mode_selector;
+mod lead_export;
+mod theme_editor;
+mod report_export;
+mod call_brief;
+mod meeting_minutes;
 
 pub use transcript_view::TranscriptView;
 pub use flash_card::FlashCard;
 pub use deep_card::DeepCard;
 pub use status_bar::StatusBar;
 pub use mode_selector::ModeSelector;
+pub use lead_export::{ExportToLead, LeadPicker};
+pub use theme_editor::ThemeEditor;
+pub use report_export::ExportReport;
+pub use call_brief::CallBrief;
+pub use meeting_minutes::MeetingMinutes;
@@ -17,6 +17,7 @@ pub fn ModeSelector(props: ModeSelectorProps) -> Element {
         CopilotMode::Sales,
         CopilotMode::Interview,
         CopilotMode::Technical,
+        CopilotMode::Meeting,
         CopilotMode::General,
     ];
 
@@ -0,0 +1,102 @@
+//! Report Export Component
+//!
+//! Saves the most recent recorded call as a summary PDF, a full HTML
+//! report or SRT/WebVTT captions in the recordings folder.
+
+use anyhow::{anyhow, Result};
+use dioxus::prelude::*;
+use std::path::PathBuf;
+
+use crate::config::Settings;
+use crate::recording::{generate_summary, list_recordings, load_recording, save_html_report, save_subtitles, save_summary_pdf, SummaryBackend};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ReportFormat {
+    Pdf,
+    Html,
+    Srt,
+    Vtt,
+}
+
+/// Save the latest recording, summarized with the configured backend
+/// unless it's just the captions
+async fn export_latest(format: ReportFormat) -> Result<PathBuf> {
+    let latest = list_recordings()
+        .await?
+        .into_iter()
+        .next()
+        .ok_or_else(|| anyhow!("No recorded calls yet"))?;
+    let session = load_recording(&latest.id).await?;
+
+    let summarize = || async {
+        let settings = Settings::load().unwrap_or_default();
+        let backend = SummaryBackend::from_settings(&settings.models, &settings.api_keys);
+        generate_summary(&session, &backend).await
+    };
+
+    match format {
+        ReportFormat::Pdf => save_summary_pdf(&session, &summarize().await?).await,
+        ReportFormat::Html => save_html_report(&session, Some(&summarize().await?)).await,
+        ReportFormat::Srt => save_subtitles(&session, false).await,
+        ReportFormat::Vtt => save_subtitles(&session, true).await,
+    }
+}
+
+/// Export buttons (PDF, HTML, SRT, VTT) for the last call
+#[component]
+pub fn ExportReport() -> Element {
+    let mut status = use_signal(|| None::<String>);
+    let mut busy = use_signal(|| false);
+
+    let mut export = move |format: ReportFormat| {
+        busy.set(true);
+        status.set(Some("Exporting the last call...".to_string()));
+        spawn(async move {
+            match export_latest(format).await {
+                Ok(path) => status.set(Some(format!("Saved to {}", path.display()))),
+                Err(e) => status.set(Some(format!("Couldn't export: {}", e))),
+            }
+            busy.set(false);
+        });
+    };
+
+    rsx! {
+        div { class: "lead-export",
+            div { class: "report-export-buttons",
+                button {
+                    class: "ui-mode-btn",
+                    title: "Save the last call's summary as a PDF",
+                    disabled: busy(),
+                    onclick: move |_| export(ReportFormat::Pdf),
+                    span { "📄" }
+                    span { "Export PDF" }
+                }
+                button {
+                    class: "ui-mode-btn",
+                    title: "Save the last call as a shareable web page",
+                    disabled: busy(),
+                    onclick: move |_| export(ReportFormat::Html),
+                    span { "🌐" }
+                    span { "Export HTML" }
+                }
+                button {
+                    class: "ui-mode-btn",
+                    title: "Save the last call's transcript as SRT captions",
+                    disabled: busy(),
+                    onclick: move |_| export(ReportFormat::Srt),
+                    span { "SRT" }
+                }
+                button {
+                    class: "ui-mode-btn",
+                    title: "Save the last call's transcript as WebVTT captions",
+                    disabled: busy(),
+                    onclick: move |_| export(ReportFormat::Vtt),
+                    span { "VTT" }
+                }
+            }
+            if let Some(message) = status() {
+                span { class: "lead-export-status", "{message}" }
+            }
+        }
+    }
+}
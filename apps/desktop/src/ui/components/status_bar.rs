@@ -9,25 +9,37 @@ use crate::ui::app::ConnectionStatus;
 pub struct StatusBarProps {
     pub status: ConnectionStatus,
     pub mode_label: String,
+    /// Latency summary, e.g. "Flash 680ms / Deep 1.2s"
+    pub latency: Option<String>,
 }
 
 #[component]
 pub fn StatusBar(props: StatusBarProps) -> Element {
-    let (status_text, is_connected) = match &props.status {
-        ConnectionStatus::Disconnected => ("Ready", false),
-        ConnectionStatus::Connecting => ("Connecting...", false),
-        ConnectionStatus::Connected => ("Listening", true),
-        ConnectionStatus::Error(e) => ("Error", false),
+    let (status_text, dot_class) = match &props.status {
+        ConnectionStatus::Disconnected => ("Ready", "status-dot"),
+        ConnectionStatus::Connecting => ("Connecting...", "status-dot"),
+        ConnectionStatus::Connected => ("Listening", "status-dot connected"),
+        ConnectionStatus::Reconnecting => ("Reconnecting...", "status-dot reconnecting"),
+        ConnectionStatus::Paused => ("Paused", "status-dot paused"),
+        ConnectionStatus::Error(_) => ("Error", "status-dot"),
+    };
+    let error = match &props.status {
+        ConnectionStatus::Error(message) => Some(message.clone()),
+        _ => None,
     };
 
     rsx! {
         div { class: "status-bar",
             div { class: "status-indicator",
-                div {
-                    class: if is_connected { "status-dot connected" } else { "status-dot" }
-                }
+                div { class: "{dot_class}" }
                 span { "{status_text}" }
             }
+            if let Some(error) = error {
+                div { class: "error-label", "{error}" }
+            }
+            if let Some(latency) = &props.latency {
+                div { class: "latency-label", "{latency}" }
+            }
             div { class: "mode-label", "{props.mode_label}" }
         }
     }
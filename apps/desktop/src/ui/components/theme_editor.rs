@@ -0,0 +1,118 @@
+//! Theme Editor Component
+//!
+//! Edits every color of a theme, previewing the result on sample cards,
+//! and saves it as a named custom theme.
+
+use dioxus::prelude::*;
+use crate::ui::theme::Theme;
+
+#[derive(Props, Clone, PartialEq)]
+pub struct ThemeEditorProps {
+    /// Palette to start from, usually the active theme
+    pub base: Theme,
+    /// Called with the new theme's key once it's saved
+    pub on_saved: EventHandler<String>,
+    pub on_cancel: EventHandler<()>,
+}
+
+/// Color pickers for each field with a live preview
+#[component]
+pub fn ThemeEditor(props: ThemeEditorProps) -> Element {
+    let base = props.base.clone();
+    let mut theme = use_signal(move || {
+        // Saving can't overwrite a preset, so start from a new name
+        let mut theme = base;
+        if Theme::preset(&Theme::key_for(&theme.name)).is_some() {
+            theme.name = format!("My {}", theme.name);
+        }
+        theme
+    });
+    let mut error = use_signal(|| None::<String>);
+
+    let save = move |_| {
+        let result = theme.read().save();
+        match result {
+            Ok(key) => {
+                error.set(None);
+                props.on_saved.call(key);
+            }
+            Err(e) => error.set(Some(e.to_string())),
+        }
+    };
+
+    let current = theme.read();
+    let colors: Vec<(&'static str, String)> = current
+        .colors()
+        .into_iter()
+        .map(|(field, value)| (field, value.to_string()))
+        .collect();
+    let invalid = current.invalid_colors();
+    let preview_style = current.to_css_vars();
+
+    rsx! {
+        div { class: "theme-editor",
+            div { class: "setting-item",
+                label { "Name" }
+                input {
+                    r#type: "text",
+                    value: "{current.name}",
+                    oninput: move |e| theme.write().name = e.value(),
+                }
+            }
+
+            div { class: "theme-preview", style: "{preview_style}",
+                div { class: "theme-preview-transcript", "\"What does onboarding look like?\"" }
+                div { class: "theme-preview-flash", "• Two-week guided setup with a named CSM" }
+                div { class: "theme-preview-deep", "Walk them through the kickoff call and first milestone." }
+                span { class: "theme-preview-badge", "Objection" }
+            }
+
+            div { class: "theme-fields",
+                for (field, value) in colors {
+                    div { class: if invalid.contains(&field) { "theme-field invalid" } else { "theme-field" },
+                        label { {field.replace('_', " ")} }
+                        input {
+                            r#type: "color",
+                            value: "{picker_value(&value)}",
+                            oninput: move |e| set_color(theme, field, e.value()),
+                        }
+                        input {
+                            r#type: "text",
+                            value: "{value}",
+                            oninput: move |e| set_color(theme, field, e.value()),
+                        }
+                    }
+                }
+            }
+
+            if let Some(message) = error() {
+                p { class: "settings-hint warning", "{message}" }
+            }
+            div { class: "theme-editor-actions",
+                button { class: "save-btn", onclick: save, "Save Theme" }
+                button { onclick: move |_| props.on_cancel.call(()), "Cancel" }
+            }
+        }
+    }
+}
+
+fn set_color(mut theme: Signal<Theme>, field: &str, value: String) {
+    let mut edited = theme.write();
+    if let Some((_, slot)) = edited.colors_mut().into_iter().find(|(f, _)| *f == field) {
+        *slot = value;
+    };
+}
+
+/// Value for an `<input type="color">`, which only takes "#rrggbb"
+///
+/// rgba() values keep their text field; the picker shows black for them.
+fn picker_value(value: &str) -> String {
+    let Some(hex) = value.trim().strip_prefix('#').filter(|h| h.chars().all(|c| c.is_ascii_hexdigit())) else {
+        return "#000000".to_string();
+    };
+    match hex.len() {
+        3 | 4 => format!("#{}", hex.chars().take(3).flat_map(|c| [c, c]).collect::<String>()),
+        6 | 8 => format!("#{}", &hex[..6]),
+        _ => "#000000".to_string(),
+    }
+}
@@ -1,34 +1,222 @@
 //! Transcript View Component
 //!
 //! Displays the real-time transcript of what the other person is saying.
+//! The last line can be edited to fix misheard words and regenerate the
+//! suggestion. A competitor they named is highlighted. The search box
+//! finds earlier turns of the call and steps through the matches.
 
 use dioxus::prelude::*;
+use crate::capture::{TranscriptIndex, TranscriptMatch};
 
 #[derive(Props, Clone, PartialEq)]
 pub struct TranscriptViewProps {
     pub text: String,
     #[props(default = false)]
     pub is_listening: bool,
+    /// Line was below the confidence threshold and not analyzed
+    #[props(default = false)]
+    pub low_confidence: bool,
+    /// Word to highlight, e.g. a competitor they named
+    #[props(default)]
+    pub highlight: Option<String>,
+    /// Called with the corrected text when an edit is saved
+    pub on_correct: EventHandler<String>,
+    /// Earlier turns to search, live or from a recording
+    #[props(default)]
+    pub history: TranscriptIndex,
+}
+
+/// Pieces of a turn's text, tagged with the class to show them in
+///
+/// Matches get "search-match", and the selected one also "current".
+fn split_matches(text: &str, matches: &[TranscriptMatch], current: Option<TranscriptMatch>) -> Vec<(String, &'static str)> {
+    let mut pieces = Vec::new();
+    let mut at = 0;
+    for m in matches {
+        if m.start < at {
+            continue;
+        }
+        if m.start > at {
+            pieces.push((text[at..m.start].to_string(), ""));
+        }
+        let class = if Some(*m) == current { "search-match current" } else { "search-match" };
+        pieces.push((text[m.start..m.end].to_string(), class));
+        at = m.end;
+    }
+    if at < text.len() {
+        pieces.push((text[at..].to_string(), ""));
+    }
+    pieces
+}
+
+
+/// Text before, at and after the first case-insensitive match
+fn split_highlight(text: &str, term: &str) -> Option<(String, String, String)> {
+    if term.is_empty() {
+        return None;
+    }
+    // ASCII lowercasing keeps byte offsets valid in the original
+    let start = text.to_ascii_lowercase().find(&term.to_ascii_lowercase())?;
+    let end = start + term.len();
+    Some((text[..start].to_string(), text[start..end].to_string(), text[end..].to_string()))
 }
 
 #[component]
 pub fn TranscriptView(props: TranscriptViewProps) -> Element {
+    // Draft text while the line is being edited
+    let mut draft = use_signal(|| None::<String>);
+    let on_correct = props.on_correct;
+    let highlighted = props
+        .highlight
+        .as_deref()
+        .and_then(|term| split_highlight(&props.text, term));
+
+    let mut save = move || {
+        if let Some(text) = draft.take() {
+            on_correct.call(text);
+        }
+    };
+
+    let mut search_open = use_signal(|| false);
+    let mut query = use_signal(String::new);
+    let mut selected = use_signal(|| 0usize);
+    let matches = props.history.search(&query.read());
+    let current = (!matches.is_empty()).then(|| matches[selected().min(matches.len() - 1)]);
+
+    // Step through matches, wrapping at either end
+    let match_count = matches.len();
+    let mut step = move |forward: bool| {
+        if match_count == 0 {
+            return;
+        }
+        let at = selected().min(match_count - 1);
+        selected.set(if forward { (at + 1) % match_count } else { (at + match_count - 1) % match_count });
+    };
+
+    // Keep the selected match in view once it has rendered
+    use_effect(move || {
+        let _ = (selected(), query.read().len());
+        let _ = eval("document.querySelector('.search-match.current')?.scrollIntoView({ block: 'nearest' });");
+    });
+
+    // With a query, only turns that match are listed
+    let turns: Vec<(usize, String, Vec<(String, &'static str)>)> = props
+        .history
+        .turns()
+        .iter()
+        .enumerate()
+        .filter_map(|(i, turn)| {
+            let in_turn: Vec<TranscriptMatch> = matches.iter().copied().filter(|m| m.turn == i).collect();
+            if !query.read().trim().is_empty() && in_turn.is_empty() {
+                return None;
+            }
+            Some((i, turn.speaker.clone(), split_matches(&turn.text, &in_turn, current)))
+        })
+        .collect();
+
     rsx! {
         div { class: "transcript-section",
             div { class: "transcript-label",
                 span { "🎤" }
                 span { "They said:" }
+                if !props.text.is_empty() && draft.read().is_none() {
+                    button {
+                        class: "transcript-edit-btn",
+                        title: "Fix misheard words",
+                        onclick: {
+                            let text = props.text.clone();
+                            move |_| draft.set(Some(text.clone()))
+                        },
+                        "✏️"
+                    }
+                }
+                if !props.history.is_empty() {
+                    button {
+                        class: "transcript-edit-btn",
+                        title: "Search the transcript",
+                        onclick: move |_| search_open.set(!search_open()),
+                        "🔍"
+                    }
+                }
+            }
+            if search_open() {
+                div { class: "transcript-search",
+                    div { class: "transcript-search-bar",
+                        input {
+                            r#type: "text",
+                            placeholder: "Search this call...",
+                            value: "{query}",
+                            autofocus: true,
+                            oninput: move |e| {
+                                query.set(e.value());
+                                selected.set(0);
+                            },
+                            onkeydown: move |e| match e.key() {
+                                Key::Enter => step(!e.modifiers().shift()),
+                                Key::Escape => search_open.set(false),
+                                _ => {}
+                            },
+                        }
+                        if !query.read().trim().is_empty() {
+                            span { class: "search-count",
+                                {if match_count == 0 { "No matches".to_string() } else { format!("{}/{}", selected().min(match_count - 1) + 1, match_count) }}
+                            }
+                        }
+                        button { disabled: match_count == 0, onclick: move |_| step(false), "▲" }
+                        button { disabled: match_count == 0, onclick: move |_| step(true), "▼" }
+                    }
+                    div { class: "transcript-history",
+                        for (i, speaker, pieces) in turns {
+                            div {
+                                key: "{i}",
+                                class: if current.is_some_and(|m| m.turn == i) { "transcript-turn current" } else { "transcript-turn" },
+                                span { class: "turn-speaker", "{speaker}" }
+                                for (piece, class) in pieces {
+                                    if class.is_empty() {
+                                        "{piece}"
+                                    } else {
+                                        mark { class: "{class}", "{piece}" }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
             }
-            div { class: "transcript-text",
-                {if props.text.is_empty() {
-                    if props.is_listening {
-                        "Listening..."
+            if let Some(text) = draft.read().clone() {
+                div { class: "transcript-edit",
+                    input {
+                        r#type: "text",
+                        value: "{text}",
+                        autofocus: true,
+                        oninput: move |e| draft.set(Some(e.value())),
+                        onkeydown: move |e| match e.key() {
+                            Key::Enter => save(),
+                            Key::Escape => draft.set(None),
+                            _ => {}
+                        },
+                    }
+                    button { onclick: move |_| save(), "Save" }
+                    button { onclick: move |_| draft.set(None), "Cancel" }
+                }
+            } else {
+                div {
+                    class: if props.low_confidence { "transcript-text low-confidence" } else { "transcript-text" },
+                    title: if props.low_confidence { "Low confidence - not analyzed" } else { "" },
+                    if props.text.is_empty() {
+                        if props.is_listening {
+                            "Listening..."
+                        } else {
+                            "Waiting for speech..."
+                        }
+                    } else if let Some((before, term, after)) = highlighted {
+                        "{before}"
+                        span { class: "competitor-mention", "{term}" }
+                        "{after}"
                     } else {
-                        "Waiting for speech..."
+                        "{props.text}"
                     }
-                } else {
-                    props.text.as_str()
-                }}
+                }
             }
         }
     }
@@ -1,131 +1,311 @@
 //! Global Hotkey Integration
 //!
-//! Registers and handles global keyboard shortcuts:
-//! - Ctrl+Shift+S: Start/Stop listening
-//! - Ctrl+Shift+H: Hide/Show window
-//! - Ctrl+Shift+M: Switch mode
-//! - Ctrl+Shift+C: Copy last suggestion
-
-use global_hotkey::{
-    hotkey::{Code, HotKey, Modifiers},
-    GlobalHotKeyEvent, GlobalHotKeyManager,
-};
-use std::sync::mpsc;
+//! Registers the global keyboard shortcuts from the user's settings
+//! (Ctrl+Shift+S/H/M/C/A/P/D by default). Bindings can be changed in the
+//! settings panel; conflicts are caught before saving and combos the OS
+//! refuses are reported back through the shared state.
+
+use anyhow::{anyhow, Result};
+use global_hotkey::{hotkey::HotKey, GlobalHotKeyEvent, GlobalHotKeyManager};
+use parking_lot::RwLock;
+use std::collections::HashMap;
+use std::sync::{mpsc, Arc, OnceLock};
+use std::time::Duration;
+
+use crate::config::{CopyTarget, HotkeySettings};
+use super::runtime::SharedState;
+
+/// How often the listener checks for new bindings
+const RELOAD_POLL: Duration = Duration::from_millis(250);
+
+/// Sends new bindings to the listener thread
+static RELOAD: OnceLock<mpsc::Sender<HotkeyConfig>> = OnceLock::new();
 
 /// Hotkey actions
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum HotkeyAction {
     ToggleListen,
     ToggleVisibility,
-    SwitchMode,
+    CycleMode,
     CopySuggestion,
+    RequestAnalysis,
+    TogglePause,
+    ToggleFlashOnly,
 }
 
-/// Hotkey manager that registers and handles global shortcuts
-pub struct HotkeyHandler {
-    manager: GlobalHotKeyManager,
-    toggle_listen_id: u32,
-    toggle_visibility_id: u32,
-    switch_mode_id: u32,
-    copy_suggestion_id: u32,
+impl HotkeyAction {
+    pub fn all() -> [HotkeyAction; 7] {
+        [
+            HotkeyAction::ToggleListen,
+            HotkeyAction::ToggleVisibility,
+            HotkeyAction::CycleMode,
+            HotkeyAction::CopySuggestion,
+            HotkeyAction::RequestAnalysis,
+            HotkeyAction::TogglePause,
+            HotkeyAction::ToggleFlashOnly,
+        ]
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            HotkeyAction::ToggleListen => "Start/Stop listening",
+            HotkeyAction::ToggleVisibility => "Hide/Show window",
+            HotkeyAction::CycleMode => "Switch mode",
+            HotkeyAction::CopySuggestion => "Copy suggestion",
+            HotkeyAction::RequestAnalysis => "Analyze now (push to talk)",
+            HotkeyAction::TogglePause => "Pause/Resume",
+            HotkeyAction::ToggleFlashOnly => "Bullets only on/off",
+        }
+    }
 }
 
-impl HotkeyHandler {
-    /// Create and register all hotkeys
-    pub fn new() -> Result<Self, Box<dyn std::error::Error>> {
-        let manager = GlobalHotKeyManager::new()?;
-
-        // Ctrl+Shift+S - Toggle listening
-        let toggle_listen = HotKey::new(
-            Some(Modifiers::CONTROL | Modifiers::SHIFT),
-            Code::KeyS,
-        );
+/// Key combo bound to each action, e.g. "Ctrl+Shift+S"
+#[derive(Debug, Clone, PartialEq)]
+pub struct HotkeyConfig {
+    pub bindings: Vec<(HotkeyAction, String)>,
+}
 
-        // Ctrl+Shift+H - Toggle visibility
-        let toggle_visibility = HotKey::new(
-            Some(Modifiers::CONTROL | Modifiers::SHIFT),
-            Code::KeyH,
-        );
+impl HotkeyConfig {
+    pub fn from_settings(settings: &HotkeySettings) -> Self {
+        Self {
+            bindings: vec![
+                (HotkeyAction::ToggleListen, settings.toggle_listen.clone()),
+                (HotkeyAction::ToggleVisibility, settings.toggle_visibility.clone()),
+                (HotkeyAction::CycleMode, settings.switch_mode.clone()),
+                (HotkeyAction::CopySuggestion, settings.copy_suggestion.clone()),
+                (HotkeyAction::RequestAnalysis, settings.request_analysis.clone()),
+                (HotkeyAction::TogglePause, settings.toggle_pause.clone()),
+                (HotkeyAction::ToggleFlashOnly, settings.toggle_flash_only.clone()),
+            ],
+        }
+    }
 
-        // Ctrl+Shift+M - Switch mode
-        let switch_mode = HotKey::new(
-            Some(Modifiers::CONTROL | Modifiers::SHIFT),
-            Code::KeyM,
-        );
+    /// Write the bindings back into settings for saving
+    pub fn apply_to(&self, settings: &mut HotkeySettings) {
+        for (action, combo) in &self.bindings {
+            let field = match action {
+                HotkeyAction::ToggleListen => &mut settings.toggle_listen,
+                HotkeyAction::ToggleVisibility => &mut settings.toggle_visibility,
+                HotkeyAction::CycleMode => &mut settings.switch_mode,
+                HotkeyAction::CopySuggestion => &mut settings.copy_suggestion,
+                HotkeyAction::RequestAnalysis => &mut settings.request_analysis,
+                HotkeyAction::TogglePause => &mut settings.toggle_pause,
+                HotkeyAction::ToggleFlashOnly => &mut settings.toggle_flash_only,
+            };
+            *field = combo.clone();
+        }
+    }
 
-        // Ctrl+Shift+C - Copy suggestion
-        let copy_suggestion = HotKey::new(
-            Some(Modifiers::CONTROL | Modifiers::SHIFT),
-            Code::KeyC,
-        );
+    pub fn combo(&self, action: HotkeyAction) -> &str {
+        self.bindings
+            .iter()
+            .find(|(a, _)| *a == action)
+            .map(|(_, combo)| combo.as_str())
+            .unwrap_or_default()
+    }
+
+    pub fn set(&mut self, action: HotkeyAction, combo: String) {
+        match self.bindings.iter_mut().find(|(a, _)| *a == action) {
+            Some(binding) => binding.1 = combo,
+            None => self.bindings.push((action, combo)),
+        }
+    }
+
+    /// Problems that stop the bindings being saved, one per action
+    ///
+    /// Combos are compared after parsing, so "ctrl+shift+s" and
+    /// "Shift+Ctrl+S" count as the same shortcut.
+    pub fn problems(&self) -> Vec<(HotkeyAction, String)> {
+        let parsed: Vec<_> = self
+            .bindings
+            .iter()
+            .map(|(action, combo)| (*action, combo.parse::<HotKey>().map(|h| h.id())))
+            .collect();
+
+        parsed
+            .iter()
+            .filter_map(|(action, id)| match id {
+                Err(_) => Some((*action, format!("\"{}\" isn't a valid shortcut", self.combo(*action)))),
+                Ok(id) => parsed
+                    .iter()
+                    .find(|(other, other_id)| other != action && matches!(other_id, Ok(other) if other == id))
+                    .map(|(other, _)| (*action, format!("Same shortcut as \"{}\"", other.label()))),
+            })
+            .collect()
+    }
+}
+
+impl Default for HotkeyConfig {
+    fn default() -> Self {
+        Self::from_settings(&HotkeySettings::default())
+    }
+}
 
-        // Register all hotkeys
-        manager.register(toggle_listen)?;
-        manager.register(toggle_visibility)?;
-        manager.register(switch_mode)?;
-        manager.register(copy_suggestion)?;
+/// Combo for a key press in the settings editor, e.g. "Ctrl+Shift+K"
+///
+/// `code` is the physical key ("KeyK", "Digit1", "F5"). Returns None while
+/// only modifiers are held, and for plain keys without a modifier (other
+/// than function keys) since those would swallow normal typing.
+pub fn combo_from_keys(ctrl: bool, alt: bool, shift: bool, meta: bool, code: &str) -> Option<String> {
+    let modifier_key = ["Control", "Alt", "Shift", "Meta", "OS"]
+        .iter()
+        .any(|m| code.starts_with(m));
+    if code.is_empty() || modifier_key {
+        return None;
+    }
+
+    let key = code
+        .strip_prefix("Key")
+        .or_else(|| code.strip_prefix("Digit"))
+        .unwrap_or(code);
+    let function_key = key.len() > 1 && key.starts_with('F') && key[1..].chars().all(|c| c.is_ascii_digit());
+    if !(ctrl || alt || shift || meta || function_key) {
+        return None;
+    }
+
+    let mut parts: Vec<&str> = Vec::new();
+    if ctrl { parts.push("Ctrl"); }
+    if alt { parts.push("Alt"); }
+    if shift { parts.push("Shift"); }
+    if meta { parts.push("Super"); }
+    parts.push(key);
+    Some(parts.join("+"))
+}
+
+/// Hotkey manager that registers and handles global shortcuts
+pub struct HotkeyHandler {
+    manager: GlobalHotKeyManager,
+    registered: Vec<HotKey>,
+    actions: HashMap<u32, HotkeyAction>,
+}
 
+impl HotkeyHandler {
+    pub fn new() -> Result<Self> {
         Ok(Self {
-            manager,
-            toggle_listen_id: toggle_listen.id(),
-            toggle_visibility_id: toggle_visibility.id(),
-            switch_mode_id: switch_mode.id(),
-            copy_suggestion_id: copy_suggestion.id(),
+            manager: GlobalHotKeyManager::new()?,
+            registered: Vec::new(),
+            actions: HashMap::new(),
         })
     }
 
+    /// Replace the registered hotkeys with a config's
+    ///
+    /// Each binding is registered on its own so one combo taken by another
+    /// app doesn't disable the rest. Returns a message per failed binding.
+    pub fn register(&mut self, config: &HotkeyConfig) -> Vec<String> {
+        if let Err(e) = self.manager.unregister_all(&self.registered) {
+            tracing::warn!("Failed to unregister hotkeys: {}", e);
+        }
+        self.registered.clear();
+        self.actions.clear();
+
+        let mut failures = Vec::new();
+        for (action, combo) in &config.bindings {
+            let hotkey = match combo.parse::<HotKey>() {
+                Ok(hotkey) => hotkey,
+                Err(e) => {
+                    failures.push(format!("{} ({}): {}", action.label(), combo, e));
+                    continue;
+                }
+            };
+            if self.actions.contains_key(&hotkey.id()) {
+                failures.push(format!("{} ({}): already bound to another action", action.label(), combo));
+                continue;
+            }
+            match self.manager.register(hotkey) {
+                Ok(()) => {
+                    tracing::info!("  {}: {}", combo, action.label());
+                    self.registered.push(hotkey);
+                    self.actions.insert(hotkey.id(), *action);
+                }
+                Err(e) => {
+                    tracing::warn!("Failed to register {}: {}", combo, e);
+                    failures.push(format!("{} ({}): in use by another app or the OS", action.label(), combo));
+                }
+            }
+        }
+        failures
+    }
+
     /// Get the action for a hotkey event
     pub fn get_action(&self, event: &GlobalHotKeyEvent) -> Option<HotkeyAction> {
-        let id = event.id();
-
-        if id == self.toggle_listen_id {
-            Some(HotkeyAction::ToggleListen)
-        } else if id == self.toggle_visibility_id {
-            Some(HotkeyAction::ToggleVisibility)
-        } else if id == self.switch_mode_id {
-            Some(HotkeyAction::SwitchMode)
-        } else if id == self.copy_suggestion_id {
-            Some(HotkeyAction::CopySuggestion)
-        } else {
-            None
-        }
+        self.actions.get(&event.id()).copied()
     }
 
     /// Get the global hotkey event receiver
-    pub fn receiver() -> std::sync::mpsc::Receiver<GlobalHotKeyEvent> {
+    pub fn receiver() -> mpsc::Receiver<GlobalHotKeyEvent> {
         GlobalHotKeyEvent::receiver().clone()
     }
 }
 
-impl Drop for HotkeyHandler {
-    fn drop(&mut self) {
-        // Hotkeys are automatically unregistered when manager is dropped
+/// Re-register the global hotkeys with new bindings
+pub fn reload_hotkeys(config: HotkeyConfig) {
+    match RELOAD.get() {
+        Some(tx) => {
+            let _ = tx.send(config);
+        }
+        None => tracing::debug!("Hotkey listener not running; bindings apply on next launch"),
     }
 }
 
+/// Text the copy shortcut should copy from the current state
+pub fn suggestion_text(state: &SharedState, target: CopyTarget) -> Option<String> {
+    let bullets = state.flash.as_ref().map(|f| f.bullets.as_slice()).unwrap_or_default();
+
+    let text = match target {
+        CopyTarget::Deep if !state.deep_content.trim().is_empty() => state.deep_content.trim().to_string(),
+        CopyTarget::Deep => bullets.iter().min_by_key(|b| b.priority)?.point.clone(),
+        CopyTarget::Bullets => {
+            let mut sorted: Vec<_> = bullets.iter().collect();
+            sorted.sort_by_key(|b| b.priority);
+            sorted.iter().map(|b| format!("• {}", b.point)).collect::<Vec<_>>().join("\n")
+        }
+        CopyTarget::Question => state.question.clone()?,
+    };
+
+    if text.is_empty() { None } else { Some(text) }
+}
+
+/// Copy the current suggestion to the system clipboard
+pub fn copy_suggestion(state: &SharedState, target: CopyTarget) -> Result<String> {
+    let text = suggestion_text(state, target).ok_or_else(|| anyhow!("Nothing to copy yet"))?;
+    arboard::Clipboard::new()?.set_text(text.clone())?;
+    Ok(text)
+}
+
 /// Spawn hotkey listener thread
+///
+/// Registers the saved bindings and writes any that failed to
+/// `hotkey_errors` so the settings panel can show them.
 pub fn spawn_hotkey_listener(
     action_tx: tokio::sync::mpsc::Sender<HotkeyAction>,
+    state: Arc<RwLock<SharedState>>,
 ) -> std::thread::JoinHandle<()> {
+    let (reload_tx, reload_rx) = mpsc::channel();
+    let _ = RELOAD.set(reload_tx);
+
     std::thread::spawn(move || {
-        let handler = match HotkeyHandler::new() {
+        let mut handler = match HotkeyHandler::new() {
             Ok(h) => h,
             Err(e) => {
                 tracing::error!("Failed to register hotkeys: {}", e);
+                state.write().hotkey_errors = vec![format!("Global shortcuts unavailable: {}", e)];
                 return;
             }
         };
 
+        let config = HotkeyConfig::from_settings(&crate::config::Settings::load().unwrap_or_default().hotkeys);
         tracing::info!("Hotkeys registered:");
-        tracing::info!("  Ctrl+Shift+S: Start/Stop listening");
-        tracing::info!("  Ctrl+Shift+H: Hide/Show window");
-        tracing::info!("  Ctrl+Shift+M: Switch mode");
-        tracing::info!("  Ctrl+Shift+C: Copy suggestion");
+        state.write().hotkey_errors = handler.register(&config);
 
         let receiver = HotkeyHandler::receiver();
 
         loop {
-            if let Ok(event) = receiver.recv() {
+            if let Ok(config) = reload_rx.try_recv() {
+                tracing::info!("Hotkeys re-registered:");
+                state.write().hotkey_errors = handler.register(&config);
+            }
+            if let Ok(event) = receiver.recv_timeout(RELOAD_POLL) {
                 if let Some(action) = handler.get_action(&event) {
                     tracing::debug!("Hotkey action: {:?}", action);
                     let _ = action_tx.blocking_send(action);
@@ -134,3 +314,75 @@ pub fn spawn_hotkey_listener(
         }
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::flash::FlashAnalysis;
+
+    fn state_with_bullets() -> SharedState {
+        let flash: FlashAnalysis = serde_json::from_str(
+            r#"{"summary": "Pricing question", "bullets": [
+                {"point": "Mention the annual discount", "priority": 2},
+                {"point": "Enterprise starts at $50/seat", "priority": 1}
+            ], "type": "question", "urgency": "answer_now"}"#,
+        )
+        .unwrap();
+        SharedState {
+            flash: Some(flash),
+            ..SharedState::default()
+        }
+    }
+
+    #[test]
+    fn test_deep_falls_back_to_top_bullet() {
+        let mut state = state_with_bullets();
+        assert_eq!(
+            suggestion_text(&state, CopyTarget::Deep).as_deref(),
+            Some("Enterprise starts at $50/seat")
+        );
+
+        state.deep_content = "## Direct Answer\nIt's $50 per seat.\n".to_string();
+        assert_eq!(
+            suggestion_text(&state, CopyTarget::Deep).as_deref(),
+            Some("## Direct Answer\nIt's $50 per seat.")
+        );
+    }
+
+    #[test]
+    fn test_hotkey_problems() {
+        let mut config = HotkeyConfig::default();
+        assert!(config.problems().is_empty());
+
+        config.set(HotkeyAction::CycleMode, "shift+ctrl+s".to_string());
+        config.set(HotkeyAction::CopySuggestion, "Ctrl+Nope".to_string());
+        let problems = config.problems();
+        assert_eq!(problems.len(), 3);
+        assert_eq!(problems[0], (HotkeyAction::ToggleListen, "Same shortcut as \"Switch mode\"".to_string()));
+        assert_eq!(problems[2].0, HotkeyAction::CopySuggestion);
+
+        let mut settings = HotkeySettings::default();
+        config.apply_to(&mut settings);
+        assert_eq!(settings.switch_mode, "shift+ctrl+s");
+    }
+
+    #[test]
+    fn test_combo_from_keys() {
+        assert_eq!(combo_from_keys(true, false, true, false, "KeyK").as_deref(), Some("Ctrl+Shift+K"));
+        assert_eq!(combo_from_keys(false, true, false, false, "Digit1").as_deref(), Some("Alt+1"));
+        assert_eq!(combo_from_keys(false, false, false, false, "F9").as_deref(), Some("F9"));
+        assert_eq!(combo_from_keys(true, false, false, false, "ControlLeft"), None);
+        assert_eq!(combo_from_keys(false, false, false, false, "KeyK"), None);
+    }
+
+    #[test]
+    fn test_copy_targets() {
+        let state = state_with_bullets();
+        assert_eq!(
+            suggestion_text(&state, CopyTarget::Bullets).as_deref(),
+            Some("• Enterprise starts at $50/seat\n• Mention the annual discount")
+        );
+        assert_eq!(suggestion_text(&state, CopyTarget::Question), None);
+        assert_eq!(suggestion_text(&SharedState::default(), CopyTarget::Deep), None);
+    }
+}
@@ -0,0 +1,164 @@
+//! Markdown Rendering
+//!
+//! Turns Deep responses into HTML for the answer card. Raw HTML from the
+//! model is shown as text and links/images are reduced to their text, so
+//! nothing in a response can run script or navigate the app window.
+//!
+//! Code blocks can be syntax highlighted with syntect. Tokens get `hl-*`
+//! scope classes rather than inline colors so the stylesheet maps them to
+//! the active theme's variables.
+
+use pulldown_cmark::{html, CodeBlockKind, CowStr, Event, Options, Parser, Tag, TagEnd};
+use std::sync::OnceLock;
+use syntect::html::{ClassStyle, ClassedHTMLGenerator};
+use syntect::parsing::{SyntaxReference, SyntaxSet};
+use syntect::util::LinesWithEndings;
+
+/// Blinking cursor placed after the last streamed text
+const STREAM_CURSOR: &str = r#"<span class="stream-cursor"></span>"#;
+
+/// Scope class prefix, so highlight classes can't clash with the app's
+const CLASS_PREFIX: &str = "hl-";
+
+fn syntax_set() -> &'static SyntaxSet {
+    static SYNTAXES: OnceLock<SyntaxSet> = OnceLock::new();
+    SYNTAXES.get_or_init(SyntaxSet::load_defaults_newlines)
+}
+
+/// Render markdown to sanitized HTML
+///
+/// While `streaming`, a cursor is placed right after the last text so it
+/// follows the answer instead of sitting below the last block. With
+/// `highlight_code`, fenced and indented code blocks are highlighted and
+/// get a copy button.
+pub fn render_markdown(text: &str, streaming: bool, highlight_code: bool) -> String {
+    let options = Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH;
+    let mut events: Vec<Event> = Vec::new();
+    // Language and text of the code block being collected
+    let mut code: Option<(String, String)> = None;
+
+    for event in Parser::new_ext(text, options) {
+        match event {
+            Event::Start(Tag::CodeBlock(kind)) if highlight_code => {
+                let lang = match kind {
+                    CodeBlockKind::Fenced(info) => info.split_whitespace().next().unwrap_or_default().to_string(),
+                    CodeBlockKind::Indented => String::new(),
+                };
+                code = Some((lang, String::new()));
+            }
+            Event::Text(body) if code.is_some() => {
+                if let Some((_, buffer)) = code.as_mut() {
+                    buffer.push_str(&body);
+                }
+            }
+            Event::End(TagEnd::CodeBlock) if code.is_some() => {
+                if let Some((lang, body)) = code.take() {
+                    events.push(Event::Html(highlight_block(&lang, &body).into()));
+                }
+            }
+            Event::Html(raw) | Event::InlineHtml(raw) => events.push(Event::Text(raw)),
+            Event::Start(Tag::Link { .. } | Tag::Image { .. }) => {}
+            Event::End(TagEnd::Link | TagEnd::Image) => {}
+            other => events.push(other),
+        }
+    }
+
+    if streaming {
+        // Model HTML was turned into text above, so any Html left is a code block
+        let last_text = events
+            .iter()
+            .rposition(|event| matches!(event, Event::Text(_) | Event::Code(_) | Event::Html(_)));
+        match last_text {
+            Some(index) => events.insert(index + 1, Event::InlineHtml(CowStr::Borrowed(STREAM_CURSOR))),
+            None => events.push(Event::InlineHtml(CowStr::Borrowed(STREAM_CURSOR))),
+        }
+    }
+
+    let mut out = String::with_capacity(text.len() * 3 / 2);
+    html::push_html(&mut out, events.into_iter());
+    out
+}
+
+/// Syntax for a block: its fence label, else a guess from the first line
+/// (shebangs, `<?php`, ...), else plain text
+fn find_syntax<'a>(syntaxes: &'a SyntaxSet, lang: &str, body: &str) -> &'a SyntaxReference {
+    Some(lang)
+        .filter(|lang| !lang.is_empty())
+        .and_then(|lang| syntaxes.find_syntax_by_token(lang))
+        .or_else(|| syntaxes.find_syntax_by_first_line(body.lines().next().unwrap_or_default()))
+        .unwrap_or_else(|| syntaxes.find_syntax_plain_text())
+}
+
+/// A code block as highlighted HTML with a copy button
+fn highlight_block(lang: &str, body: &str) -> String {
+    let syntaxes = syntax_set();
+    let syntax = find_syntax(syntaxes, lang, body);
+    let mut generator = ClassedHTMLGenerator::new_with_class_style(
+        syntax,
+        syntaxes,
+        ClassStyle::SpacedPrefixed { prefix: CLASS_PREFIX },
+    );
+
+    let highlighted = LinesWithEndings::from(body)
+        .try_for_each(|line| generator.parse_html_for_line_which_includes_newline(line))
+        .map(|_| generator.finalize());
+    let code = match highlighted {
+        Ok(html) => html,
+        Err(e) => {
+            tracing::debug!("Highlighting failed, showing plain code: {}", e);
+            let mut escaped = String::new();
+            html::push_html(&mut escaped, std::iter::once(Event::Text(body.into())));
+            escaped
+        }
+    };
+
+    format!(
+        r#"<div class="code-block"><div class="code-block-header"><span>{}</span><button class="copy-code" onclick="copyCode(this)">Copy</button></div><pre><code>{}</code></pre></div>"#,
+        syntax.name, code
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_markdown() {
+        let html = render_markdown("## Direct Answer\n- **$50** per seat\n- `SSO` included", false, false);
+        assert!(html.contains("<h2>Direct Answer</h2>"));
+        assert!(html.contains("<li><strong>$50</strong> per seat</li>"));
+        assert!(html.contains("<code>SSO</code>"));
+
+        let streaming = render_markdown("Offer the pilot", true, false);
+        assert_eq!(streaming, format!("<p>Offer the pilot{}</p>\n", STREAM_CURSOR));
+    }
+
+    #[test]
+    fn test_sanitizes_html_and_links() {
+        let html = render_markdown("<script>alert(1)</script>\n\nSee [docs](javascript:alert(1)) <img src=x onerror=alert(1)>", false, true);
+        assert!(!html.contains("<script"));
+        assert!(!html.contains("<img"));
+        assert!(!html.contains("href"));
+        assert!(html.contains("&lt;script&gt;"));
+        assert!(html.contains("docs"));
+    }
+
+    #[test]
+    fn test_highlight_code_blocks() {
+        let html = render_markdown("Use a guard:\n\n```rust\nfn main() { let x = 1 < 2; }\n```", false, true);
+        assert!(html.contains(r#"<div class="code-block">"#));
+        assert!(html.contains("<span>Rust</span>"));
+        assert!(html.contains(r#"<span class="hl-source hl-rust">"#));
+        assert!(html.contains("&lt;") && !html.contains("1 < 2"));
+
+        // Unlabeled blocks are guessed from the first line or left plain
+        let html = render_markdown("```\n#!/bin/bash\necho hi\n```", false, true);
+        assert!(html.contains("<span>Bourne Again Shell (bash)</span>"));
+        let html = render_markdown("```\nSELECT <b>\n```", false, true);
+        assert!(html.contains("<span>Plain Text</span>"));
+        assert!(html.contains("SELECT &lt;b&gt;"));
+
+        // Plain code blocks without highlighting
+        assert!(render_markdown("```rust\nfn main() {}\n```", false, false).starts_with("<pre><code class=\"language-rust\">"));
+    }
+}
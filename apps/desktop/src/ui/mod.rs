@@ -9,6 +9,7 @@
 //! - Global hotkey support
 //! - Auto-update system
 //! - Stealth mode (F8 toggle)
+//! - Screen-share detection (auto-hide)
 //! - Theme system with color-coded outputs
 
 mod app;
@@ -17,18 +18,22 @@ mod components;
 mod tray;
 mod settings;
 mod hotkeys;
+mod markdown;
 mod update_button;
 mod theme;
 mod stealth;
+mod screen_share;
 mod styles;
 pub mod runtime;
 
 pub use app::launch_app;
 pub use runtime::{RuntimeHandle, RuntimeService, SharedState};
-pub use hotkeys::{HotkeyHandler, HotkeyAction, spawn_hotkey_listener};
+pub use hotkeys::{HotkeyHandler, HotkeyAction, HotkeyConfig, reload_hotkeys, spawn_hotkey_listener};
 pub use tray::{TrayHandler, TrayAction, spawn_tray_listener};
 pub use settings::SettingsPanel;
+pub use markdown::render_markdown;
 pub use update_button::UpdateButton;
-pub use theme::{Theme, get_statement_color, get_urgency_color, get_sentiment_color};
+pub use theme::{Theme, THEME_PRESETS, get_statement_color, get_urgency_color, get_sentiment_color};
 pub use stealth::{StealthMode, StealthHotkeyManager, commands as stealth_commands};
-pub use styles::{POLISHED_CSS, get_themed_css};
+pub use screen_share::{ScreenShareEvent, detect_screen_share, spawn_screen_share_monitor};
+pub use styles::{POLISHED_CSS, get_themed_css, get_theme_vars};
@@ -4,44 +4,162 @@
 //! Manages the tokio runtime and pipeline lifecycle.
 
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 use parking_lot::RwLock;
 use tokio::sync::mpsc;
 use anyhow::Result;
 
-use crate::brain::{CopilotPipeline, PipelineConfig, PipelineEvent, FlashModelChoice};
-use crate::deep::ModelChoice;
-use crate::capture::AudioSource;
-use crate::config::Settings;
-use crate::flash::{FlashAnalysis, Bullet};
+use crate::brain::{CopilotPipeline, DetectedIntent, PipelineConfig, PipelineEvent, FlashModelChoice, TriggerMode};
+use crate::deep::{ModelChoice, DEFAULT_OPENROUTER_DEEP_MODEL};
+use crate::capture::{AudioLevel, AudioSource, CaptureError, HotwordConfig, HotwordListener, TranscriptIndex};
+use crate::config::{Battlecards, DeepModel, FlashModel, Glossary, ModelSettings, ObjectionEntry, ObjectionLibrary, OutreachAccount, ResponseMode, Settings, WakeWordSettings, WebhookConfig};
+use crate::error::{CopilotError, ErrorSeverity};
+use crate::flash::{FlashAnalysis, Bullet, DEFAULT_OPENROUTER_FLASH_MODEL};
+use crate::analytics::AnalyticsManager;
+use crate::recording::{save_recording, ActionItem, RecordingManager};
+use crate::voice::{TTSConfig, TTSProvider, VoiceOutput};
+use super::app::CopilotMode;
+
+/// How long a confirmation like "Copied" stays visible
+const NOTICE_DURATION: Duration = Duration::from_secs(2);
+
+/// How long the border stays red for an urgent suggestion
+const ALERT_DURATION: Duration = Duration::from_secs(2);
+
+/// Lowest overlay opacity, so the window can't be lost entirely
+pub const MIN_OVERLAY_OPACITY: f32 = 0.2;
 
 /// Commands from UI to runtime
 #[derive(Debug, Clone)]
 pub enum RuntimeCommand {
     Start,
     Stop,
-    SetMode(String),
+    SetMode(CopilotMode),
+    /// Advance to the next mode (hotkey)
+    CycleMode,
+    /// Replace the latest transcript line and re-analyze it
+    CorrectTranscript(String),
     SetAudioSource(AudioSource),
+    RequestAnalysis,
+    /// Pause the pipeline if it's listening, resume it if paused
+    TogglePause,
+    /// Background on the prospect for every prompt; kept for the next call
+    SetBrief(String),
+    /// Company the brief was filled from, named in webhook notifications
+    SetLead(Option<String>),
+    /// The wake phrase was heard; starts listening if idle
+    WakeWord,
+    /// Wake word settings changed
+    SetWakeWord(WakeWordSettings),
+    /// Which of Flash and Deep run, from the next utterance
+    SetResponseMode(ResponseMode),
+    /// Turn bullets-only on or off (hotkey)
+    ToggleFlashOnly,
+}
+
+/// Overlay window appearance, applied by the UI while in overlay mode
+#[derive(Debug, Clone, PartialEq)]
+pub struct OverlayWindow {
+    /// 0.2 to 1.0
+    pub opacity: f32,
+    /// Clicks pass through to the window underneath
+    pub click_through: bool,
+}
+
+impl Default for OverlayWindow {
+    fn default() -> Self {
+        Self {
+            opacity: 1.0,
+            click_through: false,
+        }
+    }
 }
 
 /// State shared between UI and runtime
 #[derive(Debug, Clone, Default)]
 pub struct SharedState {
     pub is_running: bool,
+    /// Running, but not transcribing or analyzing
+    pub is_paused: bool,
+    /// Call brief, applied when a call starts and editable during it
+    pub brief: String,
+    /// Lead the brief was filled from, if any
+    pub lead: Option<String>,
+    /// Active mode, so a hotkey change shows up in the selector
+    pub mode: CopilotMode,
     pub transcript: String,
+    /// The transcript line was below the confidence threshold and not analyzed
+    pub transcript_low_confidence: bool,
+    /// Concerns detected in their latest turn, best first
+    pub intents: Vec<DetectedIntent>,
+    /// Library response to their latest turn, shown with the Flash bullets
+    pub objection: Option<ObjectionEntry>,
+    /// Commitments picked up during a Meeting or Coaching call
+    pub action_items: Vec<ActionItem>,
     pub flash: Option<FlashAnalysis>,
     pub deep_content: String,
     pub deep_streaming: bool,
+    /// No LLM configured; the call is transcribed but not analyzed
+    pub transcription_only: bool,
+    /// Deep responses are hidden while this is `FlashOnly`
+    pub response_mode: ResponseMode,
     pub question: Option<String>,
     pub error: Option<String>,
+    /// Classified form of the last provider error, for colouring
+    pub last_error: Option<CopilotError>,
     pub status: String,
+    /// Latency summary of the last turn, e.g. "Flash 680ms / Deep 1.2s"
+    pub latency: Option<String>,
+    /// STT connection dropped and is being re-established
+    pub reconnecting: bool,
+    /// Short confirmation for the status bar, e.g. "Copied to clipboard"
+    pub notice: Option<(String, Instant)>,
+    pub overlay: OverlayWindow,
+    /// Call app sharing the screen right now, e.g. "Zoom"
+    pub screen_share: Option<String>,
+    /// Follow-up date suggested during the last call
+    pub suggested_followup: Option<chrono::DateTime<chrono::Utc>>,
+    /// When the last urgent-suggestion alert fired
+    pub alert_at: Option<Instant>,
+    /// Capture input level while listening
+    pub audio_level: Option<AudioLevel>,
+    /// Shortcuts that couldn't be registered, e.g. taken by another app
+    pub hotkey_errors: Vec<String>,
+    /// Every turn of the current (or last) call, for transcript search
+    pub transcript_index: TranscriptIndex,
+}
+
+impl SharedState {
+    /// Notice text, if it was posted recently enough to still show
+    pub fn active_notice(&self) -> Option<String> {
+        self.notice
+            .as_ref()
+            .filter(|(_, at)| at.elapsed() < NOTICE_DURATION)
+            .map(|(text, _)| text.clone())
+    }
+
+    /// Whether the urgent-suggestion border should be showing
+    pub fn alert_active(&self) -> bool {
+        self.alert_at.is_some_and(|at| at.elapsed() < ALERT_DURATION)
+    }
 }
 
 /// Runtime service that manages the pipeline
 pub struct RuntimeService {
     pipeline: Option<CopilotPipeline>,
+    /// Recording of the current call, saved when it stops
+    recorder: Option<Arc<RecordingManager>>,
+    /// Metrics for every call, loaded from disk at startup
+    analytics: Arc<AnalyticsManager>,
     state: Arc<RwLock<SharedState>>,
     settings: Settings,
+    mode: CopilotMode,
     command_rx: mpsc::Receiver<RuntimeCommand>,
+    /// For the wake word listener to start a call; weak so the service
+    /// still ends when every handle is gone
+    command_tx: mpsc::WeakSender<RuntimeCommand>,
+    /// Listening for the wake phrase; only while idle
+    hotword: Option<HotwordListener>,
 }
 
 impl RuntimeService {
@@ -50,31 +168,113 @@ impl RuntimeService {
         settings: Settings,
         state: Arc<RwLock<SharedState>>,
         command_rx: mpsc::Receiver<RuntimeCommand>,
+        command_tx: mpsc::WeakSender<RuntimeCommand>,
     ) -> Self {
+        state.write().response_mode = settings.responses.mode;
+        let analytics = AnalyticsManager::new();
+        if let Err(e) = analytics.load_from_disk() {
+            tracing::warn!("Failed to load analytics history: {}", e);
+        }
         Self {
             pipeline: None,
+            recorder: None,
+            analytics: Arc::new(analytics),
             state,
             settings,
+            mode: CopilotMode::default(),
             command_rx,
+            command_tx,
+            hotword: None,
         }
     }
 
     /// Run the service (call from tokio runtime)
     pub async fn run(mut self) {
+        self.listen_for_wake_word().await;
+
         while let Some(cmd) = self.command_rx.recv().await {
             match cmd {
-                RuntimeCommand::Start => {
+                RuntimeCommand::WakeWord if self.pipeline.is_some() => {}
+                RuntimeCommand::Start | RuntimeCommand::WakeWord => {
+                    // Free the microphone for the call
+                    self.hotword = None;
                     if let Err(e) = self.start_pipeline().await {
-                        self.state.write().error = Some(e.to_string());
-                        self.state.write().status = "Error".to_string();
+                        // Audio problems come with a fix the user can apply
+                        let message = match e.downcast_ref::<CaptureError>() {
+                            Some(capture) => {
+                                tracing::warn!("{}", capture);
+                                capture.message()
+                            }
+                            None => e.to_string(),
+                        };
+                        {
+                            let mut state = self.state.write();
+                            state.is_running = false;
+                            state.error = Some(message);
+                            state.status = "Error".to_string();
+                        }
+                        self.listen_for_wake_word().await;
                     }
                 }
                 RuntimeCommand::Stop => {
                     self.stop_pipeline();
+                    self.listen_for_wake_word().await;
+                }
+                RuntimeCommand::SetWakeWord(wake_word) => {
+                    self.settings.audio.wake_word = wake_word;
+                    self.hotword = None;
+                    self.listen_for_wake_word().await;
                 }
                 RuntimeCommand::SetMode(mode) => {
+                    self.apply_mode(mode);
+                }
+                RuntimeCommand::SetResponseMode(mode) => {
+                    self.apply_response_mode(mode);
+                }
+                RuntimeCommand::ToggleFlashOnly => {
+                    let mode = self.settings.responses.mode.toggled();
+                    self.state.write().notice = Some((mode.label().to_string(), Instant::now()));
+                    self.apply_response_mode(mode);
+                }
+                RuntimeCommand::CycleMode => {
+                    let mode = self.mode.next();
+                    self.state.write().notice = Some((format!("Mode: {}", mode.label()), Instant::now()));
+                    self.apply_mode(mode);
+                }
+                RuntimeCommand::RequestAnalysis => {
+                    if let Some(ref pipeline) = self.pipeline {
+                        pipeline.request_analysis();
+                    }
+                }
+                RuntimeCommand::TogglePause => {
+                    if let Some(ref pipeline) = self.pipeline {
+                        if pipeline.state().is_paused {
+                            pipeline.resume();
+                        } else {
+                            pipeline.pause();
+                        }
+                    }
+                }
+                RuntimeCommand::SetBrief(brief) => {
+                    // An emptied brief is no longer about the lead
+                    let lead = self.state.read().lead.clone().filter(|_| !brief.trim().is_empty());
                     if let Some(ref pipeline) = self.pipeline {
-                        pipeline.set_context(&mode);
+                        pipeline.set_brief(&brief);
+                        pipeline.set_lead(lead.clone());
+                    }
+                    let mut state = self.state.write();
+                    state.brief = brief;
+                    state.lead = lead;
+                }
+                RuntimeCommand::SetLead(lead) => {
+                    if let Some(ref pipeline) = self.pipeline {
+                        pipeline.set_lead(lead.clone());
+                    }
+                    self.state.write().lead = lead;
+                }
+                RuntimeCommand::CorrectTranscript(text) => {
+                    if let Some(ref pipeline) = self.pipeline {
+                        pipeline.correct_transcript(text);
                     }
                 }
                 RuntimeCommand::SetAudioSource(_source) => {
@@ -84,35 +284,149 @@ impl RuntimeService {
         }
     }
 
+    /// Listen for the wake phrase while idle, if it's turned on
+    async fn listen_for_wake_word(&mut self) {
+        let wake_word = &self.settings.audio.wake_word;
+        if !wake_word.enabled || self.pipeline.is_some() || self.hotword.is_some() {
+            return;
+        }
+
+        let config = HotwordConfig {
+            phrase: wake_word.phrase.clone(),
+            sensitivity: wake_word.sensitivity,
+        };
+        let command_tx = self.command_tx.clone();
+        let on_heard = move || {
+            if let Some(command_tx) = command_tx.upgrade() {
+                let _ = command_tx.try_send(RuntimeCommand::WakeWord);
+            }
+        };
+        match HotwordListener::start(config, on_heard).await {
+            Ok(listener) => self.hotword = Some(listener),
+            Err(e) => tracing::warn!("Wake word unavailable: {}", e),
+        }
+    }
+
+    /// Switch mode; the pipeline picks it up from the next utterance
+    fn apply_mode(&mut self, mode: CopilotMode) {
+        if let Some(ref pipeline) = self.pipeline {
+            pipeline.set_context(mode.label());
+            pipeline.set_mode(mode.key());
+        }
+        self.state.write().mode = mode.clone();
+        self.mode = mode;
+    }
+
+    /// Switch response mode; the pipeline picks it up from the next utterance
+    fn apply_response_mode(&mut self, mode: ResponseMode) {
+        if let Some(ref pipeline) = self.pipeline {
+            pipeline.set_response_mode(mode);
+        }
+        self.settings.responses.mode = mode;
+        self.state.write().response_mode = mode;
+    }
+
     async fn start_pipeline(&mut self) -> Result<()> {
         // Load API keys from .env or settings
         let config = self.build_config();
-
-        let mut pipeline = CopilotPipeline::new(config);
+        let user_speaker = config.user_speaker.clone().filter(|_| config.diarize);
+
+        let recorder = Arc::new(self.recording_manager());
+        let privacy = &self.settings.privacy;
+        self.analytics.set_redaction(privacy.redact_pii.then(|| privacy.rules.clone()));
+        let mut pipeline = CopilotPipeline::new(config)
+            .with_recorder(recorder.clone())
+            .with_analytics(self.analytics.clone());
+        pipeline.set_context(self.mode.label());
+        pipeline.set_mode(self.mode.key());
+        pipeline.set_brief(&self.state.read().brief);
 
         // Subscribe to events
         let mut event_rx = pipeline.subscribe();
         let state = self.state.clone();
+        let voice = self.voice_config().map(VoiceOutput::new);
+        let transcript_buffer = pipeline.transcript_buffer();
 
         // Spawn event listener
         tokio::spawn(async move {
+            let mut speech = ResponseSpeech::default();
+
             while let Ok(event) = event_rx.recv().await {
+                if let Some(voice) = &voice {
+                    speech.handle(voice, &event).await;
+                }
+
                 let mut state = state.write();
                 match event {
                     PipelineEvent::Started => {
                         state.is_running = true;
                         state.status = "Listening".to_string();
+                        state.suggested_followup = None;
+                        state.intents.clear();
+                        state.objection = None;
+                        state.action_items.clear();
+                        state.transcription_only = false;
+                        state.transcript_index = TranscriptIndex::default();
                         state.error = None;
+                        state.last_error = None;
+                    }
+                    PipelineEvent::Paused => {
+                        state.is_paused = true;
+                        state.audio_level = None;
+                        state.status = "Paused".to_string();
+                    }
+                    PipelineEvent::Resumed => {
+                        state.is_paused = false;
+                        state.status = "Listening".to_string();
                     }
                     PipelineEvent::Stopped => {
                         state.is_running = false;
+                        state.is_paused = false;
+                        state.reconnecting = false;
+                        state.audio_level = None;
                         state.status = "Stopped".to_string();
                     }
                     PipelineEvent::Transcript(text) => {
+                        state.transcript_index = TranscriptIndex::from_buffer(&transcript_buffer, user_speaker.as_deref());
                         state.transcript = text;
+                        state.transcript_low_confidence = false;
+                    }
+                    PipelineEvent::LowConfidence(segment) => {
+                        tracing::debug!("Skipped low-confidence segment ({:.2}): {}", segment.confidence, segment.text);
+                        state.transcript_low_confidence = state.transcript == segment.text;
+                    }
+                    PipelineEvent::IntentsDetected(intents) => {
+                        state.intents = intents;
+                        state.objection = None;
+                    }
+                    PipelineEvent::ObjectionMatched(entry) => {
+                        state.objection = Some(entry);
+                    }
+                    PipelineEvent::ActionItem(item) => {
+                        state.action_items.push(item);
+                    }
+                    PipelineEvent::TranscriptionOnly => {
+                        state.transcription_only = true;
+                    }
+                    PipelineEvent::FlashBullet(index, bullet) => {
+                        // The first bullet of a turn replaces the last turn's suggestion
+                        let flash = if index == 0 {
+                            state.flash.insert(FlashAnalysis::default())
+                        } else {
+                            state.flash.get_or_insert_with(FlashAnalysis::default)
+                        };
+                        flash.bullets.push(bullet);
                     }
                     PipelineEvent::FlashReady(flash) => {
                         state.flash = Some(flash);
+                        // A transient error has cleared once a call succeeds
+                        if state.last_error.as_ref().map_or(false, |e| e.severity() == ErrorSeverity::Warning) {
+                            state.error = None;
+                            state.last_error = None;
+                        }
+                    }
+                    PipelineEvent::UrgentAlert(_) => {
+                        state.alert_at = Some(Instant::now());
                     }
                     PipelineEvent::DeepChunk(chunk) => {
                         state.deep_content.push_str(&chunk);
@@ -121,13 +435,47 @@ impl RuntimeService {
                     PipelineEvent::DeepComplete => {
                         state.deep_streaming = false;
                     }
+                    PipelineEvent::FollowupSuggested(at) => {
+                        state.suggested_followup = Some(at);
+                    }
+                    PipelineEvent::DeepCancelled => {
+                        state.deep_content.clear();
+                        state.deep_streaming = false;
+                    }
+                    PipelineEvent::RemoteSpeech => {}
+                    PipelineEvent::AudioLevel(level) => {
+                        state.audio_level = Some(level);
+                    }
                     PipelineEvent::QuestionReady(q) => {
                         state.question = Some(q);
                     }
+                    PipelineEvent::SttReconnecting(attempt) => {
+                        state.reconnecting = true;
+                        state.status = format!("Reconnecting ({})...", attempt);
+                    }
+                    PipelineEvent::SttReconnected => {
+                        state.reconnecting = false;
+                        state.status = "Listening".to_string();
+                    }
+                    PipelineEvent::Timing(timing) => {
+                        state.latency = Some(timing.label());
+                    }
+                    PipelineEvent::Warning(w) => {
+                        tracing::warn!("{}", w);
+                    }
                     PipelineEvent::Error(e) => {
+                        state.reconnecting = false;
                         state.error = Some(e);
                         state.status = "Error".to_string();
                     }
+                    PipelineEvent::TypedError(e) => {
+                        state.error = Some(e.message());
+                        state.status = match e.severity() {
+                            ErrorSeverity::Warning => "Warning".to_string(),
+                            ErrorSeverity::Error => "Error".to_string(),
+                        };
+                        state.last_error = Some(e);
+                    }
                 }
             }
         });
@@ -136,25 +484,81 @@ impl RuntimeService {
         pipeline.start().await?;
         self.pipeline = Some(pipeline);
 
+        if recorder.is_auto_record() {
+            recorder.start_recording(self.mode.key());
+        }
+        self.recorder = Some(recorder);
+        self.analytics.start_session(self.mode.key());
+
         Ok(())
     }
 
+    /// Recorder set up from the privacy settings
+    fn recording_manager(&self) -> RecordingManager {
+        let privacy = &self.settings.privacy;
+        let mut recorder = RecordingManager::new();
+        recorder.set_auto_record(privacy.auto_record);
+        recorder.set_record_audio(privacy.record_audio);
+        recorder.set_redact_pii(privacy.redact_pii);
+        recorder.set_redaction_rules(privacy.rules.clone());
+        recorder.set_keep_unredacted(privacy.keep_unredacted);
+        recorder
+    }
+
     fn stop_pipeline(&mut self) {
-        if let Some(ref mut pipeline) = self.pipeline {
+        if let Some(mut pipeline) = self.pipeline.take() {
             pipeline.stop();
+            self.analytics.end_session();
+        }
+
+        if let Some(session) = self.recorder.take().and_then(|recorder| recorder.stop_recording()) {
+            tokio::spawn(async move {
+                if let Err(e) = save_recording(&session).await {
+                    tracing::warn!("Failed to save recording: {}", e);
+                }
+            });
         }
-        self.pipeline = None;
 
         let mut state = self.state.write();
         state.is_running = false;
+        state.is_paused = false;
         state.transcript.clear();
+        state.intents.clear();
+        state.objection = None;
         state.flash = None;
         state.deep_content.clear();
         state.deep_streaming = false;
+        state.transcription_only = false;
         state.question = None;
+        state.latency = None;
+        state.audio_level = None;
         state.status = "Stopped".to_string();
     }
 
+    /// TTS setup for reading responses aloud, if enabled
+    fn voice_config(&self) -> Option<TTSConfig> {
+        if !self.settings.ui.speak_responses {
+            return None;
+        }
+
+        let openai_key = std::env::var("OPENAI_API_KEY").ok()
+            .or_else(|| self.settings.api_keys.openai.clone());
+
+        let provider = if openai_key.is_some() {
+            TTSProvider::OpenAI
+        } else if cfg!(target_os = "windows") {
+            TTSProvider::WindowsSAPI
+        } else {
+            return None;
+        };
+
+        Some(TTSConfig {
+            provider,
+            api_key: openai_key,
+            ..TTSConfig::default()
+        })
+    }
+
     fn build_config(&self) -> PipelineConfig {
         // Try to load from .env first
         let deepgram_key = std::env::var("DEEPGRAM_API_KEY").ok()
@@ -169,15 +573,28 @@ impl RuntimeService {
         let google_key = std::env::var("GOOGLE_AI_API_KEY").ok()
             .or_else(|| self.settings.api_keys.google.clone());
 
-        // Determine which models to use based on available keys
+        let groq_key = std::env::var("GROQ_API_KEY").ok()
+            .or_else(|| self.settings.api_keys.groq.clone());
+
+        let openrouter_key = std::env::var("OPENROUTER_API_KEY").ok()
+            .or_else(|| self.settings.api_keys.openrouter.clone());
+
+        // Determine which models to use based on available keys;
+        // OpenRouter covers whichever stage has no direct key
         let flash_model = if google_key.is_some() {
             FlashModelChoice::GeminiFlash
+        } else if groq_key.is_some() {
+            FlashModelChoice::Groq(self.settings.models.groq_model.clone())
+        } else if openai_key.is_none() && openrouter_key.is_some() {
+            FlashModelChoice::OpenRouter(DEFAULT_OPENROUTER_FLASH_MODEL.to_string())
         } else {
             FlashModelChoice::GPT4oMini
         };
 
         let deep_model = if anthropic_key.is_some() {
             ModelChoice::ClaudeSonnet
+        } else if openai_key.is_none() && openrouter_key.is_some() {
+            ModelChoice::OpenRouter(DEFAULT_OPENROUTER_DEEP_MODEL.to_string())
         } else {
             ModelChoice::GPT4o
         };
@@ -187,12 +604,112 @@ impl RuntimeService {
             openai_key,
             anthropic_key,
             google_key,
+            groq_key,
+            openrouter_key,
             flash_model,
             deep_model,
+            trigger_mode: if self.settings.audio.push_to_talk {
+                TriggerMode::Manual
+            } else {
+                TriggerMode::Continuous
+            },
+            per_mode_flash: self.settings.models.per_mode_flash
+                .iter()
+                .map(|(mode, model)| (mode.clone(), flash_choice(model, &self.settings.models)))
+                .collect(),
+            per_mode_deep: self.settings.models.per_mode_deep
+                .iter()
+                .map(|(mode, model)| (mode.clone(), deep_choice(model)))
+                .collect(),
+            azure: self.settings.models.azure_config(),
+            min_confidence: self.settings.audio.min_confidence.clamp(0.0, 1.0),
+            // Only split speakers once the user has said which one they are
+            diarize: self.settings.audio.user_speaker.is_some(),
+            user_speaker: self.settings.audio.user_speaker.clone(),
+            glossary: Glossary::load().unwrap_or_default(),
+            battlecards: Battlecards::load().unwrap_or_default(),
+            objections: ObjectionLibrary::load().unwrap_or_default(),
+            // Conversation memory needs the Outreach API and an account token
+            memory: OutreachAccount::from_env(&self.settings.api_keys),
+            webhooks: WebhookConfig::load().unwrap_or_default(),
+            lead: self.state.read().lead.clone(),
+            followup: self.settings.followup.clone(),
+            alerts: self.settings.alerts.clone(),
+            responses: self.settings.responses.clone(),
+            language: self.settings.ui.language,
+            ..PipelineConfig::default()
+        }
+    }
+}
+
+/// Streams deep output into the voice, one response at a time
+#[derive(Default)]
+struct ResponseSpeech {
+    /// Feeds the response currently being spoken
+    tx: Option<mpsc::Sender<String>>,
+    /// The other side talked over this response; stay quiet until the next
+    interrupted: bool,
+}
+
+impl ResponseSpeech {
+    async fn handle(&mut self, voice: &VoiceOutput, event: &PipelineEvent) {
+        match event {
+            PipelineEvent::DeepChunk(chunk) => {
+                if self.interrupted {
+                    return;
+                }
+                let tx = self.tx.get_or_insert_with(|| {
+                    let (tx, rx) = mpsc::channel(256);
+                    let voice = voice.clone();
+                    tokio::spawn(async move {
+                        if let Err(e) = voice.speak_stream(rx).await {
+                            tracing::warn!("Voice output error: {}", e);
+                        }
+                    });
+                    tx
+                });
+                let _ = tx.send(chunk.clone()).await;
+            }
+            PipelineEvent::RemoteSpeech => {
+                // Mute the rest of the response only if it's still streaming
+                if voice.barge_in() && self.tx.take().is_some() {
+                    self.interrupted = true;
+                }
+            }
+            PipelineEvent::DeepComplete => {
+                // Closing the channel speaks whatever is left
+                self.tx = None;
+                self.interrupted = false;
+            }
+            PipelineEvent::DeepCancelled | PipelineEvent::Stopped => {
+                self.tx = None;
+                self.interrupted = false;
+                voice.stop();
+            }
+            _ => {}
         }
     }
 }
 
+/// Map a saved flash model to the pipeline's choice
+fn flash_choice(model: &FlashModel, models: &ModelSettings) -> FlashModelChoice {
+    match model {
+        FlashModel::GeminiFlash => FlashModelChoice::GeminiFlash,
+        FlashModel::GPT4oMini => FlashModelChoice::GPT4oMini,
+        FlashModel::LocalOllama => FlashModelChoice::LocalOllama("llama3.1:8b".to_string()),
+        FlashModel::Groq => FlashModelChoice::Groq(models.groq_model.clone()),
+    }
+}
+
+/// Map a saved deep model to the router's choice
+fn deep_choice(model: &DeepModel) -> ModelChoice {
+    match model {
+        DeepModel::ClaudeSonnet => ModelChoice::ClaudeSonnet,
+        DeepModel::GPT4o => ModelChoice::GPT4o,
+        DeepModel::O1Preview => ModelChoice::O1Preview,
+    }
+}
+
 /// Handle to control the runtime from UI
 #[derive(Clone)]
 pub struct RuntimeHandle {
@@ -204,9 +721,15 @@ impl RuntimeHandle {
     /// Create a new runtime handle and service
     pub fn new(settings: Settings) -> (Self, RuntimeService) {
         let (command_tx, command_rx) = mpsc::channel(32);
-        let state = Arc::new(RwLock::new(SharedState::default()));
-
-        let service = RuntimeService::new(settings, state.clone(), command_rx);
+        let state = Arc::new(RwLock::new(SharedState {
+            overlay: OverlayWindow {
+                opacity: settings.ui.opacity.clamp(MIN_OVERLAY_OPACITY, 1.0),
+                click_through: false,
+            },
+            ..SharedState::default()
+        }));
+
+        let service = RuntimeService::new(settings, state.clone(), command_rx, command_tx.downgrade());
         let handle = RuntimeHandle { command_tx, state };
 
         (handle, service)
@@ -223,8 +746,53 @@ impl RuntimeHandle {
     }
 
     /// Set the mode
-    pub fn set_mode(&self, mode: &str) {
-        let _ = self.command_tx.try_send(RuntimeCommand::SetMode(mode.to_string()));
+    pub fn set_mode(&self, mode: &CopilotMode) {
+        let _ = self.command_tx.try_send(RuntimeCommand::SetMode(mode.clone()));
+    }
+
+    /// Cycle to the next mode
+    pub fn cycle_mode(&self) {
+        let _ = self.command_tx.try_send(RuntimeCommand::CycleMode);
+    }
+
+    /// Analyze the recent transcript now (push to talk)
+    pub fn request_analysis(&self) {
+        let _ = self.command_tx.try_send(RuntimeCommand::RequestAnalysis);
+    }
+
+    /// Set the call brief
+    pub fn set_brief(&self, brief: String) {
+        let _ = self.command_tx.try_send(RuntimeCommand::SetBrief(brief));
+    }
+
+    /// Set the lead the call is with
+    pub fn set_lead(&self, lead: Option<String>) {
+        let _ = self.command_tx.try_send(RuntimeCommand::SetLead(lead));
+    }
+
+    /// Pause or resume transcription and analysis
+    pub fn toggle_pause(&self) {
+        let _ = self.command_tx.try_send(RuntimeCommand::TogglePause);
+    }
+
+    /// Fix the latest transcript line and regenerate the suggestion
+    pub fn correct_transcript(&self, text: String) {
+        let _ = self.command_tx.try_send(RuntimeCommand::CorrectTranscript(text));
+    }
+
+    /// Run Flash only, Deep only or both
+    pub fn set_response_mode(&self, mode: ResponseMode) {
+        let _ = self.command_tx.try_send(RuntimeCommand::SetResponseMode(mode));
+    }
+
+    /// Turn bullets-only on or off
+    pub fn toggle_flash_only(&self) {
+        let _ = self.command_tx.try_send(RuntimeCommand::ToggleFlashOnly);
+    }
+
+    /// Apply new wake word settings
+    pub fn set_wake_word(&self, wake_word: WakeWordSettings) {
+        let _ = self.command_tx.try_send(RuntimeCommand::SetWakeWord(wake_word));
     }
 
     /// Set audio source
@@ -232,6 +800,26 @@ impl RuntimeHandle {
         let _ = self.command_tx.try_send(RuntimeCommand::SetAudioSource(source));
     }
 
+    /// Set overlay opacity (0.2 to 1.0)
+    pub fn set_overlay_opacity(&self, opacity: f32) {
+        self.state.write().overlay.opacity = opacity.clamp(MIN_OVERLAY_OPACITY, 1.0);
+    }
+
+    /// Let clicks pass through the overlay
+    pub fn set_click_through(&self, enabled: bool) {
+        self.state.write().overlay.click_through = enabled;
+    }
+
+    /// Record which app, if any, is sharing the screen
+    pub fn set_screen_share(&self, app: Option<String>) {
+        self.state.write().screen_share = app;
+    }
+
+    /// Show a brief confirmation in the status bar
+    pub fn notify(&self, message: impl Into<String>) {
+        self.state.write().notice = Some((message.into(), Instant::now()));
+    }
+
     /// Get current state
     pub fn state(&self) -> SharedState {
         self.state.read().clone()
@@ -242,3 +830,37 @@ impl RuntimeHandle {
         self.state.clone()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cycle_mode_wraps_around() {
+        let mut mode = CopilotMode::default();
+        let mut seen = vec![mode.clone()];
+        for _ in 1..CopilotMode::all().len() {
+            mode = mode.next();
+            seen.push(mode.clone());
+        }
+        assert_eq!(seen, CopilotMode::all());
+        assert_eq!(mode.next(), CopilotMode::default());
+    }
+
+    #[test]
+    fn test_overlay_opacity_is_clamped() {
+        let (handle, _service) = RuntimeHandle::new(Settings::default());
+        handle.set_overlay_opacity(0.0);
+        assert_eq!(handle.state().overlay.opacity, MIN_OVERLAY_OPACITY);
+        handle.set_overlay_opacity(1.5);
+        assert_eq!(handle.state().overlay.opacity, 1.0);
+    }
+
+    #[test]
+    fn test_config_carries_lead() {
+        let (_handle, service) = RuntimeHandle::new(Settings::default());
+        assert_eq!(service.build_config().lead, None);
+        service.state.write().lead = Some("Acme".to_string());
+        assert_eq!(service.build_config().lead.as_deref(), Some("Acme"));
+    }
+}
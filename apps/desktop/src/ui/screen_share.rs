@@ -0,0 +1,128 @@
+//! Screen Share Detection
+//!
+//! Watches for call apps entering share mode so the overlay can hide
+//! before it shows up on everyone else's screen. On Windows this looks
+//! for the share toolbars and banners each app opens while sharing.
+
+use std::time::Duration;
+
+/// How often open windows are checked
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Window titles call apps show only while sharing, as (app, lowercase fragment)
+const SHARE_INDICATORS: &[(&str, &str)] = &[
+    ("Zoom", "zoom share"),
+    ("Zoom", "you are screen sharing"),
+    ("Microsoft Teams", "sharing control bar"),
+    ("Microsoft Teams", "screen sharing toolbar"),
+    ("Google Meet", "is sharing your screen"),
+    ("Google Meet", "is sharing a window"),
+    ("Discord", "screen share"),
+    ("Slack", "slack | sharing"),
+    ("WebEx", "webex share"),
+    ("Skype", "skype - screen sharing"),
+];
+
+/// Change in screen-share state
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScreenShareEvent {
+    /// An app started sharing (app name)
+    Started(String),
+    Stopped,
+}
+
+/// Call app that is sharing, judged from open window titles
+pub fn sharing_app_in(titles: &[String]) -> Option<String> {
+    titles.iter().find_map(|title| {
+        let title = title.to_lowercase();
+        SHARE_INDICATORS
+            .iter()
+            .find(|(_, fragment)| title.contains(fragment))
+            .map(|(app, _)| app.to_string())
+    })
+}
+
+/// Call app that is currently sharing the screen, if any
+pub fn detect_screen_share() -> Option<String> {
+    sharing_app_in(&window_titles())
+}
+
+/// Titles of visible top-level windows
+#[cfg(target_os = "windows")]
+fn window_titles() -> Vec<String> {
+    use windows::Win32::Foundation::{BOOL, HWND, LPARAM};
+    use windows::Win32::UI::WindowsAndMessaging::{EnumWindows, GetWindowTextW, IsWindowVisible};
+
+    unsafe extern "system" fn collect(hwnd: HWND, lparam: LPARAM) -> BOOL {
+        let titles = &mut *(lparam.0 as *mut Vec<String>);
+        if IsWindowVisible(hwnd).as_bool() {
+            let mut buf = [0u16; 256];
+            let len = GetWindowTextW(hwnd, &mut buf);
+            if len > 0 {
+                titles.push(String::from_utf16_lossy(&buf[..len as usize]));
+            }
+        }
+        BOOL(1)
+    }
+
+    let mut titles: Vec<String> = Vec::new();
+    unsafe {
+        if let Err(e) = EnumWindows(Some(collect), LPARAM(&mut titles as *mut _ as isize)) {
+            tracing::debug!("Failed to enumerate windows: {}", e);
+        }
+    }
+    titles
+}
+
+#[cfg(not(target_os = "windows"))]
+fn window_titles() -> Vec<String> {
+    // Share detection is only supported on Windows
+    Vec::new()
+}
+
+/// Poll for screen sharing and send an event whenever it starts or stops
+pub fn spawn_screen_share_monitor(
+    event_tx: tokio::sync::mpsc::Sender<ScreenShareEvent>,
+) -> std::thread::JoinHandle<()> {
+    std::thread::spawn(move || {
+        let mut sharing: Option<String> = None;
+
+        loop {
+            let current = detect_screen_share();
+            if current != sharing {
+                let event = match &current {
+                    Some(app) => {
+                        tracing::info!("Screen share detected: {}", app);
+                        ScreenShareEvent::Started(app.clone())
+                    }
+                    None => {
+                        tracing::info!("Screen share ended");
+                        ScreenShareEvent::Stopped
+                    }
+                };
+                if event_tx.blocking_send(event).is_err() {
+                    return;
+                }
+                sharing = current;
+            }
+            std::thread::sleep(POLL_INTERVAL);
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sharing_app_from_titles() {
+        let titles = vec![
+            "Zoom Meeting".to_string(),
+            "meet.google.com is sharing your screen.".to_string(),
+        ];
+        assert_eq!(sharing_app_in(&titles), Some("Google Meet".to_string()));
+
+        let idle = vec!["Zoom Meeting".to_string(), "Voice Copilot".to_string()];
+        assert_eq!(sharing_app_in(&idle), None);
+    }
+}
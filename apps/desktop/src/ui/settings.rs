@@ -3,8 +3,17 @@
 //! Configuration UI for API keys, audio devices, and preferences.
 
 use dioxus::prelude::*;
-use crate::config::{Settings, ApiKeys};
+use std::collections::HashMap;
+use crate::config::{token_device, Settings, AlertLevel, AlertSettings, ApiKeys, Battlecards, CopyTarget, FlashModel, DeepModel, Glossary, ObjectionLibrary, ResponseMode, ResponseSettings, WakeWordSettings};
+use crate::flash::StatementType;
+use crate::diagnostics::{run_self_test, CheckStatus, SelfTestReport};
+use crate::i18n::Language;
+use crate::prompts::{CustomPrompts, PromptCategory, PromptEditor};
 use crate::updater::CURRENT_VERSION;
+use super::app::CopilotMode;
+use super::hotkeys::{combo_from_keys, reload_hotkeys, HotkeyAction, HotkeyConfig};
+use super::components::ThemeEditor;
+use super::theme::Theme;
 
 /// Settings panel state
 #[derive(Debug, Clone, Default)]
@@ -13,10 +22,65 @@ pub struct SettingsState {
     pub anthropic_key: String,
     pub google_key: String,
     pub deepgram_key: String,
+    pub groq_key: String,
+    pub openrouter_key: String,
+    /// Outreach account token for conversation memory
+    pub outreach_key: String,
+    /// Device id returned with the token at sign-in
+    pub outreach_device: String,
     pub flash_model: String,
     pub deep_model: String,
     pub ollama_model: String,
+    /// Send OpenAI requests to Azure OpenAI
+    pub use_azure: bool,
+    pub azure_endpoint: String,
+    pub azure_deployment: String,
+    /// Flash override per mode key (missing = use default)
+    pub per_mode_flash: HashMap<String, String>,
+    /// Deep override per mode key (missing = use default)
+    pub per_mode_deep: HashMap<String, String>,
+    /// Editable prompt set
+    pub prompts: CustomPrompts,
+    /// Mode key of the prompt being edited
+    pub prompt_mode: String,
+    /// Stage of the prompt being edited ("flash" or "deep")
+    pub prompt_stage: String,
     pub ollama_status: OllamaStatusUI,
+    /// Last readiness check, if one has been run
+    pub self_test: Option<SelfTestReport>,
+    pub self_test_running: bool,
+    /// Global shortcut bindings
+    pub hotkeys: HotkeyConfig,
+    /// Action waiting for a key press to rebind
+    pub capturing_hotkey: Option<HotkeyAction>,
+    /// What the copy shortcut copies
+    pub copy_target: String,
+    /// Hide the window when a call app shares the screen
+    pub auto_hide_on_share: bool,
+    /// Save each call's transcript
+    pub auto_record: bool,
+    /// Save each call's audio too
+    pub record_audio: bool,
+    /// Selectable themes as (key, label), presets first
+    pub themes: Vec<(String, String)>,
+    /// Custom theme editor is open
+    pub editing_theme: bool,
+    /// Skip analysis below this transcription confidence
+    pub min_confidence: f32,
+    /// Diarized speaker ID that is the user ("" = no diarization)
+    pub user_speaker: String,
+    /// Glossary as "Term: definition" lines
+    pub glossary: String,
+    /// Battlecards in the "[Competitor]" editor format
+    pub battlecards: String,
+    /// Objection library in the "[Objection]" editor format
+    pub objections: String,
+    /// Border flash and chime per Flash urgency
+    pub alerts: AlertSettings,
+    /// Bullet count and length caps
+    pub responses: ResponseSettings,
+    /// Hands-free start phrase
+    pub wake_word: WakeWordSettings,
     pub is_saving: bool,
     pub save_message: Option<String>,
 }
@@ -36,10 +100,47 @@ impl SettingsState {
             anthropic_key: settings.api_keys.anthropic.clone().unwrap_or_default(),
             google_key: settings.api_keys.google.clone().unwrap_or_default(),
             deepgram_key: settings.api_keys.deepgram.clone().unwrap_or_default(),
+            groq_key: settings.api_keys.groq.clone().unwrap_or_default(),
+            openrouter_key: settings.api_keys.openrouter.clone().unwrap_or_default(),
+            outreach_key: settings.api_keys.outreach.clone().unwrap_or_default(),
+            outreach_device: settings.api_keys.outreach_device.clone().unwrap_or_default(),
             flash_model: format!("{:?}", settings.models.flash_model),
             deep_model: format!("{:?}", settings.models.deep_model),
             ollama_model: "llama3.1:8b".to_string(),
+            use_azure: settings.models.use_azure,
+            azure_endpoint: settings.models.azure_endpoint.clone(),
+            azure_deployment: settings.models.azure_deployment.clone(),
+            per_mode_flash: settings.models.per_mode_flash
+                .iter()
+                .map(|(mode, model)| (mode.clone(), format!("{:?}", model)))
+                .collect(),
+            per_mode_deep: settings.models.per_mode_deep
+                .iter()
+                .map(|(mode, model)| (mode.clone(), format!("{:?}", model)))
+                .collect(),
+            prompts: CustomPrompts::load_for_lang(settings.ui.language)
+                .unwrap_or_else(|_| CustomPrompts::default_for_lang(settings.ui.language)),
+            prompt_mode: "sales".to_string(),
+            prompt_stage: "flash".to_string(),
             ollama_status: OllamaStatusUI::default(),
+            self_test: None,
+            self_test_running: false,
+            hotkeys: HotkeyConfig::from_settings(&settings.hotkeys),
+            capturing_hotkey: None,
+            copy_target: format!("{:?}", settings.hotkeys.copy_target),
+            auto_hide_on_share: settings.privacy.auto_hide_on_share,
+            auto_record: settings.privacy.auto_record,
+            record_audio: settings.privacy.record_audio,
+            themes: theme_options(),
+            editing_theme: false,
+            min_confidence: settings.audio.min_confidence,
+            user_speaker: settings.audio.user_speaker.clone().unwrap_or_default(),
+            glossary: Glossary::load().unwrap_or_default().to_lines(),
+            battlecards: Battlecards::load().unwrap_or_default().to_text(),
+            objections: ObjectionLibrary::load().unwrap_or_default().to_text(),
+            alerts: settings.alerts.clone(),
+            responses: settings.responses.clone(),
+            wake_word: settings.audio.wake_word.clone(),
             is_saving: false,
             save_message: None,
         }
@@ -51,14 +152,197 @@ impl SettingsState {
             anthropic: if self.anthropic_key.is_empty() { None } else { Some(self.anthropic_key.clone()) },
             google: if self.google_key.is_empty() { None } else { Some(self.google_key.clone()) },
             deepgram: if self.deepgram_key.is_empty() { None } else { Some(self.deepgram_key.clone()) },
+            groq: if self.groq_key.is_empty() { None } else { Some(self.groq_key.clone()) },
+            openrouter: if self.openrouter_key.is_empty() { None } else { Some(self.openrouter_key.clone()) },
+            outreach: if self.outreach_key.is_empty() { None } else { Some(self.outreach_key.clone()) },
+            // Tokens name their device, so a pasted token needs no id typed in
+            outreach_device: if self.outreach_device.is_empty() {
+                token_device(&self.outreach_key)
+            } else {
+                Some(self.outreach_device.clone())
+            },
         }
     }
+
+    /// Apply per-mode model overrides to settings
+    pub fn apply_mode_overrides(&self, settings: &mut Settings) {
+        for mode in CopilotMode::all() {
+            let flash = self.per_mode_flash.get(mode.key()).and_then(|m| parse_flash_model(m));
+            let deep = self.per_mode_deep.get(mode.key()).and_then(|m| parse_deep_model(m));
+            settings.models.set_mode_flash(mode.key(), flash);
+            settings.models.set_mode_deep(mode.key(), deep);
+        }
+    }
+
+    /// Text of the prompt being edited
+    pub fn prompt_text(&self) -> String {
+        match self.prompt_stage.as_str() {
+            "deep" => self.prompts.get_deep(&self.prompt_mode).to_string(),
+            _ => self.prompts.get_flash(&self.prompt_mode).to_string(),
+        }
+    }
+
+    /// Replace the prompt being edited
+    pub fn set_prompt_text(&mut self, text: &str) {
+        let mode = self.prompt_mode.clone();
+        match self.prompt_stage.as_str() {
+            "deep" => self.prompts.set_deep(&mode, text),
+            _ => self.prompts.set_flash(&mode, text),
+        }
+    }
+
+    /// Validation errors for the prompt being edited
+    pub fn prompt_errors(&self) -> Vec<String> {
+        let category = PromptCategory::from_mode(&self.prompt_mode);
+        match PromptEditor::validate_for(&self.prompt_text(), &category) {
+            Ok(()) => Vec::new(),
+            Err(errors) => errors.iter().map(|e| e.to_string()).collect(),
+        }
+    }
+
+    /// Prompts that fail validation, as "mode stage" labels
+    pub fn invalid_prompts(&self) -> Vec<String> {
+        let stages = [("flash", &self.prompts.flash), ("deep", &self.prompts.deep)];
+        let mut invalid: Vec<String> = stages
+            .iter()
+            .flat_map(|(stage, prompts)| {
+                prompts.iter().filter_map(move |(mode, template)| {
+                    let category = PromptCategory::from_mode(mode);
+                    PromptEditor::validate_for(template, &category)
+                        .err()
+                        .map(|_| format!("{} {}", mode, stage))
+                })
+            })
+            .collect();
+        invalid.sort();
+        invalid
+    }
+}
+
+fn parse_flash_model(name: &str) -> Option<FlashModel> {
+    match name {
+        "GeminiFlash" => Some(FlashModel::GeminiFlash),
+        "GPT4oMini" => Some(FlashModel::GPT4oMini),
+        "LocalOllama" => Some(FlashModel::LocalOllama),
+        "Groq" => Some(FlashModel::Groq),
+        _ => None,
+    }
+}
+
+fn parse_deep_model(name: &str) -> Option<DeepModel> {
+    match name {
+        "ClaudeSonnet" => Some(DeepModel::ClaudeSonnet),
+        "GPT4o" => Some(DeepModel::GPT4o),
+        "O1Preview" => Some(DeepModel::O1Preview),
+        _ => None,
+    }
+}
+
+fn parse_alert_level(name: &str) -> AlertLevel {
+    match name {
+        "Visual" => AlertLevel::Visual,
+        "Audio" => AlertLevel::Audio,
+        _ => AlertLevel::Silent,
+    }
+}
+
+/// Turn types offered in the "Suggest On" section, in display order
+fn trigger_types() -> [StatementType; 6] {
+    [
+        StatementType::Question,
+        StatementType::Objection,
+        StatementType::BuyingSignal,
+        StatementType::Technical,
+        StatementType::Statement,
+        StatementType::SmallTalk,
+    ]
+}
+
+fn set_trigger(responses: &mut ResponseSettings, statement: StatementType, on: bool) {
+    responses.trigger_on.retain(|s| *s != statement);
+    if on {
+        responses.trigger_on.push(statement);
+    }
+}
+
+fn parse_response_mode(name: &str) -> ResponseMode {
+    match name {
+        "FlashOnly" => ResponseMode::FlashOnly,
+        "DeepOnly" => ResponseMode::DeepOnly,
+        _ => ResponseMode::FlashAndDeep,
+    }
+}
+
+fn parse_copy_target(name: &str) -> CopyTarget {
+    match name {
+        "Bullets" => CopyTarget::Bullets,
+        "Question" => CopyTarget::Question,
+        _ => CopyTarget::Deep,
+    }
+}
+
+/// Presets, then saved custom themes marked as such
+/// Ask where to save the objection library, then write it (None if cancelled)
+fn export_objections(library: &ObjectionLibrary) -> anyhow::Result<Option<std::path::PathBuf>> {
+    let Some(path) = native_dialog::FileDialog::new()
+        .set_filename("objections.json")
+        .add_filter("JSON", &["json"])
+        .show_save_single_file()?
+    else {
+        return Ok(None);
+    };
+    library.export_to_file(&path)?;
+    Ok(Some(path))
+}
+
+/// Pick a shared objection file and read it (None if cancelled)
+fn import_objections() -> anyhow::Result<Option<ObjectionLibrary>> {
+    let Some(path) = native_dialog::FileDialog::new()
+        .add_filter("JSON", &["json"])
+        .show_open_single_file()?
+    else {
+        return Ok(None);
+    };
+    ObjectionLibrary::import_from_file(path).map(Some)
+}
+
+fn theme_options() -> Vec<(String, String)> {
+    Theme::load_all()
+        .into_iter()
+        .map(|(key, theme)| {
+            let label = if Theme::preset(&key).is_some() { theme.name } else { format!("{} (custom)", theme.name) };
+            (key, label)
+        })
+        .collect()
+}
+
+/// Store a per-mode selection ("" clears the override)
+fn set_override(overrides: &mut HashMap<String, String>, mode: &str, value: String) {
+    if value.is_empty() {
+        overrides.remove(mode);
+    } else {
+        overrides.insert(mode.to_string(), value);
+    }
 }
 
 /// Settings panel component
 #[component]
 pub fn SettingsPanel(
     is_open: bool,
+    /// Shortcuts the OS refused to register
+    hotkey_errors: Vec<String>,
+    /// Active theme key
+    theme_name: String,
+    /// A theme was picked; applied and saved right away
+    on_theme: EventHandler<String>,
+    /// Active language
+    language: Language,
+    /// A language was picked; applied and saved right away
+    on_language: EventHandler<Language>,
+    /// Wake word settings were saved; the listener picks them up
+    on_wake_word: EventHandler<WakeWordSettings>,
+    /// Response mode was saved; applies from the next utterance
+    on_response_mode: EventHandler<ResponseMode>,
     on_close: EventHandler<()>,
 ) -> Element {
     let mut state = use_signal(|| {
@@ -89,6 +373,16 @@ pub fn SettingsPanel(
                 s.google_key = key;
             }
         }
+        if s.groq_key.is_empty() {
+            if let Ok(key) = std::env::var("GROQ_API_KEY") {
+                s.groq_key = key;
+            }
+        }
+        if s.openrouter_key.is_empty() {
+            if let Ok(key) = std::env::var("OPENROUTER_API_KEY") {
+                s.openrouter_key = key;
+            }
+        }
     });
 
     let save_settings = move |_| {
@@ -96,11 +390,55 @@ pub fn SettingsPanel(
         s.is_saving = true;
         s.save_message = None;
 
-        // Save API keys
+        let invalid = s.invalid_prompts();
+        if !invalid.is_empty() {
+            s.save_message = Some(format!("Error: fix prompt errors in {}", invalid.join(", ")));
+            s.is_saving = false;
+            return;
+        }
+        if let Some((action, problem)) = s.hotkeys.problems().into_iter().next() {
+            s.save_message = Some(format!("Error: {} shortcut: {}", action.label(), problem));
+            s.is_saving = false;
+            return;
+        }
+
+        // Save API keys (to the keychain) and model overrides
         let api_keys = s.to_api_keys();
-        match api_keys.save_secure() {
+        let mut settings = Settings::load().unwrap_or_default();
+        settings.api_keys = api_keys.clone();
+        settings.models.use_azure = s.use_azure;
+        settings.models.azure_endpoint = s.azure_endpoint.trim().to_string();
+        settings.models.azure_deployment = s.azure_deployment.trim().to_string();
+        s.hotkeys.apply_to(&mut settings.hotkeys);
+        settings.hotkeys.copy_target = parse_copy_target(&s.copy_target);
+        settings.privacy.auto_hide_on_share = s.auto_hide_on_share;
+        settings.privacy.auto_record = s.auto_record;
+        settings.privacy.record_audio = s.auto_record && s.record_audio;
+        settings.audio.min_confidence = s.min_confidence;
+        settings.audio.user_speaker = Some(s.user_speaker.clone()).filter(|speaker| !speaker.is_empty());
+        settings.audio.wake_word = WakeWordSettings {
+            phrase: s.wake_word.phrase.trim().to_string(),
+            ..s.wake_word.clone()
+        };
+        settings.alerts = s.alerts.clone();
+        settings.responses = s.responses.clone();
+        s.apply_mode_overrides(&mut settings);
+
+        let glossary = Glossary::from_lines(&s.glossary);
+        let battlecards = Battlecards::from_text(&s.battlecards);
+        let objections = ObjectionLibrary::from_text(&s.objections);
+        match settings
+            .save()
+            .and_then(|_| s.prompts.save())
+            .and_then(|_| glossary.save())
+            .and_then(|_| battlecards.save())
+            .and_then(|_| objections.save())
+        {
             Ok(_) => {
                 s.save_message = Some("Settings saved!".to_string());
+                reload_hotkeys(s.hotkeys.clone());
+                on_wake_word.call(settings.audio.wake_word.clone());
+                on_response_mode.call(settings.responses.mode);
 
                 // Also update environment variables for current session
                 if let Some(ref key) = api_keys.openai {
@@ -115,6 +453,18 @@ pub fn SettingsPanel(
                 if let Some(ref key) = api_keys.google {
                     std::env::set_var("GOOGLE_AI_API_KEY", key);
                 }
+                if let Some(ref key) = api_keys.groq {
+                    std::env::set_var("GROQ_API_KEY", key);
+                }
+                if let Some(ref key) = api_keys.openrouter {
+                    std::env::set_var("OPENROUTER_API_KEY", key);
+                }
+                if let Some(ref key) = api_keys.outreach {
+                    std::env::set_var("OUTREACH_API_TOKEN", key);
+                }
+                if let Some(ref device) = api_keys.outreach_device {
+                    std::env::set_var("OUTREACH_DEVICE_ID", device);
+                }
             }
             Err(e) => {
                 s.save_message = Some(format!("Error: {}", e));
@@ -123,7 +473,28 @@ pub fn SettingsPanel(
         s.is_saving = false;
     };
 
+    // Test with the keys currently in the form, saved or not
+    let start_self_test = move |_| {
+        let mut settings = Settings::load().unwrap_or_default();
+        {
+            let mut s = state.write();
+            if s.self_test_running {
+                return;
+            }
+            s.self_test_running = true;
+            settings.api_keys = s.to_api_keys();
+        }
+
+        spawn(async move {
+            let report = run_self_test(&settings).await;
+            let mut s = state.write();
+            s.self_test = Some(report);
+            s.self_test_running = false;
+        });
+    };
+
     let current = state.read();
+    let hotkey_problems = current.hotkeys.problems();
 
     if !is_open {
         return rsx! {};
@@ -180,6 +551,38 @@ pub fn SettingsPanel(
                         }
                     }
 
+                    div { class: "setting-item",
+                        label { "Azure OpenAI" }
+                        input {
+                            r#type: "checkbox",
+                            checked: current.use_azure,
+                            oninput: move |e| state.write().use_azure = e.value() == "true",
+                        }
+                        span { class: "key-status optional", "Uses the OpenAI key above" }
+                    }
+
+                    if current.use_azure {
+                        div { class: "setting-item",
+                            label { "Azure endpoint" }
+                            input {
+                                r#type: "text",
+                                placeholder: "https://my-resource.openai.azure.com",
+                                value: "{current.azure_endpoint}",
+                                oninput: move |e| state.write().azure_endpoint = e.value().clone(),
+                            }
+                        }
+
+                        div { class: "setting-item",
+                            label { "Azure deployment" }
+                            input {
+                                r#type: "text",
+                                placeholder: "gpt-4o",
+                                value: "{current.azure_deployment}",
+                                oninput: move |e| state.write().azure_deployment = e.value().clone(),
+                            }
+                        }
+                    }
+
                     div { class: "setting-item",
                         label { "Anthropic (Claude)" }
                         input {
@@ -209,6 +612,63 @@ pub fn SettingsPanel(
                             span { class: "key-status optional", "Optional" }
                         }
                     }
+
+                    div { class: "setting-item",
+                        label { "Groq (Fastest Flash)" }
+                        input {
+                            r#type: "password",
+                            placeholder: "gsk_...",
+                            value: "{current.groq_key}",
+                            oninput: move |e| state.write().groq_key = e.value().clone(),
+                        }
+                        if !current.groq_key.is_empty() {
+                            span { class: "key-status ok", "OK" }
+                        } else {
+                            span { class: "key-status optional", "Optional" }
+                        }
+                    }
+
+                    div { class: "setting-item",
+                        label { "OpenRouter (Any Model)" }
+                        input {
+                            r#type: "password",
+                            placeholder: "sk-or-...",
+                            value: "{current.openrouter_key}",
+                            oninput: move |e| state.write().openrouter_key = e.value().clone(),
+                        }
+                        if !current.openrouter_key.is_empty() {
+                            span { class: "key-status ok", "OK" }
+                        } else {
+                            span { class: "key-status optional", "Optional" }
+                        }
+                    }
+
+                    div { class: "setting-item",
+                        label { "Outreach Account (Call Memory)" }
+                        input {
+                            r#type: "password",
+                            placeholder: "Access token",
+                            value: "{current.outreach_key}",
+                            oninput: move |e| state.write().outreach_key = e.value().clone(),
+                        }
+                        if !current.outreach_key.is_empty() {
+                            span { class: "key-status ok", "OK" }
+                        } else {
+                            span { class: "key-status optional", "Optional" }
+                        }
+                    }
+
+                    if !current.outreach_key.is_empty() {
+                        div { class: "setting-item",
+                            label { "Outreach Device ID" }
+                            input {
+                                r#type: "text",
+                                placeholder: "From sign-in (read from the token if empty)",
+                                value: "{current.outreach_device}",
+                                oninput: move |e| state.write().outreach_device = e.value().clone(),
+                            }
+                        }
+                    }
                 }
 
                 // Model Settings
@@ -222,6 +682,7 @@ pub fn SettingsPanel(
                             onchange: move |e| state.write().flash_model = e.value().clone(),
                             option { value: "GeminiFlash", "Gemini 2.0 Flash (Recommended)" }
                             option { value: "GPT4oMini", "GPT-4o-mini" }
+                            option { value: "Groq", "Groq Llama (Fastest)" }
                             option { value: "LocalOllama", "Local Ollama (Free, Offline)" }
                         }
                     }
@@ -277,26 +738,578 @@ pub fn SettingsPanel(
                     }
                 }
 
-                // Keyboard Shortcuts (read-only info)
+                // Per-mode overrides
+                div { class: "settings-section",
+                    h3 { "Models per Mode" }
+                    p { class: "settings-hint",
+                        "Modes set to Default use the models above"
+                    }
+
+                    for mode in CopilotMode::all() {
+                        {
+                            let key = mode.key();
+                            let flash = current.per_mode_flash.get(key).cloned().unwrap_or_default();
+                            let deep = current.per_mode_deep.get(key).cloned().unwrap_or_default();
+                            rsx! {
+                                div { class: "setting-item",
+                                    label { "{mode.label()}" }
+                                    select {
+                                        value: "{flash}",
+                                        onchange: move |e| set_override(&mut state.write().per_mode_flash, key, e.value()),
+                                        option { value: "", "Default flash" }
+                                        option { value: "GeminiFlash", "Gemini 2.0 Flash" }
+                                        option { value: "GPT4oMini", "GPT-4o-mini" }
+                                        option { value: "Groq", "Groq Llama" }
+                                        option { value: "LocalOllama", "Local Ollama" }
+                                    }
+                                    select {
+                                        value: "{deep}",
+                                        onchange: move |e| set_override(&mut state.write().per_mode_deep, key, e.value()),
+                                        option { value: "", "Default deep" }
+                                        option { value: "ClaudeSonnet", "Claude 3.5 Sonnet" }
+                                        option { value: "GPT4o", "GPT-4o" }
+                                        option { value: "O1Preview", "o1-preview" }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                // Prompt editor
+                div { class: "settings-section",
+                    h3 { "Prompts" }
+                    p { class: "settings-hint",
+                        "Use {{{{transcript}}}}, {{{{context}}}}, {{{{history}}}}, {{{{bullets}}}}, {{{{mode}}}}, {{{{language}}}}, {{{{max_bullets}}}}, {{{{max_bullet_words}}}} and {{{{deep_max_words}}}}"
+                    }
+
+                    {
+                        let text = current.prompt_text();
+                        let errors = current.prompt_errors();
+                        let mut sample = HashMap::new();
+                        sample.insert("mode".to_string(), current.prompt_mode.clone());
+                        let preview = PromptEditor::preview(&text, &sample);
+                        rsx! {
+                            div { class: "setting-item prompt-editor",
+                                select {
+                                    value: "{current.prompt_mode}",
+                                    onchange: move |e| state.write().prompt_mode = e.value(),
+                                    for mode in CopilotMode::all() {
+                                        option { value: "{mode.key()}", "{mode.label()}" }
+                                    }
+                                }
+                                select {
+                                    value: "{current.prompt_stage}",
+                                    onchange: move |e| state.write().prompt_stage = e.value(),
+                                    option { value: "flash", "Flash (Quick)" }
+                                    option { value: "deep", "Deep (Detailed)" }
+                                }
+                                textarea {
+                                    rows: "10",
+                                    value: "{text}",
+                                    oninput: move |e| state.write().set_prompt_text(&e.value()),
+                                }
+                                if !errors.is_empty() {
+                                    div { class: "prompt-errors",
+                                        for error in errors {
+                                            p { class: "settings-hint warning", "{error}" }
+                                        }
+                                    }
+                                }
+                                label { "Preview" }
+                                pre { class: "prompt-preview", "{preview}" }
+                            }
+                        }
+                    }
+                }
+
+                // Keyboard Shortcuts
                 div { class: "settings-section",
                     h3 { "Keyboard Shortcuts" }
+                    p { class: "settings-hint", "Click a shortcut, then press the new keys. Esc cancels." }
 
                     div { class: "shortcut-list",
-                        div { class: "shortcut-item",
-                            span { class: "shortcut-key", "Ctrl+Shift+S" }
-                            span { "Start/Stop listening" }
+                        for action in HotkeyAction::all() {
+                            {
+                                let capturing = current.capturing_hotkey == Some(action);
+                                let combo = if capturing { "Press keys…".to_string() } else { current.hotkeys.combo(action).to_string() };
+                                let problem = hotkey_problems
+                                    .iter()
+                                    .find(|(a, _)| *a == action)
+                                    .map(|(_, problem)| problem.clone());
+                                rsx! {
+                                    div { class: "shortcut-item",
+                                        button {
+                                            class: if capturing { "shortcut-key capturing" } else { "shortcut-key" },
+                                            onclick: move |_| state.write().capturing_hotkey = Some(action),
+                                            onblur: move |_| {
+                                                if state.read().capturing_hotkey == Some(action) {
+                                                    state.write().capturing_hotkey = None;
+                                                }
+                                            },
+                                            onkeydown: move |e| {
+                                                if state.read().capturing_hotkey != Some(action) {
+                                                    return;
+                                                }
+                                                if e.key() == Key::Escape {
+                                                    state.write().capturing_hotkey = None;
+                                                    return;
+                                                }
+                                                let m = e.modifiers();
+                                                if let Some(combo) = combo_from_keys(m.ctrl(), m.alt(), m.shift(), m.meta(), &e.code().to_string()) {
+                                                    let mut s = state.write();
+                                                    s.hotkeys.set(action, combo);
+                                                    s.capturing_hotkey = None;
+                                                }
+                                            },
+                                            "{combo}"
+                                        }
+                                        span { "{action.label()}" }
+                                        if let Some(problem) = problem {
+                                            span { class: "settings-hint warning", "{problem}" }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    if !hotkey_errors.is_empty() {
+                        div { class: "prompt-errors",
+                            for error in hotkey_errors.iter() {
+                                p { class: "settings-hint warning", "{error}" }
+                            }
+                        }
+                    }
+                    button {
+                        class: "save-btn",
+                        onclick: move |_| state.write().hotkeys = HotkeyConfig::default(),
+                        "Reset shortcuts"
+                    }
+
+                    div { class: "setting-item",
+                        label { "Copy suggestion copies" }
+                        select {
+                            value: "{current.copy_target}",
+                            onchange: move |e| state.write().copy_target = e.value().clone(),
+                            option { value: "Deep", "Deep response (or top bullet)" }
+                            option { value: "Bullets", "All quick bullets" }
+                            option { value: "Question", "Question to ask" }
                         }
-                        div { class: "shortcut-item",
-                            span { class: "shortcut-key", "Ctrl+Shift+H" }
-                            span { "Hide/Show window" }
+                    }
+                }
+
+                // Transcription
+                div { class: "settings-section",
+                    h3 { "Transcription" }
+
+                    div { class: "setting-item",
+                        label { "Minimum confidence" }
+                        input {
+                            r#type: "range",
+                            min: "0",
+                            max: "1",
+                            step: "0.05",
+                            value: "{current.min_confidence}",
+                            oninput: move |e| state.write().min_confidence = e.value().parse().unwrap_or(0.6),
                         }
-                        div { class: "shortcut-item",
-                            span { class: "shortcut-key", "Ctrl+Shift+M" }
-                            span { "Switch mode" }
+                        span { class: "key-status optional", "{(current.min_confidence * 100.0).round()}%" }
+                    }
+                    p { class: "settings-hint",
+                        "Lines heard with less confidence are shown greyed out and not analyzed."
+                    }
+
+                    div { class: "setting-item",
+                        label { "Your voice" }
+                        select {
+                            value: "{current.user_speaker}",
+                            onchange: move |e| state.write().user_speaker = e.value().clone(),
+                            option { value: "", "Not separated" }
+                            option { value: "0", "First speaker" }
+                            option { value: "1", "Second speaker" }
                         }
-                        div { class: "shortcut-item",
-                            span { class: "shortcut-key", "Ctrl+Shift+C" }
-                            span { "Copy suggestion" }
+                    }
+                    p { class: "settings-hint",
+                        "Splits the call by speaker. Your lines are kept in the transcript but not answered; pick the one who talks first on your calls."
+                    }
+
+                    div { class: "setting-item",
+                        label { "Glossary" }
+                        textarea {
+                            rows: "6",
+                            placeholder: "Acme Vault: our secrets manager\nSSO: single sign-on",
+                            value: "{current.glossary}",
+                            oninput: move |e| state.write().glossary = e.value(),
+                        }
+                    }
+                    p { class: "settings-hint",
+                        "One term per line. Terms are boosted in transcription and explained to the AI."
+                    }
+                }
+
+                // Hands-free start
+                div { class: "settings-section",
+                    h3 { "Wake Word" }
+
+                    div { class: "setting-item",
+                        label { "Start on wake phrase" }
+                        input {
+                            r#type: "checkbox",
+                            checked: current.wake_word.enabled,
+                            oninput: move |e| state.write().wake_word.enabled = e.value() == "true",
+                        }
+                        span { class: "key-status optional", "Only while not listening" }
+                    }
+                    if current.wake_word.enabled {
+                        div { class: "setting-item",
+                            label { "Phrase" }
+                            input {
+                                r#type: "text",
+                                placeholder: "Hey copilot",
+                                value: "{current.wake_word.phrase}",
+                                oninput: move |e| state.write().wake_word.phrase = e.value(),
+                            }
+                        }
+                        div { class: "setting-item",
+                            label { "Sensitivity" }
+                            input {
+                                r#type: "range",
+                                min: "0",
+                                max: "1",
+                                step: "0.05",
+                                value: "{current.wake_word.sensitivity}",
+                                oninput: move |e| state.write().wake_word.sensitivity = e.value().parse().unwrap_or(0.5),
+                            }
+                            span { class: "key-status optional", "{(current.wake_word.sensitivity * 100.0).round()}%" }
+                        }
+                    }
+                    p { class: "settings-hint",
+                        "Listens on your microphone with a local Whisper model, so nothing is sent until listening starts. Raise sensitivity if the phrase is missed, lower it if it starts by accident."
+                    }
+                }
+
+                // Urgency alerts
+                div { class: "settings-section",
+                    h3 { "Alerts" }
+
+                    div { class: "setting-item",
+                        label { "Answer now" }
+                        select {
+                            value: "{current.alerts.answer_now:?}",
+                            onchange: move |e| state.write().alerts.answer_now = parse_alert_level(&e.value()),
+                            option { value: "Silent", "Silent" }
+                            option { value: "Visual", "Flash border" }
+                            option { value: "Audio", "Flash border and chime" }
+                        }
+                    }
+                    div { class: "setting-item",
+                        label { "Can elaborate" }
+                        select {
+                            value: "{current.alerts.can_elaborate:?}",
+                            onchange: move |e| state.write().alerts.can_elaborate = parse_alert_level(&e.value()),
+                            option { value: "Silent", "Silent" }
+                            option { value: "Visual", "Flash border" }
+                            option { value: "Audio", "Flash border and chime" }
+                        }
+                    }
+                    div { class: "setting-item",
+                        label { "Just listening" }
+                        select {
+                            value: "{current.alerts.just_listening:?}",
+                            onchange: move |e| state.write().alerts.just_listening = parse_alert_level(&e.value()),
+                            option { value: "Silent", "Silent" }
+                            option { value: "Visual", "Flash border" }
+                            option { value: "Audio", "Flash border and chime" }
+                        }
+                    }
+                    p { class: "settings-hint",
+                        "How a suggestion gets your attention, by how quickly you need to respond. Transcription pauses while the chime plays so it isn't picked up."
+                    }
+                }
+
+                // Response length
+                div { class: "settings-section",
+                    h3 { "Response Length" }
+
+                    div { class: "setting-item",
+                        label { "Responses" }
+                        select {
+                            value: "{current.responses.mode:?}",
+                            onchange: move |e| state.write().responses.mode = parse_response_mode(&e.value()),
+                            option { value: "FlashAndDeep", "Bullets and detailed answer" }
+                            option { value: "FlashOnly", "Bullets only" }
+                            option { value: "DeepOnly", "Detailed answer only" }
+                        }
+                    }
+                    div { class: "setting-item",
+                        label { "Quick bullets" }
+                        input {
+                            r#type: "number",
+                            min: "1",
+                            max: "8",
+                            value: "{current.responses.max_bullets}",
+                            oninput: move |e| {
+                                if let Ok(n) = e.value().parse::<usize>() {
+                                    state.write().responses.max_bullets = n.clamp(1, 8);
+                                }
+                            },
+                        }
+                    }
+                    div { class: "setting-item",
+                        label { "Words per bullet" }
+                        input {
+                            r#type: "number",
+                            min: "3",
+                            max: "40",
+                            value: "{current.responses.max_bullet_words}",
+                            oninput: move |e| {
+                                if let Ok(n) = e.value().parse::<usize>() {
+                                    state.write().responses.max_bullet_words = n.clamp(3, 40);
+                                }
+                            },
+                        }
+                    }
+                    div { class: "setting-item",
+                        label { "Detailed response words" }
+                        input {
+                            r#type: "number",
+                            min: "30",
+                            max: "600",
+                            step: "10",
+                            value: "{current.responses.deep_max_words}",
+                            oninput: move |e| {
+                                if let Ok(n) = e.value().parse::<usize>() {
+                                    state.write().responses.deep_max_words = n.clamp(30, 600);
+                                }
+                            },
+                        }
+                    }
+                    p { class: "settings-hint",
+                        "Extra bullets are dropped and long ones cut short. Lower these on a small overlay to keep suggestions glanceable. Bullets only skips the detailed answer, which is faster and uses fewer tokens; the shortcut switches it during a call."
+                    }
+                }
+
+                // Which of their turns get a suggestion
+                div { class: "settings-section",
+                    h3 { "Suggest On" }
+
+                    for statement in trigger_types() {
+                        {
+                            let checked = current.responses.trigger_on.contains(&statement);
+                            rsx! {
+                                div { class: "setting-item",
+                                    label { "{statement.emoji()} {statement.label()}" }
+                                    input {
+                                        r#type: "checkbox",
+                                        checked,
+                                        oninput: move |e| set_trigger(&mut state.write().responses, statement.clone(), e.value() == "true"),
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    p { class: "settings-hint",
+                        "Their turns are sorted on this machine before anything is sent, so chat and plain statements don't spend API calls. Analyze now still works on any turn. With nothing ticked, every turn gets a suggestion."
+                    }
+                }
+
+                // Competitor battlecards
+                div { class: "settings-section",
+                    h3 { "Competitors" }
+
+                    div { class: "setting-item",
+                        label { "Battlecards" }
+                        textarea {
+                            rows: "8",
+                            placeholder: "[HubSpot]\n+ Calls are recorded without an add-on\n? How do you review calls today?",
+                            value: "{current.battlecards}",
+                            oninput: move |e| state.write().battlecards = e.value(),
+                        }
+                    }
+                    p { class: "settings-hint",
+                        "[Name] starts a competitor, + lines are where you win, ? lines are questions that expose their weak spots. Shown to the AI when they mention that competitor."
+                    }
+                }
+
+                // Objection library
+                div { class: "settings-section",
+                    h3 { "Objections" }
+
+                    div { class: "setting-item",
+                        label { "Objection library" }
+                        textarea {
+                            rows: "10",
+                            placeholder: "[Too expensive]\nkeywords: too expensive, over budget\nAsk what the problem costs them today.",
+                            value: "{current.objections}",
+                            oninput: move |e| state.write().objections = e.value(),
+                        }
+                    }
+                    div { class: "setting-item",
+                        button {
+                            class: "save-btn",
+                            onclick: move |_| {
+                                let library = ObjectionLibrary::from_text(&state.read().objections);
+                                let message = match export_objections(&library) {
+                                    Ok(Some(path)) => format!("Exported to {}", path.display()),
+                                    Ok(None) => return,
+                                    Err(e) => format!("Export failed: {}", e),
+                                };
+                                state.write().save_message = Some(message);
+                            },
+                            "Export..."
+                        }
+                        button {
+                            class: "save-btn",
+                            onclick: move |_| {
+                                let mut s = state.write();
+                                match import_objections() {
+                                    Ok(Some(library)) => {
+                                        s.objections = library.to_text();
+                                        s.save_message = Some("Imported objections; save to keep them".to_string());
+                                    }
+                                    Ok(None) => {}
+                                    Err(e) => s.save_message = Some(format!("Import failed: {}", e)),
+                                }
+                            },
+                            "Import..."
+                        }
+                    }
+                    p { class: "settings-hint",
+                        "[Name] starts an objection, the keywords line lists phrases that raise it and the rest is your response. The best match for what they said is shown above the quick bullets and given to the AI."
+                    }
+                }
+
+                // Appearance
+                div { class: "settings-section",
+                    h3 { "Appearance" }
+
+                    div { class: "setting-item",
+                        label { "Theme" }
+                        select {
+                            value: "{theme_name}",
+                            onchange: move |e| on_theme.call(e.value()),
+                            for (key, label) in current.themes.iter() {
+                                option { value: "{key}", "{label}" }
+                            }
+                        }
+                        button {
+                            class: "save-btn",
+                            onclick: move |_| state.write().editing_theme = true,
+                            "Customize…"
+                        }
+                    }
+                    if current.editing_theme {
+                        ThemeEditor {
+                            base: Theme::by_key(&theme_name).unwrap_or_default(),
+                            on_saved: move |key: String| {
+                                {
+                                    let mut s = state.write();
+                                    s.themes = theme_options();
+                                    s.editing_theme = false;
+                                }
+                                on_theme.call(key);
+                            },
+                            on_cancel: move |_| state.write().editing_theme = false,
+                        }
+                    }
+                    p { class: "settings-hint",
+                        {format!("Custom themes are saved in {}", Theme::custom_dir().display())}
+                    }
+
+                    div { class: "setting-item",
+                        label { "Language" }
+                        select {
+                            value: "{language.code()}",
+                            onchange: move |e| {
+                                let language = Language::from_code(&e.value());
+                                // Until prompts are customized, show that language's defaults
+                                if !CustomPrompts::path().exists() {
+                                    state.write().prompts = CustomPrompts::default_for_lang(language);
+                                }
+                                on_language.call(language);
+                            },
+                            for option_language in Language::all() {
+                                option { value: "{option_language.code()}", "{option_language.native_name()}" }
+                            }
+                        }
+                    }
+                    p { class: "settings-hint",
+                        "Suggestions are written in this language and the overlay is shown in it."
+                    }
+                }
+
+                // Screen sharing
+                div { class: "settings-section",
+                    h3 { "Screen Sharing" }
+
+                    div { class: "setting-item",
+                        label { "Hide when sharing" }
+                        input {
+                            r#type: "checkbox",
+                            checked: current.auto_hide_on_share,
+                            oninput: move |e| state.write().auto_hide_on_share = e.value() == "true",
+                        }
+                        span { class: "key-status optional", "Show it again from the tray (Stealth)" }
+                    }
+                }
+
+                // Recording
+                div { class: "settings-section",
+                    h3 { "Recording" }
+
+                    div { class: "setting-item",
+                        label { "Record calls" }
+                        input {
+                            r#type: "checkbox",
+                            checked: current.auto_record,
+                            oninput: move |e| state.write().auto_record = e.value() == "true",
+                        }
+                        span { class: "key-status optional", "Transcript and timing, for reports and minutes" }
+                    }
+
+                    if current.auto_record {
+                        div { class: "setting-item",
+                            label { "Save audio" }
+                            input {
+                                r#type: "checkbox",
+                                checked: current.record_audio,
+                                oninput: move |e| state.write().record_audio = e.value() == "true",
+                            }
+                            span { class: "key-status optional", "WAV next to the transcript, up to 1 hour per call" }
+                        }
+                    }
+                    p { class: "settings-hint",
+                        "Recordings stay on this computer, in the app's recordings folder."
+                    }
+                }
+
+                // Readiness check
+                div { class: "settings-section",
+                    h3 { "Pre-Call Check" }
+                    p { class: "settings-hint",
+                        "Pings every configured provider with a tiny request."
+                    }
+
+                    button {
+                        class: "save-btn",
+                        disabled: current.self_test_running,
+                        onclick: start_self_test,
+                        {if current.self_test_running { "Checking..." } else { "Run Self-Test" }}
+                    }
+
+                    if let Some(report) = &current.self_test {
+                        for check in report.checks.iter() {
+                            div { class: "setting-item",
+                                label { "{check.component}" }
+                                span { class: "settings-hint", "{check.message}" }
+                                span {
+                                    class: match check.status {
+                                        CheckStatus::Ok => "key-status ok",
+                                        CheckStatus::Warn => "key-status optional",
+                                        CheckStatus::Fail => "key-status error",
+                                    },
+                                    {format!("{:?}", check.status)}
+                                }
+                            }
                         }
                     }
                 }
@@ -2,6 +2,8 @@
 //!
 //! Beautiful, S-tier quality CSS with animations and color-coded outputs.
 
+use super::theme::Theme;
+
 /// Main application CSS - polished and beautiful
 pub const POLISHED_CSS: &str = r##"
 /* ============================================
@@ -655,14 +657,20 @@ body {
 
 /// Get the full CSS including theme overrides
 pub fn get_themed_css(theme_name: &str) -> String {
-    let theme_vars = match theme_name {
-        "light" => LIGHT_THEME_VARS,
-        "high_contrast" => HIGH_CONTRAST_VARS,
-        "cyberpunk" => CYBERPUNK_VARS,
-        _ => "", // Dark is default
-    };
-
-    format!("{}\n\n{}", theme_vars, POLISHED_CSS)
+    format!("{}\n\n{}", get_theme_vars(theme_name), POLISHED_CSS)
+}
+
+/// Just the `:root` overrides for a preset or custom theme key
+///
+/// Dark and unknown keys return nothing, leaving the default variables.
+pub fn get_theme_vars(theme_name: &str) -> String {
+    match theme_name {
+        "light" => LIGHT_THEME_VARS.to_string(),
+        "high_contrast" => HIGH_CONTRAST_VARS.to_string(),
+        "cyberpunk" => CYBERPUNK_VARS.to_string(),
+        "dark" => String::new(),
+        key => Theme::by_key(key).map(|t| t.to_css_root()).unwrap_or_default(),
+    }
 }
 
 const LIGHT_THEME_VARS: &str = r#"
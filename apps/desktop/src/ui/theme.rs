@@ -2,11 +2,21 @@
 //!
 //! Customizable color themes and styling for the UI.
 //! Provides color-coded outputs based on content type.
+//!
+//! Besides the presets, users can save their own palettes from the theme
+//! editor to `voice-copilot/themes/<key>.json`; fields left out of a
+//! hand-written file fall back to Dark.
 
+use anyhow::{anyhow, Result};
 use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// Keys of the built-in themes, in selector order
+pub const THEME_PRESETS: [&str; 4] = ["dark", "light", "high_contrast", "cyberpunk"];
 
 /// Color palette for the application
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct Theme {
     /// Theme name
     pub name: String,
@@ -239,6 +249,196 @@ impl Theme {
         }
     }
 
+    /// Preset by key, e.g. "high_contrast"
+    pub fn preset(key: &str) -> Option<Self> {
+        match key {
+            "dark" => Some(Self::dark()),
+            "light" => Some(Self::light()),
+            "high_contrast" => Some(Self::high_contrast()),
+            "cyberpunk" => Some(Self::cyberpunk()),
+            _ => None,
+        }
+    }
+
+    /// Directory user-defined themes are loaded from
+    pub fn custom_dir() -> PathBuf {
+        dirs::config_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join("voice-copilot")
+            .join("themes")
+    }
+
+    /// User-defined themes keyed by file name, sorted by key
+    ///
+    /// Files that don't parse or hold invalid colors are skipped with a
+    /// warning, since their values end up in the page's CSS.
+    pub fn load_custom() -> Vec<(String, Self)> {
+        let Ok(entries) = std::fs::read_dir(Self::custom_dir()) else {
+            return Vec::new();
+        };
+
+        let mut themes: Vec<(String, Self)> = entries
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+            .filter_map(|path| {
+                let key = path.file_stem()?.to_string_lossy().to_string();
+                let content = std::fs::read_to_string(&path).ok()?;
+                match serde_json::from_str::<Self>(&content) {
+                    Ok(theme) if !theme.invalid_colors().is_empty() => {
+                        tracing::warn!("Skipping theme {}: invalid {}", path.display(), theme.invalid_colors().join(", "));
+                        None
+                    }
+                    Ok(theme) => Some((key, theme)),
+                    Err(e) => {
+                        tracing::warn!("Skipping theme {}: {}", path.display(), e);
+                        None
+                    }
+                }
+            })
+            .filter(|(key, _)| Self::preset(key).is_none())
+            .collect();
+        themes.sort_by(|a, b| a.0.cmp(&b.0));
+        themes
+    }
+
+    /// Presets followed by saved custom themes, for the selector
+    pub fn load_all() -> Vec<(String, Self)> {
+        THEME_PRESETS
+            .iter()
+            .filter_map(|key| Some((key.to_string(), Self::preset(key)?)))
+            .chain(Self::load_custom())
+            .collect()
+    }
+
+    /// File key for a theme name, e.g. "My Ocean" -> "my_ocean"
+    pub fn key_for(name: &str) -> String {
+        name.trim()
+            .to_lowercase()
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<_>>()
+            .join("_")
+    }
+
+    /// Save as a custom theme, returning its key
+    ///
+    /// Fails if a color isn't valid CSS or the name would replace a preset.
+    pub fn save(&self) -> Result<String> {
+        let key = Self::key_for(&self.name);
+        if key.is_empty() {
+            return Err(anyhow!("Give the theme a name"));
+        }
+        if Self::preset(&key).is_some() {
+            return Err(anyhow!("\"{}\" is a built-in theme; pick another name", self.name));
+        }
+        let invalid = self.invalid_colors();
+        if !invalid.is_empty() {
+            return Err(anyhow!("Invalid color for {}", invalid.join(", ")));
+        }
+
+        let dir = Self::custom_dir();
+        std::fs::create_dir_all(&dir)?;
+        std::fs::write(dir.join(format!("{}.json", key)), serde_json::to_string_pretty(self)?)?;
+        Ok(key)
+    }
+
+    /// Every color field as (field name, value)
+    pub fn colors(&self) -> [(&'static str, &str); 30] {
+        [
+            ("bg_primary", self.bg_primary.as_str()),
+            ("bg_secondary", self.bg_secondary.as_str()),
+            ("bg_tertiary", self.bg_tertiary.as_str()),
+            ("bg_hover", self.bg_hover.as_str()),
+            ("text_primary", self.text_primary.as_str()),
+            ("text_secondary", self.text_secondary.as_str()),
+            ("text_muted", self.text_muted.as_str()),
+            ("accent_blue", self.accent_blue.as_str()),
+            ("accent_green", self.accent_green.as_str()),
+            ("accent_yellow", self.accent_yellow.as_str()),
+            ("accent_orange", self.accent_orange.as_str()),
+            ("accent_red", self.accent_red.as_str()),
+            ("accent_purple", self.accent_purple.as_str()),
+            ("accent_cyan", self.accent_cyan.as_str()),
+            ("accent_pink", self.accent_pink.as_str()),
+            ("color_transcript", self.color_transcript.as_str()),
+            ("color_flash", self.color_flash.as_str()),
+            ("color_deep", self.color_deep.as_str()),
+            ("color_question", self.color_question.as_str()),
+            ("color_objection", self.color_objection.as_str()),
+            ("color_buying_signal", self.color_buying_signal.as_str()),
+            ("color_technical", self.color_technical.as_str()),
+            ("color_warning", self.color_warning.as_str()),
+            ("color_success", self.color_success.as_str()),
+            ("border_color", self.border_color.as_str()),
+            ("border_focus", self.border_focus.as_str()),
+            ("shadow_color", self.shadow_color.as_str()),
+            ("glow_color", self.glow_color.as_str()),
+            ("gradient_start", self.gradient_start.as_str()),
+            ("gradient_end", self.gradient_end.as_str()),
+        ]
+    }
+
+    /// Every color field, for the editor
+    pub fn colors_mut(&mut self) -> [(&'static str, &mut String); 30] {
+        [
+            ("bg_primary", &mut self.bg_primary),
+            ("bg_secondary", &mut self.bg_secondary),
+            ("bg_tertiary", &mut self.bg_tertiary),
+            ("bg_hover", &mut self.bg_hover),
+            ("text_primary", &mut self.text_primary),
+            ("text_secondary", &mut self.text_secondary),
+            ("text_muted", &mut self.text_muted),
+            ("accent_blue", &mut self.accent_blue),
+            ("accent_green", &mut self.accent_green),
+            ("accent_yellow", &mut self.accent_yellow),
+            ("accent_orange", &mut self.accent_orange),
+            ("accent_red", &mut self.accent_red),
+            ("accent_purple", &mut self.accent_purple),
+            ("accent_cyan", &mut self.accent_cyan),
+            ("accent_pink", &mut self.accent_pink),
+            ("color_transcript", &mut self.color_transcript),
+            ("color_flash", &mut self.color_flash),
+            ("color_deep", &mut self.color_deep),
+            ("color_question", &mut self.color_question),
+            ("color_objection", &mut self.color_objection),
+            ("color_buying_signal", &mut self.color_buying_signal),
+            ("color_technical", &mut self.color_technical),
+            ("color_warning", &mut self.color_warning),
+            ("color_success", &mut self.color_success),
+            ("border_color", &mut self.border_color),
+            ("border_focus", &mut self.border_focus),
+            ("shadow_color", &mut self.shadow_color),
+            ("glow_color", &mut self.glow_color),
+            ("gradient_start", &mut self.gradient_start),
+            ("gradient_end", &mut self.gradient_end),
+        ]
+    }
+
+    /// Color fields whose value isn't a CSS hex or rgb()/rgba() color
+    pub fn invalid_colors(&self) -> Vec<&'static str> {
+        self.colors()
+            .into_iter()
+            .filter(|(_, value)| !is_css_color(value))
+            .map(|(field, _)| field)
+            .collect()
+    }
+
+    /// Preset or saved custom theme by key
+    pub fn by_key(key: &str) -> Option<Self> {
+        Self::preset(key).or_else(|| {
+            Self::load_custom()
+                .into_iter()
+                .find(|(custom, _)| custom == key)
+                .map(|(_, theme)| theme)
+        })
+    }
+
+    /// The palette as a `:root` rule
+    pub fn to_css_root(&self) -> String {
+        format!(":root {{{}}}", self.to_css_vars())
+    }
+
     /// Generate CSS variables from theme
     pub fn to_css_vars(&self) -> String {
         format!(
@@ -287,6 +487,33 @@ impl Theme {
     }
 }
 
+/// Whether a value is "#rgb", "#rgba", "#rrggbb", "#rrggbbaa" or an
+/// rgb()/rgba() function with numeric arguments
+pub fn is_css_color(value: &str) -> bool {
+    let value = value.trim();
+    if let Some(hex) = value.strip_prefix('#') {
+        return matches!(hex.len(), 3 | 4 | 6 | 8) && hex.chars().all(|c| c.is_ascii_hexdigit());
+    }
+
+    let args = value
+        .strip_prefix("rgba(")
+        .or_else(|| value.strip_prefix("rgb("))
+        .and_then(|rest| rest.strip_suffix(')'));
+    let Some(args) = args else {
+        return false;
+    };
+    let parts: Vec<&str> = args.split(',').map(str::trim).collect();
+    matches!(parts.len(), 3 | 4)
+        && parts.iter().enumerate().all(|(i, part)| {
+            let number = part.strip_suffix('%').unwrap_or(part);
+            match number.parse::<f32>() {
+                Ok(n) if i == 3 => (0.0..=1.0).contains(&n) || part.ends_with('%'),
+                Ok(n) => (0.0..=255.0).contains(&n),
+                Err(_) => false,
+            }
+        })
+}
+
 /// Get color for statement type
 pub fn get_statement_color(statement_type: &str) -> &'static str {
     match statement_type.to_lowercase().as_str() {
@@ -318,3 +545,37 @@ pub fn get_sentiment_color(sentiment: &str) -> &'static str {
         _ => "var(--text-secondary)",
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_partial_custom_theme() {
+        let theme: Theme = serde_json::from_str(r##"{"name": "Ocean", "bg_primary": "#001f3f"}"##).unwrap();
+        assert_eq!(theme.name, "Ocean");
+        assert_eq!(theme.accent_blue, Theme::dark().accent_blue);
+
+        let css = theme.to_css_root();
+        assert!(css.starts_with(":root {"));
+        assert!(css.contains("--bg-primary: #001f3f;"));
+        assert!(Theme::preset("light").is_some());
+    }
+
+    #[test]
+    fn test_color_validation() {
+        assert!(is_css_color("#0d1117"));
+        assert!(is_css_color("#fff"));
+        assert!(is_css_color("rgba(0, 0, 0, 0.4)"));
+        assert!(is_css_color("rgb(88,166,255)"));
+        assert!(!is_css_color("blue; }"));
+        assert!(!is_css_color("#12345"));
+        assert!(!is_css_color("rgba(0, 0, 0, 4)"));
+
+        let mut theme = Theme::dark();
+        assert!(theme.invalid_colors().is_empty());
+        *theme.colors_mut()[0].1 = "red".to_string();
+        assert_eq!(theme.invalid_colors(), vec!["bg_primary"]);
+        assert_eq!(Theme::key_for(" My Ocean! "), "my_ocean");
+    }
+}
@@ -2,22 +2,30 @@
 //!
 //! Provides system tray icon and menu for quick access.
 
+use parking_lot::RwLock;
+use std::sync::mpsc;
+use std::sync::Arc;
 use tray_icon::{
-    menu::{Menu, MenuEvent, MenuItem, PredefinedMenuItem, Submenu},
-    TrayIcon, TrayIconBuilder,
+    menu::{CheckMenuItem, Menu, MenuEvent, MenuItem, PredefinedMenuItem, Submenu},
+    Icon, TrayIcon, TrayIconBuilder,
 };
-use std::sync::mpsc;
+
+use super::app::CopilotMode;
+use super::runtime::SharedState;
+
+/// Tray icon size in pixels
+const ICON_SIZE: u32 = 32;
 
 /// Tray menu actions
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum TrayAction {
     Show,
     Hide,
     StartListening,
     StopListening,
-    ModeSales,
-    ModeInterview,
-    ModeTechnical,
+    SetMode(CopilotMode),
+    ToggleStealth,
+    ToggleClickThrough,
     Settings,
     Quit,
 }
@@ -26,14 +34,15 @@ pub enum TrayAction {
 pub struct TrayHandler {
     tray_icon: TrayIcon,
     menu_channel: mpsc::Receiver<MenuEvent>,
+    start_item: MenuItem,
+    stop_item: MenuItem,
+    /// One checkable item per mode, in selector order
+    mode_items: Vec<(CopilotMode, CheckMenuItem)>,
+    stealth_item: CheckMenuItem,
+    click_through_item: CheckMenuItem,
     // Menu item IDs
     show_id: String,
     hide_id: String,
-    start_id: String,
-    stop_id: String,
-    sales_id: String,
-    interview_id: String,
-    technical_id: String,
     settings_id: String,
     quit_id: String,
 }
@@ -47,19 +56,20 @@ impl TrayHandler {
         let separator1 = PredefinedMenuItem::separator();
 
         let start_item = MenuItem::new("Start Listening", true, None);
-        let stop_item = MenuItem::new("Stop Listening", true, None);
+        let stop_item = MenuItem::new("Stop Listening", false, None);
         let separator2 = PredefinedMenuItem::separator();
 
         // Mode submenu
-        let sales_item = MenuItem::new("Sales", true, None);
-        let interview_item = MenuItem::new("Interview", true, None);
-        let technical_item = MenuItem::new("Technical", true, None);
-
         let mode_menu = Submenu::new("Mode", true);
-        mode_menu.append(&sales_item)?;
-        mode_menu.append(&interview_item)?;
-        mode_menu.append(&technical_item)?;
+        let mut mode_items = Vec::new();
+        for mode in CopilotMode::all() {
+            let item = CheckMenuItem::new(mode.label(), true, mode == CopilotMode::default(), None);
+            mode_menu.append(&item)?;
+            mode_items.push((mode, item));
+        }
 
+        let stealth_item = CheckMenuItem::new("Stealth (F8)", true, false, None);
+        let click_through_item = CheckMenuItem::new("Overlay Click-through", true, false, None);
         let separator3 = PredefinedMenuItem::separator();
         let settings_item = MenuItem::new("Settings...", true, None);
         let separator4 = PredefinedMenuItem::separator();
@@ -74,6 +84,8 @@ impl TrayHandler {
         menu.append(&stop_item)?;
         menu.append(&separator2)?;
         menu.append(&mode_menu)?;
+        menu.append(&stealth_item)?;
+        menu.append(&click_through_item)?;
         menu.append(&separator3)?;
         menu.append(&settings_item)?;
         menu.append(&separator4)?;
@@ -82,23 +94,24 @@ impl TrayHandler {
         // Get menu event channel
         let menu_channel = MenuEvent::receiver().clone();
 
-        // Create tray icon
-        // Note: In production, embed an actual icon
-        let tray_icon = TrayIconBuilder::new()
+        let mut builder = TrayIconBuilder::new()
             .with_menu(Box::new(menu))
-            .with_tooltip("Voice Copilot")
-            .build()?;
+            .with_tooltip(tooltip(false, &CopilotMode::default()));
+        if let Some(icon) = status_icon(false) {
+            builder = builder.with_icon(icon);
+        }
+        let tray_icon = builder.build()?;
 
         Ok(Self {
             tray_icon,
             menu_channel,
+            start_item,
+            stop_item,
+            mode_items,
+            stealth_item,
+            click_through_item,
             show_id: show_item.id().0.to_string(),
             hide_id: hide_item.id().0.to_string(),
-            start_id: start_item.id().0.to_string(),
-            stop_id: stop_item.id().0.to_string(),
-            sales_id: sales_item.id().0.to_string(),
-            interview_id: interview_item.id().0.to_string(),
-            technical_id: technical_item.id().0.to_string(),
             settings_id: settings_item.id().0.to_string(),
             quit_id: quit_item.id().0.to_string(),
         })
@@ -108,22 +121,24 @@ impl TrayHandler {
     pub fn poll_event(&self) -> Option<TrayAction> {
         match self.menu_channel.try_recv() {
             Ok(event) => {
-                let id = event.id().0.to_string();
+                let id = event.id().0.as_str();
+
+                if let Some((mode, _)) = self.mode_items.iter().find(|(_, item)| item.id().0 == id) {
+                    return Some(TrayAction::SetMode(mode.clone()));
+                }
 
                 if id == self.show_id {
                     Some(TrayAction::Show)
                 } else if id == self.hide_id {
                     Some(TrayAction::Hide)
-                } else if id == self.start_id {
+                } else if id == self.start_item.id().0 {
                     Some(TrayAction::StartListening)
-                } else if id == self.stop_id {
+                } else if id == self.stop_item.id().0 {
                     Some(TrayAction::StopListening)
-                } else if id == self.sales_id {
-                    Some(TrayAction::ModeSales)
-                } else if id == self.interview_id {
-                    Some(TrayAction::ModeInterview)
-                } else if id == self.technical_id {
-                    Some(TrayAction::ModeTechnical)
+                } else if id == self.stealth_item.id().0 {
+                    Some(TrayAction::ToggleStealth)
+                } else if id == self.click_through_item.id().0 {
+                    Some(TrayAction::ToggleClickThrough)
                 } else if id == self.settings_id {
                     Some(TrayAction::Settings)
                 } else if id == self.quit_id {
@@ -140,11 +155,53 @@ impl TrayHandler {
     pub fn set_tooltip(&self, text: &str) {
         let _ = self.tray_icon.set_tooltip(Some(text));
     }
+
+    /// Reflect the runtime state in the icon, tooltip and menu
+    pub fn update(&self, listening: bool, mode: &CopilotMode, click_through: bool) {
+        self.start_item.set_enabled(!listening);
+        self.stop_item.set_enabled(listening);
+        self.click_through_item.set_checked(click_through);
+        for (item_mode, item) in &self.mode_items {
+            item.set_checked(item_mode == mode);
+        }
+
+        self.set_tooltip(&tooltip(listening, mode));
+        let _ = self.tray_icon.set_icon(status_icon(listening));
+    }
+}
+
+/// Tooltip text for the current state
+fn tooltip(listening: bool, mode: &CopilotMode) -> String {
+    let status = if listening { "Listening" } else { "Idle" };
+    format!("Voice Copilot - {} ({})", status, mode.label())
+}
+
+/// Round status dot: green while listening, grey when idle
+fn status_icon(listening: bool) -> Option<Icon> {
+    let (r, g, b) = if listening { (34, 197, 94) } else { (148, 163, 184) };
+    let center = ICON_SIZE as f32 / 2.0;
+    let radius = center - 2.0;
+
+    let mut rgba = Vec::with_capacity((ICON_SIZE * ICON_SIZE * 4) as usize);
+    for y in 0..ICON_SIZE {
+        for x in 0..ICON_SIZE {
+            let dx = x as f32 + 0.5 - center;
+            let dy = y as f32 + 0.5 - center;
+            let alpha = if (dx * dx + dy * dy).sqrt() <= radius { 255 } else { 0 };
+            rgba.extend_from_slice(&[r, g, b, alpha]);
+        }
+    }
+
+    Icon::from_rgba(rgba, ICON_SIZE, ICON_SIZE).ok()
 }
 
 /// Spawn tray event listener
+///
+/// Polls `state` so the tray follows listening and mode changes made
+/// anywhere in the app.
 pub fn spawn_tray_listener(
     action_tx: tokio::sync::mpsc::Sender<TrayAction>,
+    state: Arc<RwLock<SharedState>>,
 ) -> Option<std::thread::JoinHandle<()>> {
     // Tray must be created on main thread for Windows
     // This function should be called from the UI thread
@@ -153,6 +210,7 @@ pub fn spawn_tray_listener(
         Ok(handler) => {
             let handle = std::thread::spawn(move || {
                 tracing::info!("System tray initialized");
+                let mut shown: Option<(bool, CopilotMode, bool)> = None;
 
                 loop {
                     if let Some(action) = handler.poll_event() {
@@ -162,7 +220,19 @@ pub fn spawn_tray_listener(
                             break;
                         }
                         let _ = action_tx.blocking_send(action);
+                        // Check items toggle themselves on click; put them back in sync
+                        shown = None;
                     }
+
+                    let current = {
+                        let state = state.read();
+                        (state.is_running, state.mode.clone(), state.overlay.click_through)
+                    };
+                    if shown.as_ref() != Some(&current) {
+                        handler.update(current.0, &current.1, current.2);
+                        shown = Some(current);
+                    }
+
                     std::thread::sleep(std::time::Duration::from_millis(100));
                 }
             });
@@ -174,3 +244,14 @@ pub fn spawn_tray_listener(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tooltip_reflects_state() {
+        assert_eq!(tooltip(false, &CopilotMode::Sales), "Voice Copilot - Idle (Sales Call)");
+        assert_eq!(tooltip(true, &CopilotMode::Technical), "Voice Copilot - Listening (Technical)");
+    }
+}
@@ -0,0 +1,127 @@
+//! Audio Playback
+//!
+//! Plays audio returned by the cloud TTS APIs on the default output
+//! device via rodio, so speech can be stopped mid-sentence.
+
+use anyhow::{anyhow, Result};
+use parking_lot::Mutex;
+use rodio::buffer::SamplesBuffer;
+use rodio::{Decoder, OutputStream, Sink};
+use std::io::Cursor;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+/// Audio returned by a TTS provider
+#[derive(Debug, Clone)]
+pub enum AudioData {
+    /// Encoded audio rodio can decode (MP3, WAV, ...)
+    Encoded(Vec<u8>),
+    /// Raw 16-bit little-endian mono PCM
+    Pcm16 { bytes: Vec<u8>, sample_rate: u32 },
+}
+
+/// Plays one clip at a time on the default output device
+///
+/// Clones share the active sink, so any clone can stop playback.
+#[derive(Clone)]
+pub struct AudioPlayer {
+    volume: f32,
+    speed: f32,
+    sink: Arc<Mutex<Option<Arc<Sink>>>>,
+    /// Bumped by `stop()` so clips still being decoded never start
+    stops: Arc<AtomicU64>,
+}
+
+impl AudioPlayer {
+    /// Create a player with volume (0.0 to 1.0) and speed (0.5 to 2.0)
+    pub fn new(volume: f32, speed: f32) -> Self {
+        Self {
+            volume: volume.clamp(0.0, 1.0),
+            speed: speed.clamp(0.5, 2.0),
+            sink: Arc::new(Mutex::new(None)),
+            stops: Arc::new(AtomicU64::new(0)),
+        }
+    }
+
+    /// Play audio, returning once it finishes or `stop()` is called
+    pub async fn play(&self, audio: AudioData) -> Result<()> {
+        let player = self.clone();
+        let generation = self.stops.load(Ordering::SeqCst);
+        tokio::task::spawn_blocking(move || player.play_blocking(audio, generation)).await?
+    }
+
+    fn play_blocking(&self, audio: AudioData, generation: u64) -> Result<()> {
+        // The output stream isn't Send, so it lives on this thread for the
+        // length of the clip
+        let (_stream, handle) = OutputStream::try_default()
+            .map_err(|e| anyhow!("No audio output device: {}", e))?;
+        let sink = Arc::new(Sink::try_new(&handle)?);
+        sink.set_volume(self.volume);
+        sink.set_speed(self.speed);
+
+        match audio {
+            AudioData::Encoded(bytes) => sink.append(Decoder::new(Cursor::new(bytes))?),
+            AudioData::Pcm16 { bytes, sample_rate } => sink.append(SamplesBuffer::new(1, sample_rate, pcm16_samples(&bytes))),
+        }
+
+        {
+            let mut active = self.sink.lock();
+            if self.stops.load(Ordering::SeqCst) != generation {
+                return Ok(());
+            }
+            // Replacing the active sink stops whatever was playing before
+            if let Some(previous) = active.replace(sink.clone()) {
+                previous.stop();
+            }
+        }
+
+        sink.sleep_until_end();
+
+        let mut active = self.sink.lock();
+        if active.as_ref().map_or(false, |s| Arc::ptr_eq(s, &sink)) {
+            *active = None;
+        }
+        Ok(())
+    }
+
+    /// Stop playback by dropping the active sink
+    pub fn stop(&self) {
+        let mut active = self.sink.lock();
+        self.stops.fetch_add(1, Ordering::SeqCst);
+        if let Some(sink) = active.take() {
+            sink.stop();
+        }
+    }
+
+    /// Whether audio is currently playing
+    pub fn is_speaking(&self) -> bool {
+        self.sink.lock().as_ref().map_or(false, |s| !s.empty())
+    }
+}
+
+/// Decode 16-bit little-endian PCM bytes
+fn pcm16_samples(bytes: &[u8]) -> Vec<i16> {
+    bytes
+        .chunks_exact(2)
+        .map(|pair| i16::from_le_bytes([pair[0], pair[1]]))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pcm16_samples() {
+        let bytes = [0x00, 0x00, 0xff, 0x7f, 0x00, 0x80, 0x01];
+        assert_eq!(pcm16_samples(&bytes), vec![0, i16::MAX, i16::MIN]);
+    }
+
+    #[test]
+    fn test_idle_player() {
+        let player = AudioPlayer::new(2.0, 1.0);
+        assert_eq!(player.volume, 1.0);
+        assert!(!player.is_speaking());
+        player.stop();
+    }
+}
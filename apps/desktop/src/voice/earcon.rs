@@ -0,0 +1,69 @@
+//! Earcon
+//!
+//! A short rising two-note chime for urgent suggestions. It's generated
+//! as PCM so there's no sound file to ship, and plays through the same
+//! `AudioPlayer` as speech.
+
+use std::time::Duration;
+
+use super::audio_playback::AudioData;
+
+const SAMPLE_RATE: u32 = 24_000;
+
+/// (frequency in Hz, length in ms), played in order
+const NOTES: [(f32, u32); 2] = [(880.0, 90), (1320.0, 140)];
+
+/// Fade at both ends of each note so it doesn't click (ms)
+const FADE_MS: u32 = 10;
+
+/// Peak level, well below full scale
+const AMPLITUDE: f32 = 0.3;
+
+/// How long the chime plays
+pub const EARCON_DURATION: Duration = Duration::from_millis(230);
+
+/// The chime as 16-bit mono PCM
+pub fn earcon() -> AudioData {
+    let bytes = earcon_samples()
+        .iter()
+        .flat_map(|s| s.to_le_bytes())
+        .collect();
+    AudioData::Pcm16 {
+        bytes,
+        sample_rate: SAMPLE_RATE,
+    }
+}
+
+fn earcon_samples() -> Vec<i16> {
+    let fade = (SAMPLE_RATE * FADE_MS / 1000) as f32;
+
+    NOTES
+        .iter()
+        .flat_map(|&(frequency, ms)| {
+            let len = SAMPLE_RATE * ms / 1000;
+            (0..len).map(move |i| {
+                let envelope = (i as f32 / fade).min((len - i) as f32 / fade).min(1.0);
+                let phase = 2.0 * std::f32::consts::PI * frequency * i as f32 / SAMPLE_RATE as f32;
+                (phase.sin() * envelope * AMPLITUDE * i16::MAX as f32) as i16
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_earcon_samples() {
+        let samples = earcon_samples();
+        let expected = SAMPLE_RATE as u128 * EARCON_DURATION.as_millis() / 1000;
+        assert_eq!(samples.len() as u128, expected);
+
+        // Quiet at the edges, never near clipping
+        assert_eq!(samples[0], 0);
+        let peak = samples.iter().map(|s| s.unsigned_abs()).max().unwrap();
+        assert!(peak as f32 <= AMPLITUDE * i16::MAX as f32);
+        assert!(peak > 5_000);
+    }
+}
@@ -4,8 +4,16 @@
 //! Offers the most natural sounding voices.
 
 use anyhow::Result;
+use parking_lot::RwLock;
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+use super::audio_playback::{AudioData, AudioPlayer};
+use super::TTSEngine;
+
+/// Rachel, the default premade voice
+const DEFAULT_VOICE_ID: &str = "21m00Tcm4TlvDq8ikWAM";
 
 /// ElevenLabs voice presets
 #[derive(Debug, Clone)]
@@ -102,11 +110,17 @@ struct VoiceInfo {
 }
 
 /// ElevenLabs TTS client
+#[derive(Clone)]
 pub struct ElevenLabsTTS {
     api_key: String,
     client: Client,
     model_id: String,
     settings: VoiceSettings,
+    player: AudioPlayer,
+    /// Voice used by `TTSEngine::speak`
+    voice_id: String,
+    /// Account voices from the last `list_voices_api` call
+    live_voices: Arc<RwLock<Vec<ElevenLabsVoice>>>,
 }
 
 impl ElevenLabsTTS {
@@ -117,9 +131,18 @@ impl ElevenLabsTTS {
             client: Client::new(),
             model_id: "eleven_monolingual_v1".to_string(),
             settings: VoiceSettings::default(),
+            player: AudioPlayer::new(1.0, 1.0),
+            voice_id: DEFAULT_VOICE_ID.to_string(),
+            live_voices: Arc::new(RwLock::new(Vec::new())),
         }
     }
 
+    /// Set the voice used by `TTSEngine::speak`
+    pub fn with_voice(mut self, voice_id: impl Into<String>) -> Self {
+        self.voice_id = voice_id.into();
+        self
+    }
+
     /// Use multilingual model (supports multiple languages)
     pub fn multilingual(mut self) -> Self {
         self.model_id = "eleven_multilingual_v2".to_string();
@@ -138,11 +161,26 @@ impl ElevenLabsTTS {
         self
     }
 
+    /// Play through a shared player (carries volume and speed)
+    pub fn with_player(mut self, player: AudioPlayer) -> Self {
+        self.player = player;
+        self
+    }
+
     /// Generate speech and play it
     pub async fn speak(&self, text: &str, voice_id: &str) -> Result<()> {
         let audio_data = self.generate(text, voice_id).await?;
-        play_audio(&audio_data)?;
-        Ok(())
+        self.player.play(AudioData::Encoded(audio_data)).await
+    }
+
+    /// Stop playback
+    pub fn stop(&self) {
+        self.player.stop();
+    }
+
+    /// Check if audio is playing
+    pub fn is_speaking(&self) -> bool {
+        self.player.is_speaking()
     }
 
     /// Generate speech audio (returns MP3 bytes)
@@ -176,8 +214,10 @@ impl ElevenLabsTTS {
         Ok(audio_data)
     }
 
-    /// List available voices
-    pub async fn list_voices(&self) -> Result<Vec<ElevenLabsVoice>> {
+    /// Fetch the account's voices, including cloned ones
+    ///
+    /// The result is cached for `voices()` and `TTSEngine::list_voices`.
+    pub async fn list_voices_api(&self) -> Result<Vec<ElevenLabsVoice>> {
         let response = self
             .client
             .get("https://api.elevenlabs.io/v1/voices")
@@ -194,7 +234,7 @@ impl ElevenLabsTTS {
 
         let voices_response: VoicesResponse = response.json().await?;
 
-        Ok(voices_response
+        let voices: Vec<ElevenLabsVoice> = voices_response
             .voices
             .into_iter()
             .map(|v| ElevenLabsVoice {
@@ -202,7 +242,20 @@ impl ElevenLabsTTS {
                 name: v.name,
                 category: v.category.unwrap_or_else(|| "custom".to_string()),
             })
-            .collect())
+            .collect();
+
+        *self.live_voices.write() = voices.clone();
+        Ok(voices)
+    }
+
+    /// Account voices if fetched, otherwise the premade presets
+    pub fn voices(&self) -> Vec<ElevenLabsVoice> {
+        let live = self.live_voices.read();
+        if self.api_key.is_empty() || live.is_empty() {
+            ElevenLabsVoice::premade()
+        } else {
+            live.clone()
+        }
     }
 
     /// Get user subscription info (quota remaining)
@@ -226,45 +279,38 @@ impl ElevenLabsTTS {
     }
 }
 
-/// Subscription info from ElevenLabs
-#[derive(Debug, Deserialize)]
-pub struct SubscriptionInfo {
-    pub character_count: u32,
-    pub character_limit: u32,
-    pub tier: String,
-}
-
-/// Play audio bytes
-fn play_audio(audio_data: &[u8]) -> Result<()> {
-    // Save to temp file and play
-    use std::process::Command;
-
-    let temp_path = std::env::temp_dir().join("voice_copilot_elevenlabs.mp3");
-    std::fs::write(&temp_path, audio_data)?;
+impl TTSEngine for ElevenLabsTTS {
+    fn speak(&self, text: &str) -> Result<()> {
+        let tts = self.clone();
+        let text = text.to_string();
+        tokio::runtime::Handle::try_current()?.spawn(async move {
+            if let Err(e) = tts.speak(&text, &tts.voice_id).await {
+                tracing::warn!("ElevenLabs TTS error: {}", e);
+            }
+        });
+        Ok(())
+    }
 
-    #[cfg(target_os = "windows")]
-    {
-        Command::new("cmd")
-            .args(["/C", "start", "/B", "", &temp_path.to_string_lossy()])
-            .spawn()?;
+    fn stop(&self) -> Result<()> {
+        self.player.stop();
+        Ok(())
     }
 
-    #[cfg(target_os = "macos")]
-    {
-        Command::new("afplay")
-            .arg(&temp_path)
-            .spawn()?;
+    fn is_speaking(&self) -> bool {
+        self.player.is_speaking()
     }
 
-    #[cfg(target_os = "linux")]
-    {
-        Command::new("mpv")
-            .arg("--really-quiet")
-            .arg(&temp_path)
-            .spawn()?;
+    fn list_voices(&self) -> Vec<String> {
+        self.voices().into_iter().map(|v| v.name).collect()
     }
+}
 
-    Ok(())
+/// Subscription info from ElevenLabs
+#[derive(Debug, Deserialize)]
+pub struct SubscriptionInfo {
+    pub character_count: u32,
+    pub character_limit: u32,
+    pub tier: String,
 }
 
 #[cfg(test)]
@@ -277,4 +323,28 @@ mod tests {
         assert!(!voices.is_empty());
         assert!(voices.iter().any(|v| v.name == "Rachel"));
     }
+
+    #[test]
+    fn test_list_voices_falls_back_to_presets() {
+        let tts = ElevenLabsTTS::new("");
+        assert_eq!(TTSEngine::list_voices(&tts).len(), ElevenLabsVoice::premade().len());
+
+        // Live voices only count when there's a key to have fetched them with
+        let tts = ElevenLabsTTS::new("key");
+        tts.live_voices.write().push(ElevenLabsVoice {
+            id: "clone-1".to_string(),
+            name: "My Voice".to_string(),
+            category: "cloned".to_string(),
+        });
+        assert_eq!(TTSEngine::list_voices(&tts), vec!["My Voice".to_string()]);
+    }
+
+    #[tokio::test]
+    #[ignore] // Requires API key
+    async fn test_list_voices_api() {
+        let tts = ElevenLabsTTS::new("YOUR_API_KEY");
+        let voices = tts.list_voices_api().await.unwrap();
+        assert!(!voices.is_empty());
+        assert_eq!(tts.voices().len(), voices.len());
+    }
 }
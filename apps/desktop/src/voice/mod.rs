@@ -6,16 +6,26 @@
 //! - Windows SAPI (local, no API needed)
 //! - ElevenLabs (premium quality, requires API)
 
+mod audio_playback;
+mod earcon;
 mod openai_tts;
 mod windows_tts;
 mod elevenlabs;
+mod sentence_splitter;
 
+pub use audio_playback::{AudioData, AudioPlayer};
+pub use earcon::{earcon, EARCON_DURATION};
 pub use openai_tts::{OpenAITTS, OpenAIVoice};
 pub use windows_tts::WindowsTTS;
 pub use elevenlabs::{ElevenLabsTTS, ElevenLabsVoice};
+pub use sentence_splitter::SentenceSplitter;
 
 use anyhow::Result;
+use parking_lot::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 use tokio::sync::mpsc;
+use tokio_util::sync::CancellationToken;
 
 /// TTS Provider selection
 #[derive(Debug, Clone, Default, PartialEq)]
@@ -44,6 +54,8 @@ pub struct TTSConfig {
     pub volume: f32,
     /// API key (for cloud providers)
     pub api_key: Option<String>,
+    /// Stop talking when the other side starts speaking
+    pub barge_in_enabled: bool,
 }
 
 impl Default for TTSConfig {
@@ -54,6 +66,7 @@ impl Default for TTSConfig {
             speed: 1.0,
             volume: 1.0,
             api_key: None,
+            barge_in_enabled: true,
         }
     }
 }
@@ -73,51 +86,50 @@ pub trait TTSEngine: Send + Sync {
     fn list_voices(&self) -> Vec<String>;
 }
 
+/// Text waiting to be spoken
+struct Utterance {
+    text: String,
+    /// Cancelled by `stop()` so queued speech is dropped
+    cancel: CancellationToken,
+}
+
 /// Voice output manager
+#[derive(Clone)]
 pub struct VoiceOutput {
     config: TTSConfig,
     is_enabled: bool,
-    speech_queue: mpsc::Sender<String>,
+    speech_queue: mpsc::Sender<Utterance>,
+    /// Token for speech queued since the last `stop()`
+    cancel: Arc<Mutex<CancellationToken>>,
+    speaking: Arc<AtomicBool>,
+    /// Shared by the cloud engines so `stop()` cuts playback
+    player: AudioPlayer,
 }
 
 impl VoiceOutput {
     /// Create a new voice output manager
     pub fn new(config: TTSConfig) -> Self {
-        let (tx, mut rx) = mpsc::channel::<String>(10);
+        let (tx, mut rx) = mpsc::channel::<Utterance>(10);
 
         let config_clone = config.clone();
+        let speaking = Arc::new(AtomicBool::new(false));
+        let speaking_clone = speaking.clone();
+        let player = AudioPlayer::new(config.volume, config.speed);
+        let player_clone = player.clone();
 
         // Spawn speech processing task
         tokio::spawn(async move {
-            while let Some(text) = rx.recv().await {
-                match &config_clone.provider {
-                    TTSProvider::OpenAI => {
-                        if let Some(api_key) = &config_clone.api_key {
-                            let tts = OpenAITTS::new(api_key.clone());
-                            if let Err(e) = tts.speak(&text, &config_clone.voice).await {
-                                tracing::warn!("TTS error: {}", e);
-                            }
-                        }
-                    }
-                    TTSProvider::WindowsSAPI => {
-                        #[cfg(target_os = "windows")]
-                        {
-                            let tts = WindowsTTS::new();
-                            if let Err(e) = tts.speak(&text) {
-                                tracing::warn!("Windows TTS error: {}", e);
-                            }
-                        }
-                    }
-                    TTSProvider::ElevenLabs => {
-                        if let Some(api_key) = &config_clone.api_key {
-                            let tts = ElevenLabsTTS::new(api_key.clone());
-                            if let Err(e) = tts.speak(&text, &config_clone.voice).await {
-                                tracing::warn!("ElevenLabs TTS error: {}", e);
-                            }
-                        }
-                    }
-                    TTSProvider::Disabled => {}
+            while let Some(utterance) = rx.recv().await {
+                if utterance.cancel.is_cancelled() {
+                    continue;
+                }
+
+                speaking_clone.store(true, Ordering::SeqCst);
+                tokio::select! {
+                    _ = utterance.cancel.cancelled() => {}
+                    _ = speak_with(&config_clone, &player_clone, &utterance.text) => {}
                 }
+                speaking_clone.store(false, Ordering::SeqCst);
             }
         });
 
@@ -125,16 +137,92 @@ impl VoiceOutput {
             config,
             is_enabled: true,
             speech_queue: tx,
+            cancel: Arc::new(Mutex::new(CancellationToken::new())),
+            speaking,
+            player,
         }
     }
 
     /// Speak text
     pub async fn speak(&self, text: &str) -> Result<()> {
-        if !self.is_enabled || self.config.provider == TTSProvider::Disabled {
+        if !self.is_active() {
+            return Ok(());
+        }
+
+        let cancel = self.cancel.lock().clone();
+        self.enqueue(text.to_string(), cancel).await
+    }
+
+    /// Speak streamed text sentence by sentence as it arrives
+    ///
+    /// Each complete sentence is queued as soon as it forms, so speech starts
+    /// before the response has finished. Returns once `rx` closes or `stop()`
+    /// is called; whatever is left in the buffer is spoken on close.
+    pub async fn speak_stream(&self, mut rx: mpsc::Receiver<String>) -> Result<()> {
+        if !self.is_active() {
             return Ok(());
         }
 
-        self.speech_queue.send(text.to_string()).await?;
+        let cancel = self.cancel.lock().clone();
+        let mut splitter = SentenceSplitter::new();
+
+        loop {
+            let chunk = tokio::select! {
+                _ = cancel.cancelled() => return Ok(()),
+                chunk = rx.recv() => chunk,
+            };
+            let Some(chunk) = chunk else { break };
+
+            for sentence in splitter.push(&chunk) {
+                self.enqueue(sentence, cancel.clone()).await?;
+            }
+        }
+
+        if let Some(rest) = splitter.finish() {
+            self.enqueue(rest, cancel).await?;
+        }
+        Ok(())
+    }
+
+    /// Stop current speech and drop anything still queued
+    pub fn stop(&self) {
+        let previous = std::mem::replace(&mut *self.cancel.lock(), CancellationToken::new());
+        previous.cancel();
+        self.player.stop();
+
+        if self.config.provider == TTSProvider::WindowsSAPI {
+            if let Err(e) = WindowsTTS::stop() {
+                tracing::warn!("Windows TTS stop error: {}", e);
+            }
+        }
+    }
+
+    /// The other side started talking: stop if we're talking over them
+    ///
+    /// Returns true if speech was interrupted.
+    pub fn barge_in(&self) -> bool {
+        if !self.config.barge_in_enabled || !self.is_speaking() {
+            return false;
+        }
+
+        self.stop();
+        true
+    }
+
+    /// Check if currently speaking
+    pub fn is_speaking(&self) -> bool {
+        self.speaking.load(Ordering::SeqCst) || self.player.is_speaking()
+    }
+
+    fn is_active(&self) -> bool {
+        self.is_enabled && self.config.provider != TTSProvider::Disabled
+    }
+
+    async fn enqueue(&self, text: String, cancel: CancellationToken) -> Result<()> {
+        if cancel.is_cancelled() {
+            return Ok(());
+        }
+        self.speech_queue.send(Utterance { text, cancel }).await?;
         Ok(())
     }
 
@@ -154,6 +242,38 @@ impl VoiceOutput {
     }
 }
 
+/// Speak one utterance with the configured backend
+async fn speak_with(config: &TTSConfig, player: &AudioPlayer, text: &str) {
+    match &config.provider {
+        TTSProvider::OpenAI => {
+            if let Some(api_key) = &config.api_key {
+                let tts = OpenAITTS::new(api_key.clone()).with_player(player.clone());
+                if let Err(e) = tts.speak(text, &config.voice).await {
+                    tracing::warn!("TTS error: {}", e);
+                }
+            }
+        }
+        TTSProvider::WindowsSAPI => {
+            #[cfg(target_os = "windows")]
+            {
+                let tts = WindowsTTS::new();
+                if let Err(e) = tts.speak(text) {
+                    tracing::warn!("Windows TTS error: {}", e);
+                }
+            }
+        }
+        TTSProvider::ElevenLabs => {
+            if let Some(api_key) = &config.api_key {
+                let tts = ElevenLabsTTS::new(api_key.clone()).with_player(player.clone());
+                if let Err(e) = tts.speak(text, &config.voice).await {
+                    tracing::warn!("ElevenLabs TTS error: {}", e);
+                }
+            }
+        }
+        TTSProvider::Disabled => {}
+    }
+}
+
 /// Check available TTS providers
 pub fn check_tts_availability() -> TTSAvailability {
     let mut available = Vec::new();
@@ -191,3 +311,27 @@ impl TTSAvailability {
         self.available.contains(provider)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_barge_in_flushes_speech() {
+        let voice = VoiceOutput::new(TTSConfig::default());
+        assert!(!voice.barge_in());
+
+        let queued = voice.cancel.lock().clone();
+        voice.speaking.store(true, Ordering::SeqCst);
+        assert!(voice.barge_in());
+        assert!(queued.is_cancelled());
+        assert!(!voice.cancel.lock().is_cancelled());
+
+        let voice = VoiceOutput::new(TTSConfig {
+            barge_in_enabled: false,
+            ..TTSConfig::default()
+        });
+        voice.speaking.store(true, Ordering::SeqCst);
+        assert!(!voice.barge_in());
+    }
+}
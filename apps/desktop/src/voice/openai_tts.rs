@@ -5,7 +5,9 @@
 
 use anyhow::Result;
 use reqwest::Client;
-use std::io::Cursor;
+
+use super::audio_playback::{AudioData, AudioPlayer};
+use super::TTSEngine;
 
 /// OpenAI voices
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -60,10 +62,14 @@ impl OpenAIVoice {
 }
 
 /// OpenAI TTS client
+#[derive(Clone)]
 pub struct OpenAITTS {
     api_key: String,
     client: Client,
     model: String,
+    player: AudioPlayer,
+    /// Voice used by `TTSEngine::speak`
+    voice: OpenAIVoice,
 }
 
 impl OpenAITTS {
@@ -73,20 +79,43 @@ impl OpenAITTS {
             api_key: api_key.into(),
             client: Client::new(),
             model: "tts-1".to_string(), // tts-1 or tts-1-hd
+            player: AudioPlayer::new(1.0, 1.0),
+            voice: OpenAIVoice::Alloy,
         }
     }
 
+    /// Set the voice used by `TTSEngine::speak`
+    pub fn with_voice(mut self, voice: OpenAIVoice) -> Self {
+        self.voice = voice;
+        self
+    }
+
     /// Use HD model (higher quality, higher latency)
     pub fn with_hd(mut self) -> Self {
         self.model = "tts-1-hd".to_string();
         self
     }
 
+    /// Play through a shared player (carries volume and speed)
+    pub fn with_player(mut self, player: AudioPlayer) -> Self {
+        self.player = player;
+        self
+    }
+
     /// Generate speech and play it
     pub async fn speak(&self, text: &str, voice: &str) -> Result<()> {
         let audio_data = self.generate(text, voice).await?;
-        play_audio(&audio_data)?;
-        Ok(())
+        self.player.play(AudioData::Encoded(audio_data)).await
+    }
+
+    /// Stop playback
+    pub fn stop(&self) {
+        self.player.stop();
+    }
+
+    /// Check if audio is playing
+    pub fn is_speaking(&self) -> bool {
+        self.player.is_speaking()
     }
 
     /// Generate speech audio (returns MP3 bytes)
@@ -123,60 +152,31 @@ impl OpenAITTS {
     }
 }
 
-/// Play audio bytes (MP3 format)
-fn play_audio(audio_data: &[u8]) -> Result<()> {
-    // Use rodio for cross-platform audio playback
-    // Note: This requires the rodio crate to be added to dependencies
-
-    #[cfg(feature = "audio_playback")]
-    {
-        use rodio::{Decoder, OutputStream, Sink};
-
-        let (_stream, stream_handle) = OutputStream::try_default()?;
-        let sink = Sink::try_new(&stream_handle)?;
-
-        let cursor = Cursor::new(audio_data.to_vec());
-        let source = Decoder::new(cursor)?;
-
-        sink.append(source);
-        sink.sleep_until_end();
+impl TTSEngine for OpenAITTS {
+    fn speak(&self, text: &str) -> Result<()> {
+        let tts = self.clone();
+        let text = text.to_string();
+        tokio::runtime::Handle::try_current()?.spawn(async move {
+            if let Err(e) = tts.speak(&text, tts.voice.as_str()).await {
+                tracing::warn!("TTS error: {}", e);
+            }
+        });
+        Ok(())
     }
 
-    #[cfg(not(feature = "audio_playback"))]
-    {
-        // Fallback: Save to temp file and use system player
-        use std::process::Command;
-
-        let temp_path = std::env::temp_dir().join("voice_copilot_tts.mp3");
-        std::fs::write(&temp_path, audio_data)?;
-
-        #[cfg(target_os = "windows")]
-        {
-            // Use Windows Media Player or default app
-            Command::new("cmd")
-                .args(["/C", "start", "", &temp_path.to_string_lossy()])
-                .spawn()?;
-        }
-
-        #[cfg(target_os = "macos")]
-        {
-            Command::new("afplay")
-                .arg(&temp_path)
-                .spawn()?
-                .wait()?;
-        }
+    fn stop(&self) -> Result<()> {
+        self.player.stop();
+        Ok(())
+    }
 
-        #[cfg(target_os = "linux")]
-        {
-            Command::new("mpv")
-                .arg("--really-quiet")
-                .arg(&temp_path)
-                .spawn()?
-                .wait()?;
-        }
+    fn is_speaking(&self) -> bool {
+        self.player.is_speaking()
     }
 
-    Ok(())
+    /// OpenAI has no voice listing endpoint; every account gets the same set
+    fn list_voices(&self) -> Vec<String> {
+        OpenAIVoice::all().iter().map(|v| v.as_str().to_string()).collect()
+    }
 }
 
 #[cfg(test)]
@@ -189,4 +189,11 @@ mod tests {
         assert_eq!(OpenAIVoice::from_str("ONYX"), OpenAIVoice::Onyx);
         assert_eq!(OpenAIVoice::from_str("unknown"), OpenAIVoice::Alloy);
     }
+
+    #[test]
+    fn test_list_voices() {
+        let voices = TTSEngine::list_voices(&OpenAITTS::new("key"));
+        assert_eq!(voices.len(), 6);
+        assert!(voices.contains(&"shimmer".to_string()));
+    }
 }
@@ -0,0 +1,126 @@
+//! Sentence Splitter
+//!
+//! Turns a stream of LLM text chunks into complete, speakable sentences
+//! so TTS can start on the first sentence while the rest is still arriving.
+
+/// Words that end in a period without ending the sentence
+const ABBREVIATIONS: &[&str] = &["e.g", "i.e", "mr", "mrs", "ms", "dr", "vs", "approx"];
+
+/// Accumulates streamed text and yields complete sentences
+#[derive(Debug, Default)]
+pub struct SentenceSplitter {
+    buffer: String,
+}
+
+impl SentenceSplitter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a chunk and return any sentences it completed
+    pub fn push(&mut self, chunk: &str) -> Vec<String> {
+        self.buffer.push_str(chunk);
+
+        let mut sentences = Vec::new();
+        while let Some(end) = find_boundary(&self.buffer) {
+            let sentence: String = self.buffer.drain(..end).collect();
+            if let Some(sentence) = clean(&sentence) {
+                sentences.push(sentence);
+            }
+        }
+        sentences
+    }
+
+    /// Take whatever is left once the stream has ended
+    pub fn finish(&mut self) -> Option<String> {
+        clean(&std::mem::take(&mut self.buffer))
+    }
+}
+
+/// Byte offset just past the first sentence boundary, if one is complete
+fn find_boundary(text: &str) -> Option<usize> {
+    let mut chars = text.char_indices().peekable();
+
+    while let Some((i, c)) = chars.next() {
+        if c == '\n' {
+            return Some(i + 1);
+        }
+        if matches!(c, '.' | '!' | '?') {
+            // Wait for the next chunk before deciding - "3." may become "3.5"
+            let &(_, next) = chars.peek()?;
+            if next.is_whitespace() && !is_abbreviation(&text[..i]) {
+                return Some(i + c.len_utf8());
+            }
+        }
+    }
+
+    None
+}
+
+/// Whether the period after `before` belongs to an abbreviation or list number
+fn is_abbreviation(before: &str) -> bool {
+    let segment = before.trim();
+    if !segment.is_empty() && segment.chars().all(|c| c.is_ascii_digit()) {
+        return true;
+    }
+
+    let word = segment
+        .rsplit(char::is_whitespace)
+        .next()
+        .unwrap_or_default()
+        .trim_start_matches(|c: char| !c.is_alphanumeric())
+        .to_lowercase();
+    ABBREVIATIONS.contains(&word.as_str())
+}
+
+/// Strip markdown so it isn't read aloud; headings are skipped entirely
+fn clean(sentence: &str) -> Option<String> {
+    let sentence = sentence.trim();
+    if sentence.starts_with('#') {
+        return None;
+    }
+
+    let sentence = sentence
+        .trim_start_matches(|c: char| matches!(c, '•' | '-' | '*') || c.is_whitespace())
+        .replace("**", "");
+    let sentence = sentence.trim();
+
+    if sentence.chars().any(|c| c.is_alphanumeric()) {
+        Some(sentence.to_string())
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_splits_streamed_chunks() {
+        let mut splitter = SentenceSplitter::new();
+        assert!(splitter.push("Our enterprise plan is").is_empty());
+        assert!(splitter.push(" $3.").is_empty());
+        assert_eq!(
+            splitter.push("50 per seat. It includes SSO! Want"),
+            vec!["Our enterprise plan is $3.50 per seat.", "It includes SSO!"]
+        );
+        assert_eq!(splitter.push(" a demo?"), Vec::<String>::new());
+        assert_eq!(splitter.finish(), Some("Want a demo?".to_string()));
+        assert_eq!(splitter.finish(), None);
+    }
+
+    #[test]
+    fn test_keeps_abbreviations_together() {
+        let mut splitter = SentenceSplitter::new();
+        let sentences = splitter.push("Big teams, e.g. Acme, use it. 1. Fast setup\n");
+        assert_eq!(sentences, vec!["Big teams, e.g. Acme, use it.", "1. Fast setup"]);
+    }
+
+    #[test]
+    fn test_strips_markdown() {
+        let mut splitter = SentenceSplitter::new();
+        let sentences = splitter.push("## Direct Answer\nYes, **absolutely**.\n• Setup takes a day\n");
+        assert_eq!(sentences, vec!["Yes, absolutely.", "Setup takes a day"]);
+    }
+}
@@ -61,6 +61,9 @@ class ApiClient {
     if (this.accessToken) {
       headers['Authorization'] = `Bearer ${this.accessToken}`;
     }
+    if (this.deviceId) {
+      headers['X-Device-Id'] = this.deviceId;
+    }
 
     const response = await fetch(`${API_URL}${endpoint}`, {
       ...options,